@@ -0,0 +1,8 @@
+-- Migration: Base tasks table
+-- IF NOT EXISTS keeps databases created before migrations were embedded working
+
+CREATE TABLE IF NOT EXISTS tasks (
+    task_id SERIAL PRIMARY KEY,
+    name VARCHAR NOT NULL,
+    priority INTEGER
+);
@@ -0,0 +1,4 @@
+-- Migration: Add optional Markdown description to tasks
+
+ALTER TABLE tasks ADD COLUMN description TEXT;
+ALTER TABLE tasks ADD CONSTRAINT check_description_length CHECK (char_length(description) <= 10000);
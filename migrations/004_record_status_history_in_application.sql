@@ -0,0 +1,5 @@
+-- Migration: Status history is now written by the application together with the task update
+-- The trigger would record every change a second time, attributed to 'system'
+
+DROP TRIGGER IF EXISTS trigger_task_status_history ON tasks;
+DROP FUNCTION IF EXISTS track_task_status_change();
@@ -0,0 +1,6 @@
+-- Migration: Soft delete tasks so accidental deletions can be restored
+
+ALTER TABLE tasks ADD COLUMN deleted_at TIMESTAMPTZ;
+
+-- Most queries only look at live tasks
+CREATE INDEX idx_tasks_live ON tasks(task_id) WHERE deleted_at IS NULL;
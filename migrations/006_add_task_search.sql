@@ -0,0 +1,9 @@
+-- Migration: Full-text search over task names, descriptions and status comments
+
+ALTER TABLE tasks ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+    setweight(to_tsvector('english', coalesce(name, '')), 'A') ||
+    setweight(to_tsvector('english', coalesce(description, '')), 'B')
+) STORED;
+
+CREATE INDEX idx_tasks_search_vector ON tasks USING GIN (search_vector);
+CREATE INDEX idx_status_history_comment_search ON status_history USING GIN (to_tsvector('english', comment)) WHERE comment IS NOT NULL;
@@ -0,0 +1,3 @@
+-- Migration: Store the reviewer's checklist with approval history entries
+
+ALTER TABLE status_history ADD COLUMN checklist JSONB;
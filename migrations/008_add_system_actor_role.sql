@@ -0,0 +1,17 @@
+-- Migration: Attribute background changes to a system actor
+
+ALTER TABLE status_history DROP CONSTRAINT check_user_role;
+ALTER TABLE status_history ADD CONSTRAINT check_user_role
+    CHECK (user_role IN ('User', 'Manager', 'Admin', 'System'));
+
+-- Cancellations written by the stale task sweep before it had its own actor
+UPDATE status_history
+SET changed_by = 'system:stale-task-sweep', user_role = 'System'
+WHERE changed_by = 'system'
+  AND to_status = 'Cancelled'
+  AND comment LIKE 'Automatically cancelled after%';
+
+-- Entries backfilled or recorded by the old database trigger
+UPDATE status_history
+SET changed_by = 'system:legacy', user_role = 'System'
+WHERE changed_by = 'system';
@@ -0,0 +1,13 @@
+-- Migration: API keys for machine clients
+
+CREATE TABLE api_keys (
+    id UUID PRIMARY KEY,
+    name VARCHAR(40) NOT NULL,
+    key_prefix VARCHAR(16) NOT NULL,
+    key_hash CHAR(64) NOT NULL UNIQUE, -- SHA-256 of the secret; the secret itself is never stored
+    role VARCHAR(10) NOT NULL,
+    created_by VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT check_api_key_role CHECK (role IN ('User', 'Manager', 'Admin'))
+);
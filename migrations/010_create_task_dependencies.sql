@@ -0,0 +1,13 @@
+-- Migration: Tasks that must finish before another task can start or complete
+
+CREATE TABLE task_dependencies (
+    task_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    depends_on_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (task_id, depends_on_id),
+    CONSTRAINT check_no_self_dependency CHECK (task_id <> depends_on_id)
+);
+
+-- Finding the tasks a finished task was blocking
+CREATE INDEX idx_task_dependencies_depends_on ON task_dependencies(depends_on_id);
@@ -0,0 +1,13 @@
+-- Migration: Task events written alongside the changes that caused them, for a relay to deliver
+
+CREATE TABLE event_outbox (
+    id BIGSERIAL PRIMARY KEY,
+    event_type VARCHAR(50) NOT NULL,
+    task_id INTEGER NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    published_at TIMESTAMPTZ
+);
+
+-- A relay reads undelivered events in insertion order
+CREATE INDEX idx_event_outbox_unpublished ON event_outbox(id) WHERE published_at IS NULL;
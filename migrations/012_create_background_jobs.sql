@@ -0,0 +1,14 @@
+-- Migration: Schedule and last run of each background job, shared by every replica
+
+CREATE TABLE background_jobs (
+    name VARCHAR(50) PRIMARY KEY,
+    interval_ms BIGINT NOT NULL,
+    paused BOOLEAN NOT NULL DEFAULT FALSE,
+    run_requested BOOLEAN NOT NULL DEFAULT FALSE,
+    last_started_at TIMESTAMPTZ,
+    last_finished_at TIMESTAMPTZ,
+    last_error TEXT,
+    next_run_at TIMESTAMPTZ NOT NULL,
+
+    CONSTRAINT check_interval_positive CHECK (interval_ms > 0)
+);
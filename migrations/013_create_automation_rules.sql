@@ -0,0 +1,12 @@
+-- Migration: Rules that act on tasks when their events match a trigger
+
+CREATE TABLE automation_rules (
+    id UUID PRIMARY KEY,
+    name VARCHAR(80) NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    rule_trigger JSONB NOT NULL, -- event_type and filter
+    rule_action JSONB NOT NULL, -- tagged by type, e.g. {"type": "set_priority", "priority": 1}
+    created_by VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
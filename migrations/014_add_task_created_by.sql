@@ -0,0 +1,3 @@
+-- Migration: Record which user created each task; existing tasks keep NULL
+
+ALTER TABLE tasks ADD COLUMN created_by VARCHAR(50);
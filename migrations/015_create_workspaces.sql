@@ -0,0 +1,18 @@
+-- Migration: Workspaces, which keep each team's tasks and history apart on a shared deployment
+-- Existing rows belong to the seeded default workspace. Tasks on a tenant shard have no
+-- workspaces table beside them, so workspace_id is not a foreign key.
+
+CREATE TABLE workspaces (
+    id VARCHAR(50) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    created_by VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO workspaces (id, name, created_by) VALUES ('default', 'Default', 'system:migration');
+
+ALTER TABLE tasks ADD COLUMN workspace_id VARCHAR(50) NOT NULL DEFAULT 'default';
+ALTER TABLE status_history ADD COLUMN workspace_id VARCHAR(50) NOT NULL DEFAULT 'default';
+
+CREATE INDEX idx_tasks_workspace_id ON tasks(workspace_id);
+CREATE INDEX idx_status_history_workspace_id ON status_history(workspace_id, changed_at);
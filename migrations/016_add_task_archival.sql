@@ -0,0 +1,6 @@
+-- Migration: Archive finished tasks so they drop out of the default listings
+
+ALTER TABLE tasks ADD COLUMN archived_at TIMESTAMPTZ;
+
+-- Archived listings only look at the archived tasks
+CREATE INDEX idx_tasks_archived ON tasks(archived_at) WHERE archived_at IS NOT NULL;
@@ -0,0 +1,3 @@
+-- Migration: Record which review stage a history entry signed off, for multi-stage review chains
+
+ALTER TABLE status_history ADD COLUMN review_stage VARCHAR(100);
@@ -0,0 +1,15 @@
+-- Migration: Files uploaded to tasks. The files themselves are kept in attachment storage under
+-- storage_key; this table only holds what the listings show.
+
+CREATE TABLE task_attachments (
+    id VARCHAR(36) PRIMARY KEY,
+    task_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(255) NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    storage_key VARCHAR(255) NOT NULL,
+    uploaded_by VARCHAR(50) NOT NULL,
+    uploaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_task_attachments_task_id ON task_attachments(task_id, uploaded_at);
@@ -0,0 +1,9 @@
+-- Migration: Workflows admins set for single workspaces. Workspaces without a row follow the
+-- configured workflow.
+
+CREATE TABLE workspace_workflows (
+    workspace_id VARCHAR(50) PRIMARY KEY REFERENCES workspaces(id) ON DELETE CASCADE,
+    definition JSONB NOT NULL, -- states and guarded transitions
+    updated_by VARCHAR(50) NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,15 @@
+-- Migration: Statuses admins define next to the built-in ones, each mapped to a category.
+-- Tasks and history entries may now name them, so the built-in status checks go.
+
+CREATE TABLE statuses (
+    name VARCHAR(20) PRIMARY KEY,
+    category VARCHAR(20) NOT NULL,
+    created_by VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT check_status_category CHECK (category IN ('open', 'in_progress', 'done'))
+);
+
+ALTER TABLE tasks DROP CONSTRAINT check_status;
+ALTER TABLE status_history DROP CONSTRAINT check_from_status;
+ALTER TABLE status_history DROP CONSTRAINT check_to_status;
@@ -0,0 +1,4 @@
+-- Migration: Let users order tasks by hand within a board column
+
+-- Compared byte by byte; tasks without a rank sort by their zero-padded id
+ALTER TABLE tasks ADD COLUMN rank TEXT COLLATE "C";
@@ -0,0 +1,8 @@
+-- Migration: Automation rules and API keys belong to one workspace, so a rule only sees its
+-- own workspace's events and a key only reaches its own workspace's tasks. Existing rows
+-- belong to the default workspace.
+
+ALTER TABLE automation_rules ADD COLUMN workspace_id VARCHAR(50) NOT NULL DEFAULT 'default';
+ALTER TABLE api_keys ADD COLUMN workspace_id VARCHAR(50) NOT NULL DEFAULT 'default';
+
+CREATE INDEX idx_automation_rules_workspace_id ON automation_rules(workspace_id, created_at);
@@ -0,0 +1,63 @@
+-- Migration: Schema for local development on SQLite
+-- Mirrors migrations/ as of 011, except for the event outbox, which only Postgres deployments use.
+-- Timestamps are RFC 3339 text in UTC, so they sort and compare as strings.
+
+CREATE TABLE tasks (
+    task_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    priority INTEGER,
+    description TEXT,
+    status TEXT NOT NULL DEFAULT 'Pending',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    deleted_at TEXT,
+
+    CONSTRAINT check_status CHECK (status IN ('Pending', 'InProgress', 'PendingReview', 'Completed', 'Cancelled')),
+    CONSTRAINT check_description_length CHECK (length(description) <= 10000)
+);
+
+CREATE INDEX idx_tasks_status ON tasks(status);
+CREATE INDEX idx_tasks_created_at ON tasks(created_at);
+
+CREATE TABLE status_history (
+    id TEXT PRIMARY KEY,
+    task_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    from_status TEXT,
+    to_status TEXT NOT NULL,
+    changed_at TEXT NOT NULL,
+    changed_by TEXT,
+    comment TEXT,
+    user_role TEXT NOT NULL DEFAULT 'User',
+    checklist TEXT, -- JSON array of checklist items
+
+    CONSTRAINT check_from_status CHECK (from_status IN ('Pending', 'InProgress', 'PendingReview', 'Completed', 'Cancelled')),
+    CONSTRAINT check_to_status CHECK (to_status IN ('Pending', 'InProgress', 'PendingReview', 'Completed', 'Cancelled')),
+    CONSTRAINT check_user_role CHECK (user_role IN ('User', 'Manager', 'Admin', 'System'))
+);
+
+CREATE INDEX idx_status_history_task_id ON status_history(task_id);
+CREATE INDEX idx_status_history_changed_at ON status_history(changed_at);
+CREATE INDEX idx_status_history_to_status ON status_history(to_status);
+
+CREATE TABLE api_keys (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    key_prefix TEXT NOT NULL,
+    key_hash TEXT NOT NULL UNIQUE, -- SHA-256 of the secret; the secret itself is never stored
+    role TEXT NOT NULL,
+    created_by TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+
+    CONSTRAINT check_api_key_role CHECK (role IN ('User', 'Manager', 'Admin'))
+);
+
+CREATE TABLE task_dependencies (
+    task_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    depends_on_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY (task_id, depends_on_id),
+    CONSTRAINT check_no_self_dependency CHECK (task_id <> depends_on_id)
+);
+
+CREATE INDEX idx_task_dependencies_depends_on ON task_dependencies(depends_on_id);
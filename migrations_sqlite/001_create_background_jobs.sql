@@ -0,0 +1,14 @@
+-- Migration: Mirrors migrations/012_create_background_jobs.sql
+
+CREATE TABLE background_jobs (
+    name TEXT PRIMARY KEY,
+    interval_ms INTEGER NOT NULL,
+    paused INTEGER NOT NULL DEFAULT 0,
+    run_requested INTEGER NOT NULL DEFAULT 0,
+    last_started_at TEXT,
+    last_finished_at TEXT,
+    last_error TEXT,
+    next_run_at TEXT NOT NULL,
+
+    CONSTRAINT check_interval_positive CHECK (interval_ms > 0)
+);
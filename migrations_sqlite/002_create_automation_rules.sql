@@ -0,0 +1,12 @@
+-- Migration: Mirrors migrations/013_create_automation_rules.sql
+
+CREATE TABLE automation_rules (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    rule_trigger TEXT NOT NULL, -- JSON: event_type and filter
+    rule_action TEXT NOT NULL, -- JSON, tagged by type
+    created_by TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,3 @@
+-- Migration: Mirrors migrations/014_add_task_created_by.sql
+
+ALTER TABLE tasks ADD COLUMN created_by TEXT;
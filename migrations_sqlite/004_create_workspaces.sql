@@ -0,0 +1,16 @@
+-- Migration: Mirrors migrations/015_create_workspaces.sql
+
+CREATE TABLE workspaces (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    created_by TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+INSERT INTO workspaces (id, name, created_by, created_at) VALUES ('default', 'Default', 'system:migration', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'));
+
+ALTER TABLE tasks ADD COLUMN workspace_id TEXT NOT NULL DEFAULT 'default';
+ALTER TABLE status_history ADD COLUMN workspace_id TEXT NOT NULL DEFAULT 'default';
+
+CREATE INDEX idx_tasks_workspace_id ON tasks(workspace_id);
+CREATE INDEX idx_status_history_workspace_id ON status_history(workspace_id, changed_at);
@@ -0,0 +1,3 @@
+-- Migration: Mirrors migrations/016_add_task_archival.sql
+
+ALTER TABLE tasks ADD COLUMN archived_at TEXT;
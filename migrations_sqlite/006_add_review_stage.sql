@@ -0,0 +1,3 @@
+-- Migration: Mirrors migrations/017_add_review_stage.sql
+
+ALTER TABLE status_history ADD COLUMN review_stage TEXT;
@@ -0,0 +1,14 @@
+-- Migration: Mirrors migrations/018_create_task_attachments.sql
+
+CREATE TABLE task_attachments (
+    id TEXT PRIMARY KEY,
+    task_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    storage_key TEXT NOT NULL,
+    uploaded_by TEXT NOT NULL,
+    uploaded_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_task_attachments_task_id ON task_attachments(task_id, uploaded_at);
@@ -0,0 +1,8 @@
+-- Migration: Mirrors migrations/019_create_workspace_workflows.sql
+
+CREATE TABLE workspace_workflows (
+    workspace_id TEXT PRIMARY KEY REFERENCES workspaces(id) ON DELETE CASCADE,
+    definition TEXT NOT NULL, -- JSON: states and guarded transitions
+    updated_by TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
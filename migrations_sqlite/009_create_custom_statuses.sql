@@ -0,0 +1,111 @@
+-- Migration: Mirrors migrations/020_create_custom_statuses.sql
+-- SQLite cannot drop a CHECK constraint, so tasks and status_history are rebuilt without the
+-- built-in status checks. Migrations run in a transaction, where foreign keys cannot be turned
+-- off, so the tables referencing tasks are set aside first; otherwise dropping the old tasks
+-- table would cascade into them.
+
+CREATE TABLE statuses (
+    name TEXT PRIMARY KEY,
+    category TEXT NOT NULL,
+    created_by TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+
+    CONSTRAINT check_status_category CHECK (category IN ('open', 'in_progress', 'done'))
+);
+
+CREATE TABLE status_history_backup AS SELECT * FROM status_history;
+CREATE TABLE task_dependencies_backup AS SELECT * FROM task_dependencies;
+CREATE TABLE task_attachments_backup AS SELECT * FROM task_attachments;
+DROP TABLE status_history;
+DROP TABLE task_dependencies;
+DROP TABLE task_attachments;
+
+CREATE TABLE tasks_rebuilt (
+    task_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    priority INTEGER,
+    description TEXT,
+    status TEXT NOT NULL DEFAULT 'Pending',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    deleted_at TEXT,
+    created_by TEXT,
+    workspace_id TEXT NOT NULL DEFAULT 'default',
+    archived_at TEXT,
+
+    CONSTRAINT check_description_length CHECK (length(description) <= 10000)
+);
+
+INSERT INTO tasks_rebuilt (task_id, name, priority, description, status, created_at, updated_at, deleted_at, created_by, workspace_id, archived_at)
+SELECT task_id, name, priority, description, status, created_at, updated_at, deleted_at, created_by, workspace_id, archived_at FROM tasks;
+
+-- Ids of tasks deleted for good are not handed out again
+UPDATE sqlite_sequence SET seq = (SELECT seq FROM sqlite_sequence WHERE name = 'tasks') WHERE name = 'tasks_rebuilt';
+INSERT INTO sqlite_sequence (name, seq)
+SELECT 'tasks_rebuilt', seq FROM sqlite_sequence WHERE name = 'tasks'
+AND NOT EXISTS (SELECT 1 FROM sqlite_sequence WHERE name = 'tasks_rebuilt');
+
+DROP TABLE tasks;
+ALTER TABLE tasks_rebuilt RENAME TO tasks;
+
+CREATE INDEX idx_tasks_status ON tasks(status);
+CREATE INDEX idx_tasks_created_at ON tasks(created_at);
+CREATE INDEX idx_tasks_workspace_id ON tasks(workspace_id);
+
+CREATE TABLE status_history (
+    id TEXT PRIMARY KEY,
+    task_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    from_status TEXT,
+    to_status TEXT NOT NULL,
+    changed_at TEXT NOT NULL,
+    changed_by TEXT,
+    comment TEXT,
+    user_role TEXT NOT NULL DEFAULT 'User',
+    checklist TEXT, -- JSON array of checklist items
+    workspace_id TEXT NOT NULL DEFAULT 'default',
+    review_stage TEXT,
+
+    CONSTRAINT check_user_role CHECK (user_role IN ('User', 'Manager', 'Admin', 'System'))
+);
+
+INSERT INTO status_history (id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role, checklist, workspace_id, review_stage)
+SELECT id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role, checklist, workspace_id, review_stage FROM status_history_backup;
+
+CREATE INDEX idx_status_history_task_id ON status_history(task_id);
+CREATE INDEX idx_status_history_changed_at ON status_history(changed_at);
+CREATE INDEX idx_status_history_to_status ON status_history(to_status);
+CREATE INDEX idx_status_history_workspace_id ON status_history(workspace_id, changed_at);
+
+CREATE TABLE task_dependencies (
+    task_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    depends_on_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+
+    PRIMARY KEY (task_id, depends_on_id),
+    CONSTRAINT check_no_self_dependency CHECK (task_id <> depends_on_id)
+);
+
+INSERT INTO task_dependencies (task_id, depends_on_id, created_at)
+SELECT task_id, depends_on_id, created_at FROM task_dependencies_backup;
+
+CREATE INDEX idx_task_dependencies_depends_on ON task_dependencies(depends_on_id);
+
+CREATE TABLE task_attachments (
+    id TEXT PRIMARY KEY,
+    task_id INTEGER NOT NULL REFERENCES tasks(task_id) ON DELETE CASCADE,
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    storage_key TEXT NOT NULL,
+    uploaded_by TEXT NOT NULL,
+    uploaded_at TEXT NOT NULL
+);
+
+INSERT INTO task_attachments (id, task_id, file_name, content_type, size_bytes, storage_key, uploaded_by, uploaded_at)
+SELECT id, task_id, file_name, content_type, size_bytes, storage_key, uploaded_by, uploaded_at FROM task_attachments_backup;
+
+CREATE INDEX idx_task_attachments_task_id ON task_attachments(task_id, uploaded_at);
+
+DROP TABLE status_history_backup;
+DROP TABLE task_dependencies_backup;
+DROP TABLE task_attachments_backup;
@@ -0,0 +1,3 @@
+-- Migration: Mirrors migrations/021_add_task_rank.sql
+
+ALTER TABLE tasks ADD COLUMN rank TEXT;
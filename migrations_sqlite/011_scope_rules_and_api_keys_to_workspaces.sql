@@ -0,0 +1,6 @@
+-- Migration: Mirrors migrations/022_scope_rules_and_api_keys_to_workspaces.sql
+
+ALTER TABLE automation_rules ADD COLUMN workspace_id TEXT NOT NULL DEFAULT 'default';
+ALTER TABLE api_keys ADD COLUMN workspace_id TEXT NOT NULL DEFAULT 'default';
+
+CREATE INDEX idx_automation_rules_workspace_id ON automation_rules(workspace_id, created_at);
@@ -0,0 +1,3 @@
+pub mod request_context;
+
+pub use request_context::*;
@@ -0,0 +1,97 @@
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+use crate::domain::{SystemActor, UserRole, DEFAULT_WORKSPACE_ID};
+
+/// Locale assumed when the client does not ask for one
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Who is making a request and how to trace it, built once per request by the web layer
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    pub user_id: String,
+    pub role: UserRole,
+    pub tenant: Option<String>,
+    /// Set for callers let in by an API key, which never act beyond the key's workspace
+    pub api_key: bool,
+    pub correlation_id: String,
+    pub locale: String,
+    /// Cancelled when the client goes away or the server shuts down; long reports stop early
+    pub cancellation: CancellationToken,
+}
+
+impl RequestContext {
+    /// An unidentified caller with the plain User role and a fresh correlation id
+    pub fn anonymous() -> Self {
+        Self {
+            user_id: "anonymous".to_string(),
+            role: UserRole::User,
+            tenant: None,
+            api_key: false,
+            correlation_id: Uuid::new_v4().to_string(),
+            locale: DEFAULT_LOCALE.to_string(),
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// A background job acting on its own authority
+    pub fn system(actor: &SystemActor) -> Self {
+        Self {
+            user_id: actor.changed_by(),
+            role: UserRole::System,
+            ..Self::anonymous()
+        }
+    }
+
+    pub fn with_user(mut self, user_id: impl Into<String>, role: UserRole) -> Self {
+        self.user_id = user_id.into();
+        self.role = role;
+        self
+    }
+
+    pub fn with_tenant(mut self, tenant: Option<String>) -> Self {
+        self.tenant = tenant;
+        self
+    }
+
+    /// The workspace the request works in: its tenant's, or the default one without a tenant
+    pub fn workspace(&self) -> &str {
+        self.tenant.as_deref().unwrap_or(DEFAULT_WORKSPACE_ID)
+    }
+
+    pub fn with_api_key(mut self) -> Self {
+        self.api_key = true;
+        self
+    }
+
+    /// Admins working without a tenant run the deployment: they manage every workspace. An API
+    /// key always has a tenant, its own workspace, so it is never one of them.
+    pub fn administers_deployment(&self) -> bool {
+        self.role.can_manage_users() && self.tenant.is_none() && !self.api_key
+    }
+
+    /// Whether an admin may manage workspace `id`: their own, or any for deployment admins
+    pub fn administers_workspace(&self, id: &str) -> bool {
+        self.role.can_manage_users() && (self.administers_deployment() || self.workspace() == id)
+    }
+
+    pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        self.correlation_id = correlation_id.into();
+        self
+    }
+
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+}
+
+impl Default for RequestContext {
+    fn default() -> Self {
+        Self::anonymous()
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::ApiKey;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateApiKeyRequest {
+    pub name: String,
+    /// Role granted to callers using the key; defaults to User
+    pub role: Option<String>,
+}
+
+/// A newly issued key; `key` is only ever returned here
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyCreatedDto {
+    pub id: String,
+    pub name: String,
+    pub role: String,
+    pub workspace_id: String,
+    pub key: String,
+    pub key_prefix: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKeyCreatedDto {
+    pub fn new(api_key: ApiKey, secret: String) -> Self {
+        Self {
+            id: api_key.id,
+            name: api_key.name,
+            role: api_key.role.as_str().to_string(),
+            workspace_id: api_key.workspace_id,
+            key: secret,
+            key_prefix: api_key.key_prefix,
+            created_at: api_key.created_at,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A file read from an upload form
+#[derive(Debug, Clone)]
+pub struct UploadAttachmentRequest {
+    /// The name the client gave the file, possibly with its client-side path
+    pub file_name: String,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentDto {
+    pub id: String,
+    pub task_id: i32,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub uploaded_by: String,
+    pub uploaded_at: DateTime<Utc>,
+    /// Downloads the file straight from storage, without credentials, until `download_url_expires_at`
+    pub download_url: String,
+    pub download_url_expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// One month of status history moved from the database to the archive
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchivedMonthDto {
+    /// The month, as `YYYY-MM`
+    pub month: String,
+    /// Object key the month was written to
+    pub key: String,
+    /// Entries in the archive object, including any archived by earlier runs
+    pub archived: usize,
+    /// Entries deleted from the database by this run
+    pub deleted: usize,
+}
+
+/// Outcome of copying an archived month back into the database
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRestoreDto {
+    pub month: String,
+    pub restored: usize,
+    /// Entries the database still held, left as they were
+    pub already_present: usize,
+    /// Entries of tasks that no longer exist, which cannot be restored
+    pub missing_task: usize,
+}
@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::{AutomationRule, RuleAction, RuleTrigger};
+
+/// A rule definition, as created or replaced through the API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutomationRuleRequest {
+    pub name: String,
+    /// Defaults to true
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+    pub trigger: RuleTrigger,
+    pub action: RuleAction,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutomationRuleDto {
+    pub id: String,
+    pub workspace_id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub trigger: RuleTrigger,
+    pub action: RuleAction,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<AutomationRule> for AutomationRuleDto {
+    fn from(rule: AutomationRule) -> Self {
+        Self {
+            id: rule.id,
+            workspace_id: rule.workspace_id,
+            name: rule.name,
+            enabled: rule.enabled,
+            trigger: rule.trigger,
+            action: rule.action,
+            created_by: rule.created_by,
+            created_at: rule.created_at,
+            updated_at: rule.updated_at,
+        }
+    }
+}
+
+/// How one matching rule's action went
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleOutcome {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub error: Option<String>,
+}
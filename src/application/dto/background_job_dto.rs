@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::BackgroundJob;
+
+/// A background job as shown to operators
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackgroundJobDto {
+    pub name: String,
+    pub interval_secs: u64,
+    pub paused: bool,
+    pub running: bool,
+    pub run_requested: bool,
+    pub last_started_at: Option<DateTime<Utc>>,
+    pub last_finished_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    /// When the job runs next; `None` while paused with no run requested
+    pub next_run_at: Option<DateTime<Utc>>,
+}
+
+impl From<BackgroundJob> for BackgroundJobDto {
+    fn from(job: BackgroundJob) -> Self {
+        let running = job.is_running();
+        let next_run_at = match (job.run_requested, job.paused) {
+            (true, _) => Some(job.next_run_at.min(Utc::now())),
+            (false, true) => None,
+            (false, false) => Some(job.next_run_at),
+        };
+        Self {
+            name: job.name,
+            interval_secs: job.interval.as_secs(),
+            paused: job.paused,
+            running,
+            run_requested: job.run_requested,
+            last_started_at: job.last_started_at,
+            last_finished_at: job.last_finished_at,
+            last_error: job.last_error,
+            next_run_at,
+        }
+    }
+}
@@ -1,3 +1,19 @@
 pub mod task_dto;
+pub mod api_key_dto;
+pub mod attachment_dto;
+pub mod audit_archive_dto;
+pub mod automation_rule_dto;
+pub mod background_job_dto;
+pub mod task_clone_dto;
+pub mod workspace_dto;
+pub mod validation;
 
-pub use task_dto::*;
\ No newline at end of file
+pub use task_dto::*;
+pub use api_key_dto::*;
+pub use attachment_dto::*;
+pub use audit_archive_dto::*;
+pub use automation_rule_dto::*;
+pub use background_job_dto::*;
+pub use task_clone_dto::*;
+pub use workspace_dto::*;
+pub use validation::*;
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneTasksRequest {
+    pub source_workspace: String,
+    pub target_workspace: String,
+    /// A `?filter=` expression choosing which active tasks to copy; every active task without one
+    #[serde(default)]
+    pub filter: Option<String>,
+    /// Who copies of each creator's tasks are credited to; copies of tasks by creators left out
+    /// are credited to the admin who started the clone
+    #[serde(default)]
+    pub user_mapping: Vec<UserMappingEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserMappingEntry {
+    /// Creator of tasks in the source workspace
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CloneState {
+    Running,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClonedTaskDto {
+    pub source_id: i32,
+    pub task_id: i32,
+}
+
+/// A clone run and how far it has got
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskCloneDto {
+    pub id: String,
+    pub source_workspace: String,
+    pub target_workspace: String,
+    pub filter: Option<String>,
+    pub state: CloneState,
+    /// How many tasks matched, once the source workspace has been read
+    pub total: Option<usize>,
+    pub copied: usize,
+    pub cloned: Vec<ClonedTaskDto>,
+    pub error: Option<String>,
+    pub started_by: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
@@ -1,39 +1,386 @@
-use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
-use crate::domain::{Task, TaskId, TaskStatus, StatusHistory, TaskAnalytics};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::BTreeMap;
+use crate::domain::{DomainError, Task, TaskFacets, TaskStatistics, TaskId, TaskStatus, StatusCategory, StatusHistory, TaskAnalytics, TransitionEdge, Cohort, CumulativeFlow, PriorityLabel, PriorityLabels, ChecklistItem, ReviewProgress, ActorKind, ImportSource, SkippedRow};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskDto {
     pub id: i32,
     pub name: String,
     pub priority: Option<i32>,
+    #[serde(default)]
+    pub priority_label: Option<PriorityLabel>,
+    pub description: Option<String>,
     pub status: TaskStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+}
+
+/// A priority given either as a number or as a label such as `"High"`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum PriorityInput {
+    Value(i32),
+    Label(PriorityLabel),
+}
+
+impl<'de> Deserialize<'de> for PriorityInput {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PriorityInputVisitor;
+
+        impl Visitor<'_> for PriorityInputVisitor {
+            type Value = PriorityInput;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a priority number or one of Critical, High, Medium, Low")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                i32::try_from(value)
+                    .map(PriorityInput::Value)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                i32::try_from(value)
+                    .map(PriorityInput::Value)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                PriorityLabel::from_str(value)
+                    .map(PriorityInput::Label)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_any(PriorityInputVisitor)
+    }
+}
+
+impl PriorityInput {
+    pub fn resolve(&self, labels: &PriorityLabels) -> i32 {
+        match self {
+            PriorityInput::Value(value) => *value,
+            PriorityInput::Label(label) => labels.value_of(*label),
+        }
+    }
+}
+
+impl From<i32> for PriorityInput {
+    fn from(value: i32) -> Self {
+        PriorityInput::Value(value)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTaskRequest {
     pub name: String,
-    pub priority: Option<i32>,
+    pub priority: Option<PriorityInput>,
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateTaskRequest {
     pub name: Option<String>,
-    pub priority: Option<i32>,
+    pub priority: Option<PriorityInput>,
+    /// A blank description clears the existing one
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateTaskStatusDto {
     pub status: TaskStatus,
     pub comment: Option<String>,
+    /// Required when approving a task if a review checklist is configured
+    #[serde(default)]
+    pub checklist: Option<Vec<ChecklistItem>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskWithTransitionsDto {
     pub task: TaskDto,
     pub valid_transitions: Vec<TaskStatus>,
+    /// Unfinished tasks that keep this one from starting or completing
+    pub blocked_by: Vec<i32>,
+    /// The task's history could not be read, so the reopening window was judged from its last update
+    /// and a review was assumed to be at its first stage
+    #[serde(default)]
+    pub history_unavailable: bool,
+    /// Where a task in PendingReview stands in its review chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review: Option<ReviewProgressDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewProgressDto {
+    /// The stage waiting for a sign-off
+    pub stage: String,
+    /// The least senior role that may sign it off
+    pub required_role: String,
+    /// 1-based position of the stage in the chain
+    pub stage_number: usize,
+    pub stage_count: usize,
+    /// Reviewers who signed off the earlier stages, in order
+    pub signed_off_by: Vec<String>,
+}
+
+impl From<&ReviewProgress<'_>> for ReviewProgressDto {
+    fn from(progress: &ReviewProgress<'_>) -> Self {
+        Self {
+            stage: progress.current_stage().name.clone(),
+            required_role: progress.current_stage().role.as_str().to_string(),
+            stage_number: progress.stage_number(),
+            stage_count: progress.stage_count(),
+            signed_off_by: progress.signed_off_by().to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddDependencyRequest {
+    pub depends_on: i32,
+}
+
+/// Changes the priority of every task matching `status` and `priority`; give either `shift` or `mapping`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReprioritizeRequest {
+    pub status: Option<TaskStatus>,
+    pub priority: Option<i32>,
+    /// Moves every matching priority by this much, stopping at the ends of the scale
+    pub shift: Option<i32>,
+    /// Target priority per current priority, e.g. `{"3": 5}`; other priorities stay as they are
+    pub mapping: Option<BTreeMap<i32, i32>>,
+    /// Reports what would change without writing anything
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReprioritizeResultDto {
+    pub dry_run: bool,
+    /// Tasks whose priority changed, or would change on a dry run
+    pub affected: usize,
+    /// Affected tasks per priority move, in `from` order
+    pub moves: Vec<PriorityMoveDto>,
+    pub task_ids: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriorityMoveDto {
+    pub from: i32,
+    pub to: i32,
+    pub count: usize,
+}
+
+/// Places a task between two others in its board column; give either neighbour or both
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveTaskRequest {
+    /// The task to place it after; without one it goes first
+    pub after_task_id: Option<i32>,
+    /// The task to place it ahead of; without one it goes last
+    pub before_task_id: Option<i32>,
+}
+
+/// Where a task sits after being moved by hand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskPositionDto {
+    pub task_id: i32,
+    pub status: TaskStatus,
+    /// Sorts with `sort_by=rank`; compare ranks as plain strings
+    pub rank: String,
+}
+
+/// Deletes every task matching `status` and `priority`.
+///
+/// Without a `confirmation_token` nothing is deleted; the response previews the deletion and
+/// carries the token that confirms it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkDeleteRequest {
+    pub status: Option<TaskStatus>,
+    pub priority: Option<i32>,
+    pub confirmation_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkDeleteResultDto {
+    /// False for a preview
+    pub deleted: bool,
+    /// Tasks deleted, or that the token would delete
+    pub count: usize,
+    /// Names of the first few of those tasks
+    pub sample_names: Vec<String>,
+    /// Echo this back to delete the previewed tasks; absent once deleted
+    pub confirmation_token: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusFacetDto {
+    pub status: TaskStatus,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriorityFacetDto {
+    /// Absent for the tasks that have no priority
+    pub priority: Option<i32>,
+    pub priority_label: Option<PriorityLabel>,
+    pub count: usize,
+}
+
+/// Counts of the tasks matching a filter, for facet sidebars next to a task list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskFacetsDto {
+    pub total: usize,
+    pub statuses: Vec<StatusFacetDto>,
+    pub priorities: Vec<PriorityFacetDto>,
+}
+
+impl TaskFacetsDto {
+    pub fn from_facets(facets: TaskFacets, labels: &PriorityLabels) -> Self {
+        Self {
+            total: facets.total,
+            statuses: facets.statuses
+                .into_iter()
+                .map(|facet| StatusFacetDto { status: facet.value, count: facet.count })
+                .collect(),
+            priorities: facets.priorities
+                .into_iter()
+                .map(|facet| PriorityFacetDto {
+                    priority: facet.value,
+                    priority_label: facet.value.map(|priority| labels.label_for(priority)),
+                    count: facet.count,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Tasks created and completed on one UTC day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyThroughputDto {
+    pub date: NaiveDate,
+    pub created: usize,
+    pub completed: usize,
+}
+
+/// Dashboard summary of the workspace's tasks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskStatisticsDto {
+    pub total: usize,
+    pub statuses: Vec<StatusFacetDto>,
+    pub priorities: Vec<PriorityFacetDto>,
+    /// Pending tasks the stale task policy would cancel; 0 when the policy is off
+    pub overdue: usize,
+    /// Oldest day first, days without activity included
+    pub days: Vec<DailyThroughputDto>,
+}
+
+impl TaskStatisticsDto {
+    pub fn from_statistics(statistics: TaskStatistics, labels: &PriorityLabels) -> Self {
+        let facets = TaskFacetsDto::from_facets(statistics.facets, labels);
+        Self {
+            total: facets.total,
+            statuses: facets.statuses,
+            priorities: facets.priorities,
+            overdue: statistics.overdue,
+            days: statistics.daily
+                .into_iter()
+                .map(|day| DailyThroughputDto { date: day.day, created: day.created, completed: day.completed })
+                .collect(),
+        }
+    }
+}
+
+/// One status's column on the board
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardColumnDto {
+    pub status: TaskStatus,
+    /// Absent for a custom status whose definition was removed
+    pub category: Option<StatusCategory>,
+    /// Every task in the column, including those beyond `tasks`
+    pub count: usize,
+    /// The workspace's work-in-progress limit for the status, if it set one
+    pub wip_limit: Option<usize>,
+    /// Whether `count` exceeds `wip_limit`
+    pub over_limit: bool,
+    pub tasks: Vec<TaskDto>,
+}
+
+/// Tasks grouped by status, in the order of the workspace's workflow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardDto {
+    pub columns: Vec<BoardColumnDto>,
+}
+
+/// Creates tasks from another tracker's export file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportTasksRequest {
+    pub source: ImportSource,
+    /// The export file's contents: board JSON for Trello, CSV for Jira
+    pub data: String,
+    /// List or status names to map in addition to, or instead of, the defaults
+    #[serde(default)]
+    pub status_mapping: Vec<StatusMappingEntry>,
+    /// Reports what would be imported without writing anything
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusMappingEntry {
+    /// List or status name in the source tool, matched ignoring case
+    pub from: String,
+    pub to: TaskStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSummaryDto {
+    pub source: ImportSource,
+    pub dry_run: bool,
+    /// Cards or issues in the export, imported or not
+    pub total_rows: usize,
+    pub imported: usize,
+    /// Imported tasks per status, in status order
+    pub statuses: Vec<ImportedStatusDto>,
+    pub task_ids: Vec<i32>,
+    pub skipped: Vec<SkippedRowDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedStatusDto {
+    pub status: TaskStatus,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedRowDto {
+    pub row: usize,
+    pub external_id: Option<String>,
+    pub reason: String,
+}
+
+impl From<SkippedRow> for SkippedRowDto {
+    fn from(row: SkippedRow) -> Self {
+        Self { row: row.row, external_id: row.external_id, reason: row.reason }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDependenciesDto {
+    pub task_id: i32,
+    /// Every task this one depends on
+    pub depends_on: Vec<i32>,
+    /// The dependencies that are not finished yet
+    pub blocked_by: Vec<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,16 +393,44 @@ pub struct StatusHistoryDto {
     pub changed_by: String,
     pub comment: Option<String>,
     pub user_role: String,
+    /// Tells changes made by people apart from those made by background jobs
+    pub actor: ActorKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checklist: Option<Vec<ChecklistItem>>,
+    /// Review stage this entry signed off
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_stage: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskHistoryDto {
     pub task_id: i32,
+    /// The requested page of entries matching the filters
     pub history: Vec<StatusHistoryDto>,
+    /// Entries matching the filters across all pages
+    pub total_entries: usize,
+    pub limit: u32,
+    pub offset: u32,
+    /// Pass as `cursor` for the next page; `None` on the last page
+    #[serde(default)]
+    pub next_cursor: Option<String>,
     pub total_time_in_progress: Option<String>, // Duration as human-readable string
     pub number_of_transitions: usize,
 }
 
+/// A page of status history entries across tasks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryListDto {
+    pub history: Vec<StatusHistoryDto>,
+    /// Entries matching the filters across all pages
+    pub total_entries: usize,
+    pub limit: u32,
+    pub offset: u32,
+    /// Pass as `cursor` for the next page; `None` on the last page
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskAnalyticsDto {
     pub task_id: i32,
@@ -66,6 +441,10 @@ pub struct TaskAnalyticsDto {
     pub approval_time: Option<String>,
     pub created_at: DateTime<Utc>,
     pub completed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub times_reopened: usize,
+    #[serde(default)]
+    pub rework_time: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -78,28 +457,237 @@ pub struct CompletionAnalyticsDto {
     pub approval_rate: f64,
 }
 
+/// What happened to a task in a [`TaskChangeDto`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskChangeKind {
+    Created,
+    Updated,
+    StatusChanged,
+    Deleted,
+    Restored,
+    Archived,
+}
+
+/// A change to a task, pushed to realtime subscribers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskChangeDto {
+    pub kind: TaskChangeKind,
+    pub task_id: i32,
+    /// The task after the change; absent for deletions
+    pub task: Option<TaskDto>,
+    /// Fields the change touched, such as `status` or `priority`; empty for creations, deletions and restores
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed_fields: Vec<String>,
+    pub changed_by: String,
+    /// The task's workspace; subscribers only receive changes to their own
+    pub workspace_id: String,
+    pub occurred_at: DateTime<Utc>,
+    /// How many changes were merged into this one by realtime batching; absent for a single change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coalesced: Option<usize>,
+}
+
+/// Point-in-time workload gauges for metrics exporters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsGaugesDto {
+    /// Tasks not yet completed or cancelled
+    pub open_tasks: usize,
+    pub open_tasks_by_status: BTreeMap<String, usize>,
+    /// Pending tasks past the stale task policy's age; always 0 when the policy is off
+    pub stale_tasks: usize,
+    /// Tasks waiting in PendingReview for an approver
+    pub approval_backlog: usize,
+}
+
+/// How tasks moved between statuses over a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitionMatrixDto {
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub total_transitions: usize,
+    /// Transition counts keyed by from status, then to status
+    pub matrix: BTreeMap<String, BTreeMap<String, usize>>,
+    pub edges: Vec<TransitionEdgeDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitionEdgeDto {
+    pub from_status: TaskStatus,
+    pub to_status: TaskStatus,
+    pub count: usize,
+    pub median_dwell_time: Option<String>,
+    pub median_dwell_seconds: Option<i64>,
+}
+
+impl TransitionMatrixDto {
+    pub fn new(period_start: DateTime<Utc>, period_end: DateTime<Utc>, edges: Vec<TransitionEdge>) -> Self {
+        let mut matrix: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+        for edge in &edges {
+            matrix
+                .entry(edge.from_status.as_str().to_string())
+                .or_default()
+                .insert(edge.to_status.as_str().to_string(), edge.count);
+        }
+
+        Self {
+            period_start,
+            period_end,
+            total_transitions: edges.iter().map(|edge| edge.count).sum(),
+            matrix,
+            edges: edges.into_iter().map(TransitionEdgeDto::from).collect(),
+        }
+    }
+}
+
+impl From<TransitionEdge> for TransitionEdgeDto {
+    fn from(edge: TransitionEdge) -> Self {
+        Self {
+            from_status: edge.from_status,
+            to_status: edge.to_status,
+            count: edge.count,
+            median_dwell_time: edge.median_dwell_time.map(format_duration),
+            median_dwell_seconds: edge.median_dwell_time.map(|dwell| dwell.num_seconds()),
+        }
+    }
+}
+
+/// Completion of tasks grouped by when they were created
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CohortAnalysisDto {
+    pub bucket: String,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub cohorts: Vec<CohortDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CohortDto {
+    pub cohort_start: DateTime<Utc>,
+    pub tasks_created: usize,
+    /// One entry per bucket elapsed since the cohort's bucket, up to the current one
+    pub completion: Vec<CohortPeriodDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CohortPeriodDto {
+    pub periods_since_creation: usize,
+    /// Tasks completed by the end of this bucket
+    pub completed: usize,
+    pub completion_percentage: f64,
+}
+
+impl CohortDto {
+    /// Reports the cohort's cumulative completion for each of `periods` buckets
+    pub fn new(cohort: &Cohort, periods: usize) -> Self {
+        let completion = cohort
+            .cumulative_completions(periods)
+            .into_iter()
+            .enumerate()
+            .map(|(period, completed)| CohortPeriodDto {
+                periods_since_creation: period,
+                completed,
+                completion_percentage: if cohort.tasks_created > 0 {
+                    (completed as f64 * 1000.0 / cohort.tasks_created as f64).round() / 10.0
+                } else {
+                    0.0
+                },
+            })
+            .collect();
+
+        Self {
+            cohort_start: cohort.start,
+            tasks_created: cohort.tasks_created,
+            completion,
+        }
+    }
+}
+
+/// Tasks per status at the close of each bucket, for cumulative flow and burndown charts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CumulativeFlowDto {
+    pub bucket: String,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    /// Statuses any bucket holds tasks in, in lifecycle order, one chart series each
+    pub statuses: Vec<TaskStatus>,
+    /// Oldest bucket first, buckets without tasks included
+    pub buckets: Vec<FlowBucketDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlowBucketDto {
+    pub bucket_start: DateTime<Utc>,
+    pub total: usize,
+    /// One entry per status of the report, in the same order, zero counts included
+    pub statuses: Vec<StatusFacetDto>,
+}
+
+impl CumulativeFlowDto {
+    pub fn new(period_start: DateTime<Utc>, period_end: DateTime<Utc>, flow: CumulativeFlow) -> Self {
+        Self {
+            bucket: flow.bucket.as_str().to_string(),
+            period_start,
+            period_end,
+            statuses: flow.statuses,
+            buckets: flow.buckets
+                .into_iter()
+                .map(|bucket| FlowBucketDto {
+                    bucket_start: bucket.start,
+                    total: bucket.total(),
+                    statuses: bucket.statuses
+                        .into_iter()
+                        .map(|facet| StatusFacetDto { status: facet.value, count: facet.count })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriorityCompletionDto {
     pub priority: i32,
+    /// Average completion time of the tasks counted in `task_count`
     pub average_time: String,
+    /// Median completion time of the same tasks; like the other percentiles, `None` when there
+    /// are no samples to take it from
+    pub p50_time: Option<String>,
+    pub p85_time: Option<String>,
+    pub p95_time: Option<String>,
+    /// Tasks of this priority first completed in the period
     pub task_count: usize,
 }
 
-impl From<Task> for TaskDto {
-    fn from(task: Task) -> Self {
+impl TaskDto {
+    /// Labels the priority using the workspace's label mapping.
+    ///
+    /// Takes the task by value so its strings move into the DTO; list endpoints never clone them.
+    pub fn from_task(task: Task, labels: &PriorityLabels) -> Self {
         Self {
             id: task.id.value(),
             name: task.name,
             priority: task.priority,
+            priority_label: task.priority.map(|priority| labels.label_for(priority)),
+            description: task.description,
             status: task.status,
             created_at: task.created_at,
             updated_at: task.updated_at,
+            deleted_at: task.deleted_at,
+            archived_at: task.archived_at,
+            created_by: task.created_by,
         }
     }
 }
 
+impl From<Task> for TaskDto {
+    fn from(task: Task) -> Self {
+        Self::from_task(task, &PriorityLabels::default())
+    }
+}
+
 impl TryFrom<TaskDto> for Task {
-    type Error = String;
+    type Error = DomainError;
 
     fn try_from(dto: TaskDto) -> Result<Self, Self::Error> {
         Task::new_with_status(
@@ -109,7 +697,8 @@ impl TryFrom<TaskDto> for Task {
             dto.status, 
             dto.created_at, 
             dto.updated_at
-        )
+        )?.with_description(dto.description)
+            .map(|task| task.with_deleted_at(dto.deleted_at).with_created_by(dto.created_by))
     }
 }
 
@@ -124,6 +713,9 @@ impl From<StatusHistory> for StatusHistoryDto {
             changed_by: history.changed_by,
             comment: history.comment,
             user_role: history.user_role.as_str().to_string(),
+            actor: ActorKind::of(&history.user_role),
+            checklist: history.checklist,
+            review_stage: history.review_stage,
         }
     }
 }
@@ -132,13 +724,15 @@ impl From<TaskAnalytics> for TaskAnalyticsDto {
     fn from(analytics: TaskAnalytics) -> Self {
         Self {
             task_id: analytics.task_id,
-            total_time_in_progress: analytics.total_time_in_progress.map(|d| format_duration(d)),
-            time_to_completion: analytics.time_to_completion.map(|d| format_duration(d)),
+            total_time_in_progress: analytics.total_time_in_progress.map(format_duration),
+            time_to_completion: analytics.time_to_completion.map(format_duration),
             number_of_transitions: analytics.number_of_transitions,
             was_approved: analytics.was_approved,
-            approval_time: analytics.approval_time.map(|d| format_duration(d)),
+            approval_time: analytics.approval_time.map(format_duration),
             created_at: analytics.created_at,
             completed_at: analytics.completed_at,
+            times_reopened: analytics.times_reopened,
+            rework_time: analytics.rework_time.map(format_duration),
         }
     }
 }
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use crate::application::dto::{CreateTaskRequest, UpdateTaskRequest};
+use crate::domain::{DomainError, TaskDomainService};
+
+/// One rule a request field broke, for UIs to show next to that field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    /// The broken rule's code, e.g. `name_too_long`
+    pub code: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, error: DomainError) -> Self {
+        Self {
+            field: field.to_string(),
+            code: error.code().to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// The field errors' messages joined into one line, for clients that only read `message`
+pub fn field_messages(errors: &[FieldError]) -> String {
+    errors.iter().map(|error| error.message.as_str()).collect::<Vec<_>>().join("; ")
+}
+
+/// Checks every field of a request body, reporting all broken rules instead of the first
+pub trait Validate {
+    fn field_errors(&self, rules: &TaskDomainService) -> Vec<FieldError>;
+}
+
+impl Validate for CreateTaskRequest {
+    fn field_errors(&self, rules: &TaskDomainService) -> Vec<FieldError> {
+        let priority = self.priority.map(|priority| priority.resolve(&rules.priority_labels()));
+        [
+            ("name", rules.validate_task_name(&self.name)),
+            ("priority", rules.validate_priority(priority)),
+            ("description", rules.validate_description(self.description.as_deref())),
+        ]
+        .into_iter()
+        .filter_map(|(field, result)| result.err().map(|error| FieldError::new(field, error)))
+        .collect()
+    }
+}
+
+impl Validate for UpdateTaskRequest {
+    fn field_errors(&self, rules: &TaskDomainService) -> Vec<FieldError> {
+        let priority = self.priority.map(|priority| priority.resolve(&rules.priority_labels()));
+        [
+            ("name", self.name.as_deref().map_or(Ok(()), |name| rules.validate_task_name(name))),
+            ("priority", rules.validate_priority(priority)),
+            ("description", rules.validate_description(self.description.as_deref())),
+        ]
+        .into_iter()
+        .filter_map(|(field, result)| result.err().map(|error| FieldError::new(field, error)))
+        .collect()
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::{CustomStatus, StatusCategory, TaskStatus, WorkflowDefinition, Workspace};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWorkspaceRequest {
+    /// The tenant callers send to work in the workspace; lowercase letters, digits and hyphens
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWorkspaceRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceDto {
+    pub id: String,
+    pub name: String,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Workspace> for WorkspaceDto {
+    fn from(workspace: Workspace) -> Self {
+        Self {
+            id: workspace.id,
+            name: workspace.name,
+            created_by: workspace.created_by,
+            created_at: workspace.created_at,
+        }
+    }
+}
+
+/// Where the workflow a workspace follows comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkflowSource {
+    /// Stored for the workspace by an admin
+    Workspace,
+    /// The deployment's `WORKFLOWS` setting, or the standard workflow without one
+    Configuration,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowDto {
+    pub workspace_id: String,
+    pub source: WorkflowSource,
+    pub workflow: WorkflowDefinition,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateStatusRequest {
+    /// Up to 20 letters and digits, starting with a letter, such as `Blocked`
+    pub name: String,
+    pub category: StatusCategory,
+}
+
+/// A status tasks may be in, built-in or defined by an admin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusDto {
+    pub name: String,
+    pub category: StatusCategory,
+    pub built_in: bool,
+    /// Unset for built-in statuses
+    pub created_by: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl StatusDto {
+    /// `None` for a custom status, which has no built-in category
+    pub fn built_in(status: &TaskStatus) -> Option<Self> {
+        status.builtin_category().map(|category| Self {
+            name: status.as_str().to_string(),
+            category,
+            built_in: true,
+            created_by: None,
+            created_at: None,
+        })
+    }
+}
+
+impl From<CustomStatus> for StatusDto {
+    fn from(status: CustomStatus) -> Self {
+        Self {
+            name: status.name().to_string(),
+            category: status.category,
+            built_in: false,
+            created_by: Some(status.created_by),
+            created_at: Some(status.created_at),
+        }
+    }
+}
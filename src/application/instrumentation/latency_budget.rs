@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How often one operation ran and how many of those runs went over its budget
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BudgetStats {
+    pub calls: u64,
+    pub exceeded: u64,
+}
+
+/// Latency budgets declared by the use cases, with per-operation overrides and counters.
+///
+/// Each use case declares its budget when it starts; a call that takes longer is logged as a
+/// warning naming the operation and counted for the metrics push.
+#[derive(Debug, Default)]
+pub struct LatencyBudgets {
+    overrides: BTreeMap<String, Duration>,
+    stats: Mutex<BTreeMap<&'static str, BudgetStats>>,
+}
+
+impl LatencyBudgets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the declared budget of each named operation
+    pub fn with_overrides(mut self, overrides: BTreeMap<String, Duration>) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Parses `operation=millis` entries separated by `;`, such as `search_tasks=800;import_tasks=10000`
+    pub fn parse_overrides(value: &str) -> Result<BTreeMap<String, Duration>, String> {
+        value
+            .split(';')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let parsed = entry.split_once('=')
+                    .and_then(|(operation, millis)| Some((operation.trim(), millis.trim().parse::<u64>().ok()?)))
+                    .filter(|(operation, millis)| !operation.is_empty() && *millis > 0);
+                match parsed {
+                    Some((operation, millis)) => Ok((operation.to_string(), Duration::from_millis(millis))),
+                    None => Err(format!("LATENCY_BUDGETS_MS entries must look like operation=millis, got '{}'", entry)),
+                }
+            })
+            .collect()
+    }
+
+    /// The budget in force for `operation`: its override, or the one it declared
+    pub fn budget_for(&self, operation: &str, declared: Duration) -> Duration {
+        self.overrides.get(operation).copied().unwrap_or(declared)
+    }
+
+    /// Starts timing a call; the call is checked against its budget when the guard is dropped
+    pub fn start(&self, operation: &'static str, declared: Duration) -> BudgetGuard<'_> {
+        BudgetGuard {
+            budgets: self,
+            operation,
+            budget: self.budget_for(operation, declared),
+            started: Instant::now(),
+        }
+    }
+
+    /// Counts one call of `operation`, warning when it took longer than `budget`; true if it did
+    pub fn record(&self, operation: &'static str, elapsed: Duration, budget: Duration) -> bool {
+        let exceeded = elapsed > budget;
+        {
+            let mut stats = self.stats.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let entry = stats.entry(operation).or_default();
+            entry.calls += 1;
+            entry.exceeded += u64::from(exceeded);
+        }
+        if exceeded {
+            tracing::warn!(
+                operation,
+                elapsed_ms = elapsed.as_secs_f64() * 1000.0,
+                budget_ms = budget.as_secs_f64() * 1000.0,
+                "Use case {} took {:?}, over its {:?} budget",
+                operation,
+                elapsed,
+                budget
+            );
+        }
+        exceeded
+    }
+
+    /// Calls and budget overruns so far, by operation
+    pub fn stats(&self) -> BTreeMap<&'static str, BudgetStats> {
+        self.stats.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+}
+
+/// Times one use case call from [`LatencyBudgets::start`] until it is dropped, whether the call
+/// succeeded, failed or was abandoned
+#[must_use = "the call is timed until the guard is dropped"]
+pub struct BudgetGuard<'a> {
+    budgets: &'a LatencyBudgets,
+    operation: &'static str,
+    budget: Duration,
+    started: Instant,
+}
+
+impl Drop for BudgetGuard<'_> {
+    fn drop(&mut self) {
+        self.budgets.record(self.operation, self.started.elapsed(), self.budget);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_calls_over_budget_are_counted_as_exceeded() {
+        let budgets = LatencyBudgets::new();
+        let budget = Duration::from_millis(100);
+
+        assert!(!budgets.record("list_tasks", Duration::from_millis(100), budget));
+        assert!(budgets.record("list_tasks", Duration::from_millis(101), budget));
+        assert!(!budgets.record("search_tasks", Duration::from_millis(5), budget));
+
+        let stats = budgets.stats();
+        assert_eq!(stats["list_tasks"], BudgetStats { calls: 2, exceeded: 1 });
+        assert_eq!(stats["search_tasks"], BudgetStats { calls: 1, exceeded: 0 });
+    }
+
+    #[test]
+    fn test_overrides_replace_declared_budgets() {
+        let overrides = LatencyBudgets::parse_overrides(" search_tasks=800; import_tasks = 10000 ;").unwrap();
+        let budgets = LatencyBudgets::new().with_overrides(overrides);
+
+        assert_eq!(budgets.budget_for("search_tasks", Duration::from_millis(500)), Duration::from_millis(800));
+        assert_eq!(budgets.budget_for("list_tasks", Duration::from_millis(200)), Duration::from_millis(200));
+
+        drop(budgets.start("import_tasks", Duration::from_millis(1)));
+        assert_eq!(budgets.stats()["import_tasks"], BudgetStats { calls: 1, exceeded: 0 });
+    }
+
+    #[test]
+    fn test_malformed_overrides_are_rejected() {
+        for value in ["search_tasks", "search_tasks=fast", "=100", "search_tasks=0"] {
+            assert!(LatencyBudgets::parse_overrides(value).is_err(), "{} should be rejected", value);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod latency_budget;
+
+pub use latency_budget::*;
@@ -1,5 +1,13 @@
 pub mod use_cases;
 pub mod dto;
+pub mod context;
+pub mod policies;
+pub mod realtime;
+pub mod instrumentation;
 
 pub use use_cases::*;
-pub use dto::*;
\ No newline at end of file
+pub use dto::*;
+pub use context::*;
+pub use policies::*;
+pub use realtime::*;
+pub use instrumentation::*;
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::application::UseCaseError;
+use crate::domain::{Task, TaskRepository, TaskStatus};
+
+/// A check every new task must pass before it is saved.
+///
+/// Policies see the fully built task and may query the repository, so rules such as
+/// quotas or duplicate detection can be added without touching `create_task`.
+#[async_trait]
+pub trait CreationPolicy: Send + Sync {
+    async fn check(&self, task: &Task, tasks: &dyn TaskRepository) -> Result<(), UseCaseError>;
+}
+
+/// Runs policies in the order they were added and stops at the first rejection
+#[derive(Clone, Default)]
+pub struct CreationPolicyChain {
+    policies: Vec<Arc<dyn CreationPolicy>>,
+}
+
+impl CreationPolicyChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, policy: Arc<dyn CreationPolicy>) -> Self {
+        self.policies.push(policy);
+        self
+    }
+
+    pub async fn check(&self, task: &Task, tasks: &dyn TaskRepository) -> Result<(), UseCaseError> {
+        for policy in &self.policies {
+            policy.check(task, tasks).await?;
+        }
+        Ok(())
+    }
+}
+
+fn is_open(task: &Task) -> bool {
+    !matches!(task.status(), TaskStatus::Completed | TaskStatus::Cancelled)
+}
+
+/// Caps how many open (not completed or cancelled) tasks may exist at once
+pub struct OpenTaskQuotaPolicy {
+    max_open_tasks: usize,
+}
+
+impl OpenTaskQuotaPolicy {
+    pub fn new(max_open_tasks: usize) -> Self {
+        Self { max_open_tasks }
+    }
+}
+
+#[async_trait]
+impl CreationPolicy for OpenTaskQuotaPolicy {
+    async fn check(&self, _task: &Task, tasks: &dyn TaskRepository) -> Result<(), UseCaseError> {
+        let open = tasks.find_all().await?.iter().filter(|task| is_open(task)).count();
+        if open >= self.max_open_tasks {
+            return Err(UseCaseError::ValidationError(format!(
+                "Open task limit of {} reached",
+                self.max_open_tasks
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Rejects a task whose name matches an open task, ignoring case and surrounding whitespace
+pub struct DuplicateNamePolicy;
+
+#[async_trait]
+impl CreationPolicy for DuplicateNamePolicy {
+    async fn check(&self, task: &Task, tasks: &dyn TaskRepository) -> Result<(), UseCaseError> {
+        let name = task.name.trim().to_lowercase();
+        let duplicate = tasks.find_all().await?
+            .into_iter()
+            .find(|existing| is_open(existing) && existing.name.trim().to_lowercase() == name);
+
+        match duplicate {
+            Some(existing) => Err(UseCaseError::ValidationError(format!(
+                "An open task with this name already exists (id {})",
+                existing.id.value()
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Rejects names or descriptions containing any blocked term, ignoring case
+pub struct ContentPolicy {
+    blocked_terms: Vec<String>,
+}
+
+impl ContentPolicy {
+    pub fn new(blocked_terms: Vec<String>) -> Self {
+        Self {
+            blocked_terms: blocked_terms
+                .into_iter()
+                .map(|term| term.trim().to_lowercase())
+                .filter(|term| !term.is_empty())
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl CreationPolicy for ContentPolicy {
+    async fn check(&self, task: &Task, _tasks: &dyn TaskRepository) -> Result<(), UseCaseError> {
+        let text = format!("{} {}", task.name, task.description.as_deref().unwrap_or_default()).to_lowercase();
+        match self.blocked_terms.iter().find(|term| text.contains(term.as_str())) {
+            Some(term) => Err(UseCaseError::ValidationError(format!(
+                "Task contains blocked term: {}",
+                term
+            ))),
+            None => Ok(()),
+        }
+    }
+}
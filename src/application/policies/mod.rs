@@ -0,0 +1,3 @@
+pub mod creation_policy;
+
+pub use creation_policy::*;
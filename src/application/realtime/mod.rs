@@ -0,0 +1,5 @@
+pub mod task_change_batcher;
+pub mod task_change_feed;
+
+pub use task_change_batcher::*;
+pub use task_change_feed::*;
@@ -0,0 +1,141 @@
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::application::dto::{TaskChangeDto, TaskChangeKind};
+
+/// Changes to one task waiting for the end of its window
+#[derive(Debug)]
+struct PendingBatch {
+    task_id: i32,
+    due: Instant,
+    changes: Vec<TaskChangeDto>,
+}
+
+/// Holds back a subscriber's task changes and merges those made to the same task within one
+/// window, so a task edited many times in quick succession costs a single update.
+///
+/// The window opens with a task's first change and is not extended by later ones, so no
+/// change waits longer than the window. Batches come out in the order their tasks first changed.
+#[derive(Debug)]
+pub struct TaskChangeBatcher {
+    window: Duration,
+    pending: Vec<PendingBatch>,
+}
+
+impl TaskChangeBatcher {
+    pub fn new(window: Duration) -> Self {
+        Self { window, pending: Vec::new() }
+    }
+
+    pub fn push(&mut self, change: TaskChangeDto, now: Instant) {
+        match self.pending.iter_mut().find(|batch| batch.task_id == change.task_id) {
+            Some(batch) => batch.changes.push(change),
+            None => self.pending.push(PendingBatch { task_id: change.task_id, due: now + self.window, changes: vec![change] }),
+        }
+    }
+
+    /// When the oldest batch is due; `None` while nothing is waiting
+    pub fn next_due(&self) -> Option<Instant> {
+        self.pending.first().map(|batch| batch.due)
+    }
+
+    /// Removes the batches due by `now`, each merged into one change
+    pub fn take_due(&mut self, now: Instant) -> Vec<TaskChangeDto> {
+        let due = self.pending.iter().take_while(|batch| batch.due <= now).count();
+        self.pending.drain(..due).map(|batch| coalesce(batch.changes)).collect()
+    }
+}
+
+/// Merges successive changes to one task into the change a subscriber would have seen overall.
+///
+/// The result carries the latest task and actor and every touched field. A batch that creates
+/// or restores the task keeps that kind unless the task ends up deleted; otherwise the last
+/// kind wins when all agree, and mixed batches read as `updated`.
+fn coalesce(mut changes: Vec<TaskChangeDto>) -> TaskChangeDto {
+    let count = changes.len();
+    let mut merged = changes.pop().expect("a batch holds at least one change");
+    if count == 1 {
+        return merged;
+    }
+
+    let first_kind = changes[0].kind;
+    let same_kind = changes.iter().all(|change| change.kind == merged.kind);
+    merged.kind = match (first_kind, merged.kind) {
+        (_, TaskChangeKind::Deleted) => TaskChangeKind::Deleted,
+        (_, TaskChangeKind::Archived) => TaskChangeKind::Archived,
+        (TaskChangeKind::Created | TaskChangeKind::Restored, _) => first_kind,
+        (_, last) if same_kind => last,
+        _ => TaskChangeKind::Updated,
+    };
+
+    let mut fields: Vec<String> = Vec::new();
+    for field in changes.into_iter().chain([merged.clone()]).flat_map(|change| change.changed_fields) {
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+    merged.changed_fields = match merged.kind {
+        TaskChangeKind::Created | TaskChangeKind::Restored | TaskChangeKind::Deleted => Vec::new(),
+        _ => fields,
+    };
+    merged.coalesced = Some(count);
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn change(task_id: i32, kind: TaskChangeKind, fields: &[&str], by: &str) -> TaskChangeDto {
+        TaskChangeDto {
+            kind,
+            task_id,
+            task: None,
+            changed_fields: fields.iter().map(|field| field.to_string()).collect(),
+            changed_by: by.to_string(),
+            workspace_id: "default".to_string(),
+            occurred_at: Utc::now(),
+            coalesced: None,
+        }
+    }
+
+    #[test]
+    fn test_changes_within_the_window_leave_as_one() {
+        let start = Instant::now();
+        let window = Duration::from_millis(100);
+        let mut batcher = TaskChangeBatcher::new(window);
+
+        batcher.push(change(1, TaskChangeKind::Updated, &["name"], "ann"), start);
+        batcher.push(change(2, TaskChangeKind::StatusChanged, &["status"], "ann"), start + Duration::from_millis(30));
+        batcher.push(change(1, TaskChangeKind::StatusChanged, &["status"], "bob"), start + Duration::from_millis(60));
+        batcher.push(change(1, TaskChangeKind::Updated, &["priority", "name"], "cid"), start + Duration::from_millis(90));
+
+        assert_eq!(batcher.next_due(), Some(start + window));
+        assert!(batcher.take_due(start + Duration::from_millis(99)).is_empty());
+
+        let first = batcher.take_due(start + window);
+        assert_eq!(first.len(), 1);
+        assert_eq!((first[0].task_id, first[0].kind, first[0].coalesced), (1, TaskChangeKind::Updated, Some(3)));
+        assert_eq!(first[0].changed_fields, vec!["name", "status", "priority"]);
+        assert_eq!(first[0].changed_by, "cid");
+
+        // A single change passes through untouched
+        let second = batcher.take_due(start + Duration::from_millis(130));
+        assert_eq!((second[0].task_id, second[0].coalesced), (2, None));
+        assert_eq!(batcher.next_due(), None);
+    }
+
+    #[test]
+    fn test_coalesce_keeps_creation_unless_the_task_is_deleted() {
+        use TaskChangeKind::*;
+        let kind_of = |kinds: &[TaskChangeKind]| coalesce(kinds.iter().map(|kind| change(1, *kind, &[], "ann")).collect()).kind;
+
+        assert_eq!(kind_of(&[Created, Updated, StatusChanged]), Created);
+        assert_eq!(kind_of(&[Restored, Updated]), Restored);
+        assert_eq!(kind_of(&[Created, Deleted]), Deleted);
+        assert_eq!(kind_of(&[StatusChanged, Archived]), Archived);
+        assert_eq!(kind_of(&[StatusChanged, StatusChanged]), StatusChanged);
+        assert_eq!(kind_of(&[StatusChanged, Updated]), Updated);
+    }
+}
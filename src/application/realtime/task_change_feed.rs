@@ -0,0 +1,41 @@
+use tokio::sync::broadcast;
+
+use crate::application::dto::TaskChangeDto;
+
+/// How many changes a slow subscriber may fall behind before it starts missing them
+pub const DEFAULT_CHANGE_FEED_CAPACITY: usize = 256;
+
+/// Fans task changes out to live subscribers such as WebSocket connections.
+///
+/// Publishing never waits: changes made while nobody listens are dropped, and a subscriber
+/// that falls more than the capacity behind skips the oldest ones.
+#[derive(Debug, Clone)]
+pub struct TaskChangeFeed {
+    sender: broadcast::Sender<TaskChangeDto>,
+}
+
+impl TaskChangeFeed {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn publish(&self, change: TaskChangeDto) {
+        let _ = self.sender.send(change);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<TaskChangeDto> {
+        self.sender.subscribe()
+    }
+
+    /// Live subscribers, one per open realtime connection
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+impl Default for TaskChangeFeed {
+    fn default() -> Self {
+        Self::new(DEFAULT_CHANGE_FEED_CAPACITY)
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use crate::domain::{ApiKey, ApiKeyRepository, Clock, IdGenerator, SystemClock, UserRole, UuidV7Generator};
+use crate::application::context::RequestContext;
+use crate::application::dto::{ApiKeyCreatedDto, CreateApiKeyRequest};
+use crate::application::use_cases::UseCaseError;
+
+pub struct ApiKeyUseCases {
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+    id_generator: Arc<dyn IdGenerator>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ApiKeyUseCases {
+    pub fn new(api_key_repository: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { api_key_repository, id_generator: Arc::new(UuidV7Generator), clock: Arc::new(SystemClock) }
+    }
+
+    /// Mints key ids with `id_generator` instead of UUIDv7
+    pub fn with_id_generator(mut self, id_generator: Arc<dyn IdGenerator>) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Issues a key bound to the workspace the admin is working in
+    pub async fn create_api_key(&self, ctx: &RequestContext, request: CreateApiKeyRequest) -> Result<ApiKeyCreatedDto, UseCaseError> {
+        if !ctx.role.can_manage_users() {
+            return Err(UseCaseError::Forbidden("Only admins can create API keys".to_string()));
+        }
+        let role = request.role
+            .as_deref()
+            .map(UserRole::from_str)
+            .transpose()
+            .map_err(UseCaseError::ValidationError)?
+            .unwrap_or_default();
+
+        let (api_key, secret) = ApiKey::issue(self.id_generator.next_id(), request.name, role, ctx.workspace().to_string(), ctx.user_id.clone(), self.clock.now())
+            .map_err(UseCaseError::ValidationError)?;
+        self.api_key_repository.save(&api_key).await?;
+        Ok(ApiKeyCreatedDto::new(api_key, secret))
+    }
+
+    /// Resolves the key a request presented. Runs before a request context exists, so takes none.
+    pub async fn authenticate(&self, secret: &str) -> Result<Option<ApiKey>, UseCaseError> {
+        Ok(self.api_key_repository.find_by_hash(&ApiKey::hash_secret(secret)).await?)
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+use std::time::Duration;
+use crate::domain::{Attachment, AttachmentRepository, AttachmentStorage, DomainError, IdGenerator, TaskId, UuidV7Generator};
+use crate::application::context::RequestContext;
+use crate::application::dto::{AttachmentDto, UploadAttachmentRequest};
+use crate::application::use_cases::{TaskUseCases, UseCaseError};
+
+/// Largest file accepted by default, in bytes
+pub const DEFAULT_MAX_ATTACHMENT_BYTES: usize = 10 * 1024 * 1024;
+
+/// How long download links stay valid by default
+pub const DEFAULT_ATTACHMENT_URL_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Uploads files to tasks and lists them with links to download them.
+///
+/// Files go to attachment storage before their metadata is saved, so a failed save can leave a
+/// file in storage that no task lists; a failed upload saves nothing.
+pub struct AttachmentUseCases {
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    storage: Arc<dyn AttachmentStorage>,
+    task_use_cases: Arc<TaskUseCases>,
+    id_generator: Arc<dyn IdGenerator>,
+    max_size_bytes: usize,
+    download_url_ttl: Duration,
+}
+
+impl AttachmentUseCases {
+    pub fn new(attachment_repository: Arc<dyn AttachmentRepository>, storage: Arc<dyn AttachmentStorage>, task_use_cases: Arc<TaskUseCases>) -> Self {
+        Self {
+            attachment_repository,
+            storage,
+            task_use_cases,
+            id_generator: Arc::new(UuidV7Generator),
+            max_size_bytes: DEFAULT_MAX_ATTACHMENT_BYTES,
+            download_url_ttl: DEFAULT_ATTACHMENT_URL_TTL,
+        }
+    }
+
+    /// Mints attachment ids with `id_generator` instead of UUIDv7
+    pub fn with_id_generator(mut self, id_generator: Arc<dyn IdGenerator>) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    pub fn with_max_size_bytes(mut self, max_size_bytes: usize) -> Self {
+        self.max_size_bytes = max_size_bytes;
+        self
+    }
+
+    pub fn with_download_url_ttl(mut self, download_url_ttl: Duration) -> Self {
+        self.download_url_ttl = download_url_ttl;
+        self
+    }
+
+    /// Largest file accepted, for callers that stop reading an upload once it is too large
+    pub fn max_size_bytes(&self) -> usize {
+        self.max_size_bytes
+    }
+
+    /// Attaches a file to a task the caller can see; archived tasks take no new files
+    pub async fn upload_attachment(&self, ctx: &RequestContext, task_id: i32, request: UploadAttachmentRequest) -> Result<AttachmentDto, UseCaseError> {
+        let task = self.task_use_cases.get_task_by_id(ctx, task_id).await?;
+        if task.archived_at.is_some() {
+            return Err(DomainError::TaskArchived.into());
+        }
+        if request.body.len() > self.max_size_bytes {
+            return Err(UseCaseError::ValidationError(format!("Attachments cannot exceed {} bytes", self.max_size_bytes)));
+        }
+
+        let attachment = Attachment::new(
+            self.id_generator.next_id(),
+            TaskId::new(task_id),
+            &request.file_name,
+            request.content_type.as_deref(),
+            request.body.len(),
+            ctx.user_id.clone(),
+            self.task_use_cases.now(),
+        ).map_err(UseCaseError::ValidationError)?;
+        self.storage.upload(&attachment.storage_key, &attachment.content_type, request.body).await
+            .map_err(|error| UseCaseError::RepositoryError(format!("Failed to store attachment: {}", error)))?;
+        self.attachment_repository.save(&attachment).await?;
+        self.to_dto(attachment)
+    }
+
+    /// The task's attachments, oldest first, each with a fresh download link
+    pub async fn list_attachments(&self, ctx: &RequestContext, task_id: i32) -> Result<Vec<AttachmentDto>, UseCaseError> {
+        self.task_use_cases.get_task_by_id(ctx, task_id).await?;
+        let attachments = self.attachment_repository.find_by_task_id(TaskId::new(task_id)).await?;
+        attachments.into_iter().map(|attachment| self.to_dto(attachment)).collect()
+    }
+
+    fn to_dto(&self, attachment: Attachment) -> Result<AttachmentDto, UseCaseError> {
+        let issued_at = self.task_use_cases.now();
+        let download_url = self.storage
+            .download_url(&attachment.storage_key, &attachment.file_name, issued_at, self.download_url_ttl)
+            .map_err(UseCaseError::RepositoryError)?;
+        Ok(AttachmentDto {
+            id: attachment.id,
+            task_id: attachment.task_id.value(),
+            file_name: attachment.file_name,
+            content_type: attachment.content_type,
+            size_bytes: attachment.size_bytes,
+            uploaded_by: attachment.uploaded_by,
+            uploaded_at: attachment.uploaded_at,
+            download_url,
+            download_url_expires_at: issued_at + chrono::Duration::from_std(self.download_url_ttl).unwrap_or_default(),
+        })
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use crate::domain::{ArchiveMonth, AuditRetentionPolicy, HistoryFilter, ObjectStorage, Pagination, RepositoryError, SortOrder, StatusHistory, StatusHistoryRepository};
+use crate::application::dto::{ArchivedMonthDto, AuditRestoreDto};
+use crate::application::use_cases::UseCaseError;
+
+/// Moves aged status history to object storage and brings it back for investigations.
+///
+/// Each month is archived as one gzipped NDJSON object, one entry per line. Run only by the
+/// retention job and the `restore-audit` command, so these use cases take no request context.
+pub struct AuditArchiveUseCases {
+    status_history_repository: Arc<dyn StatusHistoryRepository>,
+    storage: Arc<dyn ObjectStorage>,
+}
+
+impl AuditArchiveUseCases {
+    pub fn new(status_history_repository: Arc<dyn StatusHistoryRepository>, storage: Arc<dyn ObjectStorage>) -> Self {
+        Self { status_history_repository, storage }
+    }
+
+    /// Archives every month of history older than `policy` keeps, oldest first.
+    ///
+    /// A month is uploaded before any of it is deleted, so a failed run leaves the database
+    /// untouched for that month and the next run picks it up again.
+    pub async fn archive_expired(&self, policy: &AuditRetentionPolicy, now: DateTime<Utc>) -> Result<Vec<ArchivedMonthDto>, UseCaseError> {
+        let before_cutoff = HistoryFilter {
+            end_date: Some(policy.cutoff(now) - Duration::microseconds(1)),
+            ..HistoryFilter::default()
+        };
+        let oldest_first = Pagination { limit: 1, ..Pagination::default() };
+
+        let mut archived = Vec::new();
+        while let Some(oldest) = self.status_history_repository.find_matching(&before_cutoff, SortOrder::Asc, oldest_first.clone()).await?.pop() {
+            let month = self.archive_month(ArchiveMonth::containing(oldest.changed_at)).await?;
+            if month.deleted == 0 {
+                return Err(UseCaseError::RepositoryError(format!("Archiving {} deleted no status history", month.month)));
+            }
+            archived.push(month);
+        }
+        Ok(archived)
+    }
+
+    async fn archive_month(&self, month: ArchiveMonth) -> Result<ArchivedMonthDto, UseCaseError> {
+        let key = month.history_archive_key();
+        let entries = self.status_history_repository
+            .find_by_date_range(month.start(), month.end() - Duration::microseconds(1))
+            .await?;
+
+        // Entries restored for an investigation are archived again; merge by id so none are lost or doubled
+        let mut archive: BTreeMap<String, StatusHistory> = match self.storage.get(&key).await.map_err(UseCaseError::RepositoryError)? {
+            Some(object) => decode_archive(&object)?.into_iter().map(|entry| (entry.id.clone(), entry)).collect(),
+            None => BTreeMap::new(),
+        };
+        let ids: Vec<String> = entries.iter().map(|entry| entry.id.clone()).collect();
+        archive.extend(entries.into_iter().map(|entry| (entry.id.clone(), entry)));
+        let mut archive: Vec<StatusHistory> = archive.into_values().collect();
+        archive.sort_by(|a, b| a.changed_at.cmp(&b.changed_at).then_with(|| a.id.cmp(&b.id)));
+
+        self.storage.put(&key, encode_archive(&archive)?).await.map_err(UseCaseError::RepositoryError)?;
+        let deleted = self.status_history_repository.delete_many(&ids).await?;
+        Ok(ArchivedMonthDto { month: month.to_string(), key, archived: archive.len(), deleted })
+    }
+
+    /// Copies an archived month back into the database; the archive itself is kept
+    pub async fn restore_month(&self, month: ArchiveMonth) -> Result<AuditRestoreDto, UseCaseError> {
+        let object = self.storage
+            .get(&month.history_archive_key())
+            .await
+            .map_err(UseCaseError::RepositoryError)?
+            .ok_or_else(|| UseCaseError::NotFound(format!("No status history is archived for {}", month)))?;
+
+        let mut summary = AuditRestoreDto { month: month.to_string(), restored: 0, already_present: 0, missing_task: 0 };
+        for entry in decode_archive(&object)? {
+            match self.status_history_repository.save(&entry).await {
+                Ok(_) => summary.restored += 1,
+                Err(RepositoryError::ValidationError(_)) => summary.already_present += 1,
+                Err(RepositoryError::ForeignKeyViolation { .. }) => summary.missing_task += 1,
+                Err(error) => return Err(error.into()),
+            }
+        }
+        Ok(summary)
+    }
+}
+
+fn encode_archive(entries: &[StatusHistory]) -> Result<Vec<u8>, UseCaseError> {
+    let failed = |error: std::io::Error| UseCaseError::RepositoryError(format!("Could not compress the archive: {}", error));
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    for entry in entries {
+        let line = serde_json::to_vec(entry)
+            .map_err(|error| UseCaseError::RepositoryError(format!("Could not encode status history {}: {}", entry.id, error)))?;
+        encoder.write_all(&line).map_err(failed)?;
+        encoder.write_all(b"\n").map_err(failed)?;
+    }
+    encoder.finish().map_err(failed)
+}
+
+fn decode_archive(object: &[u8]) -> Result<Vec<StatusHistory>, UseCaseError> {
+    let mut ndjson = String::new();
+    GzDecoder::new(object)
+        .read_to_string(&mut ndjson)
+        .map_err(|error| UseCaseError::RepositoryError(format!("Archive is not gzipped NDJSON: {}", error)))?;
+    ndjson
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .map_err(|error| UseCaseError::RepositoryError(format!("Archive line {} is not a status history entry: {}", index + 1, error)))
+        })
+        .collect()
+}
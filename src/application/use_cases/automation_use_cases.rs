@@ -0,0 +1,163 @@
+use std::sync::Arc;
+use crate::domain::{
+    AutomationNotification, AutomationRule, AutomationRuleRepository, DomainEvent, IdGenerator, Notifier, NotifyUrlPolicy, RuleAction, SystemActor,
+    TaskEvent, TaskField, UuidV7Generator,
+};
+use crate::application::context::RequestContext;
+use crate::application::dto::{AutomationRuleDto, AutomationRuleRequest, PriorityInput, RuleOutcome, UpdateTaskRequest};
+use crate::application::use_cases::{TaskUseCases, UseCaseError};
+
+/// Manages automation rules and runs them against task events.
+///
+/// Each rule belongs to the workspace it was created in and only sees that workspace's
+/// events. Rules never fire on changes made by rules, so a rule cannot trigger itself or
+/// another rule, however they are defined.
+pub struct AutomationUseCases {
+    rule_repository: Arc<dyn AutomationRuleRepository>,
+    task_use_cases: Arc<TaskUseCases>,
+    notifier: Arc<dyn Notifier>,
+    notify_url_policy: NotifyUrlPolicy,
+    id_generator: Arc<dyn IdGenerator>,
+}
+
+impl AutomationUseCases {
+    pub fn new(rule_repository: Arc<dyn AutomationRuleRepository>, task_use_cases: Arc<TaskUseCases>, notifier: Arc<dyn Notifier>) -> Self {
+        Self { rule_repository, task_use_cases, notifier, notify_url_policy: NotifyUrlPolicy::default(), id_generator: Arc::new(UuidV7Generator) }
+    }
+
+    /// Checks the URLs of `notify` rules against `policy` instead of only refusing internal hosts
+    pub fn with_notify_url_policy(mut self, policy: NotifyUrlPolicy) -> Self {
+        self.notify_url_policy = policy;
+        self
+    }
+
+    /// Mints rule ids with `id_generator` instead of UUIDv7
+    pub fn with_id_generator(mut self, id_generator: Arc<dyn IdGenerator>) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    pub async fn list_rules(&self, ctx: &RequestContext) -> Result<Vec<AutomationRuleDto>, UseCaseError> {
+        Self::authorize(ctx)?;
+        let rules = self.rule_repository.find_all().await?;
+        Ok(rules.into_iter().map(AutomationRuleDto::from).collect())
+    }
+
+    pub async fn get_rule(&self, ctx: &RequestContext, id: &str) -> Result<AutomationRuleDto, UseCaseError> {
+        Self::authorize(ctx)?;
+        Ok(self.find_rule(id).await?.into())
+    }
+
+    pub async fn create_rule(&self, ctx: &RequestContext, request: AutomationRuleRequest) -> Result<AutomationRuleDto, UseCaseError> {
+        Self::authorize(ctx)?;
+        self.validate_action(&request.action)?;
+        let rule = AutomationRule::new(
+            self.id_generator.next_id(),
+            request.name,
+            request.enabled,
+            request.trigger,
+            request.action,
+            ctx.user_id.clone(),
+            self.task_use_cases.now(),
+        ).map_err(UseCaseError::ValidationError)?.in_workspace(ctx.workspace());
+        self.rule_repository.save(&rule).await?;
+        Ok(rule.into())
+    }
+
+    /// Replaces the rule's whole definition
+    pub async fn update_rule(&self, ctx: &RequestContext, id: &str, request: AutomationRuleRequest) -> Result<AutomationRuleDto, UseCaseError> {
+        Self::authorize(ctx)?;
+        self.validate_action(&request.action)?;
+        let mut rule = self.find_rule(id).await?;
+        rule.redefine(request.name, request.enabled, request.trigger, request.action, self.task_use_cases.now())
+            .map_err(UseCaseError::ValidationError)?;
+        self.rule_repository.update(&rule).await?;
+        Ok(rule.into())
+    }
+
+    pub async fn delete_rule(&self, ctx: &RequestContext, id: &str) -> Result<(), UseCaseError> {
+        Self::authorize(ctx)?;
+        Ok(self.rule_repository.delete(id).await?)
+    }
+
+    /// Runs the action of every enabled rule whose trigger matches `event`.
+    ///
+    /// A failing action does not stop the others; each rule's outcome is reported.
+    pub async fn handle_event(&self, event: &DomainEvent) -> Result<Vec<RuleOutcome>, UseCaseError> {
+        let actor = SystemActor::new(SystemActor::AUTOMATION);
+        if event.changed_by == actor.changed_by() {
+            return Ok(Vec::new());
+        }
+        let rules: Vec<_> = self.rule_repository.find_all().await?
+            .into_iter()
+            .filter(|rule| rule.enabled && rule.workspace_id == event.workspace_id && rule.trigger.event_type == event.event.event_type())
+            .collect();
+        let priority = match rules.iter().any(|rule| rule.trigger.filter.checks_priority()) {
+            true => self.priority_of(&event.event).await,
+            false => None,
+        };
+
+        let ctx = RequestContext::system(&actor).with_tenant(Some(event.workspace_id.clone()));
+        let mut outcomes = Vec::new();
+        for rule in rules.into_iter().filter(|rule| rule.matches(&event.event, priority)) {
+            let error = self.apply(&ctx, &rule, event).await.err();
+            outcomes.push(RuleOutcome { rule_id: rule.id, rule_name: rule.name, error });
+        }
+        Ok(outcomes)
+    }
+
+    async fn apply(&self, ctx: &RequestContext, rule: &AutomationRule, event: &DomainEvent) -> Result<(), String> {
+        match &rule.action {
+            RuleAction::SetPriority { priority } => {
+                let request = UpdateTaskRequest { name: None, priority: Some(PriorityInput::Value(*priority)), description: None };
+                self.task_use_cases.update_task(ctx, event.event.task_id(), request).await
+                    .map(|_| ())
+                    .map_err(|error| error.to_string())
+            }
+            RuleAction::Notify { url } => {
+                let notification = AutomationNotification { rule_id: rule.id.clone(), rule_name: rule.name.clone(), event: event.clone() };
+                self.notifier.notify(url, &notification).await
+            }
+        }
+    }
+
+    /// The task's priority after the event: taken from the event when it says, otherwise looked up
+    async fn priority_of(&self, event: &TaskEvent) -> Option<i32> {
+        match event {
+            TaskEvent::TaskCreated(created) => return created.priority,
+            TaskEvent::TaskUpdated(updated) => {
+                if let Some(change) = updated.changes.iter().find(|change| change.field == TaskField::Priority) {
+                    return change.new_value.as_deref().and_then(|value| value.parse().ok());
+                }
+            }
+            _ => {}
+        }
+        let actor = SystemActor::new(SystemActor::AUTOMATION);
+        self.task_use_cases.get_task_by_id(&RequestContext::system(&actor), event.task_id()).await
+            .ok()
+            .and_then(|task| task.priority)
+    }
+
+    async fn find_rule(&self, id: &str) -> Result<AutomationRule, UseCaseError> {
+        self.rule_repository.find_by_id(id).await?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Automation rule {} not found", id)))
+    }
+
+    fn validate_action(&self, action: &RuleAction) -> Result<(), UseCaseError> {
+        let scale = self.task_use_cases.priority_scale();
+        match action {
+            RuleAction::SetPriority { priority } if !scale.contains(*priority) => Err(UseCaseError::ValidationError(format!(
+                "set_priority priority must be between {} and {}, got {}", scale.min(), scale.max(), priority
+            ))),
+            RuleAction::Notify { url } => self.notify_url_policy.check(url).map_err(UseCaseError::ValidationError),
+            _ => Ok(()),
+        }
+    }
+
+    fn authorize(ctx: &RequestContext) -> Result<(), UseCaseError> {
+        match ctx.role.can_manage_automation() {
+            true => Ok(()),
+            false => Err(UseCaseError::Forbidden("Only managers and admins can manage automation rules".to_string())),
+        }
+    }
+}
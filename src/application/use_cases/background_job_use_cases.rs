@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use crate::domain::BackgroundJobRepository;
+use crate::application::context::RequestContext;
+use crate::application::dto::BackgroundJobDto;
+use crate::application::use_cases::UseCaseError;
+
+/// Lets admins see how the background jobs are doing and step in when one misbehaves
+pub struct BackgroundJobUseCases {
+    background_job_repository: Arc<dyn BackgroundJobRepository>,
+}
+
+impl BackgroundJobUseCases {
+    pub fn new(background_job_repository: Arc<dyn BackgroundJobRepository>) -> Self {
+        Self { background_job_repository }
+    }
+
+    pub async fn list_jobs(&self, ctx: &RequestContext) -> Result<Vec<BackgroundJobDto>, UseCaseError> {
+        Self::authorize(ctx)?;
+        let jobs = self.background_job_repository.find_all().await?;
+        Ok(jobs.into_iter().map(BackgroundJobDto::from).collect())
+    }
+
+    /// Stops scheduled runs until the job is resumed; a run already under way finishes
+    pub async fn pause_job(&self, ctx: &RequestContext, name: &str) -> Result<BackgroundJobDto, UseCaseError> {
+        Self::authorize(ctx)?;
+        Ok(self.background_job_repository.set_paused(name, true).await?.into())
+    }
+
+    pub async fn resume_job(&self, ctx: &RequestContext, name: &str) -> Result<BackgroundJobDto, UseCaseError> {
+        Self::authorize(ctx)?;
+        Ok(self.background_job_repository.set_paused(name, false).await?.into())
+    }
+
+    /// Runs the job at the scheduler's next check, even while it is paused
+    pub async fn trigger_job(&self, ctx: &RequestContext, name: &str) -> Result<BackgroundJobDto, UseCaseError> {
+        Self::authorize(ctx)?;
+        Ok(self.background_job_repository.request_run(name).await?.into())
+    }
+
+    fn authorize(ctx: &RequestContext) -> Result<(), UseCaseError> {
+        match ctx.role.can_manage_users() {
+            true => Ok(()),
+            false => Err(UseCaseError::Forbidden("Only admins can manage background jobs".to_string())),
+        }
+    }
+}
@@ -1,3 +1,17 @@
 pub mod task_use_cases;
+pub mod api_key_use_cases;
+pub mod attachment_use_cases;
+pub mod audit_archive_use_cases;
+pub mod automation_use_cases;
+pub mod background_job_use_cases;
+pub mod task_clone_use_cases;
+pub mod workspace_use_cases;
 
-pub use task_use_cases::*;
\ No newline at end of file
+pub use task_use_cases::*;
+pub use api_key_use_cases::*;
+pub use attachment_use_cases::*;
+pub use audit_archive_use_cases::*;
+pub use automation_use_cases::*;
+pub use background_job_use_cases::*;
+pub use task_clone_use_cases::*;
+pub use workspace_use_cases::*;
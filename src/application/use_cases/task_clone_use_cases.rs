@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use tokio_util::sync::CancellationToken;
+use crate::domain::{FilterExpression, IdGenerator, TaskFilter, TaskSort, UuidV7Generator};
+use crate::application::context::RequestContext;
+use crate::application::dto::{CloneState, CloneTasksRequest, ClonedTaskDto, TaskCloneDto, TaskDto};
+use crate::application::use_cases::{TaskUseCases, UseCaseError, WorkspaceUseCases};
+
+/// Finished clone runs kept for the progress endpoint; the oldest are forgotten past this
+pub const MAX_FINISHED_CLONES: usize = 100;
+
+struct CloneRun {
+    clone: TaskCloneDto,
+    ctx: RequestContext,
+    filter: TaskFilter,
+    user_map: BTreeMap<String, String>,
+}
+
+/// Copies a filtered set of tasks from one workspace to another, such as to seed a new workspace
+/// from a template.
+///
+/// Starting a clone only registers the run. The caller then drives it: `source_tasks` inside the
+/// source workspace, `copy_task` for each of them inside the target workspace, then
+/// `finish_clone`. Copies start out Pending with a fresh history. Runs are kept in memory, so
+/// their progress is only known to the process that ran them and is lost on restart.
+pub struct TaskCloneUseCases {
+    task_use_cases: Arc<TaskUseCases>,
+    workspace_use_cases: Arc<WorkspaceUseCases>,
+    id_generator: Arc<dyn IdGenerator>,
+    runs: Mutex<BTreeMap<String, CloneRun>>,
+}
+
+impl TaskCloneUseCases {
+    pub fn new(task_use_cases: Arc<TaskUseCases>, workspace_use_cases: Arc<WorkspaceUseCases>) -> Self {
+        Self {
+            task_use_cases,
+            workspace_use_cases,
+            id_generator: Arc::new(UuidV7Generator),
+            runs: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Mints clone ids with `id_generator` instead of UUIDv7
+    pub fn with_id_generator(mut self, id_generator: Arc<dyn IdGenerator>) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    /// Registers a Running clone between two existing, different workspaces, both of which the
+    /// caller administers
+    pub async fn start_clone(&self, ctx: &RequestContext, request: CloneTasksRequest) -> Result<TaskCloneDto, UseCaseError> {
+        Self::authorize(ctx)?;
+        if request.source_workspace == request.target_workspace {
+            return Err(UseCaseError::ValidationError("Tasks cannot be cloned into the workspace they come from".to_string()));
+        }
+        for workspace in [&request.source_workspace, &request.target_workspace] {
+            if !ctx.administers_workspace(workspace) {
+                return Err(UseCaseError::Forbidden(format!("Admins of workspace {} cannot clone tasks of workspace {}", ctx.workspace(), workspace)));
+            }
+        }
+        let mut user_map = BTreeMap::new();
+        for entry in request.user_mapping {
+            if entry.from.trim().is_empty() || entry.to.trim().is_empty() {
+                return Err(UseCaseError::ValidationError("User mappings need both a 'from' and a 'to' user".to_string()));
+            }
+            if user_map.insert(entry.from.clone(), entry.to).is_some() {
+                return Err(UseCaseError::ValidationError(format!("User '{}' is mapped twice", entry.from)));
+            }
+        }
+        self.workspace_use_cases.get_workspace(ctx, &request.source_workspace).await?;
+        self.workspace_use_cases.get_workspace(ctx, &request.target_workspace).await?;
+        let expression = request.filter.as_deref()
+            .map(|filter| FilterExpression::parse(filter, self.task_use_cases.now()))
+            .transpose()?;
+
+        let clone = TaskCloneDto {
+            id: self.id_generator.next_id().to_string(),
+            source_workspace: request.source_workspace,
+            target_workspace: request.target_workspace,
+            filter: request.filter,
+            state: CloneState::Running,
+            total: None,
+            copied: 0,
+            cloned: Vec::new(),
+            error: None,
+            started_by: ctx.user_id.clone(),
+            started_at: self.task_use_cases.now(),
+            finished_at: None,
+        };
+        let run = CloneRun {
+            clone: clone.clone(),
+            // The request's token fires once it has been answered, long before the run ends
+            ctx: ctx.clone().with_cancellation(CancellationToken::new()),
+            filter: TaskFilter { expression, ..TaskFilter::default() },
+            user_map,
+        };
+        let mut runs = self.runs.lock().unwrap();
+        runs.insert(clone.id.clone(), run);
+        Self::forget_finished(&mut runs);
+        Ok(clone)
+    }
+
+    /// The active tasks the clone copies; called inside the source workspace
+    pub async fn source_tasks(&self, clone_id: &str) -> Result<Vec<TaskDto>, UseCaseError> {
+        let (ctx, filter) = self.with_run(clone_id, |run| {
+            (run.ctx.clone().with_tenant(Some(run.clone.source_workspace.clone())), run.filter.clone())
+        })?;
+        let tasks = self.task_use_cases.list_tasks(&ctx, filter, TaskSort::default()).await?;
+        self.with_run(clone_id, |run| run.clone.total = Some(tasks.len()))?;
+        Ok(tasks)
+    }
+
+    /// Copies one source task with its creator remapped; called inside the target workspace
+    pub async fn copy_task(&self, clone_id: &str, source: &TaskDto) -> Result<i32, UseCaseError> {
+        let (ctx, created_by, comment) = self.with_run(clone_id, |run| {
+            let created_by = source.created_by.as_ref()
+                .and_then(|creator| run.user_map.get(creator))
+                .unwrap_or(&run.ctx.user_id)
+                .clone();
+            let comment = format!("Cloned from task {} in workspace {}", source.id, run.clone.source_workspace);
+            (run.ctx.clone().with_tenant(Some(run.clone.target_workspace.clone())), created_by, comment)
+        })?;
+        let task_id = self.task_use_cases.clone_task(&ctx, source, Some(created_by), comment).await?;
+        self.with_run(clone_id, |run| {
+            run.clone.copied += 1;
+            run.clone.cloned.push(ClonedTaskDto { source_id: source.id, task_id });
+        })?;
+        Ok(task_id)
+    }
+
+    /// Marks the clone Completed, or Failed with `result`'s error; tasks already copied are kept
+    pub fn finish_clone(&self, clone_id: &str, result: Result<(), UseCaseError>) -> Result<TaskCloneDto, UseCaseError> {
+        let finished_at = self.task_use_cases.now();
+        self.with_run(clone_id, |run| {
+            match result {
+                Ok(()) => run.clone.state = CloneState::Completed,
+                Err(error) => {
+                    run.clone.state = CloneState::Failed;
+                    run.clone.error = Some(error.to_string());
+                }
+            }
+            run.clone.finished_at = Some(finished_at);
+            run.clone.clone()
+        })
+    }
+
+    pub fn get_clone(&self, ctx: &RequestContext, clone_id: &str) -> Result<TaskCloneDto, UseCaseError> {
+        Self::authorize(ctx)?;
+        self.with_run(clone_id, |run| Self::visible_to(ctx, run).then(|| run.clone.clone()))?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Task clone {} not found", clone_id)))
+    }
+
+    /// Clones this process knows of into or out of a workspace the caller administers, newest first
+    pub fn list_clones(&self, ctx: &RequestContext) -> Result<Vec<TaskCloneDto>, UseCaseError> {
+        Self::authorize(ctx)?;
+        let runs = self.runs.lock().unwrap();
+        Ok(runs.values().rev().filter(|run| Self::visible_to(ctx, run)).map(|run| run.clone.clone()).collect())
+    }
+
+    fn visible_to(ctx: &RequestContext, run: &CloneRun) -> bool {
+        ctx.administers_workspace(&run.clone.source_workspace) || ctx.administers_workspace(&run.clone.target_workspace)
+    }
+
+    fn with_run<T>(&self, clone_id: &str, f: impl FnOnce(&mut CloneRun) -> T) -> Result<T, UseCaseError> {
+        let mut runs = self.runs.lock().unwrap();
+        let run = runs.get_mut(clone_id)
+            .ok_or_else(|| UseCaseError::NotFound(format!("Task clone {} not found", clone_id)))?;
+        Ok(f(run))
+    }
+
+    /// Ids are UUIDv7, so the map is oldest first
+    fn forget_finished(runs: &mut BTreeMap<String, CloneRun>) {
+        let finished: Vec<String> = runs.iter()
+            .filter(|(_, run)| run.clone.state != CloneState::Running)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in finished.iter().take(finished.len().saturating_sub(MAX_FINISHED_CLONES)) {
+            runs.remove(id);
+        }
+    }
+
+    fn authorize(ctx: &RequestContext) -> Result<(), UseCaseError> {
+        match ctx.role.can_manage_users() {
+            true => Ok(()),
+            false => Err(UseCaseError::Forbidden("Only admins can clone tasks between workspaces".to_string())),
+        }
+    }
+}
@@ -1,13 +1,33 @@
+use std::collections::{BTreeMap, HashSet};
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 use chrono::{DateTime, Utc};
-use crate::domain::{Task, TaskId, TaskRepository, StatusHistoryRepository, TaskDomainService, TaskStatusService, UserRole, RepositoryError};
-use crate::application::dto::{TaskDto, CreateTaskRequest, UpdateTaskRequest, UpdateTaskStatusDto, TaskWithTransitionsDto, TaskHistoryDto, TaskAnalyticsDto, CompletionAnalyticsDto, StatusHistoryDto, PriorityCompletionDto};
+use crate::domain::{DomainError, FilterExpression, Task, TaskId, TaskStatus, TaskUpdated, FieldChange, TaskField, PriorityChange, PriorityMapping, ImportedTask, SkippedRow, StatusMapping, TaskImportParser, TaskEvent, TaskCreated, TaskStatusChanged, TaskDeleted, TaskRestored, TaskArchived, DomainEvent, EventPublisher, Clock, SystemClock, IdGenerator, UuidV7Generator, StatusHistory, TaskRepository, AsTaskRepository, StatusHistoryRepository, TaskDependencyRepository, TaskDomainService, TaskStatusService, TransitionGate, GatedTransition, GateFallback, TaskPatch, PriorityPolicy, ReopenPolicy, StaleTaskPolicy, AutoArchivePolicy, EscalationAction, EscalationPolicy, ReviewChecklist, ReviewChain, ReviewChains, ReviewProgress, WorkflowDefinition, WorkflowRepository, Workflows, CustomStatusRepository, StatusCatalog, BoardColumn, WipLimits, DEFAULT_BOARD_COLUMN_SIZE, MAX_BOARD_COLUMN_SIZE, StatisticsQuery, TaskStatisticsRepository, DEFAULT_STATISTICS_DAYS, MAX_STATISTICS_DAYS, PriorityScale, PriorityLabels, TaskFilter, TaskSort, HistoryFilter, Pagination, SortOrder, CohortBucket, CycleTimeStats, RepositoryError, ConfirmationKey, CursorKey, PageKey};
+use crate::application::context::RequestContext;
+use crate::application::instrumentation::LatencyBudgets;
+use crate::application::policies::{CreationPolicy, CreationPolicyChain};
+use crate::application::realtime::TaskChangeFeed;
+use crate::application::dto::{field_messages, FieldError, Validate, TaskDto, PriorityInput, CreateTaskRequest, UpdateTaskRequest, UpdateTaskStatusDto, TaskWithTransitionsDto, ReviewProgressDto, TaskHistoryDto, HistoryListDto, TaskAnalyticsDto, CompletionAnalyticsDto, TransitionMatrixDto, CohortAnalysisDto, CohortDto, CumulativeFlowDto, StatusHistoryDto, PriorityCompletionDto, AddDependencyRequest, ReprioritizeRequest, ReprioritizeResultDto, PriorityMoveDto, BulkDeleteRequest, BulkDeleteResultDto, TaskFacetsDto, TaskStatisticsDto, BoardDto, BoardColumnDto, MoveTaskRequest, TaskPositionDto, ImportTasksRequest, ImportSummaryDto, ImportedStatusDto, SkippedRowDto, TaskDependenciesDto, AnalyticsGaugesDto, TaskChangeDto, TaskChangeKind};
+
+/// How many task names a bulk deletion preview lists
+pub const BULK_DELETE_SAMPLE_SIZE: usize = 5;
 
 #[derive(Debug, Clone)]
 pub enum UseCaseError {
     ValidationError(String),
+    /// A business rule was broken; carries the rule's code as well as its message
+    Domain(DomainError),
+    /// Request fields broke one or more rules, each reported against its field
+    InvalidFields(Vec<FieldError>),
     NotFound(String),
+    /// The caller is identified but not allowed to act on this resource
+    Forbidden(String),
+    /// The change collides with existing data, such as a duplicate of a unique value
+    Conflict(FieldError),
     RepositoryError(String),
+    /// The request's cancellation token fired before the work finished
+    Cancelled(String),
 }
 
 impl From<RepositoryError> for UseCaseError {
@@ -16,93 +36,495 @@ impl From<RepositoryError> for UseCaseError {
             RepositoryError::NotFound(msg) => UseCaseError::NotFound(msg),
             RepositoryError::ValidationError(msg) => UseCaseError::ValidationError(msg),
             RepositoryError::DatabaseError(msg) => UseCaseError::RepositoryError(msg),
+            RepositoryError::Conflict { field, message } => UseCaseError::Conflict(FieldError {
+                field,
+                code: "conflict".to_string(),
+                message,
+            }),
+            RepositoryError::ForeignKeyViolation { field, message } => UseCaseError::InvalidFields(vec![FieldError {
+                field,
+                code: "foreign_key_violation".to_string(),
+                message,
+            }]),
         }
     }
 }
 
+impl From<DomainError> for UseCaseError {
+    fn from(error: DomainError) -> Self {
+        UseCaseError::Domain(error)
+    }
+}
+
 impl std::fmt::Display for UseCaseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             UseCaseError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            UseCaseError::Domain(error) => write!(f, "Validation error: {}", error),
+            UseCaseError::InvalidFields(errors) => write!(f, "Validation error: {}", field_messages(errors)),
             UseCaseError::NotFound(msg) => write!(f, "Not found: {}", msg),
+            UseCaseError::Forbidden(msg) => write!(f, "Forbidden: {}", msg),
+            UseCaseError::Conflict(error) => write!(f, "Conflict: {}", error.message),
             UseCaseError::RepositoryError(msg) => write!(f, "Repository error: {}", msg),
+            UseCaseError::Cancelled(msg) => write!(f, "Cancelled: {}", msg),
         }
     }
 }
 
 impl std::error::Error for UseCaseError {}
 
-pub struct TaskUseCases {
-    task_repository: Arc<dyn TaskRepository>,
+/// Task use cases over a task repository of type `R`.
+///
+/// The default `dyn TaskRepository` lets the binary pick an adapter at runtime. Crates embedding
+/// these use cases can build them with [`TaskUseCases::with_repository`] over a concrete
+/// repository type so task repository calls are dispatched statically.
+pub struct TaskUseCases<R: ?Sized = dyn TaskRepository> {
+    task_repository: Arc<R>,
     status_history_repository: Arc<dyn StatusHistoryRepository>,
+    task_dependency_repository: Arc<dyn TaskDependencyRepository>,
     domain_service: TaskDomainService,
     status_service: TaskStatusService,
+    review_checklist: ReviewChecklist,
+    review_chains: ReviewChains,
+    workflows: Workflows,
+    workflow_repository: Option<Arc<dyn WorkflowRepository>>,
+    custom_status_repository: Option<Arc<dyn CustomStatusRepository>>,
+    wip_limits: WipLimits,
+    statistics_repository: Option<Arc<dyn TaskStatisticsRepository>>,
+    stale_task_policy: Option<StaleTaskPolicy>,
+    auto_archive_policy: Option<AutoArchivePolicy>,
+    escalation_policy: Option<EscalationPolicy>,
+    reopen_policy: ReopenPolicy,
+    creation_policies: CreationPolicyChain,
+    change_feed: Option<TaskChangeFeed>,
+    event_publishers: Vec<Arc<dyn EventPublisher>>,
+    clock: Arc<dyn Clock>,
+    id_generator: Arc<dyn IdGenerator>,
+    confirmation_key: ConfirmationKey,
+    cursor_key: CursorKey,
+    latency_budgets: LatencyBudgets,
 }
 
 impl TaskUseCases {
-    pub fn new(task_repository: Arc<dyn TaskRepository>, status_history_repository: Arc<dyn StatusHistoryRepository>) -> Self {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        status_history_repository: Arc<dyn StatusHistoryRepository>,
+        task_dependency_repository: Arc<dyn TaskDependencyRepository>,
+    ) -> Self {
+        Self::with_repository(task_repository, status_history_repository, task_dependency_repository)
+    }
+}
+
+impl<R: TaskRepository + AsTaskRepository + ?Sized> TaskUseCases<R> {
+    /// Builds the use cases over a task repository of any type, sized or a trait object
+    pub fn with_repository(
+        task_repository: Arc<R>,
+        status_history_repository: Arc<dyn StatusHistoryRepository>,
+        task_dependency_repository: Arc<dyn TaskDependencyRepository>,
+    ) -> Self {
         Self {
             task_repository,
             status_history_repository,
+            task_dependency_repository,
             domain_service: TaskDomainService::new(),
             status_service: TaskStatusService::new(),
+            review_checklist: ReviewChecklist::default(),
+            review_chains: ReviewChains::default(),
+            workflows: Workflows::default(),
+            workflow_repository: None,
+            custom_status_repository: None,
+            wip_limits: WipLimits::default(),
+            statistics_repository: None,
+            stale_task_policy: None,
+            auto_archive_policy: None,
+            escalation_policy: None,
+            reopen_policy: ReopenPolicy::default(),
+            creation_policies: CreationPolicyChain::new(),
+            change_feed: None,
+            event_publishers: Vec::new(),
+            clock: Arc::new(SystemClock),
+            id_generator: Arc::new(UuidV7Generator),
+            confirmation_key: ConfirmationKey::random(),
+            cursor_key: CursorKey::random(),
+            latency_budgets: LatencyBudgets::new(),
+        }
+    }
+
+    /// Restricts task priorities to the policy's scale and reviews those above its threshold
+    pub fn with_priority_policy(mut self, priority_policy: PriorityPolicy) -> Self {
+        self.domain_service = TaskDomainService::with_priority_policy(priority_policy);
+        self.status_service = std::mem::take(&mut self.status_service).with_priority_policy(priority_policy);
+        self
+    }
+
+    /// Replaces the label mapping derived from the priority scale
+    pub fn with_priority_labels(mut self, priority_labels: PriorityLabels) -> Self {
+        self.domain_service = self.domain_service.with_priority_labels(priority_labels);
+        self
+    }
+
+    /// Items reviewers must tick when approving a task
+    pub fn with_review_checklist(mut self, review_checklist: ReviewChecklist) -> Self {
+        self.review_checklist = review_checklist;
+        self
+    }
+
+    /// Sign-offs each workspace's tasks collect in PendingReview before they are completed
+    pub fn with_review_chains(mut self, review_chains: ReviewChains) -> Self {
+        self.review_chains = review_chains;
+        self
+    }
+
+    /// Statuses and transitions each workspace's tasks follow, unless an admin stored its own
+    pub fn with_workflows(mut self, workflows: Workflows) -> Self {
+        self.workflows = workflows;
+        self
+    }
+
+    /// Workflows admins stored for single workspaces, which take precedence over configured ones
+    pub fn with_workflow_repository(mut self, workflow_repository: Arc<dyn WorkflowRepository>) -> Self {
+        self.workflow_repository = Some(workflow_repository);
+        self
+    }
+
+    /// Statuses admins defined, which workflows may move tasks into
+    pub fn with_custom_status_repository(mut self, custom_status_repository: Arc<dyn CustomStatusRepository>) -> Self {
+        self.custom_status_repository = Some(custom_status_repository);
+        self
+    }
+
+    /// How many tasks each workspace wants per status, reported on the board
+    pub fn with_wip_limits(mut self, wip_limits: WipLimits) -> Self {
+        self.wip_limits = wip_limits;
+        self
+    }
+
+    /// Counts tasks for the statistics endpoint where they are stored
+    pub fn with_statistics_repository(mut self, statistics_repository: Arc<dyn TaskStatisticsRepository>) -> Self {
+        self.statistics_repository = Some(statistics_repository);
+        self
+    }
+
+    /// Enables automatic cancellation of tasks left in Pending
+    pub fn with_stale_task_policy(mut self, stale_task_policy: StaleTaskPolicy) -> Self {
+        self.stale_task_policy = Some(stale_task_policy);
+        self
+    }
+
+    /// Enables automatic archival of tasks completed long ago
+    pub fn with_auto_archive_policy(mut self, auto_archive_policy: AutoArchivePolicy) -> Self {
+        self.auto_archive_policy = Some(auto_archive_policy);
+        self
+    }
+
+    /// Enables escalation of tasks left too long in a status
+    pub fn with_escalation_policy(mut self, escalation_policy: EscalationPolicy) -> Self {
+        self.escalation_policy = Some(escalation_policy);
+        self
+    }
+
+    /// Makes `transitions` wait for approval from an external system such as a change-management tool
+    pub fn with_transition_gate(mut self, gate: Arc<dyn TransitionGate>, transitions: Vec<(TaskStatus, TaskStatus)>, fallback: GateFallback) -> Self {
+        self.status_service = std::mem::take(&mut self.status_service).with_gate(gate, transitions, fallback);
+        self
+    }
+
+    /// Limits how long after completion managers may reopen a task
+    pub fn with_reopen_policy(mut self, reopen_policy: ReopenPolicy) -> Self {
+        self.reopen_policy = reopen_policy;
+        self
+    }
+
+    /// Publishes every task change to the feed's subscribers
+    pub fn with_change_feed(mut self, change_feed: TaskChangeFeed) -> Self {
+        self.change_feed = Some(change_feed);
+        self
+    }
+
+    /// Adds a publisher that receives a domain event for every task change
+    pub fn with_event_publisher(mut self, publisher: Arc<dyn EventPublisher>) -> Self {
+        self.event_publishers.push(publisher);
+        self
+    }
+
+    /// Adds a check that runs on every new task after the built-in validation
+    pub fn with_creation_policy(mut self, policy: Arc<dyn CreationPolicy>) -> Self {
+        self.creation_policies = self.creation_policies.with(policy);
+        self
+    }
+
+    /// Reads the time from `clock` instead of the wall clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Mints status history ids with `id_generator` instead of UUIDv7
+    pub fn with_id_generator(mut self, id_generator: Arc<dyn IdGenerator>) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    /// Signs bulk deletion confirmation tokens with `key`; replicas sharing a key accept each other's tokens
+    pub fn with_confirmation_key(mut self, key: ConfirmationKey) -> Self {
+        self.confirmation_key = key;
+        self
+    }
+
+    /// Signs pagination cursors with `key`; replicas sharing a key accept each other's cursors
+    pub fn with_cursor_key(mut self, key: CursorKey) -> Self {
+        self.cursor_key = key;
+        self
+    }
+
+    /// Replaces the budgets calls are checked against, such as with configured overrides
+    pub fn with_latency_budgets(mut self, latency_budgets: LatencyBudgets) -> Self {
+        self.latency_budgets = latency_budgets;
+        self
+    }
+
+    /// How often each use case ran and went over its latency budget
+    pub fn latency_budgets(&self) -> &LatencyBudgets {
+        &self.latency_budgets
+    }
+
+    /// The current time on the use cases' clock, for callers choosing time-based defaults
+    pub fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
+    /// Priorities tasks may take, for callers validating a priority ahead of time
+    pub fn priority_scale(&self) -> PriorityScale {
+        self.domain_service.priority_scale()
+    }
+
+    async fn publish_event(&self, ctx: &RequestContext, event: TaskEvent) -> Result<(), UseCaseError> {
+        let event = DomainEvent { changed_by: ctx.user_id.clone(), workspace_id: ctx.workspace().to_string(), event };
+        for publisher in &self.event_publishers {
+            publisher.publish(&event).await?;
+        }
+        Ok(())
+    }
+
+    fn publish_change(&self, ctx: &RequestContext, kind: TaskChangeKind, task_id: i32, task: Option<TaskDto>, changed_fields: &[&str]) {
+        if let Some(change_feed) = &self.change_feed {
+            change_feed.publish(TaskChangeDto {
+                kind,
+                task_id,
+                task,
+                changed_fields: changed_fields.iter().map(|field| field.to_string()).collect(),
+                changed_by: ctx.user_id.clone(),
+                workspace_id: ctx.workspace().to_string(),
+                occurred_at: self.clock.now(),
+                coalesced: None,
+            });
         }
     }
 
-    pub async fn get_all_tasks(&self) -> Result<Vec<TaskDto>, UseCaseError> {
+    fn validate(&self, request: &impl Validate) -> Result<(), UseCaseError> {
+        let errors = request.field_errors(&self.domain_service);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(UseCaseError::InvalidFields(errors))
+        }
+    }
+
+    fn to_dto(&self, task: Task) -> TaskDto {
+        TaskDto::from_task(task, &self.domain_service.priority_labels())
+    }
+
+    fn resolve_priority(&self, priority: Option<PriorityInput>) -> Option<i32> {
+        priority.map(|priority| priority.resolve(&self.domain_service.priority_labels()))
+    }
+
+    pub async fn get_all_tasks(&self, _ctx: &RequestContext) -> Result<Vec<TaskDto>, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_all_tasks", Duration::from_millis(200));
         let tasks = self.task_repository.find_all().await?;
-        Ok(tasks.into_iter().map(TaskDto::from).collect())
+        Ok(tasks.into_iter().map(|task| self.to_dto(task)).collect())
+    }
+
+    /// Lists tasks matching the filter in the requested order
+    pub async fn list_tasks(&self, ctx: &RequestContext, filter: TaskFilter, sort: TaskSort) -> Result<Vec<TaskDto>, UseCaseError> {
+        let _budget = self.latency_budgets.start("list_tasks", Duration::from_millis(200));
+        if filter.include_deleted && !ctx.role.can_manage_deleted_tasks() {
+            return Err(UseCaseError::Forbidden("Only admins can list deleted tasks".to_string()));
+        }
+        self.domain_service.validate_priority(filter.priority)?;
+
+        let tasks = self.task_repository.find_matching(&filter, sort).await?;
+        Ok(tasks.into_iter().map(|task| self.to_dto(task)).collect())
     }
 
-    pub async fn get_task_by_id(&self, id: i32) -> Result<TaskDto, UseCaseError> {
+    pub async fn get_task_by_id(&self, _ctx: &RequestContext, id: i32) -> Result<TaskDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_task_by_id", Duration::from_millis(50));
         let task_id = TaskId::new(id);
         let task = self.task_repository.find_by_id(task_id).await?
             .ok_or_else(|| UseCaseError::NotFound(format!("Task with id {} not found", id)))?;
-        Ok(TaskDto::from(task))
+        Ok(self.to_dto(task))
     }
 
-    pub async fn get_tasks_by_priority(&self, priority: i32) -> Result<Vec<TaskDto>, UseCaseError> {
-        self.domain_service.validate_priority(Some(priority))
-            .map_err(UseCaseError::ValidationError)?;
+    pub async fn get_tasks_by_priority(&self, _ctx: &RequestContext, priority: i32) -> Result<Vec<TaskDto>, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_tasks_by_priority", Duration::from_millis(200));
+        self.domain_service.validate_priority(Some(priority))?;
         
         let tasks = self.task_repository.find_by_priority(priority).await?;
-        Ok(tasks.into_iter().map(TaskDto::from).collect())
+        Ok(tasks.into_iter().map(|task| self.to_dto(task)).collect())
+    }
+
+    pub async fn get_tasks_by_status(&self, _ctx: &RequestContext, status: TaskStatus) -> Result<Vec<TaskDto>, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_tasks_by_status", Duration::from_millis(200));
+        let tasks = self.task_repository.find_by_status(&status).await?;
+        Ok(tasks.into_iter().map(|task| self.to_dto(task)).collect())
+    }
+
+    /// Full-text search narrowed by an optional filter expression; a filter alone lists every match
+    pub async fn search_tasks(&self, _ctx: &RequestContext, query: &str, filter: Option<&str>) -> Result<Vec<TaskDto>, UseCaseError> {
+        let _budget = self.latency_budgets.start("search_tasks", Duration::from_millis(500));
+        let expression = filter.map(|filter| FilterExpression::parse(filter, self.clock.now())).transpose()?;
+
+        let tasks = match expression {
+            Some(expression) if query.trim().is_empty() => {
+                let filter = TaskFilter { expression: Some(expression), ..TaskFilter::default() };
+                self.task_repository.find_matching(&filter, TaskSort::default()).await?
+            }
+            expression => {
+                self.domain_service.validate_search_query(query)?;
+                let mut tasks = self.task_repository.search_tasks(query.trim()).await?;
+                tasks.retain(|task| !task.is_archived());
+                if let Some(expression) = expression {
+                    tasks.retain(|task| expression.matches(task));
+                }
+                tasks
+            }
+        };
+        Ok(tasks.into_iter().map(|task| self.to_dto(task)).collect())
+    }
+
+    /// Counts the live tasks matching an optional filter expression by status and by priority
+    pub async fn task_facets(&self, _ctx: &RequestContext, filter: Option<&str>) -> Result<TaskFacetsDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("task_facets", Duration::from_millis(300));
+        let expression = filter.map(|filter| FilterExpression::parse(filter, self.clock.now())).transpose()?;
+        let filter = TaskFilter { expression, ..TaskFilter::default() };
+
+        let facets = self.task_repository.count_facets(&filter).await?;
+        Ok(TaskFacetsDto::from_facets(facets, &self.domain_service.priority_labels()))
+    }
+
+    /// Counts of the live tasks by status and priority, the tasks the stale task policy counts
+    /// as overdue, and the tasks created and completed on each of the last `days` UTC days
+    pub async fn task_statistics(&self, _ctx: &RequestContext, days: Option<u32>) -> Result<TaskStatisticsDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("task_statistics", Duration::from_millis(500));
+        let days = days.unwrap_or(DEFAULT_STATISTICS_DAYS);
+        if !(1..=MAX_STATISTICS_DAYS).contains(&days) {
+            return Err(UseCaseError::ValidationError(format!("days must be between 1 and {}", MAX_STATISTICS_DAYS)));
+        }
+        let Some(repository) = &self.statistics_repository else {
+            return Err(UseCaseError::RepositoryError("Task statistics are not available".to_string()));
+        };
+
+        let now = self.clock.now();
+        let overdue_before = self.stale_task_policy
+            .map(|policy| now - chrono::Duration::days(i64::from(policy.max_pending_days())));
+        let statistics = repository.task_statistics(&StatisticsQuery::last_days(days, now, overdue_before)).await?;
+        Ok(TaskStatisticsDto::from_statistics(statistics, &self.domain_service.priority_labels()))
     }
 
-    pub async fn create_task(&self, request: CreateTaskRequest) -> Result<i32, UseCaseError> {
-        self.domain_service.validate_task_name(&request.name)
-            .map_err(UseCaseError::ValidationError)?;
-        self.domain_service.validate_priority(request.priority)
-            .map_err(UseCaseError::ValidationError)?;
+    /// The live tasks matching an optional filter expression in one column per status, each
+    /// holding up to `column_size` tasks in the requested order. Columns follow the workspace's
+    /// workflow, empty ones included; statuses the workflow does not name come last.
+    pub async fn task_board(&self, ctx: &RequestContext, filter: Option<&str>, sort: TaskSort, column_size: Option<usize>) -> Result<BoardDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("task_board", Duration::from_millis(300));
+        let column_size = column_size.unwrap_or(DEFAULT_BOARD_COLUMN_SIZE);
+        if !(1..=MAX_BOARD_COLUMN_SIZE).contains(&column_size) {
+            return Err(UseCaseError::ValidationError(format!("limit must be between 1 and {}", MAX_BOARD_COLUMN_SIZE)));
+        }
+        let expression = filter.map(|filter| FilterExpression::parse(filter, self.clock.now())).transpose()?;
+        let filter = TaskFilter { expression, ..TaskFilter::default() };
+
+        let mut found = self.task_repository.find_board(&filter, sort, column_size).await?;
+        let workflow = self.workflow(ctx).await?;
+        let catalog = self.status_catalog().await?;
 
-        let task = Task::new(TaskId::new(0), request.name, request.priority)
-            .map_err(UseCaseError::ValidationError)?;
+        let mut columns: Vec<BoardColumn> = workflow.states().iter()
+            .map(|status| match found.iter().position(|column| &column.status == status) {
+                Some(index) => found.remove(index),
+                None => BoardColumn { status: status.clone(), count: 0, tasks: Vec::new() },
+            })
+            .collect();
+        found.sort_by(|a, b| a.status.as_str().cmp(b.status.as_str()));
+        columns.extend(found);
+
+        let columns = columns.into_iter()
+            .map(|column| {
+                let wip_limit = self.wip_limits.limit(ctx.workspace(), &column.status);
+                BoardColumnDto {
+                    category: catalog.category(&column.status),
+                    count: column.count,
+                    wip_limit,
+                    over_limit: wip_limit.is_some_and(|limit| column.count > limit),
+                    tasks: column.tasks.into_iter().map(|task| self.to_dto(task)).collect(),
+                    status: column.status,
+                }
+            })
+            .collect();
+        Ok(BoardDto { columns })
+    }
 
-        let task_id = self.task_repository.save(&task).await?;
+    pub async fn create_task(&self, ctx: &RequestContext, request: CreateTaskRequest) -> Result<i32, UseCaseError> {
+        let _budget = self.latency_budgets.start("create_task", Duration::from_millis(200));
+        self.validate(&request)?;
+        let priority = self.resolve_priority(request.priority);
+
+        let task = Task::new_at(TaskId::new(0), request.name, priority, self.clock.now())
+            .and_then(|task| task.with_description(request.description))?
+            .with_created_by(Some(ctx.user_id.clone()));
+        self.creation_policies.check(&task, self.task_repository.as_task_repository()).await?;
+
+        let creation_entry = self.history_entry(&task, None, ctx);
+        let task_id = self.task_repository.save_with_history(&task, &creation_entry).await?;
+        let task = Task { id: task_id, ..task };
+        self.publish_event(ctx, TaskEvent::TaskCreated(TaskCreated::from_task(&task))).await?;
+        self.publish_change(ctx, TaskChangeKind::Created, task_id.value(), Some(self.to_dto(task)), &[]);
         Ok(task_id.value())
     }
 
-    pub async fn update_task(&self, id: i32, request: UpdateTaskRequest) -> Result<(), UseCaseError> {
+    pub async fn update_task(&self, ctx: &RequestContext, id: i32, request: UpdateTaskRequest) -> Result<TaskUpdated, UseCaseError> {
+        let _budget = self.latency_budgets.start("update_task", Duration::from_millis(200));
         let task_id = TaskId::new(id);
-        let mut task = self.task_repository.find_by_id(task_id).await?
+        let original = self.task_repository.find_by_id(task_id).await?
             .ok_or_else(|| UseCaseError::NotFound(format!("Task with id {} not found", id)))?;
+        self.check_can_modify(ctx, &original, "update")?;
+        let mut task = original.clone();
 
-        self.domain_service.can_update_task(&task, request.name.as_deref(), request.priority)
-            .map_err(UseCaseError::ValidationError)?;
+        self.validate(&request)?;
+        let priority = self.resolve_priority(request.priority);
 
+        let now = self.clock.now();
         if let Some(name) = request.name {
-            task.update_name(name).map_err(UseCaseError::ValidationError)?;
+            task.update_name_at(name, now)?;
         }
 
-        if let Some(priority) = request.priority {
-            task.update_priority(Some(priority)).map_err(UseCaseError::ValidationError)?;
+        if let Some(priority) = priority {
+            task.update_priority_at(Some(priority), now)?;
         }
 
-        self.task_repository.update(&task).await?;
-        Ok(())
+        if let Some(description) = request.description {
+            task.update_description_at(Some(description), now)?;
+        }
+
+        let event = TaskUpdated::between(&original, &task);
+        if !event.is_empty() {
+            self.task_repository.update_fields(task_id, &TaskPatch::between(&original, &task)).await?;
+            self.publish_event(ctx, TaskEvent::TaskUpdated(event.clone())).await?;
+            let fields: Vec<_> = event.changed_fields().iter().map(TaskField::as_str).collect();
+            self.publish_change(ctx, TaskChangeKind::Updated, id, Some(self.to_dto(task)), &fields);
+        }
+        Ok(event)
     }
 
-    pub async fn delete_task(&self, id: i32) -> Result<(), UseCaseError> {
+    pub async fn delete_task(&self, ctx: &RequestContext, id: i32) -> Result<(), UseCaseError> {
+        let _budget = self.latency_budgets.start("delete_task", Duration::from_millis(200));
         let task_id = TaskId::new(id);
         
         // Check if task exists
@@ -110,85 +532,769 @@ impl TaskUseCases {
             .ok_or_else(|| UseCaseError::NotFound(format!("Task with id {} not found", id)))?;
 
         self.task_repository.delete(task_id).await?;
+        self.publish_event(ctx, TaskEvent::TaskDeleted(TaskDeleted { task_id: id, occurred_at: self.clock.now() })).await?;
+        self.publish_change(ctx, TaskChangeKind::Deleted, id, None, &[]);
         Ok(())
     }
 
-    pub async fn update_task_status(&self, id: i32, request: UpdateTaskStatusDto) -> Result<TaskDto, UseCaseError> {
+    pub async fn restore_task(&self, ctx: &RequestContext, id: i32) -> Result<TaskDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("restore_task", Duration::from_millis(200));
+        if !ctx.role.can_manage_deleted_tasks() {
+            return Err(UseCaseError::Forbidden("Only admins can restore deleted tasks".to_string()));
+        }
+        let task = self.to_dto(self.task_repository.restore(TaskId::new(id)).await?);
+        self.publish_event(ctx, TaskEvent::TaskRestored(TaskRestored { task_id: id, occurred_at: self.clock.now() })).await?;
+        self.publish_change(ctx, TaskChangeKind::Restored, id, Some(task.clone()), &[]);
+        Ok(task)
+    }
+
+    pub async fn update_task_status(&self, ctx: &RequestContext, id: i32, request: UpdateTaskStatusDto) -> Result<TaskDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("update_task_status", Duration::from_millis(200));
         let task_id = TaskId::new(id);
         let mut task = self.task_repository.find_by_id(task_id).await?
             .ok_or_else(|| UseCaseError::NotFound(format!("Task with id {} not found", id)))?;
 
-        // For now, default to User role. TODO: Extract from JWT token
-        let user_role = UserRole::User;
+        if request.status == TaskStatus::Cancelled {
+            self.check_can_modify(ctx, &task, "cancel")?;
+        }
+
+        let user_role = &ctx.role;
+        let workflow = self.workflow(ctx).await?;
+        let catalog = self.status_catalog().await?;
+        if !catalog.contains(&request.status) {
+            return Err(UseCaseError::ValidationError(format!("Unknown task status: {}", request.status.as_str())));
+        }
+
+        // Approving a task in review signs off the current stage of its workspace's review chain
+        let is_approval = task.status() == &TaskStatus::PendingReview && request.status == TaskStatus::Completed;
+        let review = match is_approval {
+            true => Some(self.review_progress(self.review_chains.for_workspace(ctx.workspace()), &task).await?),
+            false => None,
+        };
+        let signs_off_earlier_stage = review.as_ref().is_some_and(|progress| !progress.is_final());
+
+        // Validate the transition using the status service; earlier stages only answer to their chain
+        if !signs_off_earlier_stage {
+            self.status_service.can_transition_task(&workflow, &task, &request.status, user_role)?;
+        }
+        if let Some(progress) = &review {
+            progress.check_sign_off(&ctx.user_id, user_role)?;
+        }
+
+        let blockers = self.unfinished_blockers(&catalog, task_id).await?;
+        self.status_service.check_blockers_in(&catalog, &request.status, &blockers)?;
+
+        let comment = request.comment
+            .as_deref()
+            .map(str::trim)
+            .filter(|comment| !comment.is_empty())
+            .map(str::to_string);
+        if comment.is_none() && workflow.requires_comment(task.status(), &request.status) {
+            return Err(DomainError::CommentRequired { from: task.status().clone(), to: request.status }.into());
+        }
 
-        // Validate the transition using the status service
-        self.status_service.can_transition(
-            task.status(),
-            &request.status,
-            task.is_high_priority(),
-            &user_role,
-        ).map_err(UseCaseError::ValidationError)?;
+        let is_reopening = task.status() == &TaskStatus::Completed && request.status == TaskStatus::InProgress;
+        if is_reopening {
+            let completed_at = self.completed_at(&task).await?;
+            self.reopen_policy.check(completed_at, self.clock.now())?;
+        }
+
+        if is_approval && !signs_off_earlier_stage {
+            self.review_checklist.validate(request.checklist.as_deref())?;
+        } else if request.checklist.is_some() {
+            return Err(DomainError::ChecklistNotExpected.into());
+        }
+
+        let review_stage = review.as_ref().map(|progress| progress.current_stage().name.clone());
+        if signs_off_earlier_stage {
+            // The task stays in PendingReview; only its history records the sign-off, as an entry
+            // from PendingReview to PendingReview that analytics skip like any non-transition
+            let history = StatusHistory { changed_at: self.clock.now(), ..self.history_entry(&task, Some(TaskStatus::PendingReview), ctx) }
+                .with_comment(comment)
+                .with_review_stage(review_stage);
+            self.status_history_repository.save(&history).await?;
+            return Ok(self.to_dto(task));
+        }
+
+        // Only changes that pass every local rule are sent to an external approval system
+        self.status_service.check_gate(&GatedTransition {
+            task_id: id,
+            task_name: task.name.clone(),
+            from: task.status().clone(),
+            to: request.status.clone(),
+            requested_by: ctx.user_id.clone(),
+            user_role: user_role.clone(),
+            comment: comment.clone(),
+        }).await?;
 
         // Apply the status transition with role validation
-        task.transition_to_with_role(request.status, &user_role).map_err(UseCaseError::ValidationError)?;
+        let from_status = task.status().clone();
+        let is_high_priority = self.status_service.is_high_priority(&task);
+        task.transition_in_workflow_at(request.status, user_role, &workflow, is_high_priority, self.clock.now())?;
+
+        // Save the updated task together with its history entry
+        let history = self.history_entry(&task, Some(from_status), ctx)
+            .with_comment(comment)
+            .with_checklist(request.checklist)
+            .with_review_stage(review_stage);
+        self.task_repository.update_with_history(&task, &history).await?;
+        self.publish_event(ctx, TaskEvent::TaskStatusChanged(status_changed(&history))).await?;
+
+        let task = self.to_dto(task);
+        self.publish_change(ctx, TaskChangeKind::StatusChanged, id, Some(task.clone()), &["status"]);
+        Ok(task)
+    }
+
+    /// Pending tasks the stale task policy would cancel at `now`; empty when the policy is off
+    pub async fn find_stale_tasks(&self, _ctx: &RequestContext, now: DateTime<Utc>) -> Result<Vec<TaskDto>, UseCaseError> {
+        let _budget = self.latency_budgets.start("find_stale_tasks", Duration::from_millis(500));
+        let tasks = self.stale_tasks(now).await?;
+        Ok(tasks.into_iter().map(|task| self.to_dto(task)).collect())
+    }
+
+    /// Cancels every stale Pending task, recording the policy's comment in its history
+    pub async fn cancel_stale_tasks(&self, ctx: &RequestContext, now: DateTime<Utc>) -> Result<Vec<TaskDto>, UseCaseError> {
+        let _budget = self.latency_budgets.start("cancel_stale_tasks", Duration::from_millis(5000));
+        let Some(policy) = self.stale_task_policy else {
+            return Ok(Vec::new());
+        };
+
+        let mut cancelled = Vec::new();
+        for mut task in self.stale_tasks(now).await? {
+            task.cancel_at(now)?;
+            let history = self.history_entry(&task, Some(TaskStatus::Pending), ctx)
+                .with_comment(Some(policy.cancellation_comment()));
+            self.task_repository.update_with_history(&task, &history).await?;
+            self.publish_event(ctx, TaskEvent::TaskStatusChanged(status_changed(&history))).await?;
+            let task = self.to_dto(task);
+            self.publish_change(ctx, TaskChangeKind::StatusChanged, task.id, Some(task.clone()), &["status"]);
+            cancelled.push(task);
+        }
+        Ok(cancelled)
+    }
+
+    async fn stale_tasks(&self, now: DateTime<Utc>) -> Result<Vec<Task>, UseCaseError> {
+        let Some(policy) = self.stale_task_policy else {
+            return Ok(Vec::new());
+        };
+
+        let tasks = self.task_repository.find_by_status(&TaskStatus::Pending).await?;
+        Ok(tasks.into_iter().filter(|task| policy.is_stale(task, now)).collect())
+    }
+
+    /// Archives a completed or cancelled task, taking it out of the default listings
+    pub async fn archive_task(&self, ctx: &RequestContext, id: i32) -> Result<TaskDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("archive_task", Duration::from_millis(200));
+        let mut task = self.task_repository.find_by_id(TaskId::new(id)).await?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Task with id {} not found", id)))?;
+        self.check_can_modify(ctx, &task, "archive")?;
 
-        // Save the updated task
+        let now = self.clock.now();
+        task.archive_at(now)?;
         self.task_repository.update(&task).await?;
-        
-        Ok(TaskDto::from(task))
+        self.publish_event(ctx, TaskEvent::TaskArchived(TaskArchived { task_id: id, occurred_at: now })).await?;
+
+        let task = self.to_dto(task);
+        self.publish_change(ctx, TaskChangeKind::Archived, id, Some(task.clone()), &["archived_at"]);
+        Ok(task)
+    }
+
+    /// Moves a task between neighbours in its board column, writing only the task's rank
+    pub async fn move_task(&self, ctx: &RequestContext, id: i32, request: MoveTaskRequest) -> Result<TaskPositionDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("move_task", Duration::from_millis(200));
+        if request.after_task_id.is_none() && request.before_task_id.is_none() {
+            return Err(UseCaseError::ValidationError("Give after_task_id, before_task_id or both".to_string()));
+        }
+        if [request.after_task_id, request.before_task_id].contains(&Some(id)) {
+            return Err(UseCaseError::ValidationError("A task cannot be placed next to itself".to_string()));
+        }
+        let mut task = self.task_repository.find_by_id(TaskId::new(id)).await?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Task with id {} not found", id)))?;
+        self.check_can_modify(ctx, &task, "move")?;
+        let previous = self.neighbour(request.after_task_id).await?;
+        let next = self.neighbour(request.before_task_id).await?;
+
+        task.move_between(previous.as_ref(), next.as_ref())?;
+        let rank = task.rank();
+        self.task_repository.set_rank(task.id, &rank).await?;
+
+        let position = TaskPositionDto { task_id: id, status: task.status.clone(), rank: rank.as_str().to_string() };
+        self.publish_change(ctx, TaskChangeKind::Updated, id, Some(self.to_dto(task)), &["rank"]);
+        Ok(position)
+    }
+
+    async fn neighbour(&self, id: Option<i32>) -> Result<Option<Task>, UseCaseError> {
+        let Some(id) = id else {
+            return Ok(None);
+        };
+        match self.task_repository.find_by_id(TaskId::new(id)).await? {
+            Some(task) if !task.is_archived() => Ok(Some(task)),
+            _ => Err(UseCaseError::NotFound(format!("Task with id {} not found", id))),
+        }
+    }
+
+    /// Archives every task the auto-archive policy finds due at `now`; nothing when the policy is off
+    pub async fn archive_completed_tasks(&self, ctx: &RequestContext, now: DateTime<Utc>) -> Result<Vec<TaskDto>, UseCaseError> {
+        let _budget = self.latency_budgets.start("archive_completed_tasks", Duration::from_millis(5000));
+        let Some(policy) = self.auto_archive_policy else {
+            return Ok(Vec::new());
+        };
+
+        let filter = TaskFilter { status: Some(TaskStatus::Completed), ..TaskFilter::default() };
+        let mut archived = Vec::new();
+        for mut task in self.task_repository.find_matching(&filter, TaskSort::default()).await? {
+            let completed_at = self.completed_at(&task).await?;
+            if !policy.is_due(&task, completed_at, now) {
+                continue;
+            }
+            task.archive_at(now)?;
+            self.task_repository.update(&task).await?;
+            self.publish_event(ctx, TaskEvent::TaskArchived(TaskArchived { task_id: task.id.value(), occurred_at: now })).await?;
+            let task = self.to_dto(task);
+            self.publish_change(ctx, TaskChangeKind::Archived, task.id, Some(task.clone()), &["archived_at"]);
+            archived.push(task);
+        }
+        Ok(archived)
+    }
+
+    /// Escalates every task the escalation policy finds stuck at `now`, recording each escalation
+    /// in the task's history; nothing when the policy is off
+    pub async fn escalate_stuck_tasks(&self, ctx: &RequestContext, now: DateTime<Utc>) -> Result<Vec<TaskDto>, UseCaseError> {
+        let _budget = self.latency_budgets.start("escalate_stuck_tasks", Duration::from_millis(5000));
+        let Some(policy) = &self.escalation_policy else {
+            return Ok(Vec::new());
+        };
+
+        let scale = self.domain_service.priority_scale();
+        let mut escalated = Vec::new();
+        for rule in policy.rules() {
+            for mut task in self.task_repository.find_by_status(&rule.status).await? {
+                // Escalations are history entries too, so a task escalates again only after another full threshold
+                let last_recorded_at = self.status_history_repository.find_latest_by_task_id(task.id.value()).await?
+                    .map_or(task.updated_at, |entry| entry.changed_at);
+                if !rule.is_due(&task, last_recorded_at, now) {
+                    continue;
+                }
+
+                // The task stays in its status; only its history records the escalation
+                let entry = StatusHistory { changed_at: now, ..self.history_entry(&task, Some(rule.status.clone()), ctx) };
+                match rule.action {
+                    EscalationAction::Comment => {
+                        self.status_history_repository.save(&entry.with_comment(Some(rule.comment(None)))).await?;
+                    }
+                    EscalationAction::BumpPriority => {
+                        let from = task.priority;
+                        let to = rule.bumped_priority(from, &scale);
+                        if from != Some(to) {
+                            // One task the domain refuses to change must not hold up the rest of the sweep
+                            if let Err(error) = task.update_priority_at(Some(to), now) {
+                                tracing::warn!(task_id = task.id.value(), "Skipping escalation of task {}: {}", task.id.value(), error);
+                                continue;
+                            }
+                        }
+                        self.task_repository.update_with_history(&task, &entry.with_comment(Some(rule.comment(Some((from, to)))))).await?;
+                        if from != Some(to) {
+                            let event = TaskUpdated {
+                                task_id: task.id.value(),
+                                changes: vec![FieldChange {
+                                    field: TaskField::Priority,
+                                    old_value: from.map(|priority| priority.to_string()),
+                                    new_value: Some(to.to_string()),
+                                }],
+                                occurred_at: now,
+                            };
+                            self.publish_event(ctx, TaskEvent::TaskUpdated(event)).await?;
+                            self.publish_change(ctx, TaskChangeKind::Updated, task.id.value(), Some(self.to_dto(task.clone())), &[TaskField::Priority.as_str()]);
+                        }
+                    }
+                }
+                escalated.push(self.to_dto(task));
+            }
+        }
+        Ok(escalated)
+    }
+
+    /// Current open, stale and awaiting-review task counts
+    pub async fn get_analytics_gauges(&self, _ctx: &RequestContext, now: DateTime<Utc>) -> Result<AnalyticsGaugesDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_analytics_gauges", Duration::from_millis(1000));
+        let mut open_tasks_by_status = BTreeMap::new();
+        for status in [TaskStatus::Pending, TaskStatus::InProgress, TaskStatus::PendingReview] {
+            let count = self.task_repository.find_by_status(&status).await?.len();
+            open_tasks_by_status.insert(status.as_str().to_string(), count);
+        }
+
+        Ok(AnalyticsGaugesDto {
+            open_tasks: open_tasks_by_status.values().sum(),
+            approval_backlog: open_tasks_by_status[TaskStatus::PendingReview.as_str()],
+            stale_tasks: self.stale_tasks(now).await?.len(),
+            open_tasks_by_status,
+        })
+    }
+
+    /// When a completed task was completed, falling back to its last update without history
+    async fn completed_at(&self, task: &Task) -> Result<DateTime<Utc>, UseCaseError> {
+        let latest = self.status_history_repository.find_latest_by_task_id(task.id.value()).await?;
+        Ok(latest
+            .filter(|entry| entry.is_completion())
+            .map_or(task.updated_at, |entry| entry.changed_at))
+    }
+
+    /// How far a task in PendingReview has come through `chain`; a single approval needs no history
+    async fn review_progress<'a>(&self, chain: &'a ReviewChain, task: &Task) -> Result<ReviewProgress<'a>, UseCaseError> {
+        if chain.stages().len() == 1 {
+            return Ok(chain.progress(&[]));
+        }
+        let history = self.status_history_repository.find_by_task_id(task.id.value()).await?;
+        Ok(chain.progress(&history))
+    }
+
+    fn check_can_modify(&self, ctx: &RequestContext, task: &Task, action: &str) -> Result<(), UseCaseError> {
+        if self.domain_service.can_modify_task(task, &ctx.user_id, &ctx.role) {
+            return Ok(());
+        }
+        Err(UseCaseError::Forbidden(format!(
+            "Only the task's creator, a manager or an admin can {} task {}",
+            action,
+            task.id.value()
+        )))
+    }
+
+    /// The workflow of the caller's workspace: the one an admin stored, else the configured one
+    async fn workflow(&self, ctx: &RequestContext) -> Result<WorkflowDefinition, UseCaseError> {
+        let stored = match &self.workflow_repository {
+            Some(repository) => repository.find_by_workspace(ctx.workspace()).await?,
+            None => None,
+        };
+        Ok(stored.unwrap_or_else(|| self.workflows.for_workspace(ctx.workspace()).clone()))
     }
 
-    pub async fn get_task_with_transitions(&self, id: i32) -> Result<TaskWithTransitionsDto, UseCaseError> {
+    /// Parses a status a caller filters by, which must be built in or defined by an admin
+    pub async fn known_status(&self, name: &str) -> Result<TaskStatus, UseCaseError> {
+        let unknown = || UseCaseError::ValidationError(format!("Invalid task status: {}", name));
+        let status = TaskStatus::parse(name).map_err(|_| unknown())?;
+        match self.status_catalog().await?.contains(&status) {
+            true => Ok(status),
+            false => Err(unknown()),
+        }
+    }
+
+    /// The built-in statuses plus those admins defined
+    async fn status_catalog(&self) -> Result<StatusCatalog, UseCaseError> {
+        Ok(match &self.custom_status_repository {
+            Some(repository) => StatusCatalog::new(repository.find_all().await?),
+            None => StatusCatalog::default(),
+        })
+    }
+
+    fn history_entry(&self, task: &Task, from_status: Option<TaskStatus>, ctx: &RequestContext) -> StatusHistory {
+        StatusHistory::new(
+            self.id_generator.next_id().to_string(),
+            task.id.value(),
+            from_status,
+            task.status().clone(),
+            task.updated_at,
+            ctx.user_id.clone(),
+            None,
+            ctx.role.clone(),
+        )
+    }
+
+    pub async fn get_task_with_transitions(&self, ctx: &RequestContext, id: i32) -> Result<TaskWithTransitionsDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_task_with_transitions", Duration::from_millis(100));
         let task_id = TaskId::new(id);
         let task = self.task_repository.find_by_id(task_id).await?
             .ok_or_else(|| UseCaseError::NotFound(format!("Task with id {} not found", id)))?;
 
-        // For now, default to User role. TODO: Extract from JWT token
-        let user_role = UserRole::User;
+        // Use the status service to get valid transitions based on business rules; archived tasks are frozen
+        let workflow = self.workflow(ctx).await?;
+        let catalog = self.status_catalog().await?;
+        let mut valid_transitions = self.status_service.get_valid_transitions_for_task(&workflow, &task, &ctx.role);
+        valid_transitions.retain(|status| catalog.contains(status));
+        if task.is_archived() {
+            valid_transitions.clear();
+        }
+
+        // History only refines the reopening window, so the task is still served when it cannot be read
+        let mut history_unavailable = false;
+        if task.status() == &TaskStatus::Completed && valid_transitions.contains(&TaskStatus::InProgress) {
+            let completed_at = match self.completed_at(&task).await {
+                Ok(completed_at) => completed_at,
+                Err(error) => {
+                    tracing::warn!(task_id = id, "Status history unavailable for task {}: {}", id, error);
+                    history_unavailable = true;
+                    task.updated_at
+                }
+            };
+            if !self.reopen_policy.allows(completed_at, self.clock.now()) {
+                valid_transitions.retain(|status| status != &TaskStatus::InProgress);
+            }
+        }
 
-        // Use the status service to get valid transitions based on business rules
-        let valid_transitions = self.status_service.get_valid_transitions(
-            task.status(),
-            task.is_high_priority(),
-            &user_role,
-        );
+        // Whether the caller may complete a task in review depends on the stage it has reached
+        let mut review = None;
+        if task.status() == &TaskStatus::PendingReview && !task.is_archived() {
+            let chain = self.review_chains.for_workspace(ctx.workspace());
+            let progress = match self.review_progress(chain, &task).await {
+                Ok(progress) => progress,
+                Err(error) => {
+                    tracing::warn!(task_id = id, "Status history unavailable for task {}: {}", id, error);
+                    history_unavailable = true;
+                    chain.progress(&[])
+                }
+            };
+            let may_sign_off = progress.check_sign_off(&ctx.user_id, &ctx.role).is_ok()
+                && (!progress.is_final() || valid_transitions.contains(&TaskStatus::Completed));
+            valid_transitions.retain(|status| status != &TaskStatus::Completed);
+            if may_sign_off {
+                let position = valid_transitions.iter().position(|status| status == &TaskStatus::Cancelled).unwrap_or(valid_transitions.len());
+                valid_transitions.insert(position, TaskStatus::Completed);
+            }
+            review = Some(ReviewProgressDto::from(&progress));
+        }
+
+        let blocked_by = self.unfinished_blockers(&catalog, task_id).await?;
+        valid_transitions.retain(|status| self.status_service.check_blockers_in(&catalog, status, &blocked_by).is_ok());
 
         Ok(TaskWithTransitionsDto {
-            task: TaskDto::from(task),
+            task: self.to_dto(task),
             valid_transitions,
+            blocked_by: blocked_by.into_iter().map(|id| id.value()).collect(),
+            history_unavailable,
+            review,
+        })
+    }
+
+    /// Changes the priority of every matching task in one write, auditing each task's change.
+    ///
+    /// A dry run reports the same counts from the current tasks without writing anything.
+    pub async fn reprioritize_tasks(&self, ctx: &RequestContext, request: ReprioritizeRequest) -> Result<ReprioritizeResultDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("reprioritize_tasks", Duration::from_millis(2000));
+        if !ctx.role.can_bulk_edit_tasks() {
+            return Err(UseCaseError::Forbidden("Only admins can reprioritize tasks in bulk".to_string()));
+        }
+        let mapping = match (request.shift, request.mapping) {
+            (Some(by), None) => PriorityMapping::Shift(by),
+            (None, Some(targets)) => PriorityMapping::Explicit(targets),
+            _ => return Err(UseCaseError::ValidationError("Give either shift or mapping".to_string())),
+        };
+        let scale = self.domain_service.priority_scale();
+        mapping.validate(&scale)?;
+        let pairs = mapping.pairs(&scale);
+        let filter = TaskFilter { priority: request.priority, status: request.status, include_deleted: false, archived: false, expression: None };
+
+        let changes = if request.dry_run {
+            self.task_repository.find_matching(&filter, TaskSort::default()).await?
+                .into_iter()
+                .filter_map(|task| {
+                    let from = task.priority?;
+                    pairs.iter().find(|(pair_from, _)| *pair_from == from).map(|&(from, to)| PriorityChange { task_id: task.id, from, to })
+                })
+                .collect()
+        } else if pairs.is_empty() {
+            Vec::new()
+        } else {
+            let now = self.clock.now();
+            let changes = self.task_repository.reprioritize(&filter, &pairs, now).await?;
+            for change in &changes {
+                let event = TaskUpdated {
+                    task_id: change.task_id.value(),
+                    changes: vec![FieldChange {
+                        field: TaskField::Priority,
+                        old_value: Some(change.from.to_string()),
+                        new_value: Some(change.to.to_string()),
+                    }],
+                    occurred_at: now,
+                };
+                self.publish_event(ctx, TaskEvent::TaskUpdated(event)).await?;
+                if self.change_feed.is_some() {
+                    let task = self.task_repository.find_by_id(change.task_id).await?;
+                    self.publish_change(ctx, TaskChangeKind::Updated, change.task_id.value(), task.map(|task| self.to_dto(task)), &[TaskField::Priority.as_str()]);
+                }
+            }
+            changes
+        };
+
+        let mut moves: BTreeMap<(i32, i32), usize> = BTreeMap::new();
+        for change in &changes {
+            *moves.entry((change.from, change.to)).or_default() += 1;
+        }
+        Ok(ReprioritizeResultDto {
+            dry_run: request.dry_run,
+            affected: changes.len(),
+            moves: moves.into_iter().map(|((from, to), count)| PriorityMoveDto { from, to, count }).collect(),
+            task_ids: changes.iter().map(|change| change.task_id.value()).collect(),
+        })
+    }
+
+    /// Soft-deletes every matching task once the caller confirms.
+    ///
+    /// Without a token this only previews the deletion and issues a token bound to the caller
+    /// and the exact tasks matched. The token is refused once it expires or when the tasks
+    /// matching the filter have changed since the preview.
+    pub async fn bulk_delete_tasks(&self, ctx: &RequestContext, request: BulkDeleteRequest) -> Result<BulkDeleteResultDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("bulk_delete_tasks", Duration::from_millis(2000));
+        if !ctx.role.can_bulk_edit_tasks() {
+            return Err(UseCaseError::Forbidden("Only admins can delete tasks in bulk".to_string()));
+        }
+        let filter = TaskFilter { priority: request.priority, status: request.status, include_deleted: false, archived: false, expression: None };
+        let tasks = self.task_repository.find_matching(&filter, TaskSort::default()).await?;
+        let ids: Vec<String> = tasks.iter().map(|task| task.id.value().to_string()).collect();
+        let subject = format!("bulk-delete\n{}\n{}", ctx.user_id, ids.join(","));
+        let sample_names = tasks.iter().take(BULK_DELETE_SAMPLE_SIZE).map(|task| task.name.clone()).collect();
+        let now = self.clock.now();
+
+        let Some(token) = request.confirmation_token else {
+            let (token, expires_at) = self.confirmation_key.issue(&subject, now);
+            return Ok(BulkDeleteResultDto {
+                deleted: false,
+                count: tasks.len(),
+                sample_names,
+                confirmation_token: Some(token),
+                expires_at: Some(expires_at),
+            });
+        };
+        self.confirmation_key.verify(&token, &subject, now).map_err(UseCaseError::ValidationError)?;
+
+        for task in &tasks {
+            self.task_repository.delete(task.id).await?;
+            self.publish_event(ctx, TaskEvent::TaskDeleted(TaskDeleted { task_id: task.id.value(), occurred_at: now })).await?;
+            self.publish_change(ctx, TaskChangeKind::Deleted, task.id.value(), None, &[]);
+        }
+        Ok(BulkDeleteResultDto { deleted: true, count: tasks.len(), sample_names, confirmation_token: None, expires_at: None })
+    }
+
+    /// Creates a task for every card or issue in another tracker's export.
+    ///
+    /// Each task keeps its original creation date in its history. A task whose mapped status
+    /// is not Pending gets a second entry moving it there, dated when the card or issue last
+    /// changed. Rows that cannot be read or mapped are skipped and reported in the summary;
+    /// a dry run reports the same summary without writing anything.
+    pub async fn import_tasks(&self, ctx: &RequestContext, parser: &dyn TaskImportParser, request: ImportTasksRequest) -> Result<ImportSummaryDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("import_tasks", Duration::from_millis(10000));
+        if !ctx.role.can_bulk_edit_tasks() {
+            return Err(UseCaseError::Forbidden("Only admins can import tasks".to_string()));
+        }
+        let parsed = parser.parse(&request.data)
+            .map_err(|error| UseCaseError::ValidationError(format!("Could not read the {} export: {}", parser.source().as_str(), error)))?;
+        let mapping = request.status_mapping
+            .into_iter()
+            .fold(StatusMapping::default(), |mapping, entry| mapping.with(&entry.from, entry.to));
+
+        let total_rows = parsed.tasks.len() + parsed.skipped.len();
+        let mut skipped = parsed.skipped;
+        let mut imported = Vec::new();
+        for record in parsed.tasks {
+            match self.imported_task(&record, &mapping) {
+                Ok(task) => imported.push((record, task)),
+                Err(reason) => skipped.push(SkippedRow { row: record.row, external_id: Some(record.external_id), reason }),
+            }
+        }
+        skipped.sort_by_key(|row| row.row);
+
+        let mut task_ids = Vec::new();
+        if !request.dry_run {
+            for (record, task) in &imported {
+                task_ids.push(self.save_imported_task(ctx, parser, record, task.clone()).await?);
+            }
+        }
+
+        let statuses = TaskStatus::ALL
+            .into_iter()
+            .map(|status| {
+                let count = imported.iter().filter(|(_, task)| task.status == status).count();
+                ImportedStatusDto { status, count }
+            })
+            .filter(|status| status.count > 0)
+            .collect();
+        Ok(ImportSummaryDto {
+            source: parser.source(),
+            dry_run: request.dry_run,
+            total_rows,
+            imported: imported.len(),
+            statuses,
+            task_ids,
+            skipped: skipped.into_iter().map(SkippedRowDto::from).collect(),
+        })
+    }
+
+    /// The task an imported row becomes, or why the row is skipped
+    fn imported_task(&self, record: &ImportedTask, mapping: &StatusMapping) -> Result<Task, String> {
+        let status = mapping.status_for(&record.source_status)
+            .ok_or_else(|| format!("No status is mapped to '{}'", record.source_status))?;
+        self.domain_service.validate_task_name(&record.name).map_err(|error| error.to_string())?;
+        self.domain_service.validate_description(record.description.as_deref()).map_err(|error| error.to_string())?;
+
+        let created_at = record.created_at.unwrap_or_else(|| self.clock.now());
+        let priority = record.priority.map(|label| self.domain_service.priority_labels().value_of(label));
+        let task = Task::new_with_status(TaskId::new(0), record.name.clone(), priority, status, created_at, created_at)
+            .and_then(|task| task.with_description(record.description.clone()))
+            .map_err(|error| error.to_string())?;
+        Ok(task)
+    }
+
+    async fn save_imported_task(&self, ctx: &RequestContext, parser: &dyn TaskImportParser, record: &ImportedTask, task: Task) -> Result<i32, UseCaseError> {
+        let comment = format!("Imported from {} {}", parser.source().as_str(), record.external_id);
+        let status = task.status.clone();
+        let pending = Task { status: TaskStatus::Pending, created_by: Some(ctx.user_id.clone()), ..task };
+        let creation_entry = self.history_entry(&pending, None, ctx).with_comment(Some(comment.clone()));
+        let task_id = self.task_repository.save_with_history(&pending, &creation_entry).await?;
+        let mut task = Task { id: task_id, ..pending };
+        self.publish_event(ctx, TaskEvent::TaskCreated(TaskCreated::from_task(&task))).await?;
+
+        if status != TaskStatus::Pending {
+            let changed_at = record.status_changed_at
+                .unwrap_or_else(|| self.clock.now())
+                .max(task.created_at);
+            task.status = status;
+            task.updated_at = changed_at;
+            let entry = self.history_entry(&task, Some(TaskStatus::Pending), ctx).with_comment(Some(comment));
+            self.task_repository.update_with_history(&task, &entry).await?;
+            self.publish_event(ctx, TaskEvent::TaskStatusChanged(status_changed(&entry))).await?;
+        }
+
+        self.publish_change(ctx, TaskChangeKind::Created, task_id.value(), Some(self.to_dto(task)), &[]);
+        Ok(task_id.value())
+    }
+
+    /// Saves a Pending copy of `source` in the current workspace, credited to `created_by`.
+    ///
+    /// Like imports, copies skip the creation policies; `comment` goes on the copy's first history entry.
+    pub async fn clone_task(&self, ctx: &RequestContext, source: &TaskDto, created_by: Option<String>, comment: String) -> Result<i32, UseCaseError> {
+        let _budget = self.latency_budgets.start("clone_task", Duration::from_millis(200));
+        let task = Task::new_at(TaskId::new(0), source.name.clone(), source.priority, self.clock.now())?
+            .with_description(source.description.clone())?
+            .with_created_by(created_by);
+        let entry = self.history_entry(&task, None, ctx).with_comment(Some(comment));
+        let task_id = self.task_repository.save_with_history(&task, &entry).await?;
+        let task = Task { id: task_id, ..task };
+        self.publish_event(ctx, TaskEvent::TaskCreated(TaskCreated::from_task(&task))).await?;
+        self.publish_change(ctx, TaskChangeKind::Created, task_id.value(), Some(self.to_dto(task)), &[]);
+        Ok(task_id.value())
+    }
+
+    /// Makes `id` wait for `request.depends_on` before it can start or complete
+    pub async fn add_dependency(&self, ctx: &RequestContext, id: i32, request: AddDependencyRequest) -> Result<TaskDependenciesDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("add_dependency", Duration::from_millis(200));
+        let task_id = TaskId::new(id);
+        let depends_on = TaskId::new(request.depends_on);
+        if task_id == depends_on {
+            return Err(DomainError::SelfDependency.into());
+        }
+        for dependency_id in [task_id, depends_on] {
+            let task = self.task_repository.find_by_id(dependency_id).await?
+                .ok_or_else(|| UseCaseError::NotFound(format!("Task with id {} not found", dependency_id.value())))?;
+            // Only the waiting task changes; the task it waits for may belong to anyone
+            if dependency_id == task_id {
+                self.check_can_modify(ctx, &task, "add a dependency to")?;
+            }
+        }
+        if self.depends_on(depends_on, task_id).await? {
+            return Err(DomainError::DependencyCycle { task_id: id, depends_on: depends_on.value() }.into());
+        }
+
+        self.task_dependency_repository.add(task_id, depends_on).await?;
+        self.dependencies_dto(task_id).await
+    }
+
+    pub async fn remove_dependency(&self, ctx: &RequestContext, id: i32, depends_on: i32) -> Result<TaskDependenciesDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("remove_dependency", Duration::from_millis(200));
+        let task_id = TaskId::new(id);
+        let task = self.task_repository.find_by_id(task_id).await?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Task with id {} not found", id)))?;
+        self.check_can_modify(ctx, &task, "remove a dependency from")?;
+        self.task_dependency_repository.remove(task_id, TaskId::new(depends_on)).await?;
+        self.dependencies_dto(task_id).await
+    }
+
+    async fn dependencies_dto(&self, task_id: TaskId) -> Result<TaskDependenciesDto, UseCaseError> {
+        let depends_on = self.task_dependency_repository.find_dependencies(task_id).await?;
+        let blocked_by = self.unfinished_blockers(&self.status_catalog().await?, task_id).await?;
+        Ok(TaskDependenciesDto {
+            task_id: task_id.value(),
+            depends_on: depends_on.into_iter().map(|id| id.value()).collect(),
+            blocked_by: blocked_by.into_iter().map(|id| id.value()).collect(),
         })
     }
 
-    pub async fn get_task_history(&self, id: i32) -> Result<TaskHistoryDto, UseCaseError> {
+    /// Dependencies of `task_id` that are neither finished, as `catalog` sees it, nor deleted
+    async fn unfinished_blockers(&self, catalog: &StatusCatalog, task_id: TaskId) -> Result<Vec<TaskId>, UseCaseError> {
+        let mut blockers = Vec::new();
+        for dependency_id in self.task_dependency_repository.find_dependencies(task_id).await? {
+            let dependency = self.task_repository.find_by_id(dependency_id).await?;
+            if dependency.is_some_and(|dependency| !catalog.is_finished(dependency.status())) {
+                blockers.push(dependency_id);
+            }
+        }
+        Ok(blockers)
+    }
+
+    /// Whether `task_id` depends on `target`, directly or through other tasks
+    async fn depends_on(&self, task_id: TaskId, target: TaskId) -> Result<bool, UseCaseError> {
+        let mut visited = HashSet::new();
+        let mut pending = vec![task_id];
+        while let Some(current) = pending.pop() {
+            if !visited.insert(current.value()) {
+                continue;
+            }
+            for dependency_id in self.task_dependency_repository.find_dependencies(current).await? {
+                if dependency_id == target {
+                    return Ok(true);
+                }
+                pending.push(dependency_id);
+            }
+        }
+        Ok(false)
+    }
+
+    /// One page of a task's history, after `cursor` when given; the analytics always cover the full history
+    pub async fn get_task_history(
+        &self,
+        ctx: &RequestContext,
+        id: i32,
+        filter: HistoryFilter,
+        order: SortOrder,
+        page: Pagination,
+        cursor: Option<&str>,
+    ) -> Result<TaskHistoryDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_task_history", Duration::from_millis(200));
         let task_id = TaskId::new(id);
+        let filter = HistoryFilter { task_id: Some(id), ..filter };
+        filter.validate().map_err(UseCaseError::ValidationError)?;
         
         // Verify task exists
         let _task = self.task_repository.find_by_id(task_id).await?
             .ok_or_else(|| UseCaseError::NotFound(format!("Task with id {} not found", id)))?;
 
-        let histories = self.status_history_repository.find_by_task_id(id).await?;
-        let history_dtos: Vec<StatusHistoryDto> = histories.iter().cloned().map(StatusHistoryDto::from).collect();
+        let (histories, next_cursor) = self.history_page(ctx, &filter, order, &page, cursor).await?;
+        let history_dtos: Vec<StatusHistoryDto> = histories.into_iter().map(StatusHistoryDto::from).collect();
+        let total_entries = self.status_history_repository.count_matching(&filter).await?;
 
         // Calculate basic analytics
         let analytics = self.status_history_repository.get_task_analytics(id).await?;
         let (total_time_in_progress, number_of_transitions) = if let Some(analytics) = analytics {
             (
-                analytics.total_time_in_progress.map(|d| crate::application::dto::format_duration(d)),
+                analytics.total_time_in_progress.map(crate::application::dto::format_duration),
                 analytics.number_of_transitions
             )
         } else {
-            (None, history_dtos.len())
+            let all_entries = HistoryFilter { task_id: Some(id), ..HistoryFilter::default() };
+            (None, self.status_history_repository.count_matching(&all_entries).await?)
         };
 
         Ok(TaskHistoryDto {
             task_id: id,
             history: history_dtos,
+            total_entries,
+            limit: page.limit,
+            offset: page.offset,
+            next_cursor,
             total_time_in_progress,
             number_of_transitions,
         })
     }
 
-    pub async fn get_task_analytics(&self, id: i32) -> Result<TaskAnalyticsDto, UseCaseError> {
+    pub async fn get_task_analytics(&self, _ctx: &RequestContext, id: i32) -> Result<TaskAnalyticsDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_task_analytics", Duration::from_millis(200));
         let task_id = TaskId::new(id);
         
         // Verify task exists
@@ -201,13 +1307,137 @@ impl TaskUseCases {
         Ok(TaskAnalyticsDto::from(analytics))
     }
 
+    /// Transitions across all tasks, including deleted ones, after `cursor` when given.
+    ///
+    /// Without dates, the listing covers the 30 days up to now.
+    pub async fn list_history(
+        &self,
+        ctx: &RequestContext,
+        filter: HistoryFilter,
+        order: SortOrder,
+        page: Pagination,
+        cursor: Option<&str>,
+    ) -> Result<HistoryListDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("list_history", Duration::from_millis(300));
+        if !ctx.role.has_elevated_permissions() {
+            return Err(UseCaseError::Forbidden("Only managers and admins can review history across tasks".to_string()));
+        }
+        // Cursors are tied to the dates asked for, so a defaulted window does not invalidate them as it moves
+        let fingerprint = filter.fingerprint(order);
+        let end_date = filter.end_date.unwrap_or_else(|| self.clock.now());
+        let start_date = filter.start_date.unwrap_or_else(|| end_date - chrono::Duration::days(30));
+        let filter = HistoryFilter { start_date: Some(start_date), end_date: Some(end_date), ..filter };
+        filter.validate().map_err(UseCaseError::ValidationError)?;
+
+        let (histories, next_cursor) = self.history_page_of(ctx, &fingerprint, &filter, order, &page, cursor).await?;
+        let total_entries = until_cancelled(ctx, self.status_history_repository.count_matching(&filter)).await?;
+
+        Ok(HistoryListDto {
+            history: histories.into_iter().map(StatusHistoryDto::from).collect(),
+            total_entries,
+            limit: page.limit,
+            offset: page.offset,
+            next_cursor,
+        })
+    }
+
+    async fn history_page(
+        &self,
+        ctx: &RequestContext,
+        filter: &HistoryFilter,
+        order: SortOrder,
+        page: &Pagination,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<StatusHistory>, Option<String>), UseCaseError> {
+        self.history_page_of(ctx, &filter.fingerprint(order), filter, order, page, cursor).await
+    }
+
+    /// A page of history entries and, when more follow, the cursor of the next page.
+    ///
+    /// Cursors only continue listings in the same workspace with the same `fingerprint`.
+    async fn history_page_of(
+        &self,
+        ctx: &RequestContext,
+        fingerprint: &str,
+        filter: &HistoryFilter,
+        order: SortOrder,
+        page: &Pagination,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<StatusHistory>, Option<String>), UseCaseError> {
+        let fingerprint = format!("workspace={};{}", ctx.workspace(), fingerprint);
+        let after = match cursor {
+            Some(_) if page.offset != 0 => return Err(UseCaseError::ValidationError("Pages can be chosen by offset or by cursor, not both".to_string())),
+            Some(cursor) => Some(self.cursor_key.decode::<PageKey>(cursor, &fingerprint).map_err(UseCaseError::ValidationError)?),
+            None => page.after.clone(),
+        };
+        // One entry past the page tells whether another page follows
+        let lookahead = Pagination { limit: page.limit + 1, after, ..page.clone() };
+        let mut entries = until_cancelled(ctx, self.status_history_repository.find_matching(filter, order, lookahead)).await?;
+        if entries.len() <= page.limit as usize {
+            return Ok((entries, None));
+        }
+        entries.truncate(page.limit as usize);
+        let next_cursor = entries.last()
+            .map(|entry| self.cursor_key.encode(&PageKey { at: entry.changed_at, id: entry.id.clone() }, &fingerprint));
+        Ok((entries, next_cursor))
+    }
+
+    pub async fn get_transition_matrix(
+        &self,
+        ctx: &RequestContext,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<TransitionMatrixDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_transition_matrix", Duration::from_millis(2000));
+        let edges = until_cancelled(ctx, self.status_history_repository.get_transition_matrix(start_date, end_date)).await?;
+        Ok(TransitionMatrixDto::new(start_date, end_date, edges))
+    }
+
+    /// Completion over time of tasks created within the range, one cohort per creation bucket
+    pub async fn get_cohort_analysis(
+        &self,
+        ctx: &RequestContext,
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<CohortAnalysisDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_cohort_analysis", Duration::from_millis(2000));
+        let now = self.clock.now();
+        let cohorts = until_cancelled(ctx, self.status_history_repository.get_creation_cohorts(bucket, start_date, end_date)).await?;
+
+        Ok(CohortAnalysisDto {
+            bucket: bucket.as_str().to_string(),
+            period_start: start_date,
+            period_end: end_date,
+            cohorts: cohorts
+                .iter()
+                .map(|cohort| CohortDto::new(cohort, bucket.periods_between(cohort.start, now) + 1))
+                .collect(),
+        })
+    }
+
+    /// Tasks in each status at the close of every bucket of the range, from status history
+    pub async fn get_cumulative_flow(
+        &self,
+        ctx: &RequestContext,
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<CumulativeFlowDto, UseCaseError> {
+        let _budget = self.latency_budgets.start("get_cumulative_flow", Duration::from_millis(2000));
+        let flow = until_cancelled(ctx, self.status_history_repository.get_cumulative_flow(bucket, start_date, end_date)).await?;
+        Ok(CumulativeFlowDto::new(start_date, end_date, flow))
+    }
+
     pub async fn get_completion_analytics(
         &self, 
+        ctx: &RequestContext,
         start_date: DateTime<Utc>, 
         end_date: DateTime<Utc>
     ) -> Result<CompletionAnalyticsDto, UseCaseError> {
-        let analytics_list = self.status_history_repository.get_completion_analytics(start_date, end_date).await?;
-        let priority_times = self.status_history_repository.get_average_completion_times().await?;
+        let _budget = self.latency_budgets.start("get_completion_analytics", Duration::from_millis(2000));
+        let analytics_list = until_cancelled(ctx, self.status_history_repository.get_completion_analytics(start_date, end_date)).await?;
+        let priority_times = until_cancelled(ctx, self.status_history_repository.get_completion_time_stats(start_date, end_date)).await?;
 
         let total_completed_tasks = analytics_list.len();
         
@@ -231,28 +1461,24 @@ impl TaskUseCases {
             0.0
         };
 
-        // Convert priority completion times with proper task counting
-        let mut completion_times_by_priority: Vec<PriorityCompletionDto> = Vec::new();
-        
-        for (priority, duration) in priority_times {
-            // Get the actual count of completed tasks for this priority
-            // We need to query tasks by priority and check which ones are in our analytics list
-            let tasks_for_priority = self.task_repository.find_by_priority(priority).await?;
-            let task_ids_for_priority: std::collections::HashSet<i32> = tasks_for_priority
-                .into_iter()
-                .map(|task| task.id.value())
-                .collect();
-            
-            let task_count = analytics_list.iter()
-                .filter(|analytics| task_ids_for_priority.contains(&analytics.task_id))
-                .count();
-            
-            completion_times_by_priority.push(PriorityCompletionDto {
-                priority,
-                average_time: crate::application::dto::format_duration(duration),
-                task_count,
-            });
-        }
+        // Counts, averages and percentiles all cover the tasks first completed in the period
+        let priority_completions = until_cancelled(ctx, self.status_history_repository.get_priority_completions(start_date, end_date)).await?;
+        let format = crate::application::dto::format_duration;
+        let completion_times_by_priority: Vec<PriorityCompletionDto> = priority_completions
+            .into_iter()
+            .map(|completion| {
+                let stats = priority_times.iter().find(|stats| stats.priority == completion.priority);
+                let percentile = |pick: fn(&CycleTimeStats) -> chrono::Duration| stats.map(|stats| format(pick(stats)));
+                PriorityCompletionDto {
+                    priority: completion.priority,
+                    average_time: format(completion.average),
+                    p50_time: percentile(|stats| stats.p50),
+                    p85_time: percentile(|stats| stats.p85),
+                    p95_time: percentile(|stats| stats.p95),
+                    task_count: completion.task_count,
+                }
+            })
+            .collect();
 
         Ok(CompletionAnalyticsDto {
             period_start: start_date,
@@ -265,3 +1491,23 @@ impl TaskUseCases {
     }
 }
 
+fn status_changed(history: &StatusHistory) -> TaskStatusChanged {
+    TaskStatusChanged {
+        task_id: history.task_id,
+        from_status: history.from_status.clone().unwrap_or_default(),
+        to_status: history.to_status.clone(),
+        comment: history.comment.clone(),
+        occurred_at: history.changed_at,
+    }
+}
+
+/// Awaits a repository call unless the request is cancelled first, in which case the call is dropped
+async fn until_cancelled<T>(
+    ctx: &RequestContext,
+    call: impl Future<Output = Result<T, RepositoryError>>,
+) -> Result<T, UseCaseError> {
+    match ctx.cancellation.run_until_cancelled(call).await {
+        Some(result) => Ok(result?),
+        None => Err(UseCaseError::Cancelled("The request was cancelled before the report finished".to_string())),
+    }
+}
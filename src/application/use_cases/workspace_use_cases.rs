@@ -0,0 +1,213 @@
+use std::sync::Arc;
+use crate::domain::{Clock, CustomStatus, CustomStatusRepository, StatusCatalog, SystemClock, TaskStatus, WorkflowDefinition, WorkflowRepository, Workflows, Workspace, WorkspaceRepository, DEFAULT_WORKSPACE_ID};
+use crate::application::context::RequestContext;
+use crate::application::dto::{CreateStatusRequest, CreateWorkspaceRequest, StatusDto, UpdateWorkspaceRequest, WorkflowDto, WorkflowSource, WorkspaceDto};
+use crate::application::use_cases::UseCaseError;
+
+/// Manages the workspaces tenants work in, and resolves each request's tenant to one
+pub struct WorkspaceUseCases {
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    workflows: Workflows,
+    workflow_repository: Option<Arc<dyn WorkflowRepository>>,
+    custom_status_repository: Option<Arc<dyn CustomStatusRepository>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl WorkspaceUseCases {
+    pub fn new(workspace_repository: Arc<dyn WorkspaceRepository>) -> Self {
+        Self {
+            workspace_repository,
+            workflows: Workflows::default(),
+            workflow_repository: None,
+            custom_status_repository: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Lets admins store a workflow per workspace in place of the configured `workflows`
+    pub fn with_workflows(mut self, workflows: Workflows, workflow_repository: Arc<dyn WorkflowRepository>) -> Self {
+        self.workflows = workflows;
+        self.workflow_repository = Some(workflow_repository);
+        self
+    }
+
+    /// Lets admins define statuses next to the built-in ones for workflows to use
+    pub fn with_custom_statuses(mut self, custom_status_repository: Arc<dyn CustomStatusRepository>) -> Self {
+        self.custom_status_repository = Some(custom_status_repository);
+        self
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Every workspace for deployment admins, otherwise only the admin's own
+    pub async fn list_workspaces(&self, ctx: &RequestContext) -> Result<Vec<WorkspaceDto>, UseCaseError> {
+        Self::authorize(ctx)?;
+        let workspaces = self.workspace_repository.find_all().await?;
+        Ok(workspaces.into_iter()
+            .filter(|workspace| ctx.administers_workspace(&workspace.id))
+            .map(WorkspaceDto::from)
+            .collect())
+    }
+
+    pub async fn get_workspace(&self, ctx: &RequestContext, id: &str) -> Result<WorkspaceDto, UseCaseError> {
+        Self::authorize_workspace(ctx, id)?;
+        Ok(self.find_workspace(id).await?.into())
+    }
+
+    /// Workspaces are tenants of the deployment, so only deployment admins create them
+    pub async fn create_workspace(&self, ctx: &RequestContext, request: CreateWorkspaceRequest) -> Result<WorkspaceDto, UseCaseError> {
+        Self::authorize_deployment(ctx)?;
+        let workspace = Workspace::new(request.id, request.name, ctx.user_id.clone(), self.clock.now())
+            .map_err(UseCaseError::ValidationError)?;
+        self.workspace_repository.save(&workspace).await?;
+        Ok(workspace.into())
+    }
+
+    pub async fn rename_workspace(&self, ctx: &RequestContext, id: &str, request: UpdateWorkspaceRequest) -> Result<WorkspaceDto, UseCaseError> {
+        Self::authorize_workspace(ctx, id)?;
+        let mut workspace = self.find_workspace(id).await?;
+        workspace.rename(request.name).map_err(UseCaseError::ValidationError)?;
+        self.workspace_repository.update(&workspace).await?;
+        Ok(workspace.into())
+    }
+
+    /// Deletes an empty workspace; one that ever held a task is kept so its id cannot be reused.
+    /// Only deployment admins delete workspaces.
+    pub async fn delete_workspace(&self, ctx: &RequestContext, id: &str) -> Result<(), UseCaseError> {
+        Self::authorize_deployment(ctx)?;
+        if id == DEFAULT_WORKSPACE_ID {
+            return Err(UseCaseError::ValidationError("The default workspace cannot be deleted".to_string()));
+        }
+        Ok(self.workspace_repository.delete(id).await?)
+    }
+
+    /// The workflow the workspace's tasks follow and where it comes from
+    pub async fn get_workflow(&self, ctx: &RequestContext, id: &str) -> Result<WorkflowDto, UseCaseError> {
+        Self::authorize_workspace(ctx, id)?;
+        let workspace = self.find_workspace(id).await?;
+        let stored = match &self.workflow_repository {
+            Some(repository) => repository.find_by_workspace(&workspace.id).await?,
+            None => None,
+        };
+        Ok(match stored {
+            Some(workflow) => WorkflowDto { workspace_id: workspace.id, source: WorkflowSource::Workspace, workflow },
+            None => self.configured_workflow(workspace.id),
+        })
+    }
+
+    /// Stores a workflow for the workspace. Tasks already in a status it leaves out stay there
+    /// until an admin changes the workflow again.
+    pub async fn set_workflow(&self, ctx: &RequestContext, id: &str, workflow: WorkflowDefinition) -> Result<WorkflowDto, UseCaseError> {
+        Self::authorize_workspace(ctx, id)?;
+        let repository = self.workflow_repository()?;
+        let workspace = self.find_workspace(id).await?;
+        self.status_catalog().await?.check_workflow(&workflow).map_err(UseCaseError::ValidationError)?;
+        repository.save(&workspace.id, &workflow, &ctx.user_id, self.clock.now()).await?;
+        Ok(WorkflowDto { workspace_id: workspace.id, source: WorkflowSource::Workspace, workflow })
+    }
+
+    /// Drops the workspace's stored workflow, so it follows the configured one again
+    pub async fn reset_workflow(&self, ctx: &RequestContext, id: &str) -> Result<WorkflowDto, UseCaseError> {
+        Self::authorize_workspace(ctx, id)?;
+        let repository = self.workflow_repository()?;
+        let workspace = self.find_workspace(id).await?;
+        repository.delete(&workspace.id).await?;
+        Ok(self.configured_workflow(workspace.id))
+    }
+
+    /// The built-in statuses in lifecycle order, then custom ones, oldest first
+    pub async fn list_statuses(&self, ctx: &RequestContext) -> Result<Vec<StatusDto>, UseCaseError> {
+        Self::authorize(ctx)?;
+        let catalog = self.status_catalog().await?;
+        let built_in = TaskStatus::ALL.iter().filter_map(StatusDto::built_in);
+        Ok(built_in.chain(catalog.custom().iter().cloned().map(StatusDto::from)).collect())
+    }
+
+    /// Custom statuses are shared by every workspace, so only deployment admins define them
+    pub async fn create_status(&self, ctx: &RequestContext, request: CreateStatusRequest) -> Result<StatusDto, UseCaseError> {
+        Self::authorize_deployment(ctx)?;
+        let repository = self.custom_status_repository()?;
+        let status = CustomStatus::new(&request.name, request.category, ctx.user_id.clone(), self.clock.now())
+            .map_err(UseCaseError::ValidationError)?;
+        repository.save(&status).await?;
+        Ok(status.into())
+    }
+
+    /// Deletes a custom status no task is in, in any workspace. Workflows that list it keep it,
+    /// but tasks can no longer move into it. Only deployment admins delete statuses.
+    pub async fn delete_status(&self, ctx: &RequestContext, name: &str) -> Result<(), UseCaseError> {
+        Self::authorize_deployment(ctx)?;
+        if TaskStatus::from_str(name).is_ok() {
+            return Err(UseCaseError::ValidationError(format!("{} is a built-in status and cannot be deleted", name)));
+        }
+        Ok(self.custom_status_repository()?.delete(name).await?)
+    }
+
+    /// The workspace a request with `tenant` works in: the default without one, otherwise the
+    /// tenant's own, which must exist. Runs before a request context exists, so takes none.
+    pub async fn resolve(&self, tenant: Option<&str>) -> Result<String, UseCaseError> {
+        match tenant {
+            None => Ok(DEFAULT_WORKSPACE_ID.to_string()),
+            Some(tenant) => Ok(self.find_workspace(tenant).await?.id),
+        }
+    }
+
+    /// Every workspace's id, for background jobs that work through them in turn
+    pub async fn workspace_ids(&self) -> Result<Vec<String>, UseCaseError> {
+        let workspaces = self.workspace_repository.find_all().await?;
+        Ok(workspaces.into_iter().map(|workspace| workspace.id).collect())
+    }
+
+    async fn find_workspace(&self, id: &str) -> Result<Workspace, UseCaseError> {
+        self.workspace_repository.find_by_id(id).await?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Workspace {} not found", id)))
+    }
+
+    fn configured_workflow(&self, workspace_id: String) -> WorkflowDto {
+        let workflow = self.workflows.for_workspace(&workspace_id).clone();
+        WorkflowDto { workspace_id, source: WorkflowSource::Configuration, workflow }
+    }
+
+    fn workflow_repository(&self) -> Result<&Arc<dyn WorkflowRepository>, UseCaseError> {
+        self.workflow_repository.as_ref()
+            .ok_or_else(|| UseCaseError::ValidationError("Workflows cannot be stored on this server".to_string()))
+    }
+
+    async fn status_catalog(&self) -> Result<StatusCatalog, UseCaseError> {
+        Ok(match &self.custom_status_repository {
+            Some(repository) => StatusCatalog::new(repository.find_all().await?),
+            None => StatusCatalog::default(),
+        })
+    }
+
+    fn custom_status_repository(&self) -> Result<&Arc<dyn CustomStatusRepository>, UseCaseError> {
+        self.custom_status_repository.as_ref()
+            .ok_or_else(|| UseCaseError::ValidationError("Custom statuses cannot be stored on this server".to_string()))
+    }
+
+    fn authorize(ctx: &RequestContext) -> Result<(), UseCaseError> {
+        match ctx.role.can_manage_users() {
+            true => Ok(()),
+            false => Err(UseCaseError::Forbidden("Only admins can manage workspaces".to_string())),
+        }
+    }
+
+    fn authorize_workspace(ctx: &RequestContext, id: &str) -> Result<(), UseCaseError> {
+        Self::authorize(ctx)?;
+        match ctx.administers_workspace(id) {
+            true => Ok(()),
+            false => Err(UseCaseError::Forbidden(format!("Admins of workspace {} cannot manage workspace {}", ctx.workspace(), id))),
+        }
+    }
+
+    fn authorize_deployment(ctx: &RequestContext) -> Result<(), UseCaseError> {
+        Self::authorize(ctx)?;
+        match ctx.administers_deployment() {
+            true => Ok(()),
+            false => Err(UseCaseError::Forbidden("Only deployment admins, working without a tenant, can create or delete workspaces and statuses".to_string())),
+        }
+    }
+}
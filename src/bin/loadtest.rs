@@ -0,0 +1,265 @@
+//! Drives load scenarios against a running server and reports latency for each concurrency stage.
+//!
+//! ```text
+//! cargo run --release --bin loadtest -- --url http://127.0.0.1:7878 --scenario lifecycle --ramp 1,4,16 --stage-secs 10
+//! ```
+//!
+//! `lifecycle` creates tasks and moves each through InProgress to Completed; `list` repeats the
+//! unfiltered and a filtered, sorted task listing. Every worker sends its next request as soon as
+//! the previous one answers.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, Instant};
+
+use axum_postgres_rust::application::{CreateTaskRequest, UpdateTaskStatusDto};
+use axum_postgres_rust::domain::TaskStatus;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scenario {
+    Lifecycle,
+    List,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Options {
+    url: String,
+    scenario: Scenario,
+    /// Concurrent workers in each stage, run one after another
+    ramp: Vec<usize>,
+    stage_duration: Duration,
+}
+
+impl Options {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options {
+            url: "http://127.0.0.1:7878".to_string(),
+            scenario: Scenario::Lifecycle,
+            ramp: vec![1, 4, 16],
+            stage_duration: Duration::from_secs(10),
+        };
+
+        let mut args = args.into_iter();
+        while let Some(flag) = args.next() {
+            let value = args.next().ok_or_else(|| format!("{} needs a value", flag))?;
+            match flag.as_str() {
+                "--url" => options.url = value.trim_end_matches('/').to_string(),
+                "--scenario" => options.scenario = match value.as_str() {
+                    "lifecycle" => Scenario::Lifecycle,
+                    "list" => Scenario::List,
+                    _ => return Err(format!("--scenario must be lifecycle or list, got '{}'", value)),
+                },
+                "--ramp" => options.ramp = value
+                    .split(',')
+                    .map(|workers| match workers.trim().parse() {
+                        Ok(workers) if workers > 0 => Ok(workers),
+                        _ => Err(format!("--ramp must be a comma-separated list of positive worker counts, got '{}'", value)),
+                    })
+                    .collect::<Result<_, _>>()?,
+                "--stage-secs" => options.stage_duration = match value.parse() {
+                    Ok(secs) if secs > 0 => Duration::from_secs(secs),
+                    _ => return Err(format!("--stage-secs must be a positive number of seconds, got '{}'", value)),
+                },
+                _ => return Err(format!("Unknown option '{}'; expected --url, --scenario, --ramp or --stage-secs", flag)),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Latencies of successful requests and the count of failed ones, per operation
+#[derive(Debug, Default)]
+struct Samples {
+    latencies: BTreeMap<&'static str, Vec<Duration>>,
+    errors: BTreeMap<&'static str, usize>,
+}
+
+impl Samples {
+    fn merge(&mut self, other: Samples) {
+        for (operation, latencies) in other.latencies {
+            self.latencies.entry(operation).or_default().extend(latencies);
+        }
+        for (operation, errors) in other.errors {
+            *self.errors.entry(operation).or_default() += errors;
+        }
+    }
+}
+
+/// The latency below which `quantile` of the sorted samples fall
+fn percentile(sorted: &[Duration], quantile: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (quantile * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+struct Client {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl Client {
+    /// Sends one request, recording its latency on success; returns the response body
+    async fn send(
+        &self,
+        samples: &mut Samples,
+        operation: &'static str,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&impl Serialize>,
+    ) -> Option<serde_json::Value> {
+        let mut request = self.http.request(method, format!("{}{}", self.url, path));
+        if let Some(body) = body {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(serde_json::to_vec(body).expect("request bodies always serialize"));
+        }
+
+        let started = Instant::now();
+        let response = match request.send().await {
+            Ok(response) if response.status().is_success() => response.bytes().await.ok(),
+            _ => None,
+        };
+        match response {
+            Some(bytes) => {
+                samples.latencies.entry(operation).or_default().push(started.elapsed());
+                Some(serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null))
+            }
+            None => {
+                *samples.errors.entry(operation).or_default() += 1;
+                None
+            }
+        }
+    }
+}
+
+async fn run_lifecycle(client: &Client, samples: &mut Samples, worker: usize, iteration: usize) {
+    let request = CreateTaskRequest {
+        name: format!("loadtest {}-{}", worker, iteration),
+        priority: None,
+        description: None,
+    };
+    let Some(created) = client.send(samples, "create", reqwest::Method::POST, "/tasks", Some(&request)).await else {
+        return;
+    };
+    let Some(task_id) = created["data"]["task_id"].as_i64() else {
+        return;
+    };
+
+    let path = format!("/tasks/{}/status", task_id);
+    for (operation, status) in [("start", TaskStatus::InProgress), ("complete", TaskStatus::Completed)] {
+        let transition = UpdateTaskStatusDto { status, comment: None, checklist: None };
+        if client.send(samples, operation, reqwest::Method::PATCH, &path, Some(&transition)).await.is_none() {
+            return;
+        }
+    }
+}
+
+async fn run_list(client: &Client, samples: &mut Samples) {
+    client.send(samples, "list", reqwest::Method::GET, "/tasks", None::<&()>).await;
+    client.send(samples, "list_filtered", reqwest::Method::GET, "/tasks?status=Pending&sort_by=priority&order=desc", None::<&()>).await;
+}
+
+async fn run_stage(options: &Options, http: &reqwest::Client, workers: usize) -> Samples {
+    let deadline = Instant::now() + options.stage_duration;
+    let handles: Vec<_> = (0..workers)
+        .map(|worker| {
+            let client = Client { http: http.clone(), url: options.url.clone() };
+            let scenario = options.scenario;
+            tokio::spawn(async move {
+                let mut samples = Samples::default();
+                let mut iteration = 0;
+                while Instant::now() < deadline {
+                    match scenario {
+                        Scenario::Lifecycle => run_lifecycle(&client, &mut samples, worker, iteration).await,
+                        Scenario::List => run_list(&client, &mut samples).await,
+                    }
+                    iteration += 1;
+                }
+                samples
+            })
+        })
+        .collect();
+
+    let mut samples = Samples::default();
+    for handle in handles {
+        if let Ok(worker_samples) = handle.await {
+            samples.merge(worker_samples);
+        }
+    }
+    samples
+}
+
+fn report(workers: usize, elapsed: Duration, mut samples: Samples) {
+    println!("\n{} workers for {:.1}s", workers, elapsed.as_secs_f64());
+    println!("{:<14} {:>8} {:>7} {:>9} {:>9} {:>9} {:>9} {:>9}", "operation", "ok", "errors", "req/s", "p50 ms", "p95 ms", "p99 ms", "max ms");
+    let operations: BTreeSet<_> = samples.latencies.keys().chain(samples.errors.keys()).copied().collect();
+    for operation in operations {
+        let latencies = samples.latencies.entry(operation).or_default();
+        latencies.sort();
+        let ms = |quantile| percentile(latencies, quantile).as_secs_f64() * 1000.0;
+        println!(
+            "{:<14} {:>8} {:>7} {:>9.1} {:>9.1} {:>9.1} {:>9.1} {:>9.1}",
+            operation,
+            latencies.len(),
+            samples.errors.get(operation).copied().unwrap_or(0),
+            latencies.len() as f64 / elapsed.as_secs_f64(),
+            ms(0.50),
+            ms(0.95),
+            ms(0.99),
+            ms(1.0),
+        );
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let options = Options::parse(std::env::args().skip(1))?;
+    let http = reqwest::Client::builder().timeout(Duration::from_secs(30)).build()?;
+
+    println!("Running {:?} against {} with ramp {:?}", options.scenario, options.url, options.ramp);
+    for &workers in &options.ramp {
+        let started = Instant::now();
+        let samples = run_stage(&options, &http, workers).await;
+        report(workers, started.elapsed(), samples);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_reads_every_option() {
+        let options = Options::parse(args(&["--url", "http://api:3000/", "--scenario", "list", "--ramp", "2, 8", "--stage-secs", "5"])).unwrap();
+
+        assert_eq!(options.url, "http://api:3000");
+        assert_eq!(options.scenario, Scenario::List);
+        assert_eq!(options.ramp, vec![2, 8]);
+        assert_eq!(options.stage_duration, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_values() {
+        assert!(Options::parse(args(&["--ramp", "4,0"])).is_err());
+        assert!(Options::parse(args(&["--scenario", "soak"])).is_err());
+        assert!(Options::parse(args(&["--stage-secs"])).is_err());
+        assert!(Options::parse(args(&["--workers", "4"])).is_err());
+    }
+
+    #[test]
+    fn test_percentile_uses_nearest_rank() {
+        let sorted: Vec<_> = (1..=100).map(Duration::from_millis).collect();
+
+        assert_eq!(percentile(&sorted, 0.50), Duration::from_millis(50));
+        assert_eq!(percentile(&sorted, 0.99), Duration::from_millis(99));
+        assert_eq!(percentile(&sorted, 1.0), Duration::from_millis(100));
+        assert_eq!(percentile(&[], 0.5), Duration::ZERO);
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Deserialize;
+use tracing_subscriber::EnvFilter;
+
+/// Shape of log lines, from `LOG_FORMAT`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum LogFormat {
+    /// Human-readable lines for local development
+    #[default]
+    Text,
+    /// One JSON object per event, including the fields of the enclosing spans
+    Json,
+}
+
+impl LogFormat {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!("LOG_FORMAT must be text or json, got '{}'", s)),
+        }
+    }
+}
+
+/// Installs the global tracing subscriber; levels still come from `RUST_LOG`
+pub fn init_tracing(format: LogFormat) {
+    let filter = EnvFilter::from_default_env();
+    match format {
+        LogFormat::Text => tracing_subscriber::fmt().with_env_filter(filter).init(),
+        LogFormat::Json => tracing_subscriber::fmt()
+            .json()
+            .with_current_span(true)
+            .with_span_list(false)
+            .with_env_filter(filter)
+            .init(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_format() {
+        assert_eq!(LogFormat::from_str("JSON").unwrap(), LogFormat::Json);
+        assert_eq!(LogFormat::from_str("text").unwrap(), LogFormat::Text);
+        assert!(LogFormat::from_str("xml").is_err());
+    }
+}
@@ -1,2 +1,10 @@
+#[allow(clippy::module_inception)]
 pub mod config;
-pub use config::Config;
\ No newline at end of file
+pub mod logging;
+pub mod secrets;
+pub mod sources;
+
+pub use config::{Config, ConfigBuilder, SECRET_SETTINGS};
+pub use logging::*;
+pub use secrets::*;
+pub use sources::*;
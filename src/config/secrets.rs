@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+
+/// Errors raised while resolving a secret
+#[derive(Debug, thiserror::Error)]
+pub enum SecretError {
+    #[error("failed to read secret {name} from {path}: {source}")]
+    Io {
+        name: String,
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("secret provider error for {name}: {message}")]
+    Provider { name: String, message: String },
+}
+
+/// Port for resolving sensitive configuration values.
+///
+/// Implement this for external secret managers (Vault, AWS Secrets Manager, ...)
+/// and pass it to `Config::from_env_with_secrets`.
+pub trait SecretProvider: Send + Sync {
+    /// Returns the secret value, or `None` when this provider does not know it
+    fn get_secret(&self, name: &str) -> Result<Option<String>, SecretError>;
+}
+
+/// Reads `NAME` from the environment, falling back to the file named by `NAME_FILE`
+#[derive(Debug, Default, Clone)]
+pub struct EnvSecretProvider;
+
+impl SecretProvider for EnvSecretProvider {
+    fn get_secret(&self, name: &str) -> Result<Option<String>, SecretError> {
+        if let Ok(value) = std::env::var(name) {
+            return Ok(Some(value));
+        }
+
+        match std::env::var(format!("{}_FILE", name)) {
+            Ok(path) => read_secret_file(name, PathBuf::from(path)).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+/// Reads secrets from a directory containing one file per secret,
+/// as mounted by Docker (`/run/secrets`) or Kubernetes secret volumes
+#[derive(Debug, Clone)]
+pub struct FileSecretProvider {
+    directory: PathBuf,
+}
+
+impl FileSecretProvider {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into() }
+    }
+}
+
+impl SecretProvider for FileSecretProvider {
+    fn get_secret(&self, name: &str) -> Result<Option<String>, SecretError> {
+        let path = self.directory.join(name);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        read_secret_file(name, path).map(Some)
+    }
+}
+
+/// Tries each provider in order and returns the first value found
+pub struct ChainedSecretProvider {
+    providers: Vec<Box<dyn SecretProvider>>,
+}
+
+impl ChainedSecretProvider {
+    pub fn new(providers: Vec<Box<dyn SecretProvider>>) -> Self {
+        Self { providers }
+    }
+
+    /// Environment variables and `*_FILE` references first, then `SECRETS_DIR` if set
+    pub fn from_env() -> Self {
+        let mut providers: Vec<Box<dyn SecretProvider>> = vec![Box::new(EnvSecretProvider)];
+        if let Ok(directory) = std::env::var("SECRETS_DIR") {
+            providers.push(Box::new(FileSecretProvider::new(directory)));
+        }
+        Self::new(providers)
+    }
+}
+
+impl SecretProvider for ChainedSecretProvider {
+    fn get_secret(&self, name: &str) -> Result<Option<String>, SecretError> {
+        for provider in &self.providers {
+            if let Some(value) = provider.get_secret(name)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn read_secret_file(name: &str, path: PathBuf) -> Result<String, SecretError> {
+    std::fs::read_to_string(&path)
+        // Mounted secrets usually end with a newline
+        .map(|contents| contents.trim_end_matches(['\r', '\n']).to_string())
+        .map_err(|source| SecretError::Io {
+            name: name.to_string(),
+            path: path.display().to_string(),
+            source,
+        })
+}
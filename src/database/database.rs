@@ -1,15 +1,143 @@
-use sqlx::{postgres::PgPoolOptions, PgPool};
+use sqlx::{migrate::MigrateError, postgres::PgConnectOptions, PgPool, Postgres};
+#[cfg(feature = "sqlite")]
+use sqlx::{sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions}, SqlitePool};
 use crate::config::Config;
+use crate::database::{redact_connection_string, DatabaseOptions};
+
+/// What [`Database::upgrade_schema`] changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaUpgrade {
+    pub migrations_applied: usize,
+    pub creation_entries_seeded: u64,
+}
 
 /// Database connection management
 pub struct Database;
 
 impl Database {
-    /// Creates a new database connection pool
+    /// Creates a new database connection pool with the configured [`DatabaseOptions`], applying
+    /// pending migrations unless `run_migrations` is disabled in the configuration
     pub async fn connect(config: &Config) -> Result<PgPool, sqlx::Error> {
-        PgPoolOptions::new()
-            .max_connections(config.max_connections)
-            .connect(&config.database_url)
+        let pool = Self::connect_with(&config.database_url, &DatabaseOptions::from_config(config)).await?;
+
+        if config.run_migrations {
+            Self::migrate(&pool).await?;
+        }
+
+        Ok(pool)
+    }
+
+    /// Creates a connection pool without migrating.
+    ///
+    /// While the server is unreachable or still starting up, connecting is retried as often as
+    /// `options` allow. Other failures, such as a wrong password, are returned at once.
+    pub async fn connect_with(url: &str, options: &DatabaseOptions) -> Result<PgPool, sqlx::Error> {
+        let mut connect_options: PgConnectOptions = url.parse()?;
+        if let Some(timeout) = options.statement_timeout() {
+            connect_options = connect_options.options([("statement_timeout", timeout.as_millis().to_string())]);
+        }
+
+        let mut attempt = 0;
+        loop {
+            match options.pool_options::<Postgres>().connect_with(connect_options.clone()).await {
+                Ok(pool) => return Ok(pool),
+                Err(error) if attempt < options.connect_retries() && is_transient(&error) => {
+                    let delay = options.retry_delay(attempt);
+                    attempt += 1;
+                    tracing::warn!(
+                        "Database not reachable (attempt {} of {}), retrying in {:?}: {}",
+                        attempt,
+                        options.connect_retries() + 1,
+                        delay,
+                        redact_connection_string(&error.to_string())
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Applies the migrations embedded from `migrations/` that have not run yet
+    pub async fn migrate(pool: &PgPool) -> Result<(), MigrateError> {
+        sqlx::migrate!("./migrations").run(pool).await
+    }
+
+    /// Opens the SQLite database a `sqlite://` DATABASE_URL points at, creating the file if needed.
+    ///
+    /// `sqlite::memory:` keeps the database in a single connection that is never recycled, since
+    /// every connection to it would otherwise see an empty database of its own.
+    #[cfg(feature = "sqlite")]
+    pub async fn connect_sqlite(config: &Config) -> Result<SqlitePool, sqlx::Error> {
+        let options = config.database_url.parse::<SqliteConnectOptions>()?
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .busy_timeout(std::time::Duration::from_secs(5));
+        let in_memory = config.database_url.contains(":memory:") || config.database_url.contains("mode=memory");
+        let pool_options = match in_memory {
+            true => SqlitePoolOptions::new().max_connections(1).idle_timeout(None).max_lifetime(None),
+            false => DatabaseOptions::from_config(config).pool_options(),
+        };
+        let pool = pool_options.connect_with(options).await?;
+
+        if config.run_migrations {
+            Self::migrate_sqlite(&pool).await?;
+        }
+
+        Ok(pool)
+    }
+
+    /// Applies the migrations embedded from `migrations_sqlite/` that have not run yet
+    #[cfg(feature = "sqlite")]
+    pub async fn migrate_sqlite(pool: &SqlitePool) -> Result<(), MigrateError> {
+        sqlx::migrate!("./migrations_sqlite").run(pool).await
+    }
+
+    /// Brings a database from an older release, including the original `src/models` schema, up to date.
+    ///
+    /// The pending migrations add the status and timestamp columns, backfilling `Pending` and the
+    /// upgrade time. Every task that still has no history then gets a creation entry. That step is
+    /// safe to repeat, so running the command again after cutover picks up tasks the old release
+    /// inserted while both versions were live.
+    pub async fn upgrade_schema(pool: &PgPool) -> Result<SchemaUpgrade, MigrateError> {
+        let applied_before = Self::applied_migrations(pool).await?;
+        Self::migrate(pool).await?;
+        let applied_after = Self::applied_migrations(pool).await?;
+
+        let seeded = sqlx::query(
+            "INSERT INTO status_history (task_id, from_status, to_status, changed_at, changed_by, user_role, workspace_id)
+             SELECT t.task_id, NULL, t.status, t.created_at, 'system:legacy', 'System', t.workspace_id
+             FROM tasks t
+             WHERE NOT EXISTS (SELECT 1 FROM status_history sh WHERE sh.task_id = t.task_id)"
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(SchemaUpgrade {
+            migrations_applied: (applied_after - applied_before) as usize,
+            creation_entries_seeded: seeded.rows_affected(),
+        })
+    }
+
+    async fn applied_migrations(pool: &PgPool) -> Result<i64, sqlx::Error> {
+        let tracked: bool = sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+            .fetch_one(pool)
+            .await?;
+        if !tracked {
+            return Ok(0);
+        }
+        sqlx::query_scalar("SELECT COUNT(*) FROM _sqlx_migrations WHERE success")
+            .fetch_one(pool)
             .await
     }
-}
\ No newline at end of file
+}
+
+/// Whether connecting may succeed later: the server is unreachable, or Postgres answers
+/// `cannot_connect_now` while it starts up or recovers
+fn is_transient(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::Io(_) | sqlx::Error::PoolTimedOut => true,
+        sqlx::Error::Database(database) => database.code().as_deref() == Some("57P03"),
+        _ => false,
+    }
+}
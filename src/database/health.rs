@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use sqlx::PgPool;
+
+/// Connection pool usage reported by the readiness probe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PoolStats {
+    pub size: u32,
+    pub idle: usize,
+    pub max_connections: u32,
+}
+
+/// Checks whether the database can serve queries
+#[async_trait]
+pub trait DatabaseProbe: Send + Sync {
+    /// Runs a trivial query; an error means the database is unreachable
+    async fn ping(&self) -> Result<(), String>;
+
+    fn pool_stats(&self) -> PoolStats;
+}
+
+#[async_trait]
+impl DatabaseProbe for PgPool {
+    async fn ping(&self) -> Result<(), String> {
+        sqlx::query("SELECT 1")
+            .execute(self)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    fn pool_stats(&self) -> PoolStats {
+        PoolStats {
+            size: self.size(),
+            idle: self.num_idle(),
+            max_connections: self.options().get_max_connections(),
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+#[async_trait]
+impl DatabaseProbe for sqlx::SqlitePool {
+    async fn ping(&self) -> Result<(), String> {
+        sqlx::query("SELECT 1")
+            .execute(self)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    fn pool_stats(&self) -> PoolStats {
+        PoolStats {
+            size: self.size(),
+            idle: self.num_idle(),
+            max_connections: self.options().get_max_connections(),
+        }
+    }
+}
@@ -1,2 +1,16 @@
+#[allow(clippy::module_inception)]
 pub mod database;
-pub use database::Database;
\ No newline at end of file
+pub mod health;
+pub mod options;
+pub mod redaction;
+pub mod shards;
+pub mod transaction;
+pub mod workspace;
+
+pub use database::{Database, SchemaUpgrade};
+pub use health::*;
+pub use options::*;
+pub use redaction::*;
+pub use shards::*;
+pub use transaction::*;
+pub use workspace::*;
@@ -0,0 +1,143 @@
+use std::time::Duration;
+use sqlx::pool::PoolOptions;
+use crate::config::Config;
+
+/// The longest wait between two connection attempts at startup
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// How a database pool is sized, when its connections time out, and how often connecting is
+/// retried at startup.
+///
+/// Built from [`Config`] by [`Database::connect`](super::Database::connect), or by hand:
+///
+/// ```
+/// # use std::time::Duration;
+/// # use axum_postgres_rust::database::DatabaseOptions;
+/// let options = DatabaseOptions::default()
+///     .with_max_connections(32)
+///     .with_statement_timeout(Some(Duration::from_secs(5)))
+///     .with_connect_retries(10, Duration::from_millis(250));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseOptions {
+    max_connections: u32,
+    min_connections: u32,
+    acquire_timeout: Duration,
+    idle_timeout: Option<Duration>,
+    max_lifetime: Option<Duration>,
+    statement_timeout: Option<Duration>,
+    connect_retries: u32,
+    retry_delay: Duration,
+}
+
+impl Default for DatabaseOptions {
+    /// sqlx's pool defaults with sixteen connections, no statement timeout and no retries
+    fn default() -> Self {
+        Self {
+            max_connections: 16,
+            min_connections: 0,
+            acquire_timeout: Duration::from_secs(30),
+            idle_timeout: Some(Duration::from_secs(10 * 60)),
+            max_lifetime: Some(Duration::from_secs(30 * 60)),
+            statement_timeout: None,
+            connect_retries: 0,
+            retry_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl DatabaseOptions {
+    pub fn from_config(config: &Config) -> Self {
+        Self::default()
+            .with_max_connections(config.max_connections)
+            .with_min_connections(config.min_connections)
+            .with_acquire_timeout(config.db_acquire_timeout)
+            .with_idle_timeout(config.db_idle_timeout)
+            .with_max_lifetime(config.db_max_lifetime)
+            .with_statement_timeout(config.db_statement_timeout)
+            .with_connect_retries(config.db_connect_retries, config.db_connect_retry_delay)
+    }
+
+    pub fn with_max_connections(mut self, max_connections: u32) -> Self {
+        self.max_connections = max_connections;
+        self
+    }
+
+    /// Connections the pool keeps open even when idle
+    pub fn with_min_connections(mut self, min_connections: u32) -> Self {
+        self.min_connections = min_connections;
+        self
+    }
+
+    /// How long a query waits for a free connection before failing
+    pub fn with_acquire_timeout(mut self, acquire_timeout: Duration) -> Self {
+        self.acquire_timeout = acquire_timeout;
+        self
+    }
+
+    /// How long a connection may sit idle before it is closed; `None` keeps it
+    pub fn with_idle_timeout(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// How long a connection is used before it is replaced; `None` keeps it
+    pub fn with_max_lifetime(mut self, max_lifetime: Option<Duration>) -> Self {
+        self.max_lifetime = max_lifetime;
+        self
+    }
+
+    /// Postgres cancels statements running longer than this; `None` lets them run
+    pub fn with_statement_timeout(mut self, statement_timeout: Option<Duration>) -> Self {
+        self.statement_timeout = statement_timeout;
+        self
+    }
+
+    /// Retries connecting at startup up to `retries` times, waiting `delay` and then twice as
+    /// long after each failure, up to 30 seconds
+    pub fn with_connect_retries(mut self, retries: u32, delay: Duration) -> Self {
+        self.connect_retries = retries;
+        self.retry_delay = delay;
+        self
+    }
+
+    pub fn statement_timeout(&self) -> Option<Duration> {
+        self.statement_timeout
+    }
+
+    pub fn connect_retries(&self) -> u32 {
+        self.connect_retries
+    }
+
+    /// The wait after the `attempt`th failed connection attempt, counting from zero
+    pub fn retry_delay(&self, attempt: u32) -> Duration {
+        self.retry_delay
+            .checked_mul(2u32.saturating_pow(attempt))
+            .map_or(MAX_RETRY_DELAY, |delay| delay.min(MAX_RETRY_DELAY))
+    }
+
+    /// Pool options for any database with these sizes and timeouts
+    pub fn pool_options<DB: sqlx::Database>(&self) -> PoolOptions<DB> {
+        PoolOptions::new()
+            .max_connections(self.max_connections)
+            .min_connections(self.min_connections)
+            .acquire_timeout(self.acquire_timeout)
+            .idle_timeout(self.idle_timeout)
+            .max_lifetime(self.max_lifetime)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_the_cap() {
+        let options = DatabaseOptions::default().with_connect_retries(10, Duration::from_millis(500));
+
+        assert_eq!(options.retry_delay(0), Duration::from_millis(500));
+        assert_eq!(options.retry_delay(3), Duration::from_secs(4));
+        assert_eq!(options.retry_delay(6), MAX_RETRY_DELAY);
+        assert_eq!(options.retry_delay(u32::MAX), MAX_RETRY_DELAY);
+    }
+}
@@ -0,0 +1,183 @@
+use serde::Deserialize;
+use sqlx::PgPool;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::future::Future;
+
+use crate::config::Config;
+use crate::database::{redact_connection_string, Database};
+
+tokio::task_local! {
+    static CURRENT_SHARD: PgPool;
+}
+
+/// Which Postgres cluster holds each tenant's data; tenants not listed stay on `DATABASE_URL`.
+///
+/// `Debug` output hides the passwords in the shards' URLs.
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ShardMap {
+    urls: BTreeMap<String, String>,
+    tenants: HashMap<String, String>,
+}
+
+impl ShardMap {
+    /// Parses `name=url;name=url` shards and `tenant=name;tenant=name` assignments
+    pub fn parse(shards: &str, tenants: &str) -> Result<Self, String> {
+        let urls: BTreeMap<_, _> = pairs(shards, "DATABASE_SHARDS")?.into_iter().collect();
+        let tenants: HashMap<_, _> = pairs(tenants, "TENANT_SHARDS")?.into_iter().collect();
+        if let Some((tenant, shard)) = tenants.iter().find(|(_, shard)| !urls.contains_key(*shard)) {
+            return Err(format!("TENANT_SHARDS assigns tenant '{}' to unknown shard '{}'", tenant, shard));
+        }
+        Ok(Self { urls, tenants })
+    }
+
+    /// True for single-cluster deployments
+    pub fn is_empty(&self) -> bool {
+        self.urls.is_empty()
+    }
+
+    /// Shard names with their connection URLs
+    pub fn shards(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.urls.iter().map(|(name, url)| (name.as_str(), url.as_str()))
+    }
+
+    pub fn shard_for(&self, tenant: &str) -> Option<&str> {
+        self.tenants.get(tenant).map(String::as_str)
+    }
+}
+
+impl fmt::Debug for ShardMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let urls: BTreeMap<&str, String> = self.urls.iter()
+            .map(|(name, url)| (name.as_str(), redact_connection_string(url)))
+            .collect();
+        f.debug_struct("ShardMap")
+            .field("urls", &urls)
+            .field("tenants", &self.tenants)
+            .finish()
+    }
+}
+
+fn pairs(value: &str, variable: &str) -> Result<Vec<(String, String)>, String> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| match entry.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() && !value.trim().is_empty() => {
+                Ok((key.trim().to_string(), value.trim().to_string()))
+            }
+            _ => Err(format!("{} entries must look like name=value, got '{}'", variable, entry)),
+        })
+        .collect()
+}
+
+/// One pool per shard, and the tenant assignments that pick between them
+#[derive(Debug, Clone, Default)]
+pub struct ShardRegistry {
+    pools: BTreeMap<String, PgPool>,
+    tenants: HashMap<String, String>,
+}
+
+impl ShardRegistry {
+    /// Connects to every shard with the main pool's settings, migrating each unless `run_migrations` is off
+    pub async fn connect(shards: &ShardMap, config: &Config) -> Result<Self, sqlx::Error> {
+        let mut pools = BTreeMap::new();
+        for (name, url) in shards.shards() {
+            let shard_config = Config { database_url: url.to_string(), ..config.clone() };
+            pools.insert(name.to_string(), Database::connect(&shard_config).await?);
+        }
+        Ok(Self::new(pools, shards.tenants.clone()))
+    }
+
+    pub fn new(pools: BTreeMap<String, PgPool>, tenants: HashMap<String, String>) -> Self {
+        Self { pools, tenants }
+    }
+
+    /// Shard names with their pools
+    pub fn pools(&self) -> impl Iterator<Item = (&str, &PgPool)> {
+        self.pools.iter().map(|(name, pool)| (name.as_str(), pool))
+    }
+
+    /// The pool of the tenant's shard; `None` means the default database
+    pub fn pool_for(&self, tenant: Option<&str>) -> Option<&PgPool> {
+        self.tenants.get(tenant?).and_then(|shard| self.pools.get(shard))
+    }
+
+    /// Runs `future` with every repository it calls routed to the tenant's shard
+    pub async fn scope<F: Future>(&self, tenant: Option<&str>, future: F) -> F::Output {
+        with_shard(self.pool_for(tenant).cloned(), future).await
+    }
+
+    pub async fn close(&self) {
+        for pool in self.pools.values() {
+            pool.close().await;
+        }
+    }
+}
+
+/// The shard pool the current request was routed to, to carry into tasks it spawns
+pub fn current_shard() -> Option<PgPool> {
+    CURRENT_SHARD.try_with(PgPool::clone).ok()
+}
+
+/// Runs `future` routed to `shard`, or to each repository's own pool when `None`
+pub async fn with_shard<F: Future>(shard: Option<PgPool>, future: F) -> F::Output {
+    match shard {
+        Some(pool) => CURRENT_SHARD.scope(pool, future).await,
+        None => future.await,
+    }
+}
+
+/// The pool to query: the current request's shard, otherwise the repository's own
+pub fn routed_pool(default: &PgPool) -> PgPool {
+    current_shard().unwrap_or_else(|| default.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::postgres::PgPoolOptions;
+
+    fn lazy_pool(database: &str) -> PgPool {
+        PgPoolOptions::new().connect_lazy(&format!("postgres://tester@127.0.0.1:1/{}", database)).unwrap()
+    }
+
+    fn database_of(pool: &PgPool) -> Option<String> {
+        pool.connect_options().get_database().map(str::to_string)
+    }
+
+    #[test]
+    fn test_parse_reads_shards_and_tenants() {
+        let map = ShardMap::parse("eu=postgres://db-eu/app; us=postgres://db-us/app", "acme=eu;globex=us").unwrap();
+
+        assert_eq!(map.shards().collect::<Vec<_>>(), vec![("eu", "postgres://db-eu/app"), ("us", "postgres://db-us/app")]);
+        assert_eq!(map.shard_for("acme"), Some("eu"));
+        assert_eq!(map.shard_for("initech"), None);
+        assert!(ShardMap::parse("", "").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_shard_and_malformed_entries() {
+        assert!(ShardMap::parse("eu=postgres://db-eu/app", "acme=us").is_err());
+        assert!(ShardMap::parse("postgres://db-eu/app", "").is_err());
+        assert!(ShardMap::parse("eu=postgres://db-eu/app", "acme=").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_scope_routes_only_mapped_tenants() {
+        let registry = ShardRegistry::new(
+            [("eu".to_string(), lazy_pool("eu"))].into_iter().collect(),
+            [("acme".to_string(), "eu".to_string())].into_iter().collect(),
+        );
+        let default = lazy_pool("main");
+
+        let acme = registry.scope(Some("acme"), async { routed_pool(&default) }).await;
+        let globex = registry.scope(Some("globex"), async { routed_pool(&default) }).await;
+        let anonymous = registry.scope(None, async { routed_pool(&default) }).await;
+
+        assert_eq!(database_of(&acme).as_deref(), Some("eu"));
+        assert_eq!(database_of(&globex).as_deref(), Some("main"));
+        assert_eq!(database_of(&anonymous).as_deref(), Some("main"));
+    }
+}
@@ -0,0 +1,110 @@
+use sqlx::pool::PoolConnection;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+use crate::database::routed_pool;
+
+tokio::task_local! {
+    static CURRENT_TRANSACTION: RequestTransaction;
+}
+
+/// Holds the transaction a request runs in, once a handler has asked for one.
+///
+/// Repositories cannot see request extensions, so the slot is also made ambient for the
+/// request's future through [`RequestTransaction::scope`] and picked up by [`connection`].
+#[derive(Debug, Clone)]
+pub struct RequestTransaction {
+    pool: PgPool,
+    slot: Arc<Mutex<Option<Transaction<'static, Postgres>>>>,
+}
+
+impl RequestTransaction {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            slot: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Runs `future` with this slot as the ambient transaction of every repository it calls
+    pub async fn scope<F: Future>(&self, future: F) -> F::Output {
+        CURRENT_TRANSACTION.scope(self.clone(), future).await
+    }
+
+    /// Starts the transaction, on the request's shard if it has one, unless one is already open
+    pub async fn begin(&self) -> Result<(), sqlx::Error> {
+        let mut slot = self.slot.lock().await;
+        if slot.is_none() {
+            *slot = Some(routed_pool(&self.pool).begin().await?);
+        }
+        Ok(())
+    }
+
+    /// Commits the transaction if one was started; afterwards repositories use the pool again
+    pub async fn commit(&self) -> Result<(), sqlx::Error> {
+        match self.slot.lock().await.take() {
+            Some(transaction) => transaction.commit().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Rolls the transaction back if one was started
+    pub async fn rollback(&self) -> Result<(), sqlx::Error> {
+        match self.slot.lock().await.take() {
+            Some(transaction) => transaction.rollback().await,
+            None => Ok(()),
+        }
+    }
+}
+
+/// Whether the current task runs inside an open request transaction
+pub async fn in_request_transaction() -> bool {
+    match CURRENT_TRANSACTION.try_with(|current| current.slot.clone()) {
+        Ok(slot) => slot.lock().await.is_some(),
+        Err(_) => false,
+    }
+}
+
+/// A connection from the pool, or the request's open transaction
+pub enum DbConnection {
+    Pooled(PoolConnection<Postgres>),
+    Transaction(OwnedMutexGuard<Option<Transaction<'static, Postgres>>>),
+}
+
+impl Deref for DbConnection {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &PgConnection {
+        match self {
+            DbConnection::Pooled(connection) => connection,
+            DbConnection::Transaction(slot) => slot.as_deref().expect("transaction slot checked when acquired"),
+        }
+    }
+}
+
+impl DerefMut for DbConnection {
+    fn deref_mut(&mut self) -> &mut PgConnection {
+        match self {
+            DbConnection::Pooled(connection) => connection,
+            DbConnection::Transaction(slot) => slot.as_deref_mut().expect("transaction slot checked when acquired"),
+        }
+    }
+}
+
+/// Acquires the connection a repository should query through.
+///
+/// Inside a request that opened a transaction this is that transaction, so every repository the
+/// handler touches commits or rolls back together; otherwise it is a pooled connection from the
+/// request's shard, or from `pool` when the request was not routed to one.
+pub async fn connection(pool: &PgPool) -> Result<DbConnection, sqlx::Error> {
+    if let Ok(slot) = CURRENT_TRANSACTION.try_with(|current| current.slot.clone()) {
+        let slot = slot.lock_owned().await;
+        if slot.is_some() {
+            return Ok(DbConnection::Transaction(slot));
+        }
+    }
+    Ok(DbConnection::Pooled(routed_pool(pool).acquire().await?))
+}
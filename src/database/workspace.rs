@@ -0,0 +1,42 @@
+use std::future::Future;
+
+use crate::domain::DEFAULT_WORKSPACE_ID;
+
+tokio::task_local! {
+    static CURRENT_WORKSPACE: String;
+}
+
+/// The workspace the current request was scoped to, to carry into tasks it spawns.
+///
+/// `None` outside a request: background jobs such as the audit retention run see every workspace.
+pub fn current_workspace() -> Option<String> {
+    CURRENT_WORKSPACE.try_with(String::clone).ok()
+}
+
+/// The workspace new tasks are placed in: the current one, or the default outside a request
+pub fn workspace_for_new_tasks() -> String {
+    current_workspace().unwrap_or_else(|| DEFAULT_WORKSPACE_ID.to_string())
+}
+
+/// Runs `future` with every task and status history query it makes limited to `workspace`,
+/// and every task it creates placed there; `None` leaves them unscoped
+pub async fn with_workspace<F: Future>(workspace: Option<String>, future: F) -> F::Output {
+    match workspace {
+        Some(workspace) => CURRENT_WORKSPACE.scope(workspace, future).await,
+        None => future.await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_workspace_is_only_visible_inside_its_scope() {
+        let scoped = with_workspace(Some("platform".to_string()), async { current_workspace() }).await;
+
+        assert_eq!(scoped.as_deref(), Some("platform"));
+        assert_eq!(with_workspace(None, async { current_workspace() }).await, None);
+        assert_eq!(current_workspace(), None);
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use crate::domain::value_objects::UserRole;
+
+/// Marks generated secrets so leaked keys are easy to recognise
+pub const API_KEY_PREFIX: &str = "tk_";
+
+/// Longest key name; keeps `api-key:<name>` within the history `changed_by` column
+pub const MAX_API_KEY_NAME_LENGTH: usize = 40;
+
+/// Leading characters of the secret kept in clear to identify a key
+const VISIBLE_KEY_CHARS: usize = 11;
+
+/// A credential that lets a machine client act with a fixed role in one workspace.
+///
+/// Only a SHA-256 hash of the secret is kept; the secret itself is shown once when issued.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiKey {
+    pub id: String,
+    pub name: String,
+    pub key_prefix: String,
+    pub key_hash: String,
+    pub role: UserRole,
+    /// The only workspace requests made with the key can reach
+    pub workspace_id: String,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    /// Issues a new key with the given id for `workspace_id`, returning it together with its secret
+    pub fn issue(
+        id: Uuid,
+        name: String,
+        role: UserRole,
+        workspace_id: String,
+        created_by: String,
+        created_at: DateTime<Utc>,
+    ) -> Result<(Self, String), String> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err("API key name cannot be empty".to_string());
+        }
+        if name.len() > MAX_API_KEY_NAME_LENGTH {
+            return Err(format!("API key name cannot exceed {} characters", MAX_API_KEY_NAME_LENGTH));
+        }
+        if role == UserRole::System {
+            return Err("API keys cannot be issued for the System role".to_string());
+        }
+
+        let secret = format!("{}{}{}", API_KEY_PREFIX, Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        let key = Self {
+            id: id.to_string(),
+            name,
+            key_prefix: secret[..VISIBLE_KEY_CHARS].to_string(),
+            key_hash: Self::hash_secret(&secret),
+            role,
+            workspace_id,
+            created_by,
+            created_at,
+        };
+        Ok((key, secret))
+    }
+
+    /// Hex-encoded SHA-256 of a secret, as stored and looked up
+    pub fn hash_secret(secret: &str) -> String {
+        Sha256::digest(secret.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    /// Identity recorded as `changed_by` for changes made with this key
+    pub fn principal(&self) -> String {
+        format!("api-key:{}", self.name)
+    }
+}
@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::domain::TaskId;
+
+/// Longest attachment file name
+pub const MAX_ATTACHMENT_FILE_NAME_LENGTH: usize = 255;
+
+/// Content type of uploads that do not name one
+pub const DEFAULT_ATTACHMENT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// A file uploaded to a task. Its bytes live in attachment storage under `storage_key`; only the
+/// metadata is kept beside the task.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attachment {
+    pub id: String,
+    pub task_id: TaskId,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub uploaded_by: String,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    /// Browsers may send the file's whole client-side path, so only its last component is kept
+    pub fn new(
+        id: Uuid,
+        task_id: TaskId,
+        file_name: &str,
+        content_type: Option<&str>,
+        size_bytes: usize,
+        uploaded_by: String,
+        now: DateTime<Utc>,
+    ) -> Result<Self, String> {
+        let file_name = file_name.rsplit(['/', '\\']).next().unwrap_or_default().trim().to_string();
+        let content_type = content_type.map(str::trim).filter(|value| !value.is_empty()).unwrap_or(DEFAULT_ATTACHMENT_CONTENT_TYPE);
+        let attachment = Self {
+            id: id.to_string(),
+            storage_key: format!("tasks/{}/{}", task_id.value(), id),
+            task_id,
+            file_name,
+            content_type: content_type.to_ascii_lowercase(),
+            size_bytes: size_bytes as i64,
+            uploaded_by,
+            uploaded_at: now,
+        };
+        attachment.validate()?;
+        Ok(attachment)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.file_name.is_empty() || self.file_name == "." || self.file_name == ".." {
+            return Err("Attachment file name cannot be empty".to_string());
+        }
+        if self.file_name.chars().count() > MAX_ATTACHMENT_FILE_NAME_LENGTH {
+            return Err(format!("Attachment file name cannot exceed {} characters", MAX_ATTACHMENT_FILE_NAME_LENGTH));
+        }
+        if self.file_name.chars().any(char::is_control) {
+            return Err("Attachment file name cannot contain control characters".to_string());
+        }
+        let valid_content_type = self.content_type.split_once('/').is_some_and(|(kind, subtype)| {
+            !kind.is_empty() && !subtype.is_empty() && self.content_type.chars().all(|c| c.is_ascii_graphic() || c == ' ')
+        });
+        if !valid_content_type {
+            return Err(format!("Attachment content type must look like type/subtype, got '{}'", self.content_type));
+        }
+        if self.size_bytes == 0 {
+            return Err("Attachment cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(file_name: &str, content_type: Option<&str>, size_bytes: usize) -> Result<Attachment, String> {
+        Attachment::new(Uuid::from_u128(1), TaskId::new(7), file_name, content_type, size_bytes, "ann".to_string(), Utc::now())
+    }
+
+    #[test]
+    fn test_new_keeps_the_last_path_component_and_defaults_the_content_type() {
+        let attachment = attachment("C:\\fakepath\\report.pdf", None, 10).unwrap();
+
+        assert_eq!(attachment.file_name, "report.pdf");
+        assert_eq!(attachment.content_type, DEFAULT_ATTACHMENT_CONTENT_TYPE);
+        assert_eq!(attachment.storage_key, "tasks/7/00000000-0000-0000-0000-000000000001");
+        assert_eq!(self::attachment("notes/today.txt", Some("Text/Plain"), 1).unwrap().content_type, "text/plain");
+    }
+
+    #[test]
+    fn test_new_rejects_unusable_uploads() {
+        for (file_name, content_type, size_bytes) in [
+            ("", None, 1),
+            ("dir/", None, 1),
+            ("..", None, 1),
+            ("a\nb.txt", None, 1),
+            (&"a".repeat(MAX_ATTACHMENT_FILE_NAME_LENGTH + 1), None, 1),
+            ("a.txt", Some("text"), 1),
+            ("a.txt", Some("text/plain\r\nx: y"), 1),
+            ("a.txt", None, 0),
+        ] {
+            assert!(attachment(file_name, content_type, size_bytes).is_err(), "{:?} {:?} {}", file_name, content_type, size_bytes);
+        }
+    }
+}
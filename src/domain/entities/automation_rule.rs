@@ -0,0 +1,225 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::events::{DomainEvent, TaskEvent, TaskField};
+use crate::domain::value_objects::TaskStatus;
+use crate::domain::entities::DEFAULT_WORKSPACE_ID;
+
+/// Longest rule name
+pub const MAX_AUTOMATION_RULE_NAME_LENGTH: usize = 80;
+
+/// Event types a rule can be triggered by, as serialized on [`TaskEvent`]
+pub const AUTOMATION_EVENT_TYPES: [&str; 6] = ["task_created", "task_updated", "task_status_changed", "task_deleted", "task_restored", "task_archived"];
+
+/// Narrows a trigger down to some of the events of its type; unset conditions match anything
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleFilter {
+    /// Status changes only: the status the task left
+    pub from_status: Option<TaskStatus>,
+    /// Status changes only: the status the task moved to
+    pub to_status: Option<TaskStatus>,
+    /// Updates only: a field the update changed
+    pub field: Option<TaskField>,
+    /// The task's priority is at least this
+    pub min_priority: Option<i32>,
+    /// The task's priority is at most this
+    pub max_priority: Option<i32>,
+}
+
+impl RuleFilter {
+    /// Whether the filter needs the task's priority to decide
+    pub fn checks_priority(&self) -> bool {
+        self.min_priority.is_some() || self.max_priority.is_some()
+    }
+}
+
+/// What a rule reacts to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleTrigger {
+    pub event_type: String,
+    #[serde(default)]
+    pub filter: RuleFilter,
+}
+
+/// What a rule does to the task whose event matched
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RuleAction {
+    SetPriority { priority: i32 },
+    /// POSTs an [`AutomationNotification`] to `url`
+    Notify { url: String },
+}
+
+/// A rule that acts on tasks when their events match its trigger, in its own workspace only
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutomationRule {
+    pub id: String,
+    pub workspace_id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub trigger: RuleTrigger,
+    pub action: RuleAction,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl AutomationRule {
+    pub fn new(
+        id: Uuid,
+        name: String,
+        enabled: bool,
+        trigger: RuleTrigger,
+        action: RuleAction,
+        created_by: String,
+        now: DateTime<Utc>,
+    ) -> Result<Self, String> {
+        let rule = Self {
+            id: id.to_string(),
+            workspace_id: DEFAULT_WORKSPACE_ID.to_string(),
+            name: name.trim().to_string(),
+            enabled,
+            trigger,
+            action,
+            created_by,
+            created_at: now,
+            updated_at: now,
+        };
+        rule.validate()?;
+        Ok(rule)
+    }
+
+    /// Places the rule in `workspace_id` instead of the default workspace
+    pub fn in_workspace(mut self, workspace_id: impl Into<String>) -> Self {
+        self.workspace_id = workspace_id.into();
+        self
+    }
+
+    /// Replaces the rule's definition, keeping its id and creation
+    pub fn redefine(&mut self, name: String, enabled: bool, trigger: RuleTrigger, action: RuleAction, now: DateTime<Utc>) -> Result<(), String> {
+        let redefined = Self { name: name.trim().to_string(), enabled, trigger, action, updated_at: now, ..self.clone() };
+        redefined.validate()?;
+        *self = redefined;
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() {
+            return Err("Rule name cannot be empty".to_string());
+        }
+        if self.name.chars().count() > MAX_AUTOMATION_RULE_NAME_LENGTH {
+            return Err(format!("Rule name cannot exceed {} characters", MAX_AUTOMATION_RULE_NAME_LENGTH));
+        }
+
+        let event_type = self.trigger.event_type.as_str();
+        if !AUTOMATION_EVENT_TYPES.contains(&event_type) {
+            return Err(format!("event_type must be one of {}, got '{}'", AUTOMATION_EVENT_TYPES.join(", "), event_type));
+        }
+        let filter = &self.trigger.filter;
+        if (filter.from_status.is_some() || filter.to_status.is_some()) && event_type != "task_status_changed" {
+            return Err("from_status and to_status only apply to task_status_changed".to_string());
+        }
+        if filter.field.is_some() && event_type != "task_updated" {
+            return Err("field only applies to task_updated".to_string());
+        }
+        if let (Some(min), Some(max)) = (filter.min_priority, filter.max_priority) {
+            if min > max {
+                return Err(format!("min_priority {} is above max_priority {}", min, max));
+            }
+        }
+
+        match &self.action {
+            RuleAction::SetPriority { .. } if event_type == "task_deleted" => {
+                Err("set_priority cannot act on deleted tasks".to_string())
+            }
+            RuleAction::Notify { url } if !(url.starts_with("http://") || url.starts_with("https://")) => {
+                Err(format!("notify url must be an http:// or https:// URL, got '{}'", url))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether `event` fires this rule, given the task's priority when the filter needs it
+    pub fn matches(&self, event: &TaskEvent, priority: Option<i32>) -> bool {
+        if !self.enabled || event.event_type() != self.trigger.event_type {
+            return false;
+        }
+        let filter = &self.trigger.filter;
+        if let TaskEvent::TaskStatusChanged(changed) = event {
+            if filter.from_status.as_ref().is_some_and(|status| *status != changed.from_status)
+                || filter.to_status.as_ref().is_some_and(|status| *status != changed.to_status) {
+                return false;
+            }
+        }
+        if let (TaskEvent::TaskUpdated(updated), Some(field)) = (event, filter.field) {
+            if !updated.touches(field) {
+                return false;
+            }
+        }
+        if filter.checks_priority() {
+            let Some(priority) = priority else { return false };
+            if filter.min_priority.is_some_and(|min| priority < min) || filter.max_priority.is_some_and(|max| priority > max) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// What a `notify` action sends
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutomationNotification {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub event: DomainEvent,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{TaskStatusChanged, TaskUpdated, FieldChange};
+
+    fn rule(event_type: &str, filter: RuleFilter, action: RuleAction) -> Result<AutomationRule, String> {
+        let trigger = RuleTrigger { event_type: event_type.to_string(), filter };
+        AutomationRule::new(Uuid::nil(), "Escalate".to_string(), true, trigger, action, "ann".to_string(), Utc::now())
+    }
+
+    fn status_changed(from: TaskStatus, to: TaskStatus) -> TaskEvent {
+        TaskEvent::TaskStatusChanged(TaskStatusChanged { task_id: 1, from_status: from, to_status: to, comment: None, occurred_at: Utc::now() })
+    }
+
+    #[test]
+    fn test_matches_checks_event_type_filter_and_priority() {
+        let filter = RuleFilter { to_status: Some(TaskStatus::InProgress), min_priority: Some(3), ..RuleFilter::default() };
+        let rule = rule("task_status_changed", filter, RuleAction::SetPriority { priority: 1 }).unwrap();
+
+        assert!(rule.matches(&status_changed(TaskStatus::Pending, TaskStatus::InProgress), Some(4)));
+        assert!(!rule.matches(&status_changed(TaskStatus::Pending, TaskStatus::InProgress), Some(2)));
+        assert!(!rule.matches(&status_changed(TaskStatus::Pending, TaskStatus::InProgress), None));
+        assert!(!rule.matches(&status_changed(TaskStatus::Pending, TaskStatus::Cancelled), Some(4)));
+
+        let renamed = TaskEvent::TaskUpdated(TaskUpdated {
+            task_id: 1,
+            changes: vec![FieldChange { field: TaskField::Name, old_value: None, new_value: Some("New".to_string()) }],
+            occurred_at: Utc::now(),
+        });
+        let on_priority = RuleFilter { field: Some(TaskField::Priority), ..RuleFilter::default() };
+        let rule = self::rule("task_updated", on_priority, RuleAction::Notify { url: "http://hooks/1".to_string() }).unwrap();
+        assert!(!rule.matches(&renamed, None));
+    }
+
+    #[test]
+    fn test_new_rejects_inconsistent_rules() {
+        let notify = || RuleAction::Notify { url: "https://hooks.example.com".to_string() };
+        let cases = [
+            rule("task_moved", RuleFilter::default(), notify()),
+            rule("task_created", RuleFilter { to_status: Some(TaskStatus::Completed), ..RuleFilter::default() }, notify()),
+            rule("task_created", RuleFilter { min_priority: Some(5), max_priority: Some(1), ..RuleFilter::default() }, notify()),
+            rule("task_deleted", RuleFilter::default(), RuleAction::SetPriority { priority: 1 }),
+            rule("task_created", RuleFilter::default(), RuleAction::Notify { url: "ftp://hooks".to_string() }),
+        ];
+        for case in cases {
+            assert!(case.is_err(), "{:?}", case);
+        }
+    }
+}
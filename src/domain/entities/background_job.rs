@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// A recurring background job's schedule and the outcome of its last run.
+///
+/// The state is shared through the database, so every replica sees an operator's pause or
+/// run request and each run is claimed by exactly one of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundJob {
+    pub name: String,
+    pub interval: Duration,
+    pub paused: bool,
+    /// An operator asked for a run ahead of schedule; it happens even while paused
+    pub run_requested: bool,
+    pub last_started_at: Option<DateTime<Utc>>,
+    pub last_finished_at: Option<DateTime<Utc>>,
+    /// Why the last run failed; cleared by a successful run
+    pub last_error: Option<String>,
+    pub next_run_at: DateTime<Utc>,
+}
+
+impl BackgroundJob {
+    /// Whether a scheduler should start a run at `now`
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.run_requested || (!self.paused && self.next_run_at <= now)
+    }
+
+    /// A run was started and has not finished yet
+    pub fn is_running(&self) -> bool {
+        match (self.last_started_at, self.last_finished_at) {
+            (Some(started), Some(finished)) => started > finished,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
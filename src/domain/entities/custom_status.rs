@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use crate::domain::value_objects::{StatusCategory, TaskStatus};
+
+/// A status admins added next to the built-in ones, such as `Blocked` or `QA`.
+///
+/// Its category says what it means for reporting and dependencies. Tasks only reach it through
+/// a workflow that lists it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomStatus {
+    pub status: TaskStatus,
+    pub category: StatusCategory,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CustomStatus {
+    pub fn new(name: &str, category: StatusCategory, created_by: String, now: DateTime<Utc>) -> Result<Self, String> {
+        Ok(Self { status: TaskStatus::custom(name)?, category, created_by, created_at: now })
+    }
+
+    pub fn name(&self) -> &str {
+        self.status.as_str()
+    }
+}
@@ -1,3 +1,15 @@
+pub mod api_key;
+pub mod attachment;
+pub mod automation_rule;
+pub mod background_job;
+pub mod custom_status;
 pub mod task;
+pub mod workspace;
 
-pub use task::*;
\ No newline at end of file
+pub use api_key::*;
+pub use attachment::*;
+pub use automation_rule::*;
+pub use background_job::*;
+pub use custom_status::*;
+pub use task::*;
+pub use workspace::*;
@@ -1,165 +1,314 @@
-use crate::domain::value_objects::{TaskId, TaskStatus, UserRole};
+use crate::domain::errors::DomainError;
+use crate::domain::policies::HighPriorityPolicy;
+use crate::domain::ports::{Clock, SystemClock};
+use crate::domain::value_objects::{PriorityScale, Rank, TaskId, TaskStatus, UserRole, WorkflowDefinition};
 use chrono::{DateTime, Utc};
 
+/// Maximum number of characters allowed in a task description
+pub const MAX_DESCRIPTION_LENGTH: usize = 10_000;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Task {
     pub id: TaskId,
     pub name: String,
     pub priority: Option<i32>,
+    /// Optional long-form description in Markdown
+    pub description: Option<String>,
     pub status: TaskStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set when the task has been soft-deleted
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Set when the finished task was archived; archived tasks are left out of listings and cannot change
+    pub archived_at: Option<DateTime<Utc>>,
+    /// User id of whoever created the task; `None` for tasks created before creators were recorded
+    pub created_by: Option<String>,
+    /// Set once the task was placed by hand; until then it ranks by id
+    pub rank: Option<Rank>,
 }
 
 impl Task {
-    pub fn new(id: TaskId, name: String, priority: Option<i32>) -> Result<Self, String> {
-        if name.trim().is_empty() {
-            return Err("Task name cannot be empty".to_string());
-        }
-        
-        if let Some(priority) = priority {
-            if priority < 1 || priority > 10 {
-                return Err("Priority must be between 1 and 10".to_string());
-            }
-        }
+    /// A new Pending task created now by the wall clock; see `new_at`
+    pub fn new(id: TaskId, name: String, priority: Option<i32>) -> Result<Self, DomainError> {
+        Self::new_at(id, name, priority, SystemClock.now())
+    }
 
-        let now = Utc::now();
-        Ok(Task {
-            id,
-            name: name.trim().to_string(),
-            priority,
-            status: TaskStatus::default(),
-            created_at: now,
-            updated_at: now,
-        })
+    /// A new Pending task created at `now`
+    pub fn new_at(id: TaskId, name: String, priority: Option<i32>, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        Self::new_with_status(id, name, priority, TaskStatus::default(), now, now)
     }
 
-    pub fn new_with_status(id: TaskId, name: String, priority: Option<i32>, status: TaskStatus, created_at: DateTime<Utc>, updated_at: DateTime<Utc>) -> Result<Self, String> {
+    pub fn new_with_status(id: TaskId, name: String, priority: Option<i32>, status: TaskStatus, created_at: DateTime<Utc>, updated_at: DateTime<Utc>) -> Result<Self, DomainError> {
         if name.trim().is_empty() {
-            return Err("Task name cannot be empty".to_string());
+            return Err(DomainError::EmptyName);
         }
         
-        if let Some(priority) = priority {
-            if priority < 1 || priority > 10 {
-                return Err("Priority must be between 1 and 10".to_string());
-            }
-        }
+        PriorityScale::full().validate(priority)?;
 
         Ok(Task {
             id,
             name: name.trim().to_string(),
             priority,
+            description: None,
             status,
             created_at,
             updated_at,
+            deleted_at: None,
+            archived_at: None,
+            created_by: None,
+            rank: None,
         })
     }
 
-    pub fn update_name(&mut self, name: String) -> Result<(), String> {
+    pub fn with_description(mut self, description: Option<String>) -> Result<Self, DomainError> {
+        self.description = Self::normalize_description(description)?;
+        Ok(self)
+    }
+
+    pub fn with_deleted_at(mut self, deleted_at: Option<DateTime<Utc>>) -> Self {
+        self.deleted_at = deleted_at;
+        self
+    }
+
+    pub fn with_archived_at(mut self, archived_at: Option<DateTime<Utc>>) -> Self {
+        self.archived_at = archived_at;
+        self
+    }
+
+    pub fn with_created_by(mut self, created_by: Option<String>) -> Self {
+        self.created_by = created_by;
+        self
+    }
+
+    pub fn with_rank(mut self, rank: Option<Rank>) -> Self {
+        self.rank = rank;
+        self
+    }
+
+    /// Where the task sits when tasks are ordered by hand
+    pub fn rank(&self) -> Rank {
+        self.rank.clone().unwrap_or_else(|| Rank::for_id(self.id))
+    }
+
+    /// Places the task after `previous` and ahead of `next` in its status's column; without
+    /// `previous` it goes first, without `next` last. Only the task's own rank changes.
+    pub fn move_between(&mut self, previous: Option<&Task>, next: Option<&Task>) -> Result<(), DomainError> {
+        self.ensure_not_archived()?;
+        if let Some(neighbour) = previous.into_iter().chain(next).find(|neighbour| neighbour.status != self.status) {
+            return Err(DomainError::NeighbourInOtherColumn { neighbour: neighbour.id.value(), status: self.status.clone() });
+        }
+        let rank = Rank::between(previous.map(Task::rank).as_ref(), next.map(Task::rank).as_ref())
+            .map_err(|_| DomainError::NeighboursOutOfOrder {
+                previous: previous.map_or(0, |task| task.id.value()),
+                next: next.map_or(0, |task| task.id.value()),
+            })?;
+        self.rank = Some(rank);
+        Ok(())
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// Archives a completed or cancelled task at `now`
+    pub fn archive_at(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_not_archived()?;
+        if !matches!(self.status, TaskStatus::Completed | TaskStatus::Cancelled) {
+            return Err(DomainError::ArchiveUnfinished { status: self.status.clone() });
+        }
+        self.archived_at = Some(now);
+        Ok(())
+    }
+
+    fn ensure_not_archived(&self) -> Result<(), DomainError> {
+        match self.is_archived() {
+            true => Err(DomainError::TaskArchived),
+            false => Ok(()),
+        }
+    }
+
+    pub fn update_name(&mut self, name: String) -> Result<(), DomainError> {
+        self.update_name_at(name, SystemClock.now())
+    }
+
+    pub fn update_name_at(&mut self, name: String, now: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_not_archived()?;
         if name.trim().is_empty() {
-            return Err("Task name cannot be empty".to_string());
+            return Err(DomainError::EmptyName);
         }
         self.name = name.trim().to_string();
-        self.updated_at = Utc::now();
+        self.updated_at = now;
         Ok(())
     }
 
-    pub fn update_priority(&mut self, priority: Option<i32>) -> Result<(), String> {
-        if let Some(priority) = priority {
-            if priority < 1 || priority > 10 {
-                return Err("Priority must be between 1 and 10".to_string());
-            }
-        }
+    pub fn update_priority(&mut self, priority: Option<i32>) -> Result<(), DomainError> {
+        self.update_priority_at(priority, SystemClock.now())
+    }
+
+    pub fn update_priority_at(&mut self, priority: Option<i32>, now: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_not_archived()?;
+        PriorityScale::full().validate(priority)?;
         self.priority = priority;
-        self.updated_at = Utc::now();
+        self.updated_at = now;
         Ok(())
     }
 
+    /// Replaces the description; a blank value clears it
+    pub fn update_description(&mut self, description: Option<String>) -> Result<(), DomainError> {
+        self.update_description_at(description, SystemClock.now())
+    }
+
+    pub fn update_description_at(&mut self, description: Option<String>, now: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_not_archived()?;
+        self.description = Self::normalize_description(description)?;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    fn normalize_description(description: Option<String>) -> Result<Option<String>, DomainError> {
+        match description {
+            Some(description) if description.trim().is_empty() => Ok(None),
+            Some(description) => {
+                if description.chars().count() > MAX_DESCRIPTION_LENGTH {
+                    return Err(DomainError::DescriptionTooLong { max: MAX_DESCRIPTION_LENGTH });
+                }
+                Ok(Some(description))
+            }
+            None => Ok(None),
+        }
+    }
+
     pub fn status(&self) -> &TaskStatus {
         &self.status
     }
 
+    fn invalid_transition(&self, to: TaskStatus) -> DomainError {
+        DomainError::InvalidTransition { from: self.status.clone(), to }
+    }
+
+    /// High priority under the default policy; see `is_high_priority_under`
     pub fn is_high_priority(&self) -> bool {
-        self.priority.map_or(false, |p| p <= 3)
+        self.is_high_priority_under(&HighPriorityPolicy::default())
+    }
+
+    pub fn is_high_priority_under(&self, policy: &HighPriorityPolicy) -> bool {
+        policy.is_high_priority(self.priority)
+    }
+
+    pub fn start_progress(&mut self) -> Result<(), DomainError> {
+        self.start_progress_at(SystemClock.now())
     }
 
-    pub fn start_progress(&mut self) -> Result<(), String> {
-        if !self.status.can_transition_to(&TaskStatus::InProgress) {
-            return Err("Cannot start progress on task in current status".to_string());
+    fn start_progress_at(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != TaskStatus::Pending {
+            return Err(self.invalid_transition(TaskStatus::InProgress));
         }
         
         self.status = TaskStatus::InProgress;
-        self.updated_at = Utc::now();
+        self.updated_at = now;
         Ok(())
     }
 
-    pub fn complete(&mut self) -> Result<(), String> {
-        if self.is_high_priority() {
+    pub fn complete(&mut self) -> Result<(), DomainError> {
+        self.complete_as(self.is_high_priority(), SystemClock.now())
+    }
+
+    fn complete_as(&mut self, high_priority: bool, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if high_priority {
             if !self.status.can_transition_to(&TaskStatus::PendingReview) {
-                return Err("Cannot complete high-priority task without review".to_string());
+                return Err(self.invalid_transition(TaskStatus::PendingReview));
             }
             self.status = TaskStatus::PendingReview;
         } else {
             if !self.status.can_transition_to(&TaskStatus::Completed) {
-                return Err("Cannot complete task in current status".to_string());
+                return Err(self.invalid_transition(TaskStatus::Completed));
             }
             self.status = TaskStatus::Completed;
         }
         
-        self.updated_at = Utc::now();
+        self.updated_at = now;
         Ok(())
     }
 
-    pub fn complete_with_role(&mut self, user_role: &UserRole) -> Result<(), String> {
-        match (self.status(), self.is_high_priority()) {
+    pub fn complete_with_role(&mut self, user_role: &UserRole) -> Result<(), DomainError> {
+        self.complete_with_role_as(user_role, self.is_high_priority(), SystemClock.now())
+    }
+
+    fn complete_with_role_as(&mut self, user_role: &UserRole, high_priority: bool, now: DateTime<Utc>) -> Result<(), DomainError> {
+        match (self.status(), high_priority) {
             // Low priority tasks can be completed directly
             (TaskStatus::InProgress, false) => {
                 self.status = TaskStatus::Completed;
-                self.updated_at = Utc::now();
+                self.updated_at = now;
                 Ok(())
             }
             // High priority tasks need review first
             (TaskStatus::InProgress, true) => {
                 self.status = TaskStatus::PendingReview;
-                self.updated_at = Utc::now();
+                self.updated_at = now;
                 Ok(())
             }
             // Only managers can approve from review
             (TaskStatus::PendingReview, _) if user_role.can_approve() => {
                 self.status = TaskStatus::Completed;
-                self.updated_at = Utc::now();
+                self.updated_at = now;
                 Ok(())
             }
-            (TaskStatus::PendingReview, _) => {
-                Err("Only managers can approve task completion".to_string())
-            }
-            _ => Err("Cannot complete task in current status".to_string()),
+            (TaskStatus::PendingReview, _) => Err(DomainError::ApprovalNotAllowed),
+            _ => Err(self.invalid_transition(TaskStatus::Completed)),
         }
     }
 
-    pub fn approve_completion(&mut self) -> Result<(), String> {
+    pub fn approve_completion(&mut self) -> Result<(), DomainError> {
         if self.status != TaskStatus::PendingReview {
-            return Err("Can only approve tasks in PendingReview status".to_string());
+            return Err(self.invalid_transition(TaskStatus::Completed));
         }
         
         self.status = TaskStatus::Completed;
-        self.updated_at = Utc::now();
+        self.updated_at = SystemClock.now();
         Ok(())
     }
 
-    pub fn cancel(&mut self) -> Result<(), String> {
+    /// Sends a completed task back to InProgress for rework
+    pub fn reopen(&mut self, user_role: &UserRole) -> Result<(), DomainError> {
+        self.reopen_at(user_role, SystemClock.now())
+    }
+
+    fn reopen_at(&mut self, user_role: &UserRole, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status != TaskStatus::Completed {
+            return Err(self.invalid_transition(TaskStatus::InProgress));
+        }
+        if !user_role.can_approve() {
+            return Err(DomainError::ReopenNotAllowed);
+        }
+
+        self.status = TaskStatus::InProgress;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    pub fn cancel(&mut self) -> Result<(), DomainError> {
+        self.cancel_at(SystemClock.now())
+    }
+
+    pub fn cancel_at(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
         if self.status == TaskStatus::Completed {
-            return Err("Cannot cancel completed tasks".to_string());
+            return Err(self.invalid_transition(TaskStatus::Cancelled));
         }
         
         self.status = TaskStatus::Cancelled;
-        self.updated_at = Utc::now();
+        self.updated_at = now;
         Ok(())
     }
 
-    pub fn transition_to(&mut self, new_status: TaskStatus) -> Result<(), String> {
+    pub fn transition_to(&mut self, new_status: TaskStatus) -> Result<(), DomainError> {
+        self.ensure_not_archived()?;
         if !self.status.can_transition_to(&new_status) {
-            return Err(format!("Invalid transition from {:?} to {:?}", self.status, new_status));
+            return Err(self.invalid_transition(new_status));
         }
         
         match new_status {
@@ -175,33 +324,55 @@ impl Task {
                 if self.is_high_priority() && self.status == TaskStatus::InProgress {
                     self.complete()
                 } else {
-                    Err("Only high-priority tasks can transition to PendingReview".to_string())
+                    Err(DomainError::ReviewNotRequired)
                 }
             },
             TaskStatus::Cancelled => self.cancel(),
-            _ => Err("Invalid status transition".to_string()),
+            _ => Err(self.invalid_transition(new_status)),
         }
     }
 
-    pub fn transition_to_with_role(&mut self, new_status: TaskStatus, user_role: &UserRole) -> Result<(), String> {
+    pub fn transition_to_with_role(&mut self, new_status: TaskStatus, user_role: &UserRole) -> Result<(), DomainError> {
+        self.transition_under_policy(new_status, user_role, &HighPriorityPolicy::default())
+    }
+
+    /// Moves the task as `workflow` allows, stamped with `now`. Whether the task counts as high
+    /// priority is decided by the caller's priority policy.
+    pub fn transition_in_workflow_at(&mut self, new_status: TaskStatus, user_role: &UserRole, workflow: &WorkflowDefinition, is_high_priority: bool, now: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_not_archived()?;
+        workflow.check(&self.status, &new_status, is_high_priority, user_role)?;
+
+        self.status = new_status;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Role-aware transition where the policy decides whether the task needs review
+    pub fn transition_under_policy(&mut self, new_status: TaskStatus, user_role: &UserRole, policy: &HighPriorityPolicy) -> Result<(), DomainError> {
+        self.transition_under_policy_at(new_status, user_role, policy, SystemClock.now())
+    }
+
+    /// `transition_under_policy` stamped with `now` instead of the wall clock
+    pub fn transition_under_policy_at(&mut self, new_status: TaskStatus, user_role: &UserRole, policy: &HighPriorityPolicy, now: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_not_archived()?;
         if !self.status.can_transition_to(&new_status) {
-            return Err(format!("Invalid transition from {:?} to {:?}", self.status, new_status));
+            return Err(self.invalid_transition(new_status));
         }
-        
+
+        let high_priority = self.is_high_priority_under(policy);
         match new_status {
-            TaskStatus::InProgress => self.start_progress(),
-            TaskStatus::Completed => self.complete_with_role(user_role),
+            TaskStatus::InProgress if self.status == TaskStatus::Completed => self.reopen_at(user_role, now),
+            TaskStatus::InProgress => self.start_progress_at(now),
+            TaskStatus::Completed => self.complete_with_role_as(user_role, high_priority, now),
             TaskStatus::PendingReview => {
-                if self.is_high_priority() && self.status == TaskStatus::InProgress {
-                    self.complete()
+                if high_priority && self.status == TaskStatus::InProgress {
+                    self.complete_as(true, now)
                 } else {
-                    Err("Only high-priority tasks can transition to PendingReview".to_string())
+                    Err(DomainError::ReviewNotRequired)
                 }
             },
-            TaskStatus::Cancelled => self.cancel(),
-            _ => Err("Invalid status transition".to_string()),
+            TaskStatus::Cancelled => self.cancel_at(now),
+            _ => Err(self.invalid_transition(new_status)),
         }
     }
 }
-
-
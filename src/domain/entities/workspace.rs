@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+
+/// Workspace of requests without a tenant, and of every task created before workspaces existed
+pub const DEFAULT_WORKSPACE_ID: &str = "default";
+
+/// Longest workspace id; ids are stored alongside every task and history entry
+pub const MAX_WORKSPACE_ID_LENGTH: usize = 50;
+
+/// Longest workspace name
+pub const MAX_WORKSPACE_NAME_LENGTH: usize = 100;
+
+/// A team's share of a deployment; its tasks and history are invisible to every other workspace.
+///
+/// The id is what callers send as their tenant, so it is a short lowercase slug.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Workspace {
+    pub id: String,
+    pub name: String,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Workspace {
+    pub fn new(id: String, name: String, created_by: String, now: DateTime<Utc>) -> Result<Self, String> {
+        Self::validate_id(&id)?;
+        let workspace = Self { id, name: name.trim().to_string(), created_by, created_at: now };
+        workspace.validate_name()?;
+        Ok(workspace)
+    }
+
+    pub fn rename(&mut self, name: String) -> Result<(), String> {
+        let renamed = Self { name: name.trim().to_string(), ..self.clone() };
+        renamed.validate_name()?;
+        *self = renamed;
+        Ok(())
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.id == DEFAULT_WORKSPACE_ID
+    }
+
+    /// Ids are lowercase letters, digits and hyphens, starting with a letter or digit
+    pub fn validate_id(id: &str) -> Result<(), String> {
+        if id.is_empty() {
+            return Err("Workspace id cannot be empty".to_string());
+        }
+        if id.len() > MAX_WORKSPACE_ID_LENGTH {
+            return Err(format!("Workspace id cannot exceed {} characters", MAX_WORKSPACE_ID_LENGTH));
+        }
+        let valid = id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') && !id.starts_with('-');
+        if !valid {
+            return Err(format!("Workspace id must be lowercase letters, digits and hyphens, got '{}'", id));
+        }
+        Ok(())
+    }
+
+    fn validate_name(&self) -> Result<(), String> {
+        if self.name.is_empty() {
+            return Err("Workspace name cannot be empty".to_string());
+        }
+        if self.name.chars().count() > MAX_WORKSPACE_NAME_LENGTH {
+            return Err(format!("Workspace name cannot exceed {} characters", MAX_WORKSPACE_NAME_LENGTH));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_accepts_slugs_only() {
+        let workspace = |id: &str| Workspace::new(id.to_string(), " Platform ".to_string(), "ann".to_string(), Utc::now());
+
+        assert_eq!(workspace("platform-2").unwrap().name, "Platform");
+        for id in ["", "Platform", "-platform", "plat form", "plat_form", &"a".repeat(MAX_WORKSPACE_ID_LENGTH + 1)] {
+            assert!(workspace(id).is_err(), "{:?}", id);
+        }
+        assert!(Workspace::new("platform".to_string(), "  ".to_string(), "ann".to_string(), Utc::now()).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::domain::{TaskStatus, UserRole};
+
+/// A business rule a task change broke.
+///
+/// The message is what API clients see; [`code`](Self::code) is the stable identifier they can
+/// match on instead.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DomainError {
+    #[error("Task name cannot be empty")]
+    EmptyName,
+    #[error("Task name cannot exceed {max} characters")]
+    NameTooLong { max: usize },
+    #[error("Task description cannot exceed {max} characters")]
+    DescriptionTooLong { max: usize },
+    #[error("Search query cannot be empty")]
+    EmptySearchQuery,
+    #[error("Search query cannot exceed {max} characters")]
+    SearchQueryTooLong { max: usize },
+    #[error("Invalid filter: {message}")]
+    InvalidFilter { message: String },
+    #[error("Priority must be between {min} and {max}")]
+    PriorityOutOfRange { priority: i32, min: i32, max: i32 },
+    #[error("Invalid transition from {from:?} to {to:?}")]
+    InvalidTransition { from: TaskStatus, to: TaskStatus },
+    #[error("High-priority tasks must go through review before completion")]
+    ReviewRequired,
+    #[error("Only high-priority tasks can transition to PendingReview")]
+    ReviewNotRequired,
+    #[error("Only managers can approve task completion")]
+    ApprovalNotAllowed,
+    #[error("Only managers can reopen completed tasks")]
+    ReopenNotAllowed,
+    #[error("Only a {} or above can move a task from {} to {}", role.as_str(), from.as_str(), to.as_str())]
+    TransitionRoleRequired { from: TaskStatus, to: TaskStatus, role: UserRole },
+    #[error("Tasks can only be reopened within {window_days} days of completion")]
+    ReopenWindowExpired { window_days: u32 },
+    #[error("A comment is required to move a task from {} to {}", from.as_str(), to.as_str())]
+    CommentRequired { from: TaskStatus, to: TaskStatus },
+    #[error("Task is blocked by unfinished tasks: {}", join_ids(blockers))]
+    BlockedByUnfinishedTasks { blockers: Vec<i32> },
+    #[error("Approval requires the review checklist: {}", items.join(", "))]
+    ChecklistRequired { items: Vec<String> },
+    #[error("Unknown checklist item: {item}")]
+    UnknownChecklistItem { item: String },
+    #[error("Checklist item not completed: {item}")]
+    ChecklistItemNotCompleted { item: String },
+    #[error("A review checklist can only be submitted when approving a task")]
+    ChecklistNotExpected,
+    #[error("The '{stage}' review stage must be signed off by a {} or above", role.as_str())]
+    ReviewStageNotAllowed { stage: String, role: UserRole },
+    #[error("You already signed off an earlier stage; another reviewer must sign off the '{stage}' stage")]
+    ReviewerAlreadySignedOff { stage: String },
+    #[error("A task cannot depend on itself")]
+    SelfDependency,
+    #[error("Task {depends_on} already depends on task {task_id}; the dependency would create a cycle")]
+    DependencyCycle { task_id: i32, depends_on: i32 },
+    #[error("Only completed or cancelled tasks can be archived, not {} ones", status.as_str())]
+    ArchiveUnfinished { status: TaskStatus },
+    #[error("Archived tasks cannot be changed")]
+    TaskArchived,
+    #[error("Task {neighbour} is not in the {} column", status.as_str())]
+    NeighbourInOtherColumn { neighbour: i32, status: TaskStatus },
+    #[error("Task {previous} does not sort ahead of task {next}")]
+    NeighboursOutOfOrder { previous: i32, next: i32 },
+    #[error("The approval system rejected the change: {reason}")]
+    TransitionRejected { reason: String },
+    #[error("The approval system could not be reached; try again later")]
+    TransitionGateUnavailable,
+}
+
+impl DomainError {
+    /// Machine-readable identifier, stable across message rewording
+    pub fn code(&self) -> &'static str {
+        match self {
+            DomainError::EmptyName => "empty_name",
+            DomainError::NameTooLong { .. } => "name_too_long",
+            DomainError::DescriptionTooLong { .. } => "description_too_long",
+            DomainError::EmptySearchQuery => "empty_search_query",
+            DomainError::SearchQueryTooLong { .. } => "search_query_too_long",
+            DomainError::InvalidFilter { .. } => "invalid_filter",
+            DomainError::PriorityOutOfRange { .. } => "priority_out_of_range",
+            DomainError::InvalidTransition { .. } => "invalid_transition",
+            DomainError::ReviewRequired => "review_required",
+            DomainError::ReviewNotRequired => "review_not_required",
+            DomainError::ApprovalNotAllowed => "approval_not_allowed",
+            DomainError::ReopenNotAllowed => "reopen_not_allowed",
+            DomainError::TransitionRoleRequired { .. } => "transition_role_required",
+            DomainError::ReopenWindowExpired { .. } => "reopen_window_expired",
+            DomainError::CommentRequired { .. } => "comment_required",
+            DomainError::BlockedByUnfinishedTasks { .. } => "blocked_by_unfinished_tasks",
+            DomainError::ChecklistRequired { .. } => "checklist_required",
+            DomainError::UnknownChecklistItem { .. } => "unknown_checklist_item",
+            DomainError::ChecklistItemNotCompleted { .. } => "checklist_item_not_completed",
+            DomainError::ChecklistNotExpected => "checklist_not_expected",
+            DomainError::ReviewStageNotAllowed { .. } => "review_stage_not_allowed",
+            DomainError::ReviewerAlreadySignedOff { .. } => "reviewer_already_signed_off",
+            DomainError::SelfDependency => "self_dependency",
+            DomainError::DependencyCycle { .. } => "dependency_cycle",
+            DomainError::ArchiveUnfinished { .. } => "archive_unfinished",
+            DomainError::TaskArchived => "task_archived",
+            DomainError::NeighbourInOtherColumn { .. } => "neighbour_in_other_column",
+            DomainError::NeighboursOutOfOrder { .. } => "neighbours_out_of_order",
+            DomainError::TransitionRejected { .. } => "transition_rejected",
+            DomainError::TransitionGateUnavailable => "transition_gate_unavailable",
+        }
+    }
+}
+
+fn join_ids(ids: &[i32]) -> String {
+    ids.iter().map(i32::to_string).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_carry_the_details() {
+        assert_eq!(
+            DomainError::InvalidTransition { from: TaskStatus::Completed, to: TaskStatus::Pending }.to_string(),
+            "Invalid transition from Completed to Pending"
+        );
+        assert_eq!(
+            DomainError::BlockedByUnfinishedTasks { blockers: vec![2, 5] }.to_string(),
+            "Task is blocked by unfinished tasks: 2, 5"
+        );
+        assert_eq!(DomainError::PriorityOutOfRange { priority: 12, min: 1, max: 10 }.code(), "priority_out_of_range");
+    }
+}
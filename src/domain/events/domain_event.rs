@@ -0,0 +1,143 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::{Task, TaskStatus, TaskUpdated, DEFAULT_WORKSPACE_ID};
+
+/// Emitted when a task is created
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskCreated {
+    pub task_id: i32,
+    pub name: String,
+    pub priority: Option<i32>,
+    pub description: Option<String>,
+    pub status: TaskStatus,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskCreated {
+    pub fn from_task(task: &Task) -> Self {
+        Self {
+            task_id: task.id.value(),
+            name: task.name.clone(),
+            priority: task.priority,
+            description: task.description.clone(),
+            status: task.status.clone(),
+            occurred_at: task.created_at,
+        }
+    }
+}
+
+/// Emitted when a task moves to another status
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskStatusChanged {
+    pub task_id: i32,
+    pub from_status: TaskStatus,
+    pub to_status: TaskStatus,
+    pub comment: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Emitted when a task is soft-deleted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskDeleted {
+    pub task_id: i32,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Emitted when a deleted task is brought back
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskRestored {
+    pub task_id: i32,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Emitted when a finished task is archived
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskArchived {
+    pub task_id: i32,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Everything that can happen to a task, tagged by `type` when serialized
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TaskEvent {
+    TaskCreated(TaskCreated),
+    TaskUpdated(TaskUpdated),
+    TaskStatusChanged(TaskStatusChanged),
+    TaskDeleted(TaskDeleted),
+    TaskRestored(TaskRestored),
+    TaskArchived(TaskArchived),
+}
+
+impl TaskEvent {
+    /// The `type` tag the event is serialized with
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            TaskEvent::TaskCreated(_) => "task_created",
+            TaskEvent::TaskUpdated(_) => "task_updated",
+            TaskEvent::TaskStatusChanged(_) => "task_status_changed",
+            TaskEvent::TaskDeleted(_) => "task_deleted",
+            TaskEvent::TaskRestored(_) => "task_restored",
+            TaskEvent::TaskArchived(_) => "task_archived",
+        }
+    }
+
+    pub fn task_id(&self) -> i32 {
+        match self {
+            TaskEvent::TaskCreated(event) => event.task_id,
+            TaskEvent::TaskUpdated(event) => event.task_id,
+            TaskEvent::TaskStatusChanged(event) => event.task_id,
+            TaskEvent::TaskDeleted(event) => event.task_id,
+            TaskEvent::TaskRestored(event) => event.task_id,
+            TaskEvent::TaskArchived(event) => event.task_id,
+        }
+    }
+
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            TaskEvent::TaskCreated(event) => event.occurred_at,
+            TaskEvent::TaskUpdated(event) => event.occurred_at,
+            TaskEvent::TaskStatusChanged(event) => event.occurred_at,
+            TaskEvent::TaskDeleted(event) => event.occurred_at,
+            TaskEvent::TaskRestored(event) => event.occurred_at,
+            TaskEvent::TaskArchived(event) => event.occurred_at,
+        }
+    }
+}
+
+/// A task event with the caller that caused it, as handed to event publishers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainEvent {
+    pub changed_by: String,
+    /// The workspace of the task; events stored before workspaces existed read as the default one
+    #[serde(default = "default_workspace_id")]
+    pub workspace_id: String,
+    #[serde(flatten)]
+    pub event: TaskEvent,
+}
+
+fn default_workspace_id() -> String {
+    DEFAULT_WORKSPACE_ID.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TaskId;
+
+    #[test]
+    fn test_event_type_matches_serialized_tag() {
+        let task = Task::new(TaskId::new(7), "Write docs".to_string(), Some(3)).unwrap();
+        let event = DomainEvent {
+            changed_by: "alice".to_string(),
+            workspace_id: "platform".to_string(),
+            event: TaskEvent::TaskCreated(TaskCreated::from_task(&task)),
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], event.event.event_type());
+        assert_eq!(json["task_id"], 7);
+        assert_eq!(json["changed_by"], "alice");
+        assert_eq!(serde_json::from_value::<DomainEvent>(json).unwrap(), event);
+    }
+}
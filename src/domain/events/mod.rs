@@ -0,0 +1,5 @@
+pub mod domain_event;
+pub mod task_events;
+
+pub use domain_event::*;
+pub use task_events::*;
@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::{Task, TaskId};
+
+/// Task fields that can change through a regular update
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskField {
+    Name,
+    Priority,
+    Description,
+}
+
+impl TaskField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskField::Name => "name",
+            TaskField::Priority => "priority",
+            TaskField::Description => "description",
+        }
+    }
+}
+
+/// A single field change with its previous and new value rendered as text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: TaskField,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+/// Emitted when a task's fields are updated, listing exactly what changed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskUpdated {
+    pub task_id: i32,
+    pub changes: Vec<FieldChange>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskUpdated {
+    /// Compares two versions of the same task and records the differing fields
+    pub fn between(before: &Task, after: &Task) -> Self {
+        let mut changes = Vec::new();
+
+        if before.name != after.name {
+            changes.push(FieldChange {
+                field: TaskField::Name,
+                old_value: Some(before.name.clone()),
+                new_value: Some(after.name.clone()),
+            });
+        }
+
+        if before.priority != after.priority {
+            changes.push(FieldChange {
+                field: TaskField::Priority,
+                old_value: before.priority.map(|p| p.to_string()),
+                new_value: after.priority.map(|p| p.to_string()),
+            });
+        }
+
+        if before.description != after.description {
+            changes.push(FieldChange {
+                field: TaskField::Description,
+                old_value: before.description.clone(),
+                new_value: after.description.clone(),
+            });
+        }
+
+        Self {
+            task_id: after.id.value(),
+            changes,
+            occurred_at: after.updated_at,
+        }
+    }
+
+    pub fn task_id(&self) -> TaskId {
+        TaskId::new(self.task_id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn changed_fields(&self) -> Vec<TaskField> {
+        self.changes.iter().map(|change| change.field).collect()
+    }
+
+    /// Lets subscribers filter on the fields they care about
+    pub fn touches(&self, field: TaskField) -> bool {
+        self.changes.iter().any(|change| change.field == field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(name: &str, priority: Option<i32>) -> Task {
+        Task::new(TaskId::new(1), name.to_string(), priority).unwrap()
+    }
+
+    #[test]
+    fn test_between_records_changed_fields_only() {
+        let before = task("Original", Some(5));
+        let mut after = before.clone();
+        after.update_priority(Some(2)).unwrap();
+
+        let event = TaskUpdated::between(&before, &after);
+
+        assert_eq!(event.changed_fields(), vec![TaskField::Priority]);
+        assert_eq!(event.changes[0].old_value, Some("5".to_string()));
+        assert_eq!(event.changes[0].new_value, Some("2".to_string()));
+        assert!(event.touches(TaskField::Priority));
+        assert!(!event.touches(TaskField::Name));
+    }
+
+    #[test]
+    fn test_between_identical_tasks_is_empty() {
+        let before = task("Same", None);
+        let event = TaskUpdated::between(&before, &before.clone());
+
+        assert!(event.is_empty());
+    }
+}
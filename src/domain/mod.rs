@@ -1,9 +1,15 @@
 pub mod entities;
+pub mod errors;
+pub mod events;
+pub mod policies;
 pub mod ports;
 pub mod services;
 pub mod value_objects;
 
 pub use entities::*;
+pub use errors::*;
+pub use events::*;
+pub use policies::*;
 pub use ports::*;
 pub use services::*;
 pub use value_objects::*;
\ No newline at end of file
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::ArchiveMonth;
+
+/// Decides which status history is old enough to move out of the database into the archive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRetentionPolicy {
+    retention_days: u32,
+}
+
+impl AuditRetentionPolicy {
+    pub fn new(retention_days: u32) -> Result<Self, String> {
+        if retention_days == 0 {
+            return Err("Audit retention must be at least one day".to_string());
+        }
+        Ok(Self { retention_days })
+    }
+
+    pub fn retention_days(&self) -> u32 {
+        self.retention_days
+    }
+
+    /// Entries changed before this instant are archived.
+    ///
+    /// Only whole months are archived, so the cutoff is the start of the month the retention
+    /// period reaches back into and entries are kept for somewhat longer than the retention.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        ArchiveMonth::containing(now - Duration::days(i64::from(self.retention_days))).start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_rejects_zero_days() {
+        assert!(AuditRetentionPolicy::new(0).is_err());
+    }
+
+    #[test]
+    fn test_cutoff_is_the_start_of_the_month_retention_reaches_into() {
+        let policy = AuditRetentionPolicy::new(90).unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 6, 15, 12, 0, 0).unwrap();
+
+        // 90 days before mid-June is mid-March
+        assert_eq!(policy.cutoff(now), Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap());
+    }
+}
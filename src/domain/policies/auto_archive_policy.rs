@@ -0,0 +1,62 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::{Task, TaskStatus};
+
+/// Decides when a completed task has been done long enough to be archived automatically
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoArchivePolicy {
+    after_days: u32,
+}
+
+impl AutoArchivePolicy {
+    pub fn new(after_days: u32) -> Result<Self, String> {
+        if after_days == 0 {
+            return Err("Auto-archive age must be at least one day".to_string());
+        }
+        Ok(Self { after_days })
+    }
+
+    pub fn after_days(&self) -> u32 {
+        self.after_days
+    }
+
+    /// A live, unarchived Completed task is due once it was completed longer ago than the limit
+    pub fn is_due(&self, task: &Task, completed_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        task.status() == &TaskStatus::Completed
+            && !task.is_deleted()
+            && !task.is_archived()
+            && now - completed_at > Duration::days(i64::from(self.after_days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TaskId;
+
+    fn completed_task(at: DateTime<Utc>) -> Task {
+        Task::new_with_status(TaskId::new(1), "Done".to_string(), None, TaskStatus::Completed, at, at).unwrap()
+    }
+
+    #[test]
+    fn test_rejects_zero_days() {
+        assert!(AutoArchivePolicy::new(0).is_err());
+    }
+
+    #[test]
+    fn test_only_long_completed_tasks_are_due() {
+        let policy = AutoArchivePolicy::new(30).unwrap();
+        let now = Utc::now();
+        let long_ago = now - Duration::days(31);
+
+        assert!(policy.is_due(&completed_task(long_ago), long_ago, now));
+        assert!(!policy.is_due(&completed_task(long_ago), now - Duration::days(29), now));
+
+        let mut cancelled = completed_task(long_ago);
+        cancelled.status = TaskStatus::Cancelled;
+        assert!(!policy.is_due(&cancelled, long_ago, now));
+
+        let archived = completed_task(long_ago).with_archived_at(Some(now));
+        assert!(!policy.is_due(&archived, long_ago, now));
+    }
+}
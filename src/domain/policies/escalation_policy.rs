@@ -0,0 +1,221 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+use crate::domain::{PriorityScale, Task, TaskStatus};
+
+/// What an escalation does to a task stuck past its rule's threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscalationAction {
+    /// Raises the priority one step towards the most urgent end of the scale
+    BumpPriority,
+    /// Only records the escalation in the task's history
+    Comment,
+}
+
+impl EscalationAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EscalationAction::BumpPriority => "bump_priority",
+            EscalationAction::Comment => "comment",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "bump_priority" => Ok(EscalationAction::BumpPriority),
+            "comment" => Ok(EscalationAction::Comment),
+            _ => Err(format!("Invalid escalation action: {} (expected bump_priority or comment)", s)),
+        }
+    }
+}
+
+/// Escalates tasks that stay in one status for longer than a threshold
+#[derive(Debug, Clone, PartialEq)]
+pub struct EscalationRule {
+    pub status: TaskStatus,
+    after_hours: u32,
+    pub action: EscalationAction,
+}
+
+impl EscalationRule {
+    pub fn new(status: TaskStatus, after_hours: u32, action: EscalationAction) -> Result<Self, String> {
+        if status.is_finished() {
+            return Err(format!("{} tasks are finished and cannot be escalated", status.as_str()));
+        }
+        if after_hours == 0 {
+            return Err("Escalation threshold must be at least one hour".to_string());
+        }
+        Ok(Self { status, after_hours, action })
+    }
+
+    pub fn after(&self) -> Duration {
+        Duration::hours(i64::from(self.after_hours))
+    }
+
+    /// A live, unarchived task in the rule's status is due once nothing was recorded in its
+    /// history for longer than the threshold
+    pub fn is_due(&self, task: &Task, last_recorded_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        task.status() == &self.status
+            && !task.is_deleted()
+            && !task.is_archived()
+            && now - last_recorded_at > self.after()
+    }
+
+    /// The priority one step more urgent than `priority`, where a lower number is more urgent,
+    /// kept within the scale, so a priority left off it by a narrowed scale lands on its nearest
+    /// end; a task without one is given the least urgent priority
+    pub fn bumped_priority(&self, priority: Option<i32>, scale: &PriorityScale) -> i32 {
+        match priority {
+            Some(priority) => priority.saturating_sub(1).clamp(scale.min(), scale.max()),
+            None => scale.max(),
+        }
+    }
+
+    /// Comment recorded on the escalation's history entry, with the `(from, to)` priority of a bump
+    pub fn comment(&self, priority_change: Option<(Option<i32>, i32)>) -> String {
+        let reason = format!("Escalated after more than {} in {}", self.threshold(), self.status.as_str());
+        match priority_change {
+            None => reason,
+            Some((Some(from), to)) if from == to => format!("{}; priority already at the most urgent, {}", reason, to),
+            Some((Some(from), to)) if from < to => format!("{}; priority {} is off the scale, set to {}", reason, from, to),
+            Some((Some(from), to)) => format!("{}; priority raised from {} to {}", reason, from, to),
+            Some((None, to)) => format!("{}; priority set to {}", reason, to),
+        }
+    }
+
+    fn threshold(&self) -> String {
+        match self.after_hours {
+            1 => "1 hour".to_string(),
+            24 => "1 day".to_string(),
+            hours if hours % 24 == 0 => format!("{} days", hours / 24),
+            hours => format!("{} hours", hours),
+        }
+    }
+}
+
+/// Rules escalating tasks left too long in a status, at most one per status
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct EscalationPolicy {
+    rules: Vec<EscalationRule>,
+}
+
+impl EscalationPolicy {
+    /// Parses `Status:threshold:action` rules separated by `,`, where the threshold is a number
+    /// of hours or days such as `72h` or `3d`, e.g. `InProgress:3d:bump_priority,PendingReview:48h:comment`
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut rules: Vec<EscalationRule> = Vec::new();
+        for entry in value.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let mut parts = entry.split(':').map(str::trim);
+            let (Some(status), Some(threshold), Some(action), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+                return Err(format!("Escalation rules must look like Status:threshold:action, got '{}'", entry));
+            };
+            let status = TaskStatus::parse(status)?;
+            let after_hours = parse_threshold(threshold)
+                .ok_or_else(|| format!("Escalation threshold of {} must be a number of hours or days such as 72h or 3d, got '{}'", status.as_str(), threshold))?;
+            if rules.iter().any(|rule| rule.status == status) {
+                return Err(format!("{} has more than one escalation rule", status.as_str()));
+            }
+            rules.push(EscalationRule::new(status, after_hours, EscalationAction::from_str(action)?)?);
+        }
+        Ok(Self { rules })
+    }
+
+    pub fn rules(&self) -> &[EscalationRule] {
+        &self.rules
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+impl TryFrom<String> for EscalationPolicy {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+fn parse_threshold(value: &str) -> Option<u32> {
+    let (amount, hours_per_unit) = match value.strip_suffix('d') {
+        Some(days) => (days, 24),
+        None => (value.strip_suffix('h')?, 1),
+    };
+    amount.trim().parse::<u32>().ok()?.checked_mul(hours_per_unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TaskId;
+
+    fn task(status: TaskStatus, priority: Option<i32>) -> Task {
+        let at = Utc::now();
+        Task::new_with_status(TaskId::new(1), "Stuck".to_string(), priority, status, at, at).unwrap()
+    }
+
+    #[test]
+    fn test_parse_reads_hours_and_days() {
+        let policy = EscalationPolicy::parse(" InProgress:3d:bump_priority, PendingReview:36h:comment ").unwrap();
+
+        let rules: Vec<_> = policy.rules().iter().map(|rule| (rule.status.clone(), rule.after(), rule.action)).collect();
+        assert_eq!(rules, vec![
+            (TaskStatus::InProgress, Duration::days(3), EscalationAction::BumpPriority),
+            (TaskStatus::PendingReview, Duration::hours(36), EscalationAction::Comment),
+        ]);
+        assert!(EscalationPolicy::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_duplicate_and_finished_rules() {
+        for invalid in [
+            "InProgress:3d",
+            "InProgress:3d:comment:extra",
+            "InProgress:3:comment",
+            "InProgress:0h:comment",
+            "InProgress:3d:reassign",
+            "InProgress:3d:comment,InProgress:1d:bump_priority",
+            "Completed:3d:comment",
+        ] {
+            assert!(EscalationPolicy::parse(invalid).is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_rule_is_due_once_the_threshold_passes() {
+        let rule = EscalationRule::new(TaskStatus::InProgress, 48, EscalationAction::Comment).unwrap();
+        let now = Utc::now();
+
+        assert!(rule.is_due(&task(TaskStatus::InProgress, None), now - Duration::hours(49), now));
+        assert!(!rule.is_due(&task(TaskStatus::InProgress, None), now - Duration::hours(47), now));
+        assert!(!rule.is_due(&task(TaskStatus::Pending, None), now - Duration::hours(49), now));
+        let deleted = task(TaskStatus::InProgress, None).with_deleted_at(Some(now));
+        assert!(!rule.is_due(&deleted, now - Duration::hours(49), now));
+    }
+
+    #[test]
+    fn test_bump_moves_towards_the_most_urgent_priority_and_comment_describes_it() {
+        let rule = EscalationRule::new(TaskStatus::InProgress, 72, EscalationAction::BumpPriority).unwrap();
+        let scale = PriorityScale::new(1, 5).unwrap();
+
+        assert_eq!(rule.bumped_priority(Some(3), &scale), 2);
+        assert_eq!(rule.bumped_priority(Some(1), &scale), 1);
+        assert_eq!(rule.bumped_priority(None, &scale), 5);
+        assert_eq!(rule.comment(Some((Some(3), 2))), "Escalated after more than 3 days in InProgress; priority raised from 3 to 2");
+        assert_eq!(rule.comment(Some((Some(1), 1))), "Escalated after more than 3 days in InProgress; priority already at the most urgent, 1");
+        assert_eq!(rule.comment(Some((None, 5))), "Escalated after more than 3 days in InProgress; priority set to 5");
+
+        // A narrowed scale can leave stored priorities off it at either end
+        let narrowed = PriorityScale::new(2, 4).unwrap();
+        assert_eq!(rule.bumped_priority(Some(20), &narrowed), 4);
+        assert_eq!(rule.bumped_priority(Some(5), &narrowed), 4);
+        assert_eq!(rule.bumped_priority(Some(1), &narrowed), 2);
+        assert_eq!(rule.comment(Some((Some(20), 4))), "Escalated after more than 3 days in InProgress; priority raised from 20 to 4");
+        assert_eq!(rule.comment(Some((Some(1), 2))), "Escalated after more than 3 days in InProgress; priority 1 is off the scale, set to 2");
+
+        let rule = EscalationRule::new(TaskStatus::PendingReview, 36, EscalationAction::Comment).unwrap();
+        assert_eq!(rule.comment(None), "Escalated after more than 36 hours in PendingReview");
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Priority at or below which tasks count as high priority unless configured otherwise
+pub const DEFAULT_HIGH_PRIORITY_THRESHOLD: i32 = 3;
+
+/// Decides which tasks are high priority and therefore must go through review
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HighPriorityPolicy {
+    threshold: i32,
+}
+
+impl HighPriorityPolicy {
+    /// Priorities from the top of the scale down to `threshold` (inclusive) are high priority
+    pub fn new(threshold: i32) -> Self {
+        Self { threshold }
+    }
+
+    pub fn threshold(&self) -> i32 {
+        self.threshold
+    }
+
+    pub fn is_high_priority(&self, priority: Option<i32>) -> bool {
+        priority.is_some_and(|p| p <= self.threshold)
+    }
+}
+
+impl Default for HighPriorityPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_HIGH_PRIORITY_THRESHOLD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_threshold() {
+        let policy = HighPriorityPolicy::default();
+        assert!(policy.is_high_priority(Some(3)));
+        assert!(!policy.is_high_priority(Some(4)));
+        assert!(!policy.is_high_priority(None));
+    }
+
+    #[test]
+    fn test_custom_threshold() {
+        let policy = HighPriorityPolicy::new(1);
+        assert!(policy.is_high_priority(Some(1)));
+        assert!(!policy.is_high_priority(Some(2)));
+    }
+}
@@ -0,0 +1,17 @@
+pub mod audit_retention_policy;
+pub mod auto_archive_policy;
+pub mod escalation_policy;
+pub mod high_priority_policy;
+pub mod notify_url_policy;
+pub mod priority_policy;
+pub mod reopen_policy;
+pub mod stale_task_policy;
+
+pub use audit_retention_policy::*;
+pub use auto_archive_policy::*;
+pub use escalation_policy::*;
+pub use high_priority_policy::*;
+pub use notify_url_policy::*;
+pub use priority_policy::*;
+pub use reopen_policy::*;
+pub use stale_task_policy::*;
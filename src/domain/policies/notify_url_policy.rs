@@ -0,0 +1,164 @@
+use serde::Deserialize;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Decides which URLs a `notify` automation rule may POST to.
+///
+/// With allowed hosts configured, only those hosts are accepted, e.g. `hooks.slack.com` or
+/// `*.example.com` for any subdomain. Without them any public `https` host is accepted, while
+/// `localhost`, single-label names and loopback, private, link-local and other internal
+/// addresses, such as the cloud metadata endpoint, are refused.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct NotifyUrlPolicy {
+    allowed_hosts: Vec<String>,
+}
+
+impl NotifyUrlPolicy {
+    /// Parses hosts separated by `,`, each a host name or `*.` followed by a domain
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut allowed_hosts = Vec::new();
+        for host in value.split(',').map(str::trim).filter(|host| !host.is_empty()) {
+            let host = host.to_ascii_lowercase();
+            let name = host.strip_prefix("*.").unwrap_or(&host);
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.') {
+                return Err(format!("Notify hosts must be host names such as hooks.example.com or *.example.com, got '{}'", host));
+            }
+            allowed_hosts.push(host);
+        }
+        Ok(Self { allowed_hosts })
+    }
+
+    pub fn allowed_hosts(&self) -> &[String] {
+        &self.allowed_hosts
+    }
+
+    /// Accepts `url` or says why a rule may not notify it
+    pub fn check(&self, url: &str) -> Result<(), String> {
+        let (scheme, rest) = url.split_once("://")
+            .ok_or_else(|| format!("notify url must be an http:// or https:// URL, got '{}'", url))?;
+        let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        if authority.contains('@') {
+            return Err("notify url must not carry credentials".to_string());
+        }
+        let host = host_of(authority)
+            .ok_or_else(|| format!("notify url has no valid host: '{}'", url))?;
+
+        if !self.allowed_hosts.is_empty() {
+            return match self.allowed_hosts.iter().any(|allowed| matches_host(allowed, &host)) {
+                true => Ok(()),
+                false => Err(format!("notify url host {} is not one of the allowed hosts", host)),
+            };
+        }
+        if !scheme.eq_ignore_ascii_case("https") {
+            return Err(format!("notify url must use https, got '{}'", url));
+        }
+        if is_internal_host(&host) {
+            return Err(format!("notify url host {} is an internal address", host));
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<String> for NotifyUrlPolicy {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+/// The lowercased host of an authority, without port, brackets or trailing dot
+fn host_of(authority: &str) -> Option<String> {
+    let host = match authority.strip_prefix('[') {
+        Some(bracketed) => bracketed.split_once(']')?.0,
+        None => authority.rsplit_once(':').map_or(authority, |(host, _)| host),
+    };
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    (!host.is_empty()).then_some(host)
+}
+
+fn matches_host(allowed: &str, host: &str) -> bool {
+    match allowed.strip_prefix("*.") {
+        Some(domain) => host.strip_suffix(domain).is_some_and(|sub| sub.ends_with('.') && sub.len() > 1),
+        None => allowed == host,
+    }
+}
+
+fn is_internal_host(host: &str) -> bool {
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return is_internal_ip(ip);
+    }
+    // Browsers and HTTP clients read hosts like 2130706433 or 0x7f.1 as IPv4 addresses
+    let last_label = host.rsplit('.').next().unwrap_or_default();
+    let numeric = !last_label.is_empty()
+        && (last_label.chars().all(|c| c.is_ascii_digit()) || last_label.starts_with("0x"));
+    numeric || !host.contains('.') || host == "localhost" || host.ends_with(".localhost") || host.ends_with(".internal")
+}
+
+fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_internal_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_internal_ipv4(ip),
+            None => is_internal_ipv6(ip),
+        },
+    }
+}
+
+fn is_internal_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || first == 0
+        || (first == 100 && (64..128).contains(&second))
+}
+
+fn is_internal_ipv6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    ip.is_loopback() || ip.is_unspecified() || (first & 0xfe00) == 0xfc00 || (first & 0xffc0) == 0xfe80
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_refuses_internal_and_plain_http_hosts() {
+        let policy = NotifyUrlPolicy::default();
+
+        assert!(policy.check("https://chat.example.com/hooks/tasks").is_ok());
+        for refused in [
+            "http://chat.example.com/hooks",
+            "https://169.254.169.254/latest/meta-data",
+            "https://127.0.0.1:8080/",
+            "https://10.1.2.3/hook",
+            "https://[::1]/hook",
+            "https://[::ffff:192.168.0.1]/hook",
+            "https://localhost/hook",
+            "https://metadata.google.internal/computeMetadata",
+            "https://intranet/hook",
+            "https://2130706433/hook",
+            "https://0x7f.1/hook",
+            "https://user@chat.example.com/hooks",
+            "ftp://chat.example.com",
+        ] {
+            assert!(policy.check(refused).is_err(), "{}", refused);
+        }
+    }
+
+    #[test]
+    fn test_allowed_hosts_are_the_only_ones_accepted() {
+        let policy = NotifyUrlPolicy::parse(" hooks.slack.com, *.example.com ").unwrap();
+
+        assert!(policy.check("https://hooks.slack.com/services/T0").is_ok());
+        assert!(policy.check("http://ci.example.com:8080/notify").is_ok());
+        assert!(policy.check("https://example.com/notify").is_err());
+        assert!(policy.check("https://evilexample.com/notify").is_err());
+        assert!(policy.check("https://hooks.slack.com.evil.io/").is_err());
+        assert!(NotifyUrlPolicy::parse("hooks/slack").is_err());
+        assert!(NotifyUrlPolicy::parse("").unwrap().allowed_hosts().is_empty());
+    }
+}
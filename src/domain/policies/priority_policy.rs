@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::{DomainError, HighPriorityPolicy, PriorityScale};
+
+/// The priorities a deployment accepts together with the cut-off above which tasks need review,
+/// e.g. a 1–5 scale where only 1 and 2 are reviewed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriorityPolicy {
+    scale: PriorityScale,
+    high_priority: HighPriorityPolicy,
+}
+
+impl PriorityPolicy {
+    /// Refuses a high-priority threshold outside the scale, which would review every task or none
+    pub fn new(scale: PriorityScale, high_priority: HighPriorityPolicy) -> Result<Self, String> {
+        if !scale.contains(high_priority.threshold()) {
+            return Err(format!(
+                "High-priority threshold {} is outside the priority scale {}-{}",
+                high_priority.threshold(),
+                scale.min(),
+                scale.max()
+            ));
+        }
+        Ok(Self { scale, high_priority })
+    }
+
+    pub fn scale(&self) -> PriorityScale {
+        self.scale
+    }
+
+    pub fn high_priority(&self) -> HighPriorityPolicy {
+        self.high_priority
+    }
+
+    /// Accepts a missing priority or one inside the scale
+    pub fn validate(&self, priority: Option<i32>) -> Result<(), DomainError> {
+        self.scale.validate(priority)
+    }
+
+    pub fn is_high_priority(&self, priority: Option<i32>) -> bool {
+        self.high_priority.is_high_priority(priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_reviews_one_to_three_of_ten() {
+        let policy = PriorityPolicy::default();
+        assert_eq!(policy.scale(), PriorityScale::full());
+        assert!(policy.is_high_priority(Some(3)));
+        assert!(!policy.is_high_priority(Some(4)));
+        assert!(policy.validate(Some(10)).is_ok());
+    }
+
+    #[test]
+    fn test_narrow_scale_with_its_own_threshold() {
+        let policy = PriorityPolicy::new(PriorityScale::new(1, 5).unwrap(), HighPriorityPolicy::new(2)).unwrap();
+        assert!(policy.is_high_priority(Some(2)));
+        assert!(!policy.is_high_priority(Some(3)));
+        assert!(policy.validate(Some(6)).is_err());
+    }
+
+    #[test]
+    fn test_threshold_must_lie_inside_the_scale() {
+        let error = PriorityPolicy::new(PriorityScale::new(4, 8).unwrap(), HighPriorityPolicy::new(3)).unwrap_err();
+        assert_eq!(error, "High-priority threshold 3 is outside the priority scale 4-8");
+    }
+}
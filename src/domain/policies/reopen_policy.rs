@@ -0,0 +1,58 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::DomainError;
+
+/// Days after completion during which a task may still be reopened unless configured otherwise
+pub const DEFAULT_REOPEN_WINDOW_DAYS: u32 = 14;
+
+/// Limits how long after completion a task can be reopened
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReopenPolicy {
+    window_days: u32,
+}
+
+impl ReopenPolicy {
+    pub fn new(window_days: u32) -> Self {
+        Self { window_days }
+    }
+
+    pub fn window_days(&self) -> u32 {
+        self.window_days
+    }
+
+    pub fn allows(&self, completed_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        now - completed_at <= Duration::days(i64::from(self.window_days))
+    }
+
+    pub fn check(&self, completed_at: DateTime<Utc>, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.allows(completed_at, now) {
+            Ok(())
+        } else {
+            Err(DomainError::ReopenWindowExpired { window_days: self.window_days })
+        }
+    }
+}
+
+impl Default for ReopenPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_REOPEN_WINDOW_DAYS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window() {
+        let policy = ReopenPolicy::new(7);
+        let now = Utc::now();
+
+        assert!(policy.check(now - Duration::days(7), now).is_ok());
+        assert_eq!(
+            policy.check(now - Duration::days(8), now).unwrap_err(),
+            DomainError::ReopenWindowExpired { window_days: 7 }
+        );
+    }
+
+}
@@ -0,0 +1,68 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::{Task, TaskStatus};
+
+/// Decides when a task has sat in Pending long enough to be cancelled automatically
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaleTaskPolicy {
+    max_pending_days: u32,
+}
+
+impl StaleTaskPolicy {
+    pub fn new(max_pending_days: u32) -> Result<Self, String> {
+        if max_pending_days == 0 {
+            return Err("Stale task age must be at least one day".to_string());
+        }
+        Ok(Self { max_pending_days })
+    }
+
+    pub fn max_pending_days(&self) -> u32 {
+        self.max_pending_days
+    }
+
+    /// A live Pending task is stale once it has gone untouched for longer than the limit
+    pub fn is_stale(&self, task: &Task, now: DateTime<Utc>) -> bool {
+        task.status() == &TaskStatus::Pending
+            && !task.is_deleted()
+            && now - task.updated_at > Duration::days(i64::from(self.max_pending_days))
+    }
+
+    /// Comment recorded on the history entry of an automatic cancellation
+    pub fn cancellation_comment(&self) -> String {
+        format!(
+            "Automatically cancelled after {} days in Pending without activity",
+            self.max_pending_days
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TaskId;
+
+    fn pending_task(updated_at: DateTime<Utc>) -> Task {
+        Task::new_with_status(TaskId::new(1), "Old".to_string(), None, TaskStatus::Pending, updated_at, updated_at).unwrap()
+    }
+
+    #[test]
+    fn test_rejects_zero_days() {
+        assert!(StaleTaskPolicy::new(0).is_err());
+    }
+
+    #[test]
+    fn test_only_old_pending_tasks_are_stale() {
+        let policy = StaleTaskPolicy::new(7).unwrap();
+        let now = Utc::now();
+
+        assert!(policy.is_stale(&pending_task(now - Duration::days(8)), now));
+        assert!(!policy.is_stale(&pending_task(now - Duration::days(6)), now));
+
+        let mut started = pending_task(now - Duration::days(8));
+        started.status = TaskStatus::InProgress;
+        assert!(!policy.is_stale(&started, now));
+
+        let deleted = pending_task(now - Duration::days(8)).with_deleted_at(Some(now));
+        assert!(!policy.is_stale(&deleted, now));
+    }
+}
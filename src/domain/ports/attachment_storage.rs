@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Port for where attachment files are kept, such as an S3 bucket. Downloads do not pass through
+/// the service: callers are handed short-lived links that fetch the file from storage directly.
+#[async_trait]
+pub trait AttachmentStorage: Send + Sync {
+    /// Writes `body` under `key`; it is downloaded with `content_type`
+    async fn upload(&self, key: &str, content_type: &str, body: Vec<u8>) -> Result<(), String>;
+
+    /// A link that downloads the object under `key`, saved as `file_name`, for `expires_in` after `issued_at`
+    fn download_url(&self, key: &str, file_name: &str, issued_at: DateTime<Utc>, expires_in: Duration) -> Result<String, String>;
+}
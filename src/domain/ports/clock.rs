@@ -0,0 +1,92 @@
+use std::sync::{Arc, Mutex};
+use chrono::{DateTime, Duration, Utc};
+
+/// Port for reading the current time.
+///
+/// Timestamps and time-based rules read the time through a clock instead of `Utc::now()`, so
+/// tests can freeze it and simulations can run ahead of the wall clock.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The wall clock
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that only moves when told to; clones share the same time
+#[derive(Debug, Clone)]
+pub struct FrozenClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl FrozenClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self { now: Arc::new(Mutex::new(now)) }
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = now;
+    }
+
+    pub fn advance(&self, by: Duration) {
+        let mut now = self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *now += by;
+    }
+}
+
+impl Clock for FrozenClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// The wall clock shifted by a fixed offset, e.g. to see which SLAs a task would breach tomorrow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetClock {
+    offset: Duration,
+}
+
+impl OffsetClock {
+    pub fn new(offset: Duration) -> Self {
+        Self { offset }
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_frozen_clock_moves_only_when_told_and_clones_share_time() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 9, 0, 0).unwrap();
+        let clock = FrozenClock::new(start);
+        let handle = clock.clone();
+
+        assert_eq!(clock.now(), start);
+        handle.advance(Duration::hours(2));
+        assert_eq!(clock.now(), start + Duration::hours(2));
+        handle.set(start);
+        assert_eq!(clock.now(), start);
+    }
+
+    #[test]
+    fn test_offset_clock_runs_ahead_of_the_wall_clock() {
+        let clock = OffsetClock::new(Duration::days(3));
+
+        let ahead = clock.now() - Utc::now();
+        assert!(ahead > Duration::days(3) - Duration::minutes(1) && ahead <= Duration::days(3));
+    }
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::domain::{DomainEvent, RepositoryError};
+
+/// Port for handing task events to whoever subscribes to them, such as webhooks or audit logs.
+///
+/// Use cases publish after the change is stored; a failed publish fails the use case, so a
+/// durable publisher can rely on seeing every change that was reported as successful.
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    async fn publish(&self, event: &DomainEvent) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use uuid::Uuid;
+
+/// Port for minting identifiers such as status history and API key ids
+pub trait IdGenerator: Send + Sync {
+    fn next_id(&self) -> Uuid;
+}
+
+/// Time-ordered UUIDv7 ids, so ids minted later sort after earlier ones
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UuidV7Generator;
+
+impl IdGenerator for UuidV7Generator {
+    fn next_id(&self) -> Uuid {
+        Uuid::now_v7()
+    }
+}
+
+/// Ids 1, 2, 3, ... encoded as UUIDs, for reproducible tests; clones share the counter
+#[derive(Debug, Clone, Default)]
+pub struct SequentialIdGenerator {
+    last: Arc<AtomicU64>,
+}
+
+impl SequentialIdGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_id(&self) -> Uuid {
+        Uuid::from_u128(u128::from(self.last.fetch_add(1, Ordering::Relaxed) + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequential_ids_are_reproducible_and_shared_by_clones() {
+        let ids = SequentialIdGenerator::new();
+        let clone = ids.clone();
+
+        assert_eq!(ids.next_id().to_string(), "00000000-0000-0000-0000-000000000001");
+        assert_eq!(clone.next_id().to_string(), "00000000-0000-0000-0000-000000000002");
+    }
+
+    #[test]
+    fn test_uuid_v7_ids_sort_in_minting_order() {
+        let ids: Vec<_> = (0..100).map(|_| UuidV7Generator.next_id()).collect();
+
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(ids[0].get_version_num(), 7);
+    }
+}
@@ -1,3 +1,19 @@
+pub mod attachment_storage;
+pub mod clock;
+pub mod event_publisher;
+pub mod id_generator;
+pub mod notifier;
+pub mod object_storage;
 pub mod repositories;
+pub mod task_import_parser;
+pub mod transition_gate;
 
-pub use repositories::*;
\ No newline at end of file
+pub use attachment_storage::*;
+pub use clock::*;
+pub use event_publisher::*;
+pub use id_generator::*;
+pub use notifier::*;
+pub use object_storage::*;
+pub use repositories::*;
+pub use task_import_parser::*;
+pub use transition_gate::*;
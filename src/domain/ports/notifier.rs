@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+use crate::domain::AutomationNotification;
+
+/// Port for delivering automation notifications to an endpoint, such as a chat webhook
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, url: &str, notification: &AutomationNotification) -> Result<(), String>;
+}
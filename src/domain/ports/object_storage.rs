@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+/// Port for a bucket of opaque objects addressed by key, such as S3 or a local directory
+#[async_trait]
+pub trait ObjectStorage: Send + Sync {
+    /// Writes `body` under `key`, replacing any object already there
+    async fn put(&self, key: &str, body: Vec<u8>) -> Result<(), String>;
+
+    /// Reads the object under `key`, or `None` when there is none
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String>;
+}
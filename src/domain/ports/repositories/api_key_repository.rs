@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::domain::{ApiKey, RepositoryError};
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    /// Store a newly issued key
+    async fn save(&self, api_key: &ApiKey) -> Result<(), RepositoryError>;
+
+    /// Find the key whose secret hashes to `key_hash`
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError>;
+}
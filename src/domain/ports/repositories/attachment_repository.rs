@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::domain::{Attachment, RepositoryError, TaskId};
+
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    async fn save(&self, attachment: &Attachment) -> Result<(), RepositoryError>;
+
+    /// The task's attachments, oldest first
+    async fn find_by_task_id(&self, task_id: TaskId) -> Result<Vec<Attachment>, RepositoryError>;
+}
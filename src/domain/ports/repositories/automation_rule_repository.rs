@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use crate::domain::{AutomationRule, RepositoryError};
+
+/// Rules of the current workspace; outside a request every workspace's rules are visible
+#[async_trait]
+pub trait AutomationRuleRepository: Send + Sync {
+    async fn save(&self, rule: &AutomationRule) -> Result<(), RepositoryError>;
+
+    /// Replaces a rule's definition; `NotFound` if it does not exist
+    async fn update(&self, rule: &AutomationRule) -> Result<(), RepositoryError>;
+
+    /// `NotFound` if the rule does not exist
+    async fn delete(&self, id: &str) -> Result<(), RepositoryError>;
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<AutomationRule>, RepositoryError>;
+
+    /// Every rule of the current workspace, oldest first
+    async fn find_all(&self) -> Result<Vec<AutomationRule>, RepositoryError>;
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use crate::domain::{BackgroundJob, RepositoryError};
+
+#[async_trait]
+pub trait BackgroundJobRepository: Send + Sync {
+    /// Records that this process runs the job every `interval`, first at `first_run_at`.
+    ///
+    /// A job known from an earlier start keeps its pause, pending run request and last run.
+    async fn register(&self, name: &str, interval: Duration, first_run_at: DateTime<Utc>) -> Result<BackgroundJob, RepositoryError>;
+
+    /// All jobs by name, including those no longer registered by any process
+    async fn find_all(&self) -> Result<Vec<BackgroundJob>, RepositoryError>;
+
+    /// Pauses or resumes a job; `NotFound` if it was never registered
+    async fn set_paused(&self, name: &str, paused: bool) -> Result<BackgroundJob, RepositoryError>;
+
+    /// Asks for a run as soon as a scheduler next looks; `NotFound` if the job was never registered
+    async fn request_run(&self, name: &str) -> Result<BackgroundJob, RepositoryError>;
+
+    /// Starts a run if the job is due at `now`, clearing any run request and holding the next
+    /// scheduled run off until `retry_at`, in case this run never finishes.
+    ///
+    /// Returns `false` when it is not due, including when another scheduler claimed the run first.
+    async fn claim_run(&self, name: &str, now: DateTime<Utc>, retry_at: DateTime<Utc>) -> Result<bool, RepositoryError>;
+
+    /// Records the end of a run and when the next one is due
+    async fn finish_run(
+        &self,
+        name: &str,
+        finished_at: DateTime<Utc>,
+        error: Option<&str>,
+        next_run_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+}
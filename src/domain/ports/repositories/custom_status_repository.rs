@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use crate::domain::{CustomStatus, RepositoryError};
+
+/// Statuses admins defined next to the built-in ones; shared by every workspace
+#[async_trait]
+pub trait CustomStatusRepository: Send + Sync {
+    /// `Conflict` if a status with the same name exists
+    async fn save(&self, status: &CustomStatus) -> Result<(), RepositoryError>;
+
+    /// `NotFound` if the status does not exist, `Conflict` while any task, deleted or not, is in it
+    async fn delete(&self, name: &str) -> Result<(), RepositoryError>;
+
+    /// Every custom status, oldest first
+    async fn find_all(&self) -> Result<Vec<CustomStatus>, RepositoryError>;
+}
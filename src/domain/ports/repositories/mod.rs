@@ -1,5 +1,23 @@
 pub mod task_repository;
 pub mod status_history_repository;
+pub mod api_key_repository;
+pub mod task_dependency_repository;
+pub mod background_job_repository;
+pub mod automation_rule_repository;
+pub mod workspace_repository;
+pub mod attachment_repository;
+pub mod workflow_repository;
+pub mod custom_status_repository;
+pub mod task_statistics_repository;
 
 pub use task_repository::*;
-pub use status_history_repository::*;
\ No newline at end of file
+pub use status_history_repository::*;
+pub use api_key_repository::*;
+pub use task_dependency_repository::*;
+pub use background_job_repository::*;
+pub use automation_rule_repository::*;
+pub use workspace_repository::*;
+pub use attachment_repository::*;
+pub use workflow_repository::*;
+pub use custom_status_repository::*;
+pub use task_statistics_repository::*;
@@ -1,11 +1,22 @@
 use async_trait::async_trait;
-use crate::domain::{StatusHistory, TaskAnalytics, RepositoryError};
+use crate::domain::{StatusHistory, TaskAnalytics, CycleTimeStats, PriorityCompletion, TransitionEdge, Cohort, CohortBucket, CumulativeFlow, HistoryFilter, Pagination, SortOrder, RepositoryError};
 use chrono::{DateTime, Utc};
 
 #[async_trait]
 pub trait StatusHistoryRepository: Send + Sync {
     /// Get all status history entries for a specific task
     async fn find_by_task_id(&self, task_id: i32) -> Result<Vec<StatusHistory>, RepositoryError>;
+
+    /// Get one page of status history entries matching `filter`, ordered by `changed_at`
+    async fn find_matching(
+        &self,
+        filter: &HistoryFilter,
+        order: SortOrder,
+        page: Pagination,
+    ) -> Result<Vec<StatusHistory>, RepositoryError>;
+
+    /// Count status history entries matching `filter`
+    async fn count_matching(&self, filter: &HistoryFilter) -> Result<usize, RepositoryError>;
     
     /// Get status history entries within a date range
     async fn find_by_date_range(
@@ -27,12 +38,52 @@ pub trait StatusHistoryRepository: Send + Sync {
         end_date: DateTime<Utc>
     ) -> Result<Vec<TaskAnalytics>, RepositoryError>;
     
-    /// Get average completion times by priority level
-    async fn get_average_completion_times(&self) -> Result<Vec<(i32, chrono::Duration)>, RepositoryError>;
+    /// Count transitions per from/to pair made within a date range, with the median dwell time
+    async fn get_transition_matrix(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<TransitionEdge>, RepositoryError>;
+
+    /// Group live tasks created within a date range by creation bucket, with when each was first completed
+    async fn get_creation_cohorts(
+        &self,
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<Cohort>, RepositoryError>;
+
+    /// Count the tasks in each status, by their latest history entry, at the close of every bucket
+    /// from the one containing `start_date` to the one containing `end_date`; tasks deleted by
+    /// a bucket's close are left out of it
+    async fn get_cumulative_flow(
+        &self,
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<CumulativeFlow, RepositoryError>;
+
+    /// Get the average and p50/p85/p95 completion times by priority level of the tasks first
+    /// completed within a date range, the same tasks as [`Self::get_priority_completions`]
+    async fn get_completion_time_stats(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<CycleTimeStats>, RepositoryError>;
+
+    /// Count the tasks of each priority level first completed within a date range, with their average completion time
+    async fn get_priority_completions(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<PriorityCompletion>, RepositoryError>;
     
     /// Manual entry for status history (for corrections or bulk imports)
     async fn save(&self, history: &StatusHistory) -> Result<String, RepositoryError>;
     
     /// Delete status history (admin operation)
     async fn delete(&self, id: String) -> Result<(), RepositoryError>;
+
+    /// Delete many entries at once, skipping ids that no longer exist; returns how many were deleted
+    async fn delete_many(&self, ids: &[String]) -> Result<usize, RepositoryError>;
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::domain::{RepositoryError, TaskId};
+
+#[async_trait]
+pub trait TaskDependencyRepository: Send + Sync {
+    /// Record that `task_id` cannot start or complete before `depends_on` finishes; adding it twice is a no-op
+    async fn add(&self, task_id: TaskId, depends_on: TaskId) -> Result<(), RepositoryError>;
+
+    /// Remove a dependency, failing with `NotFound` when it does not exist
+    async fn remove(&self, task_id: TaskId, depends_on: TaskId) -> Result<(), RepositoryError>;
+
+    /// Tasks `task_id` depends on, in id order
+    async fn find_dependencies(&self, task_id: TaskId) -> Result<Vec<TaskId>, RepositoryError>;
+}
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use crate::domain::entities::Task;
-use crate::domain::value_objects::TaskId;
+use crate::domain::value_objects::{BoardColumn, PriorityChange, Rank, StatusHistory, TaskFacets, TaskFilter, TaskId, TaskPatch, TaskSort, TaskStatus};
 
 #[cfg(test)]
 use mockall::automock;
@@ -10,6 +11,10 @@ pub enum RepositoryError {
     NotFound(String),
     DatabaseError(String),
     ValidationError(String),
+    /// A unique constraint rejected the write; `field` names the duplicated value
+    Conflict { field: String, message: String },
+    /// The write referenced a row that does not exist; `field` names the reference
+    ForeignKeyViolation { field: String, message: String },
 }
 
 impl std::fmt::Display for RepositoryError {
@@ -18,6 +23,8 @@ impl std::fmt::Display for RepositoryError {
             RepositoryError::NotFound(msg) => write!(f, "Not found: {}", msg),
             RepositoryError::DatabaseError(msg) => write!(f, "Database error: {}", msg),
             RepositoryError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            RepositoryError::Conflict { message, .. } => write!(f, "Conflict: {}", message),
+            RepositoryError::ForeignKeyViolation { message, .. } => write!(f, "Foreign key violation: {}", message),
         }
     }
 }
@@ -28,9 +35,51 @@ impl std::error::Error for RepositoryError {}
 #[cfg_attr(test, automock)]
 pub trait TaskRepository: Send + Sync {
     async fn find_all(&self) -> Result<Vec<Task>, RepositoryError>;
+    /// Tasks matching the filter, in the requested order
+    async fn find_matching(&self, filter: &TaskFilter, sort: TaskSort) -> Result<Vec<Task>, RepositoryError>;
+    /// Counts the tasks matching the filter by status and by priority
+    async fn count_facets(&self, filter: &TaskFilter) -> Result<TaskFacets, RepositoryError>;
+    /// The tasks matching the filter by status, each column counting all of its tasks but
+    /// holding only the first `column_size` in the requested order. Columns are in no
+    /// particular order, and statuses without tasks have none.
+    async fn find_board(&self, filter: &TaskFilter, sort: TaskSort, column_size: usize) -> Result<Vec<BoardColumn>, RepositoryError>;
     async fn find_by_id(&self, id: TaskId) -> Result<Option<Task>, RepositoryError>;
     async fn find_by_priority(&self, priority: i32) -> Result<Vec<Task>, RepositoryError>;
+    async fn find_by_status(&self, status: &TaskStatus) -> Result<Vec<Task>, RepositoryError>;
+    /// Full-text search over task names, descriptions and status comments, best matches first
+    async fn search_tasks(&self, query: &str) -> Result<Vec<Task>, RepositoryError>;
     async fn save(&self, task: &Task) -> Result<TaskId, RepositoryError>;
     async fn update(&self, task: &Task) -> Result<(), RepositoryError>;
+    /// Writes only the patched columns, leaving the rest as they are stored
+    async fn update_fields(&self, id: TaskId, patch: &TaskPatch) -> Result<(), RepositoryError>;
+    /// Stores where the task was placed by hand, leaving every other column and task alone
+    async fn set_rank(&self, id: TaskId, rank: &Rank) -> Result<(), RepositoryError>;
+    /// Moves every live task matching the filter from one priority to another in a single write.
+    ///
+    /// `pairs` lists `(from, to)` priorities; tasks whose priority is not listed are left alone.
+    async fn reprioritize(&self, filter: &TaskFilter, pairs: &[(i32, i32)], updated_at: DateTime<Utc>) -> Result<Vec<PriorityChange>, RepositoryError>;
+    /// Inserts the task and its initial history entry atomically; the entry's task_id is set to the generated id
+    async fn save_with_history(&self, task: &Task, history: &StatusHistory) -> Result<TaskId, RepositoryError>;
+    /// Updates the task and records the status change atomically
+    async fn update_with_history(&self, task: &Task, history: &StatusHistory) -> Result<(), RepositoryError>;
+    /// Soft-deletes the task; it disappears from regular queries until restored
     async fn delete(&self, id: TaskId) -> Result<(), RepositoryError>;
-}
\ No newline at end of file
+    /// Clears the deletion marker of a soft-deleted task and returns it
+    async fn restore(&self, id: TaskId) -> Result<Task, RepositoryError>;
+}
+/// Borrows a task repository as a trait object, whether its type is concrete or already `dyn`
+pub trait AsTaskRepository {
+    fn as_task_repository(&self) -> &dyn TaskRepository;
+}
+
+impl<T: TaskRepository> AsTaskRepository for T {
+    fn as_task_repository(&self) -> &dyn TaskRepository {
+        self
+    }
+}
+
+impl AsTaskRepository for dyn TaskRepository {
+    fn as_task_repository(&self) -> &dyn TaskRepository {
+        self
+    }
+}
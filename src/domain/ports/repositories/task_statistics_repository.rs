@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+use crate::domain::{RepositoryError, StatisticsQuery, TaskStatistics};
+
+/// Dashboard summaries of the current workspace's tasks, counted where the tasks are stored
+#[async_trait]
+pub trait TaskStatisticsRepository: Send + Sync {
+    /// Counts by status and priority, overdue tasks, and tasks created and completed per day
+    async fn task_statistics(&self, query: &StatisticsQuery) -> Result<TaskStatistics, RepositoryError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::{RepositoryError, WorkflowDefinition};
+
+/// Workflows admins set for single workspaces, which take precedence over configured ones
+#[async_trait]
+pub trait WorkflowRepository: Send + Sync {
+    async fn find_by_workspace(&self, workspace_id: &str) -> Result<Option<WorkflowDefinition>, RepositoryError>;
+
+    /// Stores the workspace's workflow, replacing any earlier one
+    async fn save(&self, workspace_id: &str, workflow: &WorkflowDefinition, updated_by: &str, updated_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+
+    /// `NotFound` if the workspace has no stored workflow
+    async fn delete(&self, workspace_id: &str) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use crate::domain::{RepositoryError, Workspace};
+
+#[async_trait]
+pub trait WorkspaceRepository: Send + Sync {
+    /// `Conflict` if a workspace with the same id exists
+    async fn save(&self, workspace: &Workspace) -> Result<(), RepositoryError>;
+
+    /// Renames a workspace; `NotFound` if it does not exist
+    async fn update(&self, workspace: &Workspace) -> Result<(), RepositoryError>;
+
+    /// `NotFound` if the workspace does not exist, `Conflict` while any task, deleted or not, belongs to it
+    async fn delete(&self, id: &str) -> Result<(), RepositoryError>;
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<Workspace>, RepositoryError>;
+
+    /// Every workspace, oldest first
+    async fn find_all(&self) -> Result<Vec<Workspace>, RepositoryError>;
+}
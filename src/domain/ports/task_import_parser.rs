@@ -0,0 +1,10 @@
+use crate::domain::value_objects::{ImportSource, ParsedImport};
+
+/// Port for reading the export file of another task tracker
+pub trait TaskImportParser: Send + Sync {
+    fn source(&self) -> ImportSource;
+
+    /// Reads every card or issue in `export`; rows that cannot be read are reported as skipped.
+    /// Fails only when the file as a whole is not in the expected format.
+    fn parse(&self, export: &str) -> Result<ParsedImport, String>;
+}
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use crate::domain::{TaskStatus, UserRole};
+
+/// A status change waiting on an external system's approval
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GatedTransition {
+    pub task_id: i32,
+    pub task_name: String,
+    pub from: TaskStatus,
+    pub to: TaskStatus,
+    pub requested_by: String,
+    pub user_role: UserRole,
+    pub comment: Option<String>,
+}
+
+/// What the external system answered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GateDecision {
+    Approved,
+    Rejected { reason: String },
+}
+
+/// What happens to a gated transition when the external system cannot answer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GateFallback {
+    /// Refuse the transition until the system answers again
+    #[default]
+    Deny,
+    /// Let the transition through unapproved
+    Allow,
+}
+
+impl GateFallback {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "deny" => Ok(GateFallback::Deny),
+            "allow" => Ok(GateFallback::Allow),
+            other => Err(format!("Gate fallback must be 'deny' or 'allow', got '{}'", other)),
+        }
+    }
+}
+
+/// Port for an external system that must approve some status changes, such as a change-management tool.
+///
+/// An `Err` means no decision could be reached, for example because the call timed out; the
+/// configured [`GateFallback`] then decides.
+#[async_trait]
+pub trait TransitionGate: Send + Sync {
+    async fn review(&self, transition: &GatedTransition) -> Result<GateDecision, String>;
+}
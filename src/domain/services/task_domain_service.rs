@@ -1,37 +1,108 @@
-use crate::domain::entities::Task;
+use crate::domain::entities::{Task, MAX_DESCRIPTION_LENGTH};
+use crate::domain::errors::DomainError;
+use crate::domain::policies::PriorityPolicy;
+use crate::domain::value_objects::{PriorityLabels, PriorityScale, UserRole};
 
-pub struct TaskDomainService;
+pub const MAX_SEARCH_QUERY_LENGTH: usize = 200;
+
+/// Maximum number of bytes allowed in a task name
+pub const MAX_NAME_LENGTH: usize = 255;
+
+pub struct TaskDomainService {
+    priority_policy: PriorityPolicy,
+    priority_labels: PriorityLabels,
+}
 
 impl TaskDomainService {
     pub fn new() -> Self {
-        Self
+        Self::with_priority_policy(PriorityPolicy::default())
+    }
+
+    /// Validates priorities against the configured scale, with labels spread over it
+    pub fn with_priority_policy(priority_policy: PriorityPolicy) -> Self {
+        Self {
+            priority_policy,
+            priority_labels: PriorityLabels::for_scale(&priority_policy.scale()),
+        }
+    }
+
+    pub fn with_priority_labels(mut self, priority_labels: PriorityLabels) -> Self {
+        self.priority_labels = priority_labels;
+        self
+    }
+
+    pub fn priority_policy(&self) -> PriorityPolicy {
+        self.priority_policy
+    }
+
+    pub fn priority_scale(&self) -> PriorityScale {
+        self.priority_policy.scale()
+    }
+
+    /// Whether `task` must go through review before it is completed
+    pub fn is_high_priority(&self, task: &Task) -> bool {
+        task.is_high_priority_under(&self.priority_policy.high_priority())
     }
 
-    pub fn validate_task_name(&self, name: &str) -> Result<(), String> {
+    pub fn priority_labels(&self) -> PriorityLabels {
+        self.priority_labels
+    }
+
+    pub fn validate_task_name(&self, name: &str) -> Result<(), DomainError> {
         if name.trim().is_empty() {
-            return Err("Task name cannot be empty".to_string());
+            return Err(DomainError::EmptyName);
         }
-        if name.len() > 255 {
-            return Err("Task name cannot exceed 255 characters".to_string());
+        if name.len() > MAX_NAME_LENGTH {
+            return Err(DomainError::NameTooLong { max: MAX_NAME_LENGTH });
         }
         Ok(())
     }
 
-    pub fn validate_priority(&self, priority: Option<i32>) -> Result<(), String> {
-        if let Some(priority) = priority {
-            if priority < 1 || priority > 10 {
-                return Err("Priority must be between 1 and 10".to_string());
+    pub fn validate_description(&self, description: Option<&str>) -> Result<(), DomainError> {
+        if let Some(description) = description {
+            if description.chars().count() > MAX_DESCRIPTION_LENGTH {
+                return Err(DomainError::DescriptionTooLong { max: MAX_DESCRIPTION_LENGTH });
             }
         }
         Ok(())
     }
 
-    pub fn can_update_task(&self, _task: &Task, new_name: Option<&str>, new_priority: Option<i32>) -> Result<(), String> {
+    pub fn validate_search_query(&self, query: &str) -> Result<(), DomainError> {
+        if query.trim().is_empty() {
+            return Err(DomainError::EmptySearchQuery);
+        }
+        if query.chars().count() > MAX_SEARCH_QUERY_LENGTH {
+            return Err(DomainError::SearchQueryTooLong { max: MAX_SEARCH_QUERY_LENGTH });
+        }
+        Ok(())
+    }
+
+    pub fn validate_priority(&self, priority: Option<i32>) -> Result<(), DomainError> {
+        self.priority_policy.validate(priority)
+    }
+
+    pub fn can_update_task(&self, _task: &Task, new_name: Option<&str>, new_priority: Option<i32>) -> Result<(), DomainError> {
         if let Some(name) = new_name {
             self.validate_task_name(name)?;
         }
         self.validate_priority(new_priority)?;
         Ok(())
     }
+
+    /// Whether `user_id` acting as `role` may edit or cancel `task`.
+    ///
+    /// Plain users may only touch tasks they created; managers, admins and background jobs may
+    /// touch any task. Tasks created before creators were recorded stay open to everyone.
+    pub fn can_modify_task(&self, task: &Task, user_id: &str, role: &UserRole) -> bool {
+        match role {
+            UserRole::Manager | UserRole::Admin | UserRole::System => true,
+            UserRole::User => task.created_by.as_deref().is_none_or(|creator| creator == user_id),
+        }
+    }
 }
 
+impl Default for TaskDomainService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
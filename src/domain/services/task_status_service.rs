@@ -1,39 +1,71 @@
-use crate::domain::{TaskStatus, UserRole};
+use std::sync::Arc;
+use crate::domain::{DomainError, GateDecision, GateFallback, GatedTransition, PriorityPolicy, StatusCatalog, StatusCategory, Task, TaskId, TaskStatus, TransitionGate, UserRole, WorkflowDefinition};
 
-pub struct TaskStatusService;
+pub struct TaskStatusService {
+    priority_policy: PriorityPolicy,
+    gate: Option<StatusGate>,
+}
+
+/// An external approval system and the transitions it must approve
+struct StatusGate {
+    gate: Arc<dyn TransitionGate>,
+    transitions: Vec<(TaskStatus, TaskStatus)>,
+    fallback: GateFallback,
+}
 
 impl TaskStatusService {
     pub fn new() -> Self {
-        Self
+        Self { priority_policy: PriorityPolicy::default(), gate: None }
+    }
+
+    /// Decides which tasks are high priority, and so must go through review, from `priority_policy`
+    pub fn with_priority_policy(mut self, priority_policy: PriorityPolicy) -> Self {
+        self.priority_policy = priority_policy;
+        self
+    }
+
+    pub fn priority_policy(&self) -> PriorityPolicy {
+        self.priority_policy
+    }
+
+    /// Makes each of `transitions` wait for `gate`'s approval; `fallback` decides when it cannot answer
+    pub fn with_gate(mut self, gate: Arc<dyn TransitionGate>, transitions: Vec<(TaskStatus, TaskStatus)>, fallback: GateFallback) -> Self {
+        self.gate = Some(StatusGate { gate, transitions, fallback });
+        self
+    }
+
+    pub fn is_gated(&self, from: &TaskStatus, to: &TaskStatus) -> bool {
+        self.gate.as_ref().is_some_and(|gate| gate.transitions.iter().any(|(gated_from, gated_to)| gated_from == from && gated_to == to))
+    }
+
+    /// Asks the external approval system about a gated transition; other transitions pass untouched
+    pub async fn check_gate(&self, transition: &GatedTransition) -> Result<(), DomainError> {
+        let Some(gate) = self.gate.as_ref().filter(|_| self.is_gated(&transition.from, &transition.to)) else {
+            return Ok(());
+        };
+
+        match gate.gate.review(transition).await {
+            Ok(GateDecision::Approved) => Ok(()),
+            Ok(GateDecision::Rejected { reason }) => Err(DomainError::TransitionRejected { reason }),
+            Err(_) if gate.fallback == GateFallback::Allow => Ok(()),
+            Err(_) => Err(DomainError::TransitionGateUnavailable),
+        }
     }
 
+    /// `can_transition` under `workflow` for `task`, judging its priority by the configured policy
+    pub fn can_transition_task(&self, workflow: &WorkflowDefinition, task: &Task, to: &TaskStatus, user_role: &UserRole) -> Result<(), DomainError> {
+        workflow.check(task.status(), to, self.is_high_priority(task), user_role)
+    }
+
+    /// Whether the standard workflow lets `user_role` move a task from `from` to `to`
     pub fn can_transition(
         &self,
         from: &TaskStatus,
         to: &TaskStatus,
         is_high_priority: bool,
         user_role: &UserRole,
-    ) -> Result<(), String> {
-        // First check if the basic transition is allowed
-        if !from.can_transition_to(to) {
-            return Err(format!("Invalid transition from {:?} to {:?}", from, to));
-        }
-
-        // Apply business rules based on priority and user role
-        match (from, to) {
-            // High priority tasks must go through review
-            (TaskStatus::InProgress, TaskStatus::Completed) if is_high_priority => {
-                Err("High-priority tasks must go through review before completion".to_string())
-            }
-            
-            // Only managers can approve completion from review
-            (TaskStatus::PendingReview, TaskStatus::Completed) if !user_role.can_approve() => {
-                Err("Only managers can approve task completion".to_string())
-            }
-            
-            // All other valid transitions are allowed
-            _ => Ok(()),
-        }
+    ) -> Result<(), DomainError> {
+        WorkflowDefinition::standard().check(from, to, is_high_priority, user_role)
     }
 
     pub fn get_valid_transitions(
@@ -42,24 +74,12 @@ impl TaskStatusService {
         is_high_priority: bool,
         user_role: &UserRole,
     ) -> Vec<TaskStatus> {
-        let mut valid_transitions = Vec::new();
-
-        // Check all possible statuses
-        let all_statuses = [
-            TaskStatus::Pending,
-            TaskStatus::InProgress,
-            TaskStatus::PendingReview,
-            TaskStatus::Completed,
-            TaskStatus::Cancelled,
-        ];
-
-        for status in &all_statuses {
-            if self.can_transition(current, status, is_high_priority, user_role).is_ok() {
-                valid_transitions.push(status.clone());
-            }
-        }
+        WorkflowDefinition::standard().valid_transitions(current, is_high_priority, user_role)
+    }
 
-        valid_transitions
+    /// `get_valid_transitions` under `workflow` for `task`, judging its priority by the configured policy
+    pub fn get_valid_transitions_for_task(&self, workflow: &WorkflowDefinition, task: &Task, user_role: &UserRole) -> Vec<TaskStatus> {
+        workflow.valid_transitions(task.status(), self.is_high_priority(task), user_role)
     }
 
     pub fn validate_status_change(
@@ -68,7 +88,7 @@ impl TaskStatusService {
         to: &TaskStatus,
         is_high_priority: bool,
         user_role: &UserRole,
-    ) -> Result<String, String> {
+    ) -> Result<String, DomainError> {
         self.can_transition(from, to, is_high_priority, user_role)?;
 
         let message = match (from, to) {
@@ -76,6 +96,7 @@ impl TaskStatusService {
             (TaskStatus::InProgress, TaskStatus::Completed) => "Task completed successfully",
             (TaskStatus::InProgress, TaskStatus::PendingReview) => "Task sent for review",
             (TaskStatus::PendingReview, TaskStatus::Completed) => "Task approved and completed",
+            (TaskStatus::Completed, TaskStatus::InProgress) => "Task reopened",
             (_, TaskStatus::Cancelled) => "Task cancelled",
             _ => "Task status updated",
         };
@@ -83,14 +104,31 @@ impl TaskStatusService {
         Ok(message.to_string())
     }
 
-    pub fn requires_comment(&self, from: &TaskStatus, to: &TaskStatus) -> bool {
-        match (from, to) {
-            // Require comments for approval
-            (TaskStatus::PendingReview, TaskStatus::Completed) => true,
-            // Require comments for cancellation
-            (_, TaskStatus::Cancelled) => true,
-            _ => false,
+    /// Whether `task` must go through review before it is completed
+    pub fn is_high_priority(&self, task: &Task) -> bool {
+        task.is_high_priority_under(&self.priority_policy.high_priority())
+    }
+
+    /// Starting or completing a task waits until every task blocking it is finished
+    pub fn check_blockers(&self, to: &TaskStatus, unfinished_blockers: &[TaskId]) -> Result<(), DomainError> {
+        self.check_blockers_in(&StatusCatalog::default(), to, unfinished_blockers)
+    }
+
+    /// `check_blockers` that also holds back custom statuses of the in-progress and done
+    /// categories, as the catalog defines them
+    pub fn check_blockers_in(&self, catalog: &StatusCatalog, to: &TaskStatus, unfinished_blockers: &[TaskId]) -> Result<(), DomainError> {
+        let waits_for_blockers = matches!(to, TaskStatus::InProgress | TaskStatus::Completed)
+            || (to.is_custom() && matches!(catalog.category(to), Some(StatusCategory::InProgress | StatusCategory::Done)));
+        if !waits_for_blockers || unfinished_blockers.is_empty() {
+            return Ok(());
         }
+
+        Err(DomainError::BlockedByUnfinishedTasks { blockers: unfinished_blockers.iter().map(|id| id.value()).collect() })
+    }
+
+    /// Whether the standard workflow wants a comment on the change
+    pub fn requires_comment(&self, from: &TaskStatus, to: &TaskStatus) -> bool {
+        WorkflowDefinition::standard().requires_comment(from, to)
     }
 
     pub fn get_next_assignee_role(&self, from: &TaskStatus, to: &TaskStatus) -> Option<UserRole> {
@@ -197,6 +235,27 @@ mod tests {
         );
         assert!(transitions.contains(&TaskStatus::Completed));
         assert!(transitions.contains(&TaskStatus::Cancelled));
+
+        // Only managers may reopen a completed task
+        assert_eq!(
+            service.get_valid_transitions(&TaskStatus::Completed, false, &manager_role),
+            vec![TaskStatus::InProgress]
+        );
+        assert!(service.get_valid_transitions(&TaskStatus::Completed, false, &user_role).is_empty());
+    }
+
+    #[test]
+    fn test_check_blockers() {
+        let service = TaskStatusService::new();
+        let blockers = [TaskId::new(3), TaskId::new(5)];
+
+        assert_eq!(
+            service.check_blockers(&TaskStatus::InProgress, &blockers).unwrap_err(),
+            DomainError::BlockedByUnfinishedTasks { blockers: vec![3, 5] }
+        );
+        assert!(service.check_blockers(&TaskStatus::Completed, &blockers).is_err());
+        assert!(service.check_blockers(&TaskStatus::Cancelled, &blockers).is_ok());
+        assert!(service.check_blockers(&TaskStatus::InProgress, &[]).is_ok());
     }
 
     #[test]
@@ -237,4 +296,21 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "Task started successfully");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_priority_policy_decides_which_tasks_need_review() {
+        use crate::domain::{HighPriorityPolicy, PriorityScale};
+
+        let mut task = Task::new(TaskId::new(1), "Ship release".to_string(), Some(2)).unwrap();
+        task.start_progress().unwrap();
+
+        let default = TaskStatusService::new();
+        let workflow = WorkflowDefinition::standard();
+        assert_eq!(default.can_transition_task(workflow, &task, &TaskStatus::Completed, &UserRole::User).unwrap_err(), DomainError::ReviewRequired);
+
+        let policy = PriorityPolicy::new(PriorityScale::new(1, 5).unwrap(), HighPriorityPolicy::new(1)).unwrap();
+        let service = TaskStatusService::new().with_priority_policy(policy);
+        assert!(service.can_transition_task(workflow, &task, &TaskStatus::Completed, &UserRole::User).is_ok());
+        assert_eq!(service.get_valid_transitions_for_task(workflow, &task, &UserRole::User), vec![TaskStatus::Completed, TaskStatus::Cancelled]);
+    }
+}
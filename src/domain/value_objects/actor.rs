@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::value_objects::UserRole;
+
+/// Prefix of `changed_by` for changes made by automation rather than a person
+pub const SYSTEM_ACTOR_PREFIX: &str = "system:";
+
+/// A background job acting on its own authority.
+///
+/// Its changes are recorded as `system:<name>` with the `System` role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemActor {
+    name: String,
+}
+
+impl SystemActor {
+    pub const STALE_TASK_SWEEP: &'static str = "stale-task-sweep";
+    pub const METRICS_PUSH: &'static str = "metrics-push";
+    pub const AUDIT_RETENTION: &'static str = "audit-retention";
+    pub const AUTOMATION: &'static str = "automation";
+    pub const AUTO_ARCHIVE: &'static str = "auto-archive";
+    pub const ESCALATION: &'static str = "escalation";
+
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Value recorded as `changed_by` in status history
+    pub fn changed_by(&self) -> String {
+        format!("{}{}", SYSTEM_ACTOR_PREFIX, self.name)
+    }
+}
+
+/// Whether a change was made by a person or by automation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActorKind {
+    Human,
+    System,
+}
+
+impl ActorKind {
+    pub fn of(role: &UserRole) -> Self {
+        match role {
+            UserRole::System => ActorKind::System,
+            UserRole::User | UserRole::Manager | UserRole::Admin => ActorKind::Human,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_actor_changed_by() {
+        let actor = SystemActor::new(SystemActor::STALE_TASK_SWEEP);
+        assert_eq!(actor.changed_by(), "system:stale-task-sweep");
+        assert_eq!(actor.name(), "stale-task-sweep");
+    }
+
+    #[test]
+    fn test_actor_kind_follows_role() {
+        assert_eq!(ActorKind::of(&UserRole::System), ActorKind::System);
+        assert_eq!(ActorKind::of(&UserRole::Admin), ActorKind::Human);
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use std::fmt;
+
+/// A calendar month in UTC; status history is archived and restored a month at a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArchiveMonth {
+    year: i32,
+    month: u32,
+}
+
+impl ArchiveMonth {
+    pub fn containing(at: DateTime<Utc>) -> Self {
+        Self { year: at.year(), month: at.month() }
+    }
+
+    /// Reads a month written as `YYYY-MM`, e.g. `2024-03`
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let invalid = || format!("Month must be written as YYYY-MM, got '{}'", value);
+        let (year, month) = value.trim().split_once('-').ok_or_else(invalid)?;
+        let year: i32 = year.parse().map_err(|_| invalid())?;
+        let month: u32 = month.parse().map_err(|_| invalid())?;
+        if year.to_string().len() != 4 || !(1..=12).contains(&month) {
+            return Err(invalid());
+        }
+        Ok(Self { year, month })
+    }
+
+    /// First instant of the month
+    pub fn start(&self) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(self.year, self.month, 1, 0, 0, 0).unwrap()
+    }
+
+    /// First instant of the following month, which the month itself excludes
+    pub fn end(&self) -> DateTime<Utc> {
+        self.next().start()
+    }
+
+    pub fn next(&self) -> Self {
+        match self.month {
+            12 => Self { year: self.year + 1, month: 1 },
+            month => Self { year: self.year, month: month + 1 },
+        }
+    }
+
+    /// Object key the month's archived status history is stored under
+    pub fn history_archive_key(&self) -> String {
+        format!("status_history/{}.ndjson.gz", self)
+    }
+}
+
+impl fmt::Display for ArchiveMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_bounds_and_key() {
+        let month = ArchiveMonth::containing(Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap());
+
+        assert_eq!(month.start(), Utc.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap());
+        assert_eq!(month.end(), Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(month.history_archive_key(), "status_history/2024-12.ndjson.gz");
+    }
+
+    #[test]
+    fn test_parse_round_trips_and_rejects_other_forms() {
+        assert_eq!(ArchiveMonth::parse("2024-03").unwrap().to_string(), "2024-03");
+        for value in ["2024-13", "2024", "24-03", "2024-3-1", "March"] {
+            assert!(ArchiveMonth::parse(value).is_err(), "{} should be rejected", value);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::{DateTime, Datelike, Duration, Months, NaiveTime, Utc};
+use std::collections::BTreeMap;
+
+/// Calendar period used to group tasks into cohorts; weeks start on Monday
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CohortBucket {
+    Day,
+    #[default]
+    Week,
+    Month,
+}
+
+impl CohortBucket {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CohortBucket::Day => "day",
+            CohortBucket::Week => "week",
+            CohortBucket::Month => "month",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "day" => Ok(CohortBucket::Day),
+            "week" => Ok(CohortBucket::Week),
+            "month" => Ok(CohortBucket::Month),
+            _ => Err(format!("Invalid cohort bucket: {} (expected day, week or month)", s)),
+        }
+    }
+
+    /// Start of the bucket containing `at`, like `date_trunc` in UTC
+    pub fn start_of(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let date = at.date_naive();
+        let start = match self {
+            CohortBucket::Day => date,
+            CohortBucket::Week => date - Duration::days(i64::from(date.weekday().num_days_from_monday())),
+            CohortBucket::Month => date.with_day(1).expect("every month has a first day"),
+        };
+        start.and_time(NaiveTime::MIN).and_utc()
+    }
+
+    /// Start of the bucket after the one starting at `start`
+    pub fn next_start(&self, start: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            CohortBucket::Day => start + Duration::days(1),
+            CohortBucket::Week => start + Duration::days(7),
+            CohortBucket::Month => start + Months::new(1),
+        }
+    }
+
+    /// Whole buckets from the bucket starting at `start` to the one containing `end`
+    pub fn periods_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> usize {
+        if end < start {
+            return 0;
+        }
+        let periods = match self {
+            CohortBucket::Day => (end - start).num_days(),
+            CohortBucket::Week => (end - start).num_days() / 7,
+            CohortBucket::Month => {
+                i64::from(end.year() - start.year()) * 12 + i64::from(end.month()) - i64::from(start.month())
+            }
+        };
+        periods as usize
+    }
+}
+
+/// Tasks created in the same bucket, and when they were first completed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cohort {
+    pub start: DateTime<Utc>,
+    pub tasks_created: usize,
+    /// Tasks first completed N buckets after the cohort's bucket, keyed by N
+    pub completions_by_period: BTreeMap<usize, usize>,
+}
+
+impl Cohort {
+    /// Tasks completed by the end of each of the first `periods` buckets
+    pub fn cumulative_completions(&self, periods: usize) -> Vec<usize> {
+        let mut completed = 0;
+        (0..periods)
+            .map(|period| {
+                completed += self.completions_by_period.get(&period).copied().unwrap_or(0);
+                completed
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_bucket() {
+        assert_eq!(CohortBucket::from_str("week").unwrap(), CohortBucket::Week);
+        assert_eq!(CohortBucket::from_str("month").unwrap().as_str(), "month");
+        assert!(CohortBucket::from_str("quarter").is_err());
+    }
+
+    #[test]
+    fn test_start_of_truncates_in_utc() {
+        // A Thursday
+        let at = Utc.with_ymd_and_hms(2025, 5, 15, 18, 30, 0).unwrap();
+
+        assert_eq!(CohortBucket::Day.start_of(at), Utc.with_ymd_and_hms(2025, 5, 15, 0, 0, 0).unwrap());
+        assert_eq!(CohortBucket::Week.start_of(at), Utc.with_ymd_and_hms(2025, 5, 12, 0, 0, 0).unwrap());
+        assert_eq!(CohortBucket::Month.start_of(at), Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_next_start_steps_one_bucket() {
+        assert_eq!(CohortBucket::Day.next_start(at(2025, 2, 28)), at(2025, 3, 1));
+        assert_eq!(CohortBucket::Week.next_start(at(2025, 1, 6)), at(2025, 1, 13));
+        assert_eq!(CohortBucket::Month.next_start(at(2024, 12, 1)), at(2025, 1, 1));
+    }
+
+    #[test]
+    fn test_periods_between() {
+        let monday = at(2025, 1, 6);
+        assert_eq!(CohortBucket::Day.periods_between(monday, at(2025, 1, 8)), 2);
+        assert_eq!(CohortBucket::Week.periods_between(monday, at(2025, 1, 19)), 1);
+        assert_eq!(CohortBucket::Week.periods_between(monday, at(2025, 1, 20)), 2);
+        assert_eq!(CohortBucket::Month.periods_between(at(2024, 11, 1), at(2025, 2, 28)), 3);
+        assert_eq!(CohortBucket::Week.periods_between(monday, at(2025, 1, 1)), 0);
+    }
+
+    #[test]
+    fn test_cumulative_completions() {
+        let cohort = Cohort {
+            start: at(2025, 1, 6),
+            tasks_created: 10,
+            completions_by_period: BTreeMap::from([(0, 2), (2, 3), (5, 1)]),
+        };
+        assert_eq!(cohort.cumulative_completions(4), vec![2, 2, 5, 5]);
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt::{self, Write};
+
+/// How long a confirmation token stays valid
+pub const CONFIRMATION_TOKEN_TTL_MINUTES: i64 = 5;
+
+/// Signs and checks the short-lived tokens that confirm a destructive operation.
+///
+/// A token is the expiry time and an HMAC of it together with a subject describing exactly
+/// what is being confirmed, so it only confirms that same operation and cannot be forged
+/// without the key. Replicas that share the key accept each other's tokens.
+#[derive(Clone)]
+pub struct ConfirmationKey {
+    key: Vec<u8>,
+}
+
+impl ConfirmationKey {
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self { key: key.into() }
+    }
+
+    /// A key of 32 random bytes, valid for the life of the process
+    pub fn random() -> Self {
+        let mut key = uuid::Uuid::new_v4().as_bytes().to_vec();
+        key.extend_from_slice(uuid::Uuid::new_v4().as_bytes());
+        Self { key }
+    }
+
+    /// A token for `subject` that expires `CONFIRMATION_TOKEN_TTL_MINUTES` after `now`
+    pub fn issue(&self, subject: &str, now: DateTime<Utc>) -> (String, DateTime<Utc>) {
+        let expires_at = Self::truncate(now + Duration::minutes(CONFIRMATION_TOKEN_TTL_MINUTES));
+        let signature = self.mac(subject, expires_at.timestamp()).finalize().into_bytes();
+        let token = signature.iter().fold(format!("{}.", expires_at.timestamp()), |mut token, byte| {
+            let _ = write!(token, "{:02x}", byte);
+            token
+        });
+        (token, expires_at)
+    }
+
+    /// Checks that `token` was issued for `subject` and has not expired
+    pub fn verify(&self, token: &str, subject: &str, now: DateTime<Utc>) -> Result<(), String> {
+        let invalid = || "Confirmation token does not match this operation; request a new one".to_string();
+        let (expires_at, signature) = token.trim().split_once('.').ok_or_else(invalid)?;
+        let expires_at: i64 = expires_at.parse().map_err(|_| invalid())?;
+        let signature = decode_hex(signature).ok_or_else(invalid)?;
+        self.mac(subject, expires_at).verify_slice(&signature).map_err(|_| invalid())?;
+        match Utc.timestamp_opt(expires_at, 0).single() {
+            Some(expires_at) if expires_at > now => Ok(()),
+            _ => Err("Confirmation token has expired; request a new one".to_string()),
+        }
+    }
+
+    fn mac(&self, subject: &str, expires_at: i64) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(format!("{}\n{}", expires_at, subject).as_bytes());
+        mac
+    }
+
+    fn truncate(at: DateTime<Utc>) -> DateTime<Utc> {
+        Utc.timestamp_opt(at.timestamp(), 0).unwrap()
+    }
+}
+
+impl Default for ConfirmationKey {
+    fn default() -> Self {
+        Self::random()
+    }
+}
+
+impl fmt::Debug for ConfirmationKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ConfirmationKey(***)")
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_confirms_only_its_subject_until_it_expires() {
+        let key = ConfirmationKey::new("shared secret");
+        let now = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        let (token, expires_at) = key.issue("delete 1,2,3", now);
+
+        assert_eq!(expires_at, now + Duration::minutes(CONFIRMATION_TOKEN_TTL_MINUTES));
+        assert!(key.verify(&token, "delete 1,2,3", now + Duration::minutes(4)).is_ok());
+        assert!(ConfirmationKey::new("shared secret").verify(&token, "delete 1,2,3", now).is_ok());
+        assert!(key.verify(&token, "delete 1,2", now).unwrap_err().contains("does not match"));
+        assert!(ConfirmationKey::new("other").verify(&token, "delete 1,2,3", now).is_err());
+        assert!(key.verify("not-a-token", "delete 1,2,3", now).is_err());
+        assert!(key.verify(&token, "delete 1,2,3", expires_at).unwrap_err().contains("expired"));
+    }
+}
@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use crate::domain::value_objects::{CohortBucket, FacetCount, TaskFacets, TaskStatus};
+
+/// Tasks in each status at the close of one bucket
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowBucket {
+    pub start: DateTime<Utc>,
+    /// Every status of the flow, in the flow's order, zero counts included
+    pub statuses: Vec<FacetCount<TaskStatus>>,
+}
+
+impl FlowBucket {
+    pub fn total(&self) -> usize {
+        self.statuses.iter().map(|facet| facet.count).sum()
+    }
+}
+
+/// How many tasks sat in each status at the close of every bucket of a period, for cumulative
+/// flow and burndown charts
+#[derive(Debug, Clone, PartialEq)]
+pub struct CumulativeFlow {
+    pub bucket: CohortBucket,
+    /// Statuses any bucket holds tasks in, in lifecycle order, then custom statuses by name
+    pub statuses: Vec<TaskStatus>,
+    /// One entry per bucket from the one containing the period's start to the one containing its end
+    pub buckets: Vec<FlowBucket>,
+}
+
+impl CumulativeFlow {
+    /// Builds the flow from `(bucket start, status, tasks)` counts in any order, as a `GROUP BY`
+    /// returns them; buckets without counts are filled with zeros
+    pub fn from_counts(
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+        counts: Vec<(DateTime<Utc>, TaskStatus, usize)>,
+    ) -> Self {
+        let totals = counts.iter().map(|(_, status, count)| (status.clone(), *count)).collect();
+        let statuses: Vec<TaskStatus> = TaskFacets::from_counts(totals, Vec::new())
+            .statuses
+            .into_iter()
+            .map(|facet| facet.value)
+            .collect();
+
+        let last = bucket.start_of(end_date);
+        let mut buckets = Vec::new();
+        let mut start = bucket.start_of(start_date);
+        while start <= last {
+            let statuses = statuses
+                .iter()
+                .map(|status| FacetCount {
+                    value: status.clone(),
+                    count: counts
+                        .iter()
+                        .filter(|(counted, value, _)| *counted == start && value == status)
+                        .map(|(_, _, count)| count)
+                        .sum(),
+                })
+                .collect();
+            buckets.push(FlowBucket { start, statuses });
+            start = bucket.next_start(start);
+        }
+
+        Self { bucket, statuses, buckets }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn day(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_from_counts_fills_every_bucket_with_every_status() {
+        let review = TaskStatus::parse("Review").unwrap();
+        let counts = vec![
+            (day(12), TaskStatus::Completed, 1),
+            (day(10), review.clone(), 1),
+            (day(10), TaskStatus::Pending, 2),
+            (day(12), TaskStatus::Pending, 1),
+        ];
+
+        let flow = CumulativeFlow::from_counts(CohortBucket::Day, day(10) + chrono::Duration::hours(9), day(12), counts);
+        assert_eq!(flow.statuses, vec![TaskStatus::Pending, TaskStatus::Completed, review]);
+
+        let series: Vec<_> = flow.buckets
+            .iter()
+            .map(|bucket| (bucket.start, bucket.statuses.iter().map(|facet| facet.count).collect::<Vec<_>>()))
+            .collect();
+        assert_eq!(series, vec![(day(10), vec![2, 0, 1]), (day(11), vec![0, 0, 0]), (day(12), vec![1, 1, 0])]);
+        assert_eq!(flow.buckets[0].total(), 3);
+    }
+
+    #[test]
+    fn test_from_counts_covers_the_buckets_containing_both_ends() {
+        let flow = CumulativeFlow::from_counts(CohortBucket::Week, day(4), day(16), Vec::new());
+
+        let starts: Vec<_> = flow.buckets.iter().map(|bucket| bucket.start).collect();
+        assert_eq!(starts, vec![day(2), day(9), day(16)]);
+        assert!(flow.statuses.is_empty());
+    }
+}
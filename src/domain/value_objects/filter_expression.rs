@@ -0,0 +1,486 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use crate::domain::entities::Task;
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::TaskStatus;
+
+/// Maximum number of characters allowed in a filter expression
+pub const MAX_FILTER_LENGTH: usize = 500;
+
+/// A task field a filter expression can test
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterField {
+    Status,
+    Priority,
+    Name,
+    Description,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+}
+
+impl FilterField {
+    pub const FILTERABLE: [&'static str; 7] = ["status", "priority", "name", "description", "created_at", "updated_at", "created_by"];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterField::Status => "status",
+            FilterField::Priority => "priority",
+            FilterField::Name => "name",
+            FilterField::Description => "description",
+            FilterField::CreatedAt => "created_at",
+            FilterField::UpdatedAt => "updated_at",
+            FilterField::CreatedBy => "created_by",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "status" => Some(FilterField::Status),
+            "priority" => Some(FilterField::Priority),
+            "name" => Some(FilterField::Name),
+            "description" => Some(FilterField::Description),
+            "created_at" => Some(FilterField::CreatedAt),
+            "updated_at" => Some(FilterField::UpdatedAt),
+            "created_by" => Some(FilterField::CreatedBy),
+            _ => None,
+        }
+    }
+
+    /// Whether the field may be unset on a task; an unset field matches no comparison
+    pub fn is_nullable(&self) -> bool {
+        matches!(self, FilterField::Priority | FilterField::Description | FilterField::CreatedBy)
+    }
+
+    fn is_text(&self) -> bool {
+        matches!(self, FilterField::Name | FilterField::Description | FilterField::CreatedBy)
+    }
+
+    fn is_ordered(&self) -> bool {
+        matches!(self, FilterField::Priority | FilterField::CreatedAt | FilterField::UpdatedAt)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    /// Case-insensitive substring match on a text field, written `~`
+    Contains,
+}
+
+impl Comparison {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Comparison::Eq => "=",
+            Comparison::Ne => "!=",
+            Comparison::Lt => "<",
+            Comparison::Le => "<=",
+            Comparison::Gt => ">",
+            Comparison::Ge => ">=",
+            Comparison::Contains => "~",
+        }
+    }
+
+    fn holds<T: PartialOrd>(&self, left: &T, right: &T) -> bool {
+        match self {
+            Comparison::Eq => left == right,
+            Comparison::Ne => left != right,
+            Comparison::Lt => left < right,
+            Comparison::Le => left <= right,
+            Comparison::Gt => left > right,
+            Comparison::Ge => left >= right,
+            Comparison::Contains => false,
+        }
+    }
+}
+
+/// A literal in a filter expression, already checked against its field's type
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    Status(TaskStatus),
+    Integer(i32),
+    Text(String),
+    Timestamp(DateTime<Utc>),
+}
+
+/// A parsed `?filter=` expression such as `status in (Pending,InProgress) and priority <= 3`.
+///
+/// Fields and operators come from fixed lists and values are kept apart from them, so
+/// repositories can compile an expression into bound query parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterExpression {
+    /// Every operand matches
+    All(Vec<FilterExpression>),
+    /// At least one operand matches
+    Any(Vec<FilterExpression>),
+    Not(Box<FilterExpression>),
+    Compare { field: FilterField, comparison: Comparison, value: FilterValue },
+    /// The field equals one of the values
+    In { field: FilterField, values: Vec<FilterValue> },
+}
+
+impl FilterExpression {
+    /// Parses `input`, resolving relative times such as `-7d` against `now`.
+    ///
+    /// Conditions are `field op value` with `=`, `!=`, `<`, `<=`, `>`, `>=` or `~`, or
+    /// `field [not] in (value, ...)`. They combine with `and`, `or`, `not` and parentheses;
+    /// `and` binds tighter than `or`. Times are dates (`2026-01-31`), RFC 3339 timestamps, or
+    /// offsets from now in minutes, hours, days or weeks (`-30m`, `-12h`, `-7d`, `-2w`).
+    pub fn parse(input: &str, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        if input.trim().is_empty() {
+            return Err(invalid("the expression is empty"));
+        }
+        if input.chars().count() > MAX_FILTER_LENGTH {
+            return Err(invalid(format!("the expression cannot exceed {} characters", MAX_FILTER_LENGTH)));
+        }
+        let mut parser = Parser { tokens: tokenize(input)?, position: 0, now };
+        let expression = parser.or()?;
+        match parser.peek() {
+            None => Ok(expression),
+            Some(token) => Err(invalid(format!("unexpected {} at position {}", token.kind.describe(), token.offset))),
+        }
+    }
+
+    /// Evaluates the expression against a loaded task, as the SQL it compiles to would
+    pub fn matches(&self, task: &Task) -> bool {
+        match self {
+            FilterExpression::All(operands) => operands.iter().all(|operand| operand.matches(task)),
+            FilterExpression::Any(operands) => operands.iter().any(|operand| operand.matches(task)),
+            FilterExpression::Not(operand) => !operand.matches(task),
+            FilterExpression::Compare { field, comparison, value } => compare(task, *field, *comparison, value),
+            FilterExpression::In { field, values } => values.iter().any(|value| compare(task, *field, Comparison::Eq, value)),
+        }
+    }
+}
+
+fn compare(task: &Task, field: FilterField, comparison: Comparison, value: &FilterValue) -> bool {
+    let text = |actual: Option<&str>, expected: &str| match (actual, comparison) {
+        (None, _) => false,
+        (Some(actual), Comparison::Contains) => actual.to_lowercase().contains(&expected.to_lowercase()),
+        (Some(actual), _) => comparison.holds(&actual, &expected),
+    };
+    match (field, value) {
+        (FilterField::Status, FilterValue::Status(status)) => comparison.holds(&task.status.as_str(), &status.as_str()),
+        (FilterField::Priority, FilterValue::Integer(priority)) => task.priority.is_some_and(|actual| comparison.holds(&actual, priority)),
+        (FilterField::Name, FilterValue::Text(expected)) => text(Some(&task.name), expected),
+        (FilterField::Description, FilterValue::Text(expected)) => text(task.description.as_deref(), expected),
+        (FilterField::CreatedBy, FilterValue::Text(expected)) => text(task.created_by.as_deref(), expected),
+        (FilterField::CreatedAt, FilterValue::Timestamp(at)) => comparison.holds(&task.created_at, at),
+        (FilterField::UpdatedAt, FilterValue::Timestamp(at)) => comparison.holds(&task.updated_at, at),
+        _ => false,
+    }
+}
+
+fn invalid(message: impl Into<String>) -> DomainError {
+    DomainError::InvalidFilter { message: message.into() }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Word(String),
+    Quoted(String),
+    Operator(Comparison),
+    Open,
+    Close,
+    Comma,
+}
+
+impl TokenKind {
+    fn describe(&self) -> String {
+        match self {
+            TokenKind::Word(word) => format!("`{}`", word),
+            TokenKind::Quoted(text) => format!("\"{}\"", text),
+            TokenKind::Operator(comparison) => format!("`{}`", comparison.as_str()),
+            TokenKind::Open => "`(`".to_string(),
+            TokenKind::Close => "`)`".to_string(),
+            TokenKind::Comma => "`,`".to_string(),
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, TokenKind::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    /// Character position in the input, counted from 1 for error messages
+    offset: usize,
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '+' | ':' | '.')
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, DomainError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let offset = i + 1;
+        let (kind, next) = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => (TokenKind::Open, i + 1),
+            ')' => (TokenKind::Close, i + 1),
+            ',' => (TokenKind::Comma, i + 1),
+            '~' => (TokenKind::Operator(Comparison::Contains), i + 1),
+            '=' => (TokenKind::Operator(Comparison::Eq), i + 1),
+            '!' if chars.get(i + 1) == Some(&'=') => (TokenKind::Operator(Comparison::Ne), i + 2),
+            '<' | '>' => {
+                let or_equal = chars.get(i + 1) == Some(&'=');
+                let comparison = match (c, or_equal) {
+                    ('<', false) => Comparison::Lt,
+                    ('<', true) => Comparison::Le,
+                    ('>', false) => Comparison::Gt,
+                    _ => Comparison::Ge,
+                };
+                (TokenKind::Operator(comparison), if or_equal { i + 2 } else { i + 1 })
+            }
+            '"' | '\'' => {
+                let end = chars[i + 1..].iter().position(|&q| q == c)
+                    .ok_or_else(|| invalid(format!("unterminated string starting at position {}", offset)))?;
+                (TokenKind::Quoted(chars[i + 1..i + 1 + end].iter().collect()), i + end + 2)
+            }
+            c if is_word_char(c) => {
+                let end = chars[i..].iter().position(|&w| !is_word_char(w)).map_or(chars.len(), |len| i + len);
+                (TokenKind::Word(chars[i..end].iter().collect()), end)
+            }
+            _ => return Err(invalid(format!("unexpected character `{}` at position {}", c, offset))),
+        };
+        tokens.push(Token { kind, offset });
+        i = next;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    now: DateTime<Utc>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self, expected: &str) -> Result<Token, DomainError> {
+        let token = self.tokens.get(self.position).cloned()
+            .ok_or_else(|| invalid(format!("expected {} at the end of the expression", expected)))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.peek().is_some_and(|token| token.kind.is_keyword(keyword));
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, kind: TokenKind, expected: &str) -> Result<(), DomainError> {
+        let token = self.next(expected)?;
+        if token.kind != kind {
+            return Err(invalid(format!("expected {} at position {}, found {}", expected, token.offset, token.kind.describe())));
+        }
+        Ok(())
+    }
+
+    fn or(&mut self) -> Result<FilterExpression, DomainError> {
+        let mut operands = vec![self.and()?];
+        while self.eat_keyword("or") {
+            operands.push(self.and()?);
+        }
+        Ok(if operands.len() == 1 { operands.remove(0) } else { FilterExpression::Any(operands) })
+    }
+
+    fn and(&mut self) -> Result<FilterExpression, DomainError> {
+        let mut operands = vec![self.unary()?];
+        while self.eat_keyword("and") {
+            operands.push(self.unary()?);
+        }
+        Ok(if operands.len() == 1 { operands.remove(0) } else { FilterExpression::All(operands) })
+    }
+
+    fn unary(&mut self) -> Result<FilterExpression, DomainError> {
+        if self.eat_keyword("not") {
+            return Ok(FilterExpression::Not(Box::new(self.unary()?)));
+        }
+        if self.peek().is_some_and(|token| token.kind == TokenKind::Open) {
+            self.position += 1;
+            let expression = self.or()?;
+            self.expect(TokenKind::Close, "`)`")?;
+            return Ok(expression);
+        }
+        self.condition()
+    }
+
+    fn condition(&mut self) -> Result<FilterExpression, DomainError> {
+        let token = self.next("a field")?;
+        let field = match &token.kind {
+            TokenKind::Word(name) => FilterField::from_name(name).ok_or_else(|| invalid(format!(
+                "unknown field `{}` at position {} (expected one of {})",
+                name,
+                token.offset,
+                FilterField::FILTERABLE.join(", ")
+            )))?,
+            other => return Err(invalid(format!("expected a field at position {}, found {}", token.offset, other.describe()))),
+        };
+
+        let negated = self.eat_keyword("not");
+        if self.eat_keyword("in") {
+            let values = self.list(field)?;
+            let expression = FilterExpression::In { field, values };
+            return Ok(if negated { FilterExpression::Not(Box::new(expression)) } else { expression });
+        }
+        if negated {
+            return Err(invalid(format!("expected `in` after `{} not`", field.as_str())));
+        }
+
+        let token = self.next("an operator")?;
+        let comparison = match token.kind {
+            TokenKind::Operator(comparison) => comparison,
+            other => return Err(invalid(format!("expected an operator at position {}, found {}", token.offset, other.describe()))),
+        };
+        let allowed = match comparison {
+            Comparison::Eq | Comparison::Ne => true,
+            Comparison::Contains => field.is_text(),
+            _ => field.is_ordered(),
+        };
+        if !allowed {
+            return Err(invalid(format!("`{}` cannot be used with {} at position {}", comparison.as_str(), field.as_str(), token.offset)));
+        }
+        let value = self.value(field)?;
+        Ok(FilterExpression::Compare { field, comparison, value })
+    }
+
+    fn list(&mut self, field: FilterField) -> Result<Vec<FilterValue>, DomainError> {
+        self.expect(TokenKind::Open, "`(`")?;
+        let mut values = vec![self.value(field)?];
+        loop {
+            let token = self.next("`,` or `)`")?;
+            match token.kind {
+                TokenKind::Comma => values.push(self.value(field)?),
+                TokenKind::Close => return Ok(values),
+                other => return Err(invalid(format!("expected `,` or `)` at position {}, found {}", token.offset, other.describe()))),
+            }
+        }
+    }
+
+    fn value(&mut self, field: FilterField) -> Result<FilterValue, DomainError> {
+        let token = self.next("a value")?;
+        let text = match &token.kind {
+            TokenKind::Word(text) | TokenKind::Quoted(text) => text.clone(),
+            other => return Err(invalid(format!("expected a value at position {}, found {}", token.offset, other.describe()))),
+        };
+        let bad_value = |expected: &str| invalid(format!("`{}` at position {} is not {}", text, token.offset, expected));
+        match field {
+            FilterField::Status => TaskStatus::parse(&text)
+                .map(FilterValue::Status)
+                .map_err(|_| bad_value("a task status")),
+            FilterField::Priority => text.parse().map(FilterValue::Integer).map_err(|_| bad_value("a whole number")),
+            FilterField::CreatedAt | FilterField::UpdatedAt => parse_time(&text, self.now)
+                .map(FilterValue::Timestamp)
+                .ok_or_else(|| bad_value("a date, timestamp or offset such as -7d")),
+            FilterField::Name | FilterField::Description | FilterField::CreatedBy => Ok(FilterValue::Text(text)),
+        }
+    }
+}
+
+/// Reads `2026-01-31` as midnight UTC, an RFC 3339 timestamp, or a signed offset from `now`
+fn parse_time(text: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if let Some(sign) = text.chars().next().filter(|c| matches!(c, '-' | '+')) {
+        let unit = text.chars().last()?;
+        let amount: i64 = text.get(1..text.len() - 1)?.parse().ok()?;
+        let offset = match unit {
+            'm' => Duration::try_minutes(amount)?,
+            'h' => Duration::try_hours(amount)?,
+            'd' => Duration::try_days(amount)?,
+            'w' => Duration::try_weeks(amount)?,
+            _ => return None,
+        };
+        return if sign == '-' { now.checked_sub_signed(offset) } else { now.checked_add_signed(offset) };
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        return date.and_hms_opt(0, 0, 0).map(|midnight| midnight.and_utc());
+    }
+    DateTime::parse_from_rfc3339(text).ok().map(|at| at.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TaskId;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z").unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_parse_builds_and_before_or() {
+        let expression = FilterExpression::parse("status in (Pending,InProgress) and priority <= 3 or not name ~ 'draft'", now()).unwrap();
+
+        assert_eq!(expression, FilterExpression::Any(vec![
+            FilterExpression::All(vec![
+                FilterExpression::In {
+                    field: FilterField::Status,
+                    values: vec![FilterValue::Status(TaskStatus::Pending), FilterValue::Status(TaskStatus::InProgress)],
+                },
+                FilterExpression::Compare { field: FilterField::Priority, comparison: Comparison::Le, value: FilterValue::Integer(3) },
+            ]),
+            FilterExpression::Not(Box::new(FilterExpression::Compare {
+                field: FilterField::Name,
+                comparison: Comparison::Contains,
+                value: FilterValue::Text("draft".to_string()),
+            })),
+        ]));
+    }
+
+    #[test]
+    fn test_parse_resolves_times() {
+        let at = |input: &str| match FilterExpression::parse(input, now()).unwrap() {
+            FilterExpression::Compare { value: FilterValue::Timestamp(at), .. } => at,
+            other => panic!("unexpected {:?}", other),
+        };
+
+        assert_eq!(at("created_at > -7d"), now() - Duration::days(7));
+        assert_eq!(at("updated_at < +2h"), now() + Duration::hours(2));
+        assert_eq!(at("created_at >= 2026-01-31").to_rfc3339(), "2026-01-31T00:00:00+00:00");
+        assert_eq!(at("created_at < \"2026-01-31T10:00:00+02:00\"").to_rfc3339(), "2026-01-31T08:00:00+00:00");
+    }
+
+    #[test]
+    fn test_parse_reports_where_it_failed() {
+        let error = |input: &str| FilterExpression::parse(input, now()).unwrap_err().to_string();
+
+        assert_eq!(error("owner = me"), "Invalid filter: unknown field `owner` at position 1 (expected one of status, priority, name, description, created_at, updated_at, created_by)");
+        assert_eq!(error("priority <= high"), "Invalid filter: `high` at position 13 is not a whole number");
+        assert_eq!(error("status < Pending"), "Invalid filter: `<` cannot be used with status at position 8");
+        assert_eq!(error("(priority = 1"), "Invalid filter: expected `)` at the end of the expression");
+        assert_eq!(error("priority = 1 priority = 2"), "Invalid filter: unexpected `priority` at position 14");
+        assert_eq!(error("name = 'open"), "Invalid filter: unterminated string starting at position 8");
+        assert!(FilterExpression::parse(&"a".repeat(MAX_FILTER_LENGTH + 1), now()).is_err());
+    }
+
+    #[test]
+    fn test_matches_treats_unset_fields_as_never_equal() {
+        let task = Task::new_at(TaskId::new(1), "Write Draft".to_string(), None, now()).unwrap();
+        let matches = |input: &str| FilterExpression::parse(input, now()).unwrap().matches(&task);
+
+        assert!(matches("name ~ DRAFT and status = Pending"));
+        assert!(!matches("priority != 3"));
+        assert!(matches("not priority = 3"));
+        assert!(matches("created_at > -1h and created_at <= 2026-03-10T12:00:00Z"));
+        assert!(!matches("status not in (Pending, Completed)"));
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use crate::domain::value_objects::{SortOrder, TaskStatus};
+
+/// Which status history entries a listing should return; all set criteria must match
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryFilter {
+    /// Entries of a single task; `None` spans all tasks
+    pub task_id: Option<i32>,
+    pub changed_by: Option<String>,
+    pub to_status: Option<TaskStatus>,
+    /// Inclusive lower bound on `changed_at`
+    pub start_date: Option<DateTime<Utc>>,
+    /// Inclusive upper bound on `changed_at`
+    pub end_date: Option<DateTime<Utc>>,
+}
+
+impl HistoryFilter {
+    pub fn validate(&self) -> Result<(), String> {
+        match (self.start_date, self.end_date) {
+            (Some(start), Some(end)) if start > end => Err("The start of the date range must not be after its end".to_string()),
+            _ => Ok(()),
+        }
+    }
+
+    /// The criteria and `order` as text, which pagination cursors are tied to
+    pub fn fingerprint(&self, order: SortOrder) -> String {
+        format!(
+            "task={:?};changed_by={:?};to_status={:?};start={:?};end={:?};order={:?}",
+            self.task_id, self.changed_by, self.to_status, self.start_date, self.end_date, order,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_filter_rejects_inverted_date_range() {
+        let now = Utc::now();
+        let filter = HistoryFilter {
+            start_date: Some(now),
+            end_date: Some(now - Duration::days(1)),
+            ..HistoryFilter::default()
+        };
+        assert!(filter.validate().is_err());
+        assert!(HistoryFilter { end_date: None, ..filter }.validate().is_ok());
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_every_criterion() {
+        let filter = HistoryFilter { task_id: Some(1), ..HistoryFilter::default() };
+        let fingerprint = filter.fingerprint(SortOrder::Asc);
+
+        assert_eq!(fingerprint, filter.clone().fingerprint(SortOrder::Asc));
+        assert_ne!(fingerprint, filter.fingerprint(SortOrder::Desc));
+        assert_ne!(fingerprint, HistoryFilter { task_id: Some(1), changed_by: Some("alice".to_string()), ..HistoryFilter::default() }.fingerprint(SortOrder::Asc));
+        assert_ne!(fingerprint, HistoryFilter { task_id: Some(1), to_status: Some(TaskStatus::Completed), ..HistoryFilter::default() }.fingerprint(SortOrder::Asc));
+    }
+}
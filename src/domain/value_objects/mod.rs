@@ -1,9 +1,55 @@
 pub mod task_id;
 pub mod task_status;
+pub mod status_catalog;
 pub mod user_role;
 pub mod status_history;
+pub mod priority_scale;
+pub mod priority_label;
+pub mod priority_mapping;
+pub mod task_criteria;
+pub mod filter_expression;
+pub mod task_facets;
+pub mod task_board;
+pub mod task_statistics;
+pub mod task_rank;
+pub mod task_patch;
+pub mod history_criteria;
+pub mod pagination;
+pub mod cohort;
+pub mod cumulative_flow;
+pub mod review_checklist;
+pub mod review_chain;
+pub mod workflow;
+pub mod actor;
+pub mod task_import;
+pub mod archive_month;
+pub mod report_period;
+pub mod confirmation_token;
 
 pub use task_id::*;
 pub use task_status::*;
+pub use status_catalog::*;
 pub use user_role::*;
-pub use status_history::*;
\ No newline at end of file
+pub use status_history::*;
+pub use priority_scale::*;
+pub use priority_label::*;
+pub use priority_mapping::*;
+pub use task_criteria::*;
+pub use filter_expression::*;
+pub use task_facets::*;
+pub use task_board::*;
+pub use task_statistics::*;
+pub use task_rank::*;
+pub use task_patch::*;
+pub use history_criteria::*;
+pub use pagination::*;
+pub use cohort::*;
+pub use cumulative_flow::*;
+pub use review_checklist::*;
+pub use review_chain::*;
+pub use workflow::*;
+pub use actor::*;
+pub use task_import::*;
+pub use archive_month::*;
+pub use report_period::*;
+pub use confirmation_token::*;
\ No newline at end of file
@@ -0,0 +1,163 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+/// A window into a long listing: the `limit` entries after `after`, or else after skipping `offset`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pagination {
+    pub limit: u32,
+    pub offset: u32,
+    /// Sort keys of the last entry of the previous page, read from a cursor
+    pub after: Option<PageKey>,
+}
+
+impl Pagination {
+    pub const DEFAULT_LIMIT: u32 = 50;
+    pub const MAX_LIMIT: u32 = 200;
+
+    /// Parses optional `limit`/`offset` query values
+    pub fn new(limit: Option<u32>, offset: Option<u32>) -> Result<Self, String> {
+        let limit = limit.unwrap_or(Self::DEFAULT_LIMIT);
+        if limit == 0 || limit > Self::MAX_LIMIT {
+            return Err(format!("limit must be between 1 and {}", Self::MAX_LIMIT));
+        }
+        Ok(Self { limit, offset: offset.unwrap_or(0), after: None })
+    }
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self { limit: Self::DEFAULT_LIMIT, offset: 0, after: None }
+    }
+}
+
+/// Sort keys of an entry in a listing ordered by time, with ties broken by id
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageKey {
+    pub at: DateTime<Utc>,
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CursorPayload<K> {
+    /// Where the next page starts
+    #[serde(rename = "k")]
+    key: K,
+    /// Hash of the filters the cursor was issued for
+    #[serde(rename = "f")]
+    filters: String,
+}
+
+/// Signs and checks the cursors that list endpoints hand out for their next page.
+///
+/// A cursor is the base64 of the next page's sort keys and a hash of the filters the listing was
+/// asked for, followed by an HMAC of both. Editing a cursor breaks its signature, and a cursor
+/// only continues a listing with the same filters. Replicas that share the key accept each
+/// other's cursors.
+#[derive(Clone)]
+pub struct CursorKey {
+    key: Vec<u8>,
+}
+
+impl CursorKey {
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self { key: key.into() }
+    }
+
+    /// A key of 32 random bytes, valid for the life of the process
+    pub fn random() -> Self {
+        let mut key = uuid::Uuid::new_v4().as_bytes().to_vec();
+        key.extend_from_slice(uuid::Uuid::new_v4().as_bytes());
+        Self { key }
+    }
+
+    /// A cursor resuming after `key` in a listing with `filters`
+    pub fn encode<K: Serialize>(&self, key: &K, filters: &str) -> String {
+        let payload = CursorPayload { key, filters: filters_hash(filters) };
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&payload).expect("cursor keys serialize to JSON"));
+        let signature = URL_SAFE_NO_PAD.encode(self.mac(&payload).finalize().into_bytes());
+        format!("{}.{}", payload, signature)
+    }
+
+    /// The sort keys in `cursor`, once it is known to be unaltered and issued for `filters`
+    pub fn decode<K: DeserializeOwned>(&self, cursor: &str, filters: &str) -> Result<K, String> {
+        let invalid = || "Invalid cursor; start again from the first page".to_string();
+        let (payload, signature) = cursor.trim().split_once('.').ok_or_else(invalid)?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid())?;
+        self.mac(payload).verify_slice(&signature).map_err(|_| invalid())?;
+        let payload = URL_SAFE_NO_PAD.decode(payload).map_err(|_| invalid())?;
+        let payload: CursorPayload<K> = serde_json::from_slice(&payload).map_err(|_| invalid())?;
+        if payload.filters != filters_hash(filters) {
+            return Err("The cursor belongs to a listing with other filters; start again from the first page".to_string());
+        }
+        Ok(payload.key)
+    }
+
+    fn mac(&self, payload: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(payload.as_bytes());
+        mac
+    }
+}
+
+impl Default for CursorKey {
+    fn default() -> Self {
+        Self::random()
+    }
+}
+
+impl fmt::Debug for CursorKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CursorKey(***)")
+    }
+}
+
+fn filters_hash(filters: &str) -> String {
+    URL_SAFE_NO_PAD.encode(&Sha256::digest(filters.as_bytes())[..16])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn key() -> PageKey {
+        PageKey { at: Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap(), id: "0195f1c2".to_string() }
+    }
+
+    #[test]
+    fn test_pagination_defaults_and_bounds() {
+        assert_eq!(Pagination::new(None, None).unwrap(), Pagination::default());
+        assert_eq!(Pagination::new(Some(10), Some(20)).unwrap(), Pagination { limit: 10, offset: 20, after: None });
+        assert!(Pagination::new(Some(0), None).is_err());
+        assert!(Pagination::new(Some(Pagination::MAX_LIMIT + 1), None).is_err());
+    }
+
+    #[test]
+    fn test_cursor_round_trips_only_for_its_filters() {
+        let signer = CursorKey::new("shared secret");
+        let cursor = signer.encode(&key(), "task=1;order=asc");
+
+        assert_eq!(signer.decode::<PageKey>(&cursor, "task=1;order=asc").unwrap(), key());
+        assert_eq!(CursorKey::new("shared secret").decode::<PageKey>(&cursor, "task=1;order=asc").unwrap(), key());
+        assert!(signer.decode::<PageKey>(&cursor, "task=2;order=asc").unwrap_err().contains("other filters"));
+        assert!(CursorKey::new("other").decode::<PageKey>(&cursor, "task=1;order=asc").unwrap_err().contains("Invalid cursor"));
+    }
+
+    #[test]
+    fn test_altered_cursors_are_rejected() {
+        let signer = CursorKey::new("shared secret");
+        let cursor = signer.encode(&key(), "");
+        let (_, signature) = cursor.split_once('.').unwrap();
+        let forged = PageKey { id: "00000000".to_string(), ..key() };
+        let forged = serde_json::to_vec(&CursorPayload { key: forged, filters: filters_hash("") }).unwrap();
+        let forged = format!("{}.{}", URL_SAFE_NO_PAD.encode(forged), signature);
+
+        for cursor in [forged.as_str(), "not-a-cursor", "e30.e30", ""] {
+            assert!(signer.decode::<PageKey>(cursor, "").is_err(), "{} should be rejected", cursor);
+        }
+    }
+}
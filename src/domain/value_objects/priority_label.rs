@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::value_objects::PriorityScale;
+
+/// Human-readable priority band; lower numbers are more urgent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriorityLabel {
+    Critical,
+    High,
+    Medium,
+    Low,
+}
+
+impl PriorityLabel {
+    pub const ALL: [PriorityLabel; 4] = [
+        PriorityLabel::Critical,
+        PriorityLabel::High,
+        PriorityLabel::Medium,
+        PriorityLabel::Low,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriorityLabel::Critical => "Critical",
+            PriorityLabel::High => "High",
+            PriorityLabel::Medium => "Medium",
+            PriorityLabel::Low => "Low",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|label| label.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Invalid priority label: {}", s))
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Mapping between priority labels and numbers.
+///
+/// Each label owns the numbers from its own value up to the next label's value,
+/// so with Critical=1, High=3, Medium=6, Low=8 a priority of 4 reads as High.
+/// A label given in a request resolves to its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriorityLabels {
+    values: [i32; 4],
+}
+
+impl PriorityLabels {
+    /// Values in label order (Critical, High, Medium, Low); they must not decrease
+    pub fn new(values: [i32; 4]) -> Result<Self, String> {
+        if values.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err("Priority label values must be in order Critical <= High <= Medium <= Low".to_string());
+        }
+        Ok(Self { values })
+    }
+
+    /// Spreads the four labels evenly over the scale
+    pub fn for_scale(scale: &PriorityScale) -> Self {
+        let span = scale.max() - scale.min() + 1;
+        let mut values = [0; 4];
+        for (index, value) in values.iter_mut().enumerate() {
+            *value = scale.min() + (index as i32 * span) / 4;
+        }
+        Self { values }
+    }
+
+    /// Parses `Critical=1,High=3,Medium=6,Low=8`; every label must be present
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let mut values: [Option<i32>; 4] = [None; 4];
+        for entry in s.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (label, value) = entry.split_once('=')
+                .ok_or_else(|| format!("Invalid priority label mapping: {}", entry))?;
+            let label = PriorityLabel::from_str(label.trim())?;
+            let value = value.trim().parse()
+                .map_err(|_| format!("Invalid priority value for {}: {}", label.as_str(), value.trim()))?;
+            values[label.index()] = Some(value);
+        }
+
+        let mut resolved = [0; 4];
+        for label in PriorityLabel::ALL {
+            resolved[label.index()] = values[label.index()]
+                .ok_or_else(|| format!("Missing priority label mapping for {}", label.as_str()))?;
+        }
+        Self::new(resolved)
+    }
+
+    pub fn value_of(&self, label: PriorityLabel) -> i32 {
+        self.values[label.index()]
+    }
+
+    pub fn label_for(&self, priority: i32) -> PriorityLabel {
+        PriorityLabel::ALL
+            .into_iter()
+            .rev()
+            .find(|label| self.value_of(*label) <= priority)
+            .unwrap_or(PriorityLabel::Critical)
+    }
+
+    /// Every label must resolve to a priority the scale accepts
+    pub fn validate_for(&self, scale: &PriorityScale) -> Result<(), String> {
+        match PriorityLabel::ALL.into_iter().find(|label| !scale.contains(self.value_of(*label))) {
+            Some(label) => Err(format!(
+                "Priority label {} maps to {}, outside the priority scale {}-{}",
+                label.as_str(),
+                self.value_of(label),
+                scale.min(),
+                scale.max()
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for PriorityLabels {
+    fn default() -> Self {
+        Self::for_scale(&PriorityScale::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_labels_cover_full_scale() {
+        let labels = PriorityLabels::default();
+        assert_eq!(labels.value_of(PriorityLabel::Critical), 1);
+        assert_eq!(labels.value_of(PriorityLabel::High), 3);
+        assert_eq!(labels.value_of(PriorityLabel::Medium), 6);
+        assert_eq!(labels.value_of(PriorityLabel::Low), 8);
+
+        assert_eq!(labels.label_for(2), PriorityLabel::Critical);
+        assert_eq!(labels.label_for(5), PriorityLabel::High);
+        assert_eq!(labels.label_for(10), PriorityLabel::Low);
+    }
+
+    #[test]
+    fn test_labels_follow_narrow_scale() {
+        let scale = PriorityScale::new(1, 5).unwrap();
+        let labels = PriorityLabels::for_scale(&scale);
+
+        assert!(labels.validate_for(&scale).is_ok());
+        assert_eq!(labels.value_of(PriorityLabel::Low), 4);
+        assert_eq!(labels.label_for(5), PriorityLabel::Low);
+    }
+
+    #[test]
+    fn test_parse_mapping() {
+        let labels = PriorityLabels::parse("critical=1, High=2, Medium=3, Low=5").unwrap();
+        assert_eq!(labels.value_of(PriorityLabel::Medium), 3);
+
+        assert!(PriorityLabels::parse("Critical=1,High=2,Medium=3").is_err());
+        assert!(PriorityLabels::parse("Critical=4,High=2,Medium=3,Low=5").is_err());
+        assert!(PriorityLabels::parse("Urgent=1").is_err());
+    }
+
+    #[test]
+    fn test_validate_against_scale() {
+        let labels = PriorityLabels::default();
+        let scale = PriorityScale::new(1, 5).unwrap();
+
+        assert_eq!(
+            labels.validate_for(&scale).unwrap_err(),
+            "Priority label Medium maps to 6, outside the priority scale 1-5"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{PriorityScale, TaskId};
+
+/// How a bulk reprioritization changes the priority of every matching task
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PriorityMapping {
+    /// Moves every priority by this much, stopping at the ends of the scale
+    Shift(i32),
+    /// Replaces each listed priority with its target; unlisted priorities stay as they are
+    Explicit(BTreeMap<i32, i32>),
+}
+
+impl PriorityMapping {
+    /// Explicit targets must lie on the scale; shifts are clamped onto it instead
+    pub fn validate(&self, scale: &PriorityScale) -> Result<(), DomainError> {
+        match self {
+            PriorityMapping::Shift(_) => Ok(()),
+            PriorityMapping::Explicit(targets) => targets.values().try_for_each(|&to| scale.validate(Some(to))),
+        }
+    }
+
+    /// The `(from, to)` priority pairs the mapping produces on the scale, without those it leaves unchanged
+    pub fn pairs(&self, scale: &PriorityScale) -> Vec<(i32, i32)> {
+        let pairs: Vec<(i32, i32)> = match self {
+            PriorityMapping::Shift(by) => (scale.min()..=scale.max())
+                .map(|from| (from, from.saturating_add(*by).clamp(scale.min(), scale.max())))
+                .collect(),
+            PriorityMapping::Explicit(targets) => targets.iter().map(|(&from, &to)| (from, to)).collect(),
+        };
+        pairs.into_iter().filter(|(from, to)| from != to).collect()
+    }
+}
+
+/// One task's priority as changed by a bulk reprioritization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriorityChange {
+    pub task_id: TaskId,
+    pub from: i32,
+    pub to: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_clamps_at_the_ends_of_the_scale() {
+        let scale = PriorityScale::new(1, 5).unwrap();
+
+        assert_eq!(PriorityMapping::Shift(1).pairs(&scale), vec![(1, 2), (2, 3), (3, 4), (4, 5)]);
+        assert_eq!(PriorityMapping::Shift(-3).pairs(&scale), vec![(2, 1), (3, 1), (4, 1), (5, 2)]);
+        assert!(PriorityMapping::Shift(0).pairs(&scale).is_empty());
+    }
+
+    #[test]
+    fn test_explicit_targets_must_lie_on_the_scale() {
+        let scale = PriorityScale::new(1, 5).unwrap();
+        let mapping = PriorityMapping::Explicit(BTreeMap::from([(3, 5), (4, 4)]));
+
+        assert_eq!(mapping.pairs(&scale), vec![(3, 5)]);
+        assert!(mapping.validate(&scale).is_ok());
+        assert!(PriorityMapping::Explicit(BTreeMap::from([(3, 9)])).validate(&scale).is_err());
+    }
+}
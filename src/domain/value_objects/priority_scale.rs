@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::DomainError;
+
+/// Lowest priority any workspace may use
+pub const MIN_PRIORITY: i32 = 1;
+/// Highest priority any workspace may use
+pub const MAX_PRIORITY: i32 = 10;
+
+/// The range of priorities a workspace accepts, e.g. 1–5 instead of the full 1–10
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriorityScale {
+    min: i32,
+    max: i32,
+}
+
+impl PriorityScale {
+    pub fn new(min: i32, max: i32) -> Result<Self, String> {
+        if min > max {
+            return Err(format!("Priority scale minimum {} is greater than maximum {}", min, max));
+        }
+        if min < MIN_PRIORITY || max > MAX_PRIORITY {
+            return Err(format!("Priority scale must stay within {} and {}", MIN_PRIORITY, MAX_PRIORITY));
+        }
+        Ok(Self { min, max })
+    }
+
+    /// The full 1–10 scale, which the Task entity enforces regardless of configuration
+    pub fn full() -> Self {
+        Self { min: MIN_PRIORITY, max: MAX_PRIORITY }
+    }
+
+    pub fn min(&self) -> i32 {
+        self.min
+    }
+
+    pub fn max(&self) -> i32 {
+        self.max
+    }
+
+    pub fn contains(&self, priority: i32) -> bool {
+        (self.min..=self.max).contains(&priority)
+    }
+
+    /// Accepts a missing priority or one inside the scale
+    pub fn validate(&self, priority: Option<i32>) -> Result<(), DomainError> {
+        match priority {
+            Some(priority) if !self.contains(priority) => {
+                Err(DomainError::PriorityOutOfRange { priority, min: self.min, max: self.max })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for PriorityScale {
+    fn default() -> Self {
+        Self::full()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_scale_is_full_range() {
+        let scale = PriorityScale::default();
+        assert_eq!((scale.min(), scale.max()), (1, 10));
+        assert!(scale.validate(Some(10)).is_ok());
+        assert!(scale.validate(None).is_ok());
+    }
+
+    #[test]
+    fn test_narrow_scale_rejects_outside_values() {
+        let scale = PriorityScale::new(1, 5).unwrap();
+        assert!(scale.validate(Some(5)).is_ok());
+        assert_eq!(scale.validate(Some(6)).unwrap_err(), DomainError::PriorityOutOfRange { priority: 6, min: 1, max: 5 });
+    }
+
+    #[test]
+    fn test_invalid_scales() {
+        assert!(PriorityScale::new(5, 1).is_err());
+        assert!(PriorityScale::new(0, 5).is_err());
+        assert!(PriorityScale::new(1, 11).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+/// Days a report covers when the caller gives no start
+pub const DEFAULT_REPORT_DAYS: i64 = 30;
+
+/// Longest period a report can cover
+pub const MAX_REPORT_DAYS: i64 = 366;
+
+/// The inclusive time range an analytics report covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportPeriod {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl ReportPeriod {
+    /// Reads the bounds a caller gave as RFC 3339 timestamps or `YYYY-MM-DD` dates in UTC.
+    ///
+    /// A date starts the period at its first instant and ends it at its last, so
+    /// `start=2026-01-01&end=2026-01-31` covers all of January. The end defaults to `now` and the
+    /// start to [`DEFAULT_REPORT_DAYS`] before the end.
+    pub fn parse(start: Option<&str>, end: Option<&str>, now: DateTime<Utc>) -> Result<Self, String> {
+        Self::parse_with_default(start, end, now, DEFAULT_REPORT_DAYS)
+    }
+
+    /// Like [`ReportPeriod::parse`], but a missing start lies `default_days` before the end
+    pub fn parse_with_default(start: Option<&str>, end: Option<&str>, now: DateTime<Utc>, default_days: i64) -> Result<Self, String> {
+        let end = match end {
+            Some(value) => parse_bound("end", value, true)?,
+            None => now,
+        };
+        let start = match start {
+            Some(value) => parse_bound("start", value, false)?,
+            None => end - Duration::days(default_days),
+        };
+        if start > end {
+            return Err(format!("start ({}) must not be after end ({})", start.to_rfc3339(), end.to_rfc3339()));
+        }
+        if end - start > Duration::days(MAX_REPORT_DAYS) {
+            return Err(format!("The period can span at most {} days", MAX_REPORT_DAYS));
+        }
+        Ok(Self { start, end })
+    }
+}
+
+fn parse_bound(name: &str, value: &str, end_of_day: bool) -> Result<DateTime<Utc>, String> {
+    let value = value.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let midnight = date.and_hms_opt(0, 0, 0).expect("midnight exists").and_utc();
+        return Ok(match end_of_day {
+            true => midnight + Duration::days(1) - Duration::microseconds(1),
+            false => midnight,
+        });
+    }
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|_| format!("{} must be an RFC 3339 timestamp or a YYYY-MM-DD date, got '{}'", name, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_defaults_to_the_last_thirty_days() {
+        let period = ReportPeriod::parse(None, None, now()).unwrap();
+        assert_eq!((period.start, period.end), (now() - Duration::days(30), now()));
+
+        let period = ReportPeriod::parse(None, Some("2026-01-31"), now()).unwrap();
+        assert_eq!(period.start, Utc.with_ymd_and_hms(2026, 1, 1, 23, 59, 59).unwrap() + Duration::microseconds(999_999));
+
+        let period = ReportPeriod::parse_with_default(None, None, now(), 90).unwrap();
+        assert_eq!(period.start, now() - Duration::days(90));
+    }
+
+    #[test]
+    fn test_parse_reads_dates_as_whole_days_and_timestamps_in_any_offset() {
+        let period = ReportPeriod::parse(Some("2026-01-01"), Some("2026-01-31"), now()).unwrap();
+        assert_eq!(period.start.to_rfc3339(), "2026-01-01T00:00:00+00:00");
+        assert_eq!(period.end.to_rfc3339(), "2026-01-31T23:59:59.999999+00:00");
+
+        let period = ReportPeriod::parse(Some("2026-01-31T10:00:00+02:00"), Some(" 2026-02-01T00:00:00Z "), now()).unwrap();
+        assert_eq!(period.start.to_rfc3339(), "2026-01-31T08:00:00+00:00");
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_reversed_and_overlong_periods() {
+        let error = ReportPeriod::parse(Some("31/01/2026"), None, now()).unwrap_err();
+        assert_eq!(error, "start must be an RFC 3339 timestamp or a YYYY-MM-DD date, got '31/01/2026'");
+        assert!(ReportPeriod::parse(None, Some("2026-02-30"), now()).is_err());
+        assert!(ReportPeriod::parse(Some("2026-02-01"), Some("2026-01-31"), now()).unwrap_err().contains("must not be after"));
+        assert!(ReportPeriod::parse(Some("2024-01-01"), Some("2026-01-01"), now()).is_err());
+        assert!(ReportPeriod::parse(Some("2025-01-01"), Some("2025-12-31"), now()).is_ok());
+        assert!(ReportPeriod::parse(Some("2026-01-31"), Some("2026-01-31"), now()).is_ok());
+    }
+}
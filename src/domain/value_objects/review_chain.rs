@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+use serde::Deserialize;
+use crate::domain::{DomainError, StatusHistory, TaskStatus, UserRole};
+
+/// One sign-off in a review chain and the least senior role allowed to give it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewStage {
+    pub name: String,
+    pub role: UserRole,
+}
+
+impl ReviewStage {
+    /// Managers may sign off stages meant for users, and admins any stage
+    pub fn accepts(&self, role: &UserRole) -> bool {
+        role.is_at_least(&self.role)
+    }
+}
+
+/// The sign-offs a task in PendingReview collects, in order, before it is completed.
+///
+/// Every stage but the last keeps the task in PendingReview; the last one completes it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewChain {
+    stages: Vec<ReviewStage>,
+}
+
+impl Default for ReviewChain {
+    /// A single approval by a manager
+    fn default() -> Self {
+        Self { stages: vec![ReviewStage { name: "approval".to_string(), role: UserRole::Manager }] }
+    }
+}
+
+impl ReviewChain {
+    /// The last stage completes the task, so it must belong to a role that can approve
+    pub fn new(stages: Vec<ReviewStage>) -> Result<Self, String> {
+        let Some(last) = stages.last() else {
+            return Err("A review chain needs at least one stage".to_string());
+        };
+        if !last.role.can_approve() {
+            return Err(format!("The last review stage '{}' must be signed off by a Manager or Admin", last.name));
+        }
+        for (index, stage) in stages.iter().enumerate() {
+            if stage.name.is_empty() {
+                return Err("Review stages need a name".to_string());
+            }
+            if stage.role == UserRole::System {
+                return Err(format!("Review stage '{}' cannot be signed off by the System role", stage.name));
+            }
+            if stages[..index].iter().any(|earlier| earlier.name == stage.name) {
+                return Err(format!("Review stage '{}' is listed twice", stage.name));
+            }
+        }
+        Ok(Self { stages })
+    }
+
+    /// Parses `name:Role` stages separated by `,`, such as `peer:User,approval:Manager`
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let stages = value
+            .split(',')
+            .map(|stage| {
+                let (name, role) = stage.split_once(':')
+                    .ok_or_else(|| format!("Review stages must look like name:Role, got '{}'", stage.trim()))?;
+                Ok(ReviewStage { name: name.trim().to_string(), role: UserRole::from_str(role.trim())? })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Self::new(stages)
+    }
+
+    pub fn stages(&self) -> &[ReviewStage] {
+        &self.stages
+    }
+
+    /// Who has signed off the current review round so far, read from the task's history in order.
+    ///
+    /// A round starts each time the task enters PendingReview from another status.
+    pub fn progress(&self, history: &[StatusHistory]) -> ReviewProgress<'_> {
+        let mut signed_off_by = Vec::new();
+        for entry in history {
+            match (&entry.from_status, &entry.to_status, &entry.review_stage) {
+                (Some(TaskStatus::PendingReview), TaskStatus::PendingReview, Some(_)) => signed_off_by.push(entry.changed_by.clone()),
+                (_, TaskStatus::PendingReview, _) => signed_off_by.clear(),
+                _ => {}
+            }
+        }
+        signed_off_by.truncate(self.stages.len() - 1);
+        ReviewProgress { chain: self, signed_off_by }
+    }
+}
+
+/// How far a task in PendingReview has come through its review chain
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewProgress<'a> {
+    chain: &'a ReviewChain,
+    signed_off_by: Vec<String>,
+}
+
+impl ReviewProgress<'_> {
+    /// The stage waiting for a sign-off
+    pub fn current_stage(&self) -> &ReviewStage {
+        &self.chain.stages[self.signed_off_by.len()]
+    }
+
+    /// 1-based position of the current stage
+    pub fn stage_number(&self) -> usize {
+        self.signed_off_by.len() + 1
+    }
+
+    pub fn stage_count(&self) -> usize {
+        self.chain.stages.len()
+    }
+
+    /// Signing off the final stage completes the task
+    pub fn is_final(&self) -> bool {
+        self.stage_number() == self.stage_count()
+    }
+
+    /// Reviewers who signed off the earlier stages of this round, in order
+    pub fn signed_off_by(&self) -> &[String] {
+        &self.signed_off_by
+    }
+
+    /// Each stage needs a reviewer of its role who has not signed off an earlier stage of this round
+    pub fn check_sign_off(&self, reviewer: &str, role: &UserRole) -> Result<(), DomainError> {
+        let stage = self.current_stage();
+        if !stage.accepts(role) {
+            return Err(DomainError::ReviewStageNotAllowed { stage: stage.name.clone(), role: stage.role.clone() });
+        }
+        if self.signed_off_by.iter().any(|signer| signer == reviewer) {
+            return Err(DomainError::ReviewerAlreadySignedOff { stage: stage.name.clone() });
+        }
+        Ok(())
+    }
+}
+
+/// Review chains by workspace, for workspaces that need more than the default approval
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct ReviewChains {
+    fallback: ReviewChain,
+    workspaces: BTreeMap<String, ReviewChain>,
+}
+
+impl ReviewChains {
+    /// Parses `workspace=chain` entries separated by `;`, where `*` names every other workspace,
+    /// such as `acme=peer:User,approval:Manager;*=approval:Manager`
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut chains = Self::default();
+        for entry in value.split(';').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (workspace, chain) = entry.split_once('=')
+                .ok_or_else(|| format!("Review chains must look like workspace=stages, got '{}'", entry))?;
+            let chain = ReviewChain::parse(chain)?;
+            match workspace.trim() {
+                "*" => chains.fallback = chain,
+                "" => return Err(format!("Review chain '{}' names no workspace", entry)),
+                workspace => {
+                    chains.workspaces.insert(workspace.to_string(), chain);
+                }
+            }
+        }
+        Ok(chains)
+    }
+
+    pub fn for_workspace(&self, workspace: &str) -> &ReviewChain {
+        self.workspaces.get(workspace).unwrap_or(&self.fallback)
+    }
+}
+
+impl TryFrom<String> for ReviewChains {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn entry(from: TaskStatus, to: TaskStatus, by: &str, stage: Option<&str>) -> StatusHistory {
+        StatusHistory::new("id".to_string(), 1, Some(from), to, Utc::now(), by.to_string(), None, UserRole::User)
+            .with_review_stage(stage.map(str::to_string))
+    }
+
+    #[test]
+    fn test_progress_counts_sign_offs_since_the_task_last_entered_review() {
+        let chain = ReviewChain::parse("peer:User,lead:Manager,approval:Admin").unwrap();
+        let history = vec![
+            entry(TaskStatus::InProgress, TaskStatus::PendingReview, "dev", None),
+            entry(TaskStatus::PendingReview, TaskStatus::PendingReview, "alice", Some("peer")),
+            entry(TaskStatus::PendingReview, TaskStatus::InProgress, "bob", None),
+            entry(TaskStatus::InProgress, TaskStatus::PendingReview, "dev", None),
+            entry(TaskStatus::PendingReview, TaskStatus::PendingReview, "carol", Some("peer")),
+        ];
+
+        let progress = chain.progress(&history);
+        assert_eq!(progress.current_stage().name, "lead");
+        assert_eq!(progress.stage_number(), 2);
+        assert!(!progress.is_final());
+        assert_eq!(progress.signed_off_by(), ["carol".to_string()]);
+    }
+
+    #[test]
+    fn test_sign_off_needs_the_stage_role_and_a_new_reviewer() {
+        let chain = ReviewChain::parse("peer:User,approval:Manager").unwrap();
+        let history = vec![entry(TaskStatus::PendingReview, TaskStatus::PendingReview, "alice", Some("peer"))];
+        let progress = chain.progress(&history);
+
+        assert!(progress.is_final());
+        assert!(matches!(progress.check_sign_off("bob", &UserRole::User), Err(DomainError::ReviewStageNotAllowed { .. })));
+        assert!(matches!(progress.check_sign_off("alice", &UserRole::Manager), Err(DomainError::ReviewerAlreadySignedOff { .. })));
+        assert!(progress.check_sign_off("bob", &UserRole::Admin).is_ok());
+        assert!(chain.progress(&[]).check_sign_off("alice", &UserRole::User).is_ok());
+    }
+
+    #[test]
+    fn test_chains_are_chosen_by_workspace() {
+        let chains = ReviewChains::parse("acme=peer:User, approval:Manager; *=approval:Admin").unwrap();
+
+        assert_eq!(chains.for_workspace("acme").stages().len(), 2);
+        assert_eq!(chains.for_workspace("default").stages()[0].role, UserRole::Admin);
+        assert_eq!(ReviewChains::default().for_workspace("acme"), &ReviewChain::default());
+    }
+
+    #[test]
+    fn test_malformed_chains_are_rejected() {
+        for value in ["acme", "=approval:Manager", "acme=approval", "acme=peer:User", "acme=a:User,a:Manager", "acme=approval:Owner", "acme=bot:System,approval:Manager"] {
+            assert!(ReviewChains::parse(value).is_err(), "{} should be rejected", value);
+        }
+    }
+}
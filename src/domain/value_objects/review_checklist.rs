@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::DomainError;
+
+/// A checklist item as filled in by the reviewer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub item: String,
+    pub checked: bool,
+}
+
+/// Items a reviewer must tick before approving a task in PendingReview
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReviewChecklist {
+    items: Vec<String>,
+}
+
+impl ReviewChecklist {
+    pub fn new(items: Vec<String>) -> Self {
+        Self {
+            items: items
+                .into_iter()
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Parses a `;`-separated list such as `Tests pass;Docs updated`
+    pub fn parse(s: &str) -> Self {
+        Self::new(s.split(';').map(str::to_string).collect())
+    }
+
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Every template item must be present and checked; unknown items are rejected
+    pub fn validate(&self, answers: Option<&[ChecklistItem]>) -> Result<(), DomainError> {
+        if self.items.is_empty() {
+            return Ok(());
+        }
+
+        let answers = answers.ok_or_else(|| DomainError::ChecklistRequired { items: self.items.clone() })?;
+
+        if let Some(unknown) = answers.iter().find(|answer| !self.items.contains(&answer.item)) {
+            return Err(DomainError::UnknownChecklistItem { item: unknown.item.clone() });
+        }
+
+        for item in &self.items {
+            let checked = answers.iter().any(|answer| &answer.item == item && answer.checked);
+            if !checked {
+                return Err(DomainError::ChecklistItemNotCompleted { item: item.clone() });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(item: &str, checked: bool) -> ChecklistItem {
+        ChecklistItem { item: item.to_string(), checked }
+    }
+
+    #[test]
+    fn test_empty_checklist_accepts_anything() {
+        assert!(ReviewChecklist::default().validate(None).is_ok());
+    }
+
+    #[test]
+    fn test_parse_ignores_blank_items() {
+        let checklist = ReviewChecklist::parse(" Tests pass ;; Docs updated;");
+        assert_eq!(checklist.items(), ["Tests pass", "Docs updated"]);
+    }
+
+    #[test]
+    fn test_validate_requires_every_item_checked() {
+        let checklist = ReviewChecklist::parse("Tests pass;Docs updated");
+
+        assert!(checklist.validate(None).is_err());
+        assert_eq!(
+            checklist.validate(Some(&[answer("Tests pass", true), answer("Docs updated", false)])).unwrap_err(),
+            DomainError::ChecklistItemNotCompleted { item: "Docs updated".to_string() }
+        );
+        assert_eq!(
+            checklist.validate(Some(&[answer("Tests pass", true), answer("Shipped", true)])).unwrap_err(),
+            DomainError::UnknownChecklistItem { item: "Shipped".to_string() }
+        );
+        assert!(checklist.validate(Some(&[answer("Docs updated", true), answer("Tests pass", true)])).is_ok());
+    }
+}
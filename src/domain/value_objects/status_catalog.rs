@@ -0,0 +1,64 @@
+use crate::domain::entities::CustomStatus;
+use crate::domain::value_objects::{StatusCategory, TaskStatus, WorkflowDefinition};
+
+/// The statuses tasks may be in: the built-in ones plus those admins defined
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatusCatalog {
+    custom: Vec<CustomStatus>,
+}
+
+impl StatusCatalog {
+    pub fn new(custom: Vec<CustomStatus>) -> Self {
+        Self { custom }
+    }
+
+    pub fn custom(&self) -> &[CustomStatus] {
+        &self.custom
+    }
+
+    /// `None` for a custom status nobody defined, or whose definition was removed
+    pub fn category(&self, status: &TaskStatus) -> Option<StatusCategory> {
+        status.builtin_category().or_else(|| {
+            self.custom.iter().find(|custom| &custom.status == status).map(|custom| custom.category)
+        })
+    }
+
+    pub fn contains(&self, status: &TaskStatus) -> bool {
+        self.category(status).is_some()
+    }
+
+    /// Whether a task in `status` no longer holds up the tasks that depend on it
+    pub fn is_finished(&self, status: &TaskStatus) -> bool {
+        status.is_finished() || (status.is_custom() && self.category(status) == Some(StatusCategory::Done))
+    }
+
+    /// Rejects a workflow naming custom statuses that are not defined
+    pub fn check_workflow(&self, workflow: &WorkflowDefinition) -> Result<(), String> {
+        let unknown: Vec<&str> = workflow.states().iter().filter(|status| !self.contains(status)).map(TaskStatus::as_str).collect();
+        if !unknown.is_empty() {
+            return Err(format!("Unknown task statuses in workflow: {}", unknown.join(", ")));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_custom_statuses_finish_tasks_by_category() {
+        let custom = |name: &str, category| CustomStatus::new(name, category, "ann".to_string(), Utc::now()).unwrap();
+        let catalog = StatusCatalog::new(vec![custom("Blocked", StatusCategory::Open), custom("Shipped", StatusCategory::Done)]);
+        let status = |name: &str| TaskStatus::parse(name).unwrap();
+
+        assert_eq!(catalog.category(&status("Blocked")), Some(StatusCategory::Open));
+        assert_eq!(catalog.category(&TaskStatus::PendingReview), Some(StatusCategory::InProgress));
+        assert_eq!(catalog.category(&status("Archived")), None);
+        assert!(catalog.is_finished(&status("Shipped")));
+        assert!(catalog.is_finished(&TaskStatus::Cancelled));
+        assert!(!catalog.is_finished(&status("Blocked")));
+        assert!(!catalog.is_finished(&status("Archived")));
+    }
+}
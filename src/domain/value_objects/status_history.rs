@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use crate::domain::{TaskStatus, UserRole};
+use crate::domain::{ChecklistItem, TaskStatus, UserRole};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StatusHistory {
@@ -12,9 +12,15 @@ pub struct StatusHistory {
     pub changed_by: String,
     pub comment: Option<String>,
     pub user_role: UserRole,
+    /// Review checklist filled in when approving a task
+    pub checklist: Option<Vec<ChecklistItem>>,
+    /// Review stage signed off by this entry, for sign-offs that keep the task in PendingReview
+    #[serde(default)]
+    pub review_stage: Option<String>,
 }
 
 impl StatusHistory {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: String,
         task_id: i32,
@@ -34,9 +40,26 @@ impl StatusHistory {
             changed_by,
             comment,
             user_role,
+            checklist: None,
+            review_stage: None,
         }
     }
 
+    pub fn with_comment(mut self, comment: Option<String>) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    pub fn with_checklist(mut self, checklist: Option<Vec<ChecklistItem>>) -> Self {
+        self.checklist = checklist;
+        self
+    }
+
+    pub fn with_review_stage(mut self, review_stage: Option<String>) -> Self {
+        self.review_stage = review_stage;
+        self
+    }
+
     pub fn is_initial_creation(&self) -> bool {
         self.from_status.is_none()
     }
@@ -56,6 +79,19 @@ impl StatusHistory {
         )
     }
 
+    /// An entry that keeps the task in its status, such as an escalation or an earlier review
+    /// stage's sign-off; it records something about the task without being a transition
+    pub fn is_self_transition(&self) -> bool {
+        self.from_status.as_ref() == Some(&self.to_status)
+    }
+
+    pub fn is_reopening(&self) -> bool {
+        matches!(
+            (&self.from_status, &self.to_status),
+            (Some(TaskStatus::Completed), TaskStatus::InProgress)
+        )
+    }
+
     pub fn duration_from_previous(&self, previous: &StatusHistory) -> Option<chrono::Duration> {
         if previous.task_id == self.task_id && previous.changed_at <= self.changed_at {
             Some(self.changed_at - previous.changed_at)
@@ -75,13 +111,19 @@ pub struct TaskAnalytics {
     pub approval_time: Option<chrono::Duration>,
     pub created_at: DateTime<Utc>,
     pub completed_at: Option<DateTime<Utc>>,
+    pub times_reopened: usize,
+    /// Time between reopening and completing again; kept out of the completion metrics
+    pub rework_time: Option<chrono::Duration>,
 }
 
 impl TaskAnalytics {
+    /// Entries that keep the task in its status are skipped, so they neither restart a clock nor
+    /// count as transitions
     pub fn from_history(history: Vec<StatusHistory>) -> Option<Self> {
         if history.is_empty() {
             return None;
         }
+        let history: Vec<StatusHistory> = history.into_iter().filter(|entry| !entry.is_self_transition()).collect();
 
         let task_id = history[0].task_id;
         let creation_entry = history.iter().find(|h| h.is_initial_creation())?;
@@ -93,7 +135,7 @@ impl TaskAnalytics {
         let mut approval_time = None;
         let mut completed_at = None;
 
-        // Calculate time spent in InProgress status
+        // Completion metrics describe the original completion; see rework below
         let mut in_progress_start: Option<DateTime<Utc>> = None;
         let mut pending_review_start: Option<DateTime<Utc>> = None;
 
@@ -104,13 +146,13 @@ impl TaskAnalytics {
                 }
                 TaskStatus::PendingReview => {
                     if let Some(start) = in_progress_start {
-                        total_time_in_progress = total_time_in_progress + (entry.changed_at - start);
+                        total_time_in_progress += entry.changed_at - start;
                     }
                     pending_review_start = Some(entry.changed_at);
                 }
                 TaskStatus::Completed => {
                     if let Some(start) = in_progress_start {
-                        total_time_in_progress = total_time_in_progress + (entry.changed_at - start);
+                        total_time_in_progress += entry.changed_at - start;
                     }
                     
                     if entry.is_approval() {
@@ -132,6 +174,24 @@ impl TaskAnalytics {
             }
         }
 
+        let mut times_reopened = 0;
+        let mut rework_time = chrono::Duration::zero();
+        let mut reopened_at: Option<DateTime<Utc>> = None;
+
+        let original_end = history.iter().position(|h| h.is_completion() || h.is_cancellation());
+        if let Some(end) = original_end {
+            for entry in &history[end + 1..] {
+                if entry.is_reopening() {
+                    times_reopened += 1;
+                    reopened_at = Some(entry.changed_at);
+                } else if entry.is_completion() || entry.is_cancellation() {
+                    if let Some(start) = reopened_at.take() {
+                        rework_time += entry.changed_at - start;
+                    }
+                }
+            }
+        }
+
         Some(TaskAnalytics {
             task_id,
             total_time_in_progress: if total_time_in_progress.is_zero() { None } else { Some(total_time_in_progress) },
@@ -141,6 +201,107 @@ impl TaskAnalytics {
             approval_time,
             created_at,
             completed_at,
+            times_reopened,
+            rework_time: if rework_time.is_zero() { None } else { Some(rework_time) },
         })
     }
-}
\ No newline at end of file
+}
+/// Distribution of time from creation to first completion for one priority level
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleTimeStats {
+    pub priority: i32,
+    pub average: chrono::Duration,
+    pub p50: chrono::Duration,
+    pub p85: chrono::Duration,
+    pub p95: chrono::Duration,
+}
+
+/// Tasks of one priority level first completed in a period, with their average time from creation
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriorityCompletion {
+    pub priority: i32,
+    pub task_count: usize,
+    pub average: chrono::Duration,
+}
+
+/// Aggregate of every `from_status -> to_status` change in a period
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitionEdge {
+    pub from_status: TaskStatus,
+    pub to_status: TaskStatus,
+    pub count: usize,
+    /// Median time spent in `from_status` before leaving for `to_status`
+    pub median_dwell_time: Option<chrono::Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn entry(from: Option<TaskStatus>, to: TaskStatus, changed_at: DateTime<Utc>) -> StatusHistory {
+        StatusHistory::new("id".to_string(), 1, from, to, changed_at, "someone".to_string(), None, UserRole::Manager)
+    }
+
+    #[test]
+    fn test_reopened_time_is_kept_out_of_completion_metrics() {
+        let start = Utc::now() - Duration::days(10);
+        let history = vec![
+            entry(None, TaskStatus::Pending, start),
+            entry(Some(TaskStatus::Pending), TaskStatus::InProgress, start + Duration::hours(1)),
+            entry(Some(TaskStatus::InProgress), TaskStatus::Completed, start + Duration::hours(3)),
+            entry(Some(TaskStatus::Completed), TaskStatus::InProgress, start + Duration::days(1)),
+            entry(Some(TaskStatus::InProgress), TaskStatus::Completed, start + Duration::days(1) + Duration::hours(5)),
+        ];
+
+        let analytics = TaskAnalytics::from_history(history).unwrap();
+
+        assert_eq!(analytics.time_to_completion, Some(Duration::hours(3)));
+        assert_eq!(analytics.total_time_in_progress, Some(Duration::hours(2)));
+        assert_eq!(analytics.completed_at, Some(start + Duration::hours(3)));
+        assert_eq!(analytics.times_reopened, 1);
+        assert_eq!(analytics.rework_time, Some(Duration::hours(5)));
+        assert_eq!(analytics.number_of_transitions, 5);
+    }
+
+    #[test]
+    fn test_escalation_entries_do_not_restart_the_time_in_progress() {
+        let start = Utc::now() - Duration::days(10);
+        let history = vec![
+            entry(None, TaskStatus::Pending, start),
+            entry(Some(TaskStatus::Pending), TaskStatus::InProgress, start + Duration::hours(1)),
+            entry(Some(TaskStatus::InProgress), TaskStatus::InProgress, start + Duration::hours(4)),
+            entry(Some(TaskStatus::InProgress), TaskStatus::Completed, start + Duration::hours(6)),
+        ];
+
+        let analytics = TaskAnalytics::from_history(history).unwrap();
+
+        assert_eq!(analytics.total_time_in_progress, Some(Duration::hours(5)));
+        assert_eq!(analytics.number_of_transitions, 3);
+    }
+
+    #[test]
+    fn test_escalations_in_review_keep_the_approval_time_and_time_in_progress() {
+        let start = Utc::now() - Duration::days(10);
+        let history = vec![
+            entry(None, TaskStatus::Pending, start),
+            entry(Some(TaskStatus::Pending), TaskStatus::InProgress, start + Duration::hours(1)),
+            entry(Some(TaskStatus::InProgress), TaskStatus::PendingReview, start + Duration::hours(3)),
+            entry(Some(TaskStatus::PendingReview), TaskStatus::PendingReview, start + Duration::hours(51)),
+            entry(Some(TaskStatus::PendingReview), TaskStatus::Completed, start + Duration::hours(53)),
+        ];
+
+        let analytics = TaskAnalytics::from_history(history).unwrap();
+
+        assert_eq!(analytics.approval_time, Some(Duration::hours(50)));
+        assert_eq!(analytics.number_of_transitions, 4);
+        assert!(analytics.was_approved);
+        let without_escalation = TaskAnalytics::from_history(vec![
+            entry(None, TaskStatus::Pending, start),
+            entry(Some(TaskStatus::Pending), TaskStatus::InProgress, start + Duration::hours(1)),
+            entry(Some(TaskStatus::InProgress), TaskStatus::PendingReview, start + Duration::hours(3)),
+            entry(Some(TaskStatus::PendingReview), TaskStatus::Completed, start + Duration::hours(53)),
+        ]).unwrap();
+        assert_eq!(analytics.total_time_in_progress, without_escalation.total_time_in_progress);
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use serde::Deserialize;
+use crate::domain::entities::Task;
+use crate::domain::value_objects::TaskStatus;
+
+/// Most cards a board column shows when the caller does not say
+pub const DEFAULT_BOARD_COLUMN_SIZE: usize = 50;
+
+/// Most cards a board column can show
+pub const MAX_BOARD_COLUMN_SIZE: usize = 200;
+
+/// The tasks in one status, as a board column shows them
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardColumn {
+    pub status: TaskStatus,
+    /// Every task in the status, including those beyond the shown cards
+    pub count: usize,
+    /// The first cards in the requested order
+    pub tasks: Vec<Task>,
+}
+
+impl BoardColumn {
+    /// Builds columns from cards that each carry their column's full count, as board queries
+    /// return them, keeping the order in which each status first appears
+    pub fn from_cards(cards: impl IntoIterator<Item = (Task, usize)>) -> Vec<Self> {
+        let mut columns: Vec<Self> = Vec::new();
+        for (task, count) in cards {
+            match columns.iter_mut().find(|column| column.status == task.status) {
+                Some(column) => column.tasks.push(task),
+                None => columns.push(Self { status: task.status.clone(), count, tasks: vec![task] }),
+            }
+        }
+        columns
+    }
+
+    /// Groups tasks that are already sorted into columns of at most `size` cards, in order of
+    /// each status's first task, for repositories that hold their tasks in memory
+    pub fn group(tasks: Vec<Task>, size: usize) -> Vec<Self> {
+        let mut columns: Vec<Self> = Vec::new();
+        for task in tasks {
+            let column = match columns.iter().position(|column| column.status == task.status) {
+                Some(index) => &mut columns[index],
+                None => {
+                    columns.push(Self { status: task.status.clone(), count: 0, tasks: Vec::new() });
+                    columns.last_mut().expect("a column was just pushed")
+                }
+            };
+            column.count += 1;
+            if column.tasks.len() < size {
+                column.tasks.push(task);
+            }
+        }
+        columns
+    }
+}
+
+/// How many tasks each workspace wants in a status at once, to keep work in progress down
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct WipLimits {
+    fallback: BTreeMap<String, usize>,
+    workspaces: BTreeMap<String, BTreeMap<String, usize>>,
+}
+
+impl WipLimits {
+    /// Parses `workspace=Status:limit,...` entries separated by `;`, where `*` names every other
+    /// workspace, such as `acme=InProgress:3,QA:2;*=InProgress:5`
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut limits = Self::default();
+        for entry in value.split(';').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (workspace, statuses) = entry.split_once('=')
+                .ok_or_else(|| format!("WIP limits must look like workspace=Status:limit, got '{}'", entry))?;
+            let mut by_status = BTreeMap::new();
+            for limit in statuses.split(',').map(str::trim) {
+                let (status, count) = limit.split_once(':')
+                    .ok_or_else(|| format!("WIP limit '{}' must look like Status:limit", limit))?;
+                let status = TaskStatus::parse(status.trim())?;
+                let count = count.trim().parse().ok().filter(|count| *count > 0)
+                    .ok_or_else(|| format!("WIP limit of {} must be a positive number, got '{}'", status.as_str(), count.trim()))?;
+                by_status.insert(status.as_str().to_string(), count);
+            }
+            match workspace.trim() {
+                "*" => limits.fallback = by_status,
+                "" => return Err(format!("WIP limits '{}' name no workspace", entry)),
+                workspace => {
+                    limits.workspaces.insert(workspace.to_string(), by_status);
+                }
+            }
+        }
+        Ok(limits)
+    }
+
+    /// The workspace's limit for the status; a workspace with limits of its own ignores `*`
+    pub fn limit(&self, workspace: &str, status: &TaskStatus) -> Option<usize> {
+        self.workspaces.get(workspace).unwrap_or(&self.fallback).get(status.as_str()).copied()
+    }
+}
+
+impl TryFrom<String> for WipLimits {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TaskId;
+
+    #[test]
+    fn test_group_counts_every_task_but_keeps_the_first_cards() {
+        let task = |id: i32, status: TaskStatus| {
+            let now = chrono::Utc::now();
+            Task::new_with_status(TaskId::new(id), format!("Task {}", id), Some(3), status, now, now).unwrap()
+        };
+        let columns = BoardColumn::group(vec![
+            task(1, TaskStatus::InProgress),
+            task(2, TaskStatus::Pending),
+            task(3, TaskStatus::InProgress),
+            task(4, TaskStatus::InProgress),
+        ], 2);
+
+        let summary: Vec<_> = columns.iter()
+            .map(|column| (column.status.as_str(), column.count, column.tasks.iter().map(|task| task.id.value()).collect::<Vec<_>>()))
+            .collect();
+        assert_eq!(summary, vec![("InProgress", 3, vec![1, 3]), ("Pending", 1, vec![2])]);
+    }
+
+    #[test]
+    fn test_workspace_limits_replace_the_fallback() {
+        let limits = WipLimits::parse("acme=QA:2; *=InProgress:5,PendingReview:3").unwrap();
+
+        assert_eq!(limits.limit("acme", &TaskStatus::parse("QA").unwrap()), Some(2));
+        assert_eq!(limits.limit("acme", &TaskStatus::InProgress), None);
+        assert_eq!(limits.limit("other", &TaskStatus::InProgress), Some(5));
+        for invalid in ["InProgress:3", "acme=InProgress", "acme=InProgress:0", "=InProgress:2", "acme=in progress:2"] {
+            assert!(WipLimits::parse(invalid).is_err(), "{:?}", invalid);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::domain::value_objects::{FilterExpression, TaskStatus};
+
+/// Which tasks a listing should return; all set criteria must match
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskFilter {
+    pub priority: Option<i32>,
+    pub status: Option<TaskStatus>,
+    /// Also return soft-deleted tasks
+    pub include_deleted: bool,
+    /// Return archived tasks instead of the active ones
+    pub archived: bool,
+    /// A parsed `?filter=` expression the tasks must also match
+    pub expression: Option<FilterExpression>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskSortField {
+    #[default]
+    Id,
+    Priority,
+    CreatedAt,
+    UpdatedAt,
+    Name,
+    Status,
+    /// The order users placed tasks in by hand
+    Rank,
+}
+
+impl TaskSortField {
+    pub const SORTABLE: [&'static str; 6] = ["priority", "created_at", "updated_at", "name", "status", "rank"];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskSortField::Id => "id",
+            TaskSortField::Priority => "priority",
+            TaskSortField::CreatedAt => "created_at",
+            TaskSortField::UpdatedAt => "updated_at",
+            TaskSortField::Name => "name",
+            TaskSortField::Status => "status",
+            TaskSortField::Rank => "rank",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "id" => Ok(TaskSortField::Id),
+            "priority" => Ok(TaskSortField::Priority),
+            "created_at" => Ok(TaskSortField::CreatedAt),
+            "updated_at" => Ok(TaskSortField::UpdatedAt),
+            "name" => Ok(TaskSortField::Name),
+            "status" => Ok(TaskSortField::Status),
+            "rank" => Ok(TaskSortField::Rank),
+            _ => Err(format!(
+                "Invalid sort field: {} (expected one of {})",
+                s,
+                Self::SORTABLE.join(", ")
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "asc" => Ok(SortOrder::Asc),
+            "desc" => Ok(SortOrder::Desc),
+            _ => Err(format!("Invalid sort order: {} (expected asc or desc)", s)),
+        }
+    }
+}
+
+/// Ordering of a task listing; ties are always broken by task id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TaskSort {
+    pub field: TaskSortField,
+    pub order: SortOrder,
+}
+
+impl TaskSort {
+    pub fn new(field: TaskSortField, order: SortOrder) -> Self {
+        Self { field, order }
+    }
+
+    /// Parses optional `sort_by`/`order` query values, defaulting to ascending id
+    pub fn parse(sort_by: Option<&str>, order: Option<&str>) -> Result<Self, String> {
+        Ok(Self {
+            field: sort_by.map(TaskSortField::from_str).transpose()?.unwrap_or_default(),
+            order: order.map(SortOrder::from_str).transpose()?.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_defaults_to_id_ascending() {
+        assert_eq!(TaskSort::parse(None, None).unwrap(), TaskSort::default());
+        assert_eq!(TaskSort::default().field, TaskSortField::Id);
+    }
+
+    #[test]
+    fn test_parse_field_and_order() {
+        let sort = TaskSort::parse(Some("created_at"), Some("desc")).unwrap();
+        assert_eq!(sort, TaskSort::new(TaskSortField::CreatedAt, SortOrder::Desc));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_values() {
+        assert_eq!(
+            TaskSort::parse(Some("owner"), None).unwrap_err(),
+            "Invalid sort field: owner (expected one of priority, created_at, updated_at, name, status, rank)"
+        );
+        assert!(TaskSort::parse(None, Some("sideways")).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::domain::entities::Task;
+use crate::domain::value_objects::TaskStatus;
+
+/// How many tasks share one value of a field
+#[derive(Debug, Clone, PartialEq)]
+pub struct FacetCount<T> {
+    pub value: T,
+    pub count: usize,
+}
+
+/// Counts of the tasks matching a filter, by status and by priority, for facet sidebars
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskFacets {
+    pub total: usize,
+    /// Only statuses some task has, in lifecycle order, then custom ones by name
+    pub statuses: Vec<FacetCount<TaskStatus>>,
+    /// Ascending priority, with tasks that have none counted last under `None`
+    pub priorities: Vec<FacetCount<Option<i32>>>,
+}
+
+impl TaskFacets {
+    /// Builds the facets from grouped counts in any order, as a `GROUP BY` returns them
+    pub fn from_counts(statuses: Vec<(TaskStatus, usize)>, priorities: Vec<(Option<i32>, usize)>) -> Self {
+        let total = statuses.iter().map(|(_, count)| count).sum();
+        let mut custom: Vec<TaskStatus> = statuses.iter().map(|(status, _)| status.clone()).filter(TaskStatus::is_custom).collect();
+        custom.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        custom.dedup();
+        let statuses = TaskStatus::ALL
+            .into_iter()
+            .chain(custom)
+            .filter_map(|status| {
+                let count = statuses.iter().filter(|(value, _)| value == &status).map(|(_, count)| count).sum();
+                (count > 0).then_some(FacetCount { value: status, count })
+            })
+            .collect();
+        let mut priorities: Vec<_> = priorities.into_iter().map(|(value, count)| FacetCount { value, count }).collect();
+        priorities.sort_by_key(|facet| (facet.value.is_none(), facet.value));
+        Self { total, statuses, priorities }
+    }
+
+    /// Counts already loaded tasks
+    pub fn of(tasks: &[Task]) -> Self {
+        let mut statuses: Vec<(TaskStatus, usize)> = Vec::new();
+        let mut priorities: Vec<(Option<i32>, usize)> = Vec::new();
+        for task in tasks {
+            match statuses.iter_mut().find(|(status, _)| status == &task.status) {
+                Some((_, count)) => *count += 1,
+                None => statuses.push((task.status.clone(), 1)),
+            }
+            match priorities.iter_mut().find(|(priority, _)| priority == &task.priority) {
+                Some((_, count)) => *count += 1,
+                None => priorities.push((task.priority, 1)),
+            }
+        }
+        Self::from_counts(statuses, priorities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TaskId;
+
+    #[test]
+    fn test_of_orders_statuses_by_lifecycle_and_unset_priority_last() {
+        let task = |id: i32, priority: Option<i32>, status: TaskStatus| {
+            let now = chrono::Utc::now();
+            Task::new_with_status(TaskId::new(id), format!("Task {}", id), priority, status, now, now).unwrap()
+        };
+        let facets = TaskFacets::of(&[
+            task(1, Some(3), TaskStatus::Completed),
+            task(2, None, TaskStatus::Pending),
+            task(3, Some(1), TaskStatus::Pending),
+            task(4, Some(3), TaskStatus::InProgress),
+            task(5, Some(3), TaskStatus::parse("QA").unwrap()),
+            task(6, Some(3), TaskStatus::parse("Blocked").unwrap()),
+        ]);
+
+        assert_eq!(facets.total, 6);
+        let statuses: Vec<_> = facets.statuses.iter().map(|facet| (facet.value.as_str(), facet.count)).collect();
+        assert_eq!(statuses, vec![("Pending", 2), ("InProgress", 1), ("Completed", 1), ("Blocked", 1), ("QA", 1)]);
+        let priorities: Vec<_> = facets.priorities.iter().map(|facet| (facet.value, facet.count)).collect();
+        assert_eq!(priorities, vec![(Some(1), 1), (Some(3), 4), (None, 1)]);
+    }
+}
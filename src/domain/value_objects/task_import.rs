@@ -0,0 +1,130 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use crate::domain::value_objects::{PriorityLabel, TaskStatus};
+
+/// Tool whose export file an import reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportSource {
+    /// Board JSON from Trello's "Export as JSON"
+    Trello,
+    /// Issue CSV from Jira's "Export CSV (all fields)"
+    Jira,
+}
+
+impl ImportSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportSource::Trello => "trello",
+            ImportSource::Jira => "jira",
+        }
+    }
+}
+
+/// A card or issue read from an export, before its status is mapped
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedTask {
+    /// Position of the card or issue in the export, counting from 1
+    pub row: usize,
+    /// The card id or issue key in the source tool
+    pub external_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// The list name or status in the source tool
+    pub source_status: String,
+    pub priority: Option<PriorityLabel>,
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the card or issue last changed, used as the time it reached its status
+    pub status_changed_at: Option<DateTime<Utc>>,
+}
+
+/// A card or issue an import left out, and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedRow {
+    pub row: usize,
+    pub external_id: Option<String>,
+    pub reason: String,
+}
+
+/// Everything read from one export file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedImport {
+    pub tasks: Vec<ImportedTask>,
+    /// Rows the parser could not read
+    pub skipped: Vec<SkippedRow>,
+}
+
+/// Maps list and status names from other tools to task statuses, ignoring case.
+///
+/// The default covers the usual Trello list names and Jira workflow statuses; status
+/// names of this API, such as `InProgress`, always map to themselves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusMapping {
+    names: BTreeMap<String, TaskStatus>,
+}
+
+impl StatusMapping {
+    /// A mapping with no names, so only this API's own status names are recognised
+    pub fn empty() -> Self {
+        Self { names: BTreeMap::new() }
+    }
+
+    /// Maps `name` to `status`, replacing any earlier mapping of the name
+    pub fn with(mut self, name: &str, status: TaskStatus) -> Self {
+        self.names.insert(normalize(name), status);
+        self
+    }
+
+    pub fn status_for(&self, name: &str) -> Option<TaskStatus> {
+        self.names
+            .get(&normalize(name))
+            .cloned()
+            .or_else(|| TaskStatus::from_str(name.trim()).ok())
+    }
+}
+
+impl Default for StatusMapping {
+    fn default() -> Self {
+        let defaults = [
+            (TaskStatus::Pending, &["to do", "todo", "backlog", "open", "new", "selected for development"][..]),
+            (TaskStatus::InProgress, &["doing", "in progress", "in development"][..]),
+            (TaskStatus::PendingReview, &["review", "in review", "code review", "pending review"][..]),
+            (TaskStatus::Completed, &["done", "closed", "resolved", "complete", "completed"][..]),
+            (TaskStatus::Cancelled, &["cancelled", "canceled", "won't do", "wont do"][..]),
+        ];
+        defaults
+            .into_iter()
+            .flat_map(|(status, names)| names.iter().map(move |name| (*name, status.clone())))
+            .fold(Self::empty(), |mapping, (name, status)| mapping.with(name, status))
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_mapping_ignores_case_and_spacing() {
+        let mapping = StatusMapping::default();
+
+        assert_eq!(mapping.status_for("In  Progress"), Some(TaskStatus::InProgress));
+        assert_eq!(mapping.status_for("DONE"), Some(TaskStatus::Completed));
+        assert_eq!(mapping.status_for("PendingReview"), Some(TaskStatus::PendingReview));
+        assert_eq!(mapping.status_for("Icebox"), None);
+    }
+
+    #[test]
+    fn test_configured_names_override_the_defaults() {
+        let mapping = StatusMapping::default()
+            .with("Icebox", TaskStatus::Cancelled)
+            .with("Done", TaskStatus::PendingReview);
+
+        assert_eq!(mapping.status_for("icebox"), Some(TaskStatus::Cancelled));
+        assert_eq!(mapping.status_for("Done"), Some(TaskStatus::PendingReview));
+    }
+}
@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use crate::domain::entities::Task;
+
+/// The columns an edit changes; `None` leaves a column as it is stored.
+///
+/// Writing only these keeps an edit from overwriting a concurrent change to other columns,
+/// such as a status transition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskPatch {
+    pub name: Option<String>,
+    pub priority: Option<Option<i32>>,
+    pub description: Option<Option<String>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskPatch {
+    /// The fields that differ between two versions of the same task
+    pub fn between(before: &Task, after: &Task) -> Self {
+        Self {
+            name: (before.name != after.name).then(|| after.name.clone()),
+            priority: (before.priority != after.priority).then_some(after.priority),
+            description: (before.description != after.description).then(|| after.description.clone()),
+            updated_at: after.updated_at,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.priority.is_none() && self.description.is_none()
+    }
+
+    /// Applies the patch to a task as loaded before it
+    pub fn apply_to(&self, task: &mut Task) {
+        if let Some(name) = &self.name {
+            task.name = name.clone();
+        }
+        if let Some(priority) = self.priority {
+            task.priority = priority;
+        }
+        if let Some(description) = &self.description {
+            task.description = description.clone();
+        }
+        task.updated_at = self.updated_at;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TaskId;
+
+    #[test]
+    fn test_between_keeps_only_changed_fields() {
+        let before = Task::new(TaskId::new(1), "Task".to_string(), Some(5)).unwrap()
+            .with_description(Some("Notes".to_string())).unwrap();
+        let mut after = before.clone();
+        after.update_priority(Some(2)).unwrap();
+        after.update_description(Some(" ".to_string())).unwrap();
+
+        let patch = TaskPatch::between(&before, &after);
+
+        assert_eq!(patch.name, None);
+        assert_eq!(patch.priority, Some(Some(2)));
+        assert_eq!(patch.description, Some(None));
+        assert!(TaskPatch::between(&before, &before).is_empty());
+
+        let mut patched = before.clone();
+        patch.apply_to(&mut patched);
+        assert_eq!(patched, after);
+    }
+}
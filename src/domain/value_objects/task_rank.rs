@@ -0,0 +1,123 @@
+use crate::domain::value_objects::TaskId;
+
+/// Digits a rank is written in, in the order they sort
+const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Length of the rank a task has until it is moved: its id, zero-padded
+pub const ID_RANK_LENGTH: usize = 10;
+
+/// Where a task sits when tasks are ordered by hand, compared as a plain string.
+///
+/// A task nobody moved ranks by its id. Moving a task gives it a key between its new
+/// neighbours', longer than any id rank, so only the moved task is written. Moving tasks into
+/// the same gap again and again lengthens the keys by about one digit per five moves.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rank(String);
+
+impl Rank {
+    /// Accepts the ranks this type produces: lowercase base-36 digits, at least as long as an
+    /// id rank, and not ending in `0` once longer, so that there is always room after one
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let valid = value.len() >= ID_RANK_LENGTH
+            && value.bytes().all(|byte| DIGITS.contains(&byte))
+            && (value.len() == ID_RANK_LENGTH || !value.ends_with('0'));
+        match valid {
+            true => Ok(Self(value.to_string())),
+            false => Err(format!("Invalid rank: {}", value)),
+        }
+    }
+
+    /// The rank of a task that was never moved
+    pub fn for_id(id: TaskId) -> Self {
+        Self(format!("{:0width$}", id.value(), width = ID_RANK_LENGTH))
+    }
+
+    /// A rank sorting after `before` and ahead of `after`; either end may be open
+    pub fn between(before: Option<&Rank>, after: Option<&Rank>) -> Result<Self, String> {
+        if let (Some(before), Some(after)) = (before, after) {
+            if before >= after {
+                return Err(format!("Rank {} does not sort ahead of {}", before.0, after.0));
+            }
+        }
+        let low = before.map(Self::digits).unwrap_or_default();
+        let high = after.map(Self::digits);
+        let base = DIGITS.len();
+
+        let mut digits = Vec::new();
+        let mut open = high.is_none();
+        loop {
+            let index = digits.len();
+            let low_digit = low.get(index).copied().unwrap_or(0);
+            let high_digit = match &high {
+                _ if open => base,
+                Some(high) if index < high.len() => high[index],
+                // Only zeros could follow, and nothing sorts between a key and itself padded with zeros
+                _ => return Err(format!("No rank fits ahead of {}", after.map_or("", |rank| rank.0.as_str()))),
+            };
+            if index >= ID_RANK_LENGTH && high_digit > low_digit + 1 {
+                digits.push((low_digit + high_digit) / 2);
+                break;
+            }
+            digits.push(low_digit);
+            open |= high_digit > low_digit;
+        }
+        Ok(Self(digits.into_iter().map(|digit| DIGITS[digit] as char).collect()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn digits(&self) -> Vec<usize> {
+        self.0.bytes().map(|byte| DIGITS.iter().position(|digit| *digit == byte).unwrap_or(0)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rank(value: &str) -> Rank {
+        Rank::parse(value).unwrap()
+    }
+
+    #[test]
+    fn test_id_ranks_sort_like_ids() {
+        assert_eq!(Rank::for_id(TaskId::new(42)).as_str(), "0000000042");
+        assert!(Rank::for_id(TaskId::new(9)) < Rank::for_id(TaskId::new(10)));
+    }
+
+    #[test]
+    fn test_between_sorts_inside_its_bounds() {
+        let (nine, ten) = (Rank::for_id(TaskId::new(9)), Rank::for_id(TaskId::new(10)));
+        let middle = Rank::between(Some(&nine), Some(&ten)).unwrap();
+        assert!(nine < middle && middle < ten, "{:?}", middle);
+
+        let first = Rank::between(None, Some(&Rank::for_id(TaskId::new(1)))).unwrap();
+        assert!(first < Rank::for_id(TaskId::new(1)));
+        let last = Rank::between(Some(&ten), None).unwrap();
+        assert!(last > ten);
+        assert!(Rank::between(Some(&ten), Some(&nine)).is_err());
+    }
+
+    #[test]
+    fn test_repeated_moves_into_one_gap_stay_ordered() {
+        let low = Rank::for_id(TaskId::new(1));
+        let mut high = Rank::for_id(TaskId::new(2));
+        for _ in 0..200 {
+            let middle = Rank::between(Some(&low), Some(&high)).unwrap();
+            assert!(low < middle && middle < high);
+            assert_eq!(Rank::parse(middle.as_str()), Ok(middle.clone()));
+            high = middle;
+        }
+        assert!(high.as_str().len() < 60, "{}", high.as_str());
+    }
+
+    #[test]
+    fn test_parse_rejects_keys_without_room_after_them() {
+        assert_eq!(rank("0000000001i").as_str(), "0000000001i");
+        for invalid in ["", "12", "0000000001I", "00000000010", "0000000001-"] {
+            assert!(Rank::parse(invalid).is_err(), "{:?}", invalid);
+        }
+    }
+}
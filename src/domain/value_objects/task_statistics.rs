@@ -0,0 +1,151 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use crate::domain::entities::Task;
+use crate::domain::value_objects::{StatusHistory, TaskFacets, TaskStatus};
+
+/// Days the daily series covers when the caller does not say
+pub const DEFAULT_STATISTICS_DAYS: u32 = 14;
+
+/// Most days the daily series can cover
+pub const MAX_STATISTICS_DAYS: u32 = 90;
+
+/// What a statistics summary covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatisticsQuery {
+    /// First UTC day of the daily series
+    pub first_day: NaiveDate,
+    /// Last UTC day of the daily series, today when the summary is current
+    pub last_day: NaiveDate,
+    /// Pending tasks untouched since before this are overdue; `None` counts none
+    pub overdue_before: Option<DateTime<Utc>>,
+}
+
+impl StatisticsQuery {
+    /// The `days` UTC days up to and including the one `now` falls on
+    pub fn last_days(days: u32, now: DateTime<Utc>, overdue_before: Option<DateTime<Utc>>) -> Self {
+        let last_day = now.date_naive();
+        let first_day = last_day - Duration::days(i64::from(days.max(1)) - 1);
+        Self { first_day, last_day, overdue_before }
+    }
+
+    /// Midnight UTC at the start of the first day
+    pub fn start(&self) -> DateTime<Utc> {
+        self.first_day.and_hms_opt(0, 0, 0).expect("midnight exists").and_utc()
+    }
+
+    /// Midnight UTC after the last day, the series' exclusive end
+    pub fn end(&self) -> DateTime<Utc> {
+        self.start() + Duration::days((self.last_day - self.first_day).num_days() + 1)
+    }
+
+    fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        let last_day = self.last_day;
+        self.first_day.iter_days().take_while(move |day| *day <= last_day)
+    }
+}
+
+/// Tasks created and completed on one UTC day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyThroughput {
+    pub day: NaiveDate,
+    pub created: usize,
+    /// Tasks moved to Completed that day, each counted once however often it was
+    pub completed: usize,
+}
+
+/// A summary of the current workspace's tasks for dashboards
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskStatistics {
+    /// Live, unarchived tasks by status and by priority
+    pub facets: TaskFacets,
+    /// Pending tasks untouched since before the query's cutoff
+    pub overdue: usize,
+    /// One entry per day of the query, oldest first, days without activity included
+    pub daily: Vec<DailyThroughput>,
+}
+
+impl TaskStatistics {
+    /// Builds the summary from per-day counts in any order, as a `GROUP BY` returns them
+    pub fn from_counts(
+        query: &StatisticsQuery,
+        facets: TaskFacets,
+        overdue: usize,
+        created: &[(NaiveDate, usize)],
+        completed: &[(NaiveDate, usize)],
+    ) -> Self {
+        let count_on = |counts: &[(NaiveDate, usize)], day: NaiveDate| {
+            counts.iter().filter(|(counted, _)| *counted == day).map(|(_, count)| count).sum()
+        };
+        let daily = query.days()
+            .map(|day| DailyThroughput { day, created: count_on(created, day), completed: count_on(completed, day) })
+            .collect();
+        Self { facets, overdue, daily }
+    }
+
+    /// Computes the summary from loaded tasks and history, for repositories that hold them in memory
+    pub fn of(query: &StatisticsQuery, tasks: &[Task], history: &[StatusHistory]) -> Self {
+        let live: Vec<Task> = tasks.iter().filter(|task| !task.is_deleted()).cloned().collect();
+        let active: Vec<Task> = live.iter().filter(|task| !task.is_archived()).cloned().collect();
+        let overdue = active.iter()
+            .filter(|task| task.status == TaskStatus::Pending)
+            .filter(|task| query.overdue_before.is_some_and(|cutoff| task.updated_at < cutoff))
+            .count();
+
+        let in_window = |at: &DateTime<Utc>| *at >= query.start() && *at < query.end();
+        let created: Vec<(NaiveDate, usize)> = live.iter()
+            .filter(|task| in_window(&task.created_at))
+            .map(|task| (task.created_at.date_naive(), 1))
+            .collect();
+        let mut completions: Vec<(NaiveDate, i32)> = history.iter()
+            .filter(|entry| entry.is_completion() && in_window(&entry.changed_at))
+            .map(|entry| (entry.changed_at.date_naive(), entry.task_id))
+            .collect();
+        completions.sort();
+        completions.dedup();
+        let completed: Vec<(NaiveDate, usize)> = completions.into_iter().map(|(day, _)| (day, 1)).collect();
+
+        Self::from_counts(query, TaskFacets::of(&active), overdue, &created, &completed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::domain::TaskId;
+
+    #[test]
+    fn test_last_days_ends_today_and_covers_whole_days() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 15, 30, 0).unwrap();
+        let query = StatisticsQuery::last_days(3, now, None);
+
+        assert_eq!(query.start(), Utc.with_ymd_and_hms(2026, 3, 8, 0, 0, 0).unwrap());
+        assert_eq!(query.end(), Utc.with_ymd_and_hms(2026, 3, 11, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_from_counts_fills_quiet_days_with_zeros() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 15, 30, 0).unwrap();
+        let query = StatisticsQuery::last_days(3, now, None);
+        let day = |day: u32| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+
+        let statistics = TaskStatistics::from_counts(&query, TaskFacets::default(), 0, &[(day(10), 2), (day(8), 1)], &[(day(10), 1)]);
+        let daily: Vec<_> = statistics.daily.iter().map(|entry| (entry.day, entry.created, entry.completed)).collect();
+        assert_eq!(daily, vec![(day(8), 1, 0), (day(9), 0, 0), (day(10), 2, 1)]);
+    }
+
+    #[test]
+    fn test_of_counts_overdue_pending_tasks_before_the_cutoff() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 15, 30, 0).unwrap();
+        let task = |id: i32, status: TaskStatus, days_ago: i64| {
+            let at = now - Duration::days(days_ago);
+            Task::new_with_status(TaskId::new(id), format!("Task {}", id), Some(3), status, at, at).unwrap()
+        };
+        let tasks = [task(1, TaskStatus::Pending, 10), task(2, TaskStatus::Pending, 1), task(3, TaskStatus::InProgress, 10)];
+
+        let query = StatisticsQuery::last_days(7, now, Some(now - Duration::days(7)));
+        let statistics = TaskStatistics::of(&query, &tasks, &[]);
+        assert_eq!((statistics.facets.total, statistics.overdue), (3, 1));
+        assert_eq!(statistics.daily.iter().map(|entry| entry.created).sum::<usize>(), 1);
+        assert_eq!(TaskStatistics::of(&StatisticsQuery { overdue_before: None, ..query }, &tasks, &[]).overdue, 0);
+    }
+}
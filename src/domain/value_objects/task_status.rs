@@ -1,25 +1,68 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::domain::WorkflowDefinition;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Longest status name; names are stored alongside every task and history entry
+pub const MAX_STATUS_NAME_LENGTH: usize = 20;
+
+/// Serialized as its name, so built-in and custom statuses look alike to clients
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum TaskStatus {
+    #[default]
     Pending,
     InProgress,
     PendingReview,
     Completed,
     Cancelled,
+    /// A status admins defined; tasks only reach it through a workflow that lists it
+    Custom(String),
 }
 
-impl TaskStatus {
+/// What a status means for reporting and dependencies, whatever it is called
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusCategory {
+    Open,
+    InProgress,
+    Done,
+}
+
+impl StatusCategory {
     pub fn as_str(&self) -> &'static str {
+        match self {
+            StatusCategory::Open => "open",
+            StatusCategory::InProgress => "in_progress",
+            StatusCategory::Done => "done",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "open" => Ok(StatusCategory::Open),
+            "in_progress" => Ok(StatusCategory::InProgress),
+            "done" => Ok(StatusCategory::Done),
+            _ => Err(format!("Invalid status category: {}", s)),
+        }
+    }
+}
+
+impl TaskStatus {
+    /// Every status, in lifecycle order
+    pub const ALL: [TaskStatus; 5] = [TaskStatus::Pending, TaskStatus::InProgress, TaskStatus::PendingReview, TaskStatus::Completed, TaskStatus::Cancelled];
+
+    pub fn as_str(&self) -> &str {
         match self {
             TaskStatus::Pending => "Pending",
             TaskStatus::InProgress => "InProgress",
             TaskStatus::PendingReview => "PendingReview",
             TaskStatus::Completed => "Completed",
             TaskStatus::Cancelled => "Cancelled",
+            TaskStatus::Custom(name) => name,
         }
     }
 
+    /// Parses a built-in status only; see [`TaskStatus::parse`] for custom ones
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self, String> {
         match s {
             "Pending" => Ok(TaskStatus::Pending),
@@ -31,34 +74,64 @@ impl TaskStatus {
         }
     }
 
-    pub fn can_transition_to(&self, target: &TaskStatus) -> bool {
-        match (self, target) {
-            // From Pending
-            (TaskStatus::Pending, TaskStatus::InProgress) => true,
-            (TaskStatus::Pending, TaskStatus::Cancelled) => true,
-            
-            // From InProgress
-            (TaskStatus::InProgress, TaskStatus::Completed) => true,
-            (TaskStatus::InProgress, TaskStatus::PendingReview) => true,
-            (TaskStatus::InProgress, TaskStatus::Cancelled) => true,
-            
-            // From PendingReview
-            (TaskStatus::PendingReview, TaskStatus::Completed) => true,
-            (TaskStatus::PendingReview, TaskStatus::Cancelled) => true,
-            
-            // Cannot transition from Completed or Cancelled
-            (TaskStatus::Completed, _) => false,
-            (TaskStatus::Cancelled, _) => false,
-            
-            // No other transitions allowed
-            _ => false,
+    /// Parses a built-in status, or any well-formed name as a custom one. Whether a custom
+    /// status exists is up to the status catalog.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        Self::from_str(s).or_else(|_| Self::custom(s))
+    }
+
+    /// A custom status: up to 20 ASCII letters and digits, starting with a letter, that is not
+    /// a built-in name in any case
+    pub fn custom(name: &str) -> Result<Self, String> {
+        if name.is_empty() || name.len() > MAX_STATUS_NAME_LENGTH {
+            return Err(format!("Invalid task status: {}; names are 1 to {} characters", name, MAX_STATUS_NAME_LENGTH));
+        }
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic()) || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(format!("Invalid task status: {}; names are letters and digits, starting with a letter", name));
         }
+        if Self::ALL.iter().any(|status| status.as_str().eq_ignore_ascii_case(name)) {
+            return Err(format!("Invalid task status: {} is a built-in status", name));
+        }
+        Ok(TaskStatus::Custom(name.to_string()))
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, TaskStatus::Custom(_))
+    }
+
+    /// The category of a built-in status; custom ones have whichever their definition gives
+    pub fn builtin_category(&self) -> Option<StatusCategory> {
+        match self {
+            TaskStatus::Pending => Some(StatusCategory::Open),
+            TaskStatus::InProgress | TaskStatus::PendingReview => Some(StatusCategory::InProgress),
+            TaskStatus::Completed | TaskStatus::Cancelled => Some(StatusCategory::Done),
+            TaskStatus::Custom(_) => None,
+        }
+    }
+
+    /// Completed and cancelled tasks no longer hold up the tasks that depend on them; so do
+    /// tasks in a custom status of the `done` category, which the status catalog decides
+    pub fn is_finished(&self) -> bool {
+        matches!(self, TaskStatus::Completed | TaskStatus::Cancelled)
+    }
+
+    /// Whether the standard workflow allows the move; workspaces may follow their own
+    /// [`WorkflowDefinition`]
+    pub fn can_transition_to(&self, target: &TaskStatus) -> bool {
+        WorkflowDefinition::standard().allows(self, target)
+    }
+}
+
+impl Serialize for TaskStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
     }
 }
 
-impl Default for TaskStatus {
-    fn default() -> Self {
-        TaskStatus::Pending
+impl<'de> Deserialize<'de> for TaskStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        TaskStatus::parse(&name).map_err(serde::de::Error::custom)
     }
 }
 
@@ -95,7 +168,20 @@ mod tests {
         
         let completed = TaskStatus::Completed;
         assert!(!completed.can_transition_to(&TaskStatus::Cancelled));
-        assert!(!completed.can_transition_to(&TaskStatus::InProgress));
+        assert!(completed.can_transition_to(&TaskStatus::InProgress));
+    }
+
+    #[test]
+    fn test_custom_statuses_parse_and_serialize_by_name() {
+        let blocked = TaskStatus::parse("Blocked").unwrap();
+        assert_eq!(blocked, TaskStatus::Custom("Blocked".to_string()));
+        assert_eq!(TaskStatus::parse("Completed").unwrap(), TaskStatus::Completed);
+        assert_eq!(serde_json::to_string(&blocked).unwrap(), r#""Blocked""#);
+        assert_eq!(serde_json::from_str::<TaskStatus>(r#""PendingReview""#).unwrap(), TaskStatus::PendingReview);
+
+        for name in ["", "completed", "2fa", "In Review", &"A".repeat(MAX_STATUS_NAME_LENGTH + 1)] {
+            assert!(TaskStatus::custom(name).is_err(), "{:?}", name);
+        }
     }
 
     #[test]
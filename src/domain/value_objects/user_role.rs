@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub enum UserRole {
+    #[default]
     User,
     Manager,
     Admin,
+    /// Background jobs; never granted to a client
+    System,
 }
 
 impl UserRole {
@@ -13,43 +16,82 @@ impl UserRole {
             UserRole::User => "User",
             UserRole::Manager => "Manager", 
             UserRole::Admin => "Admin",
+            UserRole::System => "System",
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self, String> {
         match s {
             "User" => Ok(UserRole::User),
             "Manager" => Ok(UserRole::Manager),
             "Admin" => Ok(UserRole::Admin),
+            "System" => Ok(UserRole::System),
             _ => Err(format!("Invalid user role: {}", s)),
         }
     }
 
     pub fn can_approve(&self) -> bool {
         match self {
-            UserRole::User => false,
+            UserRole::User | UserRole::System => false,
             UserRole::Manager | UserRole::Admin => true,
         }
     }
 
     pub fn can_manage_users(&self) -> bool {
         match self {
-            UserRole::User | UserRole::Manager => false,
+            UserRole::User | UserRole::Manager | UserRole::System => false,
             UserRole::Admin => true,
         }
     }
 
+    /// Listing and restoring soft-deleted tasks is reserved for admins
+    pub fn can_manage_deleted_tasks(&self) -> bool {
+        match self {
+            UserRole::User | UserRole::Manager | UserRole::System => false,
+            UserRole::Admin => true,
+        }
+    }
+
+    /// Bulk edits such as reprioritizing or importing many tasks at once are reserved for admins
+    pub fn can_bulk_edit_tasks(&self) -> bool {
+        match self {
+            UserRole::User | UserRole::Manager | UserRole::System => false,
+            UserRole::Admin => true,
+        }
+    }
+
+    /// Automation rules act on every task, so only managers and admins may define them
+    pub fn can_manage_automation(&self) -> bool {
+        match self {
+            UserRole::User | UserRole::System => false,
+            UserRole::Manager | UserRole::Admin => true,
+        }
+    }
+
     pub fn has_elevated_permissions(&self) -> bool {
         match self {
-            UserRole::User => false,
+            UserRole::User | UserRole::System => false,
             UserRole::Manager | UserRole::Admin => true,
         }
     }
-}
 
-impl Default for UserRole {
-    fn default() -> Self {
-        UserRole::User
+    /// Whether this role may act where `required` is asked for: managers where users may, admins
+    /// anywhere. The System role is outside the ladder and never qualifies.
+    pub fn is_at_least(&self, required: &UserRole) -> bool {
+        match (self.seniority(), required.seniority()) {
+            (Some(held), Some(required)) => held >= required,
+            _ => false,
+        }
+    }
+
+    fn seniority(&self) -> Option<u8> {
+        match self {
+            UserRole::User => Some(0),
+            UserRole::Manager => Some(1),
+            UserRole::Admin => Some(2),
+            UserRole::System => None,
+        }
     }
 }
 
@@ -69,6 +111,7 @@ mod tests {
         assert_eq!(UserRole::from_str("User").unwrap(), UserRole::User);
         assert_eq!(UserRole::from_str("Manager").unwrap(), UserRole::Manager);
         assert_eq!(UserRole::from_str("Admin").unwrap(), UserRole::Admin);
+        assert_eq!(UserRole::from_str("System").unwrap(), UserRole::System);
     }
 
     #[test]
@@ -80,6 +123,7 @@ mod tests {
     #[test]
     fn test_approval_permissions() {
         assert!(!UserRole::User.can_approve());
+        assert!(!UserRole::System.can_approve());
         assert!(UserRole::Manager.can_approve());
         assert!(UserRole::Admin.can_approve());
     }
@@ -91,6 +135,14 @@ mod tests {
         assert!(UserRole::Admin.can_manage_users());
     }
 
+    #[test]
+    fn test_automation_permissions() {
+        assert!(!UserRole::User.can_manage_automation());
+        assert!(!UserRole::System.can_manage_automation());
+        assert!(UserRole::Manager.can_manage_automation());
+        assert!(UserRole::Admin.can_manage_automation());
+    }
+
     #[test]
     fn test_elevated_permissions() {
         assert!(!UserRole::User.has_elevated_permissions());
@@ -0,0 +1,302 @@
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+use serde::{Deserialize, Serialize};
+use crate::domain::{DomainError, TaskStatus, UserRole};
+
+/// A move between two statuses that a workflow allows, and the guards it must pass
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowTransition {
+    pub from: TaskStatus,
+    pub to: TaskStatus,
+    /// High-priority tasks may not take this transition; they must go through review instead
+    #[serde(default)]
+    pub requires_review: bool,
+    /// Only high-priority tasks may take this transition, like entering review
+    #[serde(default)]
+    pub high_priority_only: bool,
+    /// The change must carry a non-blank comment
+    #[serde(default)]
+    pub requires_comment: bool,
+    /// Least senior role allowed to take the transition; any role when unset
+    #[serde(default)]
+    pub role: Option<UserRole>,
+}
+
+impl WorkflowTransition {
+    pub fn new(from: TaskStatus, to: TaskStatus) -> Self {
+        Self { from, to, requires_review: false, high_priority_only: false, requires_comment: false, role: None }
+    }
+
+    pub fn requiring_review(mut self) -> Self {
+        self.requires_review = true;
+        self
+    }
+
+    pub fn high_priority_only(mut self) -> Self {
+        self.high_priority_only = true;
+        self
+    }
+
+    pub fn requiring_comment(mut self) -> Self {
+        self.requires_comment = true;
+        self
+    }
+
+    pub fn for_role(mut self, role: UserRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+}
+
+static STANDARD: LazyLock<WorkflowDefinition> = LazyLock::new(|| {
+    use TaskStatus::*;
+    WorkflowDefinition {
+        states: TaskStatus::ALL.to_vec(),
+        transitions: vec![
+            WorkflowTransition::new(Pending, InProgress),
+            WorkflowTransition::new(Pending, Cancelled).requiring_comment(),
+            WorkflowTransition::new(InProgress, Completed).requiring_review(),
+            WorkflowTransition::new(InProgress, PendingReview).high_priority_only(),
+            WorkflowTransition::new(InProgress, Cancelled).requiring_comment(),
+            WorkflowTransition::new(PendingReview, Completed).requiring_comment().for_role(UserRole::Manager),
+            WorkflowTransition::new(PendingReview, Cancelled).requiring_comment(),
+            // Completed tasks can only be reopened for rework
+            WorkflowTransition::new(Completed, InProgress).requiring_comment().for_role(UserRole::Manager),
+        ],
+    }
+});
+
+/// The statuses a team's tasks move through and the transitions allowed between them.
+///
+/// Statuses left out of `states` cannot be entered, and every transition not listed is refused.
+/// New tasks start in Pending, so every workflow includes it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "WorkflowSpec")]
+pub struct WorkflowDefinition {
+    states: Vec<TaskStatus>,
+    transitions: Vec<WorkflowTransition>,
+}
+
+#[derive(Deserialize)]
+struct WorkflowSpec {
+    states: Vec<TaskStatus>,
+    transitions: Vec<WorkflowTransition>,
+}
+
+impl WorkflowDefinition {
+    pub fn new(states: Vec<TaskStatus>, transitions: Vec<WorkflowTransition>) -> Result<Self, String> {
+        if !states.contains(&TaskStatus::Pending) {
+            return Err("A workflow must include Pending, the status new tasks start in".to_string());
+        }
+        for (index, state) in states.iter().enumerate() {
+            if states[..index].contains(state) {
+                return Err(format!("Status {} is listed twice", state.as_str()));
+            }
+        }
+        for (index, transition) in transitions.iter().enumerate() {
+            let name = format!("{}->{}", transition.from.as_str(), transition.to.as_str());
+            if let Some(missing) = [&transition.from, &transition.to].into_iter().find(|status| !states.contains(status)) {
+                return Err(format!("Transition {} uses {}, which is not one of the workflow's states", name, missing.as_str()));
+            }
+            if transition.from == transition.to {
+                return Err(format!("Transition {} must lead to another status", name));
+            }
+            if transitions[..index].iter().any(|earlier| earlier.from == transition.from && earlier.to == transition.to) {
+                return Err(format!("Transition {} is listed twice", name));
+            }
+            if transition.requires_review && transition.high_priority_only {
+                return Err(format!("Transition {} cannot both require review and be for high-priority tasks only", name));
+            }
+            if transition.role == Some(UserRole::System) {
+                return Err(format!("Transition {} cannot be reserved for the System role", name));
+            }
+        }
+        Ok(Self { states, transitions })
+    }
+
+    /// The built-in process: review for high-priority work, and approval and reopening by managers
+    pub fn standard() -> &'static WorkflowDefinition {
+        &STANDARD
+    }
+
+    pub fn states(&self) -> &[TaskStatus] {
+        &self.states
+    }
+
+    pub fn transitions(&self) -> &[WorkflowTransition] {
+        &self.transitions
+    }
+
+    pub fn transition(&self, from: &TaskStatus, to: &TaskStatus) -> Option<&WorkflowTransition> {
+        self.transitions.iter().find(|transition| &transition.from == from && &transition.to == to)
+    }
+
+    /// Whether the workflow lists the transition at all, regardless of its guards
+    pub fn allows(&self, from: &TaskStatus, to: &TaskStatus) -> bool {
+        self.transition(from, to).is_some()
+    }
+
+    /// Checks the transition and every guard but the comment, which the caller supplies
+    pub fn check(&self, from: &TaskStatus, to: &TaskStatus, is_high_priority: bool, user_role: &UserRole) -> Result<(), DomainError> {
+        let transition = self.transition(from, to)
+            .ok_or_else(|| DomainError::InvalidTransition { from: from.clone(), to: to.clone() })?;
+
+        if transition.requires_review && is_high_priority {
+            return Err(DomainError::ReviewRequired);
+        }
+        if transition.high_priority_only && !is_high_priority {
+            return Err(DomainError::ReviewNotRequired);
+        }
+        match &transition.role {
+            Some(role) if !user_role.is_at_least(role) => Err(role_error(from, to, role)),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn requires_comment(&self, from: &TaskStatus, to: &TaskStatus) -> bool {
+        self.transition(from, to).is_some_and(|transition| transition.requires_comment)
+    }
+
+    /// Statuses a task in `from` may move to, in the order of the workflow's states
+    pub fn valid_transitions(&self, from: &TaskStatus, is_high_priority: bool, user_role: &UserRole) -> Vec<TaskStatus> {
+        self.states
+            .iter()
+            .filter(|to| self.check(from, to, is_high_priority, user_role).is_ok())
+            .cloned()
+            .collect()
+    }
+}
+
+impl Default for WorkflowDefinition {
+    fn default() -> Self {
+        Self::standard().clone()
+    }
+}
+
+impl TryFrom<WorkflowSpec> for WorkflowDefinition {
+    type Error = String;
+
+    fn try_from(spec: WorkflowSpec) -> Result<Self, Self::Error> {
+        Self::new(spec.states, spec.transitions)
+    }
+}
+
+/// Approving and reopening keep the errors clients already match on
+fn role_error(from: &TaskStatus, to: &TaskStatus, role: &UserRole) -> DomainError {
+    match (from, to) {
+        (TaskStatus::PendingReview, TaskStatus::Completed) => DomainError::ApprovalNotAllowed,
+        (TaskStatus::Completed, TaskStatus::InProgress) => DomainError::ReopenNotAllowed,
+        _ => DomainError::TransitionRoleRequired { from: from.clone(), to: to.clone(), role: role.clone() },
+    }
+}
+
+/// Workflows by workspace, for workspaces that follow another process than the standard one
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Workflows {
+    fallback: WorkflowDefinition,
+    workspaces: BTreeMap<String, WorkflowDefinition>,
+}
+
+impl Workflows {
+    /// Parses a JSON object of workflows keyed by workspace, where `*` names every other workspace
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut workflows = Self::default();
+        if value.trim().is_empty() {
+            return Ok(workflows);
+        }
+        let definitions: BTreeMap<String, serde_json::Value> = serde_json::from_str(value)
+            .map_err(|e| format!("Workflows must be a JSON object of workflows by workspace: {}", e))?;
+        for (workspace, workflow) in definitions {
+            let workflow: WorkflowDefinition = serde_json::from_value(workflow)
+                .map_err(|e| format!("Workflow of {}: {}", workspace, e))?;
+            match workspace.trim() {
+                "*" => workflows.fallback = workflow,
+                "" => return Err("A workflow names no workspace".to_string()),
+                workspace => {
+                    workflows.workspaces.insert(workspace.to_string(), workflow);
+                }
+            }
+        }
+        Ok(workflows)
+    }
+
+    pub fn for_workspace(&self, workspace: &str) -> &WorkflowDefinition {
+        self.workspaces.get(workspace).unwrap_or(&self.fallback)
+    }
+}
+
+impl TryFrom<String> for Workflows {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_standard_workflow_guards() {
+        let standard = WorkflowDefinition::standard();
+        let check = |from, to, high, role| standard.check(&from, &to, high, &role);
+
+        assert!(check(TaskStatus::InProgress, TaskStatus::Completed, false, UserRole::User).is_ok());
+        assert_eq!(check(TaskStatus::InProgress, TaskStatus::Completed, true, UserRole::User), Err(DomainError::ReviewRequired));
+        assert_eq!(check(TaskStatus::InProgress, TaskStatus::PendingReview, false, UserRole::User), Err(DomainError::ReviewNotRequired));
+        assert_eq!(check(TaskStatus::PendingReview, TaskStatus::Completed, true, UserRole::User), Err(DomainError::ApprovalNotAllowed));
+        assert!(check(TaskStatus::PendingReview, TaskStatus::Completed, true, UserRole::Admin).is_ok());
+        assert_eq!(check(TaskStatus::Completed, TaskStatus::InProgress, false, UserRole::System), Err(DomainError::ReopenNotAllowed));
+        assert!(matches!(check(TaskStatus::Cancelled, TaskStatus::Pending, false, UserRole::Admin), Err(DomainError::InvalidTransition { .. })));
+        assert!(standard.requires_comment(&TaskStatus::Pending, &TaskStatus::Cancelled));
+        assert!(!standard.requires_comment(&TaskStatus::Pending, &TaskStatus::InProgress));
+    }
+
+    #[test]
+    fn test_custom_workflow_from_json() {
+        let workflow: WorkflowDefinition = serde_json::from_value(json!({
+            "states": ["Pending", "InProgress", "Completed"],
+            "transitions": [
+                {"from": "Pending", "to": "InProgress"},
+                {"from": "InProgress", "to": "Completed", "requires_comment": true, "role": "Manager"},
+            ]
+        })).unwrap();
+
+        assert_eq!(workflow.valid_transitions(&TaskStatus::InProgress, true, &UserRole::Manager), vec![TaskStatus::Completed]);
+        assert!(workflow.valid_transitions(&TaskStatus::Pending, false, &UserRole::User).contains(&TaskStatus::InProgress));
+        assert!(!workflow.allows(&TaskStatus::Pending, &TaskStatus::Cancelled));
+        assert_eq!(
+            workflow.check(&TaskStatus::InProgress, &TaskStatus::Completed, false, &UserRole::User).unwrap_err().to_string(),
+            "Only a Manager or above can move a task from InProgress to Completed"
+        );
+    }
+
+    #[test]
+    fn test_invalid_workflows() {
+        use TaskStatus::*;
+        let cases = [
+            (vec![InProgress], vec![], "A workflow must include Pending, the status new tasks start in"),
+            (vec![Pending, Pending], vec![], "Status Pending is listed twice"),
+            (vec![Pending], vec![WorkflowTransition::new(Pending, Completed)], "Transition Pending->Completed uses Completed, which is not one of the workflow's states"),
+            (vec![Pending], vec![WorkflowTransition::new(Pending, Pending)], "Transition Pending->Pending must lead to another status"),
+            (vec![Pending, Cancelled], vec![WorkflowTransition::new(Pending, Cancelled), WorkflowTransition::new(Pending, Cancelled)], "Transition Pending->Cancelled is listed twice"),
+            (vec![Pending, Cancelled], vec![WorkflowTransition::new(Pending, Cancelled).for_role(UserRole::System)], "Transition Pending->Cancelled cannot be reserved for the System role"),
+        ];
+        for (states, transitions, expected) in cases {
+            assert_eq!(WorkflowDefinition::new(states, transitions).unwrap_err(), expected);
+        }
+    }
+
+    #[test]
+    fn test_workflows_by_workspace() {
+        let workflows = Workflows::parse(r#"{"acme": {"states": ["Pending", "Cancelled"], "transitions": [{"from": "Pending", "to": "Cancelled"}]}}"#).unwrap();
+
+        assert!(!workflows.for_workspace("acme").requires_comment(&TaskStatus::Pending, &TaskStatus::Cancelled));
+        assert_eq!(workflows.for_workspace("other"), WorkflowDefinition::standard());
+        assert_eq!(Workflows::parse("").unwrap(), Workflows::default());
+        assert!(Workflows::parse(r#"{"*": {"states": [], "transitions": []}}"#).unwrap_err().contains("must include Pending"));
+    }
+}
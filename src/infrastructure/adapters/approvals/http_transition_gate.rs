@@ -0,0 +1,66 @@
+use std::time::Duration;
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::domain::{GateDecision, GatedTransition, TransitionGate};
+
+/// The approval system's answer: `{"approved": false, "reason": "CAB has not signed off"}`
+#[derive(Debug, Deserialize)]
+struct GateResponse {
+    approved: bool,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+/// Asks an external approval system over HTTP, POSTing each gated transition as JSON and
+/// waiting at most `timeout` for its decision
+#[derive(Debug, Clone)]
+pub struct HttpTransitionGate {
+    client: reqwest::Client,
+    url: String,
+    timeout: Duration,
+}
+
+impl HttpTransitionGate {
+    pub fn new(url: &str, timeout: Duration) -> Self {
+        Self { client: reqwest::Client::new(), url: url.to_string(), timeout }
+    }
+
+    async fn ask(&self, transition: &GatedTransition) -> Result<GateDecision, String> {
+        let body = serde_json::to_string(transition)
+            .map_err(|error| format!("Failed to encode transition: {}", error))?;
+        let response = self.client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .timeout(self.timeout)
+            .body(body)
+            .send()
+            .await
+            .map_err(|error| format!("Approval request failed: {}", error))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Approval system answered {}", response.status()));
+        }
+        let body = response.bytes()
+            .await
+            .map_err(|error| format!("Approval request failed: {}", error))?;
+        let answer: GateResponse = serde_json::from_slice(&body)
+            .map_err(|error| format!("Approval system sent an unreadable answer: {}", error))?;
+
+        Ok(match answer.approved {
+            true => GateDecision::Approved,
+            false => GateDecision::Rejected { reason: answer.reason.unwrap_or_else(|| "No reason given".to_string()) },
+        })
+    }
+}
+
+#[async_trait]
+impl TransitionGate for HttpTransitionGate {
+    async fn review(&self, transition: &GatedTransition) -> Result<GateDecision, String> {
+        let decision = self.ask(transition).await;
+        if let Err(error) = &decision {
+            tracing::warn!(task_id = transition.task_id, "Transition gate unavailable: {}", error);
+        }
+        decision
+    }
+}
@@ -0,0 +1,3 @@
+pub mod http_transition_gate;
+
+pub use http_transition_gate::*;
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+use crate::domain::{DomainEvent, EventPublisher, RepositoryError};
+
+/// How many events a slow subscriber may fall behind before it starts missing them
+pub const DEFAULT_EVENT_BUS_CAPACITY: usize = 256;
+
+/// Fans task events out to subscribers in the same process.
+///
+/// Nothing is stored: events published while nobody listens are dropped, and a subscriber
+/// that falls more than the capacity behind skips the oldest ones.
+#[derive(Debug, Clone)]
+pub struct InMemoryEventPublisher {
+    sender: broadcast::Sender<DomainEvent>,
+}
+
+impl InMemoryEventPublisher {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<DomainEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for InMemoryEventPublisher {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_BUS_CAPACITY)
+    }
+}
+
+#[async_trait]
+impl EventPublisher for InMemoryEventPublisher {
+    async fn publish(&self, event: &DomainEvent) -> Result<(), RepositoryError> {
+        let _ = self.sender.send(event.clone());
+        Ok(())
+    }
+}
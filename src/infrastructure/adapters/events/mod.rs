@@ -0,0 +1,5 @@
+pub mod in_memory_event_publisher;
+pub mod webhook_notifier;
+
+pub use in_memory_event_publisher::*;
+pub use webhook_notifier::*;
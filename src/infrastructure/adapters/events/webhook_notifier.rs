@@ -0,0 +1,50 @@
+use std::time::Duration;
+use async_trait::async_trait;
+
+use crate::domain::{AutomationNotification, Notifier};
+
+/// Delivers automation notifications by POSTing them as JSON to the rule's URL.
+///
+/// Redirects are not followed, so an endpoint cannot send the request on to a host the
+/// rule's URL was never checked against.
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("a client without redirects always builds");
+        Self { client }
+    }
+}
+
+impl Default for WebhookNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, url: &str, notification: &AutomationNotification) -> Result<(), String> {
+        let body = serde_json::to_string(notification)
+            .map_err(|error| format!("Failed to encode notification: {}", error))?;
+        let response = self.client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .timeout(Duration::from_secs(10))
+            .body(body)
+            .send()
+            .await
+            .map_err(|error| format!("Notification to {} failed: {}", url, error))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Notification endpoint {} answered {}", url, response.status()));
+        }
+        Ok(())
+    }
+}
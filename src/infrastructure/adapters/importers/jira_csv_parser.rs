@@ -0,0 +1,144 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::domain::{ImportSource, ImportedTask, ParsedImport, PriorityLabel, SkippedRow, TaskImportParser};
+
+/// Formats Jira writes dates in, depending on the site's settings; times are taken as UTC
+const DATE_FORMATS: [&str; 4] = ["%d/%b/%y %I:%M %p", "%d/%b/%Y %I:%M %p", "%Y-%m-%d %H:%M", "%Y-%m-%d %H:%M:%S"];
+
+/// Column positions, looked up by header name so exports with any field selection can be read
+struct Columns {
+    summary: usize,
+    status: usize,
+    key: Option<usize>,
+    description: Option<usize>,
+    priority: Option<usize>,
+    created: Option<usize>,
+    updated: Option<usize>,
+    resolved: Option<usize>,
+}
+
+impl Columns {
+    fn find(headers: &csv::StringRecord) -> Result<Self, String> {
+        // Exports repeat some headers, such as Labels; the first column of a name wins
+        let position = |name: &str| headers.iter().position(|header| header.trim().eq_ignore_ascii_case(name));
+        Ok(Self {
+            summary: position("Summary").ok_or("The export has no Summary column")?,
+            status: position("Status").ok_or("The export has no Status column")?,
+            key: position("Issue key"),
+            description: position("Description"),
+            priority: position("Priority"),
+            created: position("Created"),
+            updated: position("Updated"),
+            resolved: position("Resolved"),
+        })
+    }
+}
+
+/// Reads issues from a Jira CSV export; each issue's workflow status becomes its source status.
+///
+/// Jira priorities map onto the priority labels: Highest and Blocker read as Critical,
+/// Major as High, and Lowest, Minor and Trivial as Low. Issues are dated by their Created
+/// column, and an issue whose dates cannot be read is skipped.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JiraCsvParser;
+
+impl TaskImportParser for JiraCsvParser {
+    fn source(&self) -> ImportSource {
+        ImportSource::Jira
+    }
+
+    fn parse(&self, export: &str) -> Result<ParsedImport, String> {
+        let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(export.as_bytes());
+        let headers = reader.headers().map_err(|error| format!("Not a Jira CSV export: {}", error))?.clone();
+        let columns = Columns::find(&headers)?;
+
+        let mut parsed = ParsedImport::default();
+        for (index, record) in reader.records().enumerate() {
+            let row = index + 1;
+            let record = match record {
+                Ok(record) => record,
+                Err(error) => {
+                    parsed.skipped.push(SkippedRow { row, external_id: None, reason: format!("Unreadable row: {}", error) });
+                    continue;
+                }
+            };
+            let field = |column: Option<usize>| column.and_then(|column| record.get(column)).map(str::trim).filter(|value| !value.is_empty());
+            let external_id = field(columns.key).unwrap_or_default().to_string();
+            match read_issue(row, external_id.clone(), &columns, field) {
+                Ok(task) => parsed.tasks.push(task),
+                Err(reason) => parsed.skipped.push(SkippedRow { row, external_id: Some(external_id).filter(|id| !id.is_empty()), reason }),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+fn read_issue<'r>(row: usize, external_id: String, columns: &Columns, field: impl Fn(Option<usize>) -> Option<&'r str>) -> Result<ImportedTask, String> {
+    let date = |column: Option<usize>, name: &str| {
+        field(column).map(|value| parse_date(value).ok_or_else(|| format!("Unreadable {} date '{}'", name, value))).transpose()
+    };
+    let status_changed_at = match date(columns.resolved, "Resolved")? {
+        Some(resolved) => Some(resolved),
+        None => date(columns.updated, "Updated")?,
+    };
+
+    Ok(ImportedTask {
+        row,
+        external_id,
+        name: field(Some(columns.summary)).unwrap_or_default().to_string(),
+        description: field(columns.description).map(str::to_string),
+        source_status: field(Some(columns.status)).ok_or("The issue has no status")?.to_string(),
+        priority: field(columns.priority).and_then(priority_label),
+        created_at: date(columns.created, "Created")?,
+        status_changed_at,
+    })
+}
+
+fn priority_label(priority: &str) -> Option<PriorityLabel> {
+    match priority.to_lowercase().as_str() {
+        "highest" | "blocker" => Some(PriorityLabel::Critical),
+        "major" => Some(PriorityLabel::High),
+        "lowest" | "minor" | "trivial" => Some(PriorityLabel::Low),
+        other => PriorityLabel::from_str(other).ok(),
+    }
+}
+
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|date| date.with_timezone(&Utc))
+        .ok()
+        .or_else(|| DATE_FORMATS.iter().find_map(|format| NaiveDateTime::parse_from_str(value, format).ok()).map(|date| date.and_utc()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_issues_are_read_by_column_name() {
+        let export = "Issue key,Summary,Status,Priority,Created,Resolved,Description\n\
+                      OPS-1,Rotate keys,Done,Highest,12/Mar/24 2:30 PM,14/Mar/24 9:00 AM,\"Two\nlines\"\n\
+                      OPS-2,Patch hosts,In Progress,Medium,2024-03-13 08:00,,\n\
+                      OPS-3,Broken date,To Do,Low,yesterday,,\n";
+
+        let parsed = JiraCsvParser.parse(export).unwrap();
+
+        assert_eq!(parsed.tasks.len(), 2);
+        let done = &parsed.tasks[0];
+        assert_eq!(done.external_id, "OPS-1");
+        assert_eq!(done.source_status, "Done");
+        assert_eq!(done.priority, Some(PriorityLabel::Critical));
+        assert_eq!(done.description.as_deref(), Some("Two\nlines"));
+        assert_eq!(done.created_at, Some(Utc.with_ymd_and_hms(2024, 3, 12, 14, 30, 0).unwrap()));
+        assert_eq!(done.status_changed_at, Some(Utc.with_ymd_and_hms(2024, 3, 14, 9, 0, 0).unwrap()));
+        assert_eq!(parsed.tasks[1].row, 2);
+        assert_eq!(parsed.tasks[1].status_changed_at, None);
+        assert_eq!(parsed.skipped, vec![SkippedRow { row: 3, external_id: Some("OPS-3".to_string()), reason: "Unreadable Created date 'yesterday'".to_string() }]);
+    }
+
+    #[test]
+    fn test_rejects_exports_without_summary_or_status() {
+        assert_eq!(JiraCsvParser.parse("Issue key,Status\nOPS-1,Done\n").unwrap_err(), "The export has no Summary column");
+    }
+}
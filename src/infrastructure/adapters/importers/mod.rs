@@ -0,0 +1,15 @@
+pub mod trello_export_parser;
+pub mod jira_csv_parser;
+
+pub use trello_export_parser::*;
+pub use jira_csv_parser::*;
+
+use crate::domain::{ImportSource, TaskImportParser};
+
+/// The parser for an export from `source`
+pub fn import_parser(source: ImportSource) -> Box<dyn TaskImportParser> {
+    match source {
+        ImportSource::Trello => Box::new(TrelloExportParser),
+        ImportSource::Jira => Box::new(JiraCsvParser),
+    }
+}
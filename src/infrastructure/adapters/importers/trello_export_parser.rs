@@ -0,0 +1,157 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::domain::{ImportSource, ImportedTask, ParsedImport, PriorityLabel, SkippedRow, TaskImportParser};
+
+#[derive(Debug, Deserialize)]
+struct Board {
+    #[serde(default)]
+    lists: Vec<List>,
+    cards: Vec<Card>,
+    #[serde(default)]
+    actions: Vec<Action>,
+}
+
+#[derive(Debug, Deserialize)]
+struct List {
+    id: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Card {
+    id: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    desc: String,
+    id_list: String,
+    #[serde(default)]
+    closed: bool,
+    #[serde(default)]
+    labels: Vec<Label>,
+    date_last_activity: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Label {
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Action {
+    #[serde(rename = "type")]
+    kind: String,
+    date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    data: ActionData,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ActionData {
+    card: Option<ActionCard>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ActionCard {
+    id: String,
+}
+
+/// Reads a Trello board exported as JSON; each card's list name becomes its source status.
+///
+/// Archived cards are skipped. A card is dated by its `createCard` action when the export
+/// has one, and otherwise by the timestamp Trello encodes in the first bytes of its id.
+/// A label named after a priority label, such as `High`, sets the priority.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrelloExportParser;
+
+impl TaskImportParser for TrelloExportParser {
+    fn source(&self) -> ImportSource {
+        ImportSource::Trello
+    }
+
+    fn parse(&self, export: &str) -> Result<ParsedImport, String> {
+        let board: Board = serde_json::from_str(export).map_err(|error| format!("Not a Trello board export: {}", error))?;
+        let lists: HashMap<&str, &str> = board.lists.iter().map(|list| (list.id.as_str(), list.name.as_str())).collect();
+        let created: HashMap<&str, DateTime<Utc>> = board
+            .actions
+            .iter()
+            .filter(|action| action.kind == "createCard")
+            .filter_map(|action| Some((action.data.card.as_ref()?.id.as_str(), action.date?)))
+            .collect();
+
+        let mut parsed = ParsedImport::default();
+        for (index, card) in board.cards.iter().enumerate() {
+            let row = index + 1;
+            let skip = |reason: &str| SkippedRow { row, external_id: Some(card.id.clone()), reason: reason.to_string() };
+            if card.closed {
+                parsed.skipped.push(skip("Card is archived"));
+                continue;
+            }
+            let Some(list) = lists.get(card.id_list.as_str()) else {
+                parsed.skipped.push(skip("Card is in a list missing from the export"));
+                continue;
+            };
+
+            parsed.tasks.push(ImportedTask {
+                row,
+                external_id: card.id.clone(),
+                name: card.name.clone(),
+                description: Some(card.desc.clone()).filter(|desc| !desc.trim().is_empty()),
+                source_status: list.to_string(),
+                priority: card.labels.iter().find_map(|label| PriorityLabel::from_str(label.name.trim()).ok()),
+                created_at: created.get(card.id.as_str()).copied().or_else(|| created_from_id(&card.id)),
+                status_changed_at: card.date_last_activity,
+            });
+        }
+        Ok(parsed)
+    }
+}
+
+/// Trello ids start with the creation time in seconds, as eight hex digits
+fn created_from_id(id: &str) -> Option<DateTime<Utc>> {
+    let seconds = i64::from_str_radix(id.get(..8)?, 16).ok()?;
+    DateTime::from_timestamp(seconds, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    #[test]
+    fn test_cards_take_their_list_name_and_creation_date() {
+        let export = json!({
+            "lists": [{"id": "l1", "name": "Doing"}],
+            "cards": [
+                {"id": "65a5d0c0aaaaaaaaaaaaaaaa", "name": "Dated by id", "desc": "", "idList": "l1", "labels": [{"name": "high"}]},
+                {"id": "c2", "name": "Dated by action", "desc": "Notes", "idList": "l1", "closed": false},
+                {"id": "c3", "name": "Archived", "idList": "l1", "closed": true},
+                {"id": "c4", "name": "Orphan", "idList": "gone"}
+            ],
+            "actions": [{"type": "createCard", "date": "2024-02-01T09:00:00Z", "data": {"card": {"id": "c2"}}}]
+        });
+
+        let parsed = TrelloExportParser.parse(&export.to_string()).unwrap();
+
+        assert_eq!(parsed.tasks.len(), 2);
+        let by_id = &parsed.tasks[0];
+        assert_eq!(by_id.source_status, "Doing");
+        assert_eq!(by_id.priority, Some(PriorityLabel::High));
+        assert_eq!(by_id.description, None);
+        assert_eq!(by_id.created_at, Some(Utc.with_ymd_and_hms(2024, 1, 16, 0, 41, 36).unwrap()));
+        assert_eq!(parsed.tasks[1].created_at, Some(Utc.with_ymd_and_hms(2024, 2, 1, 9, 0, 0).unwrap()));
+        assert_eq!(parsed.tasks[1].description.as_deref(), Some("Notes"));
+        let skipped: Vec<_> = parsed.skipped.iter().map(|row| (row.row, row.reason.as_str())).collect();
+        assert_eq!(skipped, vec![(3, "Card is archived"), (4, "Card is in a list missing from the export")]);
+    }
+
+    #[test]
+    fn test_rejects_files_that_are_not_board_exports() {
+        assert!(TrelloExportParser.parse("[1, 2]").is_err());
+    }
+}
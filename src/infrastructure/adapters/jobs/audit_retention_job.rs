@@ -0,0 +1,33 @@
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::application::AuditArchiveUseCases;
+use crate::domain::{AuditRetentionPolicy, SystemActor};
+use crate::infrastructure::adapters::jobs::JobScheduler;
+
+/// Archives status history older than `policy` keeps every `interval` as the `audit-retention`
+/// job, starting immediately
+pub fn spawn_audit_retention_job(
+    scheduler: &JobScheduler,
+    audit_archive_use_cases: Arc<AuditArchiveUseCases>,
+    policy: AuditRetentionPolicy,
+    interval: Duration,
+) -> JoinHandle<()> {
+    scheduler.spawn(SystemActor::AUDIT_RETENTION, interval, move || {
+        let audit_archive_use_cases = audit_archive_use_cases.clone();
+        async move {
+            let actor = SystemActor::new(SystemActor::AUDIT_RETENTION);
+            let months = audit_archive_use_cases.archive_expired(&policy, Utc::now()).await
+                .map_err(|error| error.to_string())?;
+            for month in months {
+                tracing::info!(
+                    actor = %actor.changed_by(),
+                    "Archived {} status history entries of {} to {}", month.deleted, month.month, month.key
+                );
+            }
+            Ok(())
+        }
+    })
+}
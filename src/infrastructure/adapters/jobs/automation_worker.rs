@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::task::JoinHandle;
+
+use crate::application::AutomationUseCases;
+use crate::database::with_workspace;
+use crate::domain::DomainEvent;
+
+/// Runs automation rules against every event received from `events`, one event at a time,
+/// in the event's workspace, until the event bus closes
+pub fn spawn_automation_worker(use_cases: Arc<AutomationUseCases>, mut events: broadcast::Receiver<DomainEvent>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Automation worker fell behind and skipped {} event(s)", skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+            match with_workspace(Some(event.workspace_id.clone()), use_cases.handle_event(&event)).await {
+                Ok(outcomes) => {
+                    for outcome in outcomes {
+                        match outcome.error {
+                            Some(error) => tracing::warn!(rule_id = %outcome.rule_id, "Automation rule '{}' failed on {} of task {}: {}", outcome.rule_name, event.event.event_type(), event.event.task_id(), error),
+                            None => tracing::debug!(rule_id = %outcome.rule_id, "Automation rule '{}' ran on {} of task {}", outcome.rule_name, event.event.event_type(), event.event.task_id()),
+                        }
+                    }
+                }
+                Err(error) => tracing::error!("Failed to evaluate automation rules for {} of task {}: {}", event.event.event_type(), event.event.task_id(), error),
+            }
+        }
+    })
+}
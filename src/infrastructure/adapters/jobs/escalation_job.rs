@@ -0,0 +1,29 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::application::{RequestContext, TaskUseCases, WorkspaceUseCases};
+use crate::database::with_workspace;
+use crate::domain::SystemActor;
+use crate::infrastructure::adapters::jobs::JobScheduler;
+
+/// Escalates stuck tasks in every workspace every `interval` as the `escalation` job, starting immediately
+pub fn spawn_escalation_job(scheduler: &JobScheduler, task_use_cases: Arc<TaskUseCases>, workspace_use_cases: Arc<WorkspaceUseCases>, interval: Duration) -> JoinHandle<()> {
+    scheduler.spawn(SystemActor::ESCALATION, interval, move || {
+        let task_use_cases = task_use_cases.clone();
+        let workspace_use_cases = workspace_use_cases.clone();
+        async move {
+            let actor = SystemActor::new(SystemActor::ESCALATION);
+            let workspace_ids = workspace_use_cases.workspace_ids().await.map_err(|error| error.to_string())?;
+            for workspace_id in workspace_ids {
+                let ctx = RequestContext::system(&actor).with_tenant(Some(workspace_id.clone()));
+                let escalated = with_workspace(Some(workspace_id.clone()), task_use_cases.escalate_stuck_tasks(&ctx, task_use_cases.now())).await
+                    .map_err(|error| error.to_string())?;
+                if !escalated.is_empty() {
+                    tracing::info!(actor = %actor.changed_by(), workspace_id = %workspace_id, "Escalated {} stuck task(s)", escalated.len());
+                }
+            }
+            Ok(())
+        }
+    })
+}
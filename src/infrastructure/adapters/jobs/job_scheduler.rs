@@ -0,0 +1,78 @@
+use chrono::{TimeDelta, Utc};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::domain::BackgroundJobRepository;
+
+/// How often schedulers look for due runs unless configured otherwise
+pub const DEFAULT_JOB_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Runs recurring jobs on the schedule kept in a [`BackgroundJobRepository`].
+///
+/// Each job's scheduler checks the shared state every poll interval, or every job interval if
+/// that is shorter, so pausing, resuming and run requests take effect within one poll. A run is
+/// claimed before it starts, so with several replicas only one of them runs it.
+#[derive(Clone)]
+pub struct JobScheduler {
+    jobs: Arc<dyn BackgroundJobRepository>,
+    poll_interval: Duration,
+}
+
+impl JobScheduler {
+    pub fn new(jobs: Arc<dyn BackgroundJobRepository>) -> Self {
+        Self { jobs, poll_interval: DEFAULT_JOB_POLL_INTERVAL }
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Registers `name` and calls `run` every `interval`, starting immediately.
+    ///
+    /// The next run is due `interval` after the previous one finished, or after it started if
+    /// the process died before it finished. Failures are logged, recorded as the job's last
+    /// error and retried on schedule; the job runs until aborted.
+    pub fn spawn<F, Fut>(&self, name: &'static str, interval: Duration, run: F) -> JoinHandle<()>
+    where
+        F: Fn() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), String>> + Send,
+    {
+        let jobs = self.jobs.clone();
+        let poll_interval = self.poll_interval.min(interval);
+        let step = TimeDelta::from_std(interval).unwrap_or(TimeDelta::MAX);
+
+        tokio::spawn(async move {
+            let mut registered = false;
+            loop {
+                if !registered {
+                    match jobs.register(name, interval, Utc::now()).await {
+                        Ok(_) => registered = true,
+                        Err(error) => tracing::error!("Registering background job {} failed: {}", name, error),
+                    }
+                }
+                if registered {
+                    let now = Utc::now();
+                    match jobs.claim_run(name, now, now.checked_add_signed(step).unwrap_or(now)).await {
+                        Ok(true) => {
+                            let outcome = run().await;
+                            if let Err(error) = &outcome {
+                                tracing::error!("Background job {} failed: {}", name, error);
+                            }
+                            let finished_at = Utc::now();
+                            let next_run_at = finished_at.checked_add_signed(step).unwrap_or(finished_at);
+                            if let Err(error) = jobs.finish_run(name, finished_at, outcome.err().as_deref(), next_run_at).await {
+                                tracing::error!("Recording the run of background job {} failed: {}", name, error);
+                            }
+                        }
+                        Ok(false) => {}
+                        Err(error) => tracing::error!("Checking background job {} failed: {}", name, error),
+                    }
+                }
+                tokio::time::sleep(poll_interval).await;
+            }
+        })
+    }
+}
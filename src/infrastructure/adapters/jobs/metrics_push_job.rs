@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::application::{AnalyticsGaugesDto, BudgetStats, RequestContext, TaskUseCases};
+use crate::domain::SystemActor;
+use crate::infrastructure::adapters::jobs::JobScheduler;
+use crate::infrastructure::adapters::repositories::{CacheStats, QueryCache};
+
+/// Grouping key the gauges are pushed under
+pub const PUSHGATEWAY_JOB: &str = "axum_postgres_rust";
+
+/// Pushes metrics in the Prometheus text format to a Pushgateway
+#[derive(Debug, Clone)]
+pub struct PushgatewayExporter {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl PushgatewayExporter {
+    /// `base_url` is the Pushgateway's address, e.g. `http://pushgateway:9091`
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: format!("{}/metrics/job/{}", base_url.trim_end_matches('/'), PUSHGATEWAY_JOB),
+        }
+    }
+
+    /// Replaces every metric previously pushed under this job
+    pub async fn push(&self, body: String) -> Result<(), String> {
+        let response = self.client
+            .put(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "text/plain; version=0.0.4")
+            .timeout(Duration::from_secs(10))
+            .body(body)
+            .send()
+            .await
+            .map_err(|error| format!("Pushgateway request failed: {}", error))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Pushgateway answered {}", response.status()));
+        }
+        Ok(())
+    }
+}
+
+/// Renders the gauges in the Prometheus text exposition format
+pub fn render_gauges(gauges: &AnalyticsGaugesDto) -> String {
+    let mut body = String::new();
+    write_gauge(&mut body, "tasks_open", "Tasks not yet completed or cancelled", &[(None, gauges.open_tasks)]);
+    let by_status: Vec<_> = gauges.open_tasks_by_status
+        .iter()
+        .map(|(status, count)| (Some(status.as_str()), *count))
+        .collect();
+    write_gauge(&mut body, "tasks_open_by_status", "Open tasks per status", &by_status);
+    write_gauge(&mut body, "tasks_stale", "Pending tasks past the stale task policy's age", &[(None, gauges.stale_tasks)]);
+    write_gauge(&mut body, "tasks_approval_backlog", "Tasks waiting in PendingReview", &[(None, gauges.approval_backlog)]);
+    body
+}
+
+/// Renders the query cache's hit and miss counters in the Prometheus text exposition format
+pub fn render_cache_stats(stats: CacheStats) -> String {
+    let mut body = String::new();
+    let _ = writeln!(body, "# HELP query_cache_requests_total Cached repository lookups by result");
+    let _ = writeln!(body, "# TYPE query_cache_requests_total counter");
+    let _ = writeln!(body, "query_cache_requests_total{{result=\"hit\"}} {}", stats.hits);
+    let _ = writeln!(body, "query_cache_requests_total{{result=\"miss\"}} {}", stats.misses);
+    body
+}
+
+/// Renders each use case's call and budget overrun counters in the Prometheus text exposition format
+pub fn render_budget_stats(stats: &BTreeMap<&'static str, BudgetStats>) -> String {
+    let mut body = String::new();
+    let _ = writeln!(body, "# HELP use_case_calls_total Use case calls by operation");
+    let _ = writeln!(body, "# TYPE use_case_calls_total counter");
+    for (operation, stats) in stats {
+        let _ = writeln!(body, "use_case_calls_total{{operation=\"{}\"}} {}", operation, stats.calls);
+    }
+    let _ = writeln!(body, "# HELP use_case_budget_exceeded_total Use case calls that took longer than their latency budget");
+    let _ = writeln!(body, "# TYPE use_case_budget_exceeded_total counter");
+    for (operation, stats) in stats {
+        let _ = writeln!(body, "use_case_budget_exceeded_total{{operation=\"{}\"}} {}", operation, stats.exceeded);
+    }
+    body
+}
+
+fn write_gauge(body: &mut String, name: &str, help: &str, samples: &[(Option<&str>, usize)]) {
+    let _ = writeln!(body, "# HELP {} {}", name, help);
+    let _ = writeln!(body, "# TYPE {} gauge", name);
+    for (status, value) in samples {
+        match status {
+            Some(status) => { let _ = writeln!(body, "{}{{status=\"{}\"}} {}", name, status, value); }
+            None => { let _ = writeln!(body, "{} {}", name, value); }
+        }
+    }
+}
+
+/// Pushes the analytics gauges, the use cases' latency budget counters, and the query cache's
+/// counters when caching is on, every `interval` as the `metrics-push` job, starting immediately
+pub fn spawn_metrics_push_job(
+    scheduler: &JobScheduler,
+    task_use_cases: Arc<TaskUseCases>,
+    exporter: PushgatewayExporter,
+    query_cache: Option<Arc<QueryCache>>,
+    interval: Duration,
+) -> JoinHandle<()> {
+    scheduler.spawn(SystemActor::METRICS_PUSH, interval, move || {
+        let (task_use_cases, exporter, query_cache) = (task_use_cases.clone(), exporter.clone(), query_cache.clone());
+        async move {
+            let actor = SystemActor::new(SystemActor::METRICS_PUSH);
+            let gauges = task_use_cases.get_analytics_gauges(&RequestContext::system(&actor), task_use_cases.now()).await
+                .map_err(|error| error.to_string())?;
+            let mut body = render_gauges(&gauges);
+            body.push_str(&render_budget_stats(&task_use_cases.latency_budgets().stats()));
+            if let Some(cache) = &query_cache {
+                body.push_str(&render_cache_stats(cache.stats()));
+            }
+            exporter.push(body).await
+        }
+    })
+}
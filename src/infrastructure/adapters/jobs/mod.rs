@@ -0,0 +1,17 @@
+pub mod job_scheduler;
+pub mod stale_task_job;
+pub mod auto_archive_job;
+pub mod escalation_job;
+pub mod metrics_push_job;
+pub mod audit_retention_job;
+pub mod automation_worker;
+pub mod task_clone_job;
+
+pub use job_scheduler::*;
+pub use stale_task_job::*;
+pub use auto_archive_job::*;
+pub use escalation_job::*;
+pub use metrics_push_job::*;
+pub use audit_retention_job::*;
+pub use automation_worker::*;
+pub use task_clone_job::*;
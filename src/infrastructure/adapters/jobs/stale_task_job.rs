@@ -0,0 +1,30 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::application::{RequestContext, TaskUseCases, WorkspaceUseCases};
+use crate::database::with_workspace;
+use crate::domain::SystemActor;
+use crate::infrastructure::adapters::jobs::JobScheduler;
+
+/// Runs the stale task sweep in every workspace every `interval` as the `stale-task-sweep` job,
+/// starting immediately
+pub fn spawn_stale_task_job(scheduler: &JobScheduler, task_use_cases: Arc<TaskUseCases>, workspace_use_cases: Arc<WorkspaceUseCases>, interval: Duration) -> JoinHandle<()> {
+    scheduler.spawn(SystemActor::STALE_TASK_SWEEP, interval, move || {
+        let task_use_cases = task_use_cases.clone();
+        let workspace_use_cases = workspace_use_cases.clone();
+        async move {
+            let actor = SystemActor::new(SystemActor::STALE_TASK_SWEEP);
+            let workspace_ids = workspace_use_cases.workspace_ids().await.map_err(|error| error.to_string())?;
+            for workspace_id in workspace_ids {
+                let ctx = RequestContext::system(&actor).with_tenant(Some(workspace_id.clone()));
+                let cancelled = with_workspace(Some(workspace_id.clone()), task_use_cases.cancel_stale_tasks(&ctx, task_use_cases.now())).await
+                    .map_err(|error| error.to_string())?;
+                if !cancelled.is_empty() {
+                    tracing::info!(actor = %actor.changed_by(), workspace_id = %workspace_id, "Cancelled {} stale pending task(s)", cancelled.len());
+                }
+            }
+            Ok(())
+        }
+    })
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+
+use crate::application::{TaskCloneDto, TaskCloneUseCases, UseCaseError};
+use crate::database::with_workspace;
+
+/// Runs a started clone in the background: reads the matching tasks in the source workspace,
+/// then copies them one by one into the target workspace, stopping at the first failure
+pub fn spawn_task_clone(task_clone_use_cases: Arc<TaskCloneUseCases>, clone: TaskCloneDto) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let result: Result<(), UseCaseError> = async {
+            let tasks = with_workspace(Some(clone.source_workspace.clone()), task_clone_use_cases.source_tasks(&clone.id)).await?;
+            for task in &tasks {
+                with_workspace(Some(clone.target_workspace.clone()), task_clone_use_cases.copy_task(&clone.id, task)).await?;
+            }
+            Ok(())
+        }.await;
+
+        match task_clone_use_cases.finish_clone(&clone.id, result) {
+            Ok(finished) => match finished.error {
+                Some(error) => tracing::warn!(clone_id = %clone.id, "Task clone failed after copying {} task(s): {}", finished.copied, error),
+                None => tracing::info!(clone_id = %clone.id, "Cloned {} task(s) from {} to {}", finished.copied, clone.source_workspace, clone.target_workspace),
+            },
+            Err(error) => tracing::warn!(clone_id = %clone.id, "Task clone disappeared before it finished: {}", error),
+        }
+    })
+}
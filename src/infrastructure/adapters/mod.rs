@@ -1,5 +1,15 @@
+pub mod approvals;
+pub mod events;
+pub mod importers;
+pub mod jobs;
 pub mod repositories;
+pub mod storage;
 pub mod web;
 
+pub use approvals::*;
+pub use events::*;
+pub use importers::*;
+pub use jobs::*;
 pub use repositories::*;
-pub use web::*;
\ No newline at end of file
+pub use storage::*;
+pub use web::*;
@@ -0,0 +1,350 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use moka::future::Cache;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::database::{current_workspace, in_request_transaction};
+use crate::domain::{
+    BoardColumn, Cohort, Rank, CohortBucket, CumulativeFlow, CycleTimeStats, PriorityCompletion, HistoryFilter, Pagination, PriorityChange, RepositoryError, SortOrder, StatusHistory,
+    StatusHistoryRepository, Task, TaskAnalytics, TaskFacets, TaskFilter, TaskId, TaskPatch, TaskRepository, TaskSort, TaskStatus, TransitionEdge,
+};
+
+/// Upper bound on entries kept by each of the query cache's maps
+const MAX_CACHED_ENTRIES: u64 = 10_000;
+
+/// Hit and miss counts since startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+#[derive(Debug, Clone)]
+enum CachedStat {
+    Count(usize),
+    CycleTimes(Arc<Vec<CycleTimeStats>>),
+}
+
+/// Read-through cache shared by the cached repositories.
+///
+/// Every write through either repository clears the aggregate entries, since they may depend on any task.
+/// Reads inside a request transaction bypass the cache so uncommitted rows are never cached. Entries
+/// remember the workspace they were read in and are only served to readers in that workspace.
+#[derive(Debug)]
+pub struct QueryCache {
+    tasks: Cache<i32, (Option<String>, Option<Task>)>,
+    stats: Cache<String, CachedStat>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl QueryCache {
+    /// Entries expire `ttl` after they were loaded
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            tasks: Cache::builder().max_capacity(MAX_CACHED_ENTRIES).time_to_live(ttl).build(),
+            stats: Cache::builder().max_capacity(MAX_CACHED_ENTRIES).time_to_live(ttl).build(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    async fn invalidate_task(&self, id: i32) {
+        self.tasks.invalidate(&id).await;
+        self.invalidate_stats();
+    }
+
+    fn invalidate_stats(&self) {
+        self.stats.invalidate_all();
+    }
+
+    fn record(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    async fn task(&self, id: i32) -> Option<Option<Task>> {
+        let workspace = current_workspace();
+        let cached = self.tasks.get(&id).await
+            .filter(|(cached_in, _)| *cached_in == workspace)
+            .map(|(_, task)| task);
+        self.record(cached.is_some());
+        cached
+    }
+
+    async fn insert_task(&self, id: i32, task: Option<Task>) {
+        self.tasks.insert(id, (current_workspace(), task)).await;
+    }
+
+    async fn stat(&self, key: &str) -> Option<CachedStat> {
+        let cached = self.stats.get(&stat_key(key)).await;
+        self.record(cached.is_some());
+        cached
+    }
+
+    async fn insert_stat(&self, key: &str, stat: CachedStat) {
+        self.stats.insert(stat_key(key), stat).await;
+    }
+}
+
+/// Aggregates differ per workspace, so their keys include the one they were computed in
+fn stat_key(key: &str) -> String {
+    match current_workspace() {
+        Some(workspace) => format!("{}@{}", key, workspace),
+        None => key.to_string(),
+    }
+}
+
+/// Caches `find_by_id` in front of another task repository, invalidating on writes
+pub struct CachedTaskRepository {
+    inner: Arc<dyn TaskRepository>,
+    cache: Arc<QueryCache>,
+}
+
+impl CachedTaskRepository {
+    pub fn new(inner: Arc<dyn TaskRepository>, cache: Arc<QueryCache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl TaskRepository for CachedTaskRepository {
+    async fn find_all(&self) -> Result<Vec<Task>, RepositoryError> {
+        self.inner.find_all().await
+    }
+
+    async fn find_matching(&self, filter: &TaskFilter, sort: TaskSort) -> Result<Vec<Task>, RepositoryError> {
+        self.inner.find_matching(filter, sort).await
+    }
+
+    async fn count_facets(&self, filter: &TaskFilter) -> Result<TaskFacets, RepositoryError> {
+        self.inner.count_facets(filter).await
+    }
+
+    async fn find_board(&self, filter: &TaskFilter, sort: TaskSort, column_size: usize) -> Result<Vec<BoardColumn>, RepositoryError> {
+        self.inner.find_board(filter, sort, column_size).await
+    }
+
+    async fn find_by_id(&self, id: TaskId) -> Result<Option<Task>, RepositoryError> {
+        if in_request_transaction().await {
+            return self.inner.find_by_id(id).await;
+        }
+        if let Some(task) = self.cache.task(id.value()).await {
+            return Ok(task);
+        }
+        let task = self.inner.find_by_id(id).await?;
+        self.cache.insert_task(id.value(), task.clone()).await;
+        Ok(task)
+    }
+
+    async fn find_by_priority(&self, priority: i32) -> Result<Vec<Task>, RepositoryError> {
+        self.inner.find_by_priority(priority).await
+    }
+
+    async fn find_by_status(&self, status: &TaskStatus) -> Result<Vec<Task>, RepositoryError> {
+        self.inner.find_by_status(status).await
+    }
+
+    async fn search_tasks(&self, query: &str) -> Result<Vec<Task>, RepositoryError> {
+        self.inner.search_tasks(query).await
+    }
+
+    async fn save(&self, task: &Task) -> Result<TaskId, RepositoryError> {
+        let id = self.inner.save(task).await?;
+        self.cache.invalidate_task(id.value()).await;
+        Ok(id)
+    }
+
+    async fn update(&self, task: &Task) -> Result<(), RepositoryError> {
+        let result = self.inner.update(task).await;
+        self.cache.invalidate_task(task.id.value()).await;
+        result
+    }
+
+    async fn update_fields(&self, id: TaskId, patch: &TaskPatch) -> Result<(), RepositoryError> {
+        let result = self.inner.update_fields(id, patch).await;
+        self.cache.invalidate_task(id.value()).await;
+        result
+    }
+
+    async fn set_rank(&self, id: TaskId, rank: &Rank) -> Result<(), RepositoryError> {
+        let result = self.inner.set_rank(id, rank).await;
+        self.cache.invalidate_task(id.value()).await;
+        result
+    }
+
+    async fn reprioritize(&self, filter: &TaskFilter, pairs: &[(i32, i32)], updated_at: DateTime<Utc>) -> Result<Vec<PriorityChange>, RepositoryError> {
+        let changes = self.inner.reprioritize(filter, pairs, updated_at).await?;
+        for change in &changes {
+            self.cache.invalidate_task(change.task_id.value()).await;
+        }
+        Ok(changes)
+    }
+
+    async fn save_with_history(&self, task: &Task, history: &StatusHistory) -> Result<TaskId, RepositoryError> {
+        let id = self.inner.save_with_history(task, history).await?;
+        self.cache.invalidate_task(id.value()).await;
+        Ok(id)
+    }
+
+    async fn update_with_history(&self, task: &Task, history: &StatusHistory) -> Result<(), RepositoryError> {
+        let result = self.inner.update_with_history(task, history).await;
+        self.cache.invalidate_task(task.id.value()).await;
+        result
+    }
+
+    async fn delete(&self, id: TaskId) -> Result<(), RepositoryError> {
+        let result = self.inner.delete(id).await;
+        self.cache.invalidate_task(id.value()).await;
+        result
+    }
+
+    async fn restore(&self, id: TaskId) -> Result<Task, RepositoryError> {
+        let result = self.inner.restore(id).await;
+        self.cache.invalidate_task(id.value()).await;
+        result
+    }
+}
+
+/// Caches history counts and completion time stats in front of another history repository
+pub struct CachedStatusHistoryRepository {
+    inner: Arc<dyn StatusHistoryRepository>,
+    cache: Arc<QueryCache>,
+}
+
+impl CachedStatusHistoryRepository {
+    pub fn new(inner: Arc<dyn StatusHistoryRepository>, cache: Arc<QueryCache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl StatusHistoryRepository for CachedStatusHistoryRepository {
+    async fn find_by_task_id(&self, task_id: i32) -> Result<Vec<StatusHistory>, RepositoryError> {
+        self.inner.find_by_task_id(task_id).await
+    }
+
+    async fn find_matching(
+        &self,
+        filter: &HistoryFilter,
+        order: SortOrder,
+        page: Pagination,
+    ) -> Result<Vec<StatusHistory>, RepositoryError> {
+        self.inner.find_matching(filter, order, page).await
+    }
+
+    async fn count_matching(&self, filter: &HistoryFilter) -> Result<usize, RepositoryError> {
+        if in_request_transaction().await {
+            return self.inner.count_matching(filter).await;
+        }
+        let key = format!("count_matching:{:?}", filter);
+        if let Some(CachedStat::Count(count)) = self.cache.stat(&key).await {
+            return Ok(count);
+        }
+        let count = self.inner.count_matching(filter).await?;
+        self.cache.insert_stat(&key, CachedStat::Count(count)).await;
+        Ok(count)
+    }
+
+    async fn find_by_date_range(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<StatusHistory>, RepositoryError> {
+        self.inner.find_by_date_range(start_date, end_date).await
+    }
+
+    async fn find_latest_by_task_id(&self, task_id: i32) -> Result<Option<StatusHistory>, RepositoryError> {
+        self.inner.find_latest_by_task_id(task_id).await
+    }
+
+    async fn get_task_analytics(&self, task_id: i32) -> Result<Option<TaskAnalytics>, RepositoryError> {
+        self.inner.get_task_analytics(task_id).await
+    }
+
+    async fn get_completion_analytics(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<TaskAnalytics>, RepositoryError> {
+        self.inner.get_completion_analytics(start_date, end_date).await
+    }
+
+    async fn get_transition_matrix(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<TransitionEdge>, RepositoryError> {
+        self.inner.get_transition_matrix(start_date, end_date).await
+    }
+
+    async fn get_creation_cohorts(
+        &self,
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<Cohort>, RepositoryError> {
+        self.inner.get_creation_cohorts(bucket, start_date, end_date).await
+    }
+
+    async fn get_cumulative_flow(
+        &self,
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<CumulativeFlow, RepositoryError> {
+        self.inner.get_cumulative_flow(bucket, start_date, end_date).await
+    }
+
+    async fn get_completion_time_stats(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<CycleTimeStats>, RepositoryError> {
+        if in_request_transaction().await {
+            return self.inner.get_completion_time_stats(start_date, end_date).await;
+        }
+        let key = format!("completion_time_stats:{}:{}", start_date.to_rfc3339(), end_date.to_rfc3339());
+        if let Some(CachedStat::CycleTimes(stats)) = self.cache.stat(&key).await {
+            return Ok(stats.as_ref().clone());
+        }
+        let stats = self.inner.get_completion_time_stats(start_date, end_date).await?;
+        self.cache.insert_stat(&key, CachedStat::CycleTimes(Arc::new(stats.clone()))).await;
+        Ok(stats)
+    }
+
+    async fn get_priority_completions(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<PriorityCompletion>, RepositoryError> {
+        self.inner.get_priority_completions(start_date, end_date).await
+    }
+
+    async fn save(&self, history: &StatusHistory) -> Result<String, RepositoryError> {
+        let result = self.inner.save(history).await;
+        self.cache.invalidate_stats();
+        result
+    }
+
+    async fn delete(&self, id: String) -> Result<(), RepositoryError> {
+        let result = self.inner.delete(id).await;
+        self.cache.invalidate_stats();
+        result
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Result<usize, RepositoryError> {
+        let result = self.inner.delete_many(ids).await;
+        self.cache.invalidate_stats();
+        result
+    }
+}
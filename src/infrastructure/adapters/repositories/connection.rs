@@ -0,0 +1,65 @@
+use sqlx::{PgConnection, PgPool};
+use std::ops::{Deref, DerefMut};
+use super::database_error::database_error;
+use crate::database::{connection, routed_pool, DbConnection};
+use crate::domain::RepositoryError;
+
+/// The request's open transaction if there is one, otherwise a pooled connection
+pub(crate) async fn acquire(pool: &PgPool) -> Result<DbConnection, RepositoryError> {
+    connection(pool).await.map_err(database_error)
+}
+
+/// A connection whose statement is cancelled on the server if the guard is dropped before [`finish`](Self::finish).
+///
+/// Dropping a query future only stops sqlx from waiting; Postgres keeps running the statement.
+/// Report queries hold one of these so an abandoned request frees its backend as well.
+pub(crate) struct CancellableConnection {
+    connection: Option<DbConnection>,
+    pool: PgPool,
+    backend_pid: i32,
+}
+
+impl CancellableConnection {
+    pub(crate) async fn acquire(pool: &PgPool) -> Result<Self, RepositoryError> {
+        let mut connection = acquire(pool).await?;
+        let backend_pid = sqlx::query_scalar::<_, i32>("SELECT pg_backend_pid()")
+            .fetch_one(&mut *connection)
+            .await
+            .map_err(database_error)?;
+        // The cancel has to reach the same cluster as the statement
+        Ok(Self { connection: Some(connection), pool: routed_pool(pool), backend_pid })
+    }
+
+    /// Releases the connection normally once its statement has completed
+    pub(crate) fn finish(mut self) {
+        self.connection.take();
+    }
+}
+
+impl Deref for CancellableConnection {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &PgConnection {
+        self.connection.as_deref().expect("connection is only taken when finished")
+    }
+}
+
+impl DerefMut for CancellableConnection {
+    fn deref_mut(&mut self) -> &mut PgConnection {
+        self.connection.as_deref_mut().expect("connection is only taken when finished")
+    }
+}
+
+impl Drop for CancellableConnection {
+    fn drop(&mut self) {
+        let Some(connection) = self.connection.take() else { return };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else { return };
+        let pool = self.pool.clone();
+        let backend_pid = self.backend_pid;
+        // The connection is held until the cancel lands so it cannot be reused for another statement first
+        runtime.spawn(async move {
+            let _ = sqlx::query("SELECT pg_cancel_backend($1)").bind(backend_pid).execute(&pool).await;
+            drop(connection);
+        });
+    }
+}
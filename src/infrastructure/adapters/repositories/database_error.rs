@@ -0,0 +1,178 @@
+use sqlx::error::{DatabaseError, ErrorKind};
+use sqlx::postgres::PgDatabaseError;
+use crate::database::redact_connection_string;
+use crate::domain::RepositoryError;
+
+/// Converts a sqlx error into a repository error without leaking connection credentials.
+///
+/// Constraint violations become typed errors naming the offending field, so callers can answer
+/// them as client errors instead of opaque database failures.
+pub(crate) fn database_error(error: sqlx::Error) -> RepositoryError {
+    if let Some(database) = error.as_database_error() {
+        let field = || violated_field(database);
+        match database.kind() {
+            ErrorKind::UniqueViolation => {
+                let field = field();
+                let message = format!("A record with this {} already exists", field);
+                return RepositoryError::Conflict { field, message };
+            }
+            ErrorKind::ForeignKeyViolation => {
+                let field = field();
+                let message = format!("The referenced {} does not exist", field);
+                return RepositoryError::ForeignKeyViolation { field, message };
+            }
+            ErrorKind::NotNullViolation | ErrorKind::CheckViolation => {
+                return RepositoryError::ValidationError(format!("Invalid value for {}", field()));
+            }
+            _ => {}
+        }
+    }
+    RepositoryError::DatabaseError(redact_connection_string(&error.to_string()))
+}
+
+/// The API field behind a violated constraint.
+///
+/// Known constraints map to the request field a client sent; others fall back to the column
+/// Postgres reports, then to the constraint name. SQLite names the constraint or key columns
+/// only in its message. Values are never included, since a unique value such as an API key
+/// hash must not be echoed back.
+fn violated_field(error: &dyn DatabaseError) -> String {
+    let constraint = error.constraint().map(str::to_string).or_else(|| sqlite_constraint(error.message()));
+    if let Some(field) = constraint.as_deref().and_then(constraint_field) {
+        return field.to_string();
+    }
+    let postgres = error.try_downcast_ref::<PgDatabaseError>();
+    postgres
+        .and_then(|postgres| postgres.column().map(str::to_string).or_else(|| postgres.detail().and_then(key_columns)))
+        .or(constraint)
+        .unwrap_or_else(|| "value".to_string())
+}
+
+fn constraint_field(constraint: &str) -> Option<&'static str> {
+    match constraint {
+        "task_dependencies_pkey" | "task_dependencies_depends_on_id_fkey" | "check_no_self_dependency" => Some("depends_on"),
+        "task_dependencies_task_id_fkey" | "status_history_task_id_fkey" => Some("task_id"),
+        "api_keys_key_hash_key" => Some("key"),
+        "check_description_length" => Some("description"),
+        "check_status" => Some("status"),
+        _ => None,
+    }
+}
+
+/// The columns in a detail such as `Key (task_id, depends_on_id)=(1, 2) already exists.`
+fn key_columns(detail: &str) -> Option<String> {
+    let columns = detail.strip_prefix("Key (")?.split_once(")=")?.0;
+    Some(columns.to_string())
+}
+
+/// The constraint or key columns in a SQLite message such as `CHECK constraint failed: check_status`
+/// or `UNIQUE constraint failed: api_keys.key_hash`; foreign key messages name neither
+fn sqlite_constraint(message: &str) -> Option<String> {
+    let failed = message.split_once("constraint failed: ")?.1;
+    let columns: Vec<&str> = failed.split(", ").map(|column| column.rsplit_once('.').map_or(column, |(_, column)| column)).collect();
+    Some(columns.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::error::Error as StdError;
+    use std::fmt;
+
+    /// `ErrorKind` is not `Clone`, so the fake keeps a function that builds it
+    #[derive(Debug)]
+    struct FakeDatabaseError {
+        kind: fn() -> ErrorKind,
+        constraint: Option<&'static str>,
+    }
+
+    impl fmt::Display for FakeDatabaseError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "violates constraint")
+        }
+    }
+
+    impl StdError for FakeDatabaseError {}
+
+    impl DatabaseError for FakeDatabaseError {
+        fn message(&self) -> &str {
+            "violates constraint"
+        }
+
+        fn constraint(&self) -> Option<&str> {
+            self.constraint
+        }
+
+        fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn StdError + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn StdError + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            (self.kind)()
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            None
+        }
+    }
+
+    fn violation(kind: fn() -> ErrorKind, constraint: Option<&'static str>) -> sqlx::Error {
+        sqlx::Error::Database(Box::new(FakeDatabaseError { kind, constraint }))
+    }
+
+    #[test]
+    fn test_constraint_violations_become_typed_errors_naming_the_field() {
+        match database_error(violation(|| ErrorKind::UniqueViolation, Some("api_keys_key_hash_key"))) {
+            RepositoryError::Conflict { field, message } => {
+                assert_eq!(field, "key");
+                assert_eq!(message, "A record with this key already exists");
+            }
+            other => panic!("expected a conflict, got {:?}", other),
+        }
+        match database_error(violation(|| ErrorKind::ForeignKeyViolation, Some("task_dependencies_depends_on_id_fkey"))) {
+            RepositoryError::ForeignKeyViolation { field, .. } => assert_eq!(field, "depends_on"),
+            other => panic!("expected a foreign key violation, got {:?}", other),
+        }
+        match database_error(violation(|| ErrorKind::CheckViolation, Some("check_description_length"))) {
+            RepositoryError::ValidationError(message) => assert_eq!(message, "Invalid value for description"),
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unknown_constraints_fall_back_to_the_constraint_name() {
+        match database_error(violation(|| ErrorKind::UniqueViolation, Some("tasks_name_key"))) {
+            RepositoryError::Conflict { field, .. } => assert_eq!(field, "tasks_name_key"),
+            other => panic!("expected a conflict, got {:?}", other),
+        }
+        assert!(matches!(
+            database_error(violation(|| ErrorKind::Other, None)),
+            RepositoryError::DatabaseError(_)
+        ));
+    }
+
+    #[test]
+    fn test_sqlite_messages_name_the_constraint_or_key_columns() {
+        assert_eq!(sqlite_constraint("CHECK constraint failed: check_status").as_deref(), Some("check_status"));
+        assert_eq!(
+            sqlite_constraint("UNIQUE constraint failed: task_dependencies.task_id, task_dependencies.depends_on_id").as_deref(),
+            Some("task_id, depends_on_id")
+        );
+        assert_eq!(sqlite_constraint("FOREIGN KEY constraint failed"), None);
+    }
+
+    #[test]
+    fn test_key_columns_leave_out_the_values() {
+        assert_eq!(key_columns("Key (key_hash)=(abc) already exists.").as_deref(), Some("key_hash"));
+        assert_eq!(key_columns("Failing row contains (1)."), None);
+    }
+}
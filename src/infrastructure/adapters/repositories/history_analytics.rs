@@ -0,0 +1,231 @@
+//! History analytics computed in Rust, for adapters whose database cannot run the Postgres
+//! report queries. Each function gives the same answer as the matching Postgres query.
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::domain::{Cohort, CohortBucket, CumulativeFlow, CycleTimeStats, PriorityCompletion, StatusHistory, TaskStatus, TransitionEdge};
+
+/// Transitions between one pair of statuses and the dwell time before each
+struct Edge<'a> {
+    from_status: &'a TaskStatus,
+    to_status: &'a TaskStatus,
+    count: usize,
+    dwell_seconds: Vec<f64>,
+}
+
+/// Counts transitions made within the period per from/to pair, with their median dwell time.
+///
+/// Dwell time is measured from the task's previous transition, which may fall before the period,
+/// so `history` must hold every entry of the tasks involved. Entries that keep the task in its
+/// status are not transitions and are skipped.
+pub(crate) fn transition_matrix<'a>(
+    history: impl IntoIterator<Item = &'a StatusHistory>,
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+) -> Vec<TransitionEdge> {
+    let mut by_task: BTreeMap<i32, Vec<&StatusHistory>> = BTreeMap::new();
+    for entry in history.into_iter().filter(|entry| !entry.is_self_transition()) {
+        by_task.entry(entry.task_id).or_default().push(entry);
+    }
+
+    let mut edges: BTreeMap<(&str, &str), Edge> = BTreeMap::new();
+    for entries in by_task.values_mut() {
+        entries.sort_by(|a, b| a.changed_at.cmp(&b.changed_at).then_with(|| a.id.cmp(&b.id)));
+        let mut previous: Option<DateTime<Utc>> = None;
+        for entry in entries.iter() {
+            let dwell = previous.map(|previous| (entry.changed_at - previous).num_milliseconds() as f64 / 1000.0);
+            previous = Some(entry.changed_at);
+            let Some(from_status) = &entry.from_status else {
+                continue;
+            };
+            if entry.changed_at < start_date || entry.changed_at > end_date {
+                continue;
+            }
+            let edge = edges
+                .entry((from_status.as_str(), entry.to_status.as_str()))
+                .or_insert_with(|| Edge { from_status, to_status: &entry.to_status, count: 0, dwell_seconds: vec![] });
+            edge.count += 1;
+            edge.dwell_seconds.extend(dwell);
+        }
+    }
+
+    edges
+        .into_values()
+        .map(|mut edge| {
+            edge.dwell_seconds.sort_by(f64::total_cmp);
+            TransitionEdge {
+                from_status: edge.from_status.clone(),
+                to_status: edge.to_status.clone(),
+                count: edge.count,
+                median_dwell_time: (!edge.dwell_seconds.is_empty()).then(|| seconds(percentile(&edge.dwell_seconds, 0.5))),
+            }
+        })
+        .collect()
+}
+
+/// Groups tasks, given as their creation and first completion times, into creation buckets
+pub(crate) fn creation_cohorts(
+    bucket: CohortBucket,
+    tasks: impl IntoIterator<Item = (DateTime<Utc>, Option<DateTime<Utc>>)>,
+) -> Vec<Cohort> {
+    let mut cohorts: BTreeMap<DateTime<Utc>, Cohort> = BTreeMap::new();
+    for (created_at, completed_at) in tasks {
+        let start = bucket.start_of(created_at);
+        let cohort = cohorts.entry(start).or_insert_with(|| Cohort { start, tasks_created: 0, completions_by_period: Default::default() });
+        cohort.tasks_created += 1;
+        if let Some(completed_at) = completed_at {
+            let period = bucket.periods_between(start, bucket.start_of(completed_at));
+            *cohort.completions_by_period.entry(period).or_insert(0) += 1;
+        }
+    }
+    cohorts.into_values().collect()
+}
+
+/// Counts tasks per status at the close of each bucket, from the history of every task and the
+/// deletion time of each, `None` while live. Entries of tasks missing from `deleted_at` are ignored.
+pub(crate) fn cumulative_flow<'a>(
+    bucket: CohortBucket,
+    start_date: DateTime<Utc>,
+    end_date: DateTime<Utc>,
+    history: impl IntoIterator<Item = &'a StatusHistory>,
+    deleted_at: &HashMap<i32, Option<DateTime<Utc>>>,
+) -> CumulativeFlow {
+    let mut by_task: BTreeMap<i32, Vec<&StatusHistory>> = BTreeMap::new();
+    for entry in history.into_iter().filter(|entry| deleted_at.contains_key(&entry.task_id)) {
+        by_task.entry(entry.task_id).or_default().push(entry);
+    }
+    for entries in by_task.values_mut() {
+        entries.sort_by(|a, b| a.changed_at.cmp(&b.changed_at).then_with(|| a.id.cmp(&b.id)));
+    }
+
+    let mut counts: BTreeMap<(DateTime<Utc>, &str), (&TaskStatus, usize)> = BTreeMap::new();
+    let mut start = bucket.start_of(start_date);
+    while start <= end_date {
+        let close = bucket.next_start(start);
+        for (task_id, entries) in &by_task {
+            if deleted_at[task_id].is_some_and(|deleted_at| deleted_at < close) {
+                continue;
+            }
+            if let Some(latest) = entries.iter().take_while(|entry| entry.changed_at < close).last() {
+                counts.entry((start, latest.to_status.as_str())).or_insert((&latest.to_status, 0)).1 += 1;
+            }
+        }
+        start = close;
+    }
+
+    let counts = counts.into_iter().map(|((start, _), (status, count))| (start, status.clone(), count)).collect();
+    CumulativeFlow::from_counts(bucket, start_date, end_date, counts)
+}
+
+/// Average and percentile completion times per priority, from `(priority, seconds)` cycle times
+pub(crate) fn completion_time_stats(cycle_times: impl IntoIterator<Item = (i32, f64)>) -> Vec<CycleTimeStats> {
+    let mut by_priority: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
+    for (priority, cycle_seconds) in cycle_times {
+        by_priority.entry(priority).or_default().push(cycle_seconds);
+    }
+
+    by_priority
+        .into_iter()
+        .map(|(priority, mut cycle_seconds)| {
+            cycle_seconds.sort_by(f64::total_cmp);
+            CycleTimeStats {
+                priority,
+                average: seconds(cycle_seconds.iter().sum::<f64>() / cycle_seconds.len() as f64),
+                p50: seconds(percentile(&cycle_seconds, 0.5)),
+                p85: seconds(percentile(&cycle_seconds, 0.85)),
+                p95: seconds(percentile(&cycle_seconds, 0.95)),
+            }
+        })
+        .collect()
+}
+
+/// Task count and average completion time per priority, from `(priority, seconds)` cycle times
+pub(crate) fn priority_completions(cycle_times: impl IntoIterator<Item = (i32, f64)>) -> Vec<PriorityCompletion> {
+    let mut by_priority: BTreeMap<i32, (usize, f64)> = BTreeMap::new();
+    for (priority, cycle_seconds) in cycle_times {
+        let (count, total) = by_priority.entry(priority).or_default();
+        *count += 1;
+        *total += cycle_seconds;
+    }
+
+    by_priority
+        .into_iter()
+        .map(|(priority, (task_count, total))| PriorityCompletion { priority, task_count, average: seconds(total / task_count as f64) })
+        .collect()
+}
+
+/// Like `PERCENTILE_CONT`: interpolates between the two closest values of a sorted slice
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    let position = fraction * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+fn seconds(value: f64) -> Duration {
+    Duration::seconds(value as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_percentile_interpolates_like_percentile_cont() {
+        let sorted = [10.0, 20.0, 30.0, 40.0];
+
+        assert_eq!(percentile(&sorted, 0.5), 25.0);
+        assert_eq!(percentile(&sorted, 0.0), 10.0);
+        assert_eq!(percentile(&[7.0], 0.95), 7.0);
+    }
+
+    #[test]
+    fn test_transition_matrix_skips_entries_that_keep_the_status() {
+        use crate::domain::UserRole;
+        let at = |hour: u32| Utc.with_ymd_and_hms(2025, 5, 12, hour, 0, 0).unwrap();
+        let entry = |id: &str, from_status: Option<TaskStatus>, to_status: TaskStatus, changed_at: DateTime<Utc>| {
+            StatusHistory::new(id.to_string(), 1, from_status, to_status, changed_at, "u1".to_string(), None, UserRole::Admin)
+        };
+        let history = [
+            entry("a1", None, TaskStatus::Pending, at(1)),
+            entry("a2", Some(TaskStatus::Pending), TaskStatus::PendingReview, at(2)),
+            entry("a3", Some(TaskStatus::PendingReview), TaskStatus::PendingReview, at(8)),
+            entry("a4", Some(TaskStatus::PendingReview), TaskStatus::Completed, at(10)),
+        ];
+
+        let edges = transition_matrix(&history, at(0), at(12));
+
+        let summary: Vec<_> = edges.iter().map(|edge| (edge.from_status.clone(), edge.to_status.clone(), edge.count, edge.median_dwell_time)).collect();
+        assert_eq!(summary, vec![
+            (TaskStatus::Pending, TaskStatus::PendingReview, 1, Some(Duration::hours(1))),
+            (TaskStatus::PendingReview, TaskStatus::Completed, 1, Some(Duration::hours(8))),
+        ]);
+    }
+
+    #[test]
+    fn test_cumulative_flow_counts_each_task_under_its_status_at_each_close() {
+        use crate::domain::UserRole;
+        let day = |day: u32, hour: u32| Utc.with_ymd_and_hms(2025, 5, day, hour, 0, 0).unwrap();
+        let entry = |id: &str, task_id: i32, to_status: TaskStatus, changed_at: DateTime<Utc>| {
+            StatusHistory::new(id.to_string(), task_id, None, to_status, changed_at, "u1".to_string(), None, UserRole::Admin)
+        };
+        let history = [
+            entry("a1", 1, TaskStatus::Pending, day(12, 9)),
+            entry("a2", 1, TaskStatus::InProgress, day(13, 9)),
+            entry("b1", 2, TaskStatus::Pending, day(12, 10)),
+            entry("c1", 3, TaskStatus::Pending, day(13, 23)),
+            entry("d1", 4, TaskStatus::Pending, day(12, 11)),
+        ];
+        let deleted_at = HashMap::from([(1, None), (2, Some(day(13, 12))), (3, None)]);
+
+        let flow = cumulative_flow(CohortBucket::Day, day(12, 0), day(14, 0), &history, &deleted_at);
+        let series: Vec<_> = flow.buckets
+            .iter()
+            .map(|bucket| bucket.statuses.iter().map(|facet| facet.count).collect::<Vec<_>>())
+            .collect();
+        assert_eq!(flow.statuses, vec![TaskStatus::Pending, TaskStatus::InProgress]);
+        assert_eq!(series, vec![vec![2, 0], vec![1, 1], vec![1, 1]]);
+    }
+}
@@ -0,0 +1,684 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::database::{current_workspace, workspace_for_new_tasks};
+use crate::domain::{
+    BoardColumn, Cohort, Rank, CohortBucket, CumulativeFlow, CustomStatus, CustomStatusRepository, CycleTimeStats, HistoryFilter, Pagination, PriorityChange, PriorityCompletion, RepositoryError, SortOrder, StatisticsQuery, StatusHistory,
+    StatusHistoryRepository, Task, TaskAnalytics, TaskFacets, TaskFilter, TaskId, TaskPatch, TaskRepository, TaskSort, TaskSortField, TaskStatistics,
+    TaskStatisticsRepository, TaskStatus,
+    TransitionEdge, WorkflowDefinition, WorkflowRepository, Workspace, WorkspaceRepository, DEFAULT_WORKSPACE_ID,
+};
+use super::history_analytics::{completion_time_stats, creation_cohorts, cumulative_flow, priority_completions, transition_matrix};
+
+/// Tasks, status history and workspaces kept together, like the tables they stand in for
+#[derive(Debug)]
+struct Store {
+    tasks: BTreeMap<i32, Task>,
+    history: Vec<StatusHistory>,
+    last_task_id: i32,
+    /// The workspace of each task, and so of its history
+    task_workspaces: HashMap<i32, String>,
+    workspaces: BTreeMap<String, Workspace>,
+    workflows: BTreeMap<String, WorkflowDefinition>,
+    statuses: Vec<CustomStatus>,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        let default = Workspace {
+            id: DEFAULT_WORKSPACE_ID.to_string(),
+            name: "Default".to_string(),
+            created_by: "system:migration".to_string(),
+            created_at: Utc::now(),
+        };
+        Self {
+            tasks: BTreeMap::new(),
+            history: Vec::new(),
+            last_task_id: 0,
+            task_workspaces: HashMap::new(),
+            workspaces: BTreeMap::from([(default.id.clone(), default)]),
+            workflows: BTreeMap::new(),
+            statuses: Vec::new(),
+        }
+    }
+}
+
+impl Store {
+    /// Whether the task belongs to the current workspace; every task does outside a request
+    fn in_workspace(&self, task_id: i32) -> bool {
+        current_workspace().is_none_or(|workspace| self.task_workspaces.get(&task_id) == Some(&workspace))
+    }
+
+    /// The current workspace's tasks
+    fn tasks(&self) -> impl Iterator<Item = &Task> {
+        self.tasks.values().filter(|task| self.in_workspace(task.id.value()))
+    }
+
+    /// The current workspace's history
+    fn history(&self) -> Vec<StatusHistory> {
+        self.history.iter().filter(|entry| self.in_workspace(entry.task_id)).cloned().collect()
+    }
+
+    fn live_task_mut(&mut self, id: TaskId) -> Result<&mut Task, RepositoryError> {
+        let in_workspace = self.in_workspace(id.value());
+        self.tasks
+            .get_mut(&id.value())
+            .filter(|task| in_workspace && !task.is_deleted())
+            .ok_or_else(|| RepositoryError::NotFound(format!("Task with id {} not found", id.value())))
+    }
+
+    fn insert_task(&mut self, task: &Task) -> TaskId {
+        self.last_task_id += 1;
+        let id = TaskId::new(self.last_task_id);
+        self.tasks.insert(id.value(), Task { id, ..task.clone() });
+        self.task_workspaces.insert(id.value(), workspace_for_new_tasks());
+        id
+    }
+
+    fn update_task(&mut self, task: &Task) -> Result<(), RepositoryError> {
+        let stored = self.live_task_mut(task.id)?;
+        *stored = Task { deleted_at: None, created_at: stored.created_at, created_by: stored.created_by.clone(), ..task.clone() };
+        Ok(())
+    }
+
+    /// Enforces the same constraints as the `status_history` table
+    fn check_history(&self, history: &StatusHistory) -> Result<(), RepositoryError> {
+        if self.history.iter().any(|entry| entry.id == history.id) {
+            return Err(RepositoryError::ValidationError(format!(
+                "Status history record with ID {} already exists. Audit records are immutable.",
+                history.id
+            )));
+        }
+        if !self.tasks.contains_key(&history.task_id) {
+            return Err(RepositoryError::ForeignKeyViolation {
+                field: "task_id".to_string(),
+                message: "The referenced task_id does not exist".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn history_of(&self, task_id: i32) -> Vec<StatusHistory> {
+        if !self.in_workspace(task_id) {
+            return Vec::new();
+        }
+        let mut history: Vec<StatusHistory> = self.history.iter().filter(|entry| entry.task_id == task_id).cloned().collect();
+        history.sort_by_key(|entry| entry.changed_at);
+        history
+    }
+
+    /// When each task first reached Completed; completing again after a reopen doesn't count
+    fn first_completions(&self) -> HashMap<i32, DateTime<Utc>> {
+        let mut completions: HashMap<i32, DateTime<Utc>> = HashMap::new();
+        for entry in self.history.iter().filter(|entry| entry.to_status == TaskStatus::Completed && self.in_workspace(entry.task_id)) {
+            completions
+                .entry(entry.task_id)
+                .and_modify(|completed_at| *completed_at = (*completed_at).min(entry.changed_at))
+                .or_insert(entry.changed_at);
+        }
+        completions
+    }
+}
+
+fn matches_filter(task: &Task, filter: &TaskFilter) -> bool {
+    (filter.include_deleted || !task.is_deleted())
+        && task.is_archived() == filter.archived
+        && filter.priority.is_none_or(|priority| task.priority == Some(priority))
+        && filter.status.as_ref().is_none_or(|status| &task.status == status)
+        && filter.expression.as_ref().is_none_or(|expression| expression.matches(task))
+}
+
+/// Orders like `ORDER BY <field> <order> NULLS LAST, task_id`
+fn compare(a: &Task, b: &Task, sort: TaskSort) -> std::cmp::Ordering {
+    let ordering = match sort.field {
+        TaskSortField::Id => a.id.value().cmp(&b.id.value()),
+        TaskSortField::Priority => match (a.priority, b.priority) {
+            (Some(first), Some(second)) => first.cmp(&second),
+            // Missing priorities sort last in either direction
+            (first, second) => return first.is_none().cmp(&second.is_none()).then(a.id.value().cmp(&b.id.value())),
+        },
+        TaskSortField::CreatedAt => a.created_at.cmp(&b.created_at),
+        TaskSortField::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+        TaskSortField::Name => a.name.cmp(&b.name),
+        TaskSortField::Status => a.status.as_str().cmp(b.status.as_str()),
+        TaskSortField::Rank => a.rank().cmp(&b.rank()),
+    };
+    let ordering = match sort.order {
+        SortOrder::Asc => ordering,
+        SortOrder::Desc => ordering.reverse(),
+    };
+    ordering.then(a.id.value().cmp(&b.id.value()))
+}
+
+/// Whether every word of the query appears, ignoring case, in `text`
+fn contains_all_words(text: &str, words: &[String]) -> bool {
+    let text = text.to_lowercase();
+    words.iter().all(|word| text.contains(word.as_str()))
+}
+
+/// Task repository that keeps everything in process memory, for tests and local experiments.
+///
+/// It behaves like [`PostgresTaskRepository`](super::PostgresTaskRepository) where the
+/// application can tell: ids are assigned on save, deletes are soft, writes to a missing or
+/// deleted task fail with `NotFound`, and a task and its history entry are written together.
+/// Search is a case-insensitive match of every query word against the name and description,
+/// or against a single status change comment, rather than full-text ranking; results are in
+/// id order. Clones share the same tasks.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTaskRepository {
+    store: Arc<RwLock<Store>>,
+}
+
+impl InMemoryTaskRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A status history repository over the history written by this repository
+    pub fn history_repository(&self) -> InMemoryStatusHistoryRepository {
+        InMemoryStatusHistoryRepository { store: self.store.clone() }
+    }
+
+    /// A workspace repository that sees which workspaces this repository's tasks belong to
+    pub fn workspace_repository(&self) -> InMemoryWorkspaceRepository {
+        InMemoryWorkspaceRepository { store: self.store.clone() }
+    }
+
+    /// A workflow repository for this repository's workspaces
+    pub fn workflow_repository(&self) -> InMemoryWorkflowRepository {
+        InMemoryWorkflowRepository { store: self.store.clone() }
+    }
+
+    /// A custom status repository that sees which statuses this repository's tasks are in
+    pub fn custom_status_repository(&self) -> InMemoryCustomStatusRepository {
+        InMemoryCustomStatusRepository { store: self.store.clone() }
+    }
+
+    /// A statistics repository over this repository's tasks and history
+    pub fn statistics_repository(&self) -> InMemoryTaskStatisticsRepository {
+        InMemoryTaskStatisticsRepository { store: self.store.clone() }
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Store> {
+        self.store.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Store> {
+        self.store.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn find_live(&self, predicate: impl Fn(&Task) -> bool) -> Vec<Task> {
+        self.read().tasks().filter(|task| !task.is_deleted() && predicate(task)).cloned().collect()
+    }
+}
+
+#[async_trait]
+impl TaskRepository for InMemoryTaskRepository {
+    async fn find_all(&self) -> Result<Vec<Task>, RepositoryError> {
+        Ok(self.find_live(|_| true))
+    }
+
+    async fn find_matching(&self, filter: &TaskFilter, sort: TaskSort) -> Result<Vec<Task>, RepositoryError> {
+        let mut tasks: Vec<Task> = self.read().tasks().filter(|task| matches_filter(task, filter)).cloned().collect();
+        tasks.sort_by(|a, b| compare(a, b, sort));
+        Ok(tasks)
+    }
+
+    async fn count_facets(&self, filter: &TaskFilter) -> Result<TaskFacets, RepositoryError> {
+        let tasks: Vec<Task> = self.read().tasks().filter(|task| matches_filter(task, filter)).cloned().collect();
+        Ok(TaskFacets::of(&tasks))
+    }
+
+    async fn find_board(&self, filter: &TaskFilter, sort: TaskSort, column_size: usize) -> Result<Vec<BoardColumn>, RepositoryError> {
+        let tasks = self.find_matching(filter, sort).await?;
+        Ok(BoardColumn::group(tasks, column_size))
+    }
+
+    async fn find_by_id(&self, id: TaskId) -> Result<Option<Task>, RepositoryError> {
+        Ok(self.read().tasks().find(|task| task.id == id && !task.is_deleted()).cloned())
+    }
+
+    async fn find_by_priority(&self, priority: i32) -> Result<Vec<Task>, RepositoryError> {
+        Ok(self.find_live(|task| task.priority == Some(priority)))
+    }
+
+    async fn find_by_status(&self, status: &TaskStatus) -> Result<Vec<Task>, RepositoryError> {
+        Ok(self.find_live(|task| &task.status == status))
+    }
+
+    async fn search_tasks(&self, query: &str) -> Result<Vec<Task>, RepositoryError> {
+        let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        if words.is_empty() {
+            return Ok(vec![]);
+        }
+        let store = self.read();
+        Ok(store
+            .tasks()
+            .filter(|task| !task.is_deleted())
+            .filter(|task| {
+                let text = format!("{} {}", task.name, task.description.as_deref().unwrap_or_default());
+                contains_all_words(&text, &words)
+                    || store.history.iter().any(|entry| {
+                        entry.task_id == task.id.value() && entry.comment.as_deref().is_some_and(|comment| contains_all_words(comment, &words))
+                    })
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn save(&self, task: &Task) -> Result<TaskId, RepositoryError> {
+        Ok(self.write().insert_task(task))
+    }
+
+    async fn update(&self, task: &Task) -> Result<(), RepositoryError> {
+        self.write().update_task(task)
+    }
+
+    async fn update_fields(&self, id: TaskId, patch: &TaskPatch) -> Result<(), RepositoryError> {
+        patch.apply_to(self.write().live_task_mut(id)?);
+        Ok(())
+    }
+
+    async fn set_rank(&self, id: TaskId, rank: &Rank) -> Result<(), RepositoryError> {
+        self.write().live_task_mut(id)?.rank = Some(rank.clone());
+        Ok(())
+    }
+
+    async fn reprioritize(&self, filter: &TaskFilter, pairs: &[(i32, i32)], updated_at: DateTime<Utc>) -> Result<Vec<PriorityChange>, RepositoryError> {
+        let targets: HashMap<i32, i32> = pairs.iter().copied().collect();
+        let filter = TaskFilter { include_deleted: false, ..filter.clone() };
+        let mut store = self.write();
+        let in_workspace: HashSet<i32> = store.tasks().map(|task| task.id.value()).collect();
+        let mut changes = Vec::new();
+        for task in store.tasks.values_mut().filter(|task| in_workspace.contains(&task.id.value()) && matches_filter(task, &filter)) {
+            let Some((from, &to)) = task.priority.and_then(|from| targets.get(&from).map(|to| (from, to))) else {
+                continue;
+            };
+            task.priority = Some(to);
+            task.updated_at = updated_at;
+            changes.push(PriorityChange { task_id: task.id, from, to });
+        }
+        Ok(changes)
+    }
+
+    async fn save_with_history(&self, task: &Task, history: &StatusHistory) -> Result<TaskId, RepositoryError> {
+        let mut store = self.write();
+        if store.history.iter().any(|entry| entry.id == history.id) {
+            return Err(RepositoryError::ValidationError(format!(
+                "Status history record with ID {} already exists. Audit records are immutable.",
+                history.id
+            )));
+        }
+        let id = store.insert_task(task);
+        store.history.push(StatusHistory { task_id: id.value(), ..history.clone() });
+        Ok(id)
+    }
+
+    async fn update_with_history(&self, task: &Task, history: &StatusHistory) -> Result<(), RepositoryError> {
+        let mut store = self.write();
+        store.live_task_mut(task.id)?;
+        store.check_history(history)?;
+        store.update_task(task)?;
+        store.history.push(history.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: TaskId) -> Result<(), RepositoryError> {
+        self.write().live_task_mut(id)?.deleted_at = Some(Utc::now());
+        Ok(())
+    }
+
+    async fn restore(&self, id: TaskId) -> Result<Task, RepositoryError> {
+        let mut store = self.write();
+        let in_workspace = store.in_workspace(id.value());
+        let task = store
+            .tasks
+            .get_mut(&id.value())
+            .filter(|task| in_workspace && task.is_deleted())
+            .ok_or_else(|| RepositoryError::NotFound(format!("Deleted task with id {} not found", id.value())))?;
+        task.deleted_at = None;
+        Ok(task.clone())
+    }
+}
+
+/// Status history repository over the tasks of an [`InMemoryTaskRepository`].
+///
+/// Analytics are computed the way the Postgres queries compute them, including first
+/// completions, dwell times measured from the previous entry and interpolated percentiles.
+/// Create it with [`InMemoryTaskRepository::history_repository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryStatusHistoryRepository {
+    store: Arc<RwLock<Store>>,
+}
+
+impl InMemoryStatusHistoryRepository {
+    fn read(&self) -> RwLockReadGuard<'_, Store> {
+        self.store.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Store> {
+        self.store.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn matching(&self, filter: &HistoryFilter) -> Vec<StatusHistory> {
+        self.read()
+            .history()
+            .into_iter()
+            .filter(|entry| filter.task_id.is_none_or(|task_id| entry.task_id == task_id))
+            .filter(|entry| filter.changed_by.as_ref().is_none_or(|changed_by| &entry.changed_by == changed_by))
+            .filter(|entry| filter.to_status.as_ref().is_none_or(|to_status| &entry.to_status == to_status))
+            .filter(|entry| filter.start_date.is_none_or(|start_date| entry.changed_at >= start_date))
+            .filter(|entry| filter.end_date.is_none_or(|end_date| entry.changed_at <= end_date))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl StatusHistoryRepository for InMemoryStatusHistoryRepository {
+    async fn find_by_task_id(&self, task_id: i32) -> Result<Vec<StatusHistory>, RepositoryError> {
+        Ok(self.read().history_of(task_id))
+    }
+
+    async fn find_matching(&self, filter: &HistoryFilter, order: SortOrder, page: Pagination) -> Result<Vec<StatusHistory>, RepositoryError> {
+        let mut entries = self.matching(filter);
+        entries.sort_by(|a, b| a.changed_at.cmp(&b.changed_at).then_with(|| a.id.cmp(&b.id)));
+        if order == SortOrder::Desc {
+            entries.reverse();
+        }
+        if let Some(key) = &page.after {
+            entries.retain(|entry| match order {
+                SortOrder::Asc => (entry.changed_at, &entry.id) > (key.at, &key.id),
+                SortOrder::Desc => (entry.changed_at, &entry.id) < (key.at, &key.id),
+            });
+        }
+        Ok(entries.into_iter().skip(page.offset as usize).take(page.limit as usize).collect())
+    }
+
+    async fn count_matching(&self, filter: &HistoryFilter) -> Result<usize, RepositoryError> {
+        Ok(self.matching(filter).len())
+    }
+
+    async fn find_by_date_range(&self, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<Vec<StatusHistory>, RepositoryError> {
+        let filter = HistoryFilter { start_date: Some(start_date), end_date: Some(end_date), ..Default::default() };
+        let mut entries = self.matching(&filter);
+        entries.sort_by_key(|entry| entry.changed_at);
+        Ok(entries)
+    }
+
+    async fn find_latest_by_task_id(&self, task_id: i32) -> Result<Option<StatusHistory>, RepositoryError> {
+        Ok(self.read().history_of(task_id).pop())
+    }
+
+    async fn get_task_analytics(&self, task_id: i32) -> Result<Option<TaskAnalytics>, RepositoryError> {
+        Ok(TaskAnalytics::from_history(self.read().history_of(task_id)))
+    }
+
+    async fn get_completion_analytics(&self, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<Vec<TaskAnalytics>, RepositoryError> {
+        let store = self.read();
+        let mut task_ids: Vec<i32> = store
+            .first_completions()
+            .into_iter()
+            .filter(|(_, completed_at)| *completed_at >= start_date && *completed_at <= end_date)
+            .map(|(task_id, _)| task_id)
+            .collect();
+        task_ids.sort_unstable();
+        Ok(task_ids.into_iter().filter_map(|task_id| TaskAnalytics::from_history(store.history_of(task_id))).collect())
+    }
+
+    async fn get_transition_matrix(&self, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<Vec<TransitionEdge>, RepositoryError> {
+        Ok(transition_matrix(&self.read().history(), start_date, end_date))
+    }
+
+    async fn get_creation_cohorts(&self, bucket: CohortBucket, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<Vec<Cohort>, RepositoryError> {
+        let store = self.read();
+        let completions = store.first_completions();
+        let created = store
+            .tasks()
+            .filter(|task| !task.is_deleted() && task.created_at >= start_date && task.created_at <= end_date)
+            .map(|task| (task.created_at, completions.get(&task.id.value()).copied()));
+        Ok(creation_cohorts(bucket, created))
+    }
+
+    async fn get_cumulative_flow(&self, bucket: CohortBucket, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<CumulativeFlow, RepositoryError> {
+        let store = self.read();
+        let deleted_at = store.tasks().map(|task| (task.id.value(), task.deleted_at)).collect();
+        Ok(cumulative_flow(bucket, start_date, end_date, &store.history(), &deleted_at))
+    }
+
+    async fn get_completion_time_stats(&self, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<Vec<CycleTimeStats>, RepositoryError> {
+        let store = self.read();
+        let completions = store.first_completions();
+        let history = store.history();
+        let cycle_times = history.iter().filter(|entry| entry.is_initial_creation()).filter_map(|entry| {
+            let priority = store.tasks.get(&entry.task_id)?.priority?;
+            let completed_at = completions.get(&entry.task_id).filter(|at| **at >= start_date && **at <= end_date)?;
+            Some((priority, (*completed_at - entry.changed_at).num_milliseconds() as f64 / 1000.0))
+        });
+        Ok(completion_time_stats(cycle_times))
+    }
+
+    async fn get_priority_completions(&self, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<Vec<PriorityCompletion>, RepositoryError> {
+        let store = self.read();
+        let completions = store.first_completions();
+        let history = store.history();
+        let cycle_times = history.iter().filter(|entry| entry.is_initial_creation()).filter_map(|entry| {
+            let priority = store.tasks.get(&entry.task_id)?.priority?;
+            let completed_at = completions.get(&entry.task_id).filter(|at| **at >= start_date && **at <= end_date)?;
+            Some((priority, (*completed_at - entry.changed_at).num_milliseconds() as f64 / 1000.0))
+        });
+        Ok(priority_completions(cycle_times))
+    }
+
+    async fn save(&self, history: &StatusHistory) -> Result<String, RepositoryError> {
+        let mut store = self.write();
+        store.check_history(history)?;
+        store.history.push(history.clone());
+        Ok(history.id.clone())
+    }
+
+    async fn delete(&self, id: String) -> Result<(), RepositoryError> {
+        let mut store = self.write();
+        let before = store.history.len();
+        let in_workspace: HashSet<i32> = store.tasks().map(|task| task.id.value()).collect();
+        store.history.retain(|entry| entry.id != id || !in_workspace.contains(&entry.task_id));
+        if store.history.len() == before {
+            return Err(RepositoryError::NotFound(format!("Status history with id {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Result<usize, RepositoryError> {
+        let ids: HashSet<&str> = ids.iter().map(String::as_str).collect();
+        let mut store = self.write();
+        let before = store.history.len();
+        let in_workspace: HashSet<i32> = store.tasks().map(|task| task.id.value()).collect();
+        store.history.retain(|entry| !ids.contains(entry.id.as_str()) || !in_workspace.contains(&entry.task_id));
+        Ok(before - store.history.len())
+    }
+}
+
+/// Workspace repository over the tasks of an [`InMemoryTaskRepository`], which starts with
+/// the default workspace like a migrated database.
+///
+/// Create it with [`InMemoryTaskRepository::workspace_repository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryWorkspaceRepository {
+    store: Arc<RwLock<Store>>,
+}
+
+impl InMemoryWorkspaceRepository {
+    fn read(&self) -> RwLockReadGuard<'_, Store> {
+        self.store.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Store> {
+        self.store.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl WorkspaceRepository for InMemoryWorkspaceRepository {
+    async fn save(&self, workspace: &Workspace) -> Result<(), RepositoryError> {
+        let mut store = self.write();
+        if store.workspaces.contains_key(&workspace.id) {
+            return Err(RepositoryError::Conflict {
+                field: "id".to_string(),
+                message: format!("Workspace {} already exists", workspace.id),
+            });
+        }
+        store.workspaces.insert(workspace.id.clone(), workspace.clone());
+        Ok(())
+    }
+
+    async fn update(&self, workspace: &Workspace) -> Result<(), RepositoryError> {
+        let mut store = self.write();
+        let stored = store
+            .workspaces
+            .get_mut(&workspace.id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Workspace {} not found", workspace.id)))?;
+        stored.name = workspace.name.clone();
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        let mut store = self.write();
+        if !store.workspaces.contains_key(id) {
+            return Err(RepositoryError::NotFound(format!("Workspace {} not found", id)));
+        }
+        if store.task_workspaces.values().any(|workspace| workspace == id) {
+            return Err(RepositoryError::Conflict {
+                field: "id".to_string(),
+                message: format!("Workspace {} still has tasks", id),
+            });
+        }
+        store.workspaces.remove(id);
+        store.workflows.remove(id);
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<Workspace>, RepositoryError> {
+        Ok(self.read().workspaces.get(id).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Workspace>, RepositoryError> {
+        let mut workspaces: Vec<Workspace> = self.read().workspaces.values().cloned().collect();
+        workspaces.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| a.id.cmp(&b.id)));
+        Ok(workspaces)
+    }
+}
+
+/// Workflow repository over the workspaces of an [`InMemoryTaskRepository`]; a workspace's
+/// workflow goes when the workspace is deleted.
+///
+/// Create it with [`InMemoryTaskRepository::workflow_repository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryWorkflowRepository {
+    store: Arc<RwLock<Store>>,
+}
+
+impl InMemoryWorkflowRepository {
+    fn read(&self) -> RwLockReadGuard<'_, Store> {
+        self.store.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Store> {
+        self.store.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl WorkflowRepository for InMemoryWorkflowRepository {
+    async fn find_by_workspace(&self, workspace_id: &str) -> Result<Option<WorkflowDefinition>, RepositoryError> {
+        Ok(self.read().workflows.get(workspace_id).cloned())
+    }
+
+    async fn save(&self, workspace_id: &str, workflow: &WorkflowDefinition, _updated_by: &str, _updated_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        let mut store = self.write();
+        if !store.workspaces.contains_key(workspace_id) {
+            return Err(RepositoryError::ForeignKeyViolation {
+                field: "workspace_id".to_string(),
+                message: "The referenced workspace_id does not exist".to_string(),
+            });
+        }
+        store.workflows.insert(workspace_id.to_string(), workflow.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, workspace_id: &str) -> Result<(), RepositoryError> {
+        match self.write().workflows.remove(workspace_id) {
+            Some(_) => Ok(()),
+            None => Err(RepositoryError::NotFound(format!("Workspace {} has no workflow of its own", workspace_id))),
+        }
+    }
+}
+
+/// Custom status repository over the tasks of an [`InMemoryTaskRepository`].
+///
+/// Create it with [`InMemoryTaskRepository::custom_status_repository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryCustomStatusRepository {
+    store: Arc<RwLock<Store>>,
+}
+
+impl InMemoryCustomStatusRepository {
+    fn read(&self) -> RwLockReadGuard<'_, Store> {
+        self.store.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Store> {
+        self.store.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl CustomStatusRepository for InMemoryCustomStatusRepository {
+    async fn save(&self, status: &CustomStatus) -> Result<(), RepositoryError> {
+        let mut store = self.write();
+        if store.statuses.iter().any(|stored| stored.status == status.status) {
+            return Err(RepositoryError::Conflict {
+                field: "name".to_string(),
+                message: format!("Status {} already exists", status.name()),
+            });
+        }
+        store.statuses.push(status.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, name: &str) -> Result<(), RepositoryError> {
+        let mut store = self.write();
+        let Some(index) = store.statuses.iter().position(|stored| stored.name() == name) else {
+            return Err(RepositoryError::NotFound(format!("Status {} not found", name)));
+        };
+        if store.tasks.values().any(|task| task.status.as_str() == name) {
+            return Err(RepositoryError::Conflict {
+                field: "name".to_string(),
+                message: format!("Status {} still has tasks", name),
+            });
+        }
+        store.statuses.remove(index);
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<CustomStatus>, RepositoryError> {
+        Ok(self.read().statuses.clone())
+    }
+}
+
+/// Statistics repository over the tasks and history of an [`InMemoryTaskRepository`].
+///
+/// Create it with [`InMemoryTaskRepository::statistics_repository`].
+#[derive(Debug, Clone)]
+pub struct InMemoryTaskStatisticsRepository {
+    store: Arc<RwLock<Store>>,
+}
+
+#[async_trait]
+impl TaskStatisticsRepository for InMemoryTaskStatisticsRepository {
+    async fn task_statistics(&self, query: &StatisticsQuery) -> Result<TaskStatistics, RepositoryError> {
+        let store = self.store.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let tasks: Vec<Task> = store.tasks().cloned().collect();
+        Ok(TaskStatistics::of(query, &tasks, &store.history()))
+    }
+}
@@ -1,5 +1,81 @@
+mod connection;
+mod database_error;
+#[cfg(any(feature = "in-memory", feature = "sqlite"))]
+mod history_analytics;
 pub mod postgres_task_repository;
 pub mod postgres_status_history_repository;
+pub mod postgres_api_key_repository;
+pub mod postgres_task_dependency_repository;
+pub mod postgres_background_job_repository;
+pub mod postgres_automation_rule_repository;
+pub mod postgres_workspace_repository;
+pub mod postgres_attachment_repository;
+pub mod postgres_workflow_repository;
+pub mod postgres_custom_status_repository;
+pub mod postgres_task_statistics_repository;
+pub mod cached_repositories;
+pub mod redis_cached_task_repository;
+pub mod postgres_event_outbox;
+#[cfg(feature = "in-memory")]
+pub mod in_memory_repositories;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_task_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_status_history_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_api_key_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_task_dependency_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_background_job_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_automation_rule_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_workspace_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_attachment_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_workflow_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_custom_status_repository;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_task_statistics_repository;
 
 pub use postgres_task_repository::*;
-pub use postgres_status_history_repository::*;
\ No newline at end of file
+pub use postgres_status_history_repository::*;
+pub use postgres_api_key_repository::*;
+pub use postgres_task_dependency_repository::*;
+pub use postgres_background_job_repository::*;
+pub use postgres_automation_rule_repository::*;
+pub use postgres_workspace_repository::*;
+pub use postgres_attachment_repository::*;
+pub use postgres_workflow_repository::*;
+pub use postgres_custom_status_repository::*;
+pub use postgres_task_statistics_repository::*;
+pub use cached_repositories::*;
+pub use redis_cached_task_repository::*;
+pub use postgres_event_outbox::*;
+#[cfg(feature = "in-memory")]
+pub use in_memory_repositories::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_task_repository::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_status_history_repository::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_api_key_repository::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_task_dependency_repository::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_background_job_repository::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_automation_rule_repository::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_workspace_repository::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_attachment_repository::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_workflow_repository::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_custom_status_repository::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite_task_statistics_repository::*;
@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use super::database_error::database_error;
+use crate::domain::{ApiKey, ApiKeyRepository, UserRole, RepositoryError};
+
+/// API keys are looked up before the request's tenant is known, since each key names its own
+/// workspace, so they always live on the default database and bypass shard routing
+pub struct PostgresApiKeyRepository {
+    pool: PgPool,
+}
+
+impl PostgresApiKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_api_key(&self, row: &sqlx::postgres::PgRow) -> Result<ApiKey, RepositoryError> {
+        let id: Uuid = row.get("id");
+        let role_str: String = row.get("role");
+        let created_at: DateTime<Utc> = row.get("created_at");
+
+        let role = UserRole::from_str(&role_str)
+            .map_err(RepositoryError::ValidationError)?;
+
+        Ok(ApiKey {
+            id: id.to_string(),
+            name: row.get("name"),
+            key_prefix: row.get("key_prefix"),
+            key_hash: row.get("key_hash"),
+            role,
+            workspace_id: row.get("workspace_id"),
+            created_by: row.get("created_by"),
+            created_at,
+        })
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for PostgresApiKeyRepository {
+    async fn save(&self, api_key: &ApiKey) -> Result<(), RepositoryError> {
+        let id = Uuid::parse_str(&api_key.id)
+            .map_err(|e| RepositoryError::ValidationError(format!("Invalid API key id: {}", e)))?;
+
+        sqlx::query(
+            "INSERT INTO api_keys (id, name, key_prefix, key_hash, role, workspace_id, created_by, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
+        )
+        .bind(id)
+        .bind(&api_key.name)
+        .bind(&api_key.key_prefix)
+        .bind(&api_key.key_hash)
+        .bind(api_key.role.as_str())
+        .bind(&api_key.workspace_id)
+        .bind(&api_key.created_by)
+        .bind(api_key.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT id, name, key_prefix, key_hash, role, workspace_id, created_by, created_at
+             FROM api_keys
+             WHERE key_hash = $1"
+        )
+        .bind(key_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        row.map(|row| self.row_to_api_key(&row)).transpose()
+    }
+}
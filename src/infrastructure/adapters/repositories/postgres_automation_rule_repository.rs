@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use uuid::Uuid;
+use super::database_error::database_error;
+use crate::database::current_workspace;
+use crate::domain::{AutomationRule, AutomationRuleRepository, RepositoryError};
+
+const RULE_COLUMNS: &str = "id, workspace_id, name, enabled, rule_trigger::text AS rule_trigger, rule_action::text AS rule_action, created_by, created_at, updated_at";
+
+/// Rules live on the default database like API keys, each tagged with its workspace; every
+/// query is limited to the current workspace, if there is one
+pub struct PostgresAutomationRuleRepository {
+    pool: PgPool,
+}
+
+impl PostgresAutomationRuleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_rule(&self, row: &sqlx::postgres::PgRow) -> Result<AutomationRule, RepositoryError> {
+        let id: Uuid = row.get("id");
+        let trigger: String = row.get("rule_trigger");
+        let action: String = row.get("rule_action");
+
+        Ok(AutomationRule {
+            id: id.to_string(),
+            workspace_id: row.get("workspace_id"),
+            name: row.get("name"),
+            enabled: row.get("enabled"),
+            trigger: serde_json::from_str(&trigger)
+                .map_err(|e| RepositoryError::ValidationError(format!("Invalid trigger of rule {}: {}", id, e)))?,
+            action: serde_json::from_str(&action)
+                .map_err(|e| RepositoryError::ValidationError(format!("Invalid action of rule {}: {}", id, e)))?,
+            created_by: row.get("created_by"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+fn rule_id(id: &str) -> Result<Uuid, RepositoryError> {
+    Uuid::parse_str(id).map_err(|_| RepositoryError::NotFound(format!("Automation rule {} not found", id)))
+}
+
+fn to_json(value: &impl serde::Serialize) -> Result<String, RepositoryError> {
+    serde_json::to_string(value)
+        .map_err(|e| RepositoryError::ValidationError(format!("Rule could not be serialized: {}", e)))
+}
+
+#[async_trait]
+impl AutomationRuleRepository for PostgresAutomationRuleRepository {
+    async fn save(&self, rule: &AutomationRule) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO automation_rules (id, workspace_id, name, enabled, rule_trigger, rule_action, created_by, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5::jsonb, $6::jsonb, $7, $8, $9)"
+        )
+        .bind(rule_id(&rule.id)?)
+        .bind(&rule.workspace_id)
+        .bind(&rule.name)
+        .bind(rule.enabled)
+        .bind(to_json(&rule.trigger)?)
+        .bind(to_json(&rule.action)?)
+        .bind(&rule.created_by)
+        .bind(rule.created_at)
+        .bind(rule.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, rule: &AutomationRule) -> Result<(), RepositoryError> {
+        let updated = sqlx::query(
+            "UPDATE automation_rules
+             SET name = $2, enabled = $3, rule_trigger = $4::jsonb, rule_action = $5::jsonb, updated_at = $6
+             WHERE id = $1 AND ($7::varchar IS NULL OR workspace_id = $7)"
+        )
+        .bind(rule_id(&rule.id)?)
+        .bind(&rule.name)
+        .bind(rule.enabled)
+        .bind(to_json(&rule.trigger)?)
+        .bind(to_json(&rule.action)?)
+        .bind(rule.updated_at)
+        .bind(current_workspace())
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        if updated.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!("Automation rule {} not found", rule.id)));
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        let deleted = sqlx::query("DELETE FROM automation_rules WHERE id = $1 AND ($2::varchar IS NULL OR workspace_id = $2)")
+            .bind(rule_id(id)?)
+            .bind(current_workspace())
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        if deleted.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!("Automation rule {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<AutomationRule>, RepositoryError> {
+        let Ok(id) = Uuid::parse_str(id) else { return Ok(None) };
+        let row = sqlx::query(&format!("SELECT {} FROM automation_rules WHERE id = $1 AND ($2::varchar IS NULL OR workspace_id = $2)", RULE_COLUMNS))
+            .bind(id)
+            .bind(current_workspace())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        row.map(|row| self.row_to_rule(&row)).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<AutomationRule>, RepositoryError> {
+        let rows = sqlx::query(&format!("SELECT {} FROM automation_rules WHERE $1::varchar IS NULL OR workspace_id = $1 ORDER BY created_at, id", RULE_COLUMNS))
+            .bind(current_workspace())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.iter().map(|row| self.row_to_rule(row)).collect()
+    }
+}
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use super::connection::acquire;
+use super::database_error::database_error;
+use crate::domain::{DomainEvent, EventPublisher, RepositoryError};
+
+/// Publishes task events by appending them to the `event_outbox` table.
+///
+/// Inside a request transaction the row commits or rolls back with the change itself.
+/// Delivering the rows and setting `published_at` is left to a relay.
+pub struct PostgresOutboxEventPublisher {
+    pool: PgPool,
+}
+
+impl PostgresOutboxEventPublisher {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EventPublisher for PostgresOutboxEventPublisher {
+    async fn publish(&self, event: &DomainEvent) -> Result<(), RepositoryError> {
+        let payload = serde_json::to_string(event)
+            .map_err(|error| RepositoryError::ValidationError(format!("Event could not be serialized: {}", error)))?;
+
+        sqlx::query(
+            "INSERT INTO event_outbox (event_type, task_id, payload, occurred_at)
+             VALUES ($1, $2, $3::jsonb, $4)"
+        )
+        .bind(event.event.event_type())
+        .bind(event.event.task_id())
+        .bind(payload)
+        .bind(event.event.occurred_at())
+        .execute(&mut *acquire(&self.pool).await?)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+}
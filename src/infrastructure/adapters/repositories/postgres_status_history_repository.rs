@@ -1,8 +1,12 @@
 use async_trait::async_trait;
-use sqlx::{PgPool, Row};
+use sqlx::{types::Json, PgExecutor, PgPool, Postgres, QueryBuilder, Row};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
-use crate::domain::{StatusHistory, StatusHistoryRepository, TaskAnalytics, TaskStatus, UserRole, RepositoryError};
+use super::connection::{acquire, CancellableConnection};
+use super::database_error::database_error;
+use super::postgres_task_repository::push_workspace;
+use crate::database::{current_workspace, workspace_for_new_tasks};
+use crate::domain::{ChecklistItem, StatusHistory, StatusHistoryRepository, TaskAnalytics, TaskStatus, CycleTimeStats, PriorityCompletion, TransitionEdge, Cohort, CohortBucket, CumulativeFlow, UserRole, HistoryFilter, Pagination, SortOrder, RepositoryError};
 
 pub struct PostgresStatusHistoryRepository {
     pool: PgPool,
@@ -22,19 +26,21 @@ impl PostgresStatusHistoryRepository {
         let changed_by: String = row.get("changed_by");
         let comment: Option<String> = row.get("comment");
         let user_role_str: String = row.get("user_role");
+        let checklist: Option<Json<Vec<ChecklistItem>>> = row.get("checklist");
+        let review_stage: Option<String> = row.get("review_stage");
 
         let from_status = if let Some(status_str) = from_status_str {
-            Some(TaskStatus::from_str(&status_str)
-                .map_err(|e| RepositoryError::ValidationError(e))?)
+            Some(TaskStatus::parse(&status_str)
+                .map_err(RepositoryError::ValidationError)?)
         } else {
             None
         };
 
-        let to_status = TaskStatus::from_str(&to_status_str)
-            .map_err(|e| RepositoryError::ValidationError(e))?;
+        let to_status = TaskStatus::parse(&to_status_str)
+            .map_err(RepositoryError::ValidationError)?;
 
         let user_role = UserRole::from_str(&user_role_str)
-            .map_err(|e| RepositoryError::ValidationError(e))?;
+            .map_err(RepositoryError::ValidationError)?;
 
         Ok(StatusHistory::new(
             id.to_string(),
@@ -45,23 +51,89 @@ impl PostgresStatusHistoryRepository {
             changed_by,
             comment,
             user_role,
-        ))
+        ).with_checklist(checklist.map(|Json(items)| items))
+        .with_review_stage(review_stage))
     }
 }
 
+/// Appends the `filter` conditions to a query that already has a WHERE clause
+fn push_history_filter(query: &mut QueryBuilder<'_, Postgres>, filter: &HistoryFilter) {
+    push_workspace(query, "workspace_id");
+    if let Some(task_id) = filter.task_id {
+        query.push(" AND task_id = ").push_bind(task_id);
+    }
+    if let Some(changed_by) = &filter.changed_by {
+        query.push(" AND changed_by = ").push_bind(changed_by.clone());
+    }
+    if let Some(to_status) = &filter.to_status {
+        query.push(" AND to_status = ").push_bind(to_status.as_str().to_string());
+    }
+    if let Some(start_date) = filter.start_date {
+        query.push(" AND changed_at >= ").push_bind(start_date);
+    }
+    if let Some(end_date) = filter.end_date {
+        query.push(" AND changed_at <= ").push_bind(end_date);
+    }
+}
+
+/// Inserts a status history record using any executor, so callers can
+/// include it in a wider transaction. The entry joins its task's workspace.
+pub(crate) async fn insert_status_history<'e, E>(executor: E, history: &StatusHistory) -> Result<String, RepositoryError>
+where
+    E: PgExecutor<'e>,
+{
+    let id = Uuid::parse_str(&history.id)
+        .map_err(|e| RepositoryError::ValidationError(format!("Invalid UUID: {}", e)))?;
+
+    let from_status_str = history.from_status.as_ref().map(|s| s.as_str());
+
+    // Use simple INSERT without UPSERT to preserve audit trail integrity
+    // Status history records should be immutable once created
+    let result = sqlx::query(
+        "INSERT INTO status_history (id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role, checklist, review_stage, workspace_id)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, COALESCE((SELECT workspace_id FROM tasks WHERE task_id = $2), $11))
+         RETURNING id"
+    )
+    .bind(id)
+    .bind(history.task_id)
+    .bind(from_status_str)
+    .bind(history.to_status.as_str())
+    .bind(history.changed_at)
+    .bind(&history.changed_by)
+    .bind(&history.comment)
+    .bind(history.user_role.as_str())
+    .bind(history.checklist.as_ref().map(Json))
+    .bind(&history.review_stage)
+    .bind(workspace_for_new_tasks())
+    .fetch_one(executor)
+    .await
+    .map_err(|e| {
+        // Provide better error context for constraint violations
+        if e.to_string().contains("duplicate key") || e.to_string().contains("unique constraint") {
+            RepositoryError::ValidationError(format!("Status history record with ID {} already exists. Audit records are immutable.", id))
+        } else {
+            database_error(e)
+        }
+    })?;
+
+    let saved_id: Uuid = result.get("id");
+    Ok(saved_id.to_string())
+}
+
 #[async_trait]
 impl StatusHistoryRepository for PostgresStatusHistoryRepository {
     async fn find_by_task_id(&self, task_id: i32) -> Result<Vec<StatusHistory>, RepositoryError> {
         let rows = sqlx::query(
-            "SELECT id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role 
+            "SELECT id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role, checklist, review_stage 
              FROM status_history 
-             WHERE task_id = $1 
+             WHERE task_id = $1 AND ($2::varchar IS NULL OR workspace_id = $2)
              ORDER BY changed_at ASC"
         )
         .bind(task_id)
-        .fetch_all(&self.pool)
+        .bind(current_workspace())
+        .fetch_all(&mut *acquire(&self.pool).await?)
         .await
-        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        .map_err(database_error)?;
 
         let mut histories = Vec::new();
         for row in rows {
@@ -72,22 +144,69 @@ impl StatusHistoryRepository for PostgresStatusHistoryRepository {
         Ok(histories)
     }
 
+    async fn find_matching(
+        &self,
+        filter: &HistoryFilter,
+        order: SortOrder,
+        page: Pagination,
+    ) -> Result<Vec<StatusHistory>, RepositoryError> {
+        let mut query = QueryBuilder::<Postgres>::new(
+            "SELECT id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role, checklist, review_stage
+             FROM status_history
+             WHERE TRUE"
+        );
+        push_history_filter(&mut query, filter);
+
+        let (direction, after) = match order {
+            SortOrder::Asc => (" ASC", " > "),
+            SortOrder::Desc => (" DESC", " < "),
+        };
+        if let Some(key) = &page.after {
+            let id = Uuid::parse_str(&key.id)
+                .map_err(|e| RepositoryError::ValidationError(format!("Invalid UUID: {}", e)))?;
+            query.push(" AND (changed_at, id)").push(after).push("(").push_bind(key.at).push(", ").push_bind(id).push(")");
+        }
+        query.push(" ORDER BY changed_at").push(direction).push(", id").push(direction);
+        query.push(" LIMIT ").push_bind(i64::from(page.limit));
+        query.push(" OFFSET ").push_bind(i64::from(page.offset));
+
+        let rows = query.build()
+            .fetch_all(&mut *acquire(&self.pool).await?)
+            .await
+            .map_err(database_error)?;
+
+        rows.iter().map(|row| self.row_to_status_history(row)).collect()
+    }
+
+    async fn count_matching(&self, filter: &HistoryFilter) -> Result<usize, RepositoryError> {
+        let mut query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM status_history WHERE TRUE");
+        push_history_filter(&mut query, filter);
+
+        let count: i64 = query.build_query_scalar()
+            .fetch_one(&mut *acquire(&self.pool).await?)
+            .await
+            .map_err(database_error)?;
+
+        Ok(count as usize)
+    }
+
     async fn find_by_date_range(
         &self, 
         start_date: DateTime<Utc>, 
         end_date: DateTime<Utc>
     ) -> Result<Vec<StatusHistory>, RepositoryError> {
         let rows = sqlx::query(
-            "SELECT id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role 
+            "SELECT id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role, checklist, review_stage 
              FROM status_history 
-             WHERE changed_at >= $1 AND changed_at <= $2 
+             WHERE changed_at >= $1 AND changed_at <= $2 AND ($3::varchar IS NULL OR workspace_id = $3)
              ORDER BY changed_at ASC"
         )
         .bind(start_date)
         .bind(end_date)
-        .fetch_all(&self.pool)
+        .bind(current_workspace())
+        .fetch_all(&mut *acquire(&self.pool).await?)
         .await
-        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        .map_err(database_error)?;
 
         let mut histories = Vec::new();
         for row in rows {
@@ -100,16 +219,17 @@ impl StatusHistoryRepository for PostgresStatusHistoryRepository {
 
     async fn find_latest_by_task_id(&self, task_id: i32) -> Result<Option<StatusHistory>, RepositoryError> {
         let row = sqlx::query(
-            "SELECT id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role 
+            "SELECT id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role, checklist, review_stage 
              FROM status_history 
-             WHERE task_id = $1 
+             WHERE task_id = $1 AND ($2::varchar IS NULL OR workspace_id = $2)
              ORDER BY changed_at DESC 
              LIMIT 1"
         )
         .bind(task_id)
-        .fetch_optional(&self.pool)
+        .bind(current_workspace())
+        .fetch_optional(&mut *acquire(&self.pool).await?)
         .await
-        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        .map_err(database_error)?;
 
         match row {
             Some(row) => Ok(Some(self.row_to_status_history(&row)?)),
@@ -127,18 +247,22 @@ impl StatusHistoryRepository for PostgresStatusHistoryRepository {
         start_date: DateTime<Utc>, 
         end_date: DateTime<Utc>
     ) -> Result<Vec<TaskAnalytics>, RepositoryError> {
-        // Get all completed tasks in the date range
+        // Get all tasks first completed in the date range; completing again after a reopen doesn't count
+        let mut connection = CancellableConnection::acquire(&self.pool).await?;
         let rows = sqlx::query(
-            "SELECT DISTINCT task_id 
+            "SELECT task_id 
              FROM status_history 
-             WHERE to_status = 'Completed' 
-             AND changed_at >= $1 AND changed_at <= $2"
+             WHERE to_status = 'Completed' AND ($3::varchar IS NULL OR workspace_id = $3)
+             GROUP BY task_id
+             HAVING MIN(changed_at) >= $1 AND MIN(changed_at) <= $2"
         )
         .bind(start_date)
         .bind(end_date)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        .bind(current_workspace())
+        .fetch_all(&mut *connection)
+        .await;
+        connection.finish();
+        let rows = rows.map_err(database_error)?;
 
         let mut analytics = Vec::new();
         for row in rows {
@@ -151,80 +275,280 @@ impl StatusHistoryRepository for PostgresStatusHistoryRepository {
         Ok(analytics)
     }
 
-    async fn get_average_completion_times(&self) -> Result<Vec<(i32, chrono::Duration)>, RepositoryError> {
+    async fn get_transition_matrix(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<TransitionEdge>, RepositoryError> {
+        // Dwell time is measured from the task's previous transition, which may fall before the period;
+        // entries that keep the task in its status are not transitions
+        let mut connection = CancellableConnection::acquire(&self.pool).await?;
         let rows = sqlx::query(
-            "SELECT t.priority, 
-                    AVG(EXTRACT(EPOCH FROM (sh_completed.changed_at - sh_created.changed_at))) as avg_seconds
-             FROM tasks t
-             JOIN status_history sh_created ON t.task_id = sh_created.task_id AND sh_created.from_status IS NULL
-             JOIN status_history sh_completed ON t.task_id = sh_completed.task_id AND sh_completed.to_status = 'Completed'
-             WHERE t.priority IS NOT NULL
-             GROUP BY t.priority
-             ORDER BY t.priority"
+            "WITH transitions AS (
+                 SELECT from_status, to_status, changed_at,
+                        changed_at - LAG(changed_at) OVER (PARTITION BY task_id ORDER BY changed_at, id) AS dwell
+                 FROM status_history
+                 WHERE ($3::varchar IS NULL OR workspace_id = $3) AND from_status IS DISTINCT FROM to_status
+             )
+             SELECT from_status, to_status, COUNT(*) AS transitions,
+                    PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY EXTRACT(EPOCH FROM dwell))::float8 AS median_dwell_seconds
+             FROM transitions
+             WHERE from_status IS NOT NULL AND changed_at >= $1 AND changed_at <= $2
+             GROUP BY from_status, to_status
+             ORDER BY from_status, to_status"
         )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        .bind(start_date)
+        .bind(end_date)
+        .bind(current_workspace())
+        .fetch_all(&mut *connection)
+        .await;
+        connection.finish();
+        let rows = rows.map_err(database_error)?;
 
-        let mut results = Vec::new();
+        let mut edges = Vec::new();
         for row in rows {
-            let priority: i32 = row.get("priority");
-            let avg_seconds: Option<f64> = row.get("avg_seconds");
-            
-            if let Some(seconds) = avg_seconds {
-                let duration = chrono::Duration::seconds(seconds as i64);
-                results.push((priority, duration));
+            let from_status: String = row.get("from_status");
+            let to_status: String = row.get("to_status");
+            let transitions: i64 = row.get("transitions");
+            let median_dwell_seconds: Option<f64> = row.get("median_dwell_seconds");
+
+            edges.push(TransitionEdge {
+                from_status: TaskStatus::parse(&from_status).map_err(RepositoryError::ValidationError)?,
+                to_status: TaskStatus::parse(&to_status).map_err(RepositoryError::ValidationError)?,
+                count: transitions as usize,
+                median_dwell_time: median_dwell_seconds.map(|seconds| chrono::Duration::seconds(seconds as i64)),
+            });
+        }
+
+        Ok(edges)
+    }
+
+    async fn get_creation_cohorts(
+        &self,
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<Cohort>, RepositoryError> {
+        // Buckets are truncated in UTC; a NULL completed_bucket counts tasks never completed
+        let mut connection = CancellableConnection::acquire(&self.pool).await?;
+        let rows = sqlx::query(
+            "WITH cohort_tasks AS (
+                 SELECT task_id, date_trunc($1, created_at AT TIME ZONE 'UTC') AS cohort_start
+                 FROM tasks
+                 WHERE deleted_at IS NULL AND created_at >= $2 AND created_at <= $3
+                 AND ($4::varchar IS NULL OR workspace_id = $4)
+             ),
+             first_completion AS (
+                 SELECT task_id, MIN(changed_at) AS completed_at
+                 FROM status_history
+                 WHERE to_status = 'Completed' AND ($4::varchar IS NULL OR workspace_id = $4)
+                 GROUP BY task_id
+             )
+             SELECT ct.cohort_start,
+                    date_trunc($1, fc.completed_at AT TIME ZONE 'UTC') AS completed_bucket,
+                    COUNT(*) AS tasks
+             FROM cohort_tasks ct
+             LEFT JOIN first_completion fc ON fc.task_id = ct.task_id
+             GROUP BY 1, 2
+             ORDER BY 1, 2"
+        )
+        .bind(bucket.as_str())
+        .bind(start_date)
+        .bind(end_date)
+        .bind(current_workspace())
+        .fetch_all(&mut *connection)
+        .await;
+        connection.finish();
+        let rows = rows.map_err(database_error)?;
+
+        let mut cohorts: Vec<Cohort> = Vec::new();
+        for row in rows {
+            let cohort_start = row.get::<chrono::NaiveDateTime, _>("cohort_start").and_utc();
+            let completed_bucket: Option<chrono::NaiveDateTime> = row.get("completed_bucket");
+            let tasks = row.get::<i64, _>("tasks") as usize;
+
+            if cohorts.last().is_none_or(|cohort| cohort.start != cohort_start) {
+                cohorts.push(Cohort {
+                    start: cohort_start,
+                    tasks_created: 0,
+                    completions_by_period: Default::default(),
+                });
+            }
+            let cohort = cohorts.last_mut().expect("cohort pushed above");
+            cohort.tasks_created += tasks;
+            if let Some(completed_bucket) = completed_bucket {
+                let period = bucket.periods_between(cohort_start, completed_bucket.and_utc());
+                *cohort.completions_by_period.entry(period).or_insert(0) += tasks;
             }
         }
 
-        Ok(results)
+        Ok(cohorts)
     }
 
-    async fn save(&self, history: &StatusHistory) -> Result<String, RepositoryError> {
-        let id = Uuid::parse_str(&history.id)
-            .map_err(|e| RepositoryError::ValidationError(format!("Invalid UUID: {}", e)))?;
+    async fn get_cumulative_flow(
+        &self,
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<CumulativeFlow, RepositoryError> {
+        // One row per bucket from generate_series; each task counts under its latest entry before
+        // the bucket closes, unless deleted by then. Empty buckets come back with a NULL status.
+        let mut connection = CancellableConnection::acquire(&self.pool).await?;
+        let rows = sqlx::query(
+            "WITH buckets AS (
+                 SELECT bucket_start, (bucket_start + ('1 ' || $1)::interval) AT TIME ZONE 'UTC' AS bucket_close
+                 FROM generate_series(
+                     date_trunc($1, $2 AT TIME ZONE 'UTC'),
+                     date_trunc($1, $3 AT TIME ZONE 'UTC'),
+                     ('1 ' || $1)::interval
+                 ) AS bucket_start
+             )
+             SELECT b.bucket_start, latest.to_status AS status, COUNT(latest.task_id) AS tasks
+             FROM buckets b
+             LEFT JOIN LATERAL (
+                 SELECT DISTINCT ON (sh.task_id) sh.task_id, sh.to_status
+                 FROM status_history sh
+                 JOIN tasks t ON t.task_id = sh.task_id
+                 WHERE sh.changed_at < b.bucket_close
+                 AND (t.deleted_at IS NULL OR t.deleted_at >= b.bucket_close)
+                 AND ($4::varchar IS NULL OR sh.workspace_id = $4)
+                 ORDER BY sh.task_id, sh.changed_at DESC, sh.id DESC
+             ) latest ON TRUE
+             GROUP BY 1, 2
+             ORDER BY 1"
+        )
+        .bind(bucket.as_str())
+        .bind(start_date)
+        .bind(end_date)
+        .bind(current_workspace())
+        .fetch_all(&mut *connection)
+        .await;
+        connection.finish();
+        let rows = rows.map_err(database_error)?;
+
+        let mut counts = Vec::new();
+        for row in rows {
+            let Some(status) = row.get::<Option<String>, _>("status") else {
+                continue;
+            };
+            counts.push((
+                row.get::<chrono::NaiveDateTime, _>("bucket_start").and_utc(),
+                TaskStatus::parse(&status).map_err(RepositoryError::ValidationError)?,
+                row.get::<i64, _>("tasks") as usize,
+            ));
+        }
 
-        let from_status_str = history.from_status.as_ref().map(|s| s.as_str());
+        Ok(CumulativeFlow::from_counts(bucket, start_date, end_date, counts))
+    }
 
-        // Use simple INSERT without UPSERT to preserve audit trail integrity
-        // Status history records should be immutable once created
-        let result = sqlx::query(
-            "INSERT INTO status_history (id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-             RETURNING id"
+    async fn get_completion_time_stats(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<CycleTimeStats>, RepositoryError> {
+        let mut connection = CancellableConnection::acquire(&self.pool).await?;
+        let rows = sqlx::query(
+            "WITH cycle_times AS (
+                 SELECT t.priority,
+                        EXTRACT(EPOCH FROM (sh_completed.changed_at - sh_created.changed_at))::float8 AS seconds
+                 FROM tasks t
+                 JOIN status_history sh_created ON t.task_id = sh_created.task_id AND sh_created.from_status IS NULL
+                 JOIN (SELECT task_id, MIN(changed_at) AS changed_at
+                       FROM status_history
+                       WHERE to_status = 'Completed' AND ($3::varchar IS NULL OR workspace_id = $3)
+                       GROUP BY task_id
+                       HAVING MIN(changed_at) >= $1 AND MIN(changed_at) <= $2) sh_completed ON t.task_id = sh_completed.task_id
+                 WHERE t.priority IS NOT NULL AND ($3::varchar IS NULL OR t.workspace_id = $3)
+             )
+             SELECT priority,
+                    AVG(seconds) AS avg_seconds,
+                    PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY seconds) AS p50_seconds,
+                    PERCENTILE_CONT(0.85) WITHIN GROUP (ORDER BY seconds) AS p85_seconds,
+                    PERCENTILE_CONT(0.95) WITHIN GROUP (ORDER BY seconds) AS p95_seconds
+             FROM cycle_times
+             GROUP BY priority
+             ORDER BY priority"
         )
-        .bind(id)
-        .bind(history.task_id)
-        .bind(from_status_str)
-        .bind(history.to_status.as_str())
-        .bind(history.changed_at)
-        .bind(&history.changed_by)
-        .bind(&history.comment)
-        .bind(history.user_role.as_str())
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| {
-            // Provide better error context for constraint violations
-            if e.to_string().contains("duplicate key") || e.to_string().contains("unique constraint") {
-                RepositoryError::ValidationError(format!("Status history record with ID {} already exists. Audit records are immutable.", id))
-            } else {
-                RepositoryError::DatabaseError(e.to_string())
-            }
-        })?;
+        .bind(start_date)
+        .bind(end_date)
+        .bind(current_workspace())
+        .fetch_all(&mut *connection)
+        .await;
+        connection.finish();
+        let rows = rows.map_err(database_error)?;
 
-        let saved_id: Uuid = result.get("id");
-        Ok(saved_id.to_string())
+        let seconds = |value: f64| chrono::Duration::seconds(value as i64);
+        let mut results = Vec::new();
+        for row in rows {
+            results.push(CycleTimeStats {
+                priority: row.get("priority"),
+                average: seconds(row.get("avg_seconds")),
+                p50: seconds(row.get("p50_seconds")),
+                p85: seconds(row.get("p85_seconds")),
+                p95: seconds(row.get("p95_seconds")),
+            });
+        }
+
+        Ok(results)
+    }
+
+    async fn get_priority_completions(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<PriorityCompletion>, RepositoryError> {
+        // Same tasks as get_completion_analytics: first completed in the range, with a creation entry
+        let mut connection = CancellableConnection::acquire(&self.pool).await?;
+        let rows = sqlx::query(
+            "WITH first_completions AS (
+                 SELECT task_id, MIN(changed_at) AS completed_at
+                 FROM status_history
+                 WHERE to_status = 'Completed' AND ($3::varchar IS NULL OR workspace_id = $3)
+                 GROUP BY task_id
+                 HAVING MIN(changed_at) >= $1 AND MIN(changed_at) <= $2
+             )
+             SELECT t.priority,
+                    COUNT(*) AS task_count,
+                    AVG(EXTRACT(EPOCH FROM (fc.completed_at - sh_created.changed_at)))::float8 AS avg_seconds
+             FROM first_completions fc
+             JOIN tasks t ON t.task_id = fc.task_id
+             JOIN status_history sh_created ON sh_created.task_id = fc.task_id AND sh_created.from_status IS NULL
+             WHERE t.priority IS NOT NULL
+             GROUP BY t.priority
+             ORDER BY t.priority"
+        )
+        .bind(start_date)
+        .bind(end_date)
+        .bind(current_workspace())
+        .fetch_all(&mut *connection)
+        .await;
+        connection.finish();
+        let rows = rows.map_err(database_error)?;
+
+        Ok(rows
+            .iter()
+            .map(|row| PriorityCompletion {
+                priority: row.get("priority"),
+                task_count: row.get::<i64, _>("task_count") as usize,
+                average: chrono::Duration::seconds(row.get::<f64, _>("avg_seconds") as i64),
+            })
+            .collect())
+    }
+
+    async fn save(&self, history: &StatusHistory) -> Result<String, RepositoryError> {
+        insert_status_history(&mut *acquire(&self.pool).await?, history).await
     }
 
     async fn delete(&self, id: String) -> Result<(), RepositoryError> {
         let uuid = Uuid::parse_str(&id)
             .map_err(|e| RepositoryError::ValidationError(format!("Invalid UUID: {}", e)))?;
 
-        let result = sqlx::query("DELETE FROM status_history WHERE id = $1")
+        let result = sqlx::query("DELETE FROM status_history WHERE id = $1 AND ($2::varchar IS NULL OR workspace_id = $2)")
             .bind(uuid)
-            .execute(&self.pool)
+            .bind(current_workspace())
+            .execute(&mut *acquire(&self.pool).await?)
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(database_error)?;
 
         if result.rows_affected() == 0 {
             return Err(RepositoryError::NotFound(
@@ -234,4 +558,20 @@ impl StatusHistoryRepository for PostgresStatusHistoryRepository {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    async fn delete_many(&self, ids: &[String]) -> Result<usize, RepositoryError> {
+        let uuids = ids
+            .iter()
+            .map(|id| Uuid::parse_str(id).map_err(|e| RepositoryError::ValidationError(format!("Invalid UUID: {}", e))))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let result = sqlx::query("DELETE FROM status_history WHERE id = ANY($1) AND ($2::varchar IS NULL OR workspace_id = $2)")
+            .bind(uuids)
+            .bind(current_workspace())
+            .execute(&mut *acquire(&self.pool).await?)
+            .await
+            .map_err(database_error)?;
+
+        Ok(result.rows_affected() as usize)
+    }
+}
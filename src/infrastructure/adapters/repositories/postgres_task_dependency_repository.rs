@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use super::connection::acquire;
+use super::database_error::database_error;
+use crate::domain::{RepositoryError, TaskDependencyRepository, TaskId};
+
+pub struct PostgresTaskDependencyRepository {
+    pool: PgPool,
+}
+
+impl PostgresTaskDependencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaskDependencyRepository for PostgresTaskDependencyRepository {
+    async fn add(&self, task_id: TaskId, depends_on: TaskId) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO task_dependencies (task_id, depends_on_id)
+             VALUES ($1, $2)
+             ON CONFLICT DO NOTHING"
+        )
+        .bind(task_id.value())
+        .bind(depends_on.value())
+        .execute(&mut *acquire(&self.pool).await?)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn remove(&self, task_id: TaskId, depends_on: TaskId) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM task_dependencies WHERE task_id = $1 AND depends_on_id = $2")
+            .bind(task_id.value())
+            .bind(depends_on.value())
+            .execute(&mut *acquire(&self.pool).await?)
+            .await
+            .map_err(database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "Task {} does not depend on task {}",
+                task_id.value(),
+                depends_on.value()
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn find_dependencies(&self, task_id: TaskId) -> Result<Vec<TaskId>, RepositoryError> {
+        let rows = sqlx::query("SELECT depends_on_id FROM task_dependencies WHERE task_id = $1 ORDER BY depends_on_id")
+            .bind(task_id.value())
+            .fetch_all(&mut *acquire(&self.pool).await?)
+            .await
+            .map_err(database_error)?;
+
+        Ok(rows.iter().map(|row| TaskId::new(row.get("depends_on_id"))).collect())
+    }
+}
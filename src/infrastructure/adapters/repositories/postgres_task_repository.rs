@@ -1,7 +1,11 @@
 use async_trait::async_trait;
-use sqlx::{PgPool, Row};
+use sqlx::{Connection, PgExecutor, PgPool, Postgres, QueryBuilder};
 use chrono::{DateTime, Utc};
-use crate::domain::{Task, TaskId, TaskStatus, TaskRepository, RepositoryError};
+use super::connection::acquire;
+use super::database_error::database_error;
+use crate::database::{current_workspace, workspace_for_new_tasks};
+use crate::domain::{BoardColumn, PriorityChange, Rank, Task, TaskId, TaskPatch, TaskStatus, TaskRepository, StatusHistory, RepositoryError, TaskFilter, TaskSort, TaskSortField, SortOrder, TaskFacets, FilterExpression, FilterField, FilterValue, Comparison};
+use super::postgres_status_history_repository::insert_status_history;
 
 pub struct PostgresTaskRepository {
     pool: PgPool,
@@ -13,147 +17,546 @@ impl PostgresTaskRepository {
     }
 }
 
+/// A `tasks` row as selected by every task query, checked against the schema at compile time
+#[derive(Debug, sqlx::FromRow)]
+struct TaskRow {
+    task_id: i32,
+    name: String,
+    priority: Option<i32>,
+    description: Option<String>,
+    status: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    archived_at: Option<DateTime<Utc>>,
+    created_by: Option<String>,
+    rank: Option<String>,
+}
+
+impl TryFrom<TaskRow> for Task {
+    type Error = RepositoryError;
+
+    fn try_from(row: TaskRow) -> Result<Self, Self::Error> {
+        let status = TaskStatus::parse(&row.status)
+            .map_err(RepositoryError::ValidationError)?;
+        let rank = row.rank.as_deref().map(Rank::parse).transpose()
+            .map_err(RepositoryError::ValidationError)?;
+
+        Task::new_with_status(
+            TaskId::new(row.task_id),
+            row.name,
+            row.priority,
+            status,
+            row.created_at,
+            row.updated_at,
+        )
+        .and_then(|task| task.with_description(row.description))
+        .map(|task| task.with_deleted_at(row.deleted_at).with_archived_at(row.archived_at).with_created_by(row.created_by).with_rank(rank))
+        .map_err(|error| RepositoryError::ValidationError(error.to_string()))
+    }
+}
+
+fn rows_to_tasks(rows: Vec<TaskRow>) -> Result<Vec<Task>, RepositoryError> {
+    rows.into_iter().map(Task::try_from).collect()
+}
+
+/// A task on the board together with the number of tasks in its column
+#[derive(Debug, sqlx::FromRow)]
+struct BoardRow {
+    #[sqlx(flatten)]
+    task: TaskRow,
+    column_count: i64,
+}
+
+fn rows_to_columns(rows: Vec<BoardRow>) -> Result<Vec<BoardColumn>, RepositoryError> {
+    let cards = rows.into_iter()
+        .map(|row| Ok((Task::try_from(row.task)?, row.column_count as usize)))
+        .collect::<Result<Vec<_>, RepositoryError>>()?;
+    Ok(BoardColumn::from_cards(cards))
+}
+
+/// Builds facets from `(status, count)` and `(priority, count)` rows
+pub(crate) fn facets_from_rows(statuses: Vec<(String, i64)>, priorities: Vec<(Option<i32>, i64)>) -> Result<TaskFacets, RepositoryError> {
+    let statuses = statuses
+        .into_iter()
+        .map(|(status, count)| TaskStatus::parse(&status).map(|status| (status, count as usize)))
+        .collect::<Result<_, _>>()
+        .map_err(RepositoryError::ValidationError)?;
+    let priorities = priorities.into_iter().map(|(priority, count)| (priority, count as usize)).collect();
+    Ok(TaskFacets::from_counts(statuses, priorities))
+}
+
+async fn insert_task<'e, E>(executor: E, task: &Task) -> Result<TaskId, RepositoryError>
+where
+    E: PgExecutor<'e>,
+{
+    let task_id = sqlx::query_scalar!(
+        "INSERT INTO tasks (name, priority, description, status, created_at, updated_at, created_by, workspace_id) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING task_id",
+        task.name,
+        task.priority,
+        task.description,
+        task.status.as_str(),
+        task.created_at,
+        task.updated_at,
+        task.created_by,
+        workspace_for_new_tasks(),
+    )
+        .fetch_one(executor)
+        .await
+        .map_err(database_error)?;
+
+    Ok(TaskId::new(task_id))
+}
+
+async fn update_task<'e, E>(executor: E, task: &Task) -> Result<(), RepositoryError>
+where
+    E: PgExecutor<'e>,
+{
+    let result = sqlx::query!(
+        "UPDATE tasks SET name = $1, priority = $2, description = $3, status = $4, updated_at = $5, archived_at = $8 WHERE task_id = $6 AND deleted_at IS NULL AND ($7::varchar IS NULL OR workspace_id = $7)",
+        task.name,
+        task.priority,
+        task.description,
+        task.status.as_str(),
+        task.updated_at,
+        task.id.value(),
+        current_workspace(),
+        task.archived_at,
+    )
+        .execute(executor)
+        .await
+        .map_err(database_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(RepositoryError::NotFound(
+            format!("Task with id {} not found", task.id.value())
+        ));
+    }
+
+    Ok(())
+}
+
+/// Sets only the patched columns, so concurrent changes to the others survive
+async fn update_task_fields<'e, E>(executor: E, id: TaskId, patch: &TaskPatch) -> Result<(), RepositoryError>
+where
+    E: PgExecutor<'e>,
+{
+    let mut query = QueryBuilder::<Postgres>::new("UPDATE tasks SET updated_at = ");
+    query.push_bind(patch.updated_at);
+    if let Some(name) = &patch.name {
+        query.push(", name = ").push_bind(name);
+    }
+    if let Some(priority) = patch.priority {
+        query.push(", priority = ").push_bind(priority);
+    }
+    if let Some(description) = &patch.description {
+        query.push(", description = ").push_bind(description);
+    }
+    query.push(" WHERE task_id = ").push_bind(id.value()).push(" AND deleted_at IS NULL");
+    push_workspace(&mut query, "workspace_id");
+
+    let result = query.build()
+        .execute(executor)
+        .await
+        .map_err(database_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(RepositoryError::NotFound(
+            format!("Task with id {} not found", id.value())
+        ));
+    }
+
+    Ok(())
+}
+
+// Column names come from this fixed mapping, never from user input
+fn sort_column(field: TaskSortField) -> &'static str {
+    match field {
+        TaskSortField::Id => "task_id",
+        TaskSortField::Priority => "priority",
+        TaskSortField::CreatedAt => "created_at",
+        TaskSortField::UpdatedAt => "updated_at",
+        TaskSortField::Name => "name",
+        TaskSortField::Status => "status",
+        // Ranks compare byte by byte; tasks never moved rank by id
+        TaskSortField::Rank => "COALESCE(rank, lpad(task_id::text, 10, '0')) COLLATE \"C\"",
+    }
+}
+
+fn sort_direction(order: SortOrder) -> &'static str {
+    match order {
+        SortOrder::Asc => " ASC NULLS LAST",
+        SortOrder::Desc => " DESC NULLS LAST",
+    }
+}
+
+/// A LIKE pattern matching `text` anywhere, with its wildcards taken literally
+fn like_pattern(text: &str) -> String {
+    let escaped = text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// Limits a query whose `WHERE` clause is already open to the current workspace, if there is one
+pub(crate) fn push_workspace(query: &mut QueryBuilder<'_, Postgres>, column: &str) {
+    if let Some(workspace) = current_workspace() {
+        query.push(" AND ").push(column).push(" = ").push_bind(workspace);
+    }
+}
+
+/// Appends the filter's conditions to a query whose `WHERE` clause is already open
+fn push_task_filter(query: &mut QueryBuilder<'_, Postgres>, filter: &TaskFilter) {
+    push_workspace(query, "workspace_id");
+    if !filter.include_deleted {
+        query.push(" AND deleted_at IS NULL");
+    }
+    query.push(if filter.archived { " AND archived_at IS NOT NULL" } else { " AND archived_at IS NULL" });
+    if let Some(priority) = filter.priority {
+        query.push(" AND priority = ").push_bind(priority);
+    }
+    if let Some(status) = &filter.status {
+        query.push(" AND status = ").push_bind(status.as_str().to_string());
+    }
+    if let Some(expression) = &filter.expression {
+        query.push(" AND ");
+        push_filter_expression(query, expression);
+    }
+}
+
+// Filter columns come from this fixed mapping, never from user input
+fn filter_column(field: FilterField) -> &'static str {
+    match field {
+        FilterField::Status => "status",
+        FilterField::Priority => "priority",
+        FilterField::Name => "name",
+        FilterField::Description => "description",
+        FilterField::CreatedAt => "created_at",
+        FilterField::UpdatedAt => "updated_at",
+        FilterField::CreatedBy => "created_by",
+    }
+}
+
+/// Pushes the column, first ruling out `NULL` for nullable ones
+fn push_filter_column(query: &mut QueryBuilder<'_, Postgres>, field: FilterField) {
+    if field.is_nullable() {
+        query.push(filter_column(field)).push(" IS NOT NULL AND ");
+    }
+    query.push(filter_column(field));
+}
+
+fn push_filter_value(query: &mut QueryBuilder<'_, Postgres>, value: &FilterValue) {
+    match value {
+        FilterValue::Status(status) => query.push_bind(status.as_str().to_string()),
+        FilterValue::Integer(integer) => query.push_bind(*integer),
+        FilterValue::Text(text) => query.push_bind(text.clone()),
+        FilterValue::Timestamp(at) => query.push_bind(*at),
+    };
+}
+
+/// Appends `expression` as one parenthesized condition with every value bound.
+///
+/// Unset nullable columns match no comparison, so `NOT` never meets an SQL `NULL`.
+fn push_filter_expression(query: &mut QueryBuilder<'_, Postgres>, expression: &FilterExpression) {
+    match expression {
+        FilterExpression::All(operands) | FilterExpression::Any(operands) => {
+            let joiner = if matches!(expression, FilterExpression::All(_)) { " AND " } else { " OR " };
+            query.push("(");
+            for (index, operand) in operands.iter().enumerate() {
+                if index > 0 {
+                    query.push(joiner);
+                }
+                push_filter_expression(query, operand);
+            }
+            query.push(")");
+        }
+        FilterExpression::Not(operand) => {
+            query.push("NOT ");
+            push_filter_expression(query, operand);
+        }
+        FilterExpression::Compare { field, comparison, value } => {
+            query.push("(");
+            push_filter_column(query, *field);
+            match (comparison, value) {
+                (Comparison::Contains, FilterValue::Text(text)) => {
+                    query.push(" ILIKE ").push_bind(like_pattern(text)).push(" ESCAPE '\\'");
+                }
+                _ => {
+                    query.push(" ").push(comparison.as_str()).push(" ");
+                    push_filter_value(query, value);
+                }
+            }
+            query.push(")");
+        }
+        FilterExpression::In { field, values } => {
+            query.push("(");
+            push_filter_column(query, *field);
+            query.push(" IN (");
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    query.push(", ");
+                }
+                push_filter_value(query, value);
+            }
+            query.push("))");
+        }
+    }
+}
+
 #[async_trait]
 impl TaskRepository for PostgresTaskRepository {
     async fn find_all(&self) -> Result<Vec<Task>, RepositoryError> {
-        let rows = sqlx::query("SELECT task_id, name, priority, status, created_at, updated_at FROM tasks ORDER BY task_id")
-            .fetch_all(&self.pool)
+        let rows = sqlx::query_as!(
+            TaskRow,
+            "SELECT task_id, name, priority, description, status, created_at, updated_at, deleted_at, archived_at, created_by, rank FROM tasks WHERE deleted_at IS NULL AND ($1::varchar IS NULL OR workspace_id = $1) ORDER BY task_id",
+            current_workspace(),
+        )
+            .fetch_all(&mut *acquire(&self.pool).await?)
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
-
-        let mut tasks = Vec::new();
-        for row in rows {
-            let task_id: i32 = row.get("task_id");
-            let name: String = row.get("name");
-            let priority: Option<i32> = row.get("priority");
-            let status_str: String = row.get("status");
-            let created_at: DateTime<Utc> = row.get("created_at");
-            let updated_at: DateTime<Utc> = row.get("updated_at");
-            
-            let status = TaskStatus::from_str(&status_str)
-                .map_err(|e| RepositoryError::ValidationError(e))?;
-            
-            let task = Task::new_with_status(
-                TaskId::new(task_id),
-                name,
-                priority,
-                status,
-                created_at,
-                updated_at,
-            ).map_err(RepositoryError::ValidationError)?;
-            tasks.push(task);
+            .map_err(database_error)?;
+
+        rows_to_tasks(rows)
+    }
+
+    async fn find_matching(&self, filter: &TaskFilter, sort: TaskSort) -> Result<Vec<Task>, RepositoryError> {
+        let mut query = QueryBuilder::<Postgres>::new(
+            "SELECT task_id, name, priority, description, status, created_at, updated_at, deleted_at, archived_at, created_by, rank FROM tasks WHERE TRUE"
+        );
+
+        push_task_filter(&mut query, filter);
+
+        query.push(" ORDER BY ").push(sort_column(sort.field)).push(sort_direction(sort.order));
+        if sort.field != TaskSortField::Id {
+            query.push(", task_id");
         }
 
-        Ok(tasks)
+        let rows = query.build_query_as::<TaskRow>()
+            .fetch_all(&mut *acquire(&self.pool).await?)
+            .await
+            .map_err(database_error)?;
+
+        rows_to_tasks(rows)
+    }
+
+    async fn find_board(&self, filter: &TaskFilter, sort: TaskSort, column_size: usize) -> Result<Vec<BoardColumn>, RepositoryError> {
+        // Windowing over the filtered tasks counts and ranks every column in one round trip
+        let mut query = QueryBuilder::<Postgres>::new(
+            "SELECT task_id, name, priority, description, status, created_at, updated_at, deleted_at, archived_at, created_by, rank, column_count \
+             FROM (SELECT task_id, name, priority, description, status, created_at, updated_at, deleted_at, archived_at, created_by, rank, \
+             COUNT(*) OVER (PARTITION BY status) AS column_count, \
+             ROW_NUMBER() OVER (PARTITION BY status ORDER BY "
+        );
+        query.push(sort_column(sort.field)).push(sort_direction(sort.order)).push(", task_id) AS position FROM tasks WHERE TRUE");
+
+        push_task_filter(&mut query, filter);
+
+        query.push(") board WHERE position <= ").push_bind(column_size as i64).push(" ORDER BY status, position");
+
+        let rows = query.build_query_as::<BoardRow>()
+            .fetch_all(&mut *acquire(&self.pool).await?)
+            .await
+            .map_err(database_error)?;
+
+        rows_to_columns(rows)
+    }
+
+    async fn count_facets(&self, filter: &TaskFilter) -> Result<TaskFacets, RepositoryError> {
+        let mut connection = acquire(&self.pool).await?;
+
+        let mut statuses = QueryBuilder::<Postgres>::new("SELECT status, COUNT(*) FROM tasks WHERE TRUE");
+        push_task_filter(&mut statuses, filter);
+        statuses.push(" GROUP BY status");
+        let statuses: Vec<(String, i64)> = statuses.build_query_as()
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(database_error)?;
+
+        let mut priorities = QueryBuilder::<Postgres>::new("SELECT priority, COUNT(*) FROM tasks WHERE TRUE");
+        push_task_filter(&mut priorities, filter);
+        priorities.push(" GROUP BY priority");
+        let priorities: Vec<(Option<i32>, i64)> = priorities.build_query_as()
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(database_error)?;
+
+        facets_from_rows(statuses, priorities)
     }
 
     async fn find_by_id(&self, id: TaskId) -> Result<Option<Task>, RepositoryError> {
-        let row = sqlx::query("SELECT task_id, name, priority, status, created_at, updated_at FROM tasks WHERE task_id = $1")
-            .bind(id.value())
-            .fetch_optional(&self.pool)
+        let row = sqlx::query_as!(
+            TaskRow,
+            "SELECT task_id, name, priority, description, status, created_at, updated_at, deleted_at, archived_at, created_by, rank FROM tasks WHERE task_id = $1 AND deleted_at IS NULL AND ($2::varchar IS NULL OR workspace_id = $2)",
+            id.value(),
+            current_workspace(),
+        )
+            .fetch_optional(&mut *acquire(&self.pool).await?)
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(database_error)?;
 
-        match row {
-            Some(row) => {
-                let task_id: i32 = row.get("task_id");
-                let name: String = row.get("name");
-                let priority: Option<i32> = row.get("priority");
-                let status_str: String = row.get("status");
-                let created_at: DateTime<Utc> = row.get("created_at");
-                let updated_at: DateTime<Utc> = row.get("updated_at");
-                
-                let status = TaskStatus::from_str(&status_str)
-                    .map_err(|e| RepositoryError::ValidationError(e))?;
-                
-                let task = Task::new_with_status(
-                    TaskId::new(task_id),
-                    name,
-                    priority,
-                    status,
-                    created_at,
-                    updated_at,
-                ).map_err(RepositoryError::ValidationError)?;
-                Ok(Some(task))
-            }
-            None => Ok(None),
-        }
+        row.map(Task::try_from).transpose()
     }
 
     async fn find_by_priority(&self, priority: i32) -> Result<Vec<Task>, RepositoryError> {
-        let rows = sqlx::query("SELECT task_id, name, priority, status, created_at, updated_at FROM tasks WHERE priority = $1 ORDER BY task_id")
-            .bind(priority)
-            .fetch_all(&self.pool)
+        let rows = sqlx::query_as!(
+            TaskRow,
+            "SELECT task_id, name, priority, description, status, created_at, updated_at, deleted_at, archived_at, created_by, rank FROM tasks WHERE priority = $1 AND deleted_at IS NULL AND ($2::varchar IS NULL OR workspace_id = $2) ORDER BY task_id",
+            priority,
+            current_workspace(),
+        )
+            .fetch_all(&mut *acquire(&self.pool).await?)
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
-
-        let mut tasks = Vec::new();
-        for row in rows {
-            let task_id: i32 = row.get("task_id");
-            let name: String = row.get("name");
-            let priority: Option<i32> = row.get("priority");
-            let status_str: String = row.get("status");
-            let created_at: DateTime<Utc> = row.get("created_at");
-            let updated_at: DateTime<Utc> = row.get("updated_at");
-            
-            let status = TaskStatus::from_str(&status_str)
-                .map_err(|e| RepositoryError::ValidationError(e))?;
-            
-            let task = Task::new_with_status(
-                TaskId::new(task_id),
-                name,
-                priority,
-                status,
-                created_at,
-                updated_at,
-            ).map_err(RepositoryError::ValidationError)?;
-            tasks.push(task);
-        }
+            .map_err(database_error)?;
 
-        Ok(tasks)
+        rows_to_tasks(rows)
     }
 
-    async fn save(&self, task: &Task) -> Result<TaskId, RepositoryError> {
-        let row = sqlx::query("INSERT INTO tasks (name, priority, status, created_at, updated_at) VALUES ($1, $2, $3, $4, $5) RETURNING task_id")
-            .bind(&task.name)
-            .bind(task.priority)
-            .bind(task.status.as_str())
-            .bind(task.created_at)
-            .bind(task.updated_at)
-            .fetch_one(&self.pool)
+    async fn find_by_status(&self, status: &TaskStatus) -> Result<Vec<Task>, RepositoryError> {
+        let rows = sqlx::query_as!(
+            TaskRow,
+            "SELECT task_id, name, priority, description, status, created_at, updated_at, deleted_at, archived_at, created_by, rank FROM tasks WHERE status = $1 AND deleted_at IS NULL AND ($2::varchar IS NULL OR workspace_id = $2) ORDER BY task_id",
+            status.as_str(),
+            current_workspace(),
+        )
+            .fetch_all(&mut *acquire(&self.pool).await?)
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(database_error)?;
 
-        let task_id: i32 = row.get("task_id");
-        Ok(TaskId::new(task_id))
+        rows_to_tasks(rows)
+    }
+
+    async fn search_tasks(&self, query: &str) -> Result<Vec<Task>, RepositoryError> {
+        // Matches on the task itself and on any status change comment; the better of the two ranks wins
+        let rows = sqlx::query_as!(
+            TaskRow,
+            r#"
+            WITH query AS (SELECT websearch_to_tsquery('english', $1) AS q),
+            comment_ranks AS (
+                SELECT sh.task_id, MAX(ts_rank(to_tsvector('english', sh.comment), query.q)) AS rank
+                FROM status_history sh, query
+                WHERE sh.comment IS NOT NULL
+                AND ($2::varchar IS NULL OR sh.workspace_id = $2)
+                AND to_tsvector('english', sh.comment) @@ query.q
+                GROUP BY sh.task_id
+            )
+            SELECT t.task_id AS "task_id!", t.name AS "name!", t.priority, t.description, t.status AS "status!",
+                   t.created_at AS "created_at!", t.updated_at AS "updated_at!", t.deleted_at, t.archived_at, t.created_by, t.rank
+            FROM tasks t
+            CROSS JOIN query
+            LEFT JOIN comment_ranks cr ON cr.task_id = t.task_id
+            WHERE t.deleted_at IS NULL
+            AND ($2::varchar IS NULL OR t.workspace_id = $2)
+            AND (t.search_vector @@ query.q OR cr.task_id IS NOT NULL)
+            ORDER BY GREATEST(ts_rank(t.search_vector, query.q), COALESCE(cr.rank, 0)) DESC, t.task_id
+            "#,
+            query,
+            current_workspace(),
+        )
+            .fetch_all(&mut *acquire(&self.pool).await?)
+            .await
+            .map_err(database_error)?;
+
+        rows_to_tasks(rows)
+    }
+
+    async fn save(&self, task: &Task) -> Result<TaskId, RepositoryError> {
+        insert_task(&mut *acquire(&self.pool).await?, task).await
     }
 
     async fn update(&self, task: &Task) -> Result<(), RepositoryError> {
-        let result = sqlx::query("UPDATE tasks SET name = $1, priority = $2, status = $3, updated_at = $4 WHERE task_id = $5")
-            .bind(&task.name)
-            .bind(task.priority)
-            .bind(task.status.as_str())
-            .bind(task.updated_at)
-            .bind(task.id.value())
-            .execute(&self.pool)
+        update_task(&mut *acquire(&self.pool).await?, task).await
+    }
+
+    async fn update_fields(&self, id: TaskId, patch: &TaskPatch) -> Result<(), RepositoryError> {
+        update_task_fields(&mut *acquire(&self.pool).await?, id, patch).await
+    }
+
+    async fn set_rank(&self, id: TaskId, rank: &Rank) -> Result<(), RepositoryError> {
+        let result = sqlx::query!(
+            "UPDATE tasks SET rank = $1 WHERE task_id = $2 AND deleted_at IS NULL AND ($3::varchar IS NULL OR workspace_id = $3)",
+            rank.as_str(),
+            id.value(),
+            current_workspace(),
+        )
+            .execute(&mut *acquire(&self.pool).await?)
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(database_error)?;
 
         if result.rows_affected() == 0 {
             return Err(RepositoryError::NotFound(
-                format!("Task with id {} not found", task.id.value())
+                format!("Task with id {} not found", id.value())
             ));
         }
 
         Ok(())
     }
 
+    async fn reprioritize(&self, filter: &TaskFilter, pairs: &[(i32, i32)], updated_at: DateTime<Utc>) -> Result<Vec<PriorityChange>, RepositoryError> {
+        let (from, to): (Vec<i32>, Vec<i32>) = pairs.iter().copied().unzip();
+        let rows = sqlx::query!(
+            r#"
+            UPDATE tasks AS t
+            SET priority = m.to_priority, updated_at = $3
+            FROM UNNEST($1::int4[], $2::int4[]) AS m(from_priority, to_priority)
+            WHERE t.priority = m.from_priority
+              AND t.deleted_at IS NULL
+              AND t.archived_at IS NULL
+              AND ($4::varchar IS NULL OR t.status = $4)
+              AND ($5::int4 IS NULL OR t.priority = $5)
+              AND ($6::varchar IS NULL OR t.workspace_id = $6)
+            RETURNING t.task_id, m.from_priority AS "from_priority!", m.to_priority AS "to_priority!"
+            "#,
+            &from,
+            &to,
+            updated_at,
+            filter.status.as_ref().map(TaskStatus::as_str),
+            filter.priority,
+            current_workspace(),
+        )
+            .fetch_all(&mut *acquire(&self.pool).await?)
+            .await
+            .map_err(database_error)?;
+
+        let mut changes: Vec<PriorityChange> = rows
+            .into_iter()
+            .map(|row| PriorityChange { task_id: TaskId::new(row.task_id), from: row.from_priority, to: row.to_priority })
+            .collect();
+        changes.sort_by_key(|change| change.task_id.value());
+        Ok(changes)
+    }
+
+    async fn save_with_history(&self, task: &Task, history: &StatusHistory) -> Result<TaskId, RepositoryError> {
+        let mut connection = acquire(&self.pool).await?;
+        let mut tx = connection.begin().await
+            .map_err(database_error)?;
+
+        let task_id = insert_task(&mut *tx, task).await?;
+        let history = StatusHistory { task_id: task_id.value(), ..history.clone() };
+        insert_status_history(&mut *tx, &history).await?;
+
+        tx.commit().await
+            .map_err(database_error)?;
+        Ok(task_id)
+    }
+
+    async fn update_with_history(&self, task: &Task, history: &StatusHistory) -> Result<(), RepositoryError> {
+        let mut connection = acquire(&self.pool).await?;
+        let mut tx = connection.begin().await
+            .map_err(database_error)?;
+
+        update_task(&mut *tx, task).await?;
+        insert_status_history(&mut *tx, history).await?;
+
+        tx.commit().await
+            .map_err(database_error)
+    }
+
     async fn delete(&self, id: TaskId) -> Result<(), RepositoryError> {
-        let result = sqlx::query("DELETE FROM tasks WHERE task_id = $1")
-            .bind(id.value())
-            .execute(&self.pool)
+        let result = sqlx::query!(
+            "UPDATE tasks SET deleted_at = NOW() WHERE task_id = $1 AND deleted_at IS NULL AND ($2::varchar IS NULL OR workspace_id = $2)",
+            id.value(),
+            current_workspace(),
+        )
+            .execute(&mut *acquire(&self.pool).await?)
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(database_error)?;
 
         if result.rows_affected() == 0 {
             return Err(RepositoryError::NotFound(
@@ -163,4 +566,23 @@ impl TaskRepository for PostgresTaskRepository {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    async fn restore(&self, id: TaskId) -> Result<Task, RepositoryError> {
+        let row = sqlx::query_as!(
+            TaskRow,
+            "UPDATE tasks SET deleted_at = NULL WHERE task_id = $1 AND deleted_at IS NOT NULL AND ($2::varchar IS NULL OR workspace_id = $2) RETURNING task_id, name, priority, description, status, created_at, updated_at, deleted_at, archived_at, created_by, rank",
+            id.value(),
+            current_workspace(),
+        )
+            .fetch_optional(&mut *acquire(&self.pool).await?)
+            .await
+            .map_err(database_error)?;
+
+        match row {
+            Some(row) => Task::try_from(row),
+            None => Err(RepositoryError::NotFound(
+                format!("Deleted task with id {} not found", id.value())
+            )),
+        }
+    }
+}
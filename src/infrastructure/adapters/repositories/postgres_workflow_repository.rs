@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+use super::database_error::database_error;
+use crate::domain::{RepositoryError, WorkflowDefinition, WorkflowRepository};
+
+/// Workflows belong to workspaces, so they live on the default database beside them
+pub struct PostgresWorkflowRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkflowRepository for PostgresWorkflowRepository {
+    async fn find_by_workspace(&self, workspace_id: &str) -> Result<Option<WorkflowDefinition>, RepositoryError> {
+        let row = sqlx::query("SELECT definition::text AS definition FROM workspace_workflows WHERE workspace_id = $1")
+            .bind(workspace_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        row.map(|row| {
+            let definition: String = row.get("definition");
+            serde_json::from_str(&definition)
+                .map_err(|e| RepositoryError::ValidationError(format!("Invalid workflow of workspace {}: {}", workspace_id, e)))
+        })
+        .transpose()
+    }
+
+    async fn save(&self, workspace_id: &str, workflow: &WorkflowDefinition, updated_by: &str, updated_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        let definition = serde_json::to_string(workflow)
+            .map_err(|e| RepositoryError::ValidationError(format!("Workflow could not be serialized: {}", e)))?;
+        sqlx::query(
+            "INSERT INTO workspace_workflows (workspace_id, definition, updated_by, updated_at) VALUES ($1, $2::jsonb, $3, $4)
+             ON CONFLICT (workspace_id) DO UPDATE SET definition = EXCLUDED.definition, updated_by = EXCLUDED.updated_by, updated_at = EXCLUDED.updated_at"
+        )
+        .bind(workspace_id)
+        .bind(definition)
+        .bind(updated_by)
+        .bind(updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, workspace_id: &str) -> Result<(), RepositoryError> {
+        let deleted = sqlx::query("DELETE FROM workspace_workflows WHERE workspace_id = $1")
+            .bind(workspace_id)
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        if deleted.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!("Workspace {} has no workflow of its own", workspace_id)));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,300 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use redis::aio::{ConnectionManager, ConnectionManagerConfig};
+use redis::AsyncCommands;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::database::{current_workspace, in_request_transaction, redact_connection_string};
+use crate::domain::{
+    BoardColumn, PriorityChange, Rank, RepositoryError, StatusHistory, Task, TaskFacets, TaskFilter, TaskId, TaskPatch, TaskRepository, TaskSort, TaskStatus,
+};
+
+const KEY_PREFIX: &str = "axum_postgres:tasks";
+/// Upper bound on a single cache command, so a slow Redis delays a read by at most this much
+const COMMAND_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// A task as stored in Redis
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedTask {
+    id: i32,
+    name: String,
+    priority: Option<i32>,
+    description: Option<String>,
+    status: TaskStatus,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    /// Defaulted so entries cached before archival existed still load
+    #[serde(default)]
+    archived_at: Option<DateTime<Utc>>,
+    /// Defaulted so entries cached before creators were recorded still load
+    #[serde(default)]
+    created_by: Option<String>,
+    /// Defaulted so entries cached before tasks could be moved by hand still load
+    #[serde(default)]
+    rank: Option<String>,
+}
+
+impl From<&Task> for CachedTask {
+    fn from(task: &Task) -> Self {
+        Self {
+            id: task.id.value(),
+            name: task.name.clone(),
+            priority: task.priority,
+            description: task.description.clone(),
+            status: task.status.clone(),
+            created_at: task.created_at,
+            updated_at: task.updated_at,
+            deleted_at: task.deleted_at,
+            archived_at: task.archived_at,
+            created_by: task.created_by.clone(),
+            rank: task.rank.as_ref().map(|rank| rank.as_str().to_string()),
+        }
+    }
+}
+
+/// A `find_by_id` result with the workspace it was read in; readers in another workspace treat it as a miss
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedLookup {
+    workspace: Option<String>,
+    task: Option<CachedTask>,
+}
+
+impl CachedTask {
+    fn into_task(self) -> Option<Task> {
+        let rank = self.rank.map(|rank| Rank::parse(&rank)).transpose().ok()?;
+        Task::new_with_status(TaskId::new(self.id), self.name, self.priority, self.status, self.created_at, self.updated_at)
+            .and_then(|task| task.with_description(self.description))
+            .map(|task| task.with_deleted_at(self.deleted_at).with_archived_at(self.archived_at).with_created_by(self.created_by).with_rank(rank))
+            .ok()
+    }
+}
+
+/// Caches `find_by_id` and `find_all` in Redis in front of another task repository.
+///
+/// Unlike [`CachedTaskRepository`](super::CachedTaskRepository), entries are shared by every
+/// server instance pointing at the same Redis. Each write deletes the written task's entry and
+/// the cached listing. The listing is a hash with one field per workspace, so the deletion
+/// clears every workspace's; its TTL restarts whenever one of them is stored. Redis errors are logged and the call falls through to the inner
+/// repository, so an unavailable cache slows reads down instead of failing them. An
+/// invalidation that fails leaves the old entry in place until its TTL runs out.
+pub struct RedisCachedTaskRepository {
+    inner: Arc<dyn TaskRepository>,
+    connection: ConnectionManager,
+    ttl: Duration,
+}
+
+impl RedisCachedTaskRepository {
+    /// Connects lazily to the Redis at `url`, so startup does not wait for it to be reachable
+    pub fn connect(inner: Arc<dyn TaskRepository>, url: &str, ttl: Duration) -> Result<Self, redis::RedisError> {
+        let config = ConnectionManagerConfig::new()
+            .set_connection_timeout(Some(COMMAND_TIMEOUT))
+            .set_response_timeout(Some(COMMAND_TIMEOUT))
+            .set_number_of_retries(1);
+        let connection = ConnectionManager::new_lazy_with_config(redis::Client::open(url)?, config)?;
+        Ok(Self { inner, connection, ttl })
+    }
+
+    fn task_key(id: TaskId) -> String {
+        format!("{}:{}", KEY_PREFIX, id.value())
+    }
+
+    fn all_key() -> String {
+        format!("{}:all", KEY_PREFIX)
+    }
+
+    /// The listing's hash field for the current workspace; `*` when unscoped
+    fn all_field() -> String {
+        current_workspace().unwrap_or_else(|| "*".to_string())
+    }
+
+    async fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let cached: Option<String> = match self.connection.clone().get(key).await {
+            Ok(cached) => cached,
+            Err(error) => {
+                tracing::warn!("Redis cache read failed: {}", redact_connection_string(&error.to_string()));
+                return None;
+            }
+        };
+        cached.and_then(|json| serde_json::from_str(&json).ok())
+    }
+
+    async fn put<T: Serialize>(&self, key: &str, value: &T) {
+        let Ok(json) = serde_json::to_string(value) else {
+            return;
+        };
+        let result: redis::RedisResult<()> = self.connection.clone().set_ex(key, json, self.ttl.as_secs().max(1)).await;
+        if let Err(error) = result {
+            tracing::warn!("Redis cache write failed: {}", redact_connection_string(&error.to_string()));
+        }
+    }
+
+    async fn hget<T: DeserializeOwned>(&self, key: &str, field: &str) -> Option<T> {
+        let cached: Option<String> = match self.connection.clone().hget(key, field).await {
+            Ok(cached) => cached,
+            Err(error) => {
+                tracing::warn!("Redis cache read failed: {}", redact_connection_string(&error.to_string()));
+                return None;
+            }
+        };
+        cached.and_then(|json| serde_json::from_str(&json).ok())
+    }
+
+    async fn hput<T: Serialize>(&self, key: &str, field: &str, value: &T) {
+        let Ok(json) = serde_json::to_string(value) else {
+            return;
+        };
+        let ttl = self.ttl.as_secs().max(1) as i64;
+        let result: redis::RedisResult<()> = redis::pipe().atomic()
+            .hset(key, field, json).ignore()
+            .expire(key, ttl).ignore()
+            .query_async(&mut self.connection.clone()).await;
+        if let Err(error) = result {
+            tracing::warn!("Redis cache write failed: {}", redact_connection_string(&error.to_string()));
+        }
+    }
+
+    /// Drops the given tasks' entries and the cached listing
+    async fn invalidate(&self, ids: impl IntoIterator<Item = TaskId>) {
+        let keys: Vec<String> = ids.into_iter().map(Self::task_key).chain([Self::all_key()]).collect();
+        let result: redis::RedisResult<()> = self.connection.clone().del(keys).await;
+        if let Err(error) = result {
+            tracing::warn!("Redis cache invalidation failed: {}", redact_connection_string(&error.to_string()));
+        }
+    }
+}
+
+#[async_trait]
+impl TaskRepository for RedisCachedTaskRepository {
+    async fn find_all(&self) -> Result<Vec<Task>, RepositoryError> {
+        if in_request_transaction().await {
+            return self.inner.find_all().await;
+        }
+        let (key, field) = (Self::all_key(), Self::all_field());
+        if let Some(cached) = self.hget::<Vec<CachedTask>>(&key, &field).await {
+            if let Some(tasks) = cached.into_iter().map(CachedTask::into_task).collect() {
+                return Ok(tasks);
+            }
+        }
+        let tasks = self.inner.find_all().await?;
+        self.hput(&key, &field, &tasks.iter().map(CachedTask::from).collect::<Vec<_>>()).await;
+        Ok(tasks)
+    }
+
+    async fn find_matching(&self, filter: &TaskFilter, sort: TaskSort) -> Result<Vec<Task>, RepositoryError> {
+        self.inner.find_matching(filter, sort).await
+    }
+
+    async fn count_facets(&self, filter: &TaskFilter) -> Result<TaskFacets, RepositoryError> {
+        self.inner.count_facets(filter).await
+    }
+
+    async fn find_board(&self, filter: &TaskFilter, sort: TaskSort, column_size: usize) -> Result<Vec<BoardColumn>, RepositoryError> {
+        self.inner.find_board(filter, sort, column_size).await
+    }
+
+    async fn find_by_id(&self, id: TaskId) -> Result<Option<Task>, RepositoryError> {
+        if in_request_transaction().await {
+            return self.inner.find_by_id(id).await;
+        }
+        let key = Self::task_key(id);
+        let workspace = current_workspace();
+        if let Some(cached) = self.get::<CachedLookup>(&key).await.filter(|cached| cached.workspace == workspace) {
+            match cached.task {
+                None => return Ok(None),
+                Some(task) => {
+                    if let Some(task) = task.into_task() {
+                        return Ok(Some(task));
+                    }
+                }
+            }
+        }
+        let task = self.inner.find_by_id(id).await?;
+        self.put(&key, &CachedLookup { workspace, task: task.as_ref().map(CachedTask::from) }).await;
+        Ok(task)
+    }
+
+    async fn find_by_priority(&self, priority: i32) -> Result<Vec<Task>, RepositoryError> {
+        self.inner.find_by_priority(priority).await
+    }
+
+    async fn find_by_status(&self, status: &TaskStatus) -> Result<Vec<Task>, RepositoryError> {
+        self.inner.find_by_status(status).await
+    }
+
+    async fn search_tasks(&self, query: &str) -> Result<Vec<Task>, RepositoryError> {
+        self.inner.search_tasks(query).await
+    }
+
+    async fn save(&self, task: &Task) -> Result<TaskId, RepositoryError> {
+        let id = self.inner.save(task).await?;
+        self.invalidate([id]).await;
+        Ok(id)
+    }
+
+    async fn update(&self, task: &Task) -> Result<(), RepositoryError> {
+        let result = self.inner.update(task).await;
+        self.invalidate([task.id]).await;
+        result
+    }
+
+    async fn update_fields(&self, id: TaskId, patch: &TaskPatch) -> Result<(), RepositoryError> {
+        let result = self.inner.update_fields(id, patch).await;
+        self.invalidate([id]).await;
+        result
+    }
+
+    async fn set_rank(&self, id: TaskId, rank: &Rank) -> Result<(), RepositoryError> {
+        let result = self.inner.set_rank(id, rank).await;
+        self.invalidate([id]).await;
+        result
+    }
+
+    async fn reprioritize(&self, filter: &TaskFilter, pairs: &[(i32, i32)], updated_at: DateTime<Utc>) -> Result<Vec<PriorityChange>, RepositoryError> {
+        let changes = self.inner.reprioritize(filter, pairs, updated_at).await?;
+        self.invalidate(changes.iter().map(|change| change.task_id)).await;
+        Ok(changes)
+    }
+
+    async fn save_with_history(&self, task: &Task, history: &StatusHistory) -> Result<TaskId, RepositoryError> {
+        let id = self.inner.save_with_history(task, history).await?;
+        self.invalidate([id]).await;
+        Ok(id)
+    }
+
+    async fn update_with_history(&self, task: &Task, history: &StatusHistory) -> Result<(), RepositoryError> {
+        let result = self.inner.update_with_history(task, history).await;
+        self.invalidate([task.id]).await;
+        result
+    }
+
+    async fn delete(&self, id: TaskId) -> Result<(), RepositoryError> {
+        let result = self.inner.delete(id).await;
+        self.invalidate([id]).await;
+        result
+    }
+
+    async fn restore(&self, id: TaskId) -> Result<Task, RepositoryError> {
+        let result = self.inner.restore(id).await;
+        self.invalidate([id]).await;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_tasks_round_trip() {
+        let task = Task::new(TaskId::new(7), "Cached".to_string(), Some(4)).unwrap()
+            .with_description(Some("Kept in Redis".to_string())).unwrap();
+
+        let json = serde_json::to_string(&CachedTask::from(&task)).unwrap();
+        let restored = serde_json::from_str::<CachedTask>(&json).unwrap().into_task();
+
+        assert_eq!(restored, Some(task));
+    }
+}
@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool};
+use uuid::Uuid;
+use super::database_error::database_error;
+use crate::domain::{ApiKey, ApiKeyRepository, UserRole, RepositoryError};
+
+pub struct SqliteApiKeyRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteApiKeyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_api_key(&self, row: &sqlx::sqlite::SqliteRow) -> Result<ApiKey, RepositoryError> {
+        let role_str: String = row.get("role");
+
+        let role = UserRole::from_str(&role_str)
+            .map_err(RepositoryError::ValidationError)?;
+
+        Ok(ApiKey {
+            id: row.get("id"),
+            name: row.get("name"),
+            key_prefix: row.get("key_prefix"),
+            key_hash: row.get("key_hash"),
+            role,
+            workspace_id: row.get("workspace_id"),
+            created_by: row.get("created_by"),
+            created_at: row.get("created_at"),
+        })
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for SqliteApiKeyRepository {
+    async fn save(&self, api_key: &ApiKey) -> Result<(), RepositoryError> {
+        let id = Uuid::parse_str(&api_key.id)
+            .map_err(|e| RepositoryError::ValidationError(format!("Invalid API key id: {}", e)))?;
+
+        sqlx::query(
+            "INSERT INTO api_keys (id, name, key_prefix, key_hash, role, workspace_id, created_by, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(id.to_string())
+        .bind(&api_key.name)
+        .bind(&api_key.key_prefix)
+        .bind(&api_key.key_hash)
+        .bind(api_key.role.as_str())
+        .bind(&api_key.workspace_id)
+        .bind(&api_key.created_by)
+        .bind(api_key.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT id, name, key_prefix, key_hash, role, workspace_id, created_by, created_at
+             FROM api_keys
+             WHERE key_hash = ?"
+        )
+        .bind(key_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        row.map(|row| self.row_to_api_key(&row)).transpose()
+    }
+}
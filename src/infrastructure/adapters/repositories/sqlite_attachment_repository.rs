@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use super::database_error::database_error;
+use crate::domain::{Attachment, AttachmentRepository, RepositoryError, TaskId};
+
+pub struct SqliteAttachmentRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAttachmentRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn row_to_attachment(row: &sqlx::sqlite::SqliteRow) -> Attachment {
+    Attachment {
+        id: row.get("id"),
+        task_id: TaskId::new(row.get("task_id")),
+        file_name: row.get("file_name"),
+        content_type: row.get("content_type"),
+        size_bytes: row.get("size_bytes"),
+        storage_key: row.get("storage_key"),
+        uploaded_by: row.get("uploaded_by"),
+        uploaded_at: row.get("uploaded_at"),
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for SqliteAttachmentRepository {
+    async fn save(&self, attachment: &Attachment) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO task_attachments (id, task_id, file_name, content_type, size_bytes, storage_key, uploaded_by, uploaded_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&attachment.id)
+        .bind(attachment.task_id.value())
+        .bind(&attachment.file_name)
+        .bind(&attachment.content_type)
+        .bind(attachment.size_bytes)
+        .bind(&attachment.storage_key)
+        .bind(&attachment.uploaded_by)
+        .bind(attachment.uploaded_at)
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn find_by_task_id(&self, task_id: TaskId) -> Result<Vec<Attachment>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT id, task_id, file_name, content_type, size_bytes, storage_key, uploaded_by, uploaded_at
+             FROM task_attachments WHERE task_id = ? ORDER BY uploaded_at, id"
+        )
+        .bind(task_id.value())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(rows.iter().map(row_to_attachment).collect())
+    }
+}
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool};
+use super::database_error::database_error;
+use crate::database::current_workspace;
+use crate::domain::{AutomationRule, AutomationRuleRepository, RepositoryError};
+
+const RULE_COLUMNS: &str = "id, workspace_id, name, enabled, rule_trigger, rule_action, created_by, created_at, updated_at";
+
+pub struct SqliteAutomationRuleRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAutomationRuleRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_rule(&self, row: &sqlx::sqlite::SqliteRow) -> Result<AutomationRule, RepositoryError> {
+        let id: String = row.get("id");
+        let trigger: String = row.get("rule_trigger");
+        let action: String = row.get("rule_action");
+
+        Ok(AutomationRule {
+            trigger: serde_json::from_str(&trigger)
+                .map_err(|e| RepositoryError::ValidationError(format!("Invalid trigger of rule {}: {}", id, e)))?,
+            action: serde_json::from_str(&action)
+                .map_err(|e| RepositoryError::ValidationError(format!("Invalid action of rule {}: {}", id, e)))?,
+            id,
+            workspace_id: row.get("workspace_id"),
+            name: row.get("name"),
+            enabled: row.get("enabled"),
+            created_by: row.get("created_by"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+}
+
+fn to_json(value: &impl serde::Serialize) -> Result<String, RepositoryError> {
+    serde_json::to_string(value)
+        .map_err(|e| RepositoryError::ValidationError(format!("Rule could not be serialized: {}", e)))
+}
+
+#[async_trait]
+impl AutomationRuleRepository for SqliteAutomationRuleRepository {
+    async fn save(&self, rule: &AutomationRule) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO automation_rules (id, workspace_id, name, enabled, rule_trigger, rule_action, created_by, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&rule.id)
+        .bind(&rule.workspace_id)
+        .bind(&rule.name)
+        .bind(rule.enabled)
+        .bind(to_json(&rule.trigger)?)
+        .bind(to_json(&rule.action)?)
+        .bind(&rule.created_by)
+        .bind(rule.created_at)
+        .bind(rule.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, rule: &AutomationRule) -> Result<(), RepositoryError> {
+        let updated = sqlx::query(
+            "UPDATE automation_rules
+             SET name = ?, enabled = ?, rule_trigger = ?, rule_action = ?, updated_at = ?
+             WHERE id = ? AND (? IS NULL OR workspace_id = ?)"
+        )
+        .bind(&rule.name)
+        .bind(rule.enabled)
+        .bind(to_json(&rule.trigger)?)
+        .bind(to_json(&rule.action)?)
+        .bind(rule.updated_at)
+        .bind(&rule.id)
+        .bind(current_workspace())
+        .bind(current_workspace())
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        if updated.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!("Automation rule {} not found", rule.id)));
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        let deleted = sqlx::query("DELETE FROM automation_rules WHERE id = ? AND (? IS NULL OR workspace_id = ?)")
+            .bind(id)
+            .bind(current_workspace())
+            .bind(current_workspace())
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        if deleted.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!("Automation rule {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<AutomationRule>, RepositoryError> {
+        let row = sqlx::query(&format!("SELECT {} FROM automation_rules WHERE id = ? AND (? IS NULL OR workspace_id = ?)", RULE_COLUMNS))
+            .bind(id)
+            .bind(current_workspace())
+            .bind(current_workspace())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        row.map(|row| self.row_to_rule(&row)).transpose()
+    }
+
+    async fn find_all(&self) -> Result<Vec<AutomationRule>, RepositoryError> {
+        let rows = sqlx::query(&format!("SELECT {} FROM automation_rules WHERE ? IS NULL OR workspace_id = ? ORDER BY created_at, id", RULE_COLUMNS))
+            .bind(current_workspace())
+            .bind(current_workspace())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.iter().map(|row| self.row_to_rule(row)).collect()
+    }
+}
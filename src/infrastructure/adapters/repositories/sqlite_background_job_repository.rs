@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{SqlitePool, Row};
+use std::time::Duration;
+use super::database_error::database_error;
+use crate::domain::{BackgroundJob, BackgroundJobRepository, RepositoryError};
+
+const JOB_COLUMNS: &str = "name, interval_ms, paused, run_requested, last_started_at, last_finished_at, last_error, next_run_at";
+
+pub struct SqliteBackgroundJobRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteBackgroundJobRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn row_to_job(&self, row: &sqlx::sqlite::SqliteRow) -> BackgroundJob {
+        let interval_ms: i64 = row.get("interval_ms");
+        BackgroundJob {
+            name: row.get("name"),
+            interval: Duration::from_millis(interval_ms.max(0) as u64),
+            paused: row.get("paused"),
+            run_requested: row.get("run_requested"),
+            last_started_at: row.get("last_started_at"),
+            last_finished_at: row.get("last_finished_at"),
+            last_error: row.get("last_error"),
+            next_run_at: row.get("next_run_at"),
+        }
+    }
+
+    /// Applies `assignment` to the job and returns it, or `NotFound`
+    async fn update(&self, name: &str, assignment: &str) -> Result<BackgroundJob, RepositoryError> {
+        let row = sqlx::query(&format!("UPDATE background_jobs SET {} WHERE name = ? RETURNING {}", assignment, JOB_COLUMNS))
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        row.map(|row| self.row_to_job(&row))
+            .ok_or_else(|| RepositoryError::NotFound(format!("Background job '{}' not found", name)))
+    }
+}
+
+#[async_trait]
+impl BackgroundJobRepository for SqliteBackgroundJobRepository {
+    async fn register(&self, name: &str, interval: Duration, first_run_at: DateTime<Utc>) -> Result<BackgroundJob, RepositoryError> {
+        let row = sqlx::query(&format!(
+            "INSERT INTO background_jobs (name, interval_ms, next_run_at)
+             VALUES (?, ?, ?)
+             ON CONFLICT (name) DO UPDATE SET interval_ms = EXCLUDED.interval_ms, next_run_at = EXCLUDED.next_run_at
+             RETURNING {}",
+            JOB_COLUMNS
+        ))
+        .bind(name)
+        .bind(interval.as_millis() as i64)
+        .bind(first_run_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(self.row_to_job(&row))
+    }
+
+    async fn find_all(&self) -> Result<Vec<BackgroundJob>, RepositoryError> {
+        let rows = sqlx::query(&format!("SELECT {} FROM background_jobs ORDER BY name", JOB_COLUMNS))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        Ok(rows.iter().map(|row| self.row_to_job(row)).collect())
+    }
+
+    async fn set_paused(&self, name: &str, paused: bool) -> Result<BackgroundJob, RepositoryError> {
+        self.update(name, if paused { "paused = 1" } else { "paused = 0" }).await
+    }
+
+    async fn request_run(&self, name: &str) -> Result<BackgroundJob, RepositoryError> {
+        self.update(name, "run_requested = 1").await
+    }
+
+    async fn claim_run(&self, name: &str, now: DateTime<Utc>, retry_at: DateTime<Utc>) -> Result<bool, RepositoryError> {
+        let claimed = sqlx::query(
+            "UPDATE background_jobs
+             SET run_requested = 0, last_started_at = ?, next_run_at = ?
+             WHERE name = ? AND (run_requested OR (NOT paused AND next_run_at <= ?))"
+        )
+        .bind(now)
+        .bind(retry_at)
+        .bind(name)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(claimed.rows_affected() == 1)
+    }
+
+    async fn finish_run(
+        &self,
+        name: &str,
+        finished_at: DateTime<Utc>,
+        error: Option<&str>,
+        next_run_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "UPDATE background_jobs
+             SET last_finished_at = ?, last_error = ?, next_run_at = ?
+             WHERE name = ?"
+        )
+        .bind(finished_at)
+        .bind(error)
+        .bind(next_run_at)
+        .bind(name)
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+}
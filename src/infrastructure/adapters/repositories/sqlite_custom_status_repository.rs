@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use super::database_error::database_error;
+use crate::domain::{CustomStatus, CustomStatusRepository, RepositoryError, StatusCategory, TaskStatus};
+
+const STATUS_COLUMNS: &str = "name, category, created_by, created_at";
+
+pub struct SqliteCustomStatusRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteCustomStatusRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn row_to_custom_status(row: &sqlx::sqlite::SqliteRow) -> Result<CustomStatus, RepositoryError> {
+    let name: String = row.get("name");
+    let category: String = row.get("category");
+    Ok(CustomStatus {
+        status: TaskStatus::custom(&name).map_err(RepositoryError::ValidationError)?,
+        category: StatusCategory::from_str(&category).map_err(RepositoryError::ValidationError)?,
+        created_by: row.get("created_by"),
+        created_at: row.get("created_at"),
+    })
+}
+
+#[async_trait]
+impl CustomStatusRepository for SqliteCustomStatusRepository {
+    async fn save(&self, status: &CustomStatus) -> Result<(), RepositoryError> {
+        sqlx::query("INSERT INTO statuses (name, category, created_by, created_at) VALUES (?, ?, ?, ?)")
+            .bind(status.name())
+            .bind(status.category.as_str())
+            .bind(&status.created_by)
+            .bind(status.created_at)
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, name: &str) -> Result<(), RepositoryError> {
+        let deleted = sqlx::query(
+            "DELETE FROM statuses WHERE name = ?1 AND NOT EXISTS (SELECT 1 FROM tasks WHERE status = ?1)"
+        )
+        .bind(name)
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        if deleted.rows_affected() == 0 {
+            let exists = sqlx::query("SELECT 1 FROM statuses WHERE name = ?")
+                .bind(name)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(database_error)?;
+            return match exists {
+                Some(_) => Err(RepositoryError::Conflict {
+                    field: "name".to_string(),
+                    message: format!("Status {} still has tasks", name),
+                }),
+                None => Err(RepositoryError::NotFound(format!("Status {} not found", name))),
+            };
+        }
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<CustomStatus>, RepositoryError> {
+        let rows = sqlx::query(&format!("SELECT {} FROM statuses ORDER BY created_at, name", STATUS_COLUMNS))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.iter().map(row_to_custom_status).collect()
+    }
+}
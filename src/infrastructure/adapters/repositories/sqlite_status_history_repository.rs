@@ -0,0 +1,432 @@
+use async_trait::async_trait;
+use sqlx::{types::Json, QueryBuilder, Row, Sqlite, SqliteExecutor, SqlitePool};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+use super::database_error::database_error;
+use super::sqlite_task_repository::push_workspace;
+use crate::database::{current_workspace, workspace_for_new_tasks};
+use super::history_analytics::{completion_time_stats, creation_cohorts, cumulative_flow, priority_completions, transition_matrix};
+use crate::domain::{ChecklistItem, StatusHistory, StatusHistoryRepository, TaskAnalytics, TaskStatus, CycleTimeStats, PriorityCompletion, TransitionEdge, Cohort, CohortBucket, CumulativeFlow, UserRole, HistoryFilter, Pagination, SortOrder, RepositoryError};
+
+const HISTORY_COLUMNS: &str = "id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role, checklist, review_stage";
+
+/// Status history stored in SQLite, for development without Postgres.
+///
+/// The reports Postgres computes with window functions and `PERCENTILE_CONT` are computed in
+/// Rust from the rows involved, with the same results.
+pub struct SqliteStatusHistoryRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteStatusHistoryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn fetch(&self, query: &str, bind: impl FnOnce(QueryBuilder<'_, Sqlite>) -> QueryBuilder<'_, Sqlite>) -> Result<Vec<StatusHistory>, RepositoryError> {
+        let mut query = bind(QueryBuilder::new(query));
+        let rows = query.build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.iter().map(row_to_status_history).collect()
+    }
+}
+
+fn row_to_status_history(row: &sqlx::sqlite::SqliteRow) -> Result<StatusHistory, RepositoryError> {
+    let from_status: Option<String> = row.get("from_status");
+    let to_status: String = row.get("to_status");
+    let user_role: String = row.get("user_role");
+    let checklist: Option<Json<Vec<ChecklistItem>>> = row.get("checklist");
+    let review_stage: Option<String> = row.get("review_stage");
+
+    let from_status = from_status
+        .map(|status| TaskStatus::parse(&status))
+        .transpose()
+        .map_err(RepositoryError::ValidationError)?;
+
+    Ok(StatusHistory::new(
+        row.get("id"),
+        row.get("task_id"),
+        from_status,
+        TaskStatus::parse(&to_status).map_err(RepositoryError::ValidationError)?,
+        row.get("changed_at"),
+        row.get::<Option<String>, _>("changed_by").unwrap_or_default(),
+        row.get("comment"),
+        UserRole::from_str(&user_role).map_err(RepositoryError::ValidationError)?,
+    ).with_checklist(checklist.map(|Json(items)| items))
+    .with_review_stage(review_stage))
+}
+
+/// Appends the `filter` conditions to a query that already has a WHERE clause
+fn push_history_filter(query: &mut QueryBuilder<'_, Sqlite>, filter: &HistoryFilter) {
+    push_workspace(query, "workspace_id");
+    if let Some(task_id) = filter.task_id {
+        query.push(" AND task_id = ").push_bind(task_id);
+    }
+    if let Some(changed_by) = &filter.changed_by {
+        query.push(" AND changed_by = ").push_bind(changed_by.clone());
+    }
+    if let Some(to_status) = &filter.to_status {
+        query.push(" AND to_status = ").push_bind(to_status.as_str().to_string());
+    }
+    if let Some(start_date) = filter.start_date {
+        query.push(" AND changed_at >= ").push_bind(start_date);
+    }
+    if let Some(end_date) = filter.end_date {
+        query.push(" AND changed_at <= ").push_bind(end_date);
+    }
+}
+
+/// Inserts a status history record using any executor, so callers can
+/// include it in a wider transaction. The entry joins its task's workspace.
+pub(crate) async fn insert_sqlite_status_history<'e, E>(executor: E, history: &StatusHistory) -> Result<String, RepositoryError>
+where
+    E: SqliteExecutor<'e>,
+{
+    let id = Uuid::parse_str(&history.id)
+        .map_err(|e| RepositoryError::ValidationError(format!("Invalid UUID: {}", e)))?;
+
+    // Status history records are immutable once created, so there is no upsert
+    sqlx::query(
+        "INSERT INTO status_history (id, task_id, from_status, to_status, changed_at, changed_by, comment, user_role, checklist, review_stage, workspace_id)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, COALESCE((SELECT workspace_id FROM tasks WHERE task_id = ?), ?))"
+    )
+    .bind(id.to_string())
+    .bind(history.task_id)
+    .bind(history.from_status.as_ref().map(TaskStatus::as_str))
+    .bind(history.to_status.as_str())
+    .bind(history.changed_at)
+    .bind(&history.changed_by)
+    .bind(&history.comment)
+    .bind(history.user_role.as_str())
+    .bind(history.checklist.as_ref().map(Json))
+    .bind(&history.review_stage)
+    .bind(history.task_id)
+    .bind(workspace_for_new_tasks())
+    .execute(executor)
+    .await
+    .map_err(|e| match database_error(e) {
+        RepositoryError::Conflict { .. } => RepositoryError::ValidationError(format!(
+            "Status history record with ID {} already exists. Audit records are immutable.",
+            id
+        )),
+        // SQLite does not say which reference failed; task_id is the only one
+        RepositoryError::ForeignKeyViolation { .. } => RepositoryError::ForeignKeyViolation {
+            field: "task_id".to_string(),
+            message: "The referenced task_id does not exist".to_string(),
+        },
+        error => error,
+    })?;
+
+    Ok(id.to_string())
+}
+
+#[async_trait]
+impl StatusHistoryRepository for SqliteStatusHistoryRepository {
+    async fn find_by_task_id(&self, task_id: i32) -> Result<Vec<StatusHistory>, RepositoryError> {
+        self.fetch(&format!("SELECT {} FROM status_history WHERE task_id = ", HISTORY_COLUMNS), |mut query| {
+            query.push_bind(task_id);
+            push_workspace(&mut query, "workspace_id");
+            query.push(" ORDER BY changed_at ASC");
+            query
+        }).await
+    }
+
+    async fn find_matching(
+        &self,
+        filter: &HistoryFilter,
+        order: SortOrder,
+        page: Pagination,
+    ) -> Result<Vec<StatusHistory>, RepositoryError> {
+        let (direction, after) = match order {
+            SortOrder::Asc => (" ASC", " > "),
+            SortOrder::Desc => (" DESC", " < "),
+        };
+        self.fetch(&format!("SELECT {} FROM status_history WHERE TRUE", HISTORY_COLUMNS), |mut query| {
+            push_history_filter(&mut query, filter);
+            if let Some(key) = &page.after {
+                query.push(" AND (changed_at, id)").push(after).push("(").push_bind(key.at).push(", ").push_bind(key.id.clone()).push(")");
+            }
+            query.push(" ORDER BY changed_at").push(direction).push(", id").push(direction);
+            query.push(" LIMIT ").push_bind(i64::from(page.limit));
+            query.push(" OFFSET ").push_bind(i64::from(page.offset));
+            query
+        }).await
+    }
+
+    async fn count_matching(&self, filter: &HistoryFilter) -> Result<usize, RepositoryError> {
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM status_history WHERE TRUE");
+        push_history_filter(&mut query, filter);
+
+        let count: i64 = query.build_query_scalar()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        Ok(count as usize)
+    }
+
+    async fn find_by_date_range(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<StatusHistory>, RepositoryError> {
+        self.fetch(&format!("SELECT {} FROM status_history WHERE changed_at >= ", HISTORY_COLUMNS), |mut query| {
+            query.push_bind(start_date).push(" AND changed_at <= ").push_bind(end_date);
+            push_workspace(&mut query, "workspace_id");
+            query.push(" ORDER BY changed_at ASC");
+            query
+        }).await
+    }
+
+    async fn find_latest_by_task_id(&self, task_id: i32) -> Result<Option<StatusHistory>, RepositoryError> {
+        let mut latest = self.fetch(&format!("SELECT {} FROM status_history WHERE task_id = ", HISTORY_COLUMNS), |mut query| {
+            query.push_bind(task_id);
+            push_workspace(&mut query, "workspace_id");
+            query.push(" ORDER BY changed_at DESC LIMIT 1");
+            query
+        }).await?;
+        Ok(latest.pop())
+    }
+
+    async fn get_task_analytics(&self, task_id: i32) -> Result<Option<TaskAnalytics>, RepositoryError> {
+        let histories = self.find_by_task_id(task_id).await?;
+        Ok(TaskAnalytics::from_history(histories))
+    }
+
+    async fn get_completion_analytics(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<TaskAnalytics>, RepositoryError> {
+        // Tasks first completed in the date range; completing again after a reopen doesn't count
+        let task_ids: Vec<i32> = sqlx::query_scalar(
+            "SELECT task_id
+             FROM status_history
+             WHERE to_status = 'Completed' AND (? IS NULL OR workspace_id = ?)
+             GROUP BY task_id
+             HAVING MIN(changed_at) >= ? AND MIN(changed_at) <= ?
+             ORDER BY task_id"
+        )
+        .bind(current_workspace())
+        .bind(current_workspace())
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        let mut analytics = Vec::new();
+        for task_id in task_ids {
+            if let Some(task_analytics) = self.get_task_analytics(task_id).await? {
+                analytics.push(task_analytics);
+            }
+        }
+
+        Ok(analytics)
+    }
+
+    async fn get_transition_matrix(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<TransitionEdge>, RepositoryError> {
+        // Dwell times reach back to entries before the period, so load the whole history of each task involved
+        let history = self.fetch(
+            &format!(
+                "SELECT {} FROM status_history WHERE task_id IN (
+                     SELECT task_id FROM status_history WHERE from_status IS NOT NULL AND changed_at >= ",
+                HISTORY_COLUMNS
+            ),
+            |mut query| {
+                query.push_bind(start_date).push(" AND changed_at <= ").push_bind(end_date);
+                push_workspace(&mut query, "workspace_id");
+                query.push(")");
+                query
+            },
+        ).await?;
+
+        Ok(transition_matrix(&history, start_date, end_date))
+    }
+
+    async fn get_creation_cohorts(
+        &self,
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<Cohort>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT t.created_at,
+                    (SELECT MIN(sh.changed_at) FROM status_history sh
+                     WHERE sh.task_id = t.task_id AND sh.to_status = 'Completed') AS completed_at
+             FROM tasks t
+             WHERE t.deleted_at IS NULL AND t.created_at >= ? AND t.created_at <= ? AND (? IS NULL OR t.workspace_id = ?)"
+        )
+        .bind(start_date)
+        .bind(end_date)
+        .bind(current_workspace())
+        .bind(current_workspace())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| Ok((row.try_get("created_at")?, row.try_get("completed_at")?)))
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(database_error)?;
+        Ok(creation_cohorts(bucket, tasks))
+    }
+
+    async fn get_cumulative_flow(
+        &self,
+        bucket: CohortBucket,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<CumulativeFlow, RepositoryError> {
+        let close = bucket.next_start(bucket.start_of(end_date));
+        let history = self.fetch(
+            &format!("SELECT {} FROM status_history WHERE changed_at < ", HISTORY_COLUMNS),
+            |mut query| {
+                query.push_bind(close);
+                push_workspace(&mut query, "workspace_id");
+                query
+            },
+        ).await?;
+
+        let rows = sqlx::query("SELECT task_id, deleted_at FROM tasks WHERE (? IS NULL OR workspace_id = ?)")
+            .bind(current_workspace())
+            .bind(current_workspace())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+        let deleted_at = rows
+            .iter()
+            .map(|row| Ok((row.try_get("task_id")?, row.try_get("deleted_at")?)))
+            .collect::<Result<HashMap<_, _>, sqlx::Error>>()
+            .map_err(database_error)?;
+
+        Ok(cumulative_flow(bucket, start_date, end_date, &history, &deleted_at))
+    }
+
+    async fn get_completion_time_stats(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<CycleTimeStats>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT t.priority, sh_created.changed_at AS created_at, MIN(sh_completed.changed_at) AS completed_at
+             FROM tasks t
+             JOIN status_history sh_created ON t.task_id = sh_created.task_id AND sh_created.from_status IS NULL
+             JOIN status_history sh_completed ON t.task_id = sh_completed.task_id AND sh_completed.to_status = 'Completed'
+             WHERE t.priority IS NOT NULL AND (? IS NULL OR t.workspace_id = ?)
+             GROUP BY sh_created.id
+             HAVING MIN(sh_completed.changed_at) >= ? AND MIN(sh_completed.changed_at) <= ?"
+        )
+        .bind(current_workspace())
+        .bind(current_workspace())
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        let cycle_times = rows
+            .iter()
+            .map(|row| {
+                let created_at: DateTime<Utc> = row.try_get("created_at")?;
+                let completed_at: DateTime<Utc> = row.try_get("completed_at")?;
+                Ok((row.try_get("priority")?, (completed_at - created_at).num_milliseconds() as f64 / 1000.0))
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(database_error)?;
+        Ok(completion_time_stats(cycle_times))
+    }
+
+    async fn get_priority_completions(
+        &self,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>
+    ) -> Result<Vec<PriorityCompletion>, RepositoryError> {
+        // SQLite has no exact interval arithmetic, so the grouped rows are averaged in Rust
+        let rows = sqlx::query(
+            "SELECT t.priority, sh_created.changed_at AS created_at, fc.completed_at
+             FROM (SELECT task_id, MIN(changed_at) AS completed_at
+                   FROM status_history
+                   WHERE to_status = 'Completed' AND (? IS NULL OR workspace_id = ?)
+                   GROUP BY task_id
+                   HAVING MIN(changed_at) >= ? AND MIN(changed_at) <= ?) fc
+             JOIN tasks t ON t.task_id = fc.task_id
+             JOIN status_history sh_created ON sh_created.task_id = fc.task_id AND sh_created.from_status IS NULL
+             WHERE t.priority IS NOT NULL"
+        )
+        .bind(current_workspace())
+        .bind(current_workspace())
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        let cycle_times = rows
+            .iter()
+            .map(|row| {
+                let created_at: DateTime<Utc> = row.try_get("created_at")?;
+                let completed_at: DateTime<Utc> = row.try_get("completed_at")?;
+                Ok((row.try_get("priority")?, (completed_at - created_at).num_milliseconds() as f64 / 1000.0))
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(database_error)?;
+        Ok(priority_completions(cycle_times))
+    }
+
+    async fn save(&self, history: &StatusHistory) -> Result<String, RepositoryError> {
+        insert_sqlite_status_history(&self.pool, history).await
+    }
+
+    async fn delete(&self, id: String) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM status_history WHERE id = ? AND (? IS NULL OR workspace_id = ?)")
+            .bind(&id)
+            .bind(current_workspace())
+            .bind(current_workspace())
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(
+                format!("Status history with id {} not found", id)
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn delete_many(&self, ids: &[String]) -> Result<usize, RepositoryError> {
+        // Bound in chunks to stay under SQLite's limit on parameters per statement
+        let mut tx = self.pool.begin().await
+            .map_err(database_error)?;
+        let mut deleted = 0;
+        for chunk in ids.chunks(500) {
+            let mut query = QueryBuilder::<Sqlite>::new("DELETE FROM status_history WHERE id IN (");
+            let mut separated = query.separated(", ");
+            for id in chunk {
+                separated.push_bind(id);
+            }
+            query.push(")");
+            push_workspace(&mut query, "workspace_id");
+            deleted += query.build()
+                .execute(&mut *tx)
+                .await
+                .map_err(database_error)?
+                .rows_affected() as usize;
+        }
+        tx.commit().await
+            .map_err(database_error)?;
+
+        Ok(deleted)
+    }
+}
@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use super::database_error::database_error;
+use crate::domain::{RepositoryError, TaskDependencyRepository, TaskId};
+
+pub struct SqliteTaskDependencyRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTaskDependencyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaskDependencyRepository for SqliteTaskDependencyRepository {
+    async fn add(&self, task_id: TaskId, depends_on: TaskId) -> Result<(), RepositoryError> {
+        sqlx::query(
+            "INSERT INTO task_dependencies (task_id, depends_on_id)
+             VALUES (?, ?)
+             ON CONFLICT DO NOTHING"
+        )
+        .bind(task_id.value())
+        .bind(depends_on.value())
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn remove(&self, task_id: TaskId, depends_on: TaskId) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM task_dependencies WHERE task_id = ? AND depends_on_id = ?")
+            .bind(task_id.value())
+            .bind(depends_on.value())
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "Task {} does not depend on task {}",
+                task_id.value(),
+                depends_on.value()
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn find_dependencies(&self, task_id: TaskId) -> Result<Vec<TaskId>, RepositoryError> {
+        let ids: Vec<i32> = sqlx::query_scalar("SELECT depends_on_id FROM task_dependencies WHERE task_id = ? ORDER BY depends_on_id")
+            .bind(task_id.value())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        Ok(ids.into_iter().map(TaskId::new).collect())
+    }
+}
@@ -0,0 +1,558 @@
+use async_trait::async_trait;
+use sqlx::{QueryBuilder, Sqlite, SqliteExecutor, SqlitePool};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use super::database_error::database_error;
+use crate::database::{current_workspace, workspace_for_new_tasks};
+use crate::domain::{BoardColumn, PriorityChange, Rank, Task, TaskId, TaskPatch, TaskStatus, TaskRepository, StatusHistory, RepositoryError, TaskFilter, TaskSort, TaskSortField, SortOrder, TaskFacets, FilterExpression, FilterField, FilterValue, Comparison};
+use super::sqlite_status_history_repository::insert_sqlite_status_history;
+
+const TASK_COLUMNS: &str = "task_id, name, priority, description, status, created_at, updated_at, deleted_at, archived_at, created_by, rank";
+
+/// Tasks stored in SQLite, for running the API and tests without a Postgres instance.
+///
+/// It behaves like [`PostgresTaskRepository`](super::PostgresTaskRepository) except for search,
+/// which matches every query word against the name and description, or against a single
+/// status change comment, ignoring ASCII case; results are in id order rather than ranked.
+/// Writes are not part of request-level transactions.
+pub struct SqliteTaskRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTaskRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn fetch(&self, mut query: QueryBuilder<'_, Sqlite>) -> Result<Vec<Task>, RepositoryError> {
+        let rows = query.build_query_as::<TaskRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        rows.into_iter().map(Task::try_from).collect()
+    }
+
+    /// Selects the current workspace's tasks meeting `condition`
+    fn select(condition: &str) -> QueryBuilder<'static, Sqlite> {
+        let mut query = QueryBuilder::new(format!("SELECT {} FROM tasks WHERE TRUE", TASK_COLUMNS));
+        push_workspace(&mut query, "workspace_id");
+        query.push(" AND ").push(condition);
+        query
+    }
+}
+
+/// A `tasks` row as selected by every task query
+#[derive(Debug, sqlx::FromRow)]
+struct TaskRow {
+    task_id: i32,
+    name: String,
+    priority: Option<i32>,
+    description: Option<String>,
+    status: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    archived_at: Option<DateTime<Utc>>,
+    created_by: Option<String>,
+    rank: Option<String>,
+}
+
+/// A task on the board together with the number of tasks in its column
+#[derive(Debug, sqlx::FromRow)]
+struct BoardRow {
+    #[sqlx(flatten)]
+    task: TaskRow,
+    column_count: i64,
+}
+
+impl TryFrom<TaskRow> for Task {
+    type Error = RepositoryError;
+
+    fn try_from(row: TaskRow) -> Result<Self, Self::Error> {
+        let status = TaskStatus::parse(&row.status)
+            .map_err(RepositoryError::ValidationError)?;
+        let rank = row.rank.as_deref().map(Rank::parse).transpose()
+            .map_err(RepositoryError::ValidationError)?;
+
+        Task::new_with_status(
+            TaskId::new(row.task_id),
+            row.name,
+            row.priority,
+            status,
+            row.created_at,
+            row.updated_at,
+        )
+        .and_then(|task| task.with_description(row.description))
+        .map(|task| task.with_deleted_at(row.deleted_at).with_archived_at(row.archived_at).with_created_by(row.created_by).with_rank(rank))
+        .map_err(|error| RepositoryError::ValidationError(error.to_string()))
+    }
+}
+
+async fn insert_task<'e, E>(executor: E, task: &Task) -> Result<TaskId, RepositoryError>
+where
+    E: SqliteExecutor<'e>,
+{
+    let task_id: i32 = sqlx::query_scalar(
+        "INSERT INTO tasks (name, priority, description, status, created_at, updated_at, created_by, workspace_id) VALUES (?, ?, ?, ?, ?, ?, ?, ?) RETURNING task_id"
+    )
+    .bind(&task.name)
+    .bind(task.priority)
+    .bind(&task.description)
+    .bind(task.status.as_str())
+    .bind(task.created_at)
+    .bind(task.updated_at)
+    .bind(&task.created_by)
+    .bind(workspace_for_new_tasks())
+    .fetch_one(executor)
+    .await
+    .map_err(database_error)?;
+
+    Ok(TaskId::new(task_id))
+}
+
+async fn update_task<'e, E>(executor: E, task: &Task) -> Result<(), RepositoryError>
+where
+    E: SqliteExecutor<'e>,
+{
+    let result = sqlx::query(
+        "UPDATE tasks SET name = ?, priority = ?, description = ?, status = ?, updated_at = ?, archived_at = ? WHERE task_id = ? AND deleted_at IS NULL AND (? IS NULL OR workspace_id = ?)"
+    )
+    .bind(&task.name)
+    .bind(task.priority)
+    .bind(&task.description)
+    .bind(task.status.as_str())
+    .bind(task.updated_at)
+    .bind(task.archived_at)
+    .bind(task.id.value())
+    .bind(current_workspace())
+    .bind(current_workspace())
+    .execute(executor)
+    .await
+    .map_err(database_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(RepositoryError::NotFound(
+            format!("Task with id {} not found", task.id.value())
+        ));
+    }
+
+    Ok(())
+}
+
+// Column names come from this fixed mapping, never from user input
+fn sort_column(field: TaskSortField) -> &'static str {
+    match field {
+        TaskSortField::Id => "task_id",
+        TaskSortField::Priority => "priority",
+        TaskSortField::CreatedAt => "created_at",
+        TaskSortField::UpdatedAt => "updated_at",
+        TaskSortField::Name => "name",
+        TaskSortField::Status => "status",
+        TaskSortField::Rank => "COALESCE(rank, printf('%010d', task_id))",
+    }
+}
+
+fn sort_direction(order: SortOrder) -> &'static str {
+    match order {
+        SortOrder::Asc => " ASC NULLS LAST",
+        SortOrder::Desc => " DESC NULLS LAST",
+    }
+}
+
+/// Limits a query whose `WHERE` clause is already open to the current workspace, if there is one
+pub(crate) fn push_workspace(query: &mut QueryBuilder<'_, Sqlite>, column: &str) {
+    if let Some(workspace) = current_workspace() {
+        query.push(" AND ").push(column).push(" = ").push_bind(workspace);
+    }
+}
+
+/// Builds facets from `(status, count)` and `(priority, count)` rows
+pub(crate) fn facets_from_rows(statuses: Vec<(String, i64)>, priorities: Vec<(Option<i32>, i64)>) -> Result<TaskFacets, RepositoryError> {
+    let statuses = statuses
+        .into_iter()
+        .map(|(status, count)| TaskStatus::parse(&status).map(|status| (status, count as usize)))
+        .collect::<Result<_, _>>()
+        .map_err(RepositoryError::ValidationError)?;
+    let priorities = priorities.into_iter().map(|(priority, count)| (priority, count as usize)).collect();
+    Ok(TaskFacets::from_counts(statuses, priorities))
+}
+
+/// Appends the filter's conditions to a query whose `WHERE` clause is already open
+fn push_task_filter(query: &mut QueryBuilder<'_, Sqlite>, filter: &TaskFilter) {
+    if !filter.include_deleted {
+        query.push(" AND deleted_at IS NULL");
+    }
+    query.push(if filter.archived { " AND archived_at IS NOT NULL" } else { " AND archived_at IS NULL" });
+    if let Some(priority) = filter.priority {
+        query.push(" AND priority = ").push_bind(priority);
+    }
+    if let Some(status) = &filter.status {
+        query.push(" AND status = ").push_bind(status.as_str().to_string());
+    }
+    if let Some(expression) = &filter.expression {
+        query.push(" AND ");
+        push_filter_expression(query, expression);
+    }
+}
+
+// Filter columns come from this fixed mapping, never from user input
+fn filter_column(field: FilterField) -> &'static str {
+    match field {
+        FilterField::Status => "status",
+        FilterField::Priority => "priority",
+        FilterField::Name => "name",
+        FilterField::Description => "description",
+        FilterField::CreatedAt => "created_at",
+        FilterField::UpdatedAt => "updated_at",
+        FilterField::CreatedBy => "created_by",
+    }
+}
+
+/// Pushes the column, first ruling out `NULL` for nullable ones
+fn push_filter_column(query: &mut QueryBuilder<'_, Sqlite>, field: FilterField) {
+    if field.is_nullable() {
+        query.push(filter_column(field)).push(" IS NOT NULL AND ");
+    }
+    query.push(filter_column(field));
+}
+
+fn push_filter_value(query: &mut QueryBuilder<'_, Sqlite>, value: &FilterValue) {
+    match value {
+        FilterValue::Status(status) => query.push_bind(status.as_str().to_string()),
+        FilterValue::Integer(integer) => query.push_bind(*integer),
+        FilterValue::Text(text) => query.push_bind(text.clone()),
+        FilterValue::Timestamp(at) => query.push_bind(*at),
+    };
+}
+
+/// Appends `expression` as one parenthesized condition with every value bound.
+///
+/// Unset nullable columns match no comparison, so `NOT` never meets an SQL `NULL`.
+fn push_filter_expression(query: &mut QueryBuilder<'_, Sqlite>, expression: &FilterExpression) {
+    match expression {
+        FilterExpression::All(operands) | FilterExpression::Any(operands) => {
+            let joiner = if matches!(expression, FilterExpression::All(_)) { " AND " } else { " OR " };
+            query.push("(");
+            for (index, operand) in operands.iter().enumerate() {
+                if index > 0 {
+                    query.push(joiner);
+                }
+                push_filter_expression(query, operand);
+            }
+            query.push(")");
+        }
+        FilterExpression::Not(operand) => {
+            query.push("NOT ");
+            push_filter_expression(query, operand);
+        }
+        FilterExpression::Compare { field, comparison, value } => {
+            query.push("(");
+            push_filter_column(query, *field);
+            match (comparison, value) {
+                (Comparison::Contains, FilterValue::Text(text)) => {
+                    query.push(" LIKE ").push_bind(like_pattern(text)).push(" ESCAPE '\\'");
+                }
+                _ => {
+                    query.push(" ").push(comparison.as_str()).push(" ");
+                    push_filter_value(query, value);
+                }
+            }
+            query.push(")");
+        }
+        FilterExpression::In { field, values } => {
+            query.push("(");
+            push_filter_column(query, *field);
+            query.push(" IN (");
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    query.push(", ");
+                }
+                push_filter_value(query, value);
+            }
+            query.push("))");
+        }
+    }
+}
+
+/// A LIKE pattern matching `word` anywhere, with its wildcards taken literally
+fn like_pattern(word: &str) -> String {
+    let escaped = word.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// Appends `column LIKE pattern` for every word, joined by AND
+fn push_all_words(query: &mut QueryBuilder<'_, Sqlite>, column: &str, patterns: &[String]) {
+    let mut separated = query.separated(" AND ");
+    for pattern in patterns {
+        separated.push(format!("{} LIKE ", column));
+        separated.push_bind_unseparated(pattern.clone());
+        separated.push_unseparated(" ESCAPE '\\'");
+    }
+}
+
+#[async_trait]
+impl TaskRepository for SqliteTaskRepository {
+    async fn find_all(&self) -> Result<Vec<Task>, RepositoryError> {
+        self.fetch(Self::select("deleted_at IS NULL ORDER BY task_id")).await
+    }
+
+    async fn find_matching(&self, filter: &TaskFilter, sort: TaskSort) -> Result<Vec<Task>, RepositoryError> {
+        let mut query = Self::select("TRUE");
+
+        push_task_filter(&mut query, filter);
+
+        query.push(" ORDER BY ").push(sort_column(sort.field)).push(sort_direction(sort.order));
+        if sort.field != TaskSortField::Id {
+            query.push(", task_id");
+        }
+
+        self.fetch(query).await
+    }
+
+    async fn find_board(&self, filter: &TaskFilter, sort: TaskSort, column_size: usize) -> Result<Vec<BoardColumn>, RepositoryError> {
+        let mut query = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT {columns}, column_count FROM (SELECT {columns}, \
+             COUNT(*) OVER (PARTITION BY status) AS column_count, \
+             ROW_NUMBER() OVER (PARTITION BY status ORDER BY ",
+            columns = TASK_COLUMNS,
+        ));
+        query.push(sort_column(sort.field)).push(sort_direction(sort.order)).push(", task_id) AS position FROM tasks WHERE TRUE");
+        push_workspace(&mut query, "workspace_id");
+        push_task_filter(&mut query, filter);
+        query.push(") board WHERE position <= ").push_bind(column_size as i64).push(" ORDER BY status, position");
+
+        let rows = query.build_query_as::<BoardRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        let cards = rows.into_iter()
+            .map(|row| Ok((Task::try_from(row.task)?, row.column_count as usize)))
+            .collect::<Result<Vec<_>, RepositoryError>>()?;
+        Ok(BoardColumn::from_cards(cards))
+    }
+
+    async fn count_facets(&self, filter: &TaskFilter) -> Result<TaskFacets, RepositoryError> {
+        let mut statuses = QueryBuilder::<Sqlite>::new("SELECT status, COUNT(*) FROM tasks WHERE TRUE");
+        push_workspace(&mut statuses, "workspace_id");
+        push_task_filter(&mut statuses, filter);
+        statuses.push(" GROUP BY status");
+        let statuses: Vec<(String, i64)> = statuses.build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        let mut priorities = QueryBuilder::<Sqlite>::new("SELECT priority, COUNT(*) FROM tasks WHERE TRUE");
+        push_workspace(&mut priorities, "workspace_id");
+        push_task_filter(&mut priorities, filter);
+        priorities.push(" GROUP BY priority");
+        let priorities: Vec<(Option<i32>, i64)> = priorities.build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        facets_from_rows(statuses, priorities)
+    }
+
+    async fn find_by_id(&self, id: TaskId) -> Result<Option<Task>, RepositoryError> {
+        let mut query = Self::select("deleted_at IS NULL AND task_id = ");
+        query.push_bind(id.value());
+        Ok(self.fetch(query).await?.pop())
+    }
+
+    async fn find_by_priority(&self, priority: i32) -> Result<Vec<Task>, RepositoryError> {
+        let mut query = Self::select("deleted_at IS NULL AND priority = ");
+        query.push_bind(priority).push(" ORDER BY task_id");
+        self.fetch(query).await
+    }
+
+    async fn find_by_status(&self, status: &TaskStatus) -> Result<Vec<Task>, RepositoryError> {
+        let mut query = Self::select("deleted_at IS NULL AND status = ");
+        query.push_bind(status.as_str().to_string()).push(" ORDER BY task_id");
+        self.fetch(query).await
+    }
+
+    async fn search_tasks(&self, query: &str) -> Result<Vec<Task>, RepositoryError> {
+        let patterns: Vec<String> = query.split_whitespace().map(like_pattern).collect();
+        if patterns.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut search = Self::select("deleted_at IS NULL AND ((");
+        push_all_words(&mut search, "name || ' ' || COALESCE(description, '')", &patterns);
+        search.push(") OR EXISTS (SELECT 1 FROM status_history sh WHERE sh.task_id = tasks.task_id AND ");
+        push_all_words(&mut search, "sh.comment", &patterns);
+        search.push(")) ORDER BY task_id");
+
+        self.fetch(search).await
+    }
+
+    async fn save(&self, task: &Task) -> Result<TaskId, RepositoryError> {
+        insert_task(&self.pool, task).await
+    }
+
+    async fn update(&self, task: &Task) -> Result<(), RepositoryError> {
+        update_task(&self.pool, task).await
+    }
+
+    async fn update_fields(&self, id: TaskId, patch: &TaskPatch) -> Result<(), RepositoryError> {
+        // Sets only the patched columns, so concurrent changes to the others survive
+        let mut query = QueryBuilder::<Sqlite>::new("UPDATE tasks SET updated_at = ");
+        query.push_bind(patch.updated_at);
+        if let Some(name) = &patch.name {
+            query.push(", name = ").push_bind(name);
+        }
+        if let Some(priority) = patch.priority {
+            query.push(", priority = ").push_bind(priority);
+        }
+        if let Some(description) = &patch.description {
+            query.push(", description = ").push_bind(description);
+        }
+        query.push(" WHERE task_id = ").push_bind(id.value()).push(" AND deleted_at IS NULL");
+        push_workspace(&mut query, "workspace_id");
+
+        let result = query.build()
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(
+                format!("Task with id {} not found", id.value())
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn set_rank(&self, id: TaskId, rank: &Rank) -> Result<(), RepositoryError> {
+        let mut query = QueryBuilder::<Sqlite>::new("UPDATE tasks SET rank = ");
+        query.push_bind(rank.as_str().to_string());
+        query.push(" WHERE task_id = ").push_bind(id.value()).push(" AND deleted_at IS NULL");
+        push_workspace(&mut query, "workspace_id");
+
+        let result = query.build()
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(
+                format!("Task with id {} not found", id.value())
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn reprioritize(&self, filter: &TaskFilter, pairs: &[(i32, i32)], updated_at: DateTime<Utc>) -> Result<Vec<PriorityChange>, RepositoryError> {
+        if pairs.is_empty() {
+            return Ok(vec![]);
+        }
+        let targets: HashMap<i32, i32> = pairs.iter().copied().collect();
+
+        // The tasks are picked before any is moved, so a task moved onto another listed priority stays put
+        let mut tx = self.pool.begin().await
+            .map_err(database_error)?;
+        let mut query = Self::select("deleted_at IS NULL AND archived_at IS NULL AND priority IN (");
+        let mut separated = query.separated(", ");
+        for (from, _) in pairs {
+            separated.push_bind(*from);
+        }
+        query.push(")");
+        if let Some(status) = &filter.status {
+            query.push(" AND status = ").push_bind(status.as_str().to_string());
+        }
+        if let Some(priority) = filter.priority {
+            query.push(" AND priority = ").push_bind(priority);
+        }
+        query.push(" ORDER BY task_id");
+        let rows = query.build_query_as::<TaskRow>()
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(database_error)?;
+
+        let mut changes = Vec::new();
+        for row in rows {
+            let Some((from, &to)) = row.priority.and_then(|from| targets.get(&from).map(|to| (from, to))) else {
+                continue;
+            };
+            sqlx::query("UPDATE tasks SET priority = ?, updated_at = ? WHERE task_id = ?")
+                .bind(to)
+                .bind(updated_at)
+                .bind(row.task_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(database_error)?;
+            changes.push(PriorityChange { task_id: TaskId::new(row.task_id), from, to });
+        }
+
+        tx.commit().await
+            .map_err(database_error)?;
+        Ok(changes)
+    }
+
+    async fn save_with_history(&self, task: &Task, history: &StatusHistory) -> Result<TaskId, RepositoryError> {
+        let mut tx = self.pool.begin().await
+            .map_err(database_error)?;
+
+        let task_id = insert_task(&mut *tx, task).await?;
+        let history = StatusHistory { task_id: task_id.value(), ..history.clone() };
+        insert_sqlite_status_history(&mut *tx, &history).await?;
+
+        tx.commit().await
+            .map_err(database_error)?;
+        Ok(task_id)
+    }
+
+    async fn update_with_history(&self, task: &Task, history: &StatusHistory) -> Result<(), RepositoryError> {
+        let mut tx = self.pool.begin().await
+            .map_err(database_error)?;
+
+        update_task(&mut *tx, task).await?;
+        insert_sqlite_status_history(&mut *tx, history).await?;
+
+        tx.commit().await
+            .map_err(database_error)
+    }
+
+    async fn delete(&self, id: TaskId) -> Result<(), RepositoryError> {
+        let result = sqlx::query("UPDATE tasks SET deleted_at = ? WHERE task_id = ? AND deleted_at IS NULL AND (? IS NULL OR workspace_id = ?)")
+            .bind(Utc::now())
+            .bind(id.value())
+            .bind(current_workspace())
+            .bind(current_workspace())
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(
+                format!("Task with id {} not found", id.value())
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn restore(&self, id: TaskId) -> Result<Task, RepositoryError> {
+        let row = sqlx::query_as::<_, TaskRow>(&format!(
+            "UPDATE tasks SET deleted_at = NULL WHERE task_id = ? AND deleted_at IS NOT NULL AND (? IS NULL OR workspace_id = ?) RETURNING {}",
+            TASK_COLUMNS
+        ))
+        .bind(id.value())
+        .bind(current_workspace())
+        .bind(current_workspace())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        match row {
+            Some(row) => Task::try_from(row),
+            None => Err(RepositoryError::NotFound(
+                format!("Deleted task with id {} not found", id.value())
+            )),
+        }
+    }
+}
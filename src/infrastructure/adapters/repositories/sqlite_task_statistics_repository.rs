@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{SqlitePool, Sqlite, QueryBuilder};
+use super::database_error::database_error;
+use super::sqlite_task_repository::{facets_from_rows, push_workspace};
+use crate::domain::{RepositoryError, StatisticsQuery, TaskStatistics, TaskStatisticsRepository};
+
+/// Counts with the same `GROUP BY` queries as the Postgres repository
+pub struct SqliteTaskStatisticsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTaskStatisticsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn daily_counts(rows: Vec<(NaiveDate, i64)>) -> Vec<(NaiveDate, usize)> {
+    rows.into_iter().map(|(day, count)| (day, count as usize)).collect()
+}
+
+#[async_trait]
+impl TaskStatisticsRepository for SqliteTaskStatisticsRepository {
+    async fn task_statistics(&self, query: &StatisticsQuery) -> Result<TaskStatistics, RepositoryError> {
+        let mut statuses = QueryBuilder::<Sqlite>::new("SELECT status, COUNT(*) FROM tasks WHERE deleted_at IS NULL AND archived_at IS NULL");
+        push_workspace(&mut statuses, "workspace_id");
+        statuses.push(" GROUP BY status");
+        let statuses: Vec<(String, i64)> = statuses.build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        let mut priorities = QueryBuilder::<Sqlite>::new("SELECT priority, COUNT(*) FROM tasks WHERE deleted_at IS NULL AND archived_at IS NULL");
+        push_workspace(&mut priorities, "workspace_id");
+        priorities.push(" GROUP BY priority");
+        let priorities: Vec<(Option<i32>, i64)> = priorities.build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        let overdue = match query.overdue_before {
+            Some(cutoff) => {
+                let mut overdue = QueryBuilder::<Sqlite>::new(
+                    "SELECT COUNT(*) FROM tasks WHERE deleted_at IS NULL AND archived_at IS NULL AND status = 'Pending' AND updated_at < "
+                );
+                overdue.push_bind(cutoff);
+                push_workspace(&mut overdue, "workspace_id");
+                let (count,): (i64,) = overdue.build_query_as()
+                    .fetch_one(&self.pool)
+                    .await
+                    .map_err(database_error)?;
+                count as usize
+            }
+            None => 0,
+        };
+
+        let mut created = QueryBuilder::<Sqlite>::new(
+            "SELECT date(created_at) AS day, COUNT(*) FROM tasks WHERE deleted_at IS NULL AND created_at >= "
+        );
+        created.push_bind(query.start()).push(" AND created_at < ").push_bind(query.end());
+        push_workspace(&mut created, "workspace_id");
+        created.push(" GROUP BY day");
+        let created: Vec<(NaiveDate, i64)> = created.build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        // A task reopened and completed again on the same day counts once
+        let mut completed = QueryBuilder::<Sqlite>::new(
+            "SELECT date(changed_at) AS day, COUNT(DISTINCT task_id) FROM status_history WHERE to_status = 'Completed' AND changed_at >= "
+        );
+        completed.push_bind(query.start()).push(" AND changed_at < ").push_bind(query.end());
+        push_workspace(&mut completed, "workspace_id");
+        completed.push(" GROUP BY day");
+        let completed: Vec<(NaiveDate, i64)> = completed.build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        Ok(TaskStatistics::from_counts(
+            query,
+            facets_from_rows(statuses, priorities)?,
+            overdue,
+            &daily_counts(created),
+            &daily_counts(completed),
+        ))
+    }
+}
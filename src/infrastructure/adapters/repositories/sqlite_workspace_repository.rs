@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use sqlx::{SqlitePool, Row};
+use super::database_error::database_error;
+use crate::domain::{RepositoryError, Workspace, WorkspaceRepository};
+
+const WORKSPACE_COLUMNS: &str = "id, name, created_by, created_at";
+
+pub struct SqliteWorkspaceRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteWorkspaceRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn row_to_workspace(row: &sqlx::sqlite::SqliteRow) -> Workspace {
+    Workspace {
+        id: row.get("id"),
+        name: row.get("name"),
+        created_by: row.get("created_by"),
+        created_at: row.get("created_at"),
+    }
+}
+
+#[async_trait]
+impl WorkspaceRepository for SqliteWorkspaceRepository {
+    async fn save(&self, workspace: &Workspace) -> Result<(), RepositoryError> {
+        sqlx::query("INSERT INTO workspaces (id, name, created_by, created_at) VALUES (?, ?, ?, ?)")
+            .bind(&workspace.id)
+            .bind(&workspace.name)
+            .bind(&workspace.created_by)
+            .bind(workspace.created_at)
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, workspace: &Workspace) -> Result<(), RepositoryError> {
+        let updated = sqlx::query("UPDATE workspaces SET name = ? WHERE id = ?")
+            .bind(&workspace.name)
+            .bind(&workspace.id)
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        if updated.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!("Workspace {} not found", workspace.id)));
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        let deleted = sqlx::query(
+            "DELETE FROM workspaces WHERE id = ? AND NOT EXISTS (SELECT 1 FROM tasks WHERE workspace_id = ?)"
+        )
+        .bind(id)
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(database_error)?;
+
+        if deleted.rows_affected() == 0 {
+            return match self.find_by_id(id).await? {
+                Some(_) => Err(RepositoryError::Conflict {
+                    field: "id".to_string(),
+                    message: format!("Workspace {} still has tasks", id),
+                }),
+                None => Err(RepositoryError::NotFound(format!("Workspace {} not found", id))),
+            };
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<Workspace>, RepositoryError> {
+        let row = sqlx::query(&format!("SELECT {} FROM workspaces WHERE id = ?", WORKSPACE_COLUMNS))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        Ok(row.as_ref().map(row_to_workspace))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Workspace>, RepositoryError> {
+        let rows = sqlx::query(&format!("SELECT {} FROM workspaces ORDER BY created_at, id", WORKSPACE_COLUMNS))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        Ok(rows.iter().map(row_to_workspace).collect())
+    }
+}
@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use std::path::{Component, Path, PathBuf};
+
+use crate::domain::ObjectStorage;
+
+/// Keeps objects as files under a directory, for development and for hosts without S3
+#[derive(Debug, Clone)]
+pub struct LocalObjectStorage {
+    root: PathBuf,
+}
+
+impl LocalObjectStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The file a key maps to; keys may use `/` for subdirectories but cannot leave the root
+    fn path(&self, key: &str) -> Result<PathBuf, String> {
+        let relative = Path::new(key);
+        if key.is_empty() || !relative.components().all(|component| matches!(component, Component::Normal(_))) {
+            return Err(format!("Invalid object key '{}'", key));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl ObjectStorage for LocalObjectStorage {
+    async fn put(&self, key: &str, body: Vec<u8>) -> Result<(), String> {
+        let path = self.path(key)?;
+        let failed = |error: std::io::Error| format!("Could not write {}: {}", path.display(), error);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(failed)?;
+        }
+        // Write beside the object and rename over it, so readers never see half an object
+        let partial = path.with_extension("partial");
+        tokio::fs::write(&partial, body).await.map_err(failed)?;
+        tokio::fs::rename(&partial, &path).await.map_err(failed)
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, String> {
+        let path = self.path(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(body) => Ok(Some(body)),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(format!("Could not read {}: {}", path.display(), error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_objects_round_trip_and_keys_stay_under_the_root() {
+        let root = std::env::temp_dir().join(format!("axum_postgres_rust_local_storage_{}", std::process::id()));
+        let storage = LocalObjectStorage::new(&root);
+
+        storage.put("status_history/2024-01.ndjson.gz", b"first".to_vec()).await.unwrap();
+        storage.put("status_history/2024-01.ndjson.gz", b"second".to_vec()).await.unwrap();
+
+        assert_eq!(storage.get("status_history/2024-01.ndjson.gz").await.unwrap(), Some(b"second".to_vec()));
+        assert_eq!(storage.get("status_history/2024-02.ndjson.gz").await.unwrap(), None);
+        for key in ["../escape", "/etc/passwd", "status_history/../../escape", ""] {
+            assert!(storage.put(key, Vec::new()).await.is_err(), "{} should be rejected", key);
+        }
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,51 @@
+pub mod local_object_storage;
+pub mod s3_object_storage;
+
+pub use local_object_storage::*;
+pub use s3_object_storage::*;
+
+use std::sync::Arc;
+use crate::domain::{AttachmentStorage, ObjectStorage};
+
+/// The storage an `s3://bucket/prefix` or `file:///directory` URL points at.
+///
+/// S3 needs `credentials`; `endpoint` replaces the AWS endpoint for S3-compatible services.
+pub fn object_storage(
+    url: &str,
+    region: &str,
+    endpoint: Option<&str>,
+    credentials: Option<AwsCredentials>,
+) -> Result<Arc<dyn ObjectStorage>, String> {
+    if url.starts_with("s3://") {
+        return Ok(Arc::new(s3_storage(url, region, endpoint, credentials)?));
+    }
+    match url.strip_prefix("file://") {
+        Some(directory) if !directory.is_empty() => Ok(Arc::new(LocalObjectStorage::new(directory))),
+        _ => Err(format!("Object storage URL must be s3://bucket/prefix or file:///directory, got '{}'", url)),
+    }
+}
+
+/// The storage an `s3://bucket/prefix` URL points at, for files callers download straight from it.
+///
+/// A local directory cannot hand out download links, so only S3 and S3-compatible services qualify.
+pub fn attachment_storage(
+    url: &str,
+    region: &str,
+    endpoint: Option<&str>,
+    credentials: Option<AwsCredentials>,
+) -> Result<Arc<dyn AttachmentStorage>, String> {
+    if !url.starts_with("s3://") {
+        return Err(format!("Attachment storage URL must be s3://bucket/prefix, got '{}'", url));
+    }
+    Ok(Arc::new(s3_storage(url, region, endpoint, credentials)?))
+}
+
+fn s3_storage(url: &str, region: &str, endpoint: Option<&str>, credentials: Option<AwsCredentials>) -> Result<S3ObjectStorage, String> {
+    let location = url.trim_start_matches("s3://");
+    let (bucket, prefix) = location.split_once('/').unwrap_or((location, ""));
+    if bucket.is_empty() {
+        return Err(format!("S3 URL '{}' names no bucket", url));
+    }
+    let credentials = credentials.ok_or("S3 storage needs AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY")?;
+    Ok(S3ObjectStorage::new(bucket, prefix, region, endpoint, credentials))
+}
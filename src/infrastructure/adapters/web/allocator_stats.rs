@@ -0,0 +1,37 @@
+use serde::Serialize;
+
+/// Heap usage as reported by jemalloc, in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AllocatorStats {
+    /// Bytes handed out to the application and not yet freed
+    pub allocated: usize,
+    /// Bytes in pages backing live allocations, including fragmentation
+    pub active: usize,
+    /// Bytes of physical memory held by the allocator
+    pub resident: usize,
+    /// Bytes of virtual memory kept for reuse instead of being returned to the OS
+    pub retained: usize,
+}
+
+impl AllocatorStats {
+    /// Current heap usage; `None` unless the server was built with the `jemalloc` feature
+    #[cfg(feature = "jemalloc")]
+    pub fn read() -> Option<Self> {
+        use tikv_jemalloc_ctl::{epoch, stats};
+
+        // jemalloc caches its statistics until the epoch is advanced
+        epoch::advance().ok()?;
+        Some(Self {
+            allocated: stats::allocated::read().ok()?,
+            active: stats::active::read().ok()?,
+            resident: stats::resident::read().ok()?,
+            retained: stats::retained::read().ok()?,
+        })
+    }
+
+    /// Current heap usage; `None` unless the server was built with the `jemalloc` feature
+    #[cfg(not(feature = "jemalloc"))]
+    pub fn read() -> Option<Self> {
+        None
+    }
+}
@@ -0,0 +1,28 @@
+use axum::{
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    Json,
+};
+use serde::de::DeserializeOwned;
+
+use crate::infrastructure::adapters::web::WebError;
+
+/// A JSON request body whose rejections answer with the usual `ApiResponse` error envelope.
+///
+/// Bodies over the router's `DefaultBodyLimit` are rejected with `413 Payload Too Large`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApiJson<T>(pub T);
+
+impl<T: DeserializeOwned, S: Send + Sync> FromRequest<S> for ApiJson<T> {
+    type Rejection = WebError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Json::<T>::from_request(request, state).await {
+            Ok(Json(value)) => Ok(ApiJson(value)),
+            Err(rejection) => Err(invalid_body(rejection)),
+        }
+    }
+}
+
+fn invalid_body(rejection: JsonRejection) -> WebError {
+    WebError::InvalidBody(rejection.status(), rejection.body_text())
+}
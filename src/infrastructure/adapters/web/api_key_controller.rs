@@ -0,0 +1,26 @@
+use axum::{extract::State, http::StatusCode, Extension, Json};
+use std::sync::Arc;
+
+use crate::application::{ApiKeyCreatedDto, ApiKeyUseCases, CreateApiKeyRequest, RequestContext};
+use crate::infrastructure::adapters::web::{ApiJson, WebError};
+use crate::responses::ApiResponse;
+
+/// Admin endpoints for issuing API keys to machine clients
+pub struct ApiKeyController {
+    api_key_use_cases: Arc<ApiKeyUseCases>,
+}
+
+impl ApiKeyController {
+    pub fn new(api_key_use_cases: Arc<ApiKeyUseCases>) -> Self {
+        Self { api_key_use_cases }
+    }
+
+    pub async fn create_api_key(
+        State(controller): State<Arc<ApiKeyController>>,
+        Extension(ctx): Extension<RequestContext>,
+        ApiJson(request): ApiJson<CreateApiKeyRequest>,
+    ) -> Result<(StatusCode, Json<ApiResponse<ApiKeyCreatedDto>>), WebError> {
+        let api_key = controller.api_key_use_cases.create_api_key(&ctx, request).await?;
+        Ok((StatusCode::CREATED, Json(ApiResponse::success(api_key))))
+    }
+}
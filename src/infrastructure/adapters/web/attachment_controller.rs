@@ -0,0 +1,53 @@
+use axum::{extract::{Path, Request, State}, http::StatusCode, Extension, Json};
+use std::sync::Arc;
+
+use crate::application::{AttachmentDto, AttachmentUseCases, RequestContext, UploadAttachmentRequest};
+use crate::infrastructure::adapters::web::{read_form_file, WebError};
+use crate::responses::ApiResponse;
+
+/// Endpoints for the files attached to tasks; they answer 404 on deployments without attachment storage
+pub struct AttachmentController {
+    attachment_use_cases: Option<Arc<AttachmentUseCases>>,
+}
+
+impl AttachmentController {
+    pub fn new(attachment_use_cases: Arc<AttachmentUseCases>) -> Self {
+        Self { attachment_use_cases: Some(attachment_use_cases) }
+    }
+
+    pub fn disabled() -> Self {
+        Self { attachment_use_cases: None }
+    }
+
+    /// Uploads the `file` field of a `multipart/form-data` body
+    pub async fn upload_attachment(
+        State(controller): State<Arc<AttachmentController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(task_id): Path<i32>,
+        request: Request,
+    ) -> Result<(StatusCode, Json<ApiResponse<AttachmentDto>>), WebError> {
+        let use_cases = controller.use_cases()?;
+        let file = read_form_file(request, "file", use_cases.max_size_bytes()).await?;
+        let request = UploadAttachmentRequest {
+            file_name: file.file_name.unwrap_or_default(),
+            content_type: file.content_type,
+            body: file.body,
+        };
+        let attachment = use_cases.upload_attachment(&ctx, task_id, request).await?;
+        Ok((StatusCode::CREATED, Json(ApiResponse::success(attachment))))
+    }
+
+    pub async fn list_attachments(
+        State(controller): State<Arc<AttachmentController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(task_id): Path<i32>,
+    ) -> Result<Json<ApiResponse<Vec<AttachmentDto>>>, WebError> {
+        let attachments = controller.use_cases()?.list_attachments(&ctx, task_id).await?;
+        Ok(Json(ApiResponse::success(attachments)))
+    }
+
+    fn use_cases(&self) -> Result<&AttachmentUseCases, WebError> {
+        self.attachment_use_cases.as_deref()
+            .ok_or_else(|| WebError::NotFound("Attachments are not enabled on this server".to_string()))
+    }
+}
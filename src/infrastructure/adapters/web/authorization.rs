@@ -0,0 +1,80 @@
+use axum::{
+    extract::{MatchedPath, OriginalUri, Request},
+    http::Method,
+    middleware::Next,
+    response::Response,
+};
+
+use crate::application::RequestContext;
+use crate::domain::UserRole;
+use crate::infrastructure::adapters::web::WebError;
+
+/// The least role a route is open to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteRole {
+    /// Managers and admins
+    Elevated,
+    Admin,
+}
+
+impl RouteRole {
+    pub fn allows(&self, role: &UserRole) -> bool {
+        match self {
+            RouteRole::Elevated => role.has_elevated_permissions(),
+            RouteRole::Admin => role.can_manage_users(),
+        }
+    }
+
+    fn describe(&self) -> &'static str {
+        match self {
+            RouteRole::Elevated => "the Manager or Admin role",
+            RouteRole::Admin => "the Admin role",
+        }
+    }
+}
+
+/// Routes closed to some callers, as (method, path template, least role).
+///
+/// A `*` method matches every method, and a template ending in `/*` matches every route below
+/// it. Routes not listed are open to everyone; the use cases still apply their own checks.
+pub const ROUTE_ROLES: &[(&str, &str, RouteRole)] = &[
+    ("*", "/admin/*", RouteRole::Admin),
+    ("DELETE", "/tasks/{task_id}", RouteRole::Elevated),
+    ("POST", "/tasks/{task_id}/restore", RouteRole::Admin),
+    ("POST", "/tasks/bulk/delete", RouteRole::Admin),
+    ("GET", "/history", RouteRole::Elevated),
+    ("*", "/automation/*", RouteRole::Elevated),
+    ("GET", "/health/diagnostics", RouteRole::Admin),
+];
+
+/// The least role `method` on the route `path_template` needs, if it is restricted.
+///
+/// The `/v2` copies of the routes need the same role as the originals.
+pub fn required_role(method: &Method, path_template: &str) -> Option<RouteRole> {
+    let path = path_template.strip_prefix("/v2").filter(|path| path.starts_with('/')).unwrap_or(path_template);
+    ROUTE_ROLES
+        .iter()
+        .filter(|(route_method, _, _)| *route_method == "*" || *route_method == method.as_str())
+        .find(|(_, template, _)| match template.strip_suffix("/*") {
+            Some(prefix) => path.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('/')),
+            None => path == *template,
+        })
+        .map(|(_, _, role)| *role)
+}
+
+/// Answers 403 before the handler runs when the caller's role is below what [`ROUTE_ROLES`]
+/// asks of the matched route. Runs after routing and inside the request context middleware.
+pub async fn authorization_middleware(request: Request, next: Next) -> Result<Response, WebError> {
+    let required = request
+        .extensions()
+        .get::<MatchedPath>()
+        .and_then(|path| required_role(request.method(), path.as_str()));
+    if let Some(required) = required {
+        let role = request.extensions().get::<RequestContext>().map(|ctx| ctx.role.clone()).unwrap_or_default();
+        if !required.allows(&role) {
+            let path = request.extensions().get::<OriginalUri>().map_or(request.uri().path(), |uri| uri.path());
+            return Err(WebError::Forbidden(format!("{} {} requires {}", request.method(), path, required.describe())));
+        }
+    }
+    Ok(next.run(request).await)
+}
@@ -0,0 +1,66 @@
+use axum::{extract::{Path, State}, http::StatusCode, Extension, Json};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::application::{AutomationRuleDto, AutomationRuleRequest, AutomationUseCases, RequestContext};
+use crate::infrastructure::adapters::web::{ApiJson, WebError};
+use crate::responses::ApiResponse;
+
+/// Endpoints for defining the automation rules run against task events
+pub struct AutomationController {
+    automation_use_cases: Arc<AutomationUseCases>,
+}
+
+impl AutomationController {
+    pub fn new(automation_use_cases: Arc<AutomationUseCases>) -> Self {
+        Self { automation_use_cases }
+    }
+
+    pub async fn list_rules(
+        State(controller): State<Arc<AutomationController>>,
+        Extension(ctx): Extension<RequestContext>,
+    ) -> Result<Json<ApiResponse<Vec<AutomationRuleDto>>>, WebError> {
+        let rules = controller.automation_use_cases.list_rules(&ctx).await?;
+        Ok(Json(ApiResponse::success(rules)))
+    }
+
+    pub async fn get_rule(
+        State(controller): State<Arc<AutomationController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(rule_id): Path<String>,
+    ) -> Result<Json<ApiResponse<AutomationRuleDto>>, WebError> {
+        let rule = controller.automation_use_cases.get_rule(&ctx, &rule_id).await?;
+        Ok(Json(ApiResponse::success(rule)))
+    }
+
+    pub async fn create_rule(
+        State(controller): State<Arc<AutomationController>>,
+        Extension(ctx): Extension<RequestContext>,
+        ApiJson(request): ApiJson<AutomationRuleRequest>,
+    ) -> Result<(StatusCode, Json<ApiResponse<AutomationRuleDto>>), WebError> {
+        let rule = controller.automation_use_cases.create_rule(&ctx, request).await?;
+        Ok((StatusCode::CREATED, Json(ApiResponse::success(rule))))
+    }
+
+    pub async fn update_rule(
+        State(controller): State<Arc<AutomationController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(rule_id): Path<String>,
+        ApiJson(request): ApiJson<AutomationRuleRequest>,
+    ) -> Result<Json<ApiResponse<AutomationRuleDto>>, WebError> {
+        let rule = controller.automation_use_cases.update_rule(&ctx, &rule_id, request).await?;
+        Ok(Json(ApiResponse::success(rule)))
+    }
+
+    pub async fn delete_rule(
+        State(controller): State<Arc<AutomationController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(rule_id): Path<String>,
+    ) -> Result<Json<ApiResponse<HashMap<String, String>>>, WebError> {
+        controller.automation_use_cases.delete_rule(&ctx, &rule_id).await?;
+
+        let mut data = HashMap::new();
+        data.insert("message".to_string(), "Automation rule deleted successfully".to_string());
+        Ok(Json(ApiResponse::success(data)))
+    }
+}
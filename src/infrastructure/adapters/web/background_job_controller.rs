@@ -0,0 +1,52 @@
+use axum::{extract::{Path, State}, Extension, Json};
+use std::sync::Arc;
+
+use crate::application::{BackgroundJobDto, BackgroundJobUseCases, RequestContext};
+use crate::infrastructure::adapters::web::WebError;
+use crate::responses::ApiResponse;
+
+/// Admin endpoints for watching and steering the background jobs
+pub struct BackgroundJobController {
+    background_job_use_cases: Arc<BackgroundJobUseCases>,
+}
+
+impl BackgroundJobController {
+    pub fn new(background_job_use_cases: Arc<BackgroundJobUseCases>) -> Self {
+        Self { background_job_use_cases }
+    }
+
+    pub async fn list_jobs(
+        State(controller): State<Arc<BackgroundJobController>>,
+        Extension(ctx): Extension<RequestContext>,
+    ) -> Result<Json<ApiResponse<Vec<BackgroundJobDto>>>, WebError> {
+        let jobs = controller.background_job_use_cases.list_jobs(&ctx).await?;
+        Ok(Json(ApiResponse::success(jobs)))
+    }
+
+    pub async fn pause_job(
+        State(controller): State<Arc<BackgroundJobController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(name): Path<String>,
+    ) -> Result<Json<ApiResponse<BackgroundJobDto>>, WebError> {
+        let job = controller.background_job_use_cases.pause_job(&ctx, &name).await?;
+        Ok(Json(ApiResponse::success(job)))
+    }
+
+    pub async fn resume_job(
+        State(controller): State<Arc<BackgroundJobController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(name): Path<String>,
+    ) -> Result<Json<ApiResponse<BackgroundJobDto>>, WebError> {
+        let job = controller.background_job_use_cases.resume_job(&ctx, &name).await?;
+        Ok(Json(ApiResponse::success(job)))
+    }
+
+    pub async fn trigger_job(
+        State(controller): State<Arc<BackgroundJobController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(name): Path<String>,
+    ) -> Result<Json<ApiResponse<BackgroundJobDto>>, WebError> {
+        let job = controller.background_job_use_cases.trigger_job(&ctx, &name).await?;
+        Ok(Json(ApiResponse::success(job)))
+    }
+}
@@ -0,0 +1,163 @@
+use axum::{
+    body::{Body, Bytes},
+    extract::{FromRequest, Request},
+    http::{header, uri::PathAndQuery, HeaderValue, Uri},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::Value;
+
+use crate::infrastructure::adapters::web::WebError;
+
+/// Serves an API version with camelCase JSON field names over handlers written for snake_case.
+///
+/// Request body keys and query parameter names are converted to snake_case before the handler
+/// runs, and JSON response keys to camelCase afterwards. Values, such as statuses and error codes,
+/// are left alone. Bodies that are not JSON pass through unchanged, and multipart uploads pass
+/// through unread, for their handlers to read against their own limit.
+pub async fn camel_case_middleware(request: Request, next: Next) -> Response {
+    let request = match rename_request(request).await {
+        Ok(request) => request,
+        Err(error) => return error.into_response(),
+    };
+    rename_response(next.run(request).await).await
+}
+
+async fn rename_request(request: Request) -> Result<Request, WebError> {
+    let (mut parts, body) = request.into_parts();
+    if let Some(query) = parts.uri.query() {
+        let query = rename_query(query, to_snake_case);
+        let path_and_query = format!("{}?{}", parts.uri.path(), query);
+        let mut uri = parts.uri.clone().into_parts();
+        uri.path_and_query = PathAndQuery::try_from(path_and_query).ok();
+        parts.uri = Uri::from_parts(uri).unwrap_or(parts.uri);
+    }
+
+    if is_multipart(parts.headers.get(header::CONTENT_TYPE)) {
+        return Ok(Request::from_parts(parts, body));
+    }
+
+    // Reading through `Bytes` keeps the router's `DefaultBodyLimit` in force
+    let bytes = Bytes::from_request(Request::from_parts(parts.clone(), body), &())
+        .await
+        .map_err(|rejection| WebError::InvalidBody(rejection.status(), rejection.body_text()))?;
+    let bytes = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(json) if is_json(parts.headers.get(header::CONTENT_TYPE)) => {
+            parts.headers.remove(header::CONTENT_LENGTH);
+            Bytes::from(rename_keys(json, to_snake_case).to_string())
+        }
+        _ => bytes,
+    };
+    Ok(Request::from_parts(parts, Body::from(bytes)))
+}
+
+async fn rename_response(response: Response) -> Response {
+    if !is_json(response.headers().get(header::CONTENT_TYPE)) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+        return WebError::InternalError("Failed to read the response body".to_string()).into_response();
+    };
+    let bytes = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(json) => {
+            parts.headers.remove(header::CONTENT_LENGTH);
+            Bytes::from(rename_keys(json, to_camel_case).to_string())
+        }
+        Err(_) => bytes,
+    };
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+pub(crate) fn is_json(content_type: Option<&HeaderValue>) -> bool {
+    content_type
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"))
+}
+
+fn is_multipart(content_type: Option<&HeaderValue>) -> bool {
+    content_type
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("multipart/form-data"))
+}
+
+fn rename_query(query: &str, rename: fn(&str) -> String) -> String {
+    query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => format!("{}={}", rename(key), value),
+            None => rename(pair),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Renames every object key in `value`, recursively
+fn rename_keys(value: Value, rename: fn(&str) -> String) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (rename(&key), rename_keys(value, rename)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(|value| rename_keys(value, rename)).collect()),
+        other => other,
+    }
+}
+
+/// `created_at` to `createdAt`
+fn to_camel_case(name: &str) -> String {
+    let mut camel = String::with_capacity(name.len());
+    let mut upper_next = false;
+    for c in name.chars() {
+        if c == '_' && !camel.is_empty() {
+            upper_next = true;
+        } else if upper_next {
+            camel.extend(c.to_uppercase());
+            upper_next = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+/// `createdAt` to `created_at`; names that are already snake_case are unchanged
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_ascii_uppercase() && !snake.is_empty() {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_names_round_trip_between_cases() {
+        for (snake, camel) in [("task_id", "taskId"), ("p95_time", "p95Time"), ("total_time_in_progress", "totalTimeInProgress"), ("name", "name")] {
+            assert_eq!(to_camel_case(snake), camel);
+            assert_eq!(to_snake_case(camel), snake);
+        }
+        assert_eq!(to_snake_case("sort_by"), "sort_by");
+    }
+
+    #[test]
+    fn test_rename_keys_leaves_values_alone() {
+        let renamed = rename_keys(json!({"task_id": 1, "valid_transitions": ["in_progress"], "task": {"created_at": "now"}}), to_camel_case);
+
+        assert_eq!(renamed, json!({"taskId": 1, "validTransitions": ["in_progress"], "task": {"createdAt": "now"}}));
+    }
+
+    #[test]
+    fn test_rename_query_only_touches_parameter_names() {
+        assert_eq!(rename_query("sortBy=created_at&includeDeleted", to_snake_case), "sort_by=created_at&include_deleted");
+    }
+}
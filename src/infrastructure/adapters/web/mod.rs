@@ -1,3 +1,43 @@
+pub mod allocator_stats;
+pub mod api_json;
+pub mod api_key_controller;
+pub mod attachment_controller;
+pub mod authorization;
+pub mod automation_controller;
+pub mod background_job_controller;
+pub mod field_naming;
+pub mod health_controller;
+pub mod multipart;
+pub mod realtime_controller;
+pub mod report_format;
+pub mod request_context;
+pub mod request_id;
+pub mod router;
+pub mod schema_validation;
+pub mod server;
+pub mod task_clone_controller;
 pub mod task_controller;
+pub mod transaction;
+pub mod workspace_controller;
 
-pub use task_controller::*;
\ No newline at end of file
+pub use allocator_stats::*;
+pub use api_json::*;
+pub use api_key_controller::*;
+pub use attachment_controller::*;
+pub use authorization::*;
+pub use automation_controller::*;
+pub use background_job_controller::*;
+pub use field_naming::*;
+pub use health_controller::*;
+pub use multipart::*;
+pub use realtime_controller::*;
+pub use report_format::*;
+pub use request_context::*;
+pub use request_id::*;
+pub use router::*;
+pub use schema_validation::*;
+pub use server::*;
+pub use task_clone_controller::*;
+pub use task_controller::*;
+pub use transaction::*;
+pub use workspace_controller::*;
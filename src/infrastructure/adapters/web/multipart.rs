@@ -0,0 +1,152 @@
+use axum::{
+    extract::Request,
+    http::{header, StatusCode},
+};
+
+use crate::infrastructure::adapters::web::WebError;
+
+/// Room for the form's boundaries and part headers beyond the file itself
+const FORM_OVERHEAD_BYTES: usize = 16 * 1024;
+
+/// One part of a `multipart/form-data` body
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormPart {
+    pub name: String,
+    /// Set on file fields
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// Reads the `field` file from a `multipart/form-data` request whose file is at most `max_file_bytes`.
+///
+/// The router's body limit is sized for JSON, so the body is read here against a limit of its own
+/// and answered with 413 past it.
+pub async fn read_form_file(request: Request, field: &str, max_file_bytes: usize) -> Result<FormPart, WebError> {
+    let content_type = request.headers().get(header::CONTENT_TYPE).and_then(|value| value.to_str().ok()).unwrap_or_default();
+    let boundary = multipart_boundary(content_type).ok_or_else(|| {
+        WebError::InvalidBody(StatusCode::UNSUPPORTED_MEDIA_TYPE, "Expected a multipart/form-data body".to_string())
+    })?;
+    let too_large = || WebError::InvalidBody(StatusCode::PAYLOAD_TOO_LARGE, format!("Files cannot exceed {} bytes", max_file_bytes));
+    let body = axum::body::to_bytes(request.into_body(), max_file_bytes.saturating_add(FORM_OVERHEAD_BYTES))
+        .await
+        .map_err(|_| too_large())?;
+
+    let part = parse_multipart(&body, &boundary)
+        .map_err(|message| WebError::InvalidBody(StatusCode::BAD_REQUEST, message))?
+        .into_iter()
+        .find(|part| part.name == field && part.file_name.is_some())
+        .ok_or_else(|| WebError::ValidationError(format!("The form has no '{}' file", field)))?;
+    if part.body.len() > max_file_bytes {
+        return Err(too_large());
+    }
+    Ok(part)
+}
+
+/// The boundary of a `multipart/form-data` content type, unquoted
+pub fn multipart_boundary(content_type: &str) -> Option<String> {
+    let mut parameters = content_type.split(';');
+    if !parameters.next()?.trim().eq_ignore_ascii_case("multipart/form-data") {
+        return None;
+    }
+    parameters
+        .filter_map(|parameter| parameter.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("boundary"))
+        .map(|(_, value)| value.trim().trim_matches('"').to_string())
+        .filter(|boundary| !boundary.is_empty())
+}
+
+/// Splits a `multipart/form-data` body (RFC 7578) into its parts
+pub fn parse_multipart(body: &[u8], boundary: &str) -> Result<Vec<FormPart>, String> {
+    let delimiter = format!("--{}", boundary).into_bytes();
+    let malformed = || "Malformed multipart body".to_string();
+
+    // Anything before the first delimiter is a preamble and ignored
+    let mut rest = &body[find(body, &delimiter).ok_or_else(malformed)? + delimiter.len()..];
+    let mut parts = Vec::new();
+    loop {
+        if rest.starts_with(b"--") {
+            return Ok(parts);
+        }
+        rest = rest.strip_prefix(b"\r\n").ok_or_else(malformed)?;
+        let end = find(rest, &[b"\r\n".as_slice(), &delimiter].concat()).ok_or_else(malformed)?;
+        parts.push(parse_part(&rest[..end])?);
+        rest = &rest[end + 2 + delimiter.len()..];
+    }
+}
+
+fn parse_part(part: &[u8]) -> Result<FormPart, String> {
+    let (headers, body) = match find(part, b"\r\n\r\n") {
+        Some(end) => (&part[..end], &part[end + 4..]),
+        None => return Err("Multipart part has no blank line after its headers".to_string()),
+    };
+    let headers = std::str::from_utf8(headers).map_err(|_| "Multipart part headers must be UTF-8".to_string())?;
+
+    let mut disposition = None;
+    let mut content_type = None;
+    for line in headers.split("\r\n") {
+        let Some((name, value)) = line.split_once(':') else { continue };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-disposition" => disposition = Some(value.trim()),
+            "content-type" => content_type = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+    let disposition = disposition.ok_or_else(|| "Multipart part has no Content-Disposition".to_string())?;
+    let parameter = |wanted: &str| {
+        disposition
+            .split(';')
+            .skip(1)
+            .filter_map(|parameter| parameter.split_once('='))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case(wanted))
+            .map(|(_, value)| value.trim().trim_matches('"').to_string())
+    };
+    Ok(FormPart {
+        name: parameter("name").ok_or_else(|| "Multipart part has no name".to_string())?,
+        file_name: parameter("filename"),
+        content_type,
+        body: body.to_vec(),
+    })
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boundary_is_read_from_the_content_type() {
+        assert_eq!(multipart_boundary("multipart/form-data; boundary=abc").as_deref(), Some("abc"));
+        assert_eq!(multipart_boundary("Multipart/Form-Data; charset=utf-8; Boundary=\"a b\"").as_deref(), Some("a b"));
+        assert_eq!(multipart_boundary("application/json"), None);
+        assert_eq!(multipart_boundary("multipart/form-data"), None);
+    }
+
+    #[test]
+    fn test_parts_are_split_on_the_boundary() {
+        let body = b"preamble\r\n--xyz\r\n\
+            Content-Disposition: form-data; name=\"note\"\r\n\r\n\
+            hello\r\n--xyz\r\n\
+            Content-Disposition: form-data; name=\"file\"; filename=\"a.txt\"\r\n\
+            Content-Type: text/plain\r\n\r\n\
+            line one\r\nline two\r\n--xyz--\r\n";
+
+        let parts = parse_multipart(body, "xyz").unwrap();
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0], FormPart { name: "note".to_string(), file_name: None, content_type: None, body: b"hello".to_vec() });
+        assert_eq!(parts[1].file_name.as_deref(), Some("a.txt"));
+        assert_eq!(parts[1].content_type.as_deref(), Some("text/plain"));
+        assert_eq!(parts[1].body, b"line one\r\nline two");
+    }
+
+    #[test]
+    fn test_malformed_bodies_are_rejected() {
+        for body in [b"no delimiter".as_slice(), b"--xyz\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\nunterminated", b"--xyz\r\nno headers\r\n--xyz--"] {
+            assert!(parse_multipart(body, "xyz").is_err(), "{:?}", String::from_utf8_lossy(body));
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+    Extension,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::Instant;
+
+use crate::application::{field_messages, ApiKeyUseCases, RequestContext, TaskChangeBatcher, TaskChangeDto, TaskChangeFeed, TaskDto, TaskUseCases, UpdateTaskStatusDto, UseCaseError};
+use crate::database::{current_shard, current_workspace, with_shard, with_workspace};
+use crate::domain::UserRole;
+
+/// Messages a WebSocket client may send
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Switches the connection to an API key's identity, for clients that cannot set headers
+    Authenticate { api_key: String },
+    /// Moves a task to another status as the connection's caller
+    UpdateStatus {
+        task_id: i32,
+        #[serde(flatten)]
+        request: UpdateTaskStatusDto,
+    },
+}
+
+/// Messages the server sends over a WebSocket
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// Who the connection acts as, sent on connect and after authenticating
+    Identity { user_id: String, role: UserRole },
+    TaskChanged { change: TaskChangeDto },
+    StatusUpdated { task: TaskDto },
+    /// The connection fell behind and skipped this many changes
+    Lagged { missed: u64 },
+    Error { message: String },
+}
+
+/// Pushes task changes to board clients and accepts a few commands over WebSocket
+pub struct RealtimeController {
+    task_use_cases: Arc<TaskUseCases>,
+    api_key_use_cases: Arc<ApiKeyUseCases>,
+    change_feed: TaskChangeFeed,
+    batch_window: Option<Duration>,
+}
+
+impl RealtimeController {
+    pub fn new(task_use_cases: Arc<TaskUseCases>, api_key_use_cases: Arc<ApiKeyUseCases>, change_feed: TaskChangeFeed) -> Self {
+        Self { task_use_cases, api_key_use_cases, change_feed, batch_window: None }
+    }
+
+    /// Merges each connection's changes to one task within `window` into a single `task_changed`
+    pub fn with_batch_window(mut self, window: Duration) -> Self {
+        self.batch_window = Some(window);
+        self
+    }
+
+    /// Upgrades to a WebSocket that starts with the caller identified from the upgrade request
+    pub async fn connect(
+        State(controller): State<Arc<RealtimeController>>,
+        Extension(ctx): Extension<RequestContext>,
+        upgrade: WebSocketUpgrade,
+    ) -> Response {
+        // The socket outlives the upgrade request, so it keeps that request's shard explicitly
+        let shard = current_shard();
+        let workspace = current_workspace();
+        upgrade.on_upgrade(move |socket| with_shard(shard, with_workspace(workspace, async move { controller.serve(socket, ctx).await })))
+    }
+
+    async fn serve(&self, mut socket: WebSocket, mut ctx: RequestContext) {
+        let mut changes = self.change_feed.subscribe();
+        let mut batcher = self.batch_window.map(TaskChangeBatcher::new);
+        if send(&mut socket, &identity(&ctx)).await.is_err() {
+            return;
+        }
+
+        loop {
+            let next_due = batcher.as_ref().and_then(TaskChangeBatcher::next_due);
+            let replies = tokio::select! {
+                incoming = socket.recv() => match incoming {
+                    Some(Ok(Message::Text(text))) => vec![self.handle(&text, &mut ctx).await],
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => continue,
+                },
+                change = changes.recv() => match (change, batcher.as_mut()) {
+                    (Ok(change), _) if change.workspace_id != ctx.workspace() => continue,
+                    (Ok(change), Some(batcher)) => {
+                        batcher.push(change, Instant::now());
+                        continue;
+                    }
+                    (Ok(change), None) => vec![ServerMessage::TaskChanged { change }],
+                    (Err(RecvError::Lagged(missed)), _) => vec![ServerMessage::Lagged { missed }],
+                    (Err(RecvError::Closed), _) => break,
+                },
+                _ = tokio::time::sleep_until(next_due.unwrap_or_else(Instant::now)), if next_due.is_some() => {
+                    let due = batcher.as_mut().map(|batcher| batcher.take_due(Instant::now())).unwrap_or_default();
+                    due.into_iter().map(|change| ServerMessage::TaskChanged { change }).collect()
+                },
+            };
+            for reply in &replies {
+                if send(&mut socket, reply).await.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn handle(&self, text: &str, ctx: &mut RequestContext) -> ServerMessage {
+        let message = match serde_json::from_str::<ClientMessage>(text) {
+            Ok(message) => message,
+            Err(error) => return ServerMessage::Error { message: format!("Invalid message: {}", error) },
+        };
+
+        match message {
+            ClientMessage::Authenticate { api_key } => match self.api_key_use_cases.authenticate(&api_key).await {
+                Ok(Some(api_key)) if api_key.workspace_id != ctx.workspace() => {
+                    ServerMessage::Error { message: "API key belongs to another workspace".to_string() }
+                }
+                Ok(Some(api_key)) => {
+                    *ctx = ctx.clone().with_user(api_key.principal(), api_key.role);
+                    identity(ctx)
+                }
+                Ok(None) => ServerMessage::Error { message: "Invalid API key".to_string() },
+                Err(error) => error_message(error),
+            },
+            ClientMessage::UpdateStatus { task_id, request } => {
+                match self.task_use_cases.update_task_status(ctx, task_id, request).await {
+                    Ok(task) => ServerMessage::StatusUpdated { task },
+                    Err(error) => error_message(error),
+                }
+            }
+        }
+    }
+}
+
+fn identity(ctx: &RequestContext) -> ServerMessage {
+    ServerMessage::Identity { user_id: ctx.user_id.clone(), role: ctx.role.clone() }
+}
+
+/// Reports a failed command with the same message the HTTP API would give
+fn error_message(error: UseCaseError) -> ServerMessage {
+    let message = match error {
+        UseCaseError::ValidationError(msg)
+        | UseCaseError::NotFound(msg)
+        | UseCaseError::Forbidden(msg)
+        | UseCaseError::RepositoryError(msg)
+        | UseCaseError::Cancelled(msg) => msg,
+        UseCaseError::Domain(error) => error.to_string(),
+        UseCaseError::InvalidFields(errors) => field_messages(&errors),
+        UseCaseError::Conflict(error) => error.message,
+    };
+    ServerMessage::Error { message }
+}
+
+async fn send(socket: &mut WebSocket, message: &ServerMessage) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(message).expect("server messages always serialize");
+    socket.send(Message::Text(text.into())).await
+}
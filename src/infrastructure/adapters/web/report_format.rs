@@ -0,0 +1,98 @@
+use axum::{
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fmt::Write;
+
+use crate::application::{CohortAnalysisDto, CumulativeFlowDto, TransitionMatrixDto};
+use crate::infrastructure::adapters::web::WebError;
+use crate::responses::ApiResponse;
+
+/// How a report endpoint answers, from `?format=` (default json)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    /// Plain sentences, one fact per line, for screen readers and terminals
+    Txt,
+}
+
+impl ReportFormat {
+    pub fn from_param(value: Option<&str>) -> Result<Self, WebError> {
+        match value {
+            None | Some("json") => Ok(ReportFormat::Json),
+            Some("txt") => Ok(ReportFormat::Txt),
+            Some(other) => Err(WebError::ValidationError(format!("Unknown report format '{}'; use json or txt", other))),
+        }
+    }
+}
+
+/// Reports that can also be read as plain text
+pub trait TextReport {
+    fn render_text(&self) -> String;
+}
+
+/// Answers with the report wrapped in the usual JSON envelope, or as plain text
+pub fn report_response<T: Serialize + TextReport>(report: T, format: ReportFormat) -> Response {
+    match format {
+        ReportFormat::Json => Json(ApiResponse::success(report)).into_response(),
+        ReportFormat::Txt => ([(header::CONTENT_TYPE, "text/plain; charset=utf-8")], report.render_text()).into_response(),
+    }
+}
+
+fn date(value: DateTime<Utc>) -> String {
+    value.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+impl TextReport for TransitionMatrixDto {
+    fn render_text(&self) -> String {
+        let mut text = String::new();
+        let _ = writeln!(text, "Status transitions from {} to {}", date(self.period_start), date(self.period_end));
+        let _ = writeln!(text, "Total transitions: {}", self.total_transitions);
+        for edge in &self.edges {
+            let _ = write!(text, "{} to {}: {} transitions", edge.from_status.as_str(), edge.to_status.as_str(), edge.count);
+            if let Some(dwell) = &edge.median_dwell_time {
+                let _ = write!(text, ", median time in {} {}", edge.from_status.as_str(), dwell);
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl TextReport for CohortAnalysisDto {
+    fn render_text(&self) -> String {
+        let mut text = String::new();
+        let _ = writeln!(text, "Completion by {} of creation, for tasks created from {} to {}", self.bucket, date(self.period_start), date(self.period_end));
+        for cohort in &self.cohorts {
+            let _ = writeln!(text, "Tasks created in the {} starting {}: {}", self.bucket, date(cohort.cohort_start), cohort.tasks_created);
+            for period in &cohort.completion {
+                let plural = if period.periods_since_creation == 1 { "" } else { "s" };
+                let _ = writeln!(
+                    text,
+                    "  {} {}{} after creation: {} completed ({}%)",
+                    period.periods_since_creation, self.bucket, plural, period.completed, period.completion_percentage
+                );
+            }
+        }
+        text
+    }
+}
+
+impl TextReport for CumulativeFlowDto {
+    fn render_text(&self) -> String {
+        let mut text = String::new();
+        let _ = writeln!(text, "Tasks in each status by {} from {} to {}", self.bucket, date(self.period_start), date(self.period_end));
+        for bucket in &self.buckets {
+            let _ = write!(text, "At the close of the {} starting {}: {} tasks", self.bucket, date(bucket.bucket_start), bucket.total);
+            for facet in &bucket.statuses {
+                let _ = write!(text, ", {} {}", facet.count, facet.status.as_str());
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
@@ -0,0 +1,174 @@
+use axum::{
+    extract::{Request, State},
+    http::HeaderMap,
+    middleware::Next,
+    response::Response,
+};
+
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+
+use crate::application::{ApiKeyUseCases, RequestContext, WorkspaceUseCases, DEFAULT_LOCALE};
+use crate::database::{with_workspace, ShardRegistry};
+use crate::domain::UserRole;
+use crate::infrastructure::adapters::web::WebError;
+
+pub const USER_ID_HEADER: &str = "x-user-id";
+pub const USER_ROLE_HEADER: &str = "x-user-role";
+pub const TENANT_HEADER: &str = "x-tenant-id";
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// How the request context middleware treats client-supplied identity
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestContextSettings {
+    /// Take user, role and tenant from headers; only safe behind a proxy that sets them
+    pub trust_identity_headers: bool,
+}
+
+/// State of the request context middleware
+#[derive(Clone)]
+pub struct RequestContextState {
+    pub settings: RequestContextSettings,
+    /// Resolves `X-Api-Key` to the key's role
+    pub api_keys: Arc<ApiKeyUseCases>,
+    /// Cancelled on server shutdown; each request's token is a child of it
+    pub shutdown: CancellationToken,
+    /// Routes each request's repository calls to its tenant's database shard
+    pub shards: Arc<ShardRegistry>,
+    /// Checks each request's tenant names an existing workspace
+    pub workspaces: Arc<WorkspaceUseCases>,
+}
+
+/// Builds the `RequestContext` for each request and stores it in the request extensions.
+///
+/// A valid `X-Api-Key` takes precedence over identity headers and sets the tenant to the
+/// key's workspace; an unknown key is rejected with 401, and a key sent with an `X-Tenant-Id`
+/// naming another workspace with 403. Without either every caller is an anonymous User. The context's
+/// cancellation token fires if the request is dropped before answering, e.g. because
+/// the client disconnected. Tenants assigned to a shard have their queries run there.
+/// The tenant is the request's workspace, the default one without a tenant; every task
+/// and status history query is limited to it and an unknown workspace is answered with 404.
+pub async fn request_context_middleware(
+    State(state): State<RequestContextState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, WebError> {
+    let mut context = context_from_headers(request.headers(), state.settings)?;
+    if let Some(secret) = header(request.headers(), API_KEY_HEADER) {
+        let api_key = state.api_keys.authenticate(secret).await?
+            .ok_or_else(|| WebError::Unauthorized("Invalid API key".to_string()))?;
+        if context.tenant.as_ref().is_some_and(|tenant| *tenant != api_key.workspace_id) {
+            return Err(WebError::Forbidden("API key belongs to another workspace".to_string()));
+        }
+        context = context.with_user(api_key.principal(), api_key.role).with_tenant(Some(api_key.workspace_id)).with_api_key();
+    }
+    let cancellation = state.shutdown.child_token();
+    let tenant = context.tenant.clone();
+    let workspace = state.workspaces.resolve(tenant.as_deref()).await?;
+    request.extensions_mut().insert(context.with_cancellation(cancellation.clone()));
+
+    let guard = cancellation.drop_guard();
+    let response = with_workspace(Some(workspace), state.shards.scope(tenant.as_deref(), next.run(request))).await;
+    guard.disarm();
+    Ok(response)
+}
+
+fn context_from_headers(headers: &HeaderMap, settings: RequestContextSettings) -> Result<RequestContext, WebError> {
+    let mut context = RequestContext::anonymous().with_locale(locale(headers));
+
+    if let Some(request_id) = header(headers, REQUEST_ID_HEADER) {
+        context = context.with_correlation_id(request_id);
+    }
+
+    if settings.trust_identity_headers {
+        let role = header(headers, USER_ROLE_HEADER)
+            .map(UserRole::from_str)
+            .transpose()
+            .map_err(WebError::ValidationError)?
+            .unwrap_or_default();
+        if role == UserRole::System {
+            return Err(WebError::ValidationError("The System role is reserved for background jobs".to_string()));
+        }
+        let user_id = header(headers, USER_ID_HEADER).unwrap_or("anonymous");
+        context = context
+            .with_user(user_id, role)
+            .with_tenant(header(headers, TENANT_HEADER).map(str::to_string));
+    }
+
+    Ok(context)
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// First language tag of `Accept-Language`, ignoring quality weights
+fn locale(headers: &HeaderMap) -> String {
+    header(headers, "accept-language")
+        .and_then(|value| value.split(',').next())
+        .and_then(|tag| tag.split(';').next())
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty() && *tag != "*")
+        .unwrap_or(DEFAULT_LOCALE)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    const TRUSTED: RequestContextSettings = RequestContextSettings { trust_identity_headers: true };
+
+    #[test]
+    fn test_identity_headers_ignored_unless_trusted() {
+        let headers = headers(&[(USER_ID_HEADER, "alice"), (USER_ROLE_HEADER, "Admin")]);
+
+        let context = context_from_headers(&headers, RequestContextSettings::default()).unwrap();
+        assert_eq!(context.user_id, "anonymous");
+        assert_eq!(context.role, UserRole::User);
+
+        let context = context_from_headers(&headers, TRUSTED).unwrap();
+        assert_eq!(context.user_id, "alice");
+        assert_eq!(context.role, UserRole::Admin);
+    }
+
+    #[test]
+    fn test_invalid_role_is_rejected() {
+        let headers = headers(&[(USER_ROLE_HEADER, "Owner")]);
+        assert!(context_from_headers(&headers, TRUSTED).is_err());
+    }
+
+    #[test]
+    fn test_system_role_cannot_be_claimed() {
+        let headers = headers(&[(USER_ROLE_HEADER, "System")]);
+        assert!(context_from_headers(&headers, TRUSTED).is_err());
+    }
+
+    #[test]
+    fn test_correlation_id_and_locale() {
+        let context = context_from_headers(
+            &headers(&[(REQUEST_ID_HEADER, "req-42"), ("accept-language", "pt-BR;q=0.9, en;q=0.8")]),
+            RequestContextSettings::default(),
+        ).unwrap();
+        assert_eq!(context.correlation_id, "req-42");
+        assert_eq!(context.locale, "pt-BR");
+
+        let context = context_from_headers(&HeaderMap::new(), RequestContextSettings::default()).unwrap();
+        assert!(!context.correlation_id.is_empty());
+        assert_eq!(context.locale, "en");
+    }
+}
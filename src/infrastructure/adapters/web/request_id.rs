@@ -0,0 +1,71 @@
+use axum::{
+    extract::Request,
+    http::HeaderValue,
+    middleware::Next,
+    response::Response,
+};
+use tower_http::trace::MakeSpan;
+use tracing::Span;
+use uuid::Uuid;
+
+use crate::infrastructure::adapters::web::REQUEST_ID_HEADER;
+
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// The id of the request being handled, when called inside `request_id_middleware`
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Accepts the caller's `X-Request-Id` or generates one, and echoes it on the response.
+///
+/// The id is written back into the request headers so later layers and the request
+/// context see the same value.
+pub async fn request_id_middleware(mut request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|id| is_valid_request_id(id))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let header = HeaderValue::from_str(&request_id).expect("request ids are visible ASCII");
+    request.headers_mut().insert(REQUEST_ID_HEADER, header.clone());
+
+    let mut response = REQUEST_ID.scope(request_id, next.run(request)).await;
+    response.headers_mut().insert(REQUEST_ID_HEADER, header);
+    response
+}
+
+fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LENGTH
+        && id.bytes().all(|byte| byte.is_ascii_graphic())
+}
+
+/// Request span for `TraceLayer` carrying the request id, so every log line can be correlated
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestIdSpan;
+
+impl<B> MakeSpan<B> for RequestIdSpan {
+    fn make_span(&mut self, request: &axum::http::Request<B>) -> Span {
+        let request_id = request
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+
+        tracing::info_span!(
+            "request",
+            method = %request.method(),
+            uri = %request.uri(),
+            request_id = %request_id,
+        )
+    }
+}
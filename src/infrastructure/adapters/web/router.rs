@@ -0,0 +1,233 @@
+use axum::{
+    extract::DefaultBodyLimit,
+    handler::Handler,
+    middleware,
+    routing::{delete, get, patch, post},
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tower::ServiceBuilder;
+use tower_http::trace::TraceLayer;
+
+use crate::infrastructure::adapters::web::{authorization_middleware, camel_case_middleware, request_context_middleware, request_id_middleware, ApiKeyController, AttachmentController, AutomationController, BackgroundJobController, HealthController, RealtimeController, RequestContextState, RequestIdSpan, RequestSchema, TaskCloneController, TaskController, WorkspaceController, schema_validation_middleware};
+
+/// Builds the application router with all task, workflow, admin and health routes
+#[allow(clippy::too_many_arguments)]
+pub fn create_router(
+    task_controller: Arc<TaskController>,
+    api_key_controller: Arc<ApiKeyController>,
+    background_job_controller: Arc<BackgroundJobController>,
+    automation_controller: Arc<AutomationController>,
+    workspace_controller: Arc<WorkspaceController>,
+    attachment_controller: Arc<AttachmentController>,
+    task_clone_controller: Arc<TaskCloneController>,
+    realtime_controller: Arc<RealtimeController>,
+    health_controller: Arc<HealthController>,
+    context: RequestContextState,
+) -> Router {
+    let admin_routes = Router::new()
+        .route("/admin/api-keys", post(ApiKeyController::create_api_key))
+        .with_state(api_key_controller)
+        .merge(background_job_routes().with_state(background_job_controller))
+        .merge(workspace_routes().with_state(workspace_controller))
+        .merge(task_clone_routes().with_state(task_clone_controller));
+
+    let realtime_routes = Router::new()
+        .route("/ws", get(RealtimeController::connect))
+        .with_state(realtime_controller);
+
+    // Probes stay open to the orchestrator; diagnostics go through the same role check as admin routes
+    let diagnostics_routes = Router::new()
+        .route("/health/diagnostics", get(HealthController::diagnostics))
+        .with_state(health_controller.clone())
+        .route_layer(middleware::from_fn(authorization_middleware));
+    let health_routes = Router::new()
+        .route("/health/live", get(HealthController::live))
+        .route("/health/ready", get(HealthController::ready))
+        .with_state(health_controller);
+
+    let automation_routes = automation_routes().with_state(automation_controller);
+    let attachment_routes = attachment_routes().with_state(attachment_controller);
+
+    // Role checks need the matched route and the caller, so they run after routing and the request context
+    let api_routes = task_routes().merge(admin_routes).merge(automation_routes).merge(attachment_routes)
+        .route_layer(middleware::from_fn(authorization_middleware));
+    // The middleware reads the body within the outer limit; the handlers then see its renamed copy
+    let camel_case_routes = api_routes.clone()
+        .layer(DefaultBodyLimit::disable())
+        .layer(middleware::from_fn(camel_case_middleware));
+
+    Router::new()
+        .route("/", get(root_handler))
+        .merge(api_routes)
+        .nest("/v2", camel_case_routes)
+        .merge(realtime_routes)
+        .merge(diagnostics_routes)
+        .layer(middleware::from_fn_with_state(context, request_context_middleware))
+        .merge(health_routes)
+        .layer(
+            ServiceBuilder::new()
+                .layer(middleware::from_fn(request_id_middleware))
+                .layer(TraceLayer::new_for_http().make_span_with(RequestIdSpan))
+        )
+        .with_state(task_controller)
+}
+
+/// The background job runbook: status of every job, and controls to pause, resume or run one now
+fn background_job_routes() -> Router<Arc<BackgroundJobController>> {
+    Router::new()
+        .route("/admin/jobs", get(BackgroundJobController::list_jobs))
+        .route("/admin/jobs/{name}/pause", post(BackgroundJobController::pause_job))
+        .route("/admin/jobs/{name}/resume", post(BackgroundJobController::resume_job))
+        .route("/admin/jobs/{name}/trigger", post(BackgroundJobController::trigger_job))
+}
+
+/// CRUD for the workspaces, the workflow each one's tasks follow and the custom statuses
+/// workflows may use; deleting a workspace or status is refused while tasks are in it
+fn workspace_routes() -> Router<Arc<WorkspaceController>> {
+    Router::new()
+        .route("/admin/workspaces", get(WorkspaceController::list_workspaces).post(WorkspaceController::create_workspace))
+        .route("/admin/workspaces/{workspace_id}",
+            get(WorkspaceController::get_workspace)
+            .patch(WorkspaceController::rename_workspace)
+            .delete(WorkspaceController::delete_workspace)
+        )
+        .route("/admin/workspaces/{workspace_id}/workflow",
+            get(WorkspaceController::get_workflow)
+            .put(WorkspaceController::set_workflow)
+            .delete(WorkspaceController::reset_workflow)
+        )
+        .route("/admin/statuses", get(WorkspaceController::list_statuses).post(WorkspaceController::create_status))
+        .route("/admin/statuses/{name}", delete(WorkspaceController::delete_status))
+}
+
+/// Copying tasks between workspaces in the background, and the progress of each copy
+fn task_clone_routes() -> Router<Arc<TaskCloneController>> {
+    Router::new()
+        .route("/admin/task-clones", get(TaskCloneController::list_clones).post(TaskCloneController::start_clone))
+        .route("/admin/task-clones/{clone_id}", get(TaskCloneController::get_clone))
+}
+
+/// CRUD for the automation rules
+fn automation_routes() -> Router<Arc<AutomationController>> {
+    Router::new()
+        .route("/automation/rules", get(AutomationController::list_rules).post(AutomationController::create_rule))
+        .route("/automation/rules/{rule_id}",
+            get(AutomationController::get_rule)
+            .put(AutomationController::update_rule)
+            .delete(AutomationController::delete_rule)
+        )
+}
+
+/// Files attached to tasks; uploads are read against their own size limit, not the router's
+fn attachment_routes() -> Router<Arc<AttachmentController>> {
+    Router::new()
+        .route("/tasks/{task_id}/attachments",
+            get(AttachmentController::list_attachments)
+            .post(AttachmentController::upload_attachment)
+        )
+}
+
+/// Task, workflow and analytics routes, served as-is and again under `/v2` with camelCase fields
+fn task_routes() -> Router<Arc<TaskController>> {
+    Router::new()
+        .route("/tasks", 
+            get(TaskController::get_tasks)
+            .post(TaskController::create_task.layer(middleware::from_fn_with_state(RequestSchema("CreateTaskRequest"), schema_validation_middleware)))
+        )
+        .route("/tasks/search", 
+            get(TaskController::search_tasks)
+        )
+        .route("/tasks/facets", 
+            get(TaskController::get_task_facets)
+        )
+        .route("/tasks/stats", 
+            get(TaskController::get_task_statistics)
+        )
+        .route("/board", 
+            get(TaskController::get_board)
+        )
+        .route("/tasks/stale", 
+            get(TaskController::get_stale_tasks)
+        )
+        .route("/tasks/archived", 
+            get(TaskController::get_archived_tasks)
+        )
+        .route("/tasks/bulk/delete", 
+            post(TaskController::bulk_delete_tasks)
+        )
+        .route("/tasks/{task_id}", 
+            get(TaskController::get_task)
+            .patch(TaskController::update_task.layer(middleware::from_fn_with_state(RequestSchema("UpdateTaskRequest"), schema_validation_middleware)))
+            .delete(TaskController::delete_task)
+        )
+        .route("/tasks/{task_id}/status", 
+            patch(TaskController::update_task_status.layer(middleware::from_fn_with_state(RequestSchema("UpdateTaskStatusDto"), schema_validation_middleware)))
+        )
+        .route("/tasks/{task_id}/restore", 
+            post(TaskController::restore_task)
+        )
+        .route("/tasks/{task_id}/archive", 
+            post(TaskController::archive_task)
+        )
+        .route("/tasks/{task_id}/position", 
+            patch(TaskController::move_task)
+        )
+        .route("/tasks/{task_id}/transitions", 
+            get(TaskController::get_task_with_transitions)
+        )
+        .route("/tasks/{task_id}/dependencies", 
+            post(TaskController::add_dependency.layer(middleware::from_fn_with_state(RequestSchema("AddDependencyRequest"), schema_validation_middleware)))
+        )
+        .route("/tasks/{task_id}/dependencies/{dep_id}", 
+            delete(TaskController::remove_dependency)
+        )
+        .route("/tasks/{task_id}/history", 
+            get(TaskController::get_task_history)
+        )
+        .route("/tasks/{task_id}/analytics", 
+            get(TaskController::get_task_analytics)
+        )
+        .route("/analytics/completion", 
+            get(TaskController::get_completion_analytics)
+        )
+        .route("/analytics/tasks/{task_id}", 
+            get(TaskController::get_task_analytics)
+        )
+        .route("/analytics/transitions", 
+            get(TaskController::get_transition_matrix)
+        )
+        .route("/analytics/cohorts", 
+            get(TaskController::get_cohort_analysis)
+        )
+        .route("/analytics/cumulative-flow", 
+            get(TaskController::get_cumulative_flow)
+        )
+        .route("/history", 
+            get(TaskController::get_history)
+        )
+        .route("/admin/tasks/reprioritize", 
+            post(TaskController::reprioritize_tasks)
+        )
+        .route("/admin/tasks/import", 
+            post(TaskController::import_tasks)
+        )
+}
+
+/// Root endpoint handler
+async fn root_handler() -> Json<serde_json::Value> {
+    Json(json!({
+        "message": "Welcome to the Axum Postgres Rust API",
+        "version": "1.0.0",
+        "endpoints": {
+            "tasks": "/tasks",
+            "tasks_camel_case": "/v2/tasks",
+            "history": "/history",
+            "realtime": "/ws",
+            "liveness": "/health/live",
+            "readiness": "/health/ready",
+            "diagnostics": "/health/diagnostics"
+        }
+    }))
+}
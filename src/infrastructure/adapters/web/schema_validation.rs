@@ -0,0 +1,305 @@
+use axum::{
+    body::{Body, Bytes},
+    extract::{FromRequest, Request, State},
+    http::header,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::{Map, Value};
+use std::sync::LazyLock;
+
+use crate::application::FieldError;
+use crate::infrastructure::adapters::web::{is_json, WebError};
+
+/// The API contract whose component schemas inbound payloads are checked against
+const OPENAPI_DOCUMENT: &str = include_str!("../../../../docs/openapi.json");
+
+static API_SCHEMAS: LazyLock<SchemaValidator> = LazyLock::new(|| {
+    SchemaValidator::from_openapi(OPENAPI_DOCUMENT).expect("docs/openapi.json is a valid OpenAPI document")
+});
+
+const REF_PREFIX: &str = "#/components/schemas/";
+
+/// Checks JSON values against the component schemas of an OpenAPI 3.0 document.
+///
+/// Supports the keywords the API's contract uses: `$ref`, `type`, `format: int32`, `nullable`,
+/// `enum` (case-insensitive with `x-ignore-case`), `oneOf`, `allOf`, `required`, `properties`,
+/// `additionalProperties: false` and `items`. Other keywords are ignored.
+#[derive(Debug, Clone)]
+pub struct SchemaValidator {
+    schemas: Map<String, Value>,
+}
+
+impl SchemaValidator {
+    /// Reads `components.schemas`, rejecting references to schemas that do not exist
+    pub fn from_openapi(document: &str) -> Result<Self, String> {
+        let document: Value = serde_json::from_str(document)
+            .map_err(|e| format!("The OpenAPI document is not valid JSON: {}", e))?;
+        let schemas = document
+            .pointer("/components/schemas")
+            .and_then(Value::as_object)
+            .cloned()
+            .ok_or("The OpenAPI document has no components.schemas")?;
+
+        let validator = Self { schemas };
+        validator.check_references(&Value::Object(validator.schemas.clone()))?;
+        Ok(validator)
+    }
+
+    /// The schemas of `docs/openapi.json`, parsed on first use
+    pub fn api() -> &'static SchemaValidator {
+        &API_SCHEMAS
+    }
+
+    pub fn has_schema(&self, name: &str) -> bool {
+        self.schemas.contains_key(name)
+    }
+
+    /// Every violation of the named schema, each located by the JSON pointer of the offending value
+    pub fn validate(&self, schema_name: &str, value: &Value) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        match self.schemas.get(schema_name) {
+            Some(schema) => self.check(schema, value, "", &mut errors),
+            None => errors.push(schema_error("", "unknown_schema", format!("has no schema named {}", schema_name))),
+        }
+        errors
+    }
+
+    fn check_references(&self, schema: &Value) -> Result<(), String> {
+        match schema {
+            Value::Object(fields) => {
+                if let Some(reference) = fields.get("$ref").and_then(Value::as_str) {
+                    self.resolve(reference).ok_or_else(|| format!("Unknown schema reference {}", reference))?;
+                }
+                fields.values().try_for_each(|field| self.check_references(field))
+            }
+            Value::Array(items) => items.iter().try_for_each(|item| self.check_references(item)),
+            _ => Ok(()),
+        }
+    }
+
+    fn resolve(&self, reference: &str) -> Option<&Value> {
+        reference.strip_prefix(REF_PREFIX).and_then(|name| self.schemas.get(name))
+    }
+
+    fn check(&self, schema: &Value, value: &Value, pointer: &str, errors: &mut Vec<FieldError>) {
+        if let Some(target) = schema.get("$ref").and_then(Value::as_str).and_then(|reference| self.resolve(reference)) {
+            return self.check(target, value, pointer, errors);
+        }
+        if value.is_null() {
+            if schema.get("nullable") != Some(&Value::Bool(true)) {
+                errors.push(schema_error(pointer, "invalid_type", format!("must be {}", self.describe(schema))));
+            }
+            return;
+        }
+
+        if let Some(all_of) = schema.get("allOf").and_then(Value::as_array) {
+            for branch in all_of {
+                self.check(branch, value, pointer, errors);
+            }
+        }
+        if let Some(one_of) = schema.get("oneOf").and_then(Value::as_array) {
+            let matching = one_of.iter().filter(|branch| self.matches(branch, value, pointer)).count();
+            if matching != 1 {
+                errors.push(schema_error(pointer, "no_matching_schema", format!("must be {}", self.describe(schema))));
+                return;
+            }
+        }
+
+        if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+            if !has_type(expected, schema.get("format").and_then(Value::as_str), value) {
+                errors.push(schema_error(pointer, "invalid_type", format!("must be {}", self.describe(schema))));
+                return;
+            }
+        }
+        if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+            let ignore_case = schema.get("x-ignore-case") == Some(&Value::Bool(true));
+            let found = allowed.iter().any(|option| match (option.as_str(), value.as_str()) {
+                (Some(option), Some(value)) if ignore_case => option.eq_ignore_ascii_case(value),
+                _ => option == value,
+            });
+            if !found {
+                errors.push(schema_error(pointer, "not_allowed", format!("must be {}", self.describe(schema))));
+            }
+        }
+
+        match value {
+            Value::Object(fields) => self.check_object(schema, fields, pointer, errors),
+            Value::Array(items) => {
+                if let Some(item_schema) = schema.get("items") {
+                    for (index, item) in items.iter().enumerate() {
+                        self.check(item_schema, item, &format!("{}/{}", pointer, index), errors);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_object(&self, schema: &Value, fields: &Map<String, Value>, pointer: &str, errors: &mut Vec<FieldError>) {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for required in schema.get("required").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str) {
+            if !fields.contains_key(required) {
+                errors.push(schema_error(&child(pointer, required), "missing_field", "is required".to_string()));
+            }
+        }
+        for (name, field) in fields {
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(field_schema) => self.check(field_schema, field, &child(pointer, name), errors),
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    errors.push(schema_error(&child(pointer, name), "unknown_field", "is not allowed".to_string()));
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn matches(&self, schema: &Value, value: &Value, pointer: &str) -> bool {
+        let mut errors = Vec::new();
+        self.check(schema, value, pointer, &mut errors);
+        errors.is_empty()
+    }
+
+    /// What a schema accepts, for error messages: "an integer or one of Critical, High, Medium, Low"
+    fn describe(&self, schema: &Value) -> String {
+        if let Some(target) = schema.get("$ref").and_then(Value::as_str).and_then(|reference| self.resolve(reference)) {
+            return self.describe(target);
+        }
+        let description = if let Some(branches) = schema.get("oneOf").or_else(|| schema.get("allOf")).and_then(Value::as_array) {
+            branches.iter().map(|branch| self.describe(branch)).collect::<Vec<_>>().join(" or ")
+        } else if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+            let options: Vec<String> = allowed.iter().map(|option| option.as_str().map_or_else(|| option.to_string(), str::to_string)).collect();
+            format!("one of {}", options.join(", "))
+        } else {
+            match (schema.get("type").and_then(Value::as_str), schema.get("format").and_then(Value::as_str)) {
+                (Some("integer"), Some("int32")) => "a 32-bit integer".to_string(),
+                (Some("integer"), _) => "an integer".to_string(),
+                (Some("object"), _) => "an object".to_string(),
+                (Some("array"), _) => "an array".to_string(),
+                (Some(other), _) => format!("a {}", other),
+                (None, _) => "a value".to_string(),
+            }
+        };
+        match schema.get("nullable") == Some(&Value::Bool(true)) {
+            true => format!("{} or null", description),
+            false => description,
+        }
+    }
+}
+
+fn has_type(expected: &str, format: Option<&str>, value: &Value) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "number" => value.is_number(),
+        "integer" if format == Some("int32") => value.as_i64().is_some_and(|value| i32::try_from(value).is_ok()),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+/// The pointer to a member, escaping `~` and `/` as RFC 6901 requires
+fn child(pointer: &str, name: &str) -> String {
+    format!("{}/{}", pointer, name.replace('~', "~0").replace('/', "~1"))
+}
+
+fn schema_error(pointer: &str, code: &str, problem: String) -> FieldError {
+    let location = match pointer {
+        "" => "The request body".to_string(),
+        pointer => pointer.to_string(),
+    };
+    FieldError {
+        field: pointer.to_string(),
+        code: code.to_string(),
+        message: format!("{} {}", location, problem),
+    }
+}
+
+/// The component schema a route's JSON body must match
+#[derive(Debug, Clone, Copy)]
+pub struct RequestSchema(pub &'static str);
+
+/// Rejects JSON bodies that break the route's schema with 422 and one entry per JSON pointer.
+///
+/// Bodies that are not JSON, or not parseable, pass through so the handler's extractor answers
+/// them as before. Under `/v2` the body has already been renamed, so pointers use snake_case.
+pub async fn schema_validation_middleware(
+    State(RequestSchema(schema)): State<RequestSchema>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !is_json(request.headers().get(header::CONTENT_TYPE)) {
+        return next.run(request).await;
+    }
+
+    let (parts, body) = request.into_parts();
+    // Reading through `Bytes` keeps the router's `DefaultBodyLimit` in force
+    let bytes = match Bytes::from_request(Request::from_parts(parts.clone(), body), &()).await {
+        Ok(bytes) => bytes,
+        Err(rejection) => return WebError::InvalidBody(rejection.status(), rejection.body_text()).into_response(),
+    };
+    if let Ok(payload) = serde_json::from_slice::<Value>(&bytes) {
+        let errors = SchemaValidator::api().validate(schema, &payload);
+        if !errors.is_empty() {
+            return WebError::InvalidFields(errors).into_response();
+        }
+    }
+    next.run(Request::from_parts(parts, Body::from(bytes))).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pointers(errors: &[FieldError]) -> Vec<(&str, &str)> {
+        errors.iter().map(|error| (error.field.as_str(), error.code.as_str())).collect()
+    }
+
+    #[test]
+    fn test_api_document_parses_and_resolves_references() {
+        let validator = SchemaValidator::api();
+
+        for schema in ["CreateTaskRequest", "UpdateTaskRequest", "UpdateTaskStatusDto", "AddDependencyRequest"] {
+            assert!(validator.has_schema(schema), "{} is missing", schema);
+        }
+        let broken = r##"{"components": {"schemas": {"A": {"$ref": "#/components/schemas/B"}}}}"##;
+        assert_eq!(SchemaValidator::from_openapi(broken).unwrap_err(), "Unknown schema reference #/components/schemas/B");
+    }
+
+    #[test]
+    fn test_violations_are_located_by_json_pointer() {
+        let validator = SchemaValidator::api();
+        let payload = json!({
+            "status": 3,
+            "checklist": [{"item": "Tests pass", "checked": true}, {"item": 3}],
+            "reviewer": "ops",
+        });
+
+        let errors = validator.validate("UpdateTaskStatusDto", &payload);
+
+        assert_eq!(pointers(&errors), vec![
+            ("/checklist/1/checked", "missing_field"),
+            ("/checklist/1/item", "invalid_type"),
+            ("/reviewer", "unknown_field"),
+            ("/status", "invalid_type"),
+        ]);
+        assert_eq!(errors[3].message, "/status must be a string");
+    }
+
+    #[test]
+    fn test_priority_accepts_numbers_labels_and_null() {
+        let validator = SchemaValidator::api();
+
+        for priority in [json!(3), json!("high"), json!(null)] {
+            assert!(validator.validate("CreateTaskRequest", &json!({"name": "a", "priority": priority})).is_empty());
+        }
+        let errors = validator.validate("CreateTaskRequest", &json!({"name": "a", "priority": 1e12}));
+        assert_eq!(pointers(&errors), vec![("/priority", "no_matching_schema")]);
+        assert_eq!(errors[0].message, "/priority must be a 32-bit integer or one of Critical, High, Medium, Low");
+        let errors = validator.validate("CreateTaskRequest", &json!([]));
+        assert_eq!(errors[0].message, "The request body must be an object");
+    }
+}
@@ -0,0 +1,68 @@
+use axum::Router;
+use std::future::Future;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+/// Serves `app` until `signal` resolves, then stops accepting connections and lets
+/// in-flight requests finish for at most `drain_timeout`.
+///
+/// Returns `Ok(false)` when the timeout expired before every request completed.
+pub async fn serve_with_graceful_shutdown<F>(
+    listener: TcpListener,
+    app: Router,
+    signal: F,
+    drain_timeout: Duration,
+) -> std::io::Result<bool>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let (triggered_tx, triggered_rx) = oneshot::channel();
+    let server = axum::serve(listener, app).with_graceful_shutdown(async move {
+        signal.await;
+        let _ = triggered_tx.send(());
+    });
+
+    tokio::select! {
+        result = server => result.map(|_| true),
+        _ = async {
+            // The sender only drops without sending once the server has stopped
+            if triggered_rx.await.is_ok() {
+                tokio::time::sleep(drain_timeout).await;
+            } else {
+                std::future::pending::<()>().await;
+            }
+        } => Ok(false),
+    }
+}
+
+/// Resolves on Ctrl+C or, on Unix, SIGTERM (sent by Kubernetes and Docker on stop)
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(error) = tokio::signal::ctrl_c().await {
+            tracing::error!("failed to listen for Ctrl+C: {}", error);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(error) => {
+                tracing::error!("failed to listen for SIGTERM: {}", error);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
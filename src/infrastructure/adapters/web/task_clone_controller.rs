@@ -0,0 +1,57 @@
+use axum::{extract::{Path, State}, http::StatusCode, Extension, Json};
+use std::sync::Arc;
+
+use crate::application::{CloneTasksRequest, RequestContext, TaskCloneDto, TaskCloneUseCases};
+use crate::infrastructure::adapters::jobs::spawn_task_clone;
+use crate::infrastructure::adapters::web::{ApiJson, WebError};
+use crate::responses::ApiResponse;
+
+/// Admin endpoints for copying tasks between workspaces in the background and following the copy;
+/// they answer 404 on deployments where cloning is not available
+pub struct TaskCloneController {
+    task_clone_use_cases: Option<Arc<TaskCloneUseCases>>,
+}
+
+impl TaskCloneController {
+    pub fn new(task_clone_use_cases: Arc<TaskCloneUseCases>) -> Self {
+        Self { task_clone_use_cases: Some(task_clone_use_cases) }
+    }
+
+    pub fn disabled() -> Self {
+        Self { task_clone_use_cases: None }
+    }
+
+    /// Answers 202 with the new clone, whose progress `GET /admin/task-clones/{clone_id}` reports
+    pub async fn start_clone(
+        State(controller): State<Arc<TaskCloneController>>,
+        Extension(ctx): Extension<RequestContext>,
+        ApiJson(request): ApiJson<CloneTasksRequest>,
+    ) -> Result<(StatusCode, Json<ApiResponse<TaskCloneDto>>), WebError> {
+        let use_cases = controller.use_cases()?;
+        let clone = use_cases.start_clone(&ctx, request).await?;
+        spawn_task_clone(use_cases.clone(), clone.clone());
+        Ok((StatusCode::ACCEPTED, Json(ApiResponse::success(clone))))
+    }
+
+    pub async fn list_clones(
+        State(controller): State<Arc<TaskCloneController>>,
+        Extension(ctx): Extension<RequestContext>,
+    ) -> Result<Json<ApiResponse<Vec<TaskCloneDto>>>, WebError> {
+        let clones = controller.use_cases()?.list_clones(&ctx)?;
+        Ok(Json(ApiResponse::success(clones)))
+    }
+
+    pub async fn get_clone(
+        State(controller): State<Arc<TaskCloneController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(clone_id): Path<String>,
+    ) -> Result<Json<ApiResponse<TaskCloneDto>>, WebError> {
+        let clone = controller.use_cases()?.get_clone(&ctx, &clone_id)?;
+        Ok(Json(ApiResponse::success(clone)))
+    }
+
+    fn use_cases(&self) -> Result<&Arc<TaskCloneUseCases>, WebError> {
+        self.task_clone_use_cases.as_ref()
+            .ok_or_else(|| WebError::NotFound("Task cloning is not enabled on this server".to_string()))
+    }
+}
@@ -1,53 +1,205 @@
 use axum::{
     extract::{Path, State, Query},
+    Extension,
     http::StatusCode,
+    response::Response,
     Json,
 };
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::application::{TaskUseCases, CreateTaskRequest, UpdateTaskRequest, UpdateTaskStatusDto, TaskDto, TaskWithTransitionsDto, TaskHistoryDto, TaskAnalyticsDto, CompletionAnalyticsDto, UseCaseError};
+use crate::application::{field_messages, FieldError, RequestContext, TaskUseCases, CreateTaskRequest, UpdateTaskRequest, UpdateTaskStatusDto, TaskDto, TaskWithTransitionsDto, TaskHistoryDto, HistoryListDto, TaskAnalyticsDto, CompletionAnalyticsDto, AddDependencyRequest, TaskDependenciesDto, ReprioritizeRequest, ReprioritizeResultDto, BulkDeleteRequest, BulkDeleteResultDto, TaskFacetsDto, TaskStatisticsDto, BoardDto, MoveTaskRequest, TaskPositionDto, ImportTasksRequest, ImportSummaryDto, UseCaseError};
+use crate::domain::{CohortBucket, DomainError, HistoryFilter, Pagination, ReportPeriod, SortOrder, TaskFilter, TaskSort};
 use chrono::{DateTime, Utc};
-use crate::responses::{ApiResponse, TaskListResponse, TaskCreatedResponse};
+use crate::responses::{ApiResponse, TaskListResponse, TaskCreatedResponse, TaskUpdatedResponse};
+use crate::infrastructure::adapters::importers::import_parser;
+use crate::infrastructure::adapters::web::{current_request_id, ApiJson, report_response, ReportFormat, Transactional};
 
 #[derive(Deserialize)]
 pub struct TaskQuery {
     priority: Option<i32>,
+    status: Option<String>,
+    #[serde(default)]
+    include_deleted: bool,
+    sort_by: Option<String>,
+    order: Option<String>,
 }
 
+#[derive(Deserialize)]
+pub struct SearchQuery {
+    #[serde(default)]
+    q: String,
+    /// A filter expression such as `status = Pending and priority <= 3`
+    filter: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct FacetQuery {
+    filter: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct StatsQuery {
+    /// How many days the daily series covers, ending today
+    days: Option<u32>,
+}
+
+#[derive(Deserialize)]
+pub struct BoardQuery {
+    filter: Option<String>,
+    sort_by: Option<String>,
+    order: Option<String>,
+    /// Most tasks shown per column
+    limit: Option<usize>,
+}
+
+/// Bounds are parsed like [`CompletionQuery`]'s
 #[derive(Deserialize)]
 pub struct AnalyticsQuery {
+    #[serde(alias = "start_date")]
+    start: Option<String>,
+    #[serde(alias = "end_date")]
+    end: Option<String>,
+    format: Option<String>,
+}
+
+/// Bounds are RFC 3339 timestamps or `YYYY-MM-DD` dates, parsed by [`ReportPeriod`]
+#[derive(Deserialize)]
+pub struct CompletionQuery {
+    #[serde(alias = "start_date")]
+    start: Option<String>,
+    #[serde(alias = "end_date")]
+    end: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct HistoryQuery {
+    changed_by: Option<String>,
+    to_status: Option<String>,
     start_date: Option<DateTime<Utc>>,
     end_date: Option<DateTime<Utc>>,
+    order: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    cursor: Option<String>,
+}
+
+/// Bounds are parsed like [`CompletionQuery`]'s; `bucket` is day, week or month
+#[derive(Deserialize)]
+pub struct CohortQuery {
+    bucket: Option<String>,
+    #[serde(alias = "start_date")]
+    start: Option<String>,
+    #[serde(alias = "end_date")]
+    end: Option<String>,
+    format: Option<String>,
+}
+
+/// Bounds are parsed like [`CompletionQuery`]'s; `bucket` is day, week or month
+#[derive(Deserialize)]
+pub struct CumulativeFlowQuery {
+    #[serde(alias = "start_date")]
+    start: Option<String>,
+    #[serde(alias = "end_date")]
+    end: Option<String>,
+    bucket: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct GlobalHistoryQuery {
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    user: Option<String>,
+    status: Option<String>,
+    order: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    cursor: Option<String>,
 }
 
 #[derive(Debug)]
 pub enum WebError {
     ValidationError(String),
+    /// A broken business rule, answered with 400 and the rule's code
+    Domain(DomainError),
+    /// Request fields that broke validation rules, answered with 422 and one entry per field
+    InvalidFields(Vec<FieldError>),
+    Unauthorized(String),
+    /// The caller is known but their role may not use the route, answered with 403
+    Forbidden(String),
     NotFound(String),
+    /// A change that collides with existing data, answered with 409 and the conflicting field
+    Conflict(FieldError),
     InternalError(String),
+    Unavailable(String),
+    /// The body could not be read or parsed, with the status axum chose (400, 413, 415 or 422)
+    InvalidBody(StatusCode, String),
 }
 
 impl From<UseCaseError> for WebError {
     fn from(error: UseCaseError) -> Self {
         match error {
             UseCaseError::ValidationError(msg) => WebError::ValidationError(msg),
+            UseCaseError::Domain(error) => WebError::Domain(error),
+            UseCaseError::InvalidFields(errors) => WebError::InvalidFields(errors),
             UseCaseError::NotFound(msg) => WebError::NotFound(msg),
+            UseCaseError::Forbidden(msg) => WebError::Forbidden(msg),
+            UseCaseError::Conflict(error) => WebError::Conflict(error),
             UseCaseError::RepositoryError(msg) => WebError::InternalError(msg),
+            UseCaseError::Cancelled(msg) => WebError::Unavailable(msg),
+        }
+    }
+}
+
+impl WebError {
+    /// Machine-readable code sent alongside the message in the error envelope
+    pub fn code(&self) -> &'static str {
+        match self {
+            WebError::ValidationError(_) => "validation_error",
+            WebError::Domain(error) => error.code(),
+            WebError::InvalidFields(_) => "invalid_fields",
+            WebError::Unauthorized(_) => "unauthorized",
+            WebError::Forbidden(_) => "forbidden",
+            WebError::NotFound(_) => "not_found",
+            WebError::Conflict(_) => "conflict",
+            WebError::InternalError(_) => "internal_error",
+            WebError::Unavailable(_) => "unavailable",
+            WebError::InvalidBody(_, _) => "invalid_body",
         }
     }
 }
 
 impl axum::response::IntoResponse for WebError {
     fn into_response(self) -> axum::response::Response {
+        let code = self.code();
+        let mut field_errors = None;
         let (status, message) = match self {
             WebError::ValidationError(msg) => (StatusCode::BAD_REQUEST, msg),
+            WebError::Domain(error) => (StatusCode::BAD_REQUEST, error.to_string()),
+            WebError::InvalidFields(errors) => {
+                let message = field_messages(&errors);
+                field_errors = Some(errors);
+                (StatusCode::UNPROCESSABLE_ENTITY, message)
+            }
+            WebError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
+            WebError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
             WebError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
+            WebError::Conflict(error) => {
+                let message = error.message.clone();
+                field_errors = Some(vec![error]);
+                (StatusCode::CONFLICT, message)
+            }
             WebError::InternalError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+            WebError::Unavailable(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg),
+            WebError::InvalidBody(status, msg) => (status, msg),
         };
 
-        let error_response = ApiResponse::<()>::error(message);
+        let error_response = ApiResponse::<()>::error(message)
+            .with_code(code)
+            .with_errors(field_errors)
+            .with_request_id(current_request_id());
         (status, Json(error_response)).into_response()
     }
 }
@@ -63,31 +215,108 @@ impl TaskController {
 
     pub async fn get_tasks(
         State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
         Query(params): Query<TaskQuery>,
     ) -> Result<Json<ApiResponse<TaskListResponse>>, WebError> {
-        let tasks = match params.priority {
-            Some(priority) => controller.task_use_cases.get_tasks_by_priority(priority).await?,
-            None => controller.task_use_cases.get_all_tasks().await?,
+        controller.list_tasks(&ctx, params, false).await
+    }
+
+    /// Lists archived tasks, with the same filters and sorting as the default listing
+    pub async fn get_archived_tasks(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Query(params): Query<TaskQuery>,
+    ) -> Result<Json<ApiResponse<TaskListResponse>>, WebError> {
+        controller.list_tasks(&ctx, params, true).await
+    }
+
+    async fn list_tasks(&self, ctx: &RequestContext, params: TaskQuery, archived: bool) -> Result<Json<ApiResponse<TaskListResponse>>, WebError> {
+        let sort = TaskSort::parse(params.sort_by.as_deref(), params.order.as_deref())
+            .map_err(WebError::ValidationError)?;
+        let status = match params.status.as_deref() {
+            Some(name) => Some(self.task_use_cases.known_status(name).await?),
+            None => None,
         };
+        let filter = TaskFilter {
+            priority: params.priority,
+            status,
+            include_deleted: params.include_deleted,
+            archived,
+            expression: None,
+        };
+
+        let tasks = self.task_use_cases.list_tasks(ctx, filter, sort).await?;
 
         let response = ApiResponse::success(TaskListResponse { tasks });
         Ok(Json(response))
     }
 
+    pub async fn search_tasks(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Query(params): Query<SearchQuery>,
+    ) -> Result<Json<ApiResponse<TaskListResponse>>, WebError> {
+        let tasks = controller.task_use_cases.search_tasks(&ctx, &params.q, params.filter.as_deref()).await?;
+        let response = ApiResponse::success(TaskListResponse { tasks });
+        Ok(Json(response))
+    }
+
+    pub async fn get_task_facets(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Query(params): Query<FacetQuery>,
+    ) -> Result<Json<ApiResponse<TaskFacetsDto>>, WebError> {
+        let facets = controller.task_use_cases.task_facets(&ctx, params.filter.as_deref()).await?;
+        Ok(Json(ApiResponse::success(facets)))
+    }
+
+    pub async fn get_task_statistics(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Query(params): Query<StatsQuery>,
+    ) -> Result<Json<ApiResponse<TaskStatisticsDto>>, WebError> {
+        let statistics = controller.task_use_cases.task_statistics(&ctx, params.days).await?;
+        Ok(Json(ApiResponse::success(statistics)))
+    }
+
+    pub async fn get_board(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Query(params): Query<BoardQuery>,
+    ) -> Result<Json<ApiResponse<BoardDto>>, WebError> {
+        // Columns show the order users placed tasks in unless asked otherwise
+        let sort = TaskSort::parse(Some(params.sort_by.as_deref().unwrap_or("rank")), params.order.as_deref())
+            .map_err(WebError::ValidationError)?;
+        let board = controller.task_use_cases.task_board(&ctx, params.filter.as_deref(), sort, params.limit).await?;
+        Ok(Json(ApiResponse::success(board)))
+    }
+
+    /// Previews the Pending tasks the next stale task sweep would cancel
+    pub async fn get_stale_tasks(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+    ) -> Result<Json<ApiResponse<TaskListResponse>>, WebError> {
+        let tasks = controller.task_use_cases.find_stale_tasks(&ctx, controller.task_use_cases.now()).await?;
+        let response = ApiResponse::success(TaskListResponse { tasks });
+        Ok(Json(response))
+    }
+
     pub async fn get_task(
         State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
         Path(task_id): Path<i32>,
     ) -> Result<Json<ApiResponse<TaskDto>>, WebError> {
-        let task = controller.task_use_cases.get_task_by_id(task_id).await?;
+        let task = controller.task_use_cases.get_task_by_id(&ctx, task_id).await?;
         let response = ApiResponse::success(task);
         Ok(Json(response))
     }
 
     pub async fn create_task(
         State(controller): State<Arc<TaskController>>,
-        Json(request): Json<CreateTaskRequest>,
+        Extension(ctx): Extension<RequestContext>,
+        ApiJson(request): ApiJson<CreateTaskRequest>,
     ) -> Result<(StatusCode, Json<ApiResponse<TaskCreatedResponse>>), WebError> {
-        let task_id = controller.task_use_cases.create_task(request).await?;
+        let task_id = controller.task_use_cases.create_task(&ctx, request).await?;
         let response = ApiResponse::success(TaskCreatedResponse {
             task_id,
             message: "Task created successfully".to_string(),
@@ -97,23 +326,25 @@ impl TaskController {
 
     pub async fn update_task(
         State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
         Path(task_id): Path<i32>,
-        Json(request): Json<UpdateTaskRequest>,
-    ) -> Result<Json<ApiResponse<HashMap<String, String>>>, WebError> {
-        controller.task_use_cases.update_task(task_id, request).await?;
-        
-        let mut data = HashMap::new();
-        data.insert("message".to_string(), "Task updated successfully".to_string());
-        
-        let response = ApiResponse::success(data);
+        ApiJson(request): ApiJson<UpdateTaskRequest>,
+    ) -> Result<Json<ApiResponse<TaskUpdatedResponse>>, WebError> {
+        let event = controller.task_use_cases.update_task(&ctx, task_id, request).await?;
+
+        let response = ApiResponse::success(TaskUpdatedResponse {
+            message: "Task updated successfully".to_string(),
+            changed_fields: event.changed_fields(),
+        });
         Ok(Json(response))
     }
 
     pub async fn delete_task(
         State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
         Path(task_id): Path<i32>,
     ) -> Result<(StatusCode, Json<ApiResponse<HashMap<String, String>>>), WebError> {
-        controller.task_use_cases.delete_task(task_id).await?;
+        controller.task_use_cases.delete_task(&ctx, task_id).await?;
         
         let mut data = HashMap::new();
         data.insert("message".to_string(), "Task deleted successfully".to_string());
@@ -122,54 +353,245 @@ impl TaskController {
         Ok((StatusCode::NO_CONTENT, Json(response)))
     }
 
+    pub async fn restore_task(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(task_id): Path<i32>,
+    ) -> Result<Json<ApiResponse<TaskDto>>, WebError> {
+        let task = controller.task_use_cases.restore_task(&ctx, task_id).await?;
+        let response = ApiResponse::success(task);
+        Ok(Json(response))
+    }
+
+    pub async fn archive_task(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(task_id): Path<i32>,
+    ) -> Result<Json<ApiResponse<TaskDto>>, WebError> {
+        let task = controller.task_use_cases.archive_task(&ctx, task_id).await?;
+        let response = ApiResponse::success(task);
+        Ok(Json(response))
+    }
+
+    /// Places the task between neighbours in its board column
+    pub async fn move_task(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(task_id): Path<i32>,
+        ApiJson(request): ApiJson<MoveTaskRequest>,
+    ) -> Result<Json<ApiResponse<TaskPositionDto>>, WebError> {
+        let position = controller.task_use_cases.move_task(&ctx, task_id, request).await?;
+        Ok(Json(ApiResponse::success(position)))
+    }
+
     pub async fn update_task_status(
         State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        _tx: Transactional,
         Path(task_id): Path<i32>,
-        Json(request): Json<UpdateTaskStatusDto>,
+        ApiJson(request): ApiJson<UpdateTaskStatusDto>,
     ) -> Result<Json<ApiResponse<TaskDto>>, WebError> {
-        let task = controller.task_use_cases.update_task_status(task_id, request).await?;
+        let task = controller.task_use_cases.update_task_status(&ctx, task_id, request).await?;
         let response = ApiResponse::success(task);
         Ok(Json(response))
     }
 
     pub async fn get_task_with_transitions(
         State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
         Path(task_id): Path<i32>,
     ) -> Result<Json<ApiResponse<TaskWithTransitionsDto>>, WebError> {
-        let result = controller.task_use_cases.get_task_with_transitions(task_id).await?;
+        let result = controller.task_use_cases.get_task_with_transitions(&ctx, task_id).await?;
         let response = ApiResponse::success(result);
         Ok(Json(response))
     }
 
+    /// Admin tool that moves the priority of every matching task at once, or previews it on a dry run
+    pub async fn reprioritize_tasks(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        _tx: Transactional,
+        ApiJson(request): ApiJson<ReprioritizeRequest>,
+    ) -> Result<Json<ApiResponse<ReprioritizeResultDto>>, WebError> {
+        let result = controller.task_use_cases.reprioritize_tasks(&ctx, request).await?;
+        Ok(Json(ApiResponse::success(result)))
+    }
+
+    /// Admin tool that deletes every matching task, once the caller echoes the token from its preview
+    pub async fn bulk_delete_tasks(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        _tx: Transactional,
+        ApiJson(request): ApiJson<BulkDeleteRequest>,
+    ) -> Result<Json<ApiResponse<BulkDeleteResultDto>>, WebError> {
+        let result = controller.task_use_cases.bulk_delete_tasks(&ctx, request).await?;
+        Ok(Json(ApiResponse::success(result)))
+    }
+
+    /// Admin tool that creates tasks from a Trello or Jira export, or previews the import on a dry run
+    pub async fn import_tasks(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        _tx: Transactional,
+        ApiJson(request): ApiJson<ImportTasksRequest>,
+    ) -> Result<Json<ApiResponse<ImportSummaryDto>>, WebError> {
+        let parser = import_parser(request.source);
+        let summary = controller.task_use_cases.import_tasks(&ctx, parser.as_ref(), request).await?;
+        Ok(Json(ApiResponse::success(summary)))
+    }
+
+    pub async fn add_dependency(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        _tx: Transactional,
+        Path(task_id): Path<i32>,
+        ApiJson(request): ApiJson<AddDependencyRequest>,
+    ) -> Result<(StatusCode, Json<ApiResponse<TaskDependenciesDto>>), WebError> {
+        let dependencies = controller.task_use_cases.add_dependency(&ctx, task_id, request).await?;
+        Ok((StatusCode::CREATED, Json(ApiResponse::success(dependencies))))
+    }
+
+    pub async fn remove_dependency(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        _tx: Transactional,
+        Path((task_id, depends_on)): Path<(i32, i32)>,
+    ) -> Result<Json<ApiResponse<TaskDependenciesDto>>, WebError> {
+        let dependencies = controller.task_use_cases.remove_dependency(&ctx, task_id, depends_on).await?;
+        Ok(Json(ApiResponse::success(dependencies)))
+    }
+
     pub async fn get_task_history(
         State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
         Path(task_id): Path<i32>,
+        Query(params): Query<HistoryQuery>,
     ) -> Result<Json<ApiResponse<TaskHistoryDto>>, WebError> {
-        let history = controller.task_use_cases.get_task_history(task_id).await?;
+        let to_status = match params.to_status.as_deref() {
+            Some(name) => Some(controller.task_use_cases.known_status(name).await?),
+            None => None,
+        };
+        let order = params.order.as_deref()
+            .map(SortOrder::from_str)
+            .transpose()
+            .map_err(WebError::ValidationError)?
+            .unwrap_or_default();
+        let page = Pagination::new(params.limit, params.offset)
+            .map_err(WebError::ValidationError)?;
+        let filter = HistoryFilter {
+            task_id: None,
+            changed_by: params.changed_by,
+            to_status,
+            start_date: params.start_date,
+            end_date: params.end_date,
+        };
+
+        let history = controller.task_use_cases.get_task_history(&ctx, task_id, filter, order, page, params.cursor.as_deref()).await?;
         let response = ApiResponse::success(history);
         Ok(Json(response))
     }
 
     pub async fn get_task_analytics(
         State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
         Path(task_id): Path<i32>,
     ) -> Result<Json<ApiResponse<TaskAnalyticsDto>>, WebError> {
-        let analytics = controller.task_use_cases.get_task_analytics(task_id).await?;
+        let analytics = controller.task_use_cases.get_task_analytics(&ctx, task_id).await?;
         let response = ApiResponse::success(analytics);
         Ok(Json(response))
     }
 
     pub async fn get_completion_analytics(
         State(controller): State<Arc<TaskController>>,
-        Query(params): Query<AnalyticsQuery>,
+        Extension(ctx): Extension<RequestContext>,
+        Query(params): Query<CompletionQuery>,
     ) -> Result<Json<ApiResponse<CompletionAnalyticsDto>>, WebError> {
-        // Default to last 30 days if no dates provided
-        let end_date = params.end_date.unwrap_or_else(|| Utc::now());
-        let start_date = params.start_date.unwrap_or_else(|| end_date - chrono::Duration::days(30));
+        let period = ReportPeriod::parse(params.start.as_deref(), params.end.as_deref(), controller.task_use_cases.now())
+            .map_err(WebError::ValidationError)?;
 
-        let analytics = controller.task_use_cases.get_completion_analytics(start_date, end_date).await?;
+        let analytics = controller.task_use_cases.get_completion_analytics(&ctx, period.start, period.end).await?;
         let response = ApiResponse::success(analytics);
         Ok(Json(response))
     }
+
+    pub async fn get_transition_matrix(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Query(params): Query<AnalyticsQuery>,
+    ) -> Result<Response, WebError> {
+        let format = ReportFormat::from_param(params.format.as_deref())?;
+        let period = ReportPeriod::parse(params.start.as_deref(), params.end.as_deref(), controller.task_use_cases.now())
+            .map_err(WebError::ValidationError)?;
+
+        let matrix = controller.task_use_cases.get_transition_matrix(&ctx, period.start, period.end).await?;
+        Ok(report_response(matrix, format))
+    }
+
+    pub async fn get_cohort_analysis(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Query(params): Query<CohortQuery>,
+    ) -> Result<Response, WebError> {
+        let format = ReportFormat::from_param(params.format.as_deref())?;
+        let bucket = params.bucket.as_deref()
+            .map(CohortBucket::from_str)
+            .transpose()
+            .map_err(WebError::ValidationError)?
+            .unwrap_or_default();
+        // Default to last 90 days so weekly cohorts have time to mature
+        let period = ReportPeriod::parse_with_default(params.start.as_deref(), params.end.as_deref(), controller.task_use_cases.now(), 90)
+            .map_err(WebError::ValidationError)?;
+
+        let cohorts = controller.task_use_cases.get_cohort_analysis(&ctx, bucket, period.start, period.end).await?;
+        Ok(report_response(cohorts, format))
+    }
+
+    pub async fn get_cumulative_flow(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Query(params): Query<CumulativeFlowQuery>,
+    ) -> Result<Response, WebError> {
+        let format = ReportFormat::from_param(params.format.as_deref())?;
+        let period = ReportPeriod::parse(params.start.as_deref(), params.end.as_deref(), controller.task_use_cases.now())
+            .map_err(WebError::ValidationError)?;
+        // Daily buckets suit burndown charts; weekly ones would hide a sprint's shape
+        let bucket = params.bucket.as_deref()
+            .map(CohortBucket::from_str)
+            .transpose()
+            .map_err(WebError::ValidationError)?
+            .unwrap_or(CohortBucket::Day);
+
+        let flow = controller.task_use_cases.get_cumulative_flow(&ctx, bucket, period.start, period.end).await?;
+        Ok(report_response(flow, format))
+    }
+
+    pub async fn get_history(
+        State(controller): State<Arc<TaskController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Query(params): Query<GlobalHistoryQuery>,
+    ) -> Result<Json<ApiResponse<HistoryListDto>>, WebError> {
+        let status = match params.status.as_deref() {
+            Some(name) => Some(controller.task_use_cases.known_status(name).await?),
+            None => None,
+        };
+        let order = params.order.as_deref()
+            .map(SortOrder::from_str)
+            .transpose()
+            .map_err(WebError::ValidationError)?
+            .unwrap_or_default();
+        let page = Pagination::new(params.limit, params.offset)
+            .map_err(WebError::ValidationError)?;
+        let filter = HistoryFilter {
+            task_id: None,
+            changed_by: params.user,
+            to_status: status,
+            start_date: params.from,
+            end_date: params.to,
+        };
+
+        let history = controller.task_use_cases.list_history(&ctx, filter, order, page, params.cursor.as_deref()).await?;
+        let response = ApiResponse::success(history);
+        Ok(Json(response))
+    }
 }
 
@@ -0,0 +1,52 @@
+use axum::{
+    extract::{FromRequestParts, Request, State},
+    http::request::Parts,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sqlx::PgPool;
+
+use crate::database::{redact_connection_string, RequestTransaction};
+use crate::infrastructure::adapters::web::WebError;
+
+/// Gives each request a transaction slot and settles the transaction once the handler has answered.
+///
+/// A transaction is only opened for handlers that take [`Transactional`]. It is committed when the
+/// response is a success or redirect and rolled back otherwise.
+pub async fn transaction_middleware(
+    State(pool): State<PgPool>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let transaction = RequestTransaction::new(pool);
+    request.extensions_mut().insert(transaction.clone());
+    let response = transaction.scope(next.run(request)).await;
+
+    if response.status().is_success() || response.status().is_redirection() {
+        if let Err(error) = transaction.commit().await {
+            return WebError::InternalError(format!("Failed to commit transaction: {}", redact_connection_string(&error.to_string()))).into_response();
+        }
+    } else if let Err(error) = transaction.rollback().await {
+        tracing::error!("Failed to roll back transaction: {}", redact_connection_string(&error.to_string()));
+    }
+    response
+}
+
+/// Runs the handler's repository calls in one transaction.
+///
+/// Without [`transaction_middleware`] in front of the route the extractor does nothing and
+/// every repository call uses its own pooled connection.
+#[derive(Debug, Clone, Copy)]
+pub struct Transactional;
+
+impl<S: Send + Sync> FromRequestParts<S> for Transactional {
+    type Rejection = WebError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        if let Some(transaction) = parts.extensions.get::<RequestTransaction>() {
+            transaction.begin().await
+                .map_err(|error| WebError::InternalError(format!("Failed to begin transaction: {}", redact_connection_string(&error.to_string()))))?;
+        }
+        Ok(Transactional)
+    }
+}
@@ -0,0 +1,126 @@
+use axum::{extract::{Path, State}, http::StatusCode, Extension, Json};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::application::{CreateStatusRequest, CreateWorkspaceRequest, RequestContext, StatusDto, UpdateWorkspaceRequest, WorkflowDto, WorkspaceDto, WorkspaceUseCases};
+use crate::domain::WorkflowDefinition;
+use crate::infrastructure::adapters::web::{ApiJson, WebError};
+use crate::responses::ApiResponse;
+
+/// Endpoints for managing the workspaces that tasks and their history are isolated in
+pub struct WorkspaceController {
+    workspace_use_cases: Arc<WorkspaceUseCases>,
+}
+
+impl WorkspaceController {
+    pub fn new(workspace_use_cases: Arc<WorkspaceUseCases>) -> Self {
+        Self { workspace_use_cases }
+    }
+
+    pub async fn list_workspaces(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+    ) -> Result<Json<ApiResponse<Vec<WorkspaceDto>>>, WebError> {
+        let workspaces = controller.workspace_use_cases.list_workspaces(&ctx).await?;
+        Ok(Json(ApiResponse::success(workspaces)))
+    }
+
+    pub async fn get_workspace(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(workspace_id): Path<String>,
+    ) -> Result<Json<ApiResponse<WorkspaceDto>>, WebError> {
+        let workspace = controller.workspace_use_cases.get_workspace(&ctx, &workspace_id).await?;
+        Ok(Json(ApiResponse::success(workspace)))
+    }
+
+    pub async fn create_workspace(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+        ApiJson(request): ApiJson<CreateWorkspaceRequest>,
+    ) -> Result<(StatusCode, Json<ApiResponse<WorkspaceDto>>), WebError> {
+        let workspace = controller.workspace_use_cases.create_workspace(&ctx, request).await?;
+        Ok((StatusCode::CREATED, Json(ApiResponse::success(workspace))))
+    }
+
+    pub async fn rename_workspace(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(workspace_id): Path<String>,
+        ApiJson(request): ApiJson<UpdateWorkspaceRequest>,
+    ) -> Result<Json<ApiResponse<WorkspaceDto>>, WebError> {
+        let workspace = controller.workspace_use_cases.rename_workspace(&ctx, &workspace_id, request).await?;
+        Ok(Json(ApiResponse::success(workspace)))
+    }
+
+    pub async fn delete_workspace(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(workspace_id): Path<String>,
+    ) -> Result<Json<ApiResponse<HashMap<String, String>>>, WebError> {
+        controller.workspace_use_cases.delete_workspace(&ctx, &workspace_id).await?;
+
+        let mut data = HashMap::new();
+        data.insert("message".to_string(), "Workspace deleted successfully".to_string());
+        Ok(Json(ApiResponse::success(data)))
+    }
+
+    pub async fn get_workflow(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(workspace_id): Path<String>,
+    ) -> Result<Json<ApiResponse<WorkflowDto>>, WebError> {
+        let workflow = controller.workspace_use_cases.get_workflow(&ctx, &workspace_id).await?;
+        Ok(Json(ApiResponse::success(workflow)))
+    }
+
+    /// The body is a workflow definition; one that breaks its own rules is answered with 422
+    pub async fn set_workflow(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(workspace_id): Path<String>,
+        ApiJson(workflow): ApiJson<WorkflowDefinition>,
+    ) -> Result<Json<ApiResponse<WorkflowDto>>, WebError> {
+        let workflow = controller.workspace_use_cases.set_workflow(&ctx, &workspace_id, workflow).await?;
+        Ok(Json(ApiResponse::success(workflow)))
+    }
+
+    /// Answers with the configured workflow the workspace follows from now on
+    pub async fn reset_workflow(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(workspace_id): Path<String>,
+    ) -> Result<Json<ApiResponse<WorkflowDto>>, WebError> {
+        let workflow = controller.workspace_use_cases.reset_workflow(&ctx, &workspace_id).await?;
+        Ok(Json(ApiResponse::success(workflow)))
+    }
+
+    pub async fn list_statuses(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+    ) -> Result<Json<ApiResponse<Vec<StatusDto>>>, WebError> {
+        let statuses = controller.workspace_use_cases.list_statuses(&ctx).await?;
+        Ok(Json(ApiResponse::success(statuses)))
+    }
+
+    pub async fn create_status(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+        ApiJson(request): ApiJson<CreateStatusRequest>,
+    ) -> Result<(StatusCode, Json<ApiResponse<StatusDto>>), WebError> {
+        let status = controller.workspace_use_cases.create_status(&ctx, request).await?;
+        Ok((StatusCode::CREATED, Json(ApiResponse::success(status))))
+    }
+
+    pub async fn delete_status(
+        State(controller): State<Arc<WorkspaceController>>,
+        Extension(ctx): Extension<RequestContext>,
+        Path(name): Path<String>,
+    ) -> Result<Json<ApiResponse<HashMap<String, String>>>, WebError> {
+        controller.workspace_use_cases.delete_status(&ctx, &name).await?;
+
+        let mut data = HashMap::new();
+        data.insert("message".to_string(), "Status deleted successfully".to_string());
+        Ok(Json(ApiResponse::success(data)))
+    }
+}
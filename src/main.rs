@@ -1,105 +1,386 @@
-use axum::{
-    routing::{get, patch},
-    Json, Router,
-};
-use serde_json::json;
+use axum::extract::DefaultBodyLimit;
 use tokio::net::TcpListener;
-use tower::ServiceBuilder;
-use tower_http::trace::TraceLayer;
-
-// Import our modules
-mod domain;
-mod application;
-mod infrastructure;
-mod config;
-mod database;
-mod responses;
-
-use config::Config;
-use database::Database;
+use tower_http::compression::CompressionLayer;
+use tokio_util::sync::CancellationToken;
+
+use axum_postgres_rust::{Config, Database};
+use axum_postgres_rust::config::init_tracing;
+use axum_postgres_rust::database::{redact_connection_string, DatabaseProbe, ShardRegistry};
+use sqlx::PgPool;
 use std::sync::Arc;
-use domain::{TaskRepository, StatusHistoryRepository};
-use application::TaskUseCases;
-use infrastructure::adapters::{PostgresTaskRepository, PostgresStatusHistoryRepository, TaskController};
-use tracing_subscriber::fmt::init;
+use axum_postgres_rust::domain::{TaskRepository, StatusHistoryRepository, TaskDependencyRepository, ApiKeyRepository, BackgroundJobRepository, AutomationRuleRepository, WorkspaceRepository, WorkflowRepository, CustomStatusRepository, TaskStatisticsRepository, AttachmentRepository, ArchiveMonth, ConfirmationKey, CursorKey, ObjectStorage};
+use axum_postgres_rust::application::{TaskUseCases, ApiKeyUseCases, AttachmentUseCases, AuditArchiveUseCases, TaskCloneUseCases, BackgroundJobUseCases, AutomationUseCases, WorkspaceUseCases, TaskChangeFeed, ContentPolicy, DuplicateNamePolicy, OpenTaskQuotaPolicy, LatencyBudgets};
+use axum_postgres_rust::infrastructure::adapters::{PostgresTaskRepository, PostgresStatusHistoryRepository, CachedTaskRepository, CachedStatusHistoryRepository, RedisCachedTaskRepository, QueryCache, PostgresTaskDependencyRepository, PostgresApiKeyRepository, PostgresBackgroundJobRepository, PostgresAutomationRuleRepository, PostgresWorkspaceRepository, PostgresWorkflowRepository, PostgresCustomStatusRepository, PostgresTaskStatisticsRepository, PostgresAttachmentRepository, InMemoryEventPublisher, WebhookNotifier, HttpTransitionGate, PostgresOutboxEventPublisher, TaskController, ApiKeyController, AttachmentController, BackgroundJobController, TaskCloneController, AutomationController, WorkspaceController, RealtimeController, HealthController, RequestContextSettings, RequestContextState, create_router, transaction_middleware, serve_with_graceful_shutdown, shutdown_signal, JobScheduler, spawn_stale_task_job, spawn_auto_archive_job, spawn_escalation_job, spawn_metrics_push_job, spawn_audit_retention_job, spawn_automation_worker, PushgatewayExporter, AwsCredentials, attachment_storage, object_storage};
+#[cfg(feature = "sqlite")]
+use axum_postgres_rust::infrastructure::adapters::{SqliteTaskRepository, SqliteStatusHistoryRepository, SqliteTaskDependencyRepository, SqliteApiKeyRepository, SqliteBackgroundJobRepository, SqliteAutomationRuleRepository, SqliteWorkspaceRepository, SqliteWorkflowRepository, SqliteCustomStatusRepository, SqliteTaskStatisticsRepository, SqliteAttachmentRepository};
+
+// Heap statistics on /health/diagnostics come from jemalloc when it is the global allocator
+#[cfg(feature = "jemalloc")]
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing
-    init();
-
     // Load configuration
     let config = Config::from_env()?;
 
-    // Create database connection pool
-    let db_pool = Database::connect(&config).await?;
+    // Initialize tracing in the configured format
+    init_tracing(config.log_format);
+    // Credentials are redacted from the Debug output
+    tracing::debug!("Loaded configuration: {:?}", config);
+
+    // `upgrade-schema` migrates the database ahead of a deploy and `restore-audit YYYY-MM` brings
+    // an archived month of status history back; both exit without serving
+    match std::env::args().nth(1).as_deref() {
+        None => {}
+        Some("upgrade-schema") => return upgrade_schema(config).await,
+        Some("restore-audit") => {
+            let month = std::env::args().nth(2).ok_or("restore-audit needs the month to restore, as YYYY-MM")?;
+            return restore_audit(config, ArchiveMonth::parse(&month)?).await;
+        }
+        Some(other) => return Err(format!("Unknown command '{}'; the commands are upgrade-schema and restore-audit", other).into()),
+    }
+
+    // Create database connection pool, on Postgres or, for local development, SQLite
+    let db_pool = DatabasePool::connect(&config).await?;
+    // Tenants listed in TENANT_SHARDS are served from their own cluster; everyone else uses db_pool
+    let shards = Arc::new(ShardRegistry::connect(&config.shard_map, &config).await
+        .map_err(|e| redact_connection_string(&e.to_string()))?);
 
     // Create repositories
-    let task_repository: Arc<dyn TaskRepository> = Arc::new(PostgresTaskRepository::new(db_pool.clone()));
-    let status_history_repository: Arc<dyn StatusHistoryRepository> = Arc::new(PostgresStatusHistoryRepository::new(db_pool));
+    let Repositories { mut task_repository, mut status_history_repository, task_dependency_repository, api_key_repository, background_job_repository, automation_rule_repository, workspace_repository, workflow_repository, custom_status_repository, statistics_repository, attachment_repository } = db_pool.repositories();
+    if let Some(url) = &config.cache_url {
+        task_repository = Arc::new(RedisCachedTaskRepository::connect(task_repository, url, config.cache_ttl)
+            .map_err(|e| redact_connection_string(&e.to_string()))?);
+    }
+    let query_cache = config.query_cache_ttl.map(|ttl| Arc::new(QueryCache::new(ttl)));
+    if let Some(cache) = &query_cache {
+        task_repository = Arc::new(CachedTaskRepository::new(task_repository, cache.clone()));
+        status_history_repository = Arc::new(CachedStatusHistoryRepository::new(status_history_repository, cache.clone()));
+    }
+    let audit_archive_use_cases = match config.audit_retention_policy {
+        Some(policy) => Some((Arc::new(AuditArchiveUseCases::new(status_history_repository.clone(), audit_archive_storage(&config)?)), policy)),
+        None => None,
+    };
+    // Files attached to tasks are uploaded to S3 and downloaded from it with presigned links
+    let attachment_store = config.attachments_url.as_deref()
+        .map(|url| attachment_storage(url, &config.aws_region, config.s3_endpoint.as_deref(), aws_credentials(&config)))
+        .transpose()?;
     
     // Create use cases
-    let task_use_cases = Arc::new(TaskUseCases::new(task_repository, status_history_repository));
+    let change_feed = TaskChangeFeed::default();
+    // Subscribers such as webhooks and audit logs attach to the in-process event bus
+    let event_bus = InMemoryEventPublisher::default();
+    let automation_events = event_bus.subscribe();
+    let mut task_use_cases = TaskUseCases::new(task_repository, status_history_repository, task_dependency_repository)
+        .with_change_feed(change_feed.clone())
+        .with_event_publisher(Arc::new(event_bus))
+        .with_priority_policy(config.priority_policy)
+        .with_priority_labels(config.priority_labels)
+        .with_review_checklist(config.review_checklist)
+        .with_review_chains(config.review_chains)
+        .with_workflows(config.workflows.clone())
+        .with_workflow_repository(workflow_repository.clone())
+        .with_custom_status_repository(custom_status_repository.clone())
+        .with_wip_limits(config.wip_limits)
+        .with_statistics_repository(statistics_repository)
+        .with_reopen_policy(config.reopen_policy)
+        .with_latency_budgets(LatencyBudgets::new().with_overrides(config.latency_budget_overrides.clone()));
+    // Configuration refuses the outbox on SQLite
+    if let (true, Some(pool)) = (config.event_outbox, db_pool.postgres()) {
+        task_use_cases = task_use_cases.with_event_publisher(Arc::new(PostgresOutboxEventPublisher::new(pool.clone())));
+    }
+    if let Some(max_open_tasks) = config.max_open_tasks {
+        task_use_cases = task_use_cases.with_creation_policy(Arc::new(OpenTaskQuotaPolicy::new(max_open_tasks)));
+    }
+    if config.reject_duplicate_task_names {
+        task_use_cases = task_use_cases.with_creation_policy(Arc::new(DuplicateNamePolicy));
+    }
+    if !config.blocked_task_terms.is_empty() {
+        task_use_cases = task_use_cases.with_creation_policy(Arc::new(ContentPolicy::new(config.blocked_task_terms.clone())));
+    }
+    if let Some(secret) = &config.confirmation_token_secret {
+        task_use_cases = task_use_cases.with_confirmation_key(ConfirmationKey::new(secret.as_bytes()));
+    }
+    if let Some(secret) = &config.cursor_secret {
+        task_use_cases = task_use_cases.with_cursor_key(CursorKey::new(secret.as_bytes()));
+    }
+    if let Some(stale_task_policy) = config.stale_task_policy {
+        task_use_cases = task_use_cases.with_stale_task_policy(stale_task_policy);
+    }
+    if let Some(url) = &config.transition_gate_url {
+        let gate = HttpTransitionGate::new(url, config.transition_gate_timeout);
+        task_use_cases = task_use_cases.with_transition_gate(Arc::new(gate), config.transition_gate_transitions.clone(), config.transition_gate_fallback);
+    }
+    if let Some(auto_archive_policy) = config.auto_archive_policy {
+        task_use_cases = task_use_cases.with_auto_archive_policy(auto_archive_policy);
+    }
+    if let Some(escalation_policy) = &config.escalation_policy {
+        task_use_cases = task_use_cases.with_escalation_policy(escalation_policy.clone());
+    }
+    let task_use_cases = Arc::new(task_use_cases);
+    let api_key_use_cases = Arc::new(ApiKeyUseCases::new(api_key_repository));
+    let background_job_use_cases = Arc::new(BackgroundJobUseCases::new(background_job_repository.clone()));
+    let automation_use_cases = Arc::new(AutomationUseCases::new(automation_rule_repository, task_use_cases.clone(), Arc::new(WebhookNotifier::new()))
+        .with_notify_url_policy(config.notify_url_policy.clone()));
+    let workspace_use_cases = Arc::new(WorkspaceUseCases::new(workspace_repository)
+        .with_workflows(config.workflows.clone(), workflow_repository)
+        .with_custom_statuses(custom_status_repository));
+    let attachment_use_cases = attachment_store.map(|storage| Arc::new(AttachmentUseCases::new(attachment_repository, storage, task_use_cases.clone())
+        .with_max_size_bytes(config.max_attachment_bytes)
+        .with_download_url_ttl(config.attachment_url_ttl)));
+    // Clones are not routed to tenant shards, so sharded deployments go without, as with automation rules
+    let task_clone_use_cases = match config.shard_map.is_empty() {
+        true => Some(Arc::new(TaskCloneUseCases::new(task_use_cases.clone(), workspace_use_cases.clone()))),
+        false => {
+            tracing::warn!("Tasks cannot be cloned between workspaces while TENANT_SHARDS is set");
+            None
+        }
+    };
+
+    // Jobs keep their schedule in the database, where /admin/jobs can pause, resume or trigger them
+    let scheduler = JobScheduler::new(background_job_repository).with_poll_interval(config.job_poll_interval);
+
+    // Cancel tasks left in Pending past the configured age, one workspace at a time
+    let stale_task_job = config.stale_task_policy
+        .map(|_| spawn_stale_task_job(&scheduler, task_use_cases.clone(), workspace_use_cases.clone(), config.stale_task_sweep_interval));
+
+    // Archive tasks completed longer ago than the configured age, one workspace at a time
+    let auto_archive_job = config.auto_archive_policy
+        .map(|_| spawn_auto_archive_job(&scheduler, task_use_cases.clone(), workspace_use_cases.clone(), config.auto_archive_interval));
+
+    // Escalate tasks stuck in a status past their rule's threshold, one workspace at a time
+    let escalation_job = config.escalation_policy.as_ref()
+        .map(|_| spawn_escalation_job(&scheduler, task_use_cases.clone(), workspace_use_cases.clone(), config.escalation_interval));
+
+    // Push analytics gauges for teams that cannot scrape the service
+    let metrics_push_job = config.pushgateway_url.as_deref()
+        .map(|url| spawn_metrics_push_job(&scheduler, task_use_cases.clone(), PushgatewayExporter::new(url), query_cache.clone(), config.metrics_push_interval));
     
+    // Move status history past the retention period to the archive
+    let audit_retention_job = audit_archive_use_cases
+        .map(|(use_cases, policy)| spawn_audit_retention_job(&scheduler, use_cases, policy, config.audit_retention_interval));
+
+    // Run automation rules against task events in their workspace; the worker is not routed to tenant
+    // shards, so sharded deployments go without
+    let automation_worker = match config.shard_map.is_empty() {
+        true => Some(spawn_automation_worker(automation_use_cases.clone(), automation_events)),
+        false => {
+            tracing::warn!("Automation rules are not evaluated while TENANT_SHARDS is set");
+            None
+        }
+    };
+
     // Create controllers
+    let health_controller = Arc::new(HealthController::new(db_pool.probe(), config.readiness_timeout)
+        .with_change_feed(change_feed.clone()));
+    let mut realtime_controller = RealtimeController::new(task_use_cases.clone(), api_key_use_cases.clone(), change_feed);
+    if let Some(window) = config.realtime_batch_window {
+        realtime_controller = realtime_controller.with_batch_window(window);
+    }
+    let realtime_controller = Arc::new(realtime_controller);
     let task_controller = Arc::new(TaskController::new(task_use_cases));
+    let api_key_controller = Arc::new(ApiKeyController::new(api_key_use_cases.clone()));
+    let background_job_controller = Arc::new(BackgroundJobController::new(background_job_use_cases));
+    let automation_controller = Arc::new(AutomationController::new(automation_use_cases));
+    let workspace_controller = Arc::new(WorkspaceController::new(workspace_use_cases.clone()));
+    let attachment_controller = Arc::new(attachment_use_cases.map_or_else(AttachmentController::disabled, AttachmentController::new));
+    let task_clone_controller = Arc::new(task_clone_use_cases.map_or_else(TaskCloneController::disabled, TaskCloneController::new));
 
     // Create TCP listener
     let listener = TcpListener::bind(&config.server_address).await?;
     println!("Server running on {}", listener.local_addr().unwrap());
 
-    // Build router with middleware
-    let app = Router::new()
-        .route("/", get(root_handler))
-        .route("/health", get(health_check))
-        .route("/tasks", 
-            get(TaskController::get_tasks)
-            .post(TaskController::create_task)
-        )
-        .route("/tasks/{task_id}", 
-            get(TaskController::get_task)
-            .patch(TaskController::update_task)
-            .delete(TaskController::delete_task)
-        )
-        .route("/tasks/{task_id}/status", 
-            patch(TaskController::update_task_status)
-        )
-        .route("/tasks/{task_id}/transitions", 
-            get(TaskController::get_task_with_transitions)
-        )
-        .route("/tasks/{task_id}/history", 
-            get(TaskController::get_task_history)
-        )
-        .route("/tasks/{task_id}/analytics", 
-            get(TaskController::get_task_analytics)
-        )
-        .layer(
-            ServiceBuilder::new()
-                .layer(TraceLayer::new_for_http())
-        )
-        .with_state(task_controller);
-
-    // Start server
-    axum::serve(listener, app).await?;
+    // Build router with middleware; requests' cancellation tokens fire when shutdown begins
+    let shutdown = CancellationToken::new();
+    let context = RequestContextState {
+        settings: RequestContextSettings {
+            trust_identity_headers: config.trust_identity_headers,
+        },
+        api_keys: api_key_use_cases,
+        shutdown: shutdown.clone(),
+        shards: shards.clone(),
+        workspaces: workspace_use_cases,
+    };
+    let mut app = create_router(task_controller, api_key_controller, background_job_controller, automation_controller, workspace_controller, attachment_controller, task_clone_controller, realtime_controller, health_controller, context);
+    // Request-level transactions are a Postgres feature; on SQLite each repository write commits on its own
+    if let Some(pool) = db_pool.postgres() {
+        app = app.layer(axum::middleware::from_fn_with_state(pool.clone(), transaction_middleware));
+    }
+    let app = app
+        .layer(DefaultBodyLimit::max(config.max_request_body_bytes))
+        .layer(CompressionLayer::new());
+
+    // Start server; on SIGTERM/SIGINT stop accepting connections and drain in-flight requests
+    let signal = {
+        let shutdown = shutdown.clone();
+        async move {
+            shutdown_signal().await;
+            shutdown.cancel();
+        }
+    };
+    let drained = serve_with_graceful_shutdown(listener, app, signal, config.shutdown_timeout).await?;
+    if !drained {
+        tracing::warn!("Shutdown timeout of {:?} elapsed with requests still in flight", config.shutdown_timeout);
+    }
+
+    for job in [stale_task_job, auto_archive_job, escalation_job, metrics_push_job, audit_retention_job, automation_worker].into_iter().flatten() {
+        job.abort();
+    }
+    shards.close().await;
+    db_pool.close().await;
+    println!("Server stopped");
     Ok(())
 }
 
-/// Root endpoint handler
-async fn root_handler() -> Json<serde_json::Value> {
-    Json(json!({
-        "message": "Welcome to the Axum Postgres Rust API",
-        "version": "1.0.0",
-        "endpoints": {
-            "tasks": "/tasks",
-            "health": "/health"
-        }
-    }))
+async fn upgrade_schema(config: Config) -> Result<(), Box<dyn std::error::Error>> {
+    if config.uses_sqlite() {
+        return Err("upgrade-schema upgrades Postgres databases; SQLite databases are migrated on startup".into());
+    }
+    let config = Config { run_migrations: false, ..config };
+    let db_pool = Database::connect(&config).await
+        .map_err(|e| redact_connection_string(&e.to_string()))?;
+    let shards = ShardRegistry::connect(&config.shard_map, &config).await
+        .map_err(|e| redact_connection_string(&e.to_string()))?;
+
+    for (name, pool) in std::iter::once(("default", &db_pool)).chain(shards.pools()) {
+        let upgrade = Database::upgrade_schema(pool).await
+            .map_err(|e| redact_connection_string(&e.to_string()))?;
+        println!(
+            "Schema of {} database upgraded: {} migrations applied, {} creation history entries seeded",
+            name, upgrade.migrations_applied, upgrade.creation_entries_seeded
+        );
+    }
+    shards.close().await;
+    db_pool.close().await;
+    Ok(())
 }
 
-/// Health check endpoint
-async fn health_check() -> Json<serde_json::Value> {
-    Json(json!({
-        "status": "healthy",
-        "timestamp": chrono::Utc::now().to_rfc3339()
-    }))
+async fn restore_audit(config: Config, month: ArchiveMonth) -> Result<(), Box<dyn std::error::Error>> {
+    let storage = audit_archive_storage(&config)?;
+    let db_pool = DatabasePool::connect(&config).await?;
+    let use_cases = AuditArchiveUseCases::new(db_pool.repositories().status_history_repository, storage);
+
+    let restored = use_cases.restore_month(month).await;
+    db_pool.close().await;
+    let restored = restored.map_err(|e| e.to_string())?;
+    println!(
+        "Status history of {} restored: {} entries restored, {} already present, {} of tasks that no longer exist",
+        restored.month, restored.restored, restored.already_present, restored.missing_task
+    );
+    if config.audit_retention_policy.is_some() {
+        println!("Restored entries are archived again on the next retention run");
+    }
+    Ok(())
+}
+
+/// The object storage AUDIT_ARCHIVE_URL points at
+fn audit_archive_storage(config: &Config) -> Result<Arc<dyn ObjectStorage>, Box<dyn std::error::Error>> {
+    let url = config.audit_archive_url.as_deref().ok_or("AUDIT_ARCHIVE_URL is not set")?;
+    Ok(object_storage(url, &config.aws_region, config.s3_endpoint.as_deref(), aws_credentials(config))?)
+}
+
+/// The S3 credentials from AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY, when both are set
+fn aws_credentials(config: &Config) -> Option<AwsCredentials> {
+    match (&config.aws_access_key_id, &config.aws_secret_access_key) {
+        (Some(access_key_id), Some(secret_access_key)) => Some(AwsCredentials {
+            access_key_id: access_key_id.clone(),
+            secret_access_key: secret_access_key.clone(),
+            session_token: config.aws_session_token.clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// The connection pool of the database DATABASE_URL points at
+enum DatabasePool {
+    Postgres(PgPool),
+    #[cfg(feature = "sqlite")]
+    Sqlite(sqlx::SqlitePool),
+}
+
+/// The repositories backed by a [`DatabasePool`]
+struct Repositories {
+    task_repository: Arc<dyn TaskRepository>,
+    status_history_repository: Arc<dyn StatusHistoryRepository>,
+    task_dependency_repository: Arc<dyn TaskDependencyRepository>,
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+    background_job_repository: Arc<dyn BackgroundJobRepository>,
+    automation_rule_repository: Arc<dyn AutomationRuleRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    custom_status_repository: Arc<dyn CustomStatusRepository>,
+    statistics_repository: Arc<dyn TaskStatisticsRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+}
+
+impl DatabasePool {
+    async fn connect(config: &Config) -> Result<Self, Box<dyn std::error::Error>> {
+        #[cfg(feature = "sqlite")]
+        if config.uses_sqlite() {
+            let pool = Database::connect_sqlite(config).await
+                .map_err(|e| redact_connection_string(&e.to_string()))?;
+            return Ok(Self::Sqlite(pool));
+        }
+        let pool = Database::connect(config).await
+            .map_err(|e| redact_connection_string(&e.to_string()))?;
+        Ok(Self::Postgres(pool))
+    }
+
+    fn repositories(&self) -> Repositories {
+        match self {
+            Self::Postgres(pool) => Repositories {
+                task_repository: Arc::new(PostgresTaskRepository::new(pool.clone())),
+                status_history_repository: Arc::new(PostgresStatusHistoryRepository::new(pool.clone())),
+                task_dependency_repository: Arc::new(PostgresTaskDependencyRepository::new(pool.clone())),
+                api_key_repository: Arc::new(PostgresApiKeyRepository::new(pool.clone())),
+                background_job_repository: Arc::new(PostgresBackgroundJobRepository::new(pool.clone())),
+                automation_rule_repository: Arc::new(PostgresAutomationRuleRepository::new(pool.clone())),
+                workspace_repository: Arc::new(PostgresWorkspaceRepository::new(pool.clone())),
+                workflow_repository: Arc::new(PostgresWorkflowRepository::new(pool.clone())),
+                custom_status_repository: Arc::new(PostgresCustomStatusRepository::new(pool.clone())),
+                statistics_repository: Arc::new(PostgresTaskStatisticsRepository::new(pool.clone())),
+                attachment_repository: Arc::new(PostgresAttachmentRepository::new(pool.clone())),
+            },
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(pool) => Repositories {
+                task_repository: Arc::new(SqliteTaskRepository::new(pool.clone())),
+                status_history_repository: Arc::new(SqliteStatusHistoryRepository::new(pool.clone())),
+                task_dependency_repository: Arc::new(SqliteTaskDependencyRepository::new(pool.clone())),
+                api_key_repository: Arc::new(SqliteApiKeyRepository::new(pool.clone())),
+                background_job_repository: Arc::new(SqliteBackgroundJobRepository::new(pool.clone())),
+                automation_rule_repository: Arc::new(SqliteAutomationRuleRepository::new(pool.clone())),
+                workspace_repository: Arc::new(SqliteWorkspaceRepository::new(pool.clone())),
+                workflow_repository: Arc::new(SqliteWorkflowRepository::new(pool.clone())),
+                custom_status_repository: Arc::new(SqliteCustomStatusRepository::new(pool.clone())),
+                statistics_repository: Arc::new(SqliteTaskStatisticsRepository::new(pool.clone())),
+                attachment_repository: Arc::new(SqliteAttachmentRepository::new(pool.clone())),
+            },
+        }
+    }
+
+    fn probe(&self) -> Arc<dyn DatabaseProbe> {
+        match self {
+            Self::Postgres(pool) => Arc::new(pool.clone()),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(pool) => Arc::new(pool.clone()),
+        }
+    }
+
+    /// The Postgres pool, which request transactions and the event outbox need
+    fn postgres(&self) -> Option<&PgPool> {
+        match self {
+            Self::Postgres(pool) => Some(pool),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(_) => None,
+        }
+    }
+
+    async fn close(&self) {
+        match self {
+            Self::Postgres(pool) => pool.close().await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(pool) => pool.close().await,
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use serde::{Serialize, Deserialize};
-use crate::application::dto::TaskDto;
+use crate::application::dto::{FieldError, TaskDto};
+use crate::domain::TaskField;
 
 /// Standard API response wrapper
 #[derive(Debug, Serialize)]
@@ -7,6 +8,15 @@ pub struct ApiResponse<T> {
     pub success: bool,
     pub data: Option<T>,
     pub message: Option<String>,
+    /// Machine-readable error code such as `invalid_transition`; only set on errors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The offending fields when a request body fails validation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<FieldError>>,
+    /// Id of the failed request, quoted to support to find its logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
 }
 
 impl<T> ApiResponse<T> {
@@ -15,6 +25,9 @@ impl<T> ApiResponse<T> {
             success: true,
             data: Some(data),
             message: None,
+            code: None,
+            errors: None,
+            request_id: None,
         }
     }
 
@@ -23,8 +36,26 @@ impl<T> ApiResponse<T> {
             success: false,
             data: None,
             message: Some(message),
+            code: None,
+            errors: None,
+            request_id: None,
         }
     }
+
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    pub fn with_errors(mut self, errors: Option<Vec<FieldError>>) -> Self {
+        self.errors = errors;
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: Option<String>) -> Self {
+        self.request_id = request_id;
+        self
+    }
 }
 
 /// Response structure for task lists
@@ -40,3 +71,9 @@ pub struct TaskCreatedResponse {
     pub message: String,
 }
 
+/// Response structure for task updates, listing the fields that changed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskUpdatedResponse {
+    pub message: String,
+    pub changed_fields: Vec<TaskField>,
+}
@@ -0,0 +1,37 @@
+use axum_postgres_rust::domain::{ApiKey, IdGenerator, SequentialIdGenerator, UserRole, UuidV7Generator, API_KEY_PREFIX};
+use chrono::{TimeZone, Utc};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_stores_only_the_hash() {
+        let issued_at = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let (key, secret) = ApiKey::issue(SequentialIdGenerator::new().next_id(), "  ci-bot ".to_string(), UserRole::User, "default".to_string(), "admin".to_string(), issued_at).unwrap();
+
+        assert!(secret.starts_with(API_KEY_PREFIX));
+        assert_eq!(key.name, "ci-bot");
+        assert!(secret.starts_with(&key.key_prefix));
+        assert_ne!(key.key_hash, secret);
+        assert_eq!(key.key_hash, ApiKey::hash_secret(&secret));
+        assert_eq!(key.key_hash.len(), 64);
+        assert_eq!(key.principal(), "api-key:ci-bot");
+        assert_eq!(key.id, "00000000-0000-0000-0000-000000000001");
+        assert_eq!(key.created_at, issued_at);
+    }
+
+    #[test]
+    fn test_issued_secrets_are_unique() {
+        let (_, first) = ApiKey::issue(UuidV7Generator.next_id(), "a".to_string(), UserRole::User, "default".to_string(), "admin".to_string(), Utc::now()).unwrap();
+        let (_, second) = ApiKey::issue(UuidV7Generator.next_id(), "a".to_string(), UserRole::User, "default".to_string(), "admin".to_string(), Utc::now()).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_issue_validates_name_and_role() {
+        assert!(ApiKey::issue(UuidV7Generator.next_id(), " ".to_string(), UserRole::User, "default".to_string(), "admin".to_string(), Utc::now()).is_err());
+        assert!(ApiKey::issue(UuidV7Generator.next_id(), "x".repeat(41), UserRole::User, "default".to_string(), "admin".to_string(), Utc::now()).is_err());
+        assert!(ApiKey::issue(UuidV7Generator.next_id(), "job".to_string(), UserRole::System, "default".to_string(), "admin".to_string(), Utc::now()).is_err());
+    }
+}
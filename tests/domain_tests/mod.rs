@@ -2,4 +2,5 @@
 pub mod task_entity_tests;
 pub mod task_id_tests;
 pub mod task_status_tests;
-pub mod task_domain_service_tests;
\ No newline at end of file
+pub mod task_domain_service_tests;
+pub mod api_key_tests;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use axum_postgres_rust::domain::{Task, TaskId, TaskDomainService};
+use axum_postgres_rust::domain::{HighPriorityPolicy, PriorityPolicy, PriorityScale, Task, TaskId, TaskDomainService, UserRole};
 
 fn create_test_task() -> Task {
     Task::new(TaskId::new(1), "Test Task".to_string(), Some(5)).unwrap()
@@ -11,8 +11,8 @@ mod tests {
     #[test]
     fn test_new_domain_service() {
         let service = TaskDomainService::new();
-        // Just verify it can be created
-        assert_eq!(std::mem::size_of_val(&service), 0); // Zero-sized type
+        // Defaults to the full priority scale
+        assert_eq!(service.priority_scale(), PriorityScale::default());
     }
 
     #[test]
@@ -29,7 +29,7 @@ mod tests {
         
         let result = service.validate_task_name("");
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot be empty");
+        assert_eq!(result.unwrap_err().to_string(), "Task name cannot be empty");
     }
 
     #[test]
@@ -38,7 +38,7 @@ mod tests {
         
         let result = service.validate_task_name("   ");
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot be empty");
+        assert_eq!(result.unwrap_err().to_string(), "Task name cannot be empty");
     }
 
     #[test]
@@ -48,7 +48,7 @@ mod tests {
         
         let result = service.validate_task_name(&long_name);
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot exceed 255 characters");
+        assert_eq!(result.unwrap_err().to_string(), "Task name cannot exceed 255 characters");
     }
 
     #[test]
@@ -92,11 +92,11 @@ mod tests {
         
         let result = service.validate_priority(Some(0));
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Priority must be between 1 and 10");
+        assert_eq!(result.unwrap_err().to_string(), "Priority must be between 1 and 10");
         
         let result = service.validate_priority(Some(-1));
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Priority must be between 1 and 10");
+        assert_eq!(result.unwrap_err().to_string(), "Priority must be between 1 and 10");
     }
 
     #[test]
@@ -105,11 +105,11 @@ mod tests {
         
         let result = service.validate_priority(Some(11));
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Priority must be between 1 and 10");
+        assert_eq!(result.unwrap_err().to_string(), "Priority must be between 1 and 10");
         
         let result = service.validate_priority(Some(100));
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Priority must be between 1 and 10");
+        assert_eq!(result.unwrap_err().to_string(), "Priority must be between 1 and 10");
     }
 
     #[test]
@@ -146,7 +146,7 @@ mod tests {
         
         let result = service.can_update_task(&task, Some(""), Some(8));
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot be empty");
+        assert_eq!(result.unwrap_err().to_string(), "Task name cannot be empty");
     }
 
     #[test]
@@ -156,7 +156,7 @@ mod tests {
         
         let result = service.can_update_task(&task, Some("Valid name"), Some(11));
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Priority must be between 1 and 10");
+        assert_eq!(result.unwrap_err().to_string(), "Priority must be between 1 and 10");
     }
 
     #[test]
@@ -176,7 +176,7 @@ mod tests {
         
         let result = service.can_update_task(&task, Some(&long_name), Some(5));
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot exceed 255 characters");
+        assert_eq!(result.unwrap_err().to_string(), "Task name cannot exceed 255 characters");
     }
 
     #[test]
@@ -186,6 +186,55 @@ mod tests {
         
         let result = service.can_update_task(&task, Some("   "), Some(5));
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot be empty");
+        assert_eq!(result.unwrap_err().to_string(), "Task name cannot be empty");
     }
-}
\ No newline at end of file
+
+
+    #[test]
+    fn test_validate_search_query() {
+        let service = TaskDomainService::new();
+
+        assert!(service.validate_search_query("deploy pipeline").is_ok());
+        assert_eq!(service.validate_search_query("   ").unwrap_err().to_string(), "Search query cannot be empty");
+        assert!(service.validate_search_query(&"a".repeat(201)).is_err());
+    }
+
+
+    #[test]
+    fn test_validate_priority_with_configured_scale() {
+        let policy = PriorityPolicy::new(PriorityScale::new(1, 5).unwrap(), HighPriorityPolicy::new(2)).unwrap();
+        let service = TaskDomainService::with_priority_policy(policy);
+
+        assert!(service.validate_priority(Some(5)).is_ok());
+        assert_eq!(service.validate_priority(Some(6)).unwrap_err().to_string(), "Priority must be between 1 and 5");
+        assert!(service.can_update_task(&create_test_task(), None, Some(8)).is_err());
+    }
+
+    #[test]
+    fn test_high_priority_follows_the_configured_threshold() {
+        let urgent = Task::new(TaskId::new(2), "Urgent".to_string(), Some(2)).unwrap();
+        let routine = Task::new(TaskId::new(3), "Routine".to_string(), Some(3)).unwrap();
+        let service = TaskDomainService::with_priority_policy(
+            PriorityPolicy::new(PriorityScale::new(1, 5).unwrap(), HighPriorityPolicy::new(2)).unwrap(),
+        );
+
+        assert!(service.is_high_priority(&urgent));
+        assert!(!service.is_high_priority(&routine));
+        assert!(TaskDomainService::new().is_high_priority(&routine));
+    }
+
+    #[test]
+    fn test_only_creators_and_elevated_roles_can_modify_a_task() {
+        let service = TaskDomainService::new();
+        let task = create_test_task().with_created_by(Some("alice".to_string()));
+
+        assert!(service.can_modify_task(&task, "alice", &UserRole::User));
+        assert!(!service.can_modify_task(&task, "bob", &UserRole::User));
+        assert!(service.can_modify_task(&task, "bob", &UserRole::Manager));
+        assert!(service.can_modify_task(&task, "bob", &UserRole::Admin));
+        assert!(service.can_modify_task(&task, "system:stale-task-canceller", &UserRole::System));
+
+        // Tasks from before creators were recorded have no owner to protect
+        assert!(service.can_modify_task(&create_test_task(), "bob", &UserRole::User));
+    }
+}
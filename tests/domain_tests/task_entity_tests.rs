@@ -1,5 +1,5 @@
-use axum_postgres_rust::domain::{Task, TaskId, TaskStatus};
-use chrono::Utc;
+use axum_postgres_rust::domain::{DomainError, HighPriorityPolicy, Task, TaskId, TaskStatus, UserRole};
+use chrono::{Duration, TimeZone, Utc};
 
 #[allow(dead_code)]
 fn create_test_task(id: i32, name: &str, priority: Option<i32>) -> Task {
@@ -48,7 +48,7 @@ mod tests {
         let result = Task::new(task_id, "".to_string(), Some(5));
         
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot be empty");
+        assert_eq!(result.unwrap_err().to_string(), "Task name cannot be empty");
     }
 
     #[test]
@@ -57,7 +57,7 @@ mod tests {
         let result = Task::new(task_id, "   ".to_string(), Some(5));
         
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot be empty");
+        assert_eq!(result.unwrap_err().to_string(), "Task name cannot be empty");
     }
 
     #[test]
@@ -66,7 +66,7 @@ mod tests {
         let result = Task::new(task_id, "Valid task".to_string(), Some(0));
         
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Priority must be between 1 and 10");
+        assert_eq!(result.unwrap_err().to_string(), "Priority must be between 1 and 10");
     }
 
     #[test]
@@ -75,7 +75,7 @@ mod tests {
         let result = Task::new(task_id, "Valid task".to_string(), Some(11));
         
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Priority must be between 1 and 10");
+        assert_eq!(result.unwrap_err().to_string(), "Priority must be between 1 and 10");
     }
 
     #[test]
@@ -118,7 +118,7 @@ mod tests {
         
         let result = task.update_name("".to_string());
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot be empty");
+        assert_eq!(result.unwrap_err().to_string(), "Task name cannot be empty");
         assert_eq!(task.name, "Original name"); // Name should remain unchanged
     }
 
@@ -129,7 +129,7 @@ mod tests {
         
         let result = task.update_name("   ".to_string());
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot be empty");
+        assert_eq!(result.unwrap_err().to_string(), "Task name cannot be empty");
         assert_eq!(task.name, "Original name"); // Name should remain unchanged
     }
 
@@ -160,7 +160,7 @@ mod tests {
         
         let result = task.update_priority(Some(0));
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Priority must be between 1 and 10");
+        assert_eq!(result.unwrap_err().to_string(), "Priority must be between 1 and 10");
         assert_eq!(task.priority, Some(5)); // Priority should remain unchanged
     }
 
@@ -171,7 +171,7 @@ mod tests {
         
         let result = task.update_priority(Some(11));
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Priority must be between 1 and 10");
+        assert_eq!(result.unwrap_err().to_string(), "Priority must be between 1 and 10");
         assert_eq!(task.priority, Some(5)); // Priority should remain unchanged
     }
 
@@ -384,4 +384,135 @@ mod tests {
         assert_ne!(task.updated_at, original_updated_at);
         assert!(task.updated_at > original_updated_at);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_task_with_description() {
+        let task = create_test_task(1, "Documented task", Some(5))
+            .with_description(Some("## Steps\n- do the thing".to_string()))
+            .unwrap();
+
+        assert_eq!(task.description, Some("## Steps\n- do the thing".to_string()));
+    }
+
+    #[test]
+    fn test_task_description_too_long_fails() {
+        let result = create_test_task(1, "Verbose task", Some(5))
+            .with_description(Some("a".repeat(10_001)));
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Task description cannot exceed 10000 characters");
+    }
+
+    #[test]
+    fn test_task_update_description_blank_clears() {
+        let mut task = create_test_task(1, "Task", Some(5))
+            .with_description(Some("Initial".to_string()))
+            .unwrap();
+
+        task.update_description(Some("   ".to_string())).unwrap();
+
+        assert_eq!(task.description, None);
+    }
+
+
+    #[test]
+    fn test_task_soft_delete_marker() {
+        let task = create_test_task(1, "Task", Some(5));
+        assert!(!task.is_deleted());
+
+        let deleted = task.with_deleted_at(Some(Utc::now()));
+        assert!(deleted.is_deleted());
+        assert!(!deleted.with_deleted_at(None).is_deleted());
+    }
+
+
+    #[test]
+    fn test_only_finished_tasks_can_be_archived_and_then_stay_frozen() {
+        let mut task = create_test_task(1, "Task", Some(5));
+        assert_eq!(task.archive_at(Utc::now()), Err(DomainError::ArchiveUnfinished { status: TaskStatus::Pending }));
+
+        task.transition_to(TaskStatus::Cancelled).unwrap();
+        task.archive_at(Utc::now()).unwrap();
+        assert!(task.is_archived());
+
+        assert_eq!(task.archive_at(Utc::now()), Err(DomainError::TaskArchived));
+        assert_eq!(task.update_name("Renamed".to_string()), Err(DomainError::TaskArchived));
+        assert_eq!(task.update_priority(Some(1)), Err(DomainError::TaskArchived));
+        assert_eq!(task.transition_to(TaskStatus::Pending), Err(DomainError::TaskArchived));
+        assert_eq!(task.name, "Task");
+    }
+
+
+    #[test]
+    fn test_transition_under_policy_uses_configured_threshold() {
+        let policy = HighPriorityPolicy::new(5);
+        let mut task = create_test_task(1, "Task", Some(5));
+        assert!(!task.is_high_priority());
+        assert!(task.is_high_priority_under(&policy));
+
+        task.transition_under_policy(TaskStatus::InProgress, &UserRole::User, &policy).unwrap();
+        task.transition_under_policy(TaskStatus::Completed, &UserRole::User, &policy).unwrap();
+
+        assert_eq!(task.status(), &TaskStatus::PendingReview);
+    }
+
+
+
+    #[test]
+    fn test_reopen_completed_task() {
+        let policy = HighPriorityPolicy::default();
+        let mut task = create_test_task(1, "Task", Some(5));
+        task.transition_to(TaskStatus::InProgress).unwrap();
+        task.transition_to(TaskStatus::Completed).unwrap();
+
+        assert!(task.transition_to(TaskStatus::InProgress).is_err());
+        assert_eq!(
+            task.transition_under_policy(TaskStatus::InProgress, &UserRole::User, &policy).unwrap_err(),
+            DomainError::ReopenNotAllowed
+        );
+
+        task.transition_under_policy(TaskStatus::InProgress, &UserRole::Manager, &policy).unwrap();
+        assert_eq!(task.status(), &TaskStatus::InProgress);
+        assert!(task.reopen(&UserRole::Manager).is_err());
+    }
+
+    #[test]
+    fn test_at_variants_stamp_the_given_time() {
+        let created = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let mut task = Task::new_at(TaskId::new(1), "Task".to_string(), Some(5), created).unwrap();
+        assert_eq!((task.created_at, task.updated_at), (created, created));
+
+        task.update_name_at("Renamed".to_string(), created + Duration::hours(1)).unwrap();
+        assert_eq!(task.updated_at, created + Duration::hours(1));
+
+        let started = created + Duration::days(2);
+        task.transition_under_policy_at(TaskStatus::InProgress, &UserRole::User, &HighPriorityPolicy::default(), started).unwrap();
+        assert_eq!(task.updated_at, started);
+        assert_eq!(task.created_at, created);
+    }
+
+    #[test]
+    fn test_move_between_ranks_only_within_the_column() {
+        let (first, second) = (create_test_task(1, "First", Some(5)), create_test_task(2, "Second", Some(5)));
+        let mut task = create_test_task(3, "Moved", Some(5));
+        let updated_at = task.updated_at;
+
+        task.move_between(Some(&first), Some(&second)).unwrap();
+        assert!(first.rank() < task.rank() && task.rank() < second.rank());
+        assert_eq!(task.updated_at, updated_at);
+
+        task.move_between(None, Some(&first)).unwrap();
+        assert!(task.rank() < first.rank());
+        assert_eq!(
+            task.move_between(Some(&second), Some(&first)).unwrap_err(),
+            DomainError::NeighboursOutOfOrder { previous: 2, next: 1 }
+        );
+
+        let mut started = create_test_task(4, "Started", Some(5));
+        started.transition_to(TaskStatus::InProgress).unwrap();
+        assert_eq!(
+            started.move_between(Some(&first), None).unwrap_err(),
+            DomainError::NeighbourInOtherColumn { neighbour: 1, status: TaskStatus::InProgress }
+        );
+    }
+}
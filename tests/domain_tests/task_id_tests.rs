@@ -42,6 +42,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_task_id_clone() {
         let id1 = TaskId::new(777);
         let id2 = id1.clone();
@@ -75,10 +75,10 @@ mod tests {
     }
 
     #[test]
-    fn test_no_transitions_from_completed() {
+    fn test_completed_can_only_be_reopened() {
         let completed = TaskStatus::Completed;
         assert!(!completed.can_transition_to(&TaskStatus::Pending));
-        assert!(!completed.can_transition_to(&TaskStatus::InProgress));
+        assert!(completed.can_transition_to(&TaskStatus::InProgress));
         assert!(!completed.can_transition_to(&TaskStatus::PendingReview));
         assert!(!completed.can_transition_to(&TaskStatus::Cancelled));
         assert!(!completed.can_transition_to(&TaskStatus::Completed));
@@ -0,0 +1,82 @@
+use axum_postgres_rust::application::{AttachmentUseCases, RequestContext, TaskUseCases, UploadAttachmentRequest, UseCaseError};
+use axum_postgres_rust::domain::{Clock, DomainError, FrozenClock, SequentialIdGenerator, Task, TaskId, TaskRepository, TaskStatus, UserRole};
+use axum_postgres_rust::infrastructure::adapters::InMemoryTaskRepository;
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::mocks::{MockAttachmentRepository, MockAttachmentStorage, MockTaskDependencyRepository};
+
+/// Tasks 1 (Pending) and 2 (Completed and archived) over in-memory storage, on a frozen clock
+async fn task_use_cases(clock: FrozenClock) -> Arc<TaskUseCases> {
+    let tasks = InMemoryTaskRepository::new();
+    let now = clock.now();
+    tasks.save(&Task::new_at(TaskId::new(0), "Draft spec".to_string(), Some(5), now).unwrap()).await.unwrap();
+    tasks.save(&Task::new_with_status(TaskId::new(0), "Shipped".to_string(), Some(5), TaskStatus::Completed, now, now).unwrap()).await.unwrap();
+    let use_cases = TaskUseCases::new(Arc::new(tasks.clone()), Arc::new(tasks.history_repository()), Arc::new(MockTaskDependencyRepository::default()))
+        .with_clock(Arc::new(clock));
+    use_cases.archive_task(&user(), 2).await.unwrap();
+    Arc::new(use_cases)
+}
+
+fn user() -> RequestContext {
+    RequestContext::anonymous().with_user("ann", UserRole::Manager)
+}
+
+fn file(name: &str, body: &[u8]) -> UploadAttachmentRequest {
+    UploadAttachmentRequest { file_name: name.to_string(), content_type: Some("text/plain".to_string()), body: body.to_vec() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_uploads_are_stored_and_listed_with_expiring_links() {
+        let clock = FrozenClock::new(Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap());
+        let storage = MockAttachmentStorage::default();
+        let attachments = AttachmentUseCases::new(Arc::new(MockAttachmentRepository::default()), Arc::new(storage.clone()), task_use_cases(clock.clone()).await)
+            .with_id_generator(Arc::new(SequentialIdGenerator::default()))
+            .with_download_url_ttl(Duration::from_secs(600));
+
+        let uploaded = attachments.upload_attachment(&user(), 1, file("C:\\fakepath\\spec.txt", b"v1")).await.unwrap();
+        assert_eq!(uploaded.file_name, "spec.txt");
+        assert_eq!(uploaded.uploaded_by, "ann");
+        assert_eq!(uploaded.download_url_expires_at, Utc.with_ymd_and_hms(2024, 3, 1, 12, 10, 0).unwrap());
+        let (key, content_type, body) = storage.uploads.lock().unwrap()[0].clone();
+        assert_eq!(key, "tasks/1/00000000-0000-0000-0000-000000000001");
+        assert_eq!((content_type.as_str(), body.as_slice()), ("text/plain", b"v1".as_slice()));
+
+        clock.advance(chrono::Duration::minutes(30));
+        let listed = attachments.list_attachments(&user(), 1).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].download_url_expires_at, Utc.with_ymd_and_hms(2024, 3, 1, 12, 40, 0).unwrap());
+        assert!(attachments.list_attachments(&user(), 2).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_uploads_need_an_unarchived_task_and_a_file_within_the_limit() {
+        let clock = FrozenClock::new(Utc::now());
+        let repository = MockAttachmentRepository::default();
+        let attachments = AttachmentUseCases::new(Arc::new(repository.clone()), Arc::new(MockAttachmentStorage::default()), task_use_cases(clock).await)
+            .with_max_size_bytes(4);
+
+        assert!(matches!(attachments.upload_attachment(&user(), 2, file("a.txt", b"v1")).await, Err(UseCaseError::Domain(DomainError::TaskArchived))));
+        assert!(matches!(attachments.upload_attachment(&user(), 9, file("a.txt", b"v1")).await, Err(UseCaseError::NotFound(_))));
+        assert!(matches!(attachments.upload_attachment(&user(), 1, file("a.txt", b"12345")).await, Err(UseCaseError::ValidationError(_))));
+        assert!(matches!(attachments.upload_attachment(&user(), 1, file("", b"v1")).await, Err(UseCaseError::ValidationError(_))));
+        assert!(repository.attachments.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_uploads_save_no_metadata() {
+        let repository = MockAttachmentRepository::default();
+        let attachments = AttachmentUseCases::new(Arc::new(repository.clone()), Arc::new(MockAttachmentStorage::unreachable()), task_use_cases(FrozenClock::new(Utc::now())).await);
+
+        match attachments.upload_attachment(&user(), 1, file("a.txt", b"v1")).await {
+            Err(UseCaseError::RepositoryError(message)) => assert_eq!(message, "Failed to store attachment: Storage unreachable"),
+            other => panic!("expected the storage failure, got {:?}", other.map(|attachment| attachment.id)),
+        }
+        assert!(repository.attachments.lock().unwrap().is_empty());
+    }
+}
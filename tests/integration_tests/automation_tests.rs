@@ -0,0 +1,151 @@
+use axum_postgres_rust::application::{AutomationRuleRequest, AutomationUseCases, CreateTaskRequest, RequestContext, TaskUseCases, UpdateTaskRequest, UseCaseError};
+use axum_postgres_rust::domain::{NotifyUrlPolicy, RuleAction, RuleFilter, RuleTrigger, TaskField, UserRole};
+use axum_postgres_rust::database::with_workspace;
+use axum_postgres_rust::infrastructure::adapters::{spawn_automation_worker, InMemoryEventPublisher, InMemoryTaskRepository};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::mocks::{MockAutomationRuleRepository, MockNotifier, MockTaskDependencyRepository};
+
+/// Task use cases over in-memory storage that publish to the returned event bus
+fn automated_use_cases() -> (Arc<TaskUseCases>, InMemoryEventPublisher) {
+    let tasks = InMemoryTaskRepository::new();
+    let history = tasks.history_repository();
+    let event_bus = InMemoryEventPublisher::default();
+    let use_cases = TaskUseCases::new(Arc::new(tasks), Arc::new(history), Arc::new(MockTaskDependencyRepository::default()))
+        .with_event_publisher(Arc::new(event_bus.clone()));
+    (Arc::new(use_cases), event_bus)
+}
+
+fn rule(name: &str, event_type: &str, filter: RuleFilter, action: RuleAction) -> AutomationRuleRequest {
+    AutomationRuleRequest {
+        name: name.to_string(),
+        enabled: true,
+        trigger: RuleTrigger { event_type: event_type.to_string(), filter },
+        action,
+    }
+}
+
+fn manager() -> RequestContext {
+    RequestContext::anonymous().with_user("mia", UserRole::Manager)
+}
+
+/// Polls `check` until it holds, failing the test after a second
+async fn eventually(mut check: impl AsyncFnMut() -> bool) {
+    tokio::time::timeout(Duration::from_secs(1), async {
+        while !check().await {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("the automation did not run in time");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_worker_applies_rules_without_reacting_to_its_own_changes() {
+        let (tasks, event_bus) = automated_use_cases();
+        let notifier = MockNotifier::default();
+        let automation = Arc::new(AutomationUseCases::new(Arc::new(MockAutomationRuleRepository::default()), tasks.clone(), Arc::new(notifier.clone())));
+        let ctx = manager();
+        let low = RuleFilter { max_priority: Some(3), ..RuleFilter::default() };
+        automation.create_rule(&ctx, rule("Raise low priorities", "task_created", low, RuleAction::SetPriority { priority: 8 })).await.unwrap();
+        let priority_changes = RuleFilter { field: Some(TaskField::Priority), ..RuleFilter::default() };
+        let notify = RuleAction::Notify { url: "https://chat.example.com/hooks/tasks".to_string() };
+        automation.create_rule(&ctx, rule("Announce priority changes", "task_updated", priority_changes, notify)).await.unwrap();
+        let worker = spawn_automation_worker(automation, event_bus.subscribe());
+
+        let id = tasks.create_task(&ctx, CreateTaskRequest { name: "Fix login".to_string(), priority: Some(2.into()), description: None }).await.unwrap();
+        eventually(async || tasks.get_task_by_id(&ctx, id).await.unwrap().priority == Some(8)).await;
+
+        // The rule's own priority change is not announced, a person's is
+        let update = UpdateTaskRequest { name: None, priority: Some(5.into()), description: None };
+        tasks.update_task(&ctx, id, update).await.unwrap();
+        eventually(async || !notifier.sent.lock().unwrap().is_empty()).await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        let sent = notifier.sent.lock().unwrap().clone();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0, "https://chat.example.com/hooks/tasks");
+        assert_eq!(sent[0].1.rule_name, "Announce priority changes");
+        assert_eq!(sent[0].1.event.changed_by, "mia");
+
+        worker.abort();
+    }
+
+    #[tokio::test]
+    async fn test_failing_action_is_reported_and_disabled_rules_are_skipped() {
+        let (tasks, event_bus) = automated_use_cases();
+        let automation = AutomationUseCases::new(Arc::new(MockAutomationRuleRepository::default()), tasks.clone(), Arc::new(MockNotifier::default()));
+        let ctx = manager();
+        let unreachable = RuleAction::Notify { url: "https://unreachable.example.com".to_string() };
+        automation.create_rule(&ctx, rule("Page on-call", "task_created", RuleFilter::default(), unreachable)).await.unwrap();
+        let mut disabled = rule("Old rule", "task_created", RuleFilter::default(), RuleAction::SetPriority { priority: 1 });
+        disabled.enabled = false;
+        automation.create_rule(&ctx, disabled).await.unwrap();
+        let mut events = event_bus.subscribe();
+
+        let id = tasks.create_task(&ctx, CreateTaskRequest { name: "Rotate keys".to_string(), priority: Some(4.into()), description: None }).await.unwrap();
+        let outcomes = automation.handle_event(&events.recv().await.unwrap()).await.unwrap();
+
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].rule_name, "Page on-call");
+        assert!(outcomes[0].error.as_deref().unwrap().contains("unreachable.example.com"));
+        assert_eq!(tasks.get_task_by_id(&ctx, id).await.unwrap().priority, Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_rules_only_see_their_own_workspace() {
+        let (tasks, event_bus) = automated_use_cases();
+        let notifier = MockNotifier::default();
+        let automation = AutomationUseCases::new(Arc::new(MockAutomationRuleRepository::default()), tasks.clone(), Arc::new(notifier.clone()));
+        let platform = || Some("platform".to_string());
+        let ctx = manager().with_tenant(platform());
+        let notify = RuleAction::Notify { url: "https://chat.example.com/hooks/platform".to_string() };
+        let created = with_workspace(platform(), automation.create_rule(&ctx, rule("Announce new tasks", "task_created", RuleFilter::default(), notify))).await.unwrap();
+        assert_eq!(created.workspace_id, "platform");
+        let mut events = event_bus.subscribe();
+
+        tasks.create_task(&manager(), CreateTaskRequest { name: "Triage".to_string(), priority: None, description: None }).await.unwrap();
+        assert!(automation.handle_event(&events.recv().await.unwrap()).await.unwrap().is_empty());
+        assert!(with_workspace(Some("default".to_string()), automation.list_rules(&manager())).await.unwrap().is_empty());
+        assert!(matches!(with_workspace(Some("default".to_string()), automation.get_rule(&manager(), &created.id)).await, Err(UseCaseError::NotFound(_))));
+
+        with_workspace(platform(), tasks.create_task(&ctx, CreateTaskRequest { name: "Rotate keys".to_string(), priority: None, description: None })).await.unwrap();
+        let outcomes = automation.handle_event(&events.recv().await.unwrap()).await.unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(notifier.sent.lock().unwrap()[0].0, "https://chat.example.com/hooks/platform");
+    }
+
+    #[tokio::test]
+    async fn test_only_managers_and_admins_define_rules() {
+        let (tasks, _) = automated_use_cases();
+        let automation = AutomationUseCases::new(Arc::new(MockAutomationRuleRepository::default()), tasks, Arc::new(MockNotifier::default()));
+        let request = rule("Raise everything", "task_created", RuleFilter::default(), RuleAction::SetPriority { priority: 8 });
+
+        let result = automation.create_rule(&RequestContext::anonymous().with_user("uma", UserRole::User), request).await;
+
+        assert!(matches!(result, Err(UseCaseError::Forbidden(_))));
+        assert!(automation.list_rules(&manager()).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_notify_rules_only_reach_permitted_hosts() {
+        let (tasks, _) = automated_use_cases();
+        let automation = AutomationUseCases::new(Arc::new(MockAutomationRuleRepository::default()), tasks.clone(), Arc::new(MockNotifier::default()));
+        let notify = |url: &str| rule("Announce", "task_created", RuleFilter::default(), RuleAction::Notify { url: url.to_string() });
+
+        for url in ["http://169.254.169.254/latest/meta-data", "https://10.0.0.7/hook", "https://localhost:9000/hook"] {
+            let result = automation.create_rule(&manager(), notify(url)).await;
+            assert!(matches!(result, Err(UseCaseError::ValidationError(_))), "{}", url);
+        }
+        automation.create_rule(&manager(), notify("https://chat.example.com/hooks")).await.unwrap();
+
+        let automation = AutomationUseCases::new(Arc::new(MockAutomationRuleRepository::default()), tasks, Arc::new(MockNotifier::default()))
+            .with_notify_url_policy(NotifyUrlPolicy::parse("hooks.slack.com").unwrap());
+        assert!(automation.create_rule(&manager(), notify("https://chat.example.com/hooks")).await.is_err());
+        automation.create_rule(&manager(), notify("https://hooks.slack.com/services/T0")).await.unwrap();
+    }
+}
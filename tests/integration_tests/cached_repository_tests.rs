@@ -0,0 +1,68 @@
+use axum_postgres_rust::domain::{HistoryFilter, StatusHistoryRepository, Task, TaskId, TaskRepository};
+use chrono::Utc;
+use axum_postgres_rust::infrastructure::adapters::{CacheStats, CachedStatusHistoryRepository, CachedTaskRepository, QueryCache};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::mocks::{MockRepository, MockStatusHistoryRepository};
+
+fn cached_repositories() -> (CachedTaskRepository, CachedStatusHistoryRepository, Arc<QueryCache>) {
+    let cache = Arc::new(QueryCache::new(Duration::from_secs(60)));
+    let task = Task::new(TaskId::new(1), "Cached".to_string(), Some(5)).unwrap();
+    let tasks = CachedTaskRepository::new(Arc::new(MockRepository::new().with_tasks(vec![task])), cache.clone());
+    let history = CachedStatusHistoryRepository::new(Arc::new(MockStatusHistoryRepository), cache.clone());
+    (tasks, history, cache)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_find_by_id_is_served_from_cache_until_the_task_is_written() {
+        let (tasks, _, cache) = cached_repositories();
+
+        let first = tasks.find_by_id(TaskId::new(1)).await.unwrap().unwrap();
+        let second = tasks.find_by_id(TaskId::new(1)).await.unwrap().unwrap();
+        assert_eq!(first, second);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+
+        tasks.update(&first).await.unwrap();
+        tasks.find_by_id(TaskId::new(1)).await.unwrap();
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 2 });
+    }
+
+    #[tokio::test]
+    async fn test_missing_tasks_are_cached_until_saved() {
+        let (tasks, _, cache) = cached_repositories();
+
+        assert!(tasks.find_by_id(TaskId::new(2)).await.unwrap().is_none());
+        assert!(tasks.find_by_id(TaskId::new(2)).await.unwrap().is_none());
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+
+        let saved = tasks.save(&Task::new(TaskId::new(0), "New".to_string(), None).unwrap()).await.unwrap();
+        assert_eq!(saved, TaskId::new(2));
+        tasks.find_by_id(TaskId::new(2)).await.unwrap();
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 2 });
+    }
+
+    #[tokio::test]
+    async fn test_history_stats_are_keyed_by_filter_and_cleared_by_task_writes() {
+        let (tasks, history, cache) = cached_repositories();
+        let by_task = HistoryFilter { task_id: Some(1), ..HistoryFilter::default() };
+        let end = Utc::now();
+        let start = end - chrono::Duration::days(30);
+
+        history.count_matching(&HistoryFilter::default()).await.unwrap();
+        history.count_matching(&by_task).await.unwrap();
+        history.count_matching(&by_task).await.unwrap();
+        history.get_completion_time_stats(start, end).await.unwrap();
+        history.get_completion_time_stats(start, end).await.unwrap();
+        assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 3 });
+
+        tasks.delete(TaskId::new(1)).await.unwrap();
+        history.count_matching(&by_task).await.unwrap();
+        history.get_completion_time_stats(start, end).await.unwrap();
+        assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 5 });
+    }
+}
@@ -1,110 +1,21 @@
 use axum_postgres_rust::{
-    domain::{Task, TaskId, TaskRepository, StatusHistoryRepository, RepositoryError, StatusHistory, TaskStatus},
-    application::{TaskUseCases, TaskDto, CreateTaskRequest, UpdateTaskRequest, UseCaseError},
+    infrastructure::adapters::{InMemoryEventPublisher, InMemoryTaskRepository, JiraCsvParser, LocalObjectStorage},
+    domain::{ArchiveMonth, GateDecision, GateFallback, AuditRetentionPolicy, HistoryFilter, ChecklistItem, DomainError, ImportSource, StatusHistoryRepository, FrozenClock, SequentialIdGenerator, TaskEvent, HighPriorityPolicy, PriorityPolicy, PriorityLabel, ReviewChecklist, PriorityScale, ReviewChains, StaleTaskPolicy, AutoArchivePolicy, EscalationPolicy, SystemActor, Task, TaskFilter, TaskSort, TaskId, TaskStatus, TaskField, TaskRepository, UserRole},
+    application::{ApiKeyUseCases, AuditArchiveUseCases, CreateApiKeyRequest, FieldError, ImportTasksRequest, StatusMappingEntry, ReprioritizeRequest, RequestContext, TaskUseCases, TaskDto, PriorityInput, CreateTaskRequest, UpdateTaskRequest, UpdateTaskStatusDto, UseCaseError, AddDependencyRequest, CreationPolicy, ContentPolicy, DuplicateNamePolicy, OpenTaskQuotaPolicy},
     responses::{ApiResponse, TaskListResponse, TaskCreatedResponse},
 };
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use async_trait::async_trait;
-use chrono::Utc;
-
-// Mock repository for integration testing
-#[derive(Clone)]
-struct MockRepository {
-    tasks: Vec<Task>,
-    next_id: i32,
-}
+use chrono::{Duration, TimeZone, Utc};
 
-impl MockRepository {
-    fn new() -> Self {
-        Self {
-            tasks: vec![],
-            next_id: 1,
-        }
-    }
+use super::mocks::{MockApiKeyRepository, MockRepository, MockStatusHistoryRepository, MockTaskDependencyRepository, MockTransitionGate, UnavailableStatusHistoryRepository};
 
-    fn with_tasks(mut self, tasks: Vec<Task>) -> Self {
-        self.next_id = tasks.iter().map(|t| t.id.value()).max().unwrap_or(0) + 1;
-        self.tasks = tasks;
-        self
-    }
+fn user() -> RequestContext {
+    RequestContext::anonymous()
 }
 
-#[async_trait]
-impl TaskRepository for MockRepository {
-    async fn find_all(&self) -> Result<Vec<Task>, RepositoryError> {
-        Ok(self.tasks.clone())
-    }
-
-    async fn find_by_id(&self, id: TaskId) -> Result<Option<Task>, RepositoryError> {
-        Ok(self.tasks.iter().find(|t| t.id == id).cloned())
-    }
-
-    async fn find_by_priority(&self, priority: i32) -> Result<Vec<Task>, RepositoryError> {
-        Ok(self.tasks
-            .iter()
-            .filter(|t| t.priority == Some(priority))
-            .cloned()
-            .collect())
-    }
-
-    async fn save(&self, _task: &Task) -> Result<TaskId, RepositoryError> {
-        Ok(TaskId::new(self.next_id))
-    }
-
-    async fn update(&self, _task: &Task) -> Result<(), RepositoryError> {
-        Ok(())
-    }
-
-    async fn delete(&self, _id: TaskId) -> Result<(), RepositoryError> {
-        Ok(())
-    }
-}
-
-// Mock status history repository for integration testing
-#[derive(Clone)]
-struct MockStatusHistoryRepository;
-
-#[async_trait]
-impl StatusHistoryRepository for MockStatusHistoryRepository {
-    async fn find_by_task_id(&self, _task_id: i32) -> Result<Vec<StatusHistory>, RepositoryError> {
-        Ok(vec![])
-    }
-    
-    async fn find_by_date_range(
-        &self, 
-        _start_date: chrono::DateTime<chrono::Utc>, 
-        _end_date: chrono::DateTime<chrono::Utc>
-    ) -> Result<Vec<StatusHistory>, RepositoryError> {
-        Ok(vec![])
-    }
-    
-    async fn find_latest_by_task_id(&self, _task_id: i32) -> Result<Option<StatusHistory>, RepositoryError> {
-        Ok(None)
-    }
-    
-    async fn get_task_analytics(&self, _task_id: i32) -> Result<Option<axum_postgres_rust::domain::TaskAnalytics>, RepositoryError> {
-        Ok(None)
-    }
-    
-    async fn get_completion_analytics(
-        &self, 
-        _start_date: chrono::DateTime<chrono::Utc>, 
-        _end_date: chrono::DateTime<chrono::Utc>
-    ) -> Result<Vec<axum_postgres_rust::domain::TaskAnalytics>, RepositoryError> {
-        Ok(vec![])
-    }
-    
-    async fn get_average_completion_times(&self) -> Result<Vec<(i32, chrono::Duration)>, RepositoryError> {
-        Ok(vec![])
-    }
-    
-    async fn save(&self, _history: &StatusHistory) -> Result<String, RepositoryError> {
-        Ok("mock-id".to_string())
-    }
-    
-    async fn delete(&self, _id: String) -> Result<(), RepositoryError> {
-        Ok(())
-    }
+fn manager() -> RequestContext {
+    RequestContext::anonymous().with_user("reviewer", UserRole::Manager)
 }
 
 fn create_test_task(id: i32, name: &str, priority: Option<i32>) -> Task {
@@ -112,7 +23,7 @@ fn create_test_task(id: i32, name: &str, priority: Option<i32>) -> Task {
 }
 
 fn create_use_cases_with_mock(mock_repo: MockRepository) -> TaskUseCases {
-    TaskUseCases::new(Arc::new(mock_repo), Arc::new(MockStatusHistoryRepository))
+    TaskUseCases::new(Arc::new(mock_repo), Arc::new(MockStatusHistoryRepository), Arc::new(MockTaskDependencyRepository::default()))
 }
 
 #[cfg(test)]
@@ -128,18 +39,19 @@ mod tests {
         // Test create task
         let create_request = CreateTaskRequest {
             name: "Integration Test Task".to_string(),
-            priority: Some(5),
+            priority: Some(5.into()),
+            description: None,
         };
 
-        let created_id = use_cases.create_task(create_request).await.unwrap();
+        let created_id = use_cases.create_task(&user(), create_request).await.unwrap();
         assert_eq!(created_id, 1);
 
         // Test get all tasks (empty in this mock)
-        let all_tasks = use_cases.get_all_tasks().await.unwrap();
+        let all_tasks = use_cases.get_all_tasks(&user()).await.unwrap();
         assert_eq!(all_tasks.len(), 0); // Mock doesn't actually store
 
         // Test get task by id (not found in this mock)
-        let result = use_cases.get_task_by_id(1).await;
+        let result = use_cases.get_task_by_id(&user(), 1).await;
         assert!(result.is_err());
         match result.unwrap_err() {
             UseCaseError::NotFound(_) => {}, // Expected
@@ -159,30 +71,36 @@ mod tests {
         let use_cases = create_use_cases_with_mock(mock_repo);
 
         // Test get all tasks
-        let all_tasks = use_cases.get_all_tasks().await.unwrap();
+        let all_tasks = use_cases.get_all_tasks(&user()).await.unwrap();
         assert_eq!(all_tasks.len(), 3);
         assert_eq!(all_tasks[0].name, "Task 1");
         assert_eq!(all_tasks[1].name, "Task 2");
         assert_eq!(all_tasks[2].name, "Task 3");
 
         // Test get task by id
-        let task = use_cases.get_task_by_id(1).await.unwrap();
+        let task = use_cases.get_task_by_id(&user(), 1).await.unwrap();
         assert_eq!(task.id, 1);
         assert_eq!(task.name, "Task 1");
         assert_eq!(task.priority, Some(5));
 
         // Test get tasks by priority
-        let high_priority_tasks = use_cases.get_tasks_by_priority(5).await.unwrap();
+        let high_priority_tasks = use_cases.get_tasks_by_priority(&user(), 5).await.unwrap();
         assert_eq!(high_priority_tasks.len(), 1);
         assert_eq!(high_priority_tasks[0].name, "Task 1");
 
-        let medium_priority_tasks = use_cases.get_tasks_by_priority(3).await.unwrap();
+        let medium_priority_tasks = use_cases.get_tasks_by_priority(&user(), 3).await.unwrap();
         assert_eq!(medium_priority_tasks.len(), 1);
         assert_eq!(medium_priority_tasks[0].name, "Task 2");
 
         // Test get tasks by non-existent priority
-        let no_tasks = use_cases.get_tasks_by_priority(10).await.unwrap();
+        let no_tasks = use_cases.get_tasks_by_priority(&user(), 10).await.unwrap();
         assert_eq!(no_tasks.len(), 0);
+
+        // Test get tasks by status
+        let pending_tasks = use_cases.get_tasks_by_status(&user(), TaskStatus::Pending).await.unwrap();
+        assert_eq!(pending_tasks.len(), 3);
+        let completed_tasks = use_cases.get_tasks_by_status(&user(), TaskStatus::Completed).await.unwrap();
+        assert!(completed_tasks.is_empty());
     }
 
     #[tokio::test]
@@ -193,41 +111,45 @@ mod tests {
         // Test create task with empty name
         let invalid_request = CreateTaskRequest {
             name: "".to_string(),
-            priority: Some(5),
+            priority: Some(5.into()),
+            description: None,
         };
 
-        let result = use_cases.create_task(invalid_request).await;
+        let result = use_cases.create_task(&user(), invalid_request).await;
         assert!(result.is_err());
         match result.unwrap_err() {
-            UseCaseError::ValidationError(msg) => {
-                assert_eq!(msg, "Task name cannot be empty");
+            UseCaseError::InvalidFields(errors) => {
+                assert_eq!(errors, vec![FieldError::new("name", DomainError::EmptyName)]);
             }
-            _ => panic!("Expected ValidationError"),
+            _ => panic!("Expected InvalidFields"),
         }
 
         // Test create task with invalid priority
         let invalid_priority_request = CreateTaskRequest {
             name: "Valid Name".to_string(),
-            priority: Some(15), // Invalid priority
+            priority: Some(15.into()), // Invalid priority
+            description: None,
         };
 
-        let result = use_cases.create_task(invalid_priority_request).await;
+        let result = use_cases.create_task(&user(), invalid_priority_request).await;
         assert!(result.is_err());
         match result.unwrap_err() {
-            UseCaseError::ValidationError(msg) => {
-                assert_eq!(msg, "Priority must be between 1 and 10");
+            UseCaseError::InvalidFields(errors) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].field, "priority");
+                assert_eq!(errors[0].message, "Priority must be between 1 and 10");
             }
-            _ => panic!("Expected ValidationError"),
+            _ => panic!("Expected InvalidFields"),
         }
 
         // Test get tasks by invalid priority
-        let result = use_cases.get_tasks_by_priority(0).await;
+        let result = use_cases.get_tasks_by_priority(&user(), 0).await;
         assert!(result.is_err());
         match result.unwrap_err() {
-            UseCaseError::ValidationError(msg) => {
-                assert_eq!(msg, "Priority must be between 1 and 10");
+            UseCaseError::Domain(error) => {
+                assert_eq!(error.to_string(), "Priority must be between 1 and 10");
             }
-            _ => panic!("Expected ValidationError"),
+            _ => panic!("Expected DomainError"),
         }
     }
 
@@ -243,19 +165,21 @@ mod tests {
         // Test update existing task
         let update_request = UpdateTaskRequest {
             name: Some("Updated Task".to_string()),
-            priority: Some(8),
+            priority: Some(8.into()),
+            description: None,
         };
 
-        let result = use_cases.update_task(1, update_request).await;
+        let result = use_cases.update_task(&user(), 1, update_request).await;
         assert!(result.is_ok());
 
         // Test update non-existent task
         let update_request = UpdateTaskRequest {
             name: Some("Won't work".to_string()),
             priority: None,
+            description: None,
         };
 
-        let result = use_cases.update_task(999, update_request).await;
+        let result = use_cases.update_task(&user(), 999, update_request).await;
         assert!(result.is_err());
         match result.unwrap_err() {
             UseCaseError::NotFound(_) => {}, // Expected
@@ -263,11 +187,11 @@ mod tests {
         }
 
         // Test delete existing task
-        let result = use_cases.delete_task(1).await;
+        let result = use_cases.delete_task(&user(), 1).await;
         assert!(result.is_ok());
 
         // Test delete non-existent task
-        let result = use_cases.delete_task(999).await;
+        let result = use_cases.delete_task(&user(), 999).await;
         assert!(result.is_err());
         match result.unwrap_err() {
             UseCaseError::NotFound(_) => {}, // Expected
@@ -299,26 +223,31 @@ mod tests {
             id: 1,
             name: "API Test".to_string(),
             priority: Some(5),
+            priority_label: None,
+            description: None,
             status: TaskStatus::Pending,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
+            archived_at: None,
+            created_by: None,
         };
 
         let success_response = ApiResponse::success(task_dto);
-        assert_eq!(success_response.success, true);
+        assert!(success_response.success);
         assert!(success_response.data.is_some());
         assert_eq!(success_response.message, None);
 
         // Test error API responses
         let error_response = ApiResponse::<()>::error("Test error".to_string());
-        assert_eq!(error_response.success, false);
+        assert!(!error_response.success);
         assert_eq!(error_response.data, None);
         assert_eq!(error_response.message, Some("Test error".to_string()));
 
         // Test task list response
         let tasks = vec![
-            TaskDto { id: 1, name: "Task 1".to_string(), priority: Some(1), status: TaskStatus::Pending, created_at: Utc::now(), updated_at: Utc::now() },
-            TaskDto { id: 2, name: "Task 2".to_string(), priority: Some(2), status: TaskStatus::Pending, created_at: Utc::now(), updated_at: Utc::now() },
+            TaskDto { id: 1, name: "Task 1".to_string(), priority: Some(1), priority_label: None, description: None, status: TaskStatus::Pending, created_at: Utc::now(), updated_at: Utc::now(), deleted_at: None, archived_at: None, created_by: None },
+            TaskDto { id: 2, name: "Task 2".to_string(), priority: Some(2), priority_label: None, description: None, status: TaskStatus::Pending, created_at: Utc::now(), updated_at: Utc::now(), deleted_at: None, archived_at: None, created_by: None },
         ];
 
         let list_response = TaskListResponse { tasks };
@@ -342,44 +271,48 @@ mod tests {
         // Test with boundary priority values
         let min_priority_request = CreateTaskRequest {
             name: "Min Priority".to_string(),
-            priority: Some(1),
+            priority: Some(1.into()),
+            description: None,
         };
 
-        let result = use_cases.create_task(min_priority_request).await;
+        let result = use_cases.create_task(&user(), min_priority_request).await;
         assert!(result.is_ok());
 
         let max_priority_request = CreateTaskRequest {
             name: "Max Priority".to_string(),
-            priority: Some(10),
+            priority: Some(10.into()),
+            description: None,
         };
 
-        let result = use_cases.create_task(max_priority_request).await;
+        let result = use_cases.create_task(&user(), max_priority_request).await;
         assert!(result.is_ok());
 
         // Test with maximum allowed name length (255 chars)
         let long_name = "a".repeat(255);
         let long_name_request = CreateTaskRequest {
             name: long_name.clone(),
-            priority: Some(5),
+            priority: Some(5.into()),
+            description: None,
         };
 
-        let result = use_cases.create_task(long_name_request).await;
+        let result = use_cases.create_task(&user(), long_name_request).await;
         assert!(result.is_ok());
 
         // Test with name that's too long (256 chars)
         let too_long_name = "a".repeat(256);
         let too_long_request = CreateTaskRequest {
             name: too_long_name,
-            priority: Some(5),
+            priority: Some(5.into()),
+            description: None,
         };
 
-        let result = use_cases.create_task(too_long_request).await;
+        let result = use_cases.create_task(&user(), too_long_request).await;
         assert!(result.is_err());
         match result.unwrap_err() {
-            UseCaseError::ValidationError(msg) => {
-                assert_eq!(msg, "Task name cannot exceed 255 characters");
+            UseCaseError::InvalidFields(errors) => {
+                assert_eq!(errors, vec![FieldError::new("name", DomainError::NameTooLong { max: 255 })]);
             }
-            _ => panic!("Expected ValidationError"),
+            _ => panic!("Expected InvalidFields"),
         }
     }
 
@@ -394,7 +327,7 @@ mod tests {
         let use_cases = create_use_cases_with_mock(mock_repo);
 
         // Test that we can retrieve tasks with special characters
-        let all_tasks = use_cases.get_all_tasks().await.unwrap();
+        let all_tasks = use_cases.get_all_tasks(&user()).await.unwrap();
         assert_eq!(all_tasks.len(), 2);
         
         // The first task name should be trimmed during creation (the Task constructor trims whitespace)
@@ -405,26 +338,29 @@ mod tests {
         let partial_update = UpdateTaskRequest {
             name: Some("Partially Updated".to_string()),
             priority: None, // Don't update priority
+            description: None,
         };
 
-        let result = use_cases.update_task(1, partial_update).await;
+        let result = use_cases.update_task(&user(), 1, partial_update).await;
         assert!(result.is_ok());
 
         let priority_only_update = UpdateTaskRequest {
             name: None, // Don't update name
-            priority: Some(9),
+            priority: Some(9.into()),
+            description: None,
         };
 
-        let result = use_cases.update_task(2, priority_only_update).await;
+        let result = use_cases.update_task(&user(), 2, priority_only_update).await;
         assert!(result.is_ok());
 
         // Test empty update (no fields to update)
         let empty_update = UpdateTaskRequest {
             name: None,
             priority: None,
+            description: None,
         };
 
-        let result = use_cases.update_task(1, empty_update).await;
+        let result = use_cases.update_task(&user(), 1, empty_update).await;
         assert!(result.is_ok());
     }
 
@@ -442,9 +378,10 @@ mod tests {
             let handle = tokio::spawn(async move {
                 let request = CreateTaskRequest {
                     name: format!("Concurrent Task {}", i),
-                    priority: Some(i % 10 + 1),
+                    priority: Some((i % 10 + 1).into()),
+                    description: None,
                 };
-                use_cases_clone.create_task(request).await
+                use_cases_clone.create_task(&user(), request).await
             });
             handles.push(handle);
         }
@@ -468,16 +405,16 @@ mod tests {
         ]);
 
         // 2. Application Layer: Create use cases
-        let use_cases = TaskUseCases::new(Arc::new(repository), Arc::new(MockStatusHistoryRepository));
+        let use_cases = TaskUseCases::new(Arc::new(repository), Arc::new(MockStatusHistoryRepository), Arc::new(MockTaskDependencyRepository::default()));
 
         // 3. Application Layer: Execute business logic
-        let all_tasks = use_cases.get_all_tasks().await?;
+        let all_tasks = use_cases.get_all_tasks(&user()).await?;
 
         // 4. Infrastructure Layer (Web): Format response
         let response = ApiResponse::success(TaskListResponse { tasks: all_tasks });
 
         // 5. Verify the complete flow worked
-        assert_eq!(response.success, true);
+        assert!(response.success);
         assert!(response.data.is_some());
         let data = response.data.unwrap();
         assert_eq!(data.tasks.len(), 1);
@@ -490,4 +427,999 @@ mod tests {
     async fn test_hexagonal_architecture_demo() {
         demonstrate_hexagonal_architecture_flow().await.unwrap();
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_update_task_reports_changed_fields() {
+        let mock_repo = MockRepository::new().with_tasks(vec![
+            create_test_task(1, "Original Task", Some(5)),
+        ]);
+        let use_cases = create_use_cases_with_mock(mock_repo.clone());
+
+        let update_request = UpdateTaskRequest {
+            name: Some("Renamed Task".to_string()),
+            priority: Some(5.into()), // unchanged
+            description: Some("Now documented".to_string()),
+        };
+
+        let event = use_cases.update_task(&user(), 1, update_request).await.unwrap();
+
+        assert_eq!(event.changed_fields(), vec![TaskField::Name, TaskField::Description]);
+        assert_eq!(event.changes[0].old_value, Some("Original Task".to_string()));
+
+        let patches = mock_repo.recorded_patches();
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].name.as_deref(), Some("Renamed Task"));
+        assert_eq!(patches[0].priority, None);
+        assert_eq!(patches[0].description, Some(Some("Now documented".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_create_task_records_initial_history() {
+        let mock_repo = MockRepository::new();
+        let use_cases = create_use_cases_with_mock(mock_repo.clone());
+
+        let request = CreateTaskRequest {
+            name: "Tracked Task".to_string(),
+            priority: Some(5.into()),
+            description: None,
+        };
+
+        let task_id = use_cases.create_task(&user(), request).await.unwrap();
+
+        let history = mock_repo.recorded_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].task_id, task_id);
+        assert!(history[0].is_initial_creation());
+        assert_eq!(history[0].to_status, TaskStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_update_task_status_records_history() {
+        let mock_repo = MockRepository::new().with_tasks(vec![
+            create_test_task(1, "Workflow Task", Some(5)),
+        ]);
+        let use_cases = create_use_cases_with_mock(mock_repo.clone());
+
+        let request = UpdateTaskStatusDto {
+            status: TaskStatus::InProgress,
+            comment: Some("Picking this up".to_string()),
+            checklist: None,
+        };
+
+        let task = use_cases.update_task_status(&user(), 1, request).await.unwrap();
+
+        let history = mock_repo.recorded_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].task_id, 1);
+        assert_eq!(history[0].from_status, Some(TaskStatus::Pending));
+        assert_eq!(history[0].to_status, TaskStatus::InProgress);
+        assert_eq!(history[0].comment, Some("Picking this up".to_string()));
+        assert_eq!(history[0].changed_at, task.updated_at);
+    }
+
+
+    #[tokio::test]
+    async fn test_configured_priority_scale_is_enforced() {
+        let use_cases = create_use_cases_with_mock(MockRepository::new())
+            .with_priority_policy(PriorityPolicy::new(PriorityScale::new(1, 5).unwrap(), HighPriorityPolicy::default()).unwrap());
+
+        let result = use_cases.create_task(&user(), CreateTaskRequest {
+            name: "Out of scale".to_string(),
+            priority: Some(7.into()),
+            description: None,
+        }).await;
+
+        match result {
+            Err(UseCaseError::InvalidFields(errors)) => {
+                assert_eq!(errors, vec![FieldError::new("priority", DomainError::PriorityOutOfRange { priority: 7, min: 1, max: 5 })]);
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+        assert!(use_cases.get_tasks_by_priority(&user(), 5).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_requests_report_every_offending_field() {
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![create_test_task(1, "Task", Some(5))]));
+
+        let result = use_cases.create_task(&user(), CreateTaskRequest {
+            name: " ".to_string(),
+            priority: Some(11.into()),
+            description: Some("x".repeat(10_001)),
+        }).await;
+        match result {
+            Err(UseCaseError::InvalidFields(errors)) => {
+                let fields: Vec<_> = errors.iter().map(|error| (error.field.as_str(), error.code.as_str())).collect();
+                assert_eq!(fields, vec![("name", "empty_name"), ("priority", "priority_out_of_range"), ("description", "description_too_long")]);
+            }
+            other => panic!("expected field errors, got {:?}", other),
+        }
+
+        let result = use_cases.update_task(&user(), 1, UpdateTaskRequest {
+            name: None,
+            priority: Some(0.into()),
+            description: None,
+        }).await;
+        match result {
+            Err(UseCaseError::InvalidFields(errors)) => assert_eq!(errors[0].field, "priority"),
+            other => panic!("expected field errors, got {:?}", other),
+        }
+    }
+
+
+    #[tokio::test]
+    async fn test_priority_labels_follow_configured_scale() {
+        let existing = vec![create_test_task(1, "Narrow", Some(4))];
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(existing))
+            .with_priority_policy(PriorityPolicy::new(PriorityScale::new(1, 5).unwrap(), HighPriorityPolicy::default()).unwrap());
+
+        let task = use_cases.get_task_by_id(&user(), 1).await.unwrap();
+        assert_eq!(task.priority_label, Some(PriorityLabel::Low));
+
+        let created = use_cases.create_task(&user(), CreateTaskRequest {
+            name: "Labelled".to_string(),
+            priority: Some(PriorityInput::Label(PriorityLabel::Critical)),
+            description: None,
+        }).await;
+        assert!(created.is_ok());
+    }
+
+
+    #[tokio::test]
+    async fn test_high_priority_policy_drives_review_requirement() {
+        let mut task = create_test_task(1, "Needs review", Some(5));
+        task.transition_to(TaskStatus::InProgress).unwrap();
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![task]))
+            .with_priority_policy(PriorityPolicy::new(PriorityScale::full(), HighPriorityPolicy::new(5)).unwrap());
+
+        let transitions = use_cases.get_task_with_transitions(&user(), 1).await.unwrap();
+        assert!(!transitions.valid_transitions.contains(&TaskStatus::Completed));
+
+        let result = use_cases.update_task_status(&user(), 1, UpdateTaskStatusDto {
+            status: TaskStatus::Completed,
+            comment: None,
+            checklist: None,
+        }).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::ReviewRequired))));
+    }
+
+    #[tokio::test]
+    async fn test_narrow_scale_with_lower_threshold_completes_without_review() {
+        let mut task = create_test_task(1, "Routine", Some(2));
+        task.transition_to(TaskStatus::InProgress).unwrap();
+        let policy = PriorityPolicy::new(PriorityScale::new(1, 5).unwrap(), HighPriorityPolicy::new(1)).unwrap();
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![task]))
+            .with_priority_policy(policy);
+
+        let transitions = use_cases.get_task_with_transitions(&user(), 1).await.unwrap();
+        assert!(transitions.valid_transitions.contains(&TaskStatus::Completed));
+
+        let completed = use_cases.update_task_status(&user(), 1, UpdateTaskStatusDto {
+            status: TaskStatus::Completed,
+            comment: None,
+            checklist: None,
+        }).await.unwrap();
+        assert_eq!(completed.status, TaskStatus::Completed);
+    }
+
+
+    #[tokio::test]
+    async fn test_checklist_only_accepted_on_approval() {
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![create_test_task(1, "Task", Some(5))]))
+            .with_review_checklist(ReviewChecklist::parse("Tests pass"));
+
+        let result = use_cases.update_task_status(&user(), 1, UpdateTaskStatusDto {
+            status: TaskStatus::InProgress,
+            comment: None,
+            checklist: Some(vec![ChecklistItem { item: "Tests pass".to_string(), checked: true }]),
+        }).await;
+
+        match result {
+            Err(UseCaseError::Domain(error)) => assert_eq!(error, DomainError::ChecklistNotExpected),
+            other => panic!("expected domain error, got {:?}", other),
+        }
+    }
+
+
+
+    #[tokio::test]
+    async fn test_stale_pending_tasks_are_cancelled() {
+        let long_ago = Utc::now() - Duration::days(30);
+        let stale = Task::new_with_status(TaskId::new(1), "Forgotten".to_string(), Some(5), TaskStatus::Pending, long_ago, long_ago).unwrap();
+        let started = Task::new_with_status(TaskId::new(2), "Started".to_string(), Some(5), TaskStatus::InProgress, long_ago, long_ago).unwrap();
+        let fresh = create_test_task(3, "Fresh", Some(5));
+        let mock_repo = MockRepository::new().with_tasks(vec![stale, started, fresh]);
+
+        let disabled = create_use_cases_with_mock(mock_repo.clone());
+        assert!(disabled.find_stale_tasks(&user(), Utc::now()).await.unwrap().is_empty());
+
+        let use_cases = create_use_cases_with_mock(mock_repo.clone())
+            .with_stale_task_policy(StaleTaskPolicy::new(14).unwrap());
+
+        let preview = use_cases.find_stale_tasks(&user(), Utc::now()).await.unwrap();
+        assert_eq!(preview.iter().map(|task| task.id).collect::<Vec<_>>(), vec![1]);
+        assert!(mock_repo.recorded_history().is_empty());
+
+        let cancelled = use_cases.cancel_stale_tasks(&RequestContext::system(&SystemActor::new(SystemActor::STALE_TASK_SWEEP)), Utc::now()).await.unwrap();
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].status, TaskStatus::Cancelled);
+
+        let history = mock_repo.recorded_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].changed_by, "system:stale-task-sweep");
+        assert_eq!(history[0].user_role, UserRole::System);
+        assert_eq!(history[0].comment.as_deref(), Some("Automatically cancelled after 14 days in Pending without activity"));
+    }
+
+
+
+    #[tokio::test]
+    async fn test_archived_tasks_leave_the_listings_and_stop_changing() {
+        let tasks = InMemoryTaskRepository::new();
+        let now = Utc::now();
+        tasks.save(&Task::new_with_status(TaskId::new(0), "Shipped".to_string(), Some(5), TaskStatus::Completed, now, now).unwrap()).await.unwrap();
+        tasks.save(&create_test_task(0, "Open", Some(5))).await.unwrap();
+        let use_cases = TaskUseCases::new(Arc::new(tasks.clone()), Arc::new(tasks.history_repository()), Arc::new(MockTaskDependencyRepository::default()));
+
+        match use_cases.archive_task(&manager(), 2).await {
+            Err(UseCaseError::Domain(error)) => assert_eq!(error, DomainError::ArchiveUnfinished { status: TaskStatus::Pending }),
+            other => panic!("expected unfinished tasks to stay active, got {:?}", other),
+        }
+
+        let archived = use_cases.archive_task(&manager(), 1).await.unwrap();
+        assert!(archived.archived_at.is_some());
+
+        let active = use_cases.list_tasks(&manager(), TaskFilter::default(), TaskSort::default()).await.unwrap();
+        assert_eq!(active.iter().map(|task| task.id).collect::<Vec<_>>(), vec![2]);
+        let archive = use_cases.list_tasks(&manager(), TaskFilter { archived: true, ..TaskFilter::default() }, TaskSort::default()).await.unwrap();
+        assert_eq!(archive.iter().map(|task| task.id).collect::<Vec<_>>(), vec![1]);
+
+        let rename = use_cases.update_task(&manager(), 1, UpdateTaskRequest { name: Some("Renamed".to_string()), priority: None, description: None }).await;
+        assert!(matches!(rename, Err(UseCaseError::Domain(DomainError::TaskArchived))));
+        assert!(matches!(use_cases.archive_task(&manager(), 1).await, Err(UseCaseError::Domain(DomainError::TaskArchived))));
+        assert!(use_cases.get_task_with_transitions(&manager(), 1).await.unwrap().valid_transitions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_tasks_completed_long_ago_are_archived() {
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let tasks = InMemoryTaskRepository::new();
+        tasks.save(&Task::new_with_status(TaskId::new(0), "Old".to_string(), Some(5), TaskStatus::Completed, start, start).unwrap()).await.unwrap();
+        let recent = start + Duration::days(20);
+        tasks.save(&Task::new_with_status(TaskId::new(0), "Recent".to_string(), Some(5), TaskStatus::Completed, recent, recent).unwrap()).await.unwrap();
+        tasks.save(&Task::new_with_status(TaskId::new(0), "Cancelled".to_string(), Some(5), TaskStatus::Cancelled, start, start).unwrap()).await.unwrap();
+        let now = start + Duration::days(31);
+        let use_cases = || TaskUseCases::new(Arc::new(tasks.clone()), Arc::new(tasks.history_repository()), Arc::new(MockTaskDependencyRepository::default()));
+        let sweep = RequestContext::system(&SystemActor::new(SystemActor::AUTO_ARCHIVE));
+
+        assert!(use_cases().archive_completed_tasks(&sweep, now).await.unwrap().is_empty());
+
+        let use_cases = use_cases().with_auto_archive_policy(AutoArchivePolicy::new(30).unwrap());
+        let archived = use_cases.archive_completed_tasks(&sweep, now).await.unwrap();
+        assert_eq!(archived.iter().map(|task| task.id).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(archived[0].archived_at, Some(now));
+        assert!(use_cases.archive_completed_tasks(&sweep, now).await.unwrap().is_empty());
+    }
+
+
+
+    #[tokio::test]
+    async fn test_stuck_tasks_are_escalated_once_per_threshold() {
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let now = start + Duration::days(5);
+        let tasks = InMemoryTaskRepository::new();
+        for (name, priority, status, updated_at) in [
+            ("Stuck", Some(3), TaskStatus::InProgress, start),
+            ("Top", Some(1), TaskStatus::InProgress, start),
+            ("Fresh", Some(3), TaskStatus::InProgress, now - Duration::hours(1)),
+            ("Waiting", None, TaskStatus::PendingReview, start),
+        ] {
+            tasks.save(&Task::new_with_status(TaskId::new(0), name.to_string(), priority, status, start, updated_at).unwrap()).await.unwrap();
+        }
+        let history = tasks.history_repository();
+        let use_cases = || TaskUseCases::new(Arc::new(tasks.clone()), Arc::new(history.clone()), Arc::new(MockTaskDependencyRepository::default()));
+        let sweep = RequestContext::system(&SystemActor::new(SystemActor::ESCALATION));
+
+        assert!(use_cases().escalate_stuck_tasks(&sweep, now).await.unwrap().is_empty());
+
+        let policy = EscalationPolicy::parse("InProgress:3d:bump_priority,PendingReview:2d:comment").unwrap();
+        let use_cases = use_cases().with_escalation_policy(policy);
+        let escalated = use_cases.escalate_stuck_tasks(&sweep, now).await.unwrap();
+        let summary: Vec<_> = escalated.iter().map(|task| (task.id, task.priority, task.status.clone())).collect();
+        assert_eq!(summary, vec![(1, Some(2), TaskStatus::InProgress), (2, Some(1), TaskStatus::InProgress), (4, None, TaskStatus::PendingReview)]);
+
+        let entries = history.find_by_task_id(1).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!((entries[0].from_status.clone(), entries[0].to_status.clone()), (Some(TaskStatus::InProgress), TaskStatus::InProgress));
+        assert_eq!((entries[0].changed_by.as_str(), &entries[0].user_role), ("system:escalation", &UserRole::System));
+        assert_eq!(entries[0].comment.as_deref(), Some("Escalated after more than 3 days in InProgress; priority raised from 3 to 2"));
+        assert_eq!(history.find_by_task_id(2).await.unwrap()[0].comment.as_deref(), Some("Escalated after more than 3 days in InProgress; priority already at the most urgent, 1"));
+        assert_eq!(history.find_by_task_id(4).await.unwrap()[0].comment.as_deref(), Some("Escalated after more than 2 days in PendingReview"));
+        assert_eq!(tasks.find_by_id(TaskId::new(4)).await.unwrap().unwrap().updated_at, start);
+
+        // The escalation entries restart each task's clock
+        assert!(use_cases.escalate_stuck_tasks(&sweep, now + Duration::days(1)).await.unwrap().is_empty());
+        let again = use_cases.escalate_stuck_tasks(&sweep, now + Duration::days(3) + Duration::hours(1)).await.unwrap();
+        assert_eq!(again.iter().map(|task| (task.id, task.priority)).collect::<Vec<_>>(), vec![(1, Some(1)), (2, Some(1)), (3, Some(2)), (4, None)]);
+    }
+
+    #[tokio::test]
+    async fn test_api_keys_are_issued_at_the_clocks_time() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let use_cases = ApiKeyUseCases::new(Arc::new(MockApiKeyRepository::default())).with_clock(Arc::new(FrozenClock::new(now)));
+        let admin = RequestContext::anonymous().with_user("ops", UserRole::Admin);
+
+        let created = use_cases.create_api_key(&admin, CreateApiKeyRequest { name: "ci-bot".to_string(), role: None }).await.unwrap();
+
+        assert_eq!(created.created_at, now);
+        let key = use_cases.authenticate(&created.key).await.unwrap().unwrap();
+        assert_eq!(key.created_at, now);
+    }
+
+    #[tokio::test]
+    async fn test_analytics_gauges_count_open_stale_and_review_tasks() {
+        let long_ago = Utc::now() - Duration::days(30);
+        let stale = Task::new_with_status(TaskId::new(1), "Forgotten".to_string(), Some(5), TaskStatus::Pending, long_ago, long_ago).unwrap();
+        let review = Task::new_with_status(TaskId::new(2), "Review".to_string(), Some(5), TaskStatus::PendingReview, long_ago, long_ago).unwrap();
+        let done = Task::new_with_status(TaskId::new(3), "Done".to_string(), Some(5), TaskStatus::Completed, long_ago, long_ago).unwrap();
+        let fresh = create_test_task(4, "Fresh", Some(5));
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![stale, review, done, fresh]))
+            .with_stale_task_policy(StaleTaskPolicy::new(14).unwrap());
+
+        let gauges = use_cases.get_analytics_gauges(&user(), Utc::now()).await.unwrap();
+        assert_eq!(gauges.open_tasks, 3);
+        assert_eq!(gauges.open_tasks_by_status["Pending"], 2);
+        assert_eq!(gauges.open_tasks_by_status["InProgress"], 0);
+        assert_eq!(gauges.stale_tasks, 1);
+        assert_eq!(gauges.approval_backlog, 1);
+    }
+
+
+
+    #[tokio::test]
+    async fn test_reopening_requires_a_manager() {
+        let mut task = create_test_task(1, "Done", Some(5));
+        task.transition_to(TaskStatus::InProgress).unwrap();
+        task.transition_to(TaskStatus::Completed).unwrap();
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![task]));
+
+        let transitions = use_cases.get_task_with_transitions(&user(), 1).await.unwrap();
+        assert!(transitions.valid_transitions.is_empty());
+
+        let result = use_cases.update_task_status(&user(), 1, UpdateTaskStatusDto {
+            status: TaskStatus::InProgress,
+            comment: Some("Needs rework".to_string()),
+            checklist: None,
+        }).await;
+
+        match result {
+            Err(UseCaseError::Domain(error)) => assert_eq!(error, DomainError::ReopenNotAllowed),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+
+
+    #[tokio::test]
+    async fn test_frozen_clock_stamps_changes_and_drives_the_reopen_window() {
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let done = Task::new_with_status(TaskId::new(1), "Done".to_string(), Some(5), TaskStatus::Completed, start, start).unwrap();
+        let mock_repo = MockRepository::new().with_tasks(vec![done]);
+        let clock = FrozenClock::new(start);
+        let use_cases = create_use_cases_with_mock(mock_repo.clone()).with_clock(Arc::new(clock.clone()));
+
+        use_cases.create_task(&user(), CreateTaskRequest {
+            name: "Timed".to_string(),
+            priority: None,
+            description: None,
+        }).await.unwrap();
+        assert_eq!(mock_repo.recorded_history()[0].changed_at, start);
+
+        let reviewer = manager();
+        let reopen = || use_cases.update_task_status(&reviewer, 1, UpdateTaskStatusDto {
+            status: TaskStatus::InProgress,
+            comment: Some("Needs rework".to_string()),
+            checklist: None,
+        });
+        clock.advance(Duration::days(15));
+        match reopen().await {
+            Err(UseCaseError::Domain(error)) => assert_eq!(error, DomainError::ReopenWindowExpired { window_days: 14 }),
+            other => panic!("expected the reopen window to have closed, got {:?}", other),
+        }
+
+        clock.set(start + Duration::days(13));
+        let reopened = reopen().await.unwrap();
+        assert_eq!(reopened.status, TaskStatus::InProgress);
+        assert_eq!(reopened.updated_at, start + Duration::days(13));
+    }
+
+
+
+    #[tokio::test]
+    async fn test_transitions_are_served_while_history_is_unavailable() {
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let done = Task::new_with_status(TaskId::new(1), "Done".to_string(), Some(5), TaskStatus::Completed, start, start).unwrap();
+        let mock_repo = MockRepository::new().with_tasks(vec![done, create_test_task(2, "Open", Some(5))]);
+        let clock = FrozenClock::new(start + Duration::days(3));
+        let use_cases = TaskUseCases::new(Arc::new(mock_repo), Arc::new(UnavailableStatusHistoryRepository), Arc::new(MockTaskDependencyRepository::default()))
+            .with_clock(Arc::new(clock.clone()));
+
+        let transitions = use_cases.get_task_with_transitions(&manager(), 1).await.unwrap();
+        assert!(transitions.history_unavailable);
+        assert_eq!(transitions.task.status, TaskStatus::Completed);
+        assert!(transitions.valid_transitions.contains(&TaskStatus::InProgress));
+
+        clock.advance(Duration::days(15));
+        let transitions = use_cases.get_task_with_transitions(&manager(), 1).await.unwrap();
+        assert!(!transitions.valid_transitions.contains(&TaskStatus::InProgress));
+
+        assert!(!use_cases.get_task_with_transitions(&manager(), 2).await.unwrap().history_unavailable);
+        assert!(matches!(use_cases.get_task_history(&manager(), 1, Default::default(), Default::default(), Default::default(), None).await, Err(UseCaseError::RepositoryError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_use_case_calls_are_counted_against_their_budgets() {
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![create_test_task(1, "Task", Some(5))]));
+
+        use_cases.get_task_by_id(&user(), 1).await.unwrap();
+        assert!(use_cases.get_task_by_id(&user(), 2).await.is_err());
+        use_cases.search_tasks(&user(), "task", None).await.unwrap();
+
+        let stats = use_cases.latency_budgets().stats();
+        assert_eq!(stats["get_task_by_id"].calls, 2);
+        assert_eq!(stats["search_tasks"].calls, 1);
+        assert!(!stats.contains_key("list_tasks"));
+    }
+
+    #[tokio::test]
+    async fn test_history_ids_come_from_the_id_generator() {
+        let mock_repo = MockRepository::new().with_tasks(vec![create_test_task(1, "Task", Some(5))]);
+        let use_cases = create_use_cases_with_mock(mock_repo.clone()).with_id_generator(Arc::new(SequentialIdGenerator::new()));
+
+        use_cases.create_task(&user(), CreateTaskRequest { name: "New".to_string(), priority: None, description: None }).await.unwrap();
+        use_cases.update_task_status(&user(), 1, UpdateTaskStatusDto { status: TaskStatus::InProgress, comment: None, checklist: None }).await.unwrap();
+
+        let ids: Vec<_> = mock_repo.recorded_history().into_iter().map(|entry| entry.id).collect();
+        assert_eq!(ids, vec!["00000000-0000-0000-0000-000000000001", "00000000-0000-0000-0000-000000000002"]);
+    }
+
+
+
+    #[tokio::test]
+    async fn test_creation_policies_run_in_order() {
+        let mut done = create_test_task(2, "Ship it", Some(5));
+        done.transition_to(TaskStatus::InProgress).unwrap();
+        done.transition_to(TaskStatus::Completed).unwrap();
+        let mock_repo = MockRepository::new().with_tasks(vec![create_test_task(1, "Write docs", Some(5)), done]);
+        let use_cases = create_use_cases_with_mock(mock_repo.clone())
+            .with_creation_policy(Arc::new(ContentPolicy::new(vec!["secret".to_string()])))
+            .with_creation_policy(Arc::new(DuplicateNamePolicy))
+            .with_creation_policy(Arc::new(OpenTaskQuotaPolicy::new(2)));
+
+        let request = |name: &str, description: Option<&str>| CreateTaskRequest {
+            name: name.to_string(),
+            priority: Some(5.into()),
+            description: description.map(str::to_string),
+        };
+        let rejection = |result: Result<i32, UseCaseError>| match result {
+            Err(UseCaseError::ValidationError(msg)) => msg,
+            other => panic!("expected validation error, got {:?}", other),
+        };
+
+        assert_eq!(
+            rejection(use_cases.create_task(&user(), request(" write DOCS ", Some("top SECRET"))).await),
+            "Task contains blocked term: secret"
+        );
+        assert_eq!(
+            rejection(use_cases.create_task(&user(), request(" write DOCS ", None)).await),
+            "An open task with this name already exists (id 1)"
+        );
+        // Completed tasks count neither as duplicates nor against the quota
+        assert!(use_cases.create_task(&user(), request("Ship it", None)).await.is_ok());
+        assert_eq!(mock_repo.recorded_history().len(), 1);
+
+        let full = create_use_cases_with_mock(mock_repo.clone())
+            .with_creation_policy(Arc::new(OpenTaskQuotaPolicy::new(1)));
+        assert_eq!(
+            rejection(full.create_task(&user(), request("One more", None)).await),
+            "Open task limit of 1 reached"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_custom_creation_policy() {
+        struct RequirePriority;
+
+        #[async_trait::async_trait]
+        impl CreationPolicy for RequirePriority {
+            async fn check(&self, task: &Task, _tasks: &dyn TaskRepository) -> Result<(), UseCaseError> {
+                match task.priority {
+                    Some(_) => Ok(()),
+                    None => Err(UseCaseError::ValidationError("Priority is required".to_string())),
+                }
+            }
+        }
+
+        let use_cases = create_use_cases_with_mock(MockRepository::new())
+            .with_creation_policy(Arc::new(RequirePriority));
+
+        let result = use_cases.create_task(&user(), CreateTaskRequest {
+            name: "No priority".to_string(),
+            priority: None,
+            description: None,
+        }).await;
+        assert!(matches!(result, Err(UseCaseError::ValidationError(msg)) if msg == "Priority is required"));
+    }
+
+
+
+    #[tokio::test]
+    async fn test_manager_approval_requires_checklist_and_records_actor() {
+        let mut task = create_test_task(1, "Urgent", Some(1));
+        task.transition_to(TaskStatus::InProgress).unwrap();
+        task.transition_to(TaskStatus::PendingReview).unwrap();
+        let mock_repo = MockRepository::new().with_tasks(vec![task]);
+        let use_cases = create_use_cases_with_mock(mock_repo.clone())
+            .with_review_checklist(ReviewChecklist::parse("Tests pass"));
+        let approve = |checklist| UpdateTaskStatusDto {
+            status: TaskStatus::Completed,
+            comment: Some("Looks good".to_string()),
+            checklist,
+        };
+
+        let result = use_cases.update_task_status(&user(), 1, approve(None)).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::ApprovalNotAllowed))));
+
+        let result = use_cases.update_task_status(&manager(), 1, approve(None)).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::ChecklistRequired { .. }))));
+
+        let checklist = vec![ChecklistItem { item: "Tests pass".to_string(), checked: true }];
+        let task = use_cases.update_task_status(&manager(), 1, approve(Some(checklist.clone()))).await.unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+
+        let history = mock_repo.recorded_history();
+        assert_eq!(history[0].changed_by, "reviewer");
+        assert_eq!(history[0].user_role, UserRole::Manager);
+        assert_eq!(history[0].checklist, Some(checklist));
+    }
+
+    #[tokio::test]
+    async fn test_review_chain_collects_each_sign_off_before_completion() {
+        let tasks = InMemoryTaskRepository::new();
+        tasks.save(&create_test_task(0, "Release", Some(1))).await.unwrap();
+        let history = tasks.history_repository();
+        let use_cases = TaskUseCases::new(Arc::new(tasks.clone()), Arc::new(history.clone()), Arc::new(MockTaskDependencyRepository::default()))
+            .with_review_chains(ReviewChains::parse("default=peer:User,approval:Manager").unwrap());
+        let alice = RequestContext::anonymous().with_user("alice", UserRole::User);
+        let sign_off = || UpdateTaskStatusDto { status: TaskStatus::Completed, comment: Some("Reviewed".to_string()), checklist: None };
+
+        for status in [TaskStatus::InProgress, TaskStatus::PendingReview] {
+            use_cases.update_task_status(&alice, 1, UpdateTaskStatusDto { status, comment: None, checklist: None }).await.unwrap();
+        }
+        let peer_stage = use_cases.get_task_with_transitions(&alice, 1).await.unwrap();
+        assert!(peer_stage.valid_transitions.contains(&TaskStatus::Completed));
+        assert_eq!(peer_stage.review.map(|review| (review.stage, review.stage_number)), Some(("peer".to_string(), 1)));
+
+        let task = use_cases.update_task_status(&alice, 1, sign_off()).await.unwrap();
+        assert_eq!(task.status, TaskStatus::PendingReview);
+
+        let approval_stage = use_cases.get_task_with_transitions(&manager(), 1).await.unwrap();
+        assert!(approval_stage.valid_transitions.contains(&TaskStatus::Completed));
+        let review = approval_stage.review.unwrap();
+        assert_eq!((review.stage.as_str(), review.required_role.as_str()), ("approval", "Manager"));
+        assert_eq!(review.signed_off_by, vec!["alice".to_string()]);
+        assert!(!use_cases.get_task_with_transitions(&alice, 1).await.unwrap().valid_transitions.contains(&TaskStatus::Completed));
+
+        let result = use_cases.update_task_status(&alice, 1, sign_off()).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::ApprovalNotAllowed))));
+        let result = use_cases.update_task_status(&alice.clone().with_user("alice", UserRole::Manager), 1, sign_off()).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::ReviewerAlreadySignedOff { .. }))));
+
+        let task = use_cases.update_task_status(&manager(), 1, sign_off()).await.unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+        let stages: Vec<_> = history.find_by_task_id(1).await.unwrap().into_iter().filter_map(|entry| entry.review_stage).collect();
+        assert_eq!(stages, vec!["peer", "approval"]);
+    }
+
+    #[tokio::test]
+    async fn test_earlier_stage_sign_offs_are_not_counted_as_transitions() {
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let reviewed = |chains: Option<&'static str>| async move {
+            let tasks = InMemoryTaskRepository::new();
+            let history = tasks.history_repository();
+            let clock = FrozenClock::new(start);
+            let mut use_cases = TaskUseCases::new(Arc::new(tasks), Arc::new(history), Arc::new(MockTaskDependencyRepository::default()))
+                .with_clock(Arc::new(clock.clone()));
+            if let Some(chains) = chains {
+                use_cases = use_cases.with_review_chains(ReviewChains::parse(chains).unwrap());
+            }
+            let alice = RequestContext::anonymous().with_user("alice", UserRole::User);
+            let id = use_cases.create_task(&alice, CreateTaskRequest { name: "Release".to_string(), priority: Some(1.into()), description: None }).await.unwrap();
+            let mut steps = vec![(1, &alice, TaskStatus::InProgress), (3, &alice, TaskStatus::PendingReview)];
+            let reviewer = manager();
+            if chains.is_some() {
+                steps.push((10, &alice, TaskStatus::Completed));
+            }
+            steps.push((12, &reviewer, TaskStatus::Completed));
+            for (hours, ctx, status) in steps {
+                clock.set(start + Duration::hours(hours));
+                use_cases.update_task_status(ctx, id, UpdateTaskStatusDto { status, comment: Some("Reviewed".to_string()), checklist: None }).await.unwrap();
+            }
+            let analytics = use_cases.get_task_analytics(&reviewer, id).await.unwrap();
+            let matrix = use_cases.get_transition_matrix(&reviewer, start, start + Duration::days(1)).await.unwrap();
+            (analytics, matrix)
+        };
+
+        let (chained, matrix) = reviewed(Some("default=peer:User,approval:Manager")).await;
+        let (single, _) = reviewed(None).await;
+
+        assert_eq!(chained.approval_time, single.approval_time);
+        assert_eq!(chained.total_time_in_progress, single.total_time_in_progress);
+        assert_eq!(chained.number_of_transitions, 4);
+        assert!(chained.was_approved);
+        assert_eq!(matrix.total_transitions, 3);
+        assert!(!matrix.matrix["PendingReview"].contains_key("PendingReview"));
+    }
+
+    #[tokio::test]
+    async fn test_review_stages_need_their_role() {
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![in_review(1)]))
+            .with_review_chains(ReviewChains::parse("*=approval:Admin").unwrap());
+
+        let result = use_cases.update_task_status(&manager(), 1, approve()).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::ReviewStageNotAllowed { .. }))));
+        assert!(!use_cases.get_task_with_transitions(&manager(), 1).await.unwrap().valid_transitions.contains(&TaskStatus::Completed));
+    }
+
+    fn in_review(id: i32) -> Task {
+        let mut task = create_test_task(id, "Release", Some(1));
+        task.transition_to(TaskStatus::InProgress).unwrap();
+        task.transition_to(TaskStatus::PendingReview).unwrap();
+        task
+    }
+
+    fn gated_use_cases(mock_repo: MockRepository, gate: &MockTransitionGate, fallback: GateFallback) -> TaskUseCases {
+        create_use_cases_with_mock(mock_repo)
+            .with_transition_gate(Arc::new(gate.clone()), vec![(TaskStatus::PendingReview, TaskStatus::Completed)], fallback)
+    }
+
+    fn approve() -> UpdateTaskStatusDto {
+        UpdateTaskStatusDto { status: TaskStatus::Completed, comment: Some("Ship it".to_string()), checklist: None }
+    }
+
+    #[tokio::test]
+    async fn test_gated_transitions_wait_for_the_approval_system() {
+        let gate = MockTransitionGate::answering(GateDecision::Approved);
+        let use_cases = gated_use_cases(MockRepository::new().with_tasks(vec![in_review(1)]), &gate, GateFallback::Deny);
+
+        let task = use_cases.update_task_status(&manager(), 1, approve()).await.unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+
+        let reviewed = gate.reviewed.lock().unwrap().clone();
+        assert_eq!(reviewed.len(), 1);
+        assert_eq!((reviewed[0].task_id, reviewed[0].from.clone(), reviewed[0].to.clone()), (1, TaskStatus::PendingReview, TaskStatus::Completed));
+        assert_eq!(reviewed[0].requested_by, "reviewer");
+        assert_eq!(reviewed[0].comment.as_deref(), Some("Ship it"));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_transitions_leave_the_task_unchanged() {
+        let gate = MockTransitionGate::answering(GateDecision::Rejected { reason: "Change freeze".to_string() });
+        let mock_repo = MockRepository::new().with_tasks(vec![in_review(1)]);
+        let use_cases = gated_use_cases(mock_repo.clone(), &gate, GateFallback::Deny);
+
+        let result = use_cases.update_task_status(&manager(), 1, approve()).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::TransitionRejected { ref reason })) if reason == "Change freeze"));
+        assert!(mock_repo.recorded_history().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_gate_applies_the_configured_fallback() {
+        let gate = MockTransitionGate::unreachable();
+
+        let denying = gated_use_cases(MockRepository::new().with_tasks(vec![in_review(1)]), &gate, GateFallback::Deny);
+        let result = denying.update_task_status(&manager(), 1, approve()).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::TransitionGateUnavailable))));
+
+        let allowing = gated_use_cases(MockRepository::new().with_tasks(vec![in_review(1)]), &gate, GateFallback::Allow);
+        let task = allowing.update_task_status(&manager(), 1, approve()).await.unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_only_listed_transitions_and_locally_valid_ones_reach_the_gate() {
+        let gate = MockTransitionGate::answering(GateDecision::Rejected { reason: "Never".to_string() });
+        let use_cases = gated_use_cases(MockRepository::new().with_tasks(vec![create_test_task(1, "Start", Some(5)), in_review(2)]), &gate, GateFallback::Deny);
+
+        use_cases.update_task_status(&user(), 1, UpdateTaskStatusDto { status: TaskStatus::InProgress, comment: None, checklist: None }).await.unwrap();
+
+        // A member may not approve, so the gate is never asked
+        let result = use_cases.update_task_status(&user(), 2, approve()).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::ApprovalNotAllowed))));
+
+        assert!(gate.reviewed.lock().unwrap().is_empty());
+    }
+
+
+    #[tokio::test]
+    async fn test_transitions_that_require_a_comment_reject_blank_ones() {
+        let mock_repo = MockRepository::new().with_tasks(vec![
+            create_test_task(1, "Abandoned", Some(5)),
+        ]);
+        let use_cases = create_use_cases_with_mock(mock_repo.clone());
+        let cancel = |comment: Option<&str>| UpdateTaskStatusDto {
+            status: TaskStatus::Cancelled,
+            comment: comment.map(str::to_string),
+            checklist: None,
+        };
+
+        for comment in [None, Some("   ")] {
+            let result = use_cases.update_task_status(&user(), 1, cancel(comment)).await;
+            assert!(matches!(result, Err(UseCaseError::Domain(error)) if error.to_string() == "A comment is required to move a task from Pending to Cancelled"));
+        }
+        assert!(mock_repo.recorded_history().is_empty());
+
+        use_cases.update_task_status(&user(), 1, cancel(Some("  Duplicate of #2 "))).await.unwrap();
+        assert_eq!(mock_repo.recorded_history()[0].comment.as_deref(), Some("Duplicate of #2"));
+    }
+
+
+    #[tokio::test]
+    async fn test_unfinished_dependencies_block_starting_a_task() {
+        let mut finished_blocker = create_test_task(3, "Done", Some(5));
+        finished_blocker.transition_to(TaskStatus::InProgress).unwrap();
+        finished_blocker.transition_to(TaskStatus::Completed).unwrap();
+        let mock_repo = MockRepository::new().with_tasks(vec![
+            create_test_task(1, "Blocked", Some(5)),
+            create_test_task(2, "Blocker", Some(5)),
+            finished_blocker,
+        ]);
+        let dependencies = MockTaskDependencyRepository::with_dependencies(vec![(1, 2), (1, 3)]);
+        let use_cases = TaskUseCases::new(Arc::new(mock_repo), Arc::new(MockStatusHistoryRepository), Arc::new(dependencies));
+
+        let transitions = use_cases.get_task_with_transitions(&user(), 1).await.unwrap();
+        assert_eq!(transitions.blocked_by, vec![2]);
+        assert_eq!(transitions.valid_transitions, vec![TaskStatus::Cancelled]);
+
+        let result = use_cases.update_task_status(&user(), 1, UpdateTaskStatusDto {
+            status: TaskStatus::InProgress,
+            comment: None,
+            checklist: None,
+        }).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::BlockedByUnfinishedTasks { blockers })) if blockers == vec![2]));
+
+        let dependencies = use_cases.remove_dependency(&user(), 1, 2).await.unwrap();
+        assert_eq!(dependencies.depends_on, vec![3]);
+        assert!(dependencies.blocked_by.is_empty());
+        assert!(use_cases.get_task_with_transitions(&user(), 1).await.unwrap().valid_transitions.contains(&TaskStatus::InProgress));
+    }
+
+    #[tokio::test]
+    async fn test_add_dependency_rejects_cycles() {
+        let mock_repo = MockRepository::new().with_tasks(vec![
+            create_test_task(1, "First", Some(5)),
+            create_test_task(2, "Second", Some(5)),
+            create_test_task(3, "Third", Some(5)),
+        ]);
+        let dependencies = MockTaskDependencyRepository::with_dependencies(vec![(2, 3)]);
+        let use_cases = TaskUseCases::new(Arc::new(mock_repo), Arc::new(MockStatusHistoryRepository), Arc::new(dependencies));
+        let depends_on = |id| AddDependencyRequest { depends_on: id };
+
+        let added = use_cases.add_dependency(&user(), 3, depends_on(1)).await.unwrap();
+        assert_eq!(added.blocked_by, vec![1]);
+
+        let result = use_cases.add_dependency(&user(), 1, depends_on(2)).await;
+        assert!(matches!(result, Err(UseCaseError::Domain(DomainError::DependencyCycle { .. }))));
+        assert!(matches!(use_cases.add_dependency(&user(), 1, depends_on(1)).await, Err(UseCaseError::Domain(DomainError::SelfDependency))));
+        assert!(matches!(use_cases.add_dependency(&user(), 1, depends_on(9)).await, Err(UseCaseError::NotFound(_))));
+    }
+
+
+    #[tokio::test]
+    async fn test_only_the_owner_or_a_manager_changes_a_tasks_dependencies() {
+        let owned = |id, name: &str| create_test_task(id, name, Some(5)).with_created_by(Some("alice".to_string()));
+        let mock_repo = MockRepository::new().with_tasks(vec![owned(1, "Alice's"), owned(2, "Also Alice's")]);
+        let dependencies = MockTaskDependencyRepository::with_dependencies(vec![(1, 2)]);
+        let use_cases = TaskUseCases::new(Arc::new(mock_repo), Arc::new(MockStatusHistoryRepository), Arc::new(dependencies));
+        let bob = RequestContext::anonymous().with_user("bob", UserRole::User);
+
+        let result = use_cases.add_dependency(&bob, 2, AddDependencyRequest { depends_on: 1 }).await;
+        assert!(matches!(result, Err(UseCaseError::Forbidden(_))));
+        assert!(matches!(use_cases.remove_dependency(&bob, 1, 2).await, Err(UseCaseError::Forbidden(_))));
+
+        let alice = RequestContext::anonymous().with_user("alice", UserRole::User);
+        assert!(use_cases.remove_dependency(&alice, 1, 2).await.unwrap().depends_on.is_empty());
+        assert_eq!(use_cases.add_dependency(&manager(), 2, AddDependencyRequest { depends_on: 1 }).await.unwrap().depends_on, vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_use_cases_over_a_concrete_repository_type() {
+        let use_cases: TaskUseCases<MockRepository> = TaskUseCases::with_repository(
+            Arc::new(MockRepository::new().with_tasks(vec![create_test_task(1, "Static", Some(5))])),
+            Arc::new(MockStatusHistoryRepository),
+            Arc::new(MockTaskDependencyRepository::default()),
+        )
+        .with_creation_policy(Arc::new(OpenTaskQuotaPolicy::new(1)));
+
+        assert_eq!(use_cases.get_task_by_id(&user(), 1).await.unwrap().name, "Static");
+
+        let result = use_cases.create_task(&user(), CreateTaskRequest {
+            name: "Over quota".to_string(),
+            priority: Some(5.into()),
+            description: None,
+        }).await;
+        assert!(matches!(result, Err(UseCaseError::ValidationError(_))));
+    }
+
+
+    #[tokio::test]
+    async fn test_reports_stop_once_the_request_is_cancelled() {
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![create_test_task(1, "Done", Some(5))]));
+        let ctx = user();
+        ctx.cancellation.cancel();
+
+        let result = use_cases.get_completion_analytics(&ctx, Utc::now() - Duration::days(7), Utc::now()).await;
+        assert!(matches!(result, Err(UseCaseError::Cancelled(_))));
+        assert!(use_cases.get_completion_analytics(&user(), Utc::now() - Duration::days(7), Utc::now()).await.is_ok());
+    }
+
+
+    #[tokio::test]
+    async fn test_task_changes_are_published_as_domain_events() {
+        let event_bus = InMemoryEventPublisher::default();
+        let mut events = event_bus.subscribe();
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![create_test_task(1, "Existing", Some(5))]))
+            .with_event_publisher(Arc::new(event_bus));
+
+        let created_id = use_cases.create_task(&manager(), CreateTaskRequest {
+            name: "Evented".to_string(),
+            priority: Some(5.into()),
+            description: None,
+        }).await.unwrap();
+        let id = 1;
+        use_cases.update_task_status(&manager(), id, UpdateTaskStatusDto {
+            status: TaskStatus::InProgress,
+            comment: None,
+            checklist: None,
+        }).await.unwrap();
+        use_cases.delete_task(&manager(), id).await.unwrap();
+
+        let created = events.recv().await.unwrap();
+        assert_eq!(created.changed_by, "reviewer");
+        assert!(matches!(created.event, TaskEvent::TaskCreated(ref event) if event.task_id == created_id && event.name == "Evented"));
+        match events.recv().await.unwrap().event {
+            TaskEvent::TaskStatusChanged(event) => {
+                assert_eq!(event.from_status, TaskStatus::Pending);
+                assert_eq!(event.to_status, TaskStatus::InProgress);
+            }
+            other => panic!("Expected a status change, got {:?}", other),
+        }
+        assert!(matches!(events.recv().await.unwrap().event, TaskEvent::TaskDeleted(ref event) if event.task_id == id));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_reprioritization_previews_then_audits_each_task() {
+        let event_bus = InMemoryEventPublisher::default();
+        let mut events = event_bus.subscribe();
+        let use_cases = create_use_cases_with_mock(MockRepository::new().with_tasks(vec![
+            create_test_task(1, "Low", Some(2)),
+            create_test_task(2, "Top", Some(10)),
+            create_test_task(3, "Unprioritized", None),
+            create_test_task(4, "Also low", Some(2)),
+        ]))
+            .with_event_publisher(Arc::new(event_bus));
+        let admin = RequestContext::anonymous().with_user("ops", UserRole::Admin);
+        let request = |dry_run| ReprioritizeRequest { status: None, priority: None, shift: Some(1), mapping: None, dry_run };
+
+        let refused = use_cases.reprioritize_tasks(&manager(), request(false)).await;
+        assert!(matches!(refused, Err(UseCaseError::Forbidden(_))));
+
+        let preview = use_cases.reprioritize_tasks(&admin, request(true)).await.unwrap();
+        assert_eq!(preview.affected, 2);
+        assert_eq!((preview.moves[0].from, preview.moves[0].to, preview.moves[0].count), (2, 3, 2));
+        assert!(events.try_recv().is_err());
+
+        let applied = use_cases.reprioritize_tasks(&admin, request(false)).await.unwrap();
+        assert_eq!(applied.task_ids, vec![1, 4]);
+        match events.recv().await.unwrap().event {
+            TaskEvent::TaskUpdated(event) => {
+                assert_eq!(event.task_id, 1);
+                assert_eq!(event.changes[0].field, TaskField::Priority);
+                assert_eq!(event.changes[0].new_value.as_deref(), Some("3"));
+            }
+            other => panic!("Expected a task update, got {:?}", other),
+        }
+
+        let both = ReprioritizeRequest { shift: Some(1), mapping: Some(BTreeMap::from([(2, 5)])), ..request(false) };
+        assert!(use_cases.reprioritize_tasks(&admin, both).await.is_err());
+        let off_scale = ReprioritizeRequest { shift: None, mapping: Some(BTreeMap::from([(2, 11)])), ..request(false) };
+        assert!(matches!(
+            use_cases.reprioritize_tasks(&admin, off_scale).await,
+            Err(UseCaseError::Domain(DomainError::PriorityOutOfRange { .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_import_keeps_original_dates_and_reports_skipped_rows() {
+        let tasks = InMemoryTaskRepository::new();
+        let history = tasks.history_repository();
+        let use_cases = TaskUseCases::new(Arc::new(tasks.clone()), Arc::new(history.clone()), Arc::new(MockTaskDependencyRepository::default()));
+        let admin = RequestContext::anonymous().with_user("ops", UserRole::Admin);
+        let export = "Issue key,Summary,Status,Priority,Created,Resolved\n\
+                      OPS-1,Rotate keys,Done,Highest,2024-03-12 14:30,2024-03-14 09:00\n\
+                      OPS-2,Patch hosts,Icebox,,2024-03-13 08:00,\n\
+                      OPS-3,,To Do,,2024-03-13 08:00,\n\
+                      OPS-4,Audit logs,Parked,,2024-03-15 08:00,\n";
+        let request = |dry_run| ImportTasksRequest {
+            source: ImportSource::Jira,
+            data: export.to_string(),
+            status_mapping: vec![StatusMappingEntry { from: "parked".to_string(), to: TaskStatus::Cancelled }],
+            dry_run,
+        };
+
+        let refused = use_cases.import_tasks(&manager(), &JiraCsvParser, request(false)).await;
+        assert!(matches!(refused, Err(UseCaseError::Forbidden(_))));
+
+        let preview = use_cases.import_tasks(&admin, &JiraCsvParser, request(true)).await.unwrap();
+        assert_eq!((preview.total_rows, preview.imported), (4, 2));
+        assert!(preview.task_ids.is_empty());
+        assert!(use_cases.get_all_tasks(&admin).await.unwrap().is_empty());
+
+        let summary = use_cases.import_tasks(&admin, &JiraCsvParser, request(false)).await.unwrap();
+        assert_eq!(summary.task_ids, vec![1, 2]);
+        let statuses: Vec<_> = summary.statuses.iter().map(|status| (status.status.clone(), status.count)).collect();
+        assert_eq!(statuses, vec![(TaskStatus::Completed, 1), (TaskStatus::Cancelled, 1)]);
+        let skipped: Vec<_> = summary.skipped.iter().map(|row| (row.row, row.reason.as_str())).collect();
+        assert_eq!(skipped, vec![(2, "No status is mapped to 'Icebox'"), (3, "Task name cannot be empty")]);
+
+        let rotated = use_cases.get_task_by_id(&admin, 1).await.unwrap();
+        assert_eq!(rotated.status, TaskStatus::Completed);
+        assert_eq!(rotated.created_at, Utc.with_ymd_and_hms(2024, 3, 12, 14, 30, 0).unwrap());
+        assert_eq!(rotated.priority, Some(1));
+        let entries = history.find_by_task_id(1).await.unwrap();
+        let dates: Vec<_> = entries.iter().map(|entry| (entry.to_status.clone(), entry.changed_at)).collect();
+        assert_eq!(dates, vec![
+            (TaskStatus::Pending, Utc.with_ymd_and_hms(2024, 3, 12, 14, 30, 0).unwrap()),
+            (TaskStatus::Completed, Utc.with_ymd_and_hms(2024, 3, 14, 9, 0, 0).unwrap()),
+        ]);
+        assert_eq!(entries[0].comment.as_deref(), Some("Imported from jira OPS-1"));
+    }
+
+    #[tokio::test]
+    async fn test_aged_history_is_archived_by_month_and_can_be_restored() {
+        let tasks = InMemoryTaskRepository::new();
+        let history = tasks.history_repository();
+        let use_cases = TaskUseCases::new(Arc::new(tasks.clone()), Arc::new(history.clone()), Arc::new(MockTaskDependencyRepository::default()));
+        let export = "Issue key,Summary,Status,Created,Resolved\n\
+                      OPS-1,Rotate keys,Done,2024-03-12 14:30,2024-04-02 09:00\n\
+                      OPS-2,Patch hosts,To Do,2024-05-20 08:00,\n";
+        let import = ImportTasksRequest { source: ImportSource::Jira, data: export.to_string(), status_mapping: vec![], dry_run: false };
+        use_cases.import_tasks(&RequestContext::anonymous().with_user("ops", UserRole::Admin), &JiraCsvParser, import).await.unwrap();
+
+        let root = std::env::temp_dir().join(format!("axum_postgres_rust_audit_archive_{}", std::process::id()));
+        let archive = AuditArchiveUseCases::new(Arc::new(history.clone()), Arc::new(LocalObjectStorage::new(&root)));
+        let policy = AuditRetentionPolicy::new(30).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap();
+
+        let archived = archive.archive_expired(&policy, now).await.unwrap();
+        let months: Vec<_> = archived.iter().map(|month| (month.month.as_str(), month.archived, month.deleted)).collect();
+        assert_eq!(months, vec![("2024-03", 1, 1), ("2024-04", 1, 1)]);
+        assert!(root.join("status_history/2024-04.ndjson.gz").exists());
+        let kept = history.find_matching(&HistoryFilter::default(), Default::default(), Default::default()).await.unwrap();
+        assert_eq!(kept.iter().map(|entry| entry.task_id).collect::<Vec<_>>(), vec![2]);
+        assert!(archive.archive_expired(&policy, now).await.unwrap().is_empty());
+
+        let april = ArchiveMonth::parse("2024-04").unwrap();
+        let restored = archive.restore_month(april).await.unwrap();
+        assert_eq!((restored.restored, restored.already_present, restored.missing_task), (1, 0, 0));
+        assert_eq!(history.find_latest_by_task_id(1).await.unwrap().unwrap().to_status, TaskStatus::Completed);
+        assert_eq!(archive.restore_month(april).await.unwrap().already_present, 1);
+
+        // Restored entries go back to the archive without being written twice
+        let rearchived = archive.archive_expired(&policy, now).await.unwrap();
+        let months: Vec<_> = rearchived.iter().map(|month| (month.month.as_str(), month.archived, month.deleted)).collect();
+        assert_eq!(months, vec![("2024-04", 1, 1)]);
+        assert!(matches!(archive.restore_month(ArchiveMonth::parse("2024-02").unwrap()).await, Err(UseCaseError::NotFound(_))));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
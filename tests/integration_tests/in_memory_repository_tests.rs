@@ -0,0 +1,199 @@
+use axum_postgres_rust::application::{BulkDeleteRequest, CreateTaskRequest, RequestContext, TaskUseCases, UpdateTaskRequest, UpdateTaskStatusDto, UseCaseError};
+use axum_postgres_rust::database::with_workspace;
+use axum_postgres_rust::domain::{
+    HistoryFilter, Pagination, RepositoryError, SortOrder, StatusHistory, StatusHistoryRepository, Task, TaskFilter, TaskId, TaskRepository, TaskSort,
+    TaskSortField, TaskStatus, UserRole, Workspace, WorkspaceRepository,
+};
+use axum_postgres_rust::infrastructure::adapters::{InMemoryStatusHistoryRepository, InMemoryTaskRepository};
+use chrono::Utc;
+use std::sync::Arc;
+
+use super::mocks::MockTaskDependencyRepository;
+
+fn in_memory_use_cases() -> (TaskUseCases, InMemoryTaskRepository, InMemoryStatusHistoryRepository) {
+    let tasks = InMemoryTaskRepository::new();
+    let history = tasks.history_repository();
+    let use_cases = TaskUseCases::new(Arc::new(tasks.clone()), Arc::new(history.clone()), Arc::new(MockTaskDependencyRepository::default()));
+    (use_cases, tasks, history)
+}
+
+fn create_request(name: &str, priority: i32) -> CreateTaskRequest {
+    CreateTaskRequest { name: name.to_string(), priority: Some(priority.into()), description: None }
+}
+
+fn move_to(status: TaskStatus, comment: Option<&str>) -> UpdateTaskStatusDto {
+    UpdateTaskStatusDto { status, comment: comment.map(str::to_string), checklist: None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_task_lifecycle_is_stored_and_audited() {
+        let (use_cases, tasks, history) = in_memory_use_cases();
+        let ctx = RequestContext::anonymous();
+
+        let id = use_cases.create_task(&ctx, create_request("Write release notes", 5)).await.unwrap();
+        let other = use_cases.create_task(&ctx, create_request("Tag the release", 4)).await.unwrap();
+        assert_eq!((id, other), (1, 2));
+
+        use_cases.update_task_status(&ctx, id, move_to(TaskStatus::InProgress, Some("picking this up"))).await.unwrap();
+        use_cases.update_task_status(&ctx, id, move_to(TaskStatus::Completed, None)).await.unwrap();
+
+        let stored = tasks.find_by_id(TaskId::new(id)).await.unwrap().unwrap();
+        assert_eq!(stored.status, TaskStatus::Completed);
+        let entries = history.find_by_task_id(id).await.unwrap();
+        let statuses: Vec<_> = entries.iter().map(|entry| entry.to_status.clone()).collect();
+        assert_eq!(statuses, vec![TaskStatus::Pending, TaskStatus::InProgress, TaskStatus::Completed]);
+        assert_eq!(history.count_matching(&HistoryFilter::default()).await.unwrap(), 4);
+
+        let analytics = use_cases.get_task_analytics(&ctx, id).await.unwrap();
+        assert_eq!(analytics.number_of_transitions, 3);
+        assert!(analytics.completed_at.is_some());
+
+        let found = use_cases.search_tasks(&ctx, "PICKING", None).await.unwrap();
+        assert_eq!(found.iter().map(|task| task.id).collect::<Vec<_>>(), vec![id]);
+
+        use_cases.delete_task(&ctx, id).await.unwrap();
+        assert!(tasks.find_by_id(TaskId::new(id)).await.unwrap().is_none());
+        assert_eq!(use_cases.get_all_tasks(&ctx).await.unwrap().len(), 1);
+        assert!(matches!(tasks.delete(TaskId::new(id)).await, Err(RepositoryError::NotFound(_))));
+
+        let admin = RequestContext::anonymous().with_user("ops", UserRole::Admin);
+        let restored = use_cases.restore_task(&admin, id).await.unwrap();
+        assert_eq!(restored.status, TaskStatus::Completed);
+        assert_eq!(use_cases.get_all_tasks(&ctx).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_completion_analytics_count_each_priority_completed_in_the_period() {
+        let (use_cases, _, _) = in_memory_use_cases();
+        let ctx = RequestContext::anonymous();
+        for (name, priority) in [("Ship", 5), ("Deleted later", 5), ("Still open", 4)] {
+            let id = use_cases.create_task(&ctx, create_request(name, priority)).await.unwrap();
+            if name != "Still open" {
+                use_cases.update_task_status(&ctx, id, move_to(TaskStatus::InProgress, None)).await.unwrap();
+                use_cases.update_task_status(&ctx, id, move_to(TaskStatus::Completed, None)).await.unwrap();
+            }
+        }
+        use_cases.delete_task(&ctx, 2).await.unwrap();
+
+        let analytics = use_cases.get_completion_analytics(&ctx, Utc::now() - chrono::Duration::days(1), Utc::now()).await.unwrap();
+        assert_eq!(analytics.total_completed_tasks, 2);
+        let counts: Vec<_> = analytics.completion_times_by_priority.iter().map(|row| (row.priority, row.task_count)).collect();
+        assert_eq!(counts, vec![(5, 2)]);
+        let earlier = use_cases.get_completion_analytics(&ctx, Utc::now() - chrono::Duration::days(2), Utc::now() - chrono::Duration::days(1)).await.unwrap();
+        assert!(earlier.completion_times_by_priority.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_listing_filters_and_sorts_like_postgres() {
+        let tasks = InMemoryTaskRepository::new();
+        for (name, priority) in [("b", Some(2)), ("a", None), ("c", Some(5))] {
+            tasks.save(&Task::new(TaskId::new(0), name.to_string(), priority).unwrap()).await.unwrap();
+        }
+        tasks.delete(TaskId::new(3)).await.unwrap();
+
+        let by_priority = |order| TaskSort::new(TaskSortField::Priority, order);
+        let names = |found: Vec<Task>| found.into_iter().map(|task| task.name).collect::<Vec<_>>();
+
+        let live = tasks.find_matching(&TaskFilter::default(), by_priority(SortOrder::Desc)).await.unwrap();
+        assert_eq!(names(live), vec!["b", "a"]);
+        let all = TaskFilter { include_deleted: true, ..TaskFilter::default() };
+        assert_eq!(names(tasks.find_matching(&all, by_priority(SortOrder::Asc)).await.unwrap()), vec!["b", "c", "a"]);
+        assert_eq!(names(tasks.find_matching(&all, by_priority(SortOrder::Desc)).await.unwrap()), vec!["c", "b", "a"]);
+    }
+
+    #[tokio::test]
+    async fn test_history_is_immutable_and_needs_an_existing_task() {
+        let (use_cases, _, history) = in_memory_use_cases();
+        let id = use_cases.create_task(&RequestContext::anonymous(), create_request("Audited", 5)).await.unwrap();
+        let entry = history.find_latest_by_task_id(id).await.unwrap().unwrap();
+
+        assert!(matches!(history.save(&entry).await, Err(RepositoryError::ValidationError(_))));
+        let orphan = StatusHistory { id: "orphan".to_string(), task_id: 99, ..entry.clone() };
+        assert!(matches!(history.save(&orphan).await, Err(RepositoryError::ForeignKeyViolation { .. })));
+
+        history.delete(entry.id.clone()).await.unwrap();
+        assert!(matches!(history.delete(entry.id).await, Err(RepositoryError::NotFound(_))));
+        let page = history.find_matching(&HistoryFilter::default(), SortOrder::Asc, Pagination::new(None, None).unwrap()).await.unwrap();
+        assert!(page.is_empty());
+        assert!(history.get_completion_time_stats(Utc::now() - chrono::Duration::days(1), Utc::now()).await.unwrap().is_empty());
+        assert!(history.get_transition_matrix(Utc::now() - chrono::Duration::days(1), Utc::now()).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_delete_needs_a_token_for_the_previewed_tasks() {
+        let (use_cases, tasks, _) = in_memory_use_cases();
+        let admin = RequestContext::anonymous().with_user("ada", UserRole::Admin);
+        for name in ["Old spike", "Old prototype", "Keep me"] {
+            use_cases.create_task(&admin, create_request(name, if name == "Keep me" { 5 } else { 1 })).await.unwrap();
+        }
+        let low = |token: Option<String>| BulkDeleteRequest { status: None, priority: Some(1), confirmation_token: token };
+
+        let preview = use_cases.bulk_delete_tasks(&admin, low(None)).await.unwrap();
+        assert!(!preview.deleted);
+        assert_eq!((preview.count, preview.sample_names.clone()), (2, vec!["Old spike".to_string(), "Old prototype".to_string()]));
+        assert_eq!(tasks.find_matching(&TaskFilter::default(), TaskSort::default()).await.unwrap().len(), 3);
+
+        // Another caller, or a changed selection, cannot use the token
+        let other_admin = RequestContext::anonymous().with_user("bob", UserRole::Admin);
+        assert!(use_cases.bulk_delete_tasks(&other_admin, low(preview.confirmation_token.clone())).await.is_err());
+        let late = use_cases.create_task(&admin, create_request("Old draft", 1)).await.unwrap();
+        assert!(use_cases.bulk_delete_tasks(&admin, low(preview.confirmation_token.clone())).await.is_err());
+        tasks.delete(TaskId::new(late)).await.unwrap();
+
+        let deleted = use_cases.bulk_delete_tasks(&admin, low(preview.confirmation_token)).await.unwrap();
+        assert!(deleted.deleted);
+        assert_eq!(deleted.count, 2);
+        let left: Vec<_> = tasks.find_matching(&TaskFilter::default(), TaskSort::default()).await.unwrap().into_iter().map(|task| task.name).collect();
+        assert_eq!(left, vec!["Keep me"]);
+    }
+
+    #[tokio::test]
+    async fn test_users_can_only_update_and_cancel_their_own_tasks() {
+        let (use_cases, tasks, _) = in_memory_use_cases();
+        let alice = RequestContext::anonymous().with_user("alice", UserRole::User);
+        let bob = RequestContext::anonymous().with_user("bob", UserRole::User);
+        let manager = RequestContext::anonymous().with_user("mia", UserRole::Manager);
+        let id = use_cases.create_task(&alice, create_request("Alice's task", 5)).await.unwrap();
+        assert_eq!(tasks.find_by_id(TaskId::new(id)).await.unwrap().unwrap().created_by.as_deref(), Some("alice"));
+        let rename = |name: &str| UpdateTaskRequest { name: Some(name.to_string()), priority: None, description: None };
+
+        assert!(matches!(use_cases.update_task(&bob, id, rename("Bob's now")).await, Err(UseCaseError::Forbidden(_))));
+        assert!(matches!(use_cases.update_task_status(&bob, id, move_to(TaskStatus::Cancelled, None)).await, Err(UseCaseError::Forbidden(_))));
+        // Ownership only guards edits and cancellation
+        use_cases.update_task_status(&bob, id, move_to(TaskStatus::InProgress, None)).await.unwrap();
+
+        use_cases.update_task(&alice, id, rename("Still Alice's")).await.unwrap();
+        use_cases.update_task(&manager, id, rename("Managed")).await.unwrap();
+        use_cases.update_task_status(&manager, id, move_to(TaskStatus::Cancelled, Some("Dropped"))).await.unwrap();
+
+        let task = tasks.find_by_id(TaskId::new(id)).await.unwrap().unwrap();
+        assert_eq!((task.name.as_str(), task.created_by.as_deref()), ("Managed", Some("alice")));
+    }
+
+    #[tokio::test]
+    async fn test_workspaces_keep_tasks_and_history_apart() {
+        let (use_cases, tasks, history) = in_memory_use_cases();
+        let workspaces = tasks.workspace_repository();
+        workspaces.save(&Workspace::new("platform".to_string(), "Platform".to_string(), "mia".to_string(), Utc::now()).unwrap()).await.unwrap();
+        let platform = || Some("platform".to_string());
+        let ctx = RequestContext::anonymous().with_tenant(platform());
+
+        let id = with_workspace(platform(), use_cases.create_task(&ctx, create_request("Rotate keys", 5))).await.unwrap();
+        with_workspace(platform(), use_cases.update_task_status(&ctx, id, move_to(TaskStatus::InProgress, None))).await.unwrap();
+
+        let default = || Some("default".to_string());
+        assert!(with_workspace(default(), tasks.find_all()).await.unwrap().is_empty());
+        assert!(with_workspace(default(), history.find_by_task_id(id)).await.unwrap().is_empty());
+        assert!(matches!(with_workspace(default(), tasks.delete(TaskId::new(id))).await, Err(RepositoryError::NotFound(_))));
+        assert_eq!(with_workspace(platform(), history.count_matching(&HistoryFilter::default())).await.unwrap(), 2);
+        assert_eq!(tasks.find_all().await.unwrap().len(), 1);
+
+        assert!(matches!(workspaces.delete("platform").await, Err(RepositoryError::Conflict { .. })));
+        with_workspace(platform(), use_cases.delete_task(&ctx, id)).await.unwrap();
+        assert!(matches!(workspaces.delete("platform").await, Err(RepositoryError::Conflict { .. })));
+    }
+}
@@ -0,0 +1,65 @@
+use axum_postgres_rust::domain::BackgroundJobRepository;
+use axum_postgres_rust::infrastructure::adapters::JobScheduler;
+use chrono::Utc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::mocks::MockBackgroundJobRepository;
+
+/// Waits until `runs` reaches `count`, failing the test after a second
+async fn wait_for_runs(runs: &AtomicUsize, count: usize) {
+    tokio::time::timeout(Duration::from_secs(1), async {
+        while runs.load(Ordering::SeqCst) < count {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("the job did not run in time");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_scheduler_records_runs_and_honours_pause_and_run_requests() {
+        let jobs = MockBackgroundJobRepository::default();
+        let scheduler = JobScheduler::new(Arc::new(jobs.clone())).with_poll_interval(Duration::from_millis(10));
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        let handle = scheduler.spawn("nightly-sweep", Duration::from_secs(3600), move || {
+            let run = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                match run {
+                    1 => Err("database went away".to_string()),
+                    _ => Ok(()),
+                }
+            }
+        });
+
+        // The first run starts at once and its failure is kept
+        wait_for_runs(&runs, 1).await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        let job = jobs.find_all().await.unwrap().remove(0);
+        assert_eq!(job.last_error.as_deref(), Some("database went away"));
+        assert!(!job.is_running());
+        assert!(job.next_run_at > Utc::now() + chrono::Duration::minutes(59));
+
+        // A requested run happens even while paused, and clears the error
+        jobs.set_paused("nightly-sweep", true).await.unwrap();
+        jobs.request_run("nightly-sweep").await.unwrap();
+        wait_for_runs(&runs, 2).await;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        let job = jobs.find_all().await.unwrap().remove(0);
+        assert_eq!(job.last_error, None);
+        assert!(!job.run_requested);
+
+        // Paused and no longer requested, the job stays idle even once due
+        jobs.register("nightly-sweep", Duration::from_secs(3600), Utc::now()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+        handle.abort();
+    }
+}
@@ -0,0 +1,636 @@
+use axum_postgres_rust::domain::{Attachment, AttachmentRepository, AttachmentStorage, GateDecision, GatedTransition, TransitionGate, ApiKey, ApiKeyRepository, AutomationNotification, AutomationRule, AutomationRuleRepository, BackgroundJob, BackgroundJobRepository, Notifier, TaskDependencyRepository, Task, TaskId, TaskPatch, PriorityChange, TaskRepository, StatusHistoryRepository, RepositoryError, StatusHistory, TaskStatus, CycleTimeStats, PriorityCompletion, TransitionEdge, Cohort, CohortBucket, CumulativeFlow, TaskFacets, BoardColumn, Rank, TaskFilter, TaskSort, TaskSortField, SortOrder, HistoryFilter, Pagination};
+use axum_postgres_rust::database::{current_workspace, DatabaseProbe, PoolStats};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex};
+
+// Mock repository for integration testing
+#[derive(Clone)]
+pub struct MockRepository {
+    tasks: Vec<Task>,
+    next_id: i32,
+    recorded_history: Arc<Mutex<Vec<StatusHistory>>>,
+    recorded_patches: Arc<Mutex<Vec<TaskPatch>>>,
+}
+
+impl MockRepository {
+    pub fn new() -> Self {
+        Self {
+            tasks: vec![],
+            next_id: 1,
+            recorded_history: Arc::new(Mutex::new(vec![])),
+            recorded_patches: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    pub fn with_tasks(mut self, tasks: Vec<Task>) -> Self {
+        self.next_id = tasks.iter().map(|t| t.id.value()).max().unwrap_or(0) + 1;
+        self.tasks = tasks;
+        self
+    }
+
+    /// Patches written through update_fields
+    pub fn recorded_patches(&self) -> Vec<TaskPatch> {
+        self.recorded_patches.lock().unwrap().clone()
+    }
+
+    /// History entries written through save_with_history/update_with_history
+    pub fn recorded_history(&self) -> Vec<StatusHistory> {
+        self.recorded_history.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl TaskRepository for MockRepository {
+    async fn find_all(&self) -> Result<Vec<Task>, RepositoryError> {
+        Ok(self.tasks.iter().filter(|t| !t.is_deleted()).cloned().collect())
+    }
+
+    async fn find_matching(&self, filter: &TaskFilter, sort: TaskSort) -> Result<Vec<Task>, RepositoryError> {
+        let mut tasks: Vec<Task> = self.tasks
+            .iter()
+            .filter(|t| filter.include_deleted || !t.is_deleted())
+            .filter(|t| t.is_archived() == filter.archived)
+            .filter(|t| filter.priority.is_none() || t.priority == filter.priority)
+            .filter(|t| filter.status.as_ref().is_none_or(|status| &t.status == status))
+            .filter(|t| filter.expression.as_ref().is_none_or(|expression| expression.matches(t)))
+            .cloned()
+            .collect();
+
+        tasks.sort_by(|a, b| {
+            let ordering = match sort.field {
+                TaskSortField::Id => a.id.value().cmp(&b.id.value()),
+                TaskSortField::Priority => a.priority.cmp(&b.priority),
+                TaskSortField::CreatedAt => a.created_at.cmp(&b.created_at),
+                TaskSortField::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+                TaskSortField::Name => a.name.cmp(&b.name),
+                TaskSortField::Status => a.status.as_str().cmp(b.status.as_str()),
+                TaskSortField::Rank => a.rank().cmp(&b.rank()),
+            };
+            match sort.order {
+                SortOrder::Asc => ordering,
+                SortOrder::Desc => ordering.reverse(),
+            }
+        });
+        Ok(tasks)
+    }
+
+    async fn count_facets(&self, filter: &TaskFilter) -> Result<TaskFacets, RepositoryError> {
+        let tasks = self.find_matching(filter, TaskSort::default()).await?;
+        Ok(TaskFacets::of(&tasks))
+    }
+
+    async fn find_board(&self, filter: &TaskFilter, sort: TaskSort, column_size: usize) -> Result<Vec<BoardColumn>, RepositoryError> {
+        let tasks = self.find_matching(filter, sort).await?;
+        Ok(BoardColumn::group(tasks, column_size))
+    }
+
+    async fn find_by_id(&self, id: TaskId) -> Result<Option<Task>, RepositoryError> {
+        Ok(self.tasks.iter().find(|t| t.id == id && !t.is_deleted()).cloned())
+    }
+
+    async fn find_by_priority(&self, priority: i32) -> Result<Vec<Task>, RepositoryError> {
+        Ok(self.tasks
+            .iter()
+            .filter(|t| t.priority == Some(priority) && !t.is_deleted())
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_status(&self, status: &TaskStatus) -> Result<Vec<Task>, RepositoryError> {
+        Ok(self.tasks
+            .iter()
+            .filter(|t| &t.status == status && !t.is_deleted())
+            .cloned()
+            .collect())
+    }
+
+    async fn search_tasks(&self, query: &str) -> Result<Vec<Task>, RepositoryError> {
+        let query = query.to_lowercase();
+        Ok(self.tasks
+            .iter()
+            .filter(|t| !t.is_deleted())
+            .filter(|t| {
+                t.name.to_lowercase().contains(&query)
+                    || t.description.as_deref().is_some_and(|d| d.to_lowercase().contains(&query))
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn save(&self, _task: &Task) -> Result<TaskId, RepositoryError> {
+        Ok(TaskId::new(self.next_id))
+    }
+
+    async fn update(&self, _task: &Task) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn update_fields(&self, _id: TaskId, patch: &TaskPatch) -> Result<(), RepositoryError> {
+        self.recorded_patches.lock().unwrap().push(patch.clone());
+        Ok(())
+    }
+
+    async fn set_rank(&self, id: TaskId, _rank: &Rank) -> Result<(), RepositoryError> {
+        match self.tasks.iter().any(|t| t.id == id && !t.is_deleted()) {
+            true => Ok(()),
+            false => Err(RepositoryError::NotFound(format!("Task with id {} not found", id.value()))),
+        }
+    }
+
+    async fn reprioritize(&self, filter: &TaskFilter, pairs: &[(i32, i32)], _updated_at: DateTime<Utc>) -> Result<Vec<PriorityChange>, RepositoryError> {
+        Ok(self.tasks
+            .iter()
+            .filter(|t| !t.is_deleted())
+            .filter(|t| filter.priority.is_none() || t.priority == filter.priority)
+            .filter(|t| filter.status.as_ref().is_none_or(|status| &t.status == status))
+            .filter_map(|t| {
+                let from = t.priority?;
+                pairs.iter().find(|(pair_from, _)| *pair_from == from).map(|&(from, to)| PriorityChange { task_id: t.id, from, to })
+            })
+            .collect())
+    }
+
+    async fn save_with_history(&self, _task: &Task, history: &StatusHistory) -> Result<TaskId, RepositoryError> {
+        let history = StatusHistory { task_id: self.next_id, ..history.clone() };
+        self.recorded_history.lock().unwrap().push(history);
+        Ok(TaskId::new(self.next_id))
+    }
+
+    async fn update_with_history(&self, _task: &Task, history: &StatusHistory) -> Result<(), RepositoryError> {
+        self.recorded_history.lock().unwrap().push(history.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, _id: TaskId) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn restore(&self, id: TaskId) -> Result<Task, RepositoryError> {
+        self.tasks
+            .iter()
+            .find(|t| t.id == id && t.is_deleted())
+            .map(|t| t.clone().with_deleted_at(None))
+            .ok_or_else(|| RepositoryError::NotFound(format!("Deleted task with id {} not found", id.value())))
+    }
+}
+
+// Mock status history repository for integration testing
+#[derive(Clone)]
+pub struct MockStatusHistoryRepository;
+
+#[async_trait]
+impl StatusHistoryRepository for MockStatusHistoryRepository {
+    async fn find_by_task_id(&self, _task_id: i32) -> Result<Vec<StatusHistory>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn find_matching(
+        &self,
+        _filter: &HistoryFilter,
+        _order: SortOrder,
+        _page: Pagination,
+    ) -> Result<Vec<StatusHistory>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn count_matching(&self, _filter: &HistoryFilter) -> Result<usize, RepositoryError> {
+        Ok(0)
+    }
+    
+    async fn find_by_date_range(
+        &self, 
+        _start_date: chrono::DateTime<chrono::Utc>, 
+        _end_date: chrono::DateTime<chrono::Utc>
+    ) -> Result<Vec<StatusHistory>, RepositoryError> {
+        Ok(vec![])
+    }
+    
+    async fn find_latest_by_task_id(&self, _task_id: i32) -> Result<Option<StatusHistory>, RepositoryError> {
+        Ok(None)
+    }
+    
+    async fn get_task_analytics(&self, _task_id: i32) -> Result<Option<axum_postgres_rust::domain::TaskAnalytics>, RepositoryError> {
+        Ok(None)
+    }
+    
+    async fn get_completion_analytics(
+        &self, 
+        _start_date: chrono::DateTime<chrono::Utc>, 
+        _end_date: chrono::DateTime<chrono::Utc>
+    ) -> Result<Vec<axum_postgres_rust::domain::TaskAnalytics>, RepositoryError> {
+        Ok(vec![])
+    }
+    
+    async fn get_transition_matrix(
+        &self,
+        _start_date: chrono::DateTime<chrono::Utc>,
+        _end_date: chrono::DateTime<chrono::Utc>
+    ) -> Result<Vec<TransitionEdge>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_creation_cohorts(
+        &self,
+        _bucket: CohortBucket,
+        _start_date: chrono::DateTime<chrono::Utc>,
+        _end_date: chrono::DateTime<chrono::Utc>
+    ) -> Result<Vec<Cohort>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_cumulative_flow(&self, bucket: CohortBucket, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<CumulativeFlow, RepositoryError> {
+        Ok(CumulativeFlow::from_counts(bucket, start_date, end_date, vec![]))
+    }
+
+    async fn get_completion_time_stats(&self, _start_date: DateTime<Utc>, _end_date: DateTime<Utc>) -> Result<Vec<CycleTimeStats>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_priority_completions(&self, _start_date: DateTime<Utc>, _end_date: DateTime<Utc>) -> Result<Vec<PriorityCompletion>, RepositoryError> {
+        Ok(vec![])
+    }
+    
+    async fn save(&self, _history: &StatusHistory) -> Result<String, RepositoryError> {
+        Ok("mock-id".to_string())
+    }
+    
+    async fn delete(&self, _id: String) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn delete_many(&self, _ids: &[String]) -> Result<usize, RepositoryError> {
+        Ok(0)
+    }
+}
+
+// Status history repository whose database is down: every call fails
+#[derive(Clone)]
+pub struct UnavailableStatusHistoryRepository;
+
+fn history_unavailable<T>() -> Result<T, RepositoryError> {
+    Err(RepositoryError::DatabaseError("status_history is unavailable".to_string()))
+}
+
+#[async_trait]
+impl StatusHistoryRepository for UnavailableStatusHistoryRepository {
+    async fn find_by_task_id(&self, _task_id: i32) -> Result<Vec<StatusHistory>, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn find_matching(&self, _filter: &HistoryFilter, _order: SortOrder, _page: Pagination) -> Result<Vec<StatusHistory>, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn count_matching(&self, _filter: &HistoryFilter) -> Result<usize, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn find_by_date_range(&self, _start_date: DateTime<Utc>, _end_date: DateTime<Utc>) -> Result<Vec<StatusHistory>, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn find_latest_by_task_id(&self, _task_id: i32) -> Result<Option<StatusHistory>, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn get_task_analytics(&self, _task_id: i32) -> Result<Option<axum_postgres_rust::domain::TaskAnalytics>, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn get_completion_analytics(&self, _start_date: DateTime<Utc>, _end_date: DateTime<Utc>) -> Result<Vec<axum_postgres_rust::domain::TaskAnalytics>, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn get_transition_matrix(&self, _start_date: DateTime<Utc>, _end_date: DateTime<Utc>) -> Result<Vec<TransitionEdge>, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn get_creation_cohorts(&self, _bucket: CohortBucket, _start_date: DateTime<Utc>, _end_date: DateTime<Utc>) -> Result<Vec<Cohort>, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn get_cumulative_flow(&self, _bucket: CohortBucket, _start_date: DateTime<Utc>, _end_date: DateTime<Utc>) -> Result<CumulativeFlow, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn get_completion_time_stats(&self, _start_date: DateTime<Utc>, _end_date: DateTime<Utc>) -> Result<Vec<CycleTimeStats>, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn get_priority_completions(&self, _start_date: DateTime<Utc>, _end_date: DateTime<Utc>) -> Result<Vec<PriorityCompletion>, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn save(&self, _history: &StatusHistory) -> Result<String, RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn delete(&self, _id: String) -> Result<(), RepositoryError> {
+        history_unavailable()
+    }
+
+    async fn delete_many(&self, _ids: &[String]) -> Result<usize, RepositoryError> {
+        history_unavailable()
+    }
+}
+
+// Mock database probe for health route testing
+#[derive(Clone)]
+pub enum MockDatabaseProbe {
+    Healthy,
+    Failing(String),
+    Hanging,
+}
+
+#[async_trait]
+impl DatabaseProbe for MockDatabaseProbe {
+    async fn ping(&self) -> Result<(), String> {
+        match self {
+            MockDatabaseProbe::Healthy => Ok(()),
+            MockDatabaseProbe::Failing(error) => Err(error.clone()),
+            MockDatabaseProbe::Hanging => std::future::pending().await,
+        }
+    }
+
+    fn pool_stats(&self) -> PoolStats {
+        PoolStats { size: 2, idle: 1, max_connections: 16 }
+    }
+}
+
+// In-memory API key store for authentication route testing
+#[derive(Clone, Default)]
+pub struct MockApiKeyRepository {
+    keys: Arc<Mutex<Vec<ApiKey>>>,
+}
+
+#[async_trait]
+impl ApiKeyRepository for MockApiKeyRepository {
+    async fn save(&self, api_key: &ApiKey) -> Result<(), RepositoryError> {
+        self.keys.lock().unwrap().push(api_key.clone());
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+        Ok(self.keys.lock().unwrap().iter().find(|key| key.key_hash == key_hash).cloned())
+    }
+}
+
+// In-memory background job state, claimed and updated like the database tables
+#[derive(Clone, Default)]
+pub struct MockBackgroundJobRepository {
+    jobs: Arc<Mutex<Vec<BackgroundJob>>>,
+}
+
+impl MockBackgroundJobRepository {
+    fn update(&self, name: &str, change: impl FnOnce(&mut BackgroundJob)) -> Result<BackgroundJob, RepositoryError> {
+        let mut jobs = self.jobs.lock().unwrap();
+        let job = jobs.iter_mut()
+            .find(|job| job.name == name)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Background job '{}' not found", name)))?;
+        change(job);
+        Ok(job.clone())
+    }
+}
+
+#[async_trait]
+impl BackgroundJobRepository for MockBackgroundJobRepository {
+    async fn register(&self, name: &str, interval: std::time::Duration, first_run_at: DateTime<Utc>) -> Result<BackgroundJob, RepositoryError> {
+        let registered = self.update(name, |job| {
+            job.interval = interval;
+            job.next_run_at = first_run_at;
+        });
+        if let Ok(job) = registered {
+            return Ok(job);
+        }
+        let job = BackgroundJob {
+            name: name.to_string(),
+            interval,
+            paused: false,
+            run_requested: false,
+            last_started_at: None,
+            last_finished_at: None,
+            last_error: None,
+            next_run_at: first_run_at,
+        };
+        self.jobs.lock().unwrap().push(job.clone());
+        Ok(job)
+    }
+
+    async fn find_all(&self) -> Result<Vec<BackgroundJob>, RepositoryError> {
+        let mut jobs = self.jobs.lock().unwrap().clone();
+        jobs.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(jobs)
+    }
+
+    async fn set_paused(&self, name: &str, paused: bool) -> Result<BackgroundJob, RepositoryError> {
+        self.update(name, |job| job.paused = paused)
+    }
+
+    async fn request_run(&self, name: &str) -> Result<BackgroundJob, RepositoryError> {
+        self.update(name, |job| job.run_requested = true)
+    }
+
+    async fn claim_run(&self, name: &str, now: DateTime<Utc>, retry_at: DateTime<Utc>) -> Result<bool, RepositoryError> {
+        let mut claimed = false;
+        self.update(name, |job| {
+            if job.is_due(now) {
+                job.run_requested = false;
+                job.last_started_at = Some(now);
+                job.next_run_at = retry_at;
+                claimed = true;
+            }
+        })?;
+        Ok(claimed)
+    }
+
+    async fn finish_run(&self, name: &str, finished_at: DateTime<Utc>, error: Option<&str>, next_run_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        self.update(name, |job| {
+            job.last_finished_at = Some(finished_at);
+            job.last_error = error.map(str::to_string);
+            job.next_run_at = next_run_at;
+        })?;
+        Ok(())
+    }
+}
+
+// In-memory automation rules, kept in insertion order and scoped to the current workspace like the tables
+#[derive(Clone, Default)]
+pub struct MockAutomationRuleRepository {
+    rules: Arc<Mutex<Vec<AutomationRule>>>,
+}
+
+fn in_current_workspace(rule: &AutomationRule) -> bool {
+    current_workspace().is_none_or(|workspace| rule.workspace_id == workspace)
+}
+
+#[async_trait]
+impl AutomationRuleRepository for MockAutomationRuleRepository {
+    async fn save(&self, rule: &AutomationRule) -> Result<(), RepositoryError> {
+        self.rules.lock().unwrap().push(rule.clone());
+        Ok(())
+    }
+
+    async fn update(&self, rule: &AutomationRule) -> Result<(), RepositoryError> {
+        let mut rules = self.rules.lock().unwrap();
+        let stored = rules.iter_mut()
+            .find(|stored| stored.id == rule.id && in_current_workspace(stored))
+            .ok_or_else(|| RepositoryError::NotFound(format!("Automation rule {} not found", rule.id)))?;
+        *stored = rule.clone();
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        let mut rules = self.rules.lock().unwrap();
+        let count = rules.len();
+        rules.retain(|rule| rule.id != id || !in_current_workspace(rule));
+        if rules.len() == count {
+            return Err(RepositoryError::NotFound(format!("Automation rule {} not found", id)));
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<AutomationRule>, RepositoryError> {
+        Ok(self.rules.lock().unwrap().iter().find(|rule| rule.id == id && in_current_workspace(rule)).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<AutomationRule>, RepositoryError> {
+        Ok(self.rules.lock().unwrap().iter().filter(|rule| in_current_workspace(rule)).cloned().collect())
+    }
+}
+
+// Records notifications instead of sending them; fails for URLs containing "unreachable"
+#[derive(Clone, Default)]
+pub struct MockNotifier {
+    pub sent: Arc<Mutex<Vec<(String, AutomationNotification)>>>,
+}
+
+#[async_trait]
+impl Notifier for MockNotifier {
+    async fn notify(&self, url: &str, notification: &AutomationNotification) -> Result<(), String> {
+        if url.contains("unreachable") {
+            return Err(format!("Notification to {} failed", url));
+        }
+        self.sent.lock().unwrap().push((url.to_string(), notification.clone()));
+        Ok(())
+    }
+}
+
+// Answers every review with the same decision, or fails when it has none, recording what it was asked
+#[derive(Clone, Default)]
+pub struct MockTransitionGate {
+    decision: Option<GateDecision>,
+    pub reviewed: Arc<Mutex<Vec<GatedTransition>>>,
+}
+
+impl MockTransitionGate {
+    pub fn answering(decision: GateDecision) -> Self {
+        Self { decision: Some(decision), reviewed: Arc::default() }
+    }
+
+    pub fn unreachable() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TransitionGate for MockTransitionGate {
+    async fn review(&self, transition: &GatedTransition) -> Result<GateDecision, String> {
+        self.reviewed.lock().unwrap().push(transition.clone());
+        self.decision.clone().ok_or_else(|| "Approval system timed out".to_string())
+    }
+}
+
+// In-memory dependency store as (task, depends_on) pairs
+#[derive(Clone, Default)]
+pub struct MockTaskDependencyRepository {
+    dependencies: Arc<Mutex<Vec<(i32, i32)>>>,
+}
+
+impl MockTaskDependencyRepository {
+    pub fn with_dependencies(dependencies: Vec<(i32, i32)>) -> Self {
+        Self { dependencies: Arc::new(Mutex::new(dependencies)) }
+    }
+}
+
+#[async_trait]
+impl TaskDependencyRepository for MockTaskDependencyRepository {
+    async fn add(&self, task_id: TaskId, depends_on: TaskId) -> Result<(), RepositoryError> {
+        let mut dependencies = self.dependencies.lock().unwrap();
+        let pair = (task_id.value(), depends_on.value());
+        if !dependencies.contains(&pair) {
+            dependencies.push(pair);
+        }
+        Ok(())
+    }
+
+    async fn remove(&self, task_id: TaskId, depends_on: TaskId) -> Result<(), RepositoryError> {
+        let mut dependencies = self.dependencies.lock().unwrap();
+        let count = dependencies.len();
+        dependencies.retain(|pair| *pair != (task_id.value(), depends_on.value()));
+        if dependencies.len() == count {
+            return Err(RepositoryError::NotFound("Dependency not found".to_string()));
+        }
+        Ok(())
+    }
+
+    async fn find_dependencies(&self, task_id: TaskId) -> Result<Vec<TaskId>, RepositoryError> {
+        let mut ids: Vec<i32> = self.dependencies.lock().unwrap()
+            .iter()
+            .filter(|(task, _)| *task == task_id.value())
+            .map(|(_, depends_on)| *depends_on)
+            .collect();
+        ids.sort();
+        Ok(ids.into_iter().map(TaskId::new).collect())
+    }
+}
+
+// Keeps attachment metadata in memory, in upload order
+#[derive(Clone, Default)]
+pub struct MockAttachmentRepository {
+    pub attachments: Arc<Mutex<Vec<Attachment>>>,
+}
+
+#[async_trait]
+impl AttachmentRepository for MockAttachmentRepository {
+    async fn save(&self, attachment: &Attachment) -> Result<(), RepositoryError> {
+        self.attachments.lock().unwrap().push(attachment.clone());
+        Ok(())
+    }
+
+    async fn find_by_task_id(&self, task_id: TaskId) -> Result<Vec<Attachment>, RepositoryError> {
+        Ok(self.attachments.lock().unwrap().iter().filter(|attachment| attachment.task_id == task_id).cloned().collect())
+    }
+}
+
+// An uploaded file as (key, content type, body)
+pub type StoredUpload = (String, String, Vec<u8>);
+
+// Keeps uploaded files in memory, or fails every upload when unreachable;
+// download links name the key, file and expiry instead of being signed
+#[derive(Clone, Default)]
+pub struct MockAttachmentStorage {
+    unreachable: bool,
+    pub uploads: Arc<Mutex<Vec<StoredUpload>>>,
+}
+
+impl MockAttachmentStorage {
+    pub fn unreachable() -> Self {
+        Self { unreachable: true, uploads: Arc::default() }
+    }
+}
+
+#[async_trait]
+impl AttachmentStorage for MockAttachmentStorage {
+    async fn upload(&self, key: &str, content_type: &str, body: Vec<u8>) -> Result<(), String> {
+        if self.unreachable {
+            return Err("Storage unreachable".to_string());
+        }
+        self.uploads.lock().unwrap().push((key.to_string(), content_type.to_string(), body));
+        Ok(())
+    }
+
+    fn download_url(&self, key: &str, file_name: &str, issued_at: DateTime<Utc>, expires_in: std::time::Duration) -> Result<String, String> {
+        Ok(format!("https://files.test/{}?name={}&expires={}", key, file_name, issued_at.timestamp() + expires_in.as_secs() as i64))
+    }
+}
@@ -1,2 +1,13 @@
 // Integration tests for the complete hexagonal architecture
-pub mod hexagonal_architecture_tests;
\ No newline at end of file
+pub mod mocks;
+pub mod hexagonal_architecture_tests;
+pub mod router_tests;
+pub mod server_tests;
+pub mod cached_repository_tests;
+pub mod realtime_tests;
+pub mod in_memory_repository_tests;
+pub mod sqlite_repository_tests;
+pub mod job_scheduler_tests;
+pub mod automation_tests;
+pub mod transition_gate_tests;
+pub mod attachment_tests;
\ No newline at end of file
@@ -0,0 +1,163 @@
+use axum_postgres_rust::{
+    application::{ApiKeyUseCases, AutomationUseCases, BackgroundJobUseCases, CreateTaskRequest, RequestContext, TaskChangeFeed, TaskUseCases, UpdateTaskRequest, UpdateTaskStatusDto, WorkspaceUseCases},
+    domain::{Task, TaskId, TaskStatus},
+    infrastructure::adapters::{create_router, ApiKeyController, AttachmentController, AutomationController, BackgroundJobController, HealthController, InMemoryTaskRepository, RealtimeController, RequestContextSettings, RequestContextState, TaskCloneController, TaskController, WorkspaceController},
+};
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{connect_async, tungstenite::{client::IntoClientRequest, Message}, MaybeTlsStream, WebSocketStream};
+
+use super::mocks::{MockApiKeyRepository, MockAutomationRuleRepository, MockBackgroundJobRepository, MockDatabaseProbe, MockNotifier, MockRepository, MockStatusHistoryRepository, MockTaskDependencyRepository};
+
+type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Serves the router on a free port and returns its address with the use cases behind it
+async fn start_server() -> (String, Arc<TaskUseCases>) {
+    start_server_with(MockRepository::new(), None).await
+}
+
+async fn start_server_with(repository: MockRepository, batch_window: Option<Duration>) -> (String, Arc<TaskUseCases>) {
+    let change_feed = TaskChangeFeed::default();
+    let use_cases = Arc::new(
+        TaskUseCases::new(Arc::new(repository), Arc::new(MockStatusHistoryRepository), Arc::new(MockTaskDependencyRepository::default()))
+            .with_change_feed(change_feed.clone()),
+    );
+    let api_keys = Arc::new(ApiKeyUseCases::new(Arc::new(MockApiKeyRepository::default())));
+    let workspaces = Arc::new(WorkspaceUseCases::new(Arc::new(InMemoryTaskRepository::new().workspace_repository())));
+    let context = RequestContextState {
+        settings: RequestContextSettings { trust_identity_headers: true },
+        api_keys: api_keys.clone(),
+        shutdown: CancellationToken::new(),
+        shards: Arc::default(),
+        workspaces: workspaces.clone(),
+    };
+    let mut realtime = RealtimeController::new(use_cases.clone(), api_keys.clone(), change_feed);
+    if let Some(window) = batch_window {
+        realtime = realtime.with_batch_window(window);
+    }
+    let router = create_router(
+        Arc::new(TaskController::new(use_cases.clone())),
+        Arc::new(ApiKeyController::new(api_keys.clone())),
+        Arc::new(BackgroundJobController::new(Arc::new(BackgroundJobUseCases::new(Arc::new(MockBackgroundJobRepository::default()))))),
+        Arc::new(AutomationController::new(Arc::new(AutomationUseCases::new(Arc::new(MockAutomationRuleRepository::default()), use_cases.clone(), Arc::new(MockNotifier::default()))))),
+        Arc::new(WorkspaceController::new(workspaces)),
+        Arc::new(AttachmentController::disabled()),
+        Arc::new(TaskCloneController::disabled()),
+        Arc::new(realtime),
+        Arc::new(HealthController::new(Arc::new(MockDatabaseProbe::Healthy), Duration::from_millis(50))),
+        context,
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+    (format!("ws://{}/ws", address), use_cases)
+}
+
+async fn connect(url: &str, headers: &[(&'static str, &str)]) -> Client {
+    let mut request = url.into_client_request().unwrap();
+    for (name, value) in headers {
+        request.headers_mut().insert(*name, value.parse().unwrap());
+    }
+    connect_async(request).await.unwrap().0
+}
+
+async fn next_json(client: &mut Client) -> Value {
+    loop {
+        let message = tokio::time::timeout(Duration::from_secs(2), client.next()).await.unwrap().unwrap().unwrap();
+        if let Message::Text(text) = message {
+            return serde_json::from_str(&text).unwrap();
+        }
+    }
+}
+
+async fn send_json(client: &mut Client, value: Value) {
+    client.send(Message::Text(value.to_string().into())).await.unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_websocket_greets_with_the_callers_identity() {
+        let (url, _) = start_server().await;
+
+        let mut client = connect(&url, &[("x-user-id", "lead"), ("x-user-role", "Manager")]).await;
+
+        let identity = next_json(&mut client).await;
+        assert_eq!(identity["type"], "identity");
+        assert_eq!(identity["user_id"], "lead");
+        assert_eq!(identity["role"], "Manager");
+    }
+
+    #[tokio::test]
+    async fn test_websocket_receives_task_changes() {
+        let (url, use_cases) = start_server().await;
+        let mut client = connect(&url, &[]).await;
+        next_json(&mut client).await;
+
+        use_cases.create_task(&RequestContext::anonymous().with_user("writer", Default::default()), CreateTaskRequest {
+            name: "Live".to_string(),
+            priority: Some(5.into()),
+            description: None,
+        }).await.unwrap();
+
+        let change = next_json(&mut client).await;
+        assert_eq!(change["type"], "task_changed");
+        assert_eq!(change["change"]["kind"], "created");
+        assert_eq!(change["change"]["task"]["name"], "Live");
+        assert_eq!(change["change"]["changed_by"], "writer");
+    }
+
+    #[tokio::test]
+    async fn test_websocket_commands_run_with_the_connections_role() {
+        let (url, _) = start_server().await;
+        let mut client = connect(&url, &[]).await;
+        next_json(&mut client).await;
+
+        send_json(&mut client, json!({"type": "update_status", "task_id": 7, "status": "InProgress"})).await;
+        let reply = next_json(&mut client).await;
+        assert_eq!(reply["type"], "error");
+        assert_eq!(reply["message"], "Task with id 7 not found");
+
+        send_json(&mut client, json!({"type": "authenticate", "api_key": "tk_unknown"})).await;
+        let reply = next_json(&mut client).await;
+        assert_eq!(reply, json!({"type": "error", "message": "Invalid API key"}));
+
+        send_json(&mut client, json!({"type": "delete_task", "task_id": 7})).await;
+        let reply = next_json(&mut client).await;
+        assert_eq!(reply["type"], "error");
+        assert!(reply["message"].as_str().unwrap().starts_with("Invalid message"));
+    }
+
+    #[tokio::test]
+    async fn test_websocket_batches_changes_to_one_task() {
+        let task = Task::new(TaskId::new(1), "Draft".to_string(), Some(5)).unwrap();
+        let (url, use_cases) = start_server_with(MockRepository::new().with_tasks(vec![task]), Some(Duration::from_millis(200))).await;
+        let mut client = connect(&url, &[]).await;
+        next_json(&mut client).await;
+        let ctx = RequestContext::anonymous().with_user("editor", Default::default());
+        let edit = |name: Option<&str>, priority: Option<i32>| UpdateTaskRequest { name: name.map(str::to_string), priority: priority.map(Into::into), description: None };
+
+        use_cases.update_task(&ctx, 1, edit(Some("Final"), None)).await.unwrap();
+        use_cases.update_task_status(&ctx, 1, UpdateTaskStatusDto { status: TaskStatus::InProgress, comment: None, checklist: None }).await.unwrap();
+        use_cases.update_task(&ctx, 1, edit(None, Some(2))).await.unwrap();
+
+        let change = next_json(&mut client).await;
+        assert_eq!(change["type"], "task_changed");
+        assert_eq!(change["change"]["kind"], "updated");
+        assert_eq!(change["change"]["coalesced"], 3);
+        assert_eq!(change["change"]["changed_fields"], json!(["name", "status", "priority"]));
+        assert_eq!(change["change"]["changed_by"], "editor");
+
+        use_cases.update_task(&ctx, 1, edit(None, Some(3))).await.unwrap();
+        let change = next_json(&mut client).await;
+        assert_eq!(change["change"]["changed_fields"], json!(["priority"]));
+        assert!(change["change"].get("coalesced").is_none());
+    }
+}
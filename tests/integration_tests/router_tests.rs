@@ -0,0 +1,1501 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{Request, StatusCode},
+    Router,
+};
+use axum_postgres_rust::{
+    application::{ApiKeyUseCases, AttachmentUseCases, AutomationUseCases, BackgroundJobUseCases, TaskChangeFeed, TaskCloneUseCases, TaskUseCases, WorkspaceUseCases},
+    domain::{BackgroundJobRepository, Task, TaskId, TaskStatus, WipLimits, Workflows},
+    infrastructure::adapters::{create_router, transaction_middleware, ApiKeyController, AttachmentController, AutomationController, BackgroundJobController, HealthController, InMemoryTaskRepository, RealtimeController, RequestContextSettings, RequestContextState, TaskCloneController, TaskController, WorkspaceController},
+};
+use serde_json::Value;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+use std::time::Duration;
+use tower::ServiceExt;
+
+use super::mocks::{MockApiKeyRepository, MockAttachmentRepository, MockAttachmentStorage, MockAutomationRuleRepository, MockBackgroundJobRepository, MockDatabaseProbe, MockNotifier, MockRepository, MockStatusHistoryRepository, MockTaskDependencyRepository};
+
+/// Above axum's default 2 MiB body limit, which the `/v2` field renaming reads JSON bodies within
+const MAX_TEST_ATTACHMENT_BYTES: usize = 3 * 1024 * 1024;
+
+fn create_test_router(tasks: Vec<Task>) -> Router {
+    create_test_router_with_database(tasks, MockDatabaseProbe::Healthy)
+}
+
+fn create_test_router_with_database(tasks: Vec<Task>, database: MockDatabaseProbe) -> Router {
+    create_test_router_with(tasks, database, MockBackgroundJobRepository::default())
+}
+
+fn create_test_router_with(tasks: Vec<Task>, database: MockDatabaseProbe, jobs: MockBackgroundJobRepository) -> Router {
+    let repository = MockRepository::new().with_tasks(tasks);
+    let use_cases = TaskUseCases::new(Arc::new(repository), Arc::new(MockStatusHistoryRepository), Arc::new(MockTaskDependencyRepository::default()));
+    let store = InMemoryTaskRepository::new();
+    build_router(use_cases, &store, database, jobs)
+}
+
+/// A router over in-memory tasks and history, which keep every workspace's apart
+fn create_in_memory_router() -> Router {
+    let tasks = InMemoryTaskRepository::new();
+    let use_cases = TaskUseCases::new(Arc::new(tasks.clone()), Arc::new(tasks.history_repository()), Arc::new(MockTaskDependencyRepository::default()));
+    build_router(use_cases, &tasks, MockDatabaseProbe::Healthy, MockBackgroundJobRepository::default())
+}
+
+/// Workspaces, workflows, custom statuses and statistics come from `store`, whatever repository the tasks use
+fn build_router(use_cases: TaskUseCases, store: &InMemoryTaskRepository, database: MockDatabaseProbe, jobs: MockBackgroundJobRepository) -> Router {
+    let (workflows, statuses) = (Arc::new(store.workflow_repository()), Arc::new(store.custom_status_repository()));
+    let use_cases = use_cases.with_workflow_repository(workflows.clone()).with_custom_status_repository(statuses.clone());
+    let use_cases = Arc::new(use_cases.with_statistics_repository(Arc::new(store.statistics_repository())));
+    let workspaces = WorkspaceUseCases::new(Arc::new(store.workspace_repository()))
+        .with_workflows(Workflows::default(), workflows)
+        .with_custom_statuses(statuses);
+    let workspaces = Arc::new(workspaces);
+    let api_keys = Arc::new(ApiKeyUseCases::new(Arc::new(MockApiKeyRepository::default())));
+    let change_feed = TaskChangeFeed::default();
+    let health = HealthController::new(Arc::new(database), Duration::from_millis(50)).with_change_feed(change_feed.clone());
+    let context = RequestContextState {
+        settings: RequestContextSettings { trust_identity_headers: true },
+        api_keys: api_keys.clone(),
+        shutdown: CancellationToken::new(),
+        shards: Arc::default(),
+        workspaces: workspaces.clone(),
+    };
+    let realtime = RealtimeController::new(use_cases.clone(), api_keys.clone(), change_feed);
+    let jobs = BackgroundJobController::new(Arc::new(BackgroundJobUseCases::new(Arc::new(jobs))));
+    let automation = AutomationController::new(Arc::new(AutomationUseCases::new(Arc::new(MockAutomationRuleRepository::default()), use_cases.clone(), Arc::new(MockNotifier::default()))));
+    let attachments = AttachmentUseCases::new(Arc::new(MockAttachmentRepository::default()), Arc::new(MockAttachmentStorage::default()), use_cases.clone())
+        .with_max_size_bytes(MAX_TEST_ATTACHMENT_BYTES);
+    let attachments = AttachmentController::new(Arc::new(attachments));
+    let clones = TaskCloneController::new(Arc::new(TaskCloneUseCases::new(use_cases.clone(), workspaces.clone())));
+    create_router(Arc::new(TaskController::new(use_cases)), Arc::new(ApiKeyController::new(api_keys)), Arc::new(jobs), Arc::new(automation), Arc::new(WorkspaceController::new(workspaces)), Arc::new(attachments), Arc::new(clones), Arc::new(realtime), Arc::new(health), context)
+}
+
+/// Wraps the router in the transaction layer over a pool whose database never answers
+fn with_unreachable_transaction_pool(router: Router) -> Router {
+    let pool = sqlx::postgres::PgPoolOptions::new()
+        .acquire_timeout(Duration::from_millis(200))
+        .connect_lazy("postgres://tester@127.0.0.1:1/unreachable")
+        .unwrap();
+    router.layer(axum::middleware::from_fn_with_state(pool, transaction_middleware))
+}
+
+async fn send(router: Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    send_with_headers(router, method, uri, body, &[]).await
+}
+
+async fn send_as(router: Router, role: &str, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    send_with_headers(router, method, uri, body, &[("x-user-id", "tester"), ("x-user-role", role)]).await
+}
+
+async fn send_with_headers(
+    router: Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+    headers: &[(&str, &str)],
+) -> (StatusCode, Value) {
+    let mut request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json");
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let request = request
+        .body(body.map_or_else(Body::empty, |json| Body::from(json.to_string())))
+        .unwrap();
+
+    let response = router.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, json)
+}
+
+/// Posts `file` as the `file` field of a multipart form
+async fn upload(router: Router, uri: &str, file_name: &str, file: &[u8]) -> (StatusCode, Value) {
+    let mut body = format!(
+        "--test-boundary\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: text/plain\r\n\r\n",
+        file_name
+    ).into_bytes();
+    body.extend_from_slice(file);
+    body.extend_from_slice(b"\r\n--test-boundary--\r\n");
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "multipart/form-data; boundary=test-boundary")
+        .body(Body::from(body))
+        .unwrap();
+
+    let response = router.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pending_task() -> Task {
+        Task::new(TaskId::new(1), "Routed task".to_string(), Some(5)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_update_task_status_route() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send(router, "PATCH", "/tasks/1/status", Some(json!({"status": "InProgress"}))).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["status"], "InProgress");
+    }
+
+    #[tokio::test]
+    async fn test_update_task_status_route_rejects_invalid_transition() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send(router, "PATCH", "/tasks/1/status", Some(json!({"status": "Completed"}))).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["success"], false);
+    }
+
+    #[tokio::test]
+    async fn test_get_task_transitions_route() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send(router, "GET", "/tasks/1/transitions", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["valid_transitions"], json!(["InProgress", "Cancelled"]));
+    }
+
+    #[tokio::test]
+    async fn test_get_task_history_route() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send(router, "GET", "/tasks/1/history", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["task_id"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_task_history_route_validates_filters() {
+        let uri = "/tasks/1/history?to_status=Completed&changed_by=reviewer&order=desc&limit=10&offset=20";
+        let (status, body) = send(create_test_router(vec![pending_task()]), "GET", uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["limit"], 10);
+        assert_eq!(body["data"]["offset"], 20);
+        assert_eq!(body["data"]["total_entries"], 0);
+
+        for uri in [
+            "/tasks/1/history?to_status=Done",
+            "/tasks/1/history?order=newest",
+            "/tasks/1/history?limit=0",
+            "/tasks/1/history?start_date=2025-02-01T00:00:00Z&end_date=2025-01-01T00:00:00Z",
+        ] {
+            let (status, _) = send(create_test_router(vec![pending_task()]), "GET", uri, None).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_global_history_route_is_for_managers() {
+        let uri = "/history?from=2025-01-01T00:00:00Z&to=2025-02-01T00:00:00Z&user=alice&status=Completed&limit=5";
+        let (status, body) = send_as(create_test_router(vec![]), "Manager", "GET", uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["limit"], 5);
+        assert_eq!(body["data"]["history"], json!([]));
+
+        let (status, _) = send_as(create_test_router(vec![]), "User", "GET", "/history", None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = send_as(create_test_router(vec![]), "Admin", "GET", "/history?status=Done", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_history_pages_follow_signed_cursors() {
+        let router = create_in_memory_router();
+        for name in ["First", "Second", "Third"] {
+            let (status, _) = send(router.clone(), "POST", "/tasks", Some(json!({"name": name, "priority": 3}))).await;
+            assert_eq!(status, StatusCode::CREATED);
+        }
+
+        let (status, first) = send_as(router.clone(), "Manager", "GET", "/history?limit=2", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(first["data"]["history"].as_array().unwrap().len(), 2);
+        let cursor = first["data"]["next_cursor"].as_str().unwrap().to_string();
+
+        let (status, second) = send_as(router.clone(), "Manager", "GET", &format!("/history?limit=2&cursor={}", cursor), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(second["data"]["history"].as_array().unwrap().len(), 1);
+        assert_eq!(second["data"]["next_cursor"], Value::Null);
+        assert_eq!(second["data"]["total_entries"], 3);
+        let task_ids: Vec<_> = [&first, &second].iter()
+            .flat_map(|page| page["data"]["history"].as_array().unwrap().iter().map(|entry| entry["task_id"].as_i64().unwrap()))
+            .collect();
+        assert_eq!(task_ids, vec![1, 2, 3]);
+
+        let tampered = format!("{}A", cursor);
+        let refused = [
+            (format!("/history?limit=2&cursor={}", tampered), "Invalid cursor"),
+            (format!("/history?limit=2&order=desc&cursor={}", cursor), "other filters"),
+            (format!("/history?limit=2&user=alice&cursor={}", cursor), "other filters"),
+            (format!("/history?limit=2&offset=2&cursor={}", cursor), "offset or by cursor"),
+            (format!("/tasks/1/history?limit=2&cursor={}", cursor), "other filters"),
+        ];
+        for (uri, message) in refused {
+            let (status, body) = send_as(router.clone(), "Manager", "GET", &uri, None).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", uri);
+            assert!(body["message"].as_str().unwrap().contains(message), "{}: {}", uri, body["message"]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transition_matrix_route() {
+        let (status, body) = send(create_test_router(vec![]), "GET", "/analytics/transitions", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["total_transitions"], 0);
+
+        let uri = "/analytics/transitions?start_date=2025-02-01T00:00:00Z&end_date=2025-01-01T00:00:00Z";
+        let (status, _) = send(create_test_router(vec![]), "GET", uri, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = send(create_test_router(vec![]), "GET", "/analytics/transitions?start=2026-01-01&end=2026-01-31", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["period_end"], "2026-01-31T23:59:59.999999Z");
+
+        for query in ["start=2024-01-01&end=2026-01-01", "end=31/01/2026"] {
+            let (status, _) = send(create_test_router(vec![]), "GET", &format!("/analytics/transitions?{}", query), None).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", query);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_completion_and_task_analytics_routes() {
+        let router = create_in_memory_router();
+        let (status, _) = send(router.clone(), "POST", "/tasks", Some(json!({"name": "Ship it", "priority": 5}))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        for status in ["InProgress", "Completed"] {
+            let (code, _) = send(router.clone(), "PATCH", "/tasks/1/status", Some(json!({"status": status}))).await;
+            assert_eq!(code, StatusCode::OK);
+        }
+
+        let (status, body) = send(router.clone(), "GET", "/analytics/completion", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["total_completed_tasks"], 1);
+        assert_eq!(body["data"]["completion_times_by_priority"][0]["task_count"], 1);
+        let today = chrono::Utc::now().date_naive();
+        let uri = format!("/v2/analytics/completion?start={}&end={}", today, today);
+        let (status, body) = send(router.clone(), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["totalCompletedTasks"], 1);
+        let uri = format!("/analytics/completion?end={}", today - chrono::Duration::days(1));
+        let (_, body) = send(router.clone(), "GET", &uri, None).await;
+        assert_eq!(body["data"]["total_completed_tasks"], 0);
+
+        let (status, body) = send(router.clone(), "GET", "/analytics/completion?start=yesterday", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["message"], "start must be an RFC 3339 timestamp or a YYYY-MM-DD date, got 'yesterday'");
+        for query in ["start=2026-02-01&end=2026-01-31", "start=2024-01-01T00:00:00Z&end=2026-01-01T00:00:00Z"] {
+            let (status, _) = send(router.clone(), "GET", &format!("/analytics/completion?{}", query), None).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", query);
+        }
+
+        let (status, body) = send(router.clone(), "GET", "/analytics/tasks/1", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["task_id"], 1);
+        assert_eq!(body["data"]["number_of_transitions"], 3);
+        let (status, _) = send(router, "GET", "/analytics/tasks/99", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_cumulative_flow_route_counts_tasks_per_status_each_day() {
+        let router = create_in_memory_router();
+        for name in ["Draft", "Build"] {
+            let (status, _) = send(router.clone(), "POST", "/tasks", Some(json!({"name": name, "priority": 5}))).await;
+            assert_eq!(status, StatusCode::CREATED);
+        }
+        let (code, _) = send(router.clone(), "PATCH", "/tasks/2/status", Some(json!({"status": "InProgress"}))).await;
+        assert_eq!(code, StatusCode::OK);
+
+        let today = chrono::Utc::now().date_naive();
+        let uri = format!("/analytics/cumulative-flow?start={}&end={}", today - chrono::Duration::days(2), today);
+        let (status, body) = send(router.clone(), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["bucket"], "day");
+        assert_eq!(body["data"]["statuses"], json!(["Pending", "InProgress"]));
+        let buckets = body["data"]["buckets"].as_array().unwrap();
+        assert_eq!(buckets.len(), 3);
+        assert_eq!(buckets[0]["total"], 0);
+        assert_eq!(buckets[2]["statuses"], json!([{"status": "Pending", "count": 1}, {"status": "InProgress", "count": 1}]));
+
+        let (status, body) = send(router.clone(), "GET", "/v2/analytics/cumulative-flow?bucket=month", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["buckets"].as_array().unwrap().last().unwrap()["total"], 2);
+        assert!(body["data"]["periodStart"].is_string());
+
+        for query in ["bucket=quarter", "start=2026-02-01&end=2026-01-31"] {
+            let (status, _) = send(router.clone(), "GET", &format!("/analytics/cumulative-flow?{}", query), None).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", query);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cohort_analysis_route() {
+        let (status, body) = send(create_test_router(vec![]), "GET", "/analytics/cohorts?bucket=month", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["bucket"], "month");
+
+        let (status, _) = send(create_test_router(vec![]), "GET", "/analytics/cohorts?bucket=quarter", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = send(create_test_router(vec![]), "GET", "/analytics/cohorts?start=2026-01-01&end=2026-03-31", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["period_start"], "2026-01-01T00:00:00Z");
+
+        for query in ["start=2024-01-01&end=2026-01-01", "start=2026-02-01&end=2026-01-01"] {
+            let (status, _) = send(create_test_router(vec![]), "GET", &format!("/analytics/cohorts?{}", query), None).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", query);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reports_render_as_plain_text() {
+        let request = Request::builder().uri("/analytics/transitions?format=txt").body(Body::empty()).unwrap();
+        let response = create_test_router(vec![]).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "text/plain; charset=utf-8");
+        let text = String::from_utf8(to_bytes(response.into_body(), usize::MAX).await.unwrap().to_vec()).unwrap();
+        assert!(text.starts_with("Status transitions from "));
+        assert!(text.contains("Total transitions: 0"));
+
+        let request = Request::builder().uri("/analytics/cumulative-flow?start=2026-01-01&end=2026-01-02&format=txt").body(Body::empty()).unwrap();
+        let response = create_test_router(vec![]).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "text/plain; charset=utf-8");
+        let text = String::from_utf8(to_bytes(response.into_body(), usize::MAX).await.unwrap().to_vec()).unwrap();
+        assert!(text.starts_with("Tasks in each status by day from 2026-01-01 00:00 UTC"));
+
+        let (status, body) = send(create_test_router(vec![]), "GET", "/analytics/cohorts?format=pdf", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["message"], "Unknown report format 'pdf'; use json or txt");
+    }
+
+    #[tokio::test]
+    async fn test_dependency_routes() {
+        let router = create_test_router(vec![pending_task(), Task::new(TaskId::new(2), "Blocker".to_string(), Some(5)).unwrap()]);
+
+        let (status, body) = send(router.clone(), "POST", "/tasks/1/dependencies", Some(json!({"depends_on": 2}))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["data"]["blocked_by"], json!([2]));
+
+        let (_, body) = send(router.clone(), "GET", "/tasks/1/transitions", None).await;
+        assert_eq!(body["data"]["blocked_by"], json!([2]));
+
+        let (status, body) = send(router.clone(), "DELETE", "/tasks/1/dependencies/2", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["depends_on"], json!([]));
+
+        let (status, _) = send(router, "DELETE", "/tasks/1/dependencies/2", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_get_task_analytics_route_without_history() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, _) = send(router, "GET", "/tasks/1/analytics", None).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_workflow_routes_return_not_found_for_missing_task() {
+        let router = create_test_router(vec![]);
+
+        let (status, _) = send(router, "GET", "/tasks/42/transitions", None).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+
+    #[tokio::test]
+    async fn test_deleting_a_task_needs_an_elevated_role() {
+        let router = create_test_router(vec![pending_task()]);
+
+        for uri in ["/tasks/1", "/v2/tasks/1"] {
+            let (status, body) = send_as(router.clone(), "User", "DELETE", uri, None).await;
+            assert_eq!(status, StatusCode::FORBIDDEN);
+            assert_eq!(body["success"], false);
+            assert_eq!(body["code"], "forbidden");
+            assert_eq!(body["message"], format!("DELETE {} requires the Manager or Admin role", uri));
+            assert!(body.get("request_id").or(body.get("requestId")).is_some());
+        }
+
+        let (status, _) = send_as(router, "Manager", "DELETE", "/tasks/1", None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_users_cannot_change_tasks_created_by_someone_else() {
+        let task = pending_task().with_created_by(Some("someone-else".to_string()));
+        let router = create_test_router(vec![task]);
+        let rename = json!({"name": "Renamed"});
+
+        let (status, body) = send_as(router.clone(), "User", "PATCH", "/tasks/1", Some(rename.clone())).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "forbidden");
+        assert_eq!(body["message"], "Only the task's creator, a manager or an admin can update task 1");
+
+        let (status, body) = send_as(router.clone(), "User", "PATCH", "/tasks/1/status", Some(json!({"status": "Cancelled"}))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["message"], "Only the task's creator, a manager or an admin can cancel task 1");
+
+        let (status, _) = send_as(router, "Manager", "PATCH", "/tasks/1", Some(rename)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_restore_task_route() {
+        let deleted = pending_task().with_deleted_at(Some(chrono::Utc::now()));
+        let router = create_test_router(vec![deleted]);
+
+        let (status, body) = send(router.clone(), "POST", "/tasks/1/restore", None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "forbidden");
+
+        let (status, body) = send_as(router, "Admin", "POST", "/tasks/1/restore", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["id"], 1);
+        assert!(body["data"].get("deleted_at").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_restore_task_route_rejects_live_task() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, _) = send_as(router, "Admin", "POST", "/tasks/1/restore", None).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_route_hides_deleted_unless_requested() {
+        let deleted = Task::new(TaskId::new(2), "Deleted task".to_string(), Some(5)).unwrap()
+            .with_deleted_at(Some(chrono::Utc::now()));
+        let tasks = vec![pending_task(), deleted];
+
+        let (_, body) = send(create_test_router(tasks.clone()), "GET", "/tasks", None).await;
+        assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 1);
+
+        let (status, _) = send_as(create_test_router(tasks.clone()), "Manager", "GET", "/tasks?include_deleted=true", None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = send_as(create_test_router(tasks), "Admin", "GET", "/tasks?include_deleted=true&priority=5", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let listed = body["data"]["tasks"].as_array().unwrap();
+        assert_eq!(listed.len(), 2);
+        assert!(listed[1]["deleted_at"].is_string());
+    }
+
+
+    #[tokio::test]
+    async fn test_search_tasks_route() {
+        let other = Task::new(TaskId::new(2), "Write docs".to_string(), Some(3)).unwrap();
+        let router = create_test_router(vec![pending_task(), other]);
+
+        let (status, body) = send(router, "GET", "/tasks/search?q=docs", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        let tasks = body["data"]["tasks"].as_array().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0]["id"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_search_tasks_route_takes_a_filter_expression() {
+        let other = Task::new(TaskId::new(2), "Write docs".to_string(), Some(3)).unwrap();
+        let router = create_test_router(vec![pending_task(), other]);
+
+        // status = Pending and priority <= 3
+        let (status, body) = send(router.clone(), "GET", "/tasks/search?filter=status%20%3D%20Pending%20and%20priority%20%3C%3D%203", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let ids: Vec<_> = body["data"]["tasks"].as_array().unwrap().iter().map(|task| task["id"].clone()).collect();
+        assert_eq!(ids, vec![json!(2)]);
+
+        let (status, body) = send(router.clone(), "GET", "/tasks/search?q=task&filter=priority%20%3E%204", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["tasks"][0]["id"], 1);
+
+        let (status, body) = send(router, "GET", "/tasks/search?filter=priority%20%3C%3D%20high", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_filter");
+        assert_eq!(body["message"], "Invalid filter: `high` at position 13 is not a whole number");
+    }
+
+    #[tokio::test]
+    async fn test_task_facets_route_counts_matching_tasks() {
+        let other = Task::new(TaskId::new(2), "Write docs".to_string(), Some(3)).unwrap();
+        let router = create_test_router(vec![pending_task(), other]);
+
+        let (status, body) = send(router.clone(), "GET", "/tasks/facets", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["total"], 2);
+        assert_eq!(body["data"]["statuses"], json!([{"status": "Pending", "count": 2}]));
+        assert_eq!(body["data"]["priorities"], json!([
+            {"priority": 3, "priority_label": "High", "count": 1},
+            {"priority": 5, "priority_label": "High", "count": 1},
+        ]));
+
+        let (status, body) = send(router.clone(), "GET", "/v2/tasks/facets?filter=priority%20%3E%204", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["total"], 1);
+        assert_eq!(body["data"]["priorities"][0]["priorityLabel"], "High");
+
+        let (status, body) = send(router, "GET", "/tasks/facets?filter=owner%20%3D%20me", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_filter");
+    }
+
+    #[tokio::test]
+    async fn test_board_route_groups_tasks_by_workflow_status() {
+        let now = chrono::Utc::now();
+        let started = Task::new_with_status(TaskId::new(2), "Write docs".to_string(), Some(3), TaskStatus::InProgress, now, now).unwrap();
+        let queued = Task::new(TaskId::new(3), "Plan release".to_string(), Some(1)).unwrap();
+        let repository = MockRepository::new().with_tasks(vec![pending_task(), started, queued]);
+        let use_cases = TaskUseCases::new(Arc::new(repository), Arc::new(MockStatusHistoryRepository), Arc::new(MockTaskDependencyRepository::default()))
+            .with_wip_limits(WipLimits::parse("*=Pending:1,InProgress:2").unwrap());
+        let router = build_router(use_cases, &InMemoryTaskRepository::new(), MockDatabaseProbe::Healthy, MockBackgroundJobRepository::default());
+
+        let (status, body) = send(router.clone(), "GET", "/board?sort_by=priority&limit=1", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let columns = body["data"]["columns"].as_array().unwrap();
+        let statuses: Vec<_> = columns.iter().map(|column| column["status"].as_str().unwrap()).collect();
+        assert_eq!(statuses, ["Pending", "InProgress", "PendingReview", "Completed", "Cancelled"]);
+        assert_eq!(columns[0]["count"], 2);
+        assert_eq!(columns[0]["category"], "open");
+        assert_eq!(columns[0]["wip_limit"], 1);
+        assert_eq!(columns[0]["over_limit"], true);
+        assert_eq!(columns[0]["tasks"].as_array().unwrap().len(), 1);
+        assert_eq!(columns[0]["tasks"][0]["id"], 3);
+        assert_eq!(columns[1]["over_limit"], false);
+        assert_eq!(columns[2]["count"], 0);
+        assert_eq!(columns[2]["wip_limit"], Value::Null);
+
+        let (status, body) = send(router.clone(), "GET", "/v2/board?filter=priority%20%3E%204", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["columns"][0]["count"], 1);
+        assert_eq!(body["data"]["columns"][0]["overLimit"], false);
+
+        let (status, _) = send(router, "GET", "/board?limit=0", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_move_task_route_reorders_the_board_column() {
+        let router = create_in_memory_router();
+        for name in ["First", "Second", "Third"] {
+            let (status, _) = send(router.clone(), "POST", "/tasks", Some(json!({"name": name, "priority": 3}))).await;
+            assert_eq!(status, StatusCode::CREATED);
+        }
+
+        let (status, body) = send(router.clone(), "PATCH", "/tasks/3/position", Some(json!({"after_task_id": 1, "before_task_id": 2}))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["status"], "Pending");
+        let (status, _) = send(router.clone(), "PATCH", "/v2/tasks/2/position", Some(json!({"beforeTaskId": 1}))).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (_, body) = send(router.clone(), "GET", "/board", None).await;
+        let ids: Vec<_> = body["data"]["columns"][0]["tasks"].as_array().unwrap().iter().map(|task| task["id"].as_i64().unwrap()).collect();
+        assert_eq!(ids, [2, 1, 3]);
+
+        let (status, body) = send(router.clone(), "PATCH", "/tasks/3/position", Some(json!({"after_task_id": 1, "before_task_id": 2}))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "neighbours_out_of_order");
+        let (status, _) = send(router.clone(), "PATCH", "/tasks/3/position", Some(json!({}))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = send(router, "PATCH", "/tasks/3/position", Some(json!({"after_task_id": 42}))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_stats_route_counts_tasks_and_daily_throughput() {
+        let router = create_in_memory_router();
+        for (name, priority) in [("First", 5), ("Second", 5), ("Third", 4)] {
+            let (status, _) = send(router.clone(), "POST", "/tasks", Some(json!({"name": name, "priority": priority}))).await;
+            assert_eq!(status, StatusCode::CREATED);
+        }
+        for status in ["InProgress", "Completed"] {
+            let (code, _) = send(router.clone(), "PATCH", "/tasks/1/status", Some(json!({"status": status}))).await;
+            assert_eq!(code, StatusCode::OK);
+        }
+
+        let (status, body) = send(router.clone(), "GET", "/tasks/stats?days=3", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["total"], 3);
+        let statuses: Vec<_> = body["data"]["statuses"].as_array().unwrap().iter().map(|facet| (facet["status"].as_str().unwrap(), facet["count"].as_u64().unwrap())).collect();
+        assert_eq!(statuses, [("Pending", 2), ("Completed", 1)]);
+        assert_eq!(body["data"]["priorities"][1]["count"], 2);
+        assert_eq!(body["data"]["overdue"], 0);
+        let days = body["data"]["days"].as_array().unwrap();
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[2]["date"], chrono::Utc::now().date_naive().to_string());
+        assert_eq!((days[2]["created"].as_u64(), days[2]["completed"].as_u64()), (Some(3), Some(1)));
+        assert_eq!(days[0]["created"], 0);
+
+        let (status, _) = send(router, "GET", "/tasks/stats?days=0", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_search_tasks_route_requires_query() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send(router, "GET", "/tasks/search", None).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["message"], "Search query cannot be empty");
+    }
+
+
+    #[tokio::test]
+    async fn test_list_tasks_route_sorts_results() {
+        let tasks = vec![
+            Task::new(TaskId::new(1), "Bravo".to_string(), Some(2)).unwrap(),
+            Task::new(TaskId::new(2), "Alpha".to_string(), Some(9)).unwrap(),
+            Task::new(TaskId::new(3), "Charlie".to_string(), Some(5)).unwrap(),
+        ];
+
+        let (status, body) = send(create_test_router(tasks.clone()), "GET", "/tasks?sort_by=priority&order=desc", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let ids: Vec<i64> = body["data"]["tasks"].as_array().unwrap().iter().map(|t| t["id"].as_i64().unwrap()).collect();
+        assert_eq!(ids, vec![2, 3, 1]);
+
+        let (_, body) = send(create_test_router(tasks), "GET", "/tasks?sort_by=name", None).await;
+        assert_eq!(body["data"]["tasks"][0]["name"], "Alpha");
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_route_rejects_unknown_sort_field() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send(router, "GET", "/tasks?sort_by=owner", None).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["message"].as_str().unwrap().starts_with("Invalid sort field: owner"));
+    }
+
+
+    #[tokio::test]
+    async fn test_list_tasks_route_filters_by_status_and_priority() {
+        let in_progress = |id: i32, priority: i32| {
+            let mut task = Task::new(TaskId::new(id), format!("Task {}", id), Some(priority)).unwrap();
+            task.transition_to(TaskStatus::InProgress).unwrap();
+            task
+        };
+        let tasks = vec![pending_task(), in_progress(2, 5), in_progress(3, 8)];
+
+        let (status, body) = send(create_test_router(tasks.clone()), "GET", "/tasks?status=InProgress", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 2);
+
+        let (_, body) = send(create_test_router(tasks), "GET", "/tasks?status=InProgress&priority=5", None).await;
+        let listed = body["data"]["tasks"].as_array().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0]["id"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_route_rejects_unknown_status() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send(router, "GET", "/tasks?status=Done", None).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["message"], "Invalid task status: Done");
+    }
+
+
+
+    #[tokio::test]
+    async fn test_stale_tasks_route_is_not_a_task_id() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send(router, "GET", "/tasks/stale", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["tasks"], serde_json::json!([]));
+    }
+
+
+
+    #[tokio::test]
+    async fn test_archived_tasks_are_listed_on_their_own_route() {
+        let router = create_in_memory_router();
+        for name in ["Dropped", "Open"] {
+            let (status, _) = send_as(router.clone(), "Admin", "POST", "/tasks", Some(json!({"name": name, "priority": 1}))).await;
+            assert_eq!(status, StatusCode::CREATED);
+        }
+
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/tasks/2/archive", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "archive_unfinished");
+
+        let (status, _) = send_as(router.clone(), "Admin", "PATCH", "/tasks/1/status", Some(json!({"status": "Cancelled", "comment": "Out of scope"}))).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/tasks/1/archive", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["data"]["archived_at"].is_string());
+
+        let (_, body) = send_as(router.clone(), "Admin", "GET", "/tasks", None).await;
+        assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 1);
+        assert_eq!(body["data"]["tasks"][0]["name"], "Open");
+        let (status, body) = send_as(router, "Admin", "GET", "/tasks/archived", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["tasks"][0]["name"], "Dropped");
+    }
+
+    #[tokio::test]
+    async fn test_liveness_does_not_check_database() {
+        let router = create_test_router_with_database(vec![], MockDatabaseProbe::Failing("down".to_string()));
+
+        let (status, body) = send(router, "GET", "/health/live", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "alive");
+    }
+
+    #[tokio::test]
+    async fn test_readiness_reports_pool_stats() {
+        let (status, body) = send(create_test_router(vec![]), "GET", "/health/ready", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["database"], "up");
+        assert_eq!(body["pool"]["size"], 2);
+        assert_eq!(body["pool"]["idle"], 1);
+        assert_eq!(body["pool"]["max_connections"], 16);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_fails_when_database_is_unreachable() {
+        let router = create_test_router_with_database(vec![], MockDatabaseProbe::Failing("connection refused".to_string()));
+
+        let (status, body) = send(router, "GET", "/health/ready", None).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["database"], "down");
+        assert_eq!(body["error"], "connection refused");
+    }
+
+    #[tokio::test]
+    async fn test_readiness_times_out() {
+        let router = create_test_router_with_database(vec![], MockDatabaseProbe::Hanging);
+
+        let (status, body) = send(router, "GET", "/health/ready", None).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["error"], "database did not respond within 50ms");
+    }
+
+    #[tokio::test]
+    async fn test_diagnostics_reports_connections_without_checking_database() {
+        let router = create_test_router_with_database(vec![], MockDatabaseProbe::Failing("down".to_string()));
+
+        let (status, body) = send_as(router, "Admin", "GET", "/health/diagnostics", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["pool"]["size"], 2);
+        assert_eq!(body["realtime_connections"], 0);
+        assert!(body["uptime_seconds"].is_u64());
+        assert_eq!(body["allocator"]["allocated"].is_u64(), cfg!(feature = "jemalloc"));
+    }
+
+    #[tokio::test]
+    async fn test_diagnostics_are_for_admins_while_probes_stay_open() {
+        let router = create_test_router(vec![]);
+
+        for role in ["User", "Manager"] {
+            let (status, _) = send_as(router.clone(), role, "GET", "/health/diagnostics", None).await;
+            assert_eq!(status, StatusCode::FORBIDDEN, "{}", role);
+        }
+        let (status, _) = send_as(router.clone(), "Admin", "GET", "/v2/health/diagnostics", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send_as(router.clone(), "User", "GET", "/health/live", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send(router, "GET", "/health/ready", None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+
+
+    #[tokio::test]
+    async fn test_request_context_rejects_unknown_role() {
+        let (status, body) = send_as(create_test_router(vec![]), "Owner", "GET", "/tasks", None).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["message"], "Invalid user role: Owner");
+    }
+
+
+
+    async fn request_id_of(router: Router, uri: &str, request_id: Option<&str>) -> (String, Value) {
+        let mut request = Request::builder().uri(uri);
+        if let Some(request_id) = request_id {
+            request = request.header("x-request-id", request_id);
+        }
+        let response = router.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+        let header = response.headers()["x-request-id"].to_str().unwrap().to_string();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (header, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn test_request_id_is_echoed_or_generated() {
+        let (echoed, _) = request_id_of(create_test_router(vec![]), "/tasks", Some("support-123")).await;
+        assert_eq!(echoed, "support-123");
+
+        let (generated, _) = request_id_of(create_test_router(vec![]), "/tasks", None).await;
+        assert_eq!(generated.len(), 36);
+
+        let (replaced, _) = request_id_of(create_test_router(vec![]), "/tasks", Some("has spaces")).await;
+        assert_ne!(replaced, "has spaces");
+    }
+
+    #[tokio::test]
+    async fn test_error_payload_carries_request_id() {
+        let (request_id, body) = request_id_of(create_test_router(vec![]), "/tasks/42", Some("trace-me")).await;
+
+        assert_eq!(request_id, "trace-me");
+        assert_eq!(body["success"], false);
+        assert_eq!(body["request_id"], "trace-me");
+
+        let (_, body) = request_id_of(create_test_router(vec![pending_task()]), "/tasks/1", Some("trace-me")).await;
+        assert!(body.get("request_id").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_api_key_authenticates_machine_client() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/admin/api-keys", Some(json!({"name": "ci-bot", "role": "Manager"}))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["data"]["role"], "Manager");
+        let key = body["data"]["key"].as_str().unwrap().to_string();
+        assert!(key.starts_with("tk_"));
+
+        let (status, body) = send_with_headers(router.clone(), "PATCH", "/tasks/1/status", Some(json!({"status": "InProgress"})), &[("x-api-key", &key)]).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["status"], "InProgress");
+
+        let (status, body) = send_with_headers(router, "GET", "/tasks", None, &[("x-api-key", "tk_unknown")]).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["message"], "Invalid API key");
+    }
+
+    #[tokio::test]
+    async fn test_only_admins_create_api_keys() {
+        let (status, _) = send_as(create_test_router(vec![]), "Manager", "POST", "/admin/api-keys", Some(json!({"name": "ci-bot"}))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = send_as(create_test_router(vec![]), "Admin", "POST", "/admin/api-keys", Some(json!({"name": "ci-bot", "role": "System"}))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_delete_route_previews_before_deleting() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/tasks/bulk/delete", Some(json!({"status": "Pending"}))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["deleted"], false);
+        assert_eq!(body["data"]["count"], 1);
+        assert_eq!(body["data"]["sample_names"], json!(["Routed task"]));
+        let token = body["data"]["confirmation_token"].as_str().unwrap().to_string();
+
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/tasks/bulk/delete", Some(json!({"status": "Pending", "confirmation_token": "1.00"}))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["message"].as_str().unwrap().contains("does not match"));
+
+        let (status, _) = send_as(router.clone(), "Manager", "POST", "/tasks/bulk/delete", Some(json!({"status": "Pending"}))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = send_as(router, "Admin", "POST", "/tasks/bulk/delete", Some(json!({"status": "Pending", "confirmation_token": token}))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["deleted"], true);
+        assert_eq!(body["data"]["confirmation_token"], Value::Null);
+    }
+
+    #[tokio::test]
+    async fn test_admins_pause_resume_and_trigger_background_jobs() {
+        let jobs = MockBackgroundJobRepository::default();
+        jobs.register("stale-task-sweep", Duration::from_secs(3600), chrono::Utc::now()).await.unwrap();
+        let router = create_test_router_with(vec![], MockDatabaseProbe::Healthy, jobs);
+
+        let (status, body) = send_as(router.clone(), "Admin", "GET", "/admin/jobs", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"][0]["name"], "stale-task-sweep");
+        assert_eq!(body["data"][0]["interval_secs"], 3600);
+        assert_eq!(body["data"][0]["paused"], false);
+
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/admin/jobs/stale-task-sweep/pause", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["paused"], true);
+        assert_eq!(body["data"]["next_run_at"], Value::Null);
+
+        let (_, body) = send_as(router.clone(), "Admin", "POST", "/admin/jobs/stale-task-sweep/trigger", None).await;
+        assert_eq!(body["data"]["run_requested"], true);
+        assert_ne!(body["data"]["next_run_at"], Value::Null);
+
+        let (_, body) = send_as(router.clone(), "Admin", "POST", "/admin/jobs/stale-task-sweep/resume", None).await;
+        assert_eq!(body["data"]["paused"], false);
+
+        let (status, _) = send_as(router.clone(), "Admin", "POST", "/admin/jobs/nightly-report/trigger", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = send_as(router, "Manager", "GET", "/admin/jobs", None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_managers_create_update_and_delete_automation_rules() {
+        let router = create_test_router(vec![]);
+        let rule = json!({
+            "name": "Prioritise work in review",
+            "trigger": {"event_type": "task_status_changed", "filter": {"to_status": "PendingReview"}},
+            "action": {"type": "set_priority", "priority": 9}
+        });
+
+        let (status, body) = send_as(router.clone(), "Manager", "POST", "/automation/rules", Some(rule.clone())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["data"]["enabled"], true);
+        assert_eq!(body["data"]["created_by"], "tester");
+        let id = body["data"]["id"].as_str().unwrap().to_string();
+
+        let mut disabled = rule.clone();
+        disabled["enabled"] = json!(false);
+        let (status, body) = send_as(router.clone(), "Manager", "PUT", &format!("/automation/rules/{}", id), Some(disabled)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["enabled"], false);
+
+        let (_, body) = send_as(router.clone(), "Admin", "GET", "/automation/rules", None).await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+        assert_eq!(body["data"][0]["trigger"]["filter"]["to_status"], "PendingReview");
+
+        let (status, _) = send_as(router.clone(), "User", "GET", "/automation/rules", None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let mut out_of_scale = rule.clone();
+        out_of_scale["action"]["priority"] = json!(99);
+        let (status, _) = send_as(router.clone(), "Manager", "POST", "/automation/rules", Some(out_of_scale)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send_as(router.clone(), "Manager", "DELETE", &format!("/automation/rules/{}", id), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_as(router, "Manager", "GET", &format!("/automation/rules/{}", id), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_routes_without_transactional_extractor_never_open_a_transaction() {
+        let router = with_unreachable_transaction_pool(create_test_router(vec![pending_task()]));
+
+        let (status, body) = send(router, "GET", "/tasks/1", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["name"], "Routed task");
+    }
+
+    #[tokio::test]
+    async fn test_transactional_route_fails_when_transaction_cannot_begin() {
+        let router = with_unreachable_transaction_pool(create_test_router(vec![pending_task()]));
+
+        let (status, body) = send(router, "PATCH", "/tasks/1/status", Some(json!({"status": "InProgress"}))).await;
+
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(body["message"].as_str().unwrap().starts_with("Failed to begin transaction"));
+    }
+
+
+    #[tokio::test]
+    async fn test_malformed_json_gets_an_error_envelope() {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/tasks")
+            .header("content-type", "application/json")
+            .body(Body::from("{\"name\": "))
+            .unwrap();
+        let response = create_test_router(vec![]).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body: Value = serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await.unwrap()).unwrap();
+        assert_eq!(body["success"], false);
+        assert!(body["message"].as_str().unwrap().starts_with("Failed to parse the request body as JSON"));
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_is_rejected_with_an_error_envelope() {
+        let router = create_test_router(vec![]).layer(axum::extract::DefaultBodyLimit::max(32));
+        let name = "x".repeat(64);
+
+        let (status, body) = send(router, "POST", "/tasks", Some(json!({"name": name}))).await;
+
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(body["success"], false);
+        assert!(body["message"].as_str().unwrap().contains("length limit exceeded"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_fields_get_422_with_field_errors() {
+        let router = create_test_router(vec![]);
+
+        let (status, body) = send(router, "POST", "/tasks", Some(json!({"name": "", "priority": 42}))).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "invalid_fields");
+        assert_eq!(body["errors"], json!([
+            {"field": "name", "code": "empty_name", "message": "Task name cannot be empty"},
+            {"field": "priority", "code": "priority_out_of_range", "message": "Priority must be between 1 and 10"},
+        ]));
+        assert_eq!(body["message"], "Task name cannot be empty; Priority must be between 1 and 10");
+    }
+
+    #[tokio::test]
+    async fn test_payloads_breaking_the_schema_get_json_pointers() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = send(router.clone(), "PATCH", "/tasks/1/status", Some(json!({"status": 7, "checklist": [{"item": 1, "checked": true}], "note": "x"}))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "invalid_fields");
+        let fields: Vec<_> = body["errors"].as_array().unwrap().iter().map(|error| (error["field"].as_str().unwrap(), error["code"].as_str().unwrap())).collect();
+        assert_eq!(fields, vec![("/checklist/0/item", "invalid_type"), ("/note", "unknown_field"), ("/status", "invalid_type")]);
+
+        let (status, body) = send(router.clone(), "POST", "/v2/tasks", Some(json!({"priority": "High"}))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["errors"][0]["message"], "/name is required");
+
+        let (status, _) = send(router, "POST", "/tasks", Some(json!({"name": "Ship it", "priority": "high"}))).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_v2_routes_speak_camel_case() {
+        let router = create_test_router(vec![pending_task(), Task::new(TaskId::new(2), "Blocker".to_string(), Some(9)).unwrap()]);
+
+        let (status, body) = send(router.clone(), "POST", "/v2/tasks/1/dependencies", Some(json!({"dependsOn": 2}))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["data"]["taskId"], 1);
+        assert_eq!(body["data"]["blockedBy"], json!([2]));
+
+        let (_, body) = send(router.clone(), "GET", "/v2/tasks?sortBy=priority&order=desc", None).await;
+        assert_eq!(body["data"]["tasks"][0]["id"], 2);
+        assert!(body["data"]["tasks"][0]["createdAt"].is_string());
+        assert!(body["data"]["tasks"][0].get("created_at").is_none());
+
+        let (status, body) = send(router.clone(), "GET", "/v2/tasks/9", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(body["requestId"].is_string());
+
+        let (_, body) = send(router, "GET", "/tasks/1/transitions", None).await;
+        assert_eq!(body["data"]["blocked_by"], json!([2]));
+    }
+
+    #[tokio::test]
+    async fn test_v2_routes_keep_the_body_limit() {
+        let router = create_test_router(vec![]).layer(axum::extract::DefaultBodyLimit::max(32));
+
+        let (status, body) = send(router, "POST", "/v2/tasks", Some(json!({"name": "x".repeat(64)}))).await;
+
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(body["code"], "invalid_body");
+    }
+
+    #[tokio::test]
+    async fn test_workspace_crud_is_admin_only() {
+        let router = create_test_router(vec![]);
+
+        let (status, _) = send_as(router.clone(), "Manager", "GET", "/admin/workspaces", None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/admin/workspaces", Some(json!({"id": "platform", "name": "Platform"}))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["data"]["created_by"], "tester");
+
+        let (status, _) = send_as(router.clone(), "Admin", "POST", "/admin/workspaces", Some(json!({"id": "platform", "name": "Again"}))).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (status, body) = send_as(router.clone(), "Admin", "PATCH", "/admin/workspaces/platform", Some(json!({"name": "Platform team"}))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["name"], "Platform team");
+
+        let (_, body) = send_as(router.clone(), "Admin", "GET", "/admin/workspaces", None).await;
+        let ids: Vec<_> = body["data"].as_array().unwrap().iter().map(|workspace| workspace["id"].clone()).collect();
+        assert_eq!(ids, vec![json!("default"), json!("platform")]);
+
+        let (status, _) = send_as(router.clone(), "Admin", "DELETE", "/admin/workspaces/default", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send_as(router.clone(), "Admin", "DELETE", "/admin/workspaces/platform", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_as(router, "Admin", "GET", "/admin/workspaces/platform", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_tenants_only_see_their_own_workspace() {
+        let router = create_in_memory_router();
+        let platform = [("x-user-id", "alice"), ("x-user-role", "Admin"), ("x-tenant-id", "platform")];
+        let payments = [("x-user-id", "bob"), ("x-user-role", "Admin"), ("x-tenant-id", "payments")];
+
+        let (status, body) = send_with_headers(router.clone(), "GET", "/tasks", None, &platform).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["message"], "Workspace platform not found");
+
+        for id in ["platform", "payments"] {
+            let (status, _) = send_as(router.clone(), "Admin", "POST", "/admin/workspaces", Some(json!({"id": id, "name": id}))).await;
+            assert_eq!(status, StatusCode::CREATED);
+        }
+        let (status, body) = send_with_headers(router.clone(), "POST", "/tasks", Some(json!({"name": "Rotate keys", "priority": 5})), &platform).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let task_id = body["data"]["task_id"].as_i64().unwrap();
+
+        let (_, body) = send_with_headers(router.clone(), "GET", "/tasks", None, &platform).await;
+        assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 1);
+        let (_, body) = send_with_headers(router.clone(), "GET", "/tasks", None, &payments).await;
+        assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 0);
+        let (_, body) = send(router.clone(), "GET", "/tasks", None).await;
+        assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 0);
+
+        let uri = format!("/tasks/{}/status", task_id);
+        let (status, _) = send_with_headers(router.clone(), "PATCH", &uri, Some(json!({"status": "InProgress"})), &payments).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = send_as(router, "Admin", "DELETE", "/admin/workspaces/platform", None).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["message"], "Workspace platform still has tasks");
+    }
+
+    #[tokio::test]
+    async fn test_api_keys_only_reach_the_workspace_they_were_issued_in() {
+        let router = create_in_memory_router();
+        for id in ["platform", "payments"] {
+            send_as(router.clone(), "Admin", "POST", "/admin/workspaces", Some(json!({"id": id, "name": id}))).await;
+        }
+        let platform_admin = [("x-user-id", "alice"), ("x-user-role", "Admin"), ("x-tenant-id", "platform")];
+        let (_, body) = send_with_headers(router.clone(), "POST", "/admin/api-keys", Some(json!({"name": "ci-bot", "role": "Manager"})), &platform_admin).await;
+        assert_eq!(body["data"]["workspace_id"], "platform");
+        let key = body["data"]["key"].as_str().unwrap().to_string();
+
+        let (status, _) = send_with_headers(router.clone(), "POST", "/tasks", Some(json!({"name": "Rotate keys", "priority": 5})), &[("x-api-key", &key)]).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (_, body) = send_with_headers(router.clone(), "GET", "/tasks", None, &platform_admin).await;
+        assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 1);
+        let (_, body) = send(router.clone(), "GET", "/tasks", None).await;
+        assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 0);
+
+        let (status, body) = send_with_headers(router, "GET", "/tasks", None, &[("x-api-key", &key), ("x-tenant-id", "payments")]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["message"], "API key belongs to another workspace");
+    }
+
+    #[tokio::test]
+    async fn test_workspace_admins_only_manage_their_own_workspace() {
+        let router = create_in_memory_router();
+        for id in ["platform", "payments"] {
+            send_as(router.clone(), "Admin", "POST", "/admin/workspaces", Some(json!({"id": id, "name": id}))).await;
+        }
+        let platform_admin = [("x-user-id", "alice"), ("x-user-role", "Admin"), ("x-tenant-id", "platform")];
+        let (_, body) = send_with_headers(router.clone(), "POST", "/admin/api-keys", Some(json!({"name": "ops", "role": "Admin"})), &platform_admin).await;
+        let key = body["data"]["key"].as_str().unwrap().to_string();
+        let platform_key = [("x-api-key", key.as_str())];
+        let workflow = json!({"states": ["Pending", "Completed"], "transitions": [{"from": "Pending", "to": "Completed"}]});
+
+        for caller in [&platform_admin[..], &platform_key[..]] {
+            let (_, body) = send_with_headers(router.clone(), "GET", "/admin/workspaces", None, caller).await;
+            assert_eq!(body["data"].as_array().unwrap().len(), 1);
+            assert_eq!(body["data"][0]["id"], "platform");
+
+            let (status, _) = send_with_headers(router.clone(), "GET", "/admin/workspaces/platform/workflow", None, caller).await;
+            assert_eq!(status, StatusCode::OK);
+            for (method, uri, body) in [
+                ("GET", "/admin/workspaces/payments", None),
+                ("PATCH", "/admin/workspaces/payments", Some(json!({"name": "Mine now"}))),
+                ("DELETE", "/admin/workspaces/payments", None),
+                ("DELETE", "/admin/workspaces/platform", None),
+                ("POST", "/admin/workspaces", Some(json!({"id": "billing", "name": "Billing"}))),
+                ("GET", "/admin/workspaces/payments/workflow", None),
+                ("PUT", "/admin/workspaces/payments/workflow", Some(workflow.clone())),
+                ("DELETE", "/admin/workspaces/payments/workflow", None),
+            ] {
+                let (status, _) = send_with_headers(router.clone(), method, uri, body, caller).await;
+                assert_eq!(status, StatusCode::FORBIDDEN, "{} {}", method, uri);
+            }
+        }
+
+        let (_, body) = send_as(router, "Admin", "GET", "/admin/workspaces/payments", None).await;
+        assert_eq!(body["data"]["name"], "payments");
+    }
+
+    #[tokio::test]
+    async fn test_workspaces_follow_the_workflow_admins_store_for_them() {
+        let router = create_in_memory_router();
+        let (status, _) = send_as(router.clone(), "Admin", "POST", "/admin/workspaces", Some(json!({"id": "support", "name": "Support"}))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let support = [("x-user-id", "alice"), ("x-user-role", "User"), ("x-tenant-id", "support")];
+        let (_, body) = send_with_headers(router.clone(), "POST", "/tasks", Some(json!({"name": "Answer ticket", "priority": 1})), &support).await;
+        let task_id = body["data"]["task_id"].as_i64().unwrap();
+        let status_uri = format!("/tasks/{}/status", task_id);
+
+        let (status, body) = send_as(router.clone(), "Admin", "GET", "/admin/workspaces/support/workflow", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["source"], "Configuration");
+        let (status, _) = send_with_headers(router.clone(), "PATCH", &status_uri, Some(json!({"status": "Completed"})), &support).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        // Support closes tickets straight from Pending, with a note, and never cancels them
+        let workflow = json!({
+            "states": ["Pending", "InProgress", "Completed"],
+            "transitions": [
+                {"from": "Pending", "to": "InProgress"},
+                {"from": "Pending", "to": "Completed", "requires_comment": true},
+                {"from": "InProgress", "to": "Completed", "requires_comment": true},
+                {"from": "Completed", "to": "Pending", "role": "Manager"}
+            ]
+        });
+        let (status, _) = send_as(router.clone(), "Manager", "PUT", "/admin/workspaces/support/workflow", Some(workflow.clone())).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, body) = send_as(router.clone(), "Admin", "PUT", "/admin/workspaces/support/workflow", Some(workflow)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["source"], "Workspace");
+        let (status, _) = send_as(router.clone(), "Admin", "PUT", "/admin/workspaces/support/workflow", Some(json!({"states": ["Completed"], "transitions": []}))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let (_, body) = send_with_headers(router.clone(), "GET", &format!("/tasks/{}/transitions", task_id), None, &support).await;
+        assert_eq!(body["data"]["valid_transitions"], json!(["InProgress", "Completed"]));
+        let (status, body) = send_with_headers(router.clone(), "PATCH", &status_uri, Some(json!({"status": "Completed"})), &support).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "comment_required");
+        let (status, body) = send_with_headers(router.clone(), "PATCH", &status_uri, Some(json!({"status": "Completed", "comment": "Answered"})), &support).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["status"], "Completed");
+        let (status, body) = send_with_headers(router.clone(), "PATCH", &status_uri, Some(json!({"status": "Pending"})), &support).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "transition_role_required");
+
+        // Other workspaces keep the configured workflow
+        let (_, body) = send(router.clone(), "POST", "/tasks", Some(json!({"name": "Elsewhere", "priority": 5}))).await;
+        let (status, _) = send(router.clone(), "PATCH", &format!("/tasks/{}/status", body["data"]["task_id"]), Some(json!({"status": "Completed", "comment": "Done"}))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = send_as(router.clone(), "Admin", "DELETE", "/admin/workspaces/support/workflow", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["source"], "Configuration");
+        let (status, _) = send_as(router, "Admin", "DELETE", "/admin/workspaces/support/workflow", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_workflows_move_tasks_through_custom_statuses() {
+        let router = create_in_memory_router();
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/admin/statuses", Some(json!({"name": "Blocked", "category": "open"}))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["data"]["built_in"], false);
+        let (status, _) = send_as(router.clone(), "Admin", "POST", "/admin/statuses", Some(json!({"name": "Blocked", "category": "done"}))).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        let (status, _) = send_as(router.clone(), "Admin", "POST", "/admin/statuses", Some(json!({"name": "completed", "category": "done"}))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (_, body) = send_as(router.clone(), "Admin", "GET", "/admin/statuses", None).await;
+        let names: Vec<_> = body["data"].as_array().unwrap().iter().map(|status| (status["name"].as_str().unwrap(), status["category"].as_str().unwrap())).collect();
+        assert_eq!(names, vec![("Pending", "open"), ("InProgress", "in_progress"), ("PendingReview", "in_progress"), ("Completed", "done"), ("Cancelled", "done"), ("Blocked", "open")]);
+
+        let workflow = |states: Value| json!({
+            "states": states,
+            "transitions": [
+                {"from": "Pending", "to": "Blocked"},
+                {"from": "Blocked", "to": "InProgress"},
+                {"from": "InProgress", "to": "Completed"}
+            ]
+        });
+        let (status, body) = send_as(router.clone(), "Admin", "PUT", "/admin/workspaces/default/workflow", Some(workflow(json!(["Pending", "Blocked", "InProgress", "Completed", "Ghost"])))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["message"], "Unknown task statuses in workflow: Ghost");
+        let (status, _) = send_as(router.clone(), "Admin", "PUT", "/admin/workspaces/default/workflow", Some(workflow(json!(["Pending", "Blocked", "InProgress", "Completed"])))).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (_, body) = send(router.clone(), "POST", "/tasks", Some(json!({"name": "Wait for vendor", "priority": 2}))).await;
+        let task_id = body["data"]["task_id"].as_i64().unwrap();
+        let status_uri = format!("/tasks/{}/status", task_id);
+        let (status, body) = send(router.clone(), "PATCH", &status_uri, Some(json!({"status": "Shipping"}))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["message"], "Unknown task status: Shipping");
+        let (status, body) = send(router.clone(), "PATCH", &status_uri, Some(json!({"status": "Blocked"}))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["status"], "Blocked");
+        let (_, body) = send(router.clone(), "GET", &format!("/tasks/{}/transitions", task_id), None).await;
+        assert_eq!(body["data"]["valid_transitions"], json!(["InProgress"]));
+        let (_, body) = send(router.clone(), "GET", "/tasks?status=Blocked", None).await;
+        assert_eq!(body["data"]["tasks"].as_array().unwrap().len(), 1);
+
+        let tenant_admin = [("x-user-id", "alice"), ("x-user-role", "Admin"), ("x-tenant-id", "default")];
+        let (status, _) = send_with_headers(router.clone(), "DELETE", "/admin/statuses/Blocked", None, &tenant_admin).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = send_with_headers(router.clone(), "POST", "/admin/statuses", Some(json!({"name": "Parked", "category": "open"})), &tenant_admin).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = send_with_headers(router.clone(), "GET", "/admin/statuses", None, &tenant_admin).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send_as(router.clone(), "Admin", "DELETE", "/admin/statuses/Blocked", None).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        let (status, _) = send(router.clone(), "PATCH", &status_uri, Some(json!({"status": "InProgress"}))).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_as(router.clone(), "Admin", "DELETE", "/admin/statuses/Blocked", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_as(router, "Admin", "DELETE", "/admin/statuses/Pending", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_admins_clone_filtered_tasks_into_another_workspace() {
+        let router = create_in_memory_router();
+        let as_user = |user: &'static str| [("x-user-id", user), ("x-user-role", "Admin"), ("x-tenant-id", "template")];
+        for id in ["template", "acme"] {
+            let (status, _) = send_as(router.clone(), "Admin", "POST", "/admin/workspaces", Some(json!({"id": id, "name": id}))).await;
+            assert_eq!(status, StatusCode::CREATED);
+        }
+        for (name, priority, user) in [("Rotate keys", 5, "alice"), ("Review access", 4, "carol"), ("Draft notes", 1, "alice")] {
+            let (status, body) = send_with_headers(router.clone(), "POST", "/tasks", Some(json!({"name": name, "priority": priority})), &as_user(user)).await;
+            assert_eq!(status, StatusCode::CREATED);
+            if name == "Rotate keys" {
+                let uri = format!("/tasks/{}/status", body["data"]["task_id"]);
+                let (status, _) = send_with_headers(router.clone(), "PATCH", &uri, Some(json!({"status": "InProgress"})), &as_user(user)).await;
+                assert_eq!(status, StatusCode::OK);
+            }
+        }
+
+        let request = json!({
+            "source_workspace": "template",
+            "target_workspace": "acme",
+            "filter": "priority >= 4",
+            "user_mapping": [{"from": "alice", "to": "dave"}],
+        });
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/admin/task-clones", Some(request)).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        assert_eq!(body["data"]["state"], "Running");
+        let uri = format!("/admin/task-clones/{}", body["data"]["id"].as_str().unwrap());
+
+        let mut clone = Value::Null;
+        for _ in 0..100 {
+            (_, clone) = send_as(router.clone(), "Admin", "GET", &uri, None).await;
+            if clone["data"]["state"] != "Running" {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(clone["data"]["state"], "Completed");
+        assert_eq!(clone["data"]["total"], 2);
+        assert_eq!(clone["data"]["copied"], 2);
+
+        let acme = [("x-user-id", "dave"), ("x-user-role", "User"), ("x-tenant-id", "acme")];
+        let (_, body) = send_with_headers(router.clone(), "GET", "/tasks", None, &acme).await;
+        let copies = body["data"]["tasks"].as_array().unwrap();
+        let summary: Vec<_> = copies.iter().map(|task| (task["name"].clone(), task["status"].clone(), task["created_by"].clone())).collect();
+        assert_eq!(summary, vec![
+            (json!("Rotate keys"), json!("Pending"), json!("dave")),
+            (json!("Review access"), json!("Pending"), json!("tester")),
+        ]);
+        let (_, body) = send_with_headers(router.clone(), "GET", &format!("/tasks/{}/history", copies[0]["id"]), None, &acme).await;
+        let source_id = clone["data"]["cloned"].as_array().unwrap().iter()
+            .find(|cloned| cloned["task_id"] == copies[0]["id"])
+            .map(|cloned| cloned["source_id"].clone())
+            .unwrap();
+        assert_eq!(body["data"]["history"][0]["comment"], format!("Cloned from task {} in workspace template", source_id));
+
+        let (_, body) = send_as(router.clone(), "Admin", "GET", "/admin/task-clones", None).await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+
+        let default_admin = [("x-user-id", "erin"), ("x-user-role", "Admin"), ("x-tenant-id", "default")];
+        let (_, body) = send_with_headers(router.clone(), "GET", "/admin/task-clones", None, &default_admin).await;
+        assert_eq!(body["data"], json!([]));
+        let (status, _) = send_with_headers(router, "GET", &uri, None, &default_admin).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_task_clones_are_admin_only_and_checked_before_starting() {
+        let router = create_in_memory_router();
+        let request = |source: &str, target: &str| json!({"source_workspace": source, "target_workspace": target});
+        let (status, _) = send_as(router.clone(), "Admin", "POST", "/admin/workspaces", Some(json!({"id": "acme", "name": "Acme"}))).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, _) = send_as(router.clone(), "Manager", "POST", "/admin/task-clones", Some(request("default", "acme"))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/admin/task-clones", Some(request("acme", "acme"))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["message"], "Tasks cannot be cloned into the workspace they come from");
+        let (status, _) = send_as(router.clone(), "Admin", "POST", "/admin/task-clones", Some(request("default", "missing"))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let acme_admin = [("x-user-id", "alice"), ("x-user-role", "Admin"), ("x-tenant-id", "acme")];
+        for (source, target) in [("default", "acme"), ("acme", "default")] {
+            let (status, _) = send_with_headers(router.clone(), "POST", "/admin/task-clones", Some(request(source, target)), &acme_admin).await;
+            assert_eq!(status, StatusCode::FORBIDDEN, "{} to {}", source, target);
+        }
+
+        let mut duplicate = request("default", "acme");
+        duplicate["user_mapping"] = json!([{"from": "alice", "to": "bob"}, {"from": "alice", "to": "carol"}]);
+        let (status, body) = send_as(router.clone(), "Admin", "POST", "/admin/task-clones", Some(duplicate)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["message"], "User 'alice' is mapped twice");
+
+        let (status, _) = send_as(router, "Admin", "GET", "/admin/task-clones/unknown", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_uploaded_attachments_are_listed_with_download_links() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = upload(router.clone(), "/tasks/1/attachments", "notes.txt", b"first draft").await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["data"]["file_name"], "notes.txt");
+        assert_eq!(body["data"]["content_type"], "text/plain");
+        assert_eq!(body["data"]["size_bytes"], 11);
+
+        let (status, body) = send(router, "GET", "/tasks/1/attachments", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let attachments = body["data"].as_array().unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert!(attachments[0]["download_url"].as_str().unwrap().starts_with("https://files.test/tasks/1/"));
+    }
+
+    #[tokio::test]
+    async fn test_v2_uploads_are_not_held_to_the_json_body_limit() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = upload(router, "/v2/tasks/1/attachments", "scan.txt", &vec![b'a'; MAX_TEST_ATTACHMENT_BYTES]).await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["data"]["fileName"], "scan.txt");
+        assert!(body["data"]["downloadUrl"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_uploads_must_be_multipart_forms_within_the_size_limit() {
+        let router = create_test_router(vec![pending_task()]);
+
+        let (status, body) = upload(router.clone(), "/tasks/1/attachments", "big.txt", &vec![b'a'; MAX_TEST_ATTACHMENT_BYTES + 1]).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(body["code"], "invalid_body");
+
+        let (status, _) = send(router.clone(), "POST", "/tasks/1/attachments", Some(json!({"file": "notes.txt"}))).await;
+        assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let (status, _) = upload(router, "/tasks/99/attachments", "notes.txt", b"draft").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
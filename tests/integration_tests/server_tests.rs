@@ -0,0 +1,70 @@
+use axum::{routing::get, Router};
+use axum_postgres_rust::infrastructure::adapters::serve_with_graceful_shutdown;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
+
+fn slow_router(delay: Duration) -> Router {
+    Router::new().route("/slow", get(move || async move {
+        tokio::time::sleep(delay).await;
+        "done"
+    }))
+}
+
+async fn start_request(address: std::net::SocketAddr) -> TcpStream {
+    let mut stream = TcpStream::connect(address).await.unwrap();
+    stream
+        .write_all(b"GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .await
+        .unwrap();
+    stream
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_flight_request_completes_after_shutdown_signal() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (signal_tx, signal_rx) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve_with_graceful_shutdown(
+            listener,
+            slow_router(Duration::from_millis(200)),
+            async { let _ = signal_rx.await; },
+            Duration::from_secs(5),
+        ));
+
+        let mut stream = start_request(address).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        signal_tx.send(()).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("done"));
+        assert!(server.await.unwrap().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_gives_up_after_drain_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (signal_tx, signal_rx) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve_with_graceful_shutdown(
+            listener,
+            slow_router(Duration::from_secs(30)),
+            async { let _ = signal_rx.await; },
+            Duration::from_millis(100),
+        ));
+
+        let _stream = start_request(address).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        signal_tx.send(()).unwrap();
+
+        let drained = tokio::time::timeout(Duration::from_secs(5), server).await.unwrap().unwrap().unwrap();
+        assert!(!drained);
+    }
+}
@@ -0,0 +1,553 @@
+use axum_postgres_rust::application::{AddDependencyRequest, CreateTaskRequest, RequestContext, TaskUseCases, UpdateTaskStatusDto};
+use axum_postgres_rust::database::{with_workspace, Database};
+use axum_postgres_rust::domain::{
+    Attachment, AttachmentRepository, AutomationRule, AutomationRuleRepository, BackgroundJobRepository, RuleAction, RuleFilter, RuleTrigger, CohortBucket, CustomStatus, CustomStatusRepository, StatusCategory, WorkflowDefinition, WorkflowRepository, WorkflowTransition, Workspace, WorkspaceRepository, HistoryFilter, PageKey, Pagination, Rank, RepositoryError, SortOrder, StatisticsQuery, StatusHistory, StatusHistoryRepository, Task, TaskAnalytics, TaskFilter, FilterExpression,
+    TaskId, TaskRepository, TaskSort, TaskSortField, TaskStatisticsRepository, TaskStatus, UserRole,
+};
+use axum_postgres_rust::infrastructure::adapters::{
+    InMemoryTaskRepository, SqliteAttachmentRepository, SqliteAutomationRuleRepository, SqliteBackgroundJobRepository, SqliteCustomStatusRepository, SqliteStatusHistoryRepository, SqliteTaskDependencyRepository, SqliteTaskRepository, SqliteTaskStatisticsRepository, SqliteWorkflowRepository, SqliteWorkspaceRepository,
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+
+/// A migrated in-memory database; one connection, since each would open a database of its own
+async fn sqlite_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    Database::migrate_sqlite(&pool).await.unwrap();
+    pool
+}
+
+async fn sqlite_use_cases() -> (TaskUseCases, SqliteTaskRepository, SqliteStatusHistoryRepository) {
+    sqlite_use_cases_on(sqlite_pool().await)
+}
+
+fn sqlite_use_cases_on(pool: SqlitePool) -> (TaskUseCases, SqliteTaskRepository, SqliteStatusHistoryRepository) {
+    let use_cases = TaskUseCases::new(
+        Arc::new(SqliteTaskRepository::new(pool.clone())),
+        Arc::new(SqliteStatusHistoryRepository::new(pool.clone())),
+        Arc::new(SqliteTaskDependencyRepository::new(pool.clone())),
+    );
+    (use_cases, SqliteTaskRepository::new(pool.clone()), SqliteStatusHistoryRepository::new(pool))
+}
+
+fn create_request(name: &str, priority: i32) -> CreateTaskRequest {
+    CreateTaskRequest { name: name.to_string(), priority: Some(priority.into()), description: None }
+}
+
+fn move_to(status: TaskStatus, comment: Option<&str>) -> UpdateTaskStatusDto {
+    UpdateTaskStatusDto { status, comment: comment.map(str::to_string), checklist: None }
+}
+
+fn entry(task_id: i32, from_status: Option<TaskStatus>, to_status: TaskStatus, changed_at: DateTime<Utc>) -> StatusHistory {
+    StatusHistory::new(uuid::Uuid::new_v4().to_string(), task_id, from_status, to_status, changed_at, "tester".to_string(), None, UserRole::User)
+}
+
+/// Creates a task at `created_at` and walks it through `steps` of hours after creation and target status
+async fn seed_task(tasks: &dyn TaskRepository, name: &str, priority: i32, created_at: DateTime<Utc>, steps: &[(f64, TaskStatus)]) {
+    let task = Task::new_with_status(TaskId::new(0), name.to_string(), Some(priority), TaskStatus::Pending, created_at, created_at).unwrap();
+    let id = tasks.save_with_history(&task, &entry(0, None, TaskStatus::Pending, created_at)).await.unwrap();
+
+    let mut current = Task { id, ..task };
+    for (hours, status) in steps {
+        let changed_at = created_at + Duration::milliseconds((hours * 3_600_000.0) as i64);
+        let history = entry(id.value(), Some(current.status.clone()), status.clone(), changed_at);
+        current = Task { status: status.clone(), updated_at: changed_at, ..current };
+        tasks.update_with_history(&current, &history).await.unwrap();
+    }
+}
+
+/// Tasks with reopenings and sub-second timestamps, over more than one week
+async fn seed_history(tasks: &dyn TaskRepository) {
+    let monday = Utc.with_ymd_and_hms(2025, 5, 12, 9, 0, 0).unwrap();
+    use TaskStatus::*;
+    seed_task(tasks, "Reopened", 5, monday, &[(1.0, InProgress), (3.0, Completed), (5.0, InProgress), (6.5, Completed)]).await;
+    seed_task(tasks, "Straight through", 5, monday + Duration::milliseconds(86_400_500), &[(2.0, InProgress), (10.25, Completed)]).await;
+    seed_task(tasks, "Next week", 2, monday + Duration::days(8), &[(0.5, InProgress)]).await;
+    seed_task(tasks, "Fast", 2, monday + Duration::days(9), &[(0.001, InProgress), (0.002, Completed)]).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_task_lifecycle_is_stored_and_audited() {
+        let (use_cases, tasks, history) = sqlite_use_cases().await;
+        let ctx = RequestContext::anonymous();
+
+        let id = use_cases.create_task(&ctx, create_request("Write release notes", 5)).await.unwrap();
+        let other = use_cases.create_task(&ctx, create_request("Tag the release", 4)).await.unwrap();
+        assert_eq!((id, other), (1, 2));
+
+        use_cases.update_task_status(&ctx, id, move_to(TaskStatus::InProgress, Some("picking this up"))).await.unwrap();
+        use_cases.update_task_status(&ctx, id, move_to(TaskStatus::Completed, None)).await.unwrap();
+
+        let stored = tasks.find_by_id(TaskId::new(id)).await.unwrap().unwrap();
+        assert_eq!(stored.status, TaskStatus::Completed);
+        assert_eq!(stored.created_by.as_deref(), Some("anonymous"));
+        let entries = history.find_by_task_id(id).await.unwrap();
+        let statuses: Vec<_> = entries.iter().map(|entry| entry.to_status.clone()).collect();
+        assert_eq!(statuses, vec![TaskStatus::Pending, TaskStatus::InProgress, TaskStatus::Completed]);
+        assert_eq!(history.count_matching(&HistoryFilter::default()).await.unwrap(), 4);
+
+        let found = use_cases.search_tasks(&ctx, "PICKING", None).await.unwrap();
+        assert_eq!(found.iter().map(|task| task.id).collect::<Vec<_>>(), vec![id]);
+        assert!(use_cases.search_tasks(&ctx, "release 100%", None).await.unwrap().is_empty());
+
+        use_cases.add_dependency(&ctx, other, AddDependencyRequest { depends_on: id }).await.unwrap();
+        use_cases.add_dependency(&ctx, other, AddDependencyRequest { depends_on: id }).await.unwrap();
+        assert!(use_cases.remove_dependency(&ctx, other, id).await.unwrap().depends_on.is_empty());
+
+        use_cases.delete_task(&ctx, id).await.unwrap();
+        assert!(tasks.find_by_id(TaskId::new(id)).await.unwrap().is_none());
+        assert_eq!(use_cases.get_all_tasks(&ctx).await.unwrap().len(), 1);
+        assert!(matches!(tasks.delete(TaskId::new(id)).await, Err(RepositoryError::NotFound(_))));
+
+        let admin = RequestContext::anonymous().with_user("ops", UserRole::Admin);
+        let restored = use_cases.restore_task(&admin, id).await.unwrap();
+        assert_eq!(restored.status, TaskStatus::Completed);
+        assert_eq!(use_cases.get_all_tasks(&ctx).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_listing_filters_sorts_and_reprioritizes_like_postgres() {
+        let tasks = SqliteTaskRepository::new(sqlite_pool().await);
+        for (name, priority) in [("b", Some(2)), ("a", None), ("c", Some(5)), ("d", Some(3))] {
+            tasks.save(&Task::new(TaskId::new(0), name.to_string(), priority).unwrap()).await.unwrap();
+        }
+        tasks.delete(TaskId::new(3)).await.unwrap();
+
+        let by_priority = |order| TaskSort::new(TaskSortField::Priority, order);
+        let names = |found: Vec<Task>| found.into_iter().map(|task| task.name).collect::<Vec<_>>();
+
+        let live = tasks.find_matching(&TaskFilter::default(), by_priority(SortOrder::Desc)).await.unwrap();
+        assert_eq!(names(live), vec!["d", "b", "a"]);
+        let all = TaskFilter { include_deleted: true, ..TaskFilter::default() };
+        assert_eq!(names(tasks.find_matching(&all, by_priority(SortOrder::Asc)).await.unwrap()), vec!["b", "d", "c", "a"]);
+
+        // 2 -> 3 -> 4 moves each task one step, not the task at 2 all the way to 4
+        let changes = tasks.reprioritize(&TaskFilter::default(), &[(2, 3), (3, 4), (5, 1)], Utc::now()).await.unwrap();
+        let moved: Vec<_> = changes.iter().map(|change| (change.task_id.value(), change.from, change.to)).collect();
+        assert_eq!(moved, vec![(1, 2, 3), (4, 3, 4)]);
+    }
+
+    #[tokio::test]
+    async fn test_archived_tasks_round_trip_and_are_listed_apart() {
+        let tasks = SqliteTaskRepository::new(sqlite_pool().await);
+        let now = Utc::now();
+        for name in ["shipped", "open"] {
+            tasks.save(&Task::new_with_status(TaskId::new(0), name.to_string(), Some(2), TaskStatus::Completed, now, now).unwrap()).await.unwrap();
+        }
+        let mut shipped = tasks.find_by_id(TaskId::new(1)).await.unwrap().unwrap();
+        shipped.archive_at(now).unwrap();
+        tasks.update(&shipped).await.unwrap();
+
+        let stored = tasks.find_by_id(TaskId::new(1)).await.unwrap().unwrap();
+        assert_eq!(stored.archived_at.map(|at| at.timestamp_millis()), Some(now.timestamp_millis()));
+
+        let names = |found: Vec<Task>| found.into_iter().map(|task| task.name).collect::<Vec<_>>();
+        assert_eq!(names(tasks.find_matching(&TaskFilter::default(), TaskSort::default()).await.unwrap()), vec!["open"]);
+        let archived = TaskFilter { archived: true, ..TaskFilter::default() };
+        assert_eq!(names(tasks.find_matching(&archived, TaskSort::default()).await.unwrap()), vec!["shipped"]);
+
+        let changes = tasks.reprioritize(&TaskFilter::default(), &[(2, 3)], Utc::now()).await.unwrap();
+        assert_eq!(changes.iter().map(|change| change.task_id.value()).collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_filter_expressions_match_the_in_memory_repository() {
+        let sqlite = SqliteTaskRepository::new(sqlite_pool().await);
+        let memory = InMemoryTaskRepository::new();
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        let seeds = [
+            ("Ship 100% of docs", Some(1), TaskStatus::Pending, Some("ada"), 1),
+            ("Review budget", Some(3), TaskStatus::InProgress, None, 3),
+            ("Plan offsite", None, TaskStatus::Pending, Some("bob"), 10),
+            ("Archive draft", Some(5), TaskStatus::Completed, Some("ada"), 20),
+        ];
+        for (name, priority, status, creator, age_days) in seeds {
+            let created_at = now - Duration::days(age_days);
+            let task = Task::new_with_status(TaskId::new(0), name.to_string(), priority, status, created_at, created_at).unwrap()
+                .with_created_by(creator.map(str::to_string));
+            sqlite.save(&task).await.unwrap();
+            memory.save(&task).await.unwrap();
+        }
+
+        let cases = [
+            ("status in (Pending,InProgress) and priority <= 3 and created_at > -7d", vec![1, 2]),
+            ("name ~ '100%' or created_by = bob", vec![1, 3]),
+            ("not priority = 3 and status != Completed", vec![1, 3]),
+            ("created_by not in (ada) or (priority >= 5 and name ~ DRAFT)", vec![2, 3, 4]),
+            ("updated_at < 2026-03-01", vec![3, 4]),
+        ];
+        for (input, expected) in cases {
+            let filter = TaskFilter { expression: Some(FilterExpression::parse(input, now).unwrap()), ..TaskFilter::default() };
+            for repository in [&sqlite as &dyn TaskRepository, &memory] {
+                let ids: Vec<_> = repository.find_matching(&filter, TaskSort::default()).await.unwrap().iter().map(|task| task.id.value()).collect();
+                assert_eq!(ids, expected, "{}", input);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_facets_are_grouped_like_the_in_memory_repository() {
+        let sqlite = SqliteTaskRepository::new(sqlite_pool().await);
+        let memory = InMemoryTaskRepository::new();
+        let now = Utc::now();
+        for (priority, status) in [(Some(2), TaskStatus::Pending), (None, TaskStatus::Completed), (Some(2), TaskStatus::InProgress), (Some(1), TaskStatus::Pending), (None, TaskStatus::Pending)] {
+            let task = Task::new_with_status(TaskId::new(0), "Task".to_string(), priority, status, now, now).unwrap();
+            sqlite.save(&task).await.unwrap();
+            memory.save(&task).await.unwrap();
+        }
+        sqlite.delete(TaskId::new(4)).await.unwrap();
+        memory.delete(TaskId::new(4)).await.unwrap();
+
+        let open = TaskFilter { expression: Some(FilterExpression::parse("status != Completed", now).unwrap()), ..TaskFilter::default() };
+        for repository in [&sqlite as &dyn TaskRepository, &memory] {
+            let facets = repository.count_facets(&open).await.unwrap();
+            assert_eq!(facets.total, 3);
+            let statuses: Vec<_> = facets.statuses.iter().map(|facet| (facet.value.clone(), facet.count)).collect();
+            assert_eq!(statuses, vec![(TaskStatus::Pending, 2), (TaskStatus::InProgress, 1)]);
+            let priorities: Vec<_> = facets.priorities.iter().map(|facet| (facet.value, facet.count)).collect();
+            assert_eq!(priorities, vec![(Some(2), 2), (None, 1)]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_board_columns_match_the_in_memory_repository() {
+        let sqlite = SqliteTaskRepository::new(sqlite_pool().await);
+        let memory = InMemoryTaskRepository::new();
+        let now = Utc::now();
+        for (priority, status) in [(Some(4), TaskStatus::Pending), (Some(2), TaskStatus::InProgress), (Some(1), TaskStatus::Pending), (None, TaskStatus::Pending), (Some(3), TaskStatus::Pending)] {
+            let task = Task::new_with_status(TaskId::new(0), "Task".to_string(), priority, status, now, now).unwrap();
+            sqlite.save(&task).await.unwrap();
+            memory.save(&task).await.unwrap();
+        }
+        sqlite.delete(TaskId::new(5)).await.unwrap();
+        memory.delete(TaskId::new(5)).await.unwrap();
+
+        let sort = TaskSort::new(TaskSortField::Priority, SortOrder::Asc);
+        for repository in [&sqlite as &dyn TaskRepository, &memory] {
+            let mut columns = repository.find_board(&TaskFilter::default(), sort, 2).await.unwrap();
+            columns.sort_by(|a, b| a.status.as_str().cmp(b.status.as_str()));
+            let columns: Vec<_> = columns.iter()
+                .map(|column| (column.status.clone(), column.count, column.tasks.iter().map(|task| task.id.value()).collect::<Vec<_>>()))
+                .collect();
+            assert_eq!(columns, vec![(TaskStatus::InProgress, 1, vec![2]), (TaskStatus::Pending, 3, vec![3, 1])]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ranks_sort_like_the_in_memory_repository() {
+        let sqlite = SqliteTaskRepository::new(sqlite_pool().await);
+        let memory = InMemoryTaskRepository::new();
+        for name in ["First", "Second", "Third"] {
+            let task = Task::new(TaskId::new(0), name.to_string(), Some(3)).unwrap();
+            sqlite.save(&task).await.unwrap();
+            memory.save(&task).await.unwrap();
+        }
+        let between = Rank::between(Some(&Rank::for_id(TaskId::new(1))), Some(&Rank::for_id(TaskId::new(2)))).unwrap();
+
+        let sort = TaskSort::new(TaskSortField::Rank, SortOrder::Asc);
+        for repository in [&sqlite as &dyn TaskRepository, &memory] {
+            repository.set_rank(TaskId::new(3), &between).await.unwrap();
+            let ids: Vec<i32> = repository.find_matching(&TaskFilter::default(), sort).await.unwrap()
+                .iter().map(|task| task.id.value()).collect();
+            assert_eq!(ids, vec![1, 3, 2]);
+            assert_eq!(repository.find_by_id(TaskId::new(3)).await.unwrap().unwrap().rank, Some(between.clone()));
+            assert!(matches!(repository.set_rank(TaskId::new(9), &between).await, Err(RepositoryError::NotFound(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_statistics_match_the_in_memory_repository() {
+        let pool = sqlite_pool().await;
+        let (sqlite, sqlite_history) = (SqliteTaskRepository::new(pool.clone()), SqliteStatusHistoryRepository::new(pool.clone()));
+        let memory = InMemoryTaskRepository::new();
+        let memory_history = memory.history_repository();
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        let tasks = [
+            (TaskStatus::Pending, now - Duration::days(10)),
+            (TaskStatus::Pending, now - Duration::days(1)),
+            (TaskStatus::Completed, now - Duration::hours(3)),
+            (TaskStatus::Pending, now - Duration::days(1)),
+        ];
+        for (index, (status, at)) in tasks.into_iter().enumerate() {
+            let task = Task::new_with_status(TaskId::new(0), format!("Task {}", index + 1), Some(3), status, at, at).unwrap();
+            sqlite.save(&task).await.unwrap();
+            memory.save(&task).await.unwrap();
+        }
+        // Completed twice on the same day after a reopen, which counts once
+        for hours in [2, 1] {
+            let entry = StatusHistory::new(uuid::Uuid::new_v4().to_string(), 3, Some(TaskStatus::InProgress), TaskStatus::Completed, now - Duration::hours(hours), "user1".to_string(), None, UserRole::User);
+            sqlite_history.save(&entry).await.unwrap();
+            memory_history.save(&entry).await.unwrap();
+        }
+        sqlite.delete(TaskId::new(4)).await.unwrap();
+        memory.delete(TaskId::new(4)).await.unwrap();
+
+        let query = StatisticsQuery::last_days(3, now, Some(now - Duration::days(7)));
+        let statistics = SqliteTaskStatisticsRepository::new(pool).task_statistics(&query).await.unwrap();
+        assert_eq!(statistics, memory.statistics_repository().task_statistics(&query).await.unwrap());
+        assert_eq!((statistics.facets.total, statistics.overdue), (3, 1));
+        let daily: Vec<_> = statistics.daily.iter().map(|day| (day.day.to_string(), day.created, day.completed)).collect();
+        assert_eq!(daily, vec![("2026-03-08".to_string(), 0, 0), ("2026-03-09".to_string(), 1, 0), ("2026-03-10".to_string(), 1, 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_history_is_immutable_and_needs_an_existing_task() {
+        let (use_cases, _, history) = sqlite_use_cases().await;
+        let id = use_cases.create_task(&RequestContext::anonymous(), create_request("Audited", 5)).await.unwrap();
+        let entry = history.find_latest_by_task_id(id).await.unwrap().unwrap();
+
+        assert!(matches!(history.save(&entry).await, Err(RepositoryError::ValidationError(_))));
+        let orphan = StatusHistory { id: uuid::Uuid::new_v4().to_string(), task_id: 99, ..entry.clone() };
+        match history.save(&orphan).await {
+            Err(RepositoryError::ForeignKeyViolation { field, .. }) => assert_eq!(field, "task_id"),
+            other => panic!("expected a foreign key violation, got {:?}", other),
+        }
+
+        history.delete(entry.id.clone()).await.unwrap();
+        assert!(matches!(history.delete(entry.id).await, Err(RepositoryError::NotFound(_))));
+        let page = history.find_matching(&HistoryFilter::default(), SortOrder::Asc, Pagination::new(None, None).unwrap()).await.unwrap();
+        assert!(page.is_empty());
+        assert!(history.get_completion_time_stats(Utc::now() - chrono::Duration::days(1), Utc::now()).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_history_pages_resume_after_a_page_key() {
+        let (use_cases, _, history) = sqlite_use_cases().await;
+        for name in ["First", "Second", "Third"] {
+            use_cases.create_task(&RequestContext::anonymous(), create_request(name, 3)).await.unwrap();
+        }
+        let all = history.find_matching(&HistoryFilter::default(), SortOrder::Asc, Pagination::default()).await.unwrap();
+        let after = |entry: &StatusHistory| Pagination { after: Some(PageKey { at: entry.changed_at, id: entry.id.clone() }), ..Pagination::default() };
+        let task_ids = |entries: Vec<StatusHistory>| entries.iter().map(|entry| entry.task_id).collect::<Vec<_>>();
+
+        let rest = history.find_matching(&HistoryFilter::default(), SortOrder::Asc, after(&all[0])).await.unwrap();
+        assert_eq!(task_ids(rest), vec![all[1].task_id, all[2].task_id]);
+        let rest = history.find_matching(&HistoryFilter::default(), SortOrder::Desc, after(&all[2])).await.unwrap();
+        assert_eq!(task_ids(rest), vec![all[1].task_id, all[0].task_id]);
+    }
+
+    #[tokio::test]
+    async fn test_review_stage_sign_offs_are_stored_with_history() {
+        let (use_cases, _, history) = sqlite_use_cases().await;
+        let id = use_cases.create_task(&RequestContext::anonymous(), create_request("Reviewed", 1)).await.unwrap();
+        let mut sign_off = entry(id, Some(TaskStatus::PendingReview), TaskStatus::PendingReview, Utc::now()).with_review_stage(Some("peer".to_string()));
+        sign_off.changed_by = "alice".to_string();
+        history.save(&sign_off).await.unwrap();
+
+        let stored = history.find_latest_by_task_id(id).await.unwrap().unwrap();
+        assert_eq!(stored.review_stage.as_deref(), Some("peer"));
+        assert_eq!(stored.changed_by, "alice");
+    }
+
+    #[tokio::test]
+    async fn test_reports_match_the_in_memory_repository() {
+        let sqlite = sqlite_pool().await;
+        let sqlite_tasks = SqliteTaskRepository::new(sqlite.clone());
+        let sqlite_history = SqliteStatusHistoryRepository::new(sqlite);
+        let memory_tasks = InMemoryTaskRepository::new();
+        let memory_history = memory_tasks.history_repository();
+        seed_history(&sqlite_tasks).await;
+        seed_history(&memory_tasks).await;
+
+        let start = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let second_week = Utc.with_ymd_and_hms(2025, 5, 19, 0, 0, 0).unwrap();
+
+        let matrix = sqlite_history.get_transition_matrix(start, end).await.unwrap();
+        assert_eq!(matrix, memory_history.get_transition_matrix(start, end).await.unwrap());
+        assert!(!matrix.is_empty());
+        assert_eq!(
+            sqlite_history.get_transition_matrix(second_week, end).await.unwrap(),
+            memory_history.get_transition_matrix(second_week, end).await.unwrap()
+        );
+
+        let stats = sqlite_history.get_completion_time_stats(start, end).await.unwrap();
+        assert_eq!(stats, memory_history.get_completion_time_stats(start, end).await.unwrap());
+        assert_eq!(stats.iter().map(|stats| stats.priority).collect::<Vec<_>>(), vec![2, 5]);
+
+        let completions = sqlite_history.get_priority_completions(start, end).await.unwrap();
+        assert_eq!(completions, memory_history.get_priority_completions(start, end).await.unwrap());
+        let counts: Vec<_> = completions.iter().map(|completion| (completion.priority, completion.task_count)).collect();
+        assert_eq!(counts, vec![(2, 1), (5, 2)]);
+        assert_eq!(completions[1].average, stats[1].average);
+        let first_week = sqlite_history.get_priority_completions(start, second_week).await.unwrap();
+        assert_eq!(first_week, memory_history.get_priority_completions(start, second_week).await.unwrap());
+        assert_eq!(first_week.iter().map(|completion| (completion.priority, completion.task_count)).collect::<Vec<_>>(), vec![(5, 2)]);
+        let first_week_stats = sqlite_history.get_completion_time_stats(start, second_week).await.unwrap();
+        assert_eq!(first_week_stats, memory_history.get_completion_time_stats(start, second_week).await.unwrap());
+        assert_eq!(first_week_stats.iter().map(|stats| stats.priority).collect::<Vec<_>>(), vec![5]);
+        assert_eq!(first_week_stats[0].p50, first_week[0].average);
+
+        let cohorts = sqlite_history.get_creation_cohorts(CohortBucket::Week, start, end).await.unwrap();
+        assert_eq!(cohorts, memory_history.get_creation_cohorts(CohortBucket::Week, start, end).await.unwrap());
+        assert_eq!(cohorts.len(), 2);
+
+        for bucket in [CohortBucket::Day, CohortBucket::Week] {
+            let flow = sqlite_history.get_cumulative_flow(bucket, start, end).await.unwrap();
+            assert_eq!(flow, memory_history.get_cumulative_flow(bucket, start, end).await.unwrap());
+        }
+        let flow = sqlite_history.get_cumulative_flow(CohortBucket::Week, start, end).await.unwrap();
+        let last = flow.buckets.last().unwrap();
+        let counts: Vec<_> = last.statuses.iter().map(|facet| (facet.value.clone(), facet.count)).collect();
+        assert_eq!(counts, vec![(TaskStatus::InProgress, 1), (TaskStatus::Completed, 3)]);
+        assert_eq!(flow.buckets[0].total(), 0);
+
+        let completed = |analytics: Vec<TaskAnalytics>| analytics.into_iter().map(|task| task.task_id).collect::<Vec<_>>();
+        let first_completed = completed(sqlite_history.get_completion_analytics(start, second_week).await.unwrap());
+        assert_eq!(first_completed, completed(memory_history.get_completion_analytics(start, second_week).await.unwrap()));
+        assert_eq!(first_completed, vec![1, 2]);
+        let first_week = HistoryFilter { end_date: Some(second_week), ..HistoryFilter::default() };
+        assert_eq!(sqlite_history.count_matching(&first_week).await.unwrap(), memory_history.count_matching(&first_week).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_background_job_runs_are_claimed_once() {
+        let jobs = SqliteBackgroundJobRepository::new(sqlite_pool().await);
+        let now = Utc::now();
+        jobs.register("metrics-push", std::time::Duration::from_secs(60), now).await.unwrap();
+
+        assert!(jobs.claim_run("metrics-push", now, now + Duration::minutes(1)).await.unwrap());
+        assert!(!jobs.claim_run("metrics-push", now, now + Duration::minutes(1)).await.unwrap());
+        jobs.finish_run("metrics-push", now, Some("Pushgateway answered 502"), now + Duration::minutes(1)).await.unwrap();
+
+        // Paused, only a requested run is claimed; re-registering keeps the pause
+        jobs.set_paused("metrics-push", true).await.unwrap();
+        let job = jobs.register("metrics-push", std::time::Duration::from_secs(30), now).await.unwrap();
+        assert!(job.paused);
+        assert_eq!(job.last_error.as_deref(), Some("Pushgateway answered 502"));
+        assert!(!jobs.claim_run("metrics-push", now, now + Duration::minutes(1)).await.unwrap());
+        jobs.request_run("metrics-push").await.unwrap();
+        assert!(jobs.claim_run("metrics-push", now, now + Duration::minutes(1)).await.unwrap());
+
+        let job = jobs.find_all().await.unwrap().remove(0);
+        assert_eq!((job.interval.as_secs(), job.run_requested, job.last_started_at), (30, false, Some(now)));
+        assert!(matches!(jobs.set_paused("nightly-report", true).await, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_automation_rules_round_trip() {
+        let rules = SqliteAutomationRuleRepository::new(sqlite_pool().await);
+        let now = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        let trigger = RuleTrigger {
+            event_type: "task_status_changed".to_string(),
+            filter: RuleFilter { to_status: Some(TaskStatus::PendingReview), min_priority: Some(7), ..RuleFilter::default() },
+        };
+        let mut rule = AutomationRule::new(uuid::Uuid::now_v7(), "Flag urgent reviews".to_string(), true, trigger, RuleAction::Notify { url: "https://chat.example.com/hooks".to_string() }, "mia".to_string(), now).unwrap();
+        rules.save(&rule).await.unwrap();
+
+        assert_eq!(rules.find_by_id(&rule.id).await.unwrap(), Some(rule.clone()));
+        assert_eq!(with_workspace(Some("platform".to_string()), rules.find_by_id(&rule.id)).await.unwrap(), None);
+        assert!(with_workspace(Some("platform".to_string()), rules.find_all()).await.unwrap().is_empty());
+        let trigger = RuleTrigger { event_type: "task_created".to_string(), filter: RuleFilter::default() };
+        rule.redefine("Raise new tasks".to_string(), false, trigger, RuleAction::SetPriority { priority: 6 }, now + Duration::hours(1)).unwrap();
+        rules.update(&rule).await.unwrap();
+        assert_eq!(rules.find_all().await.unwrap(), vec![rule.clone()]);
+
+        rules.delete(&rule.id).await.unwrap();
+        assert!(matches!(rules.delete(&rule.id).await, Err(RepositoryError::NotFound(_))));
+        assert!(matches!(rules.update(&rule).await, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_workspaces_keep_tasks_and_history_apart() {
+        let pool = sqlite_pool().await;
+        let (use_cases, tasks, history) = sqlite_use_cases_on(pool.clone());
+        let workspaces = SqliteWorkspaceRepository::new(pool);
+        workspaces.save(&Workspace::new("platform".to_string(), "Platform".to_string(), "mia".to_string(), Utc::now()).unwrap()).await.unwrap();
+        let platform = || Some("platform".to_string());
+        let ctx = RequestContext::anonymous().with_tenant(platform());
+
+        let id = with_workspace(platform(), use_cases.create_task(&ctx, create_request("Rotate keys", 5))).await.unwrap();
+        with_workspace(platform(), use_cases.update_task_status(&ctx, id, move_to(TaskStatus::InProgress, None))).await.unwrap();
+        let default = with_workspace(Some("default".to_string()), use_cases.create_task(&RequestContext::anonymous(), create_request("Triage", 3))).await.unwrap();
+
+        let default_ids: Vec<_> = with_workspace(Some("default".to_string()), tasks.find_all()).await.unwrap().iter().map(|task| task.id.value()).collect();
+        assert_eq!(default_ids, vec![default]);
+        assert!(with_workspace(Some("default".to_string()), tasks.find_by_id(TaskId::new(id))).await.unwrap().is_none());
+        assert!(with_workspace(Some("default".to_string()), history.find_by_task_id(id)).await.unwrap().is_empty());
+        assert_eq!(with_workspace(platform(), history.count_matching(&HistoryFilter::default())).await.unwrap(), 2);
+        assert!(matches!(with_workspace(Some("default".to_string()), tasks.delete(TaskId::new(id))).await, Err(RepositoryError::NotFound(_))));
+        assert_eq!(tasks.find_all().await.unwrap().len(), 2);
+
+        assert!(matches!(workspaces.delete("platform").await, Err(RepositoryError::Conflict { .. })));
+        assert!(matches!(workspaces.delete("unknown").await, Err(RepositoryError::NotFound(_))));
+        assert_eq!(workspaces.find_all().await.unwrap().iter().map(|workspace| workspace.id.as_str()).collect::<Vec<_>>(), vec!["default", "platform"]);
+    }
+
+    #[tokio::test]
+    async fn test_attachments_round_trip_in_upload_order() {
+        let pool = sqlite_pool().await;
+        let (use_cases, _, _) = sqlite_use_cases_on(pool.clone());
+        let attachments = SqliteAttachmentRepository::new(pool);
+        let ctx = RequestContext::anonymous();
+        let id = use_cases.create_task(&ctx, create_request("Write spec", 5)).await.unwrap();
+        let other = use_cases.create_task(&ctx, create_request("Review spec", 5)).await.unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let upload = |n: u128, name: &str, minutes: i64| {
+            Attachment::new(uuid::Uuid::from_u128(n), TaskId::new(id), name, Some("text/plain"), 3, "ann".to_string(), at + Duration::minutes(minutes)).unwrap()
+        };
+        let (draft, final_version) = (upload(1, "draft.txt", 5), upload(2, "final.txt", 0));
+
+        attachments.save(&draft).await.unwrap();
+        attachments.save(&final_version).await.unwrap();
+
+        assert_eq!(attachments.find_by_task_id(TaskId::new(id)).await.unwrap(), vec![final_version, draft]);
+        assert!(attachments.find_by_task_id(TaskId::new(other)).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_workflows_are_replaced_and_go_with_their_workspace() {
+        let pool = sqlite_pool().await;
+        let workspaces = SqliteWorkspaceRepository::new(pool.clone());
+        let workflows = SqliteWorkflowRepository::new(pool);
+        workspaces.save(&Workspace::new("support".to_string(), "Support".to_string(), "mia".to_string(), Utc::now()).unwrap()).await.unwrap();
+        let workflow = WorkflowDefinition::new(
+            vec![TaskStatus::Pending, TaskStatus::Completed],
+            vec![WorkflowTransition::new(TaskStatus::Pending, TaskStatus::Completed).requiring_comment().for_role(UserRole::Manager)],
+        ).unwrap();
+
+        assert!(workflows.find_by_workspace("support").await.unwrap().is_none());
+        workflows.save("support", WorkflowDefinition::standard(), "mia", Utc::now()).await.unwrap();
+        workflows.save("support", &workflow, "mia", Utc::now()).await.unwrap();
+        assert_eq!(workflows.find_by_workspace("support").await.unwrap(), Some(workflow.clone()));
+        assert!(matches!(workflows.save("unknown", &workflow, "mia", Utc::now()).await, Err(RepositoryError::ForeignKeyViolation { .. })));
+
+        workspaces.delete("support").await.unwrap();
+        assert!(workflows.find_by_workspace("support").await.unwrap().is_none());
+        assert!(matches!(workflows.delete("support").await, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_tasks_and_history_keep_custom_statuses() {
+        let pool = sqlite_pool().await;
+        let (tasks, history) = (SqliteTaskRepository::new(pool.clone()), SqliteStatusHistoryRepository::new(pool.clone()));
+        let statuses = SqliteCustomStatusRepository::new(pool);
+        let blocked = CustomStatus::new("Blocked", StatusCategory::Open, "mia".to_string(), Utc::now()).unwrap();
+        statuses.save(&blocked).await.unwrap();
+        assert!(matches!(statuses.save(&blocked).await, Err(RepositoryError::Conflict { .. })));
+
+        let now = Utc::now();
+        let id = tasks.save(&Task::new_with_status(TaskId::new(0), "Wait".to_string(), Some(2), blocked.status.clone(), now, now).unwrap()).await.unwrap();
+        history.save(&entry(id.value(), Some(TaskStatus::Pending), blocked.status.clone(), now)).await.unwrap();
+        assert_eq!(tasks.find_by_id(id).await.unwrap().unwrap().status, blocked.status);
+        assert_eq!(history.find_latest_by_task_id(id.value()).await.unwrap().unwrap().to_status, blocked.status);
+        assert_eq!(statuses.find_all().await.unwrap(), vec![blocked.clone()]);
+
+        assert!(matches!(statuses.delete("Blocked").await, Err(RepositoryError::Conflict { .. })));
+        tasks.delete(id).await.unwrap();
+        assert!(matches!(statuses.delete("Blocked").await, Err(RepositoryError::Conflict { .. })));
+        assert!(matches!(statuses.delete("Shipped").await, Err(RepositoryError::NotFound(_))));
+    }
+}
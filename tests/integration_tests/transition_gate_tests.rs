@@ -0,0 +1,63 @@
+use axum::{http::StatusCode, routing::post, Json, Router};
+use axum_postgres_rust::domain::{GateDecision, GatedTransition, TaskStatus, TransitionGate, UserRole};
+use axum_postgres_rust::infrastructure::adapters::HttpTransitionGate;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+// Approval system that answers according to the task name it is sent
+async fn approval_system(Json(transition): Json<Value>) -> (StatusCode, Json<Value>) {
+    match transition["task_name"].as_str().unwrap_or_default() {
+        "Approved" => (StatusCode::OK, Json(json!({ "approved": true }))),
+        "Frozen" => (StatusCode::OK, Json(json!({ "approved": false, "reason": "Change freeze" }))),
+        "Unexplained" => (StatusCode::OK, Json(json!({ "approved": false }))),
+        "Slow" => {
+            tokio::time::sleep(Duration::from_secs(2)).await;
+            (StatusCode::OK, Json(json!({ "approved": true })))
+        }
+        _ => (StatusCode::INTERNAL_SERVER_ERROR, Json(json!({}))),
+    }
+}
+
+async fn start_approval_system() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, Router::new().route("/review", post(approval_system))).await.unwrap();
+    });
+    format!("http://{}/review", address)
+}
+
+fn transition(task_name: &str) -> GatedTransition {
+    GatedTransition {
+        task_id: 1,
+        task_name: task_name.to_string(),
+        from: TaskStatus::PendingReview,
+        to: TaskStatus::Completed,
+        requested_by: "reviewer".to_string(),
+        user_role: UserRole::Manager,
+        comment: Some("Ship it".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_http_gate_reads_the_approval_systems_decision() {
+        let gate = HttpTransitionGate::new(&start_approval_system().await, Duration::from_secs(1));
+
+        assert_eq!(gate.review(&transition("Approved")).await, Ok(GateDecision::Approved));
+        assert_eq!(gate.review(&transition("Frozen")).await, Ok(GateDecision::Rejected { reason: "Change freeze".to_string() }));
+        assert_eq!(gate.review(&transition("Unexplained")).await, Ok(GateDecision::Rejected { reason: "No reason given".to_string() }));
+    }
+
+    #[tokio::test]
+    async fn test_http_gate_fails_on_errors_and_timeouts() {
+        let gate = HttpTransitionGate::new(&start_approval_system().await, Duration::from_millis(100));
+
+        assert!(gate.review(&transition("Broken")).await.is_err());
+        assert!(gate.review(&transition("Slow")).await.is_err());
+    }
+}
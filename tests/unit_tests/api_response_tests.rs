@@ -9,9 +9,14 @@ fn create_test_dto(id: i32, name: &str, priority: Option<i32>) -> TaskDto {
         id,
         name: name.to_string(),
         priority,
+        priority_label: None,
+        description: None,
         status: TaskStatus::Pending,
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        deleted_at: None,
+        archived_at: None,
+        created_by: None,
     }
 }
 
@@ -24,7 +29,7 @@ mod tests {
         let data = "test data";
         let response = ApiResponse::success(data);
 
-        assert_eq!(response.success, true);
+        assert!(response.success);
         assert_eq!(response.data, Some("test data"));
         assert_eq!(response.message, None);
     }
@@ -34,7 +39,7 @@ mod tests {
         let task_dto = create_test_dto(1, "Test Task", Some(5));
         let response = ApiResponse::success(task_dto);
 
-        assert_eq!(response.success, true);
+        assert!(response.success);
         assert!(response.data.is_some());
         assert_eq!(response.message, None);
         
@@ -49,7 +54,7 @@ mod tests {
         let error_message = "Something went wrong".to_string();
         let response = ApiResponse::<()>::error(error_message);
 
-        assert_eq!(response.success, false);
+        assert!(!response.success);
         assert_eq!(response.data, None);
         assert_eq!(response.message, Some("Something went wrong".to_string()));
     }
@@ -167,7 +172,7 @@ mod tests {
         let task_list = TaskListResponse { tasks };
         let api_response = ApiResponse::success(task_list);
 
-        assert_eq!(api_response.success, true);
+        assert!(api_response.success);
         assert!(api_response.data.is_some());
         assert_eq!(api_response.message, None);
         
@@ -184,7 +189,7 @@ mod tests {
         };
         let api_response = ApiResponse::success(created_response);
 
-        assert_eq!(api_response.success, true);
+        assert!(api_response.success);
         assert!(api_response.data.is_some());
         
         let data = api_response.data.unwrap();
@@ -232,7 +237,7 @@ mod tests {
 
         for message in test_messages {
             let response = ApiResponse::<()>::error(message.to_string());
-            assert_eq!(response.success, false);
+            assert!(!response.success);
             assert_eq!(response.data, None);
             assert_eq!(response.message, Some(message.to_string()));
         }
@@ -268,7 +273,7 @@ mod tests {
     fn test_api_response_success_with_none_data() {
         let response: ApiResponse<Option<String>> = ApiResponse::success(None);
         
-        assert_eq!(response.success, true);
+        assert!(response.success);
         assert_eq!(response.data, Some(None));
         assert_eq!(response.message, None);
     }
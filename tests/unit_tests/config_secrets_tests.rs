@@ -0,0 +1,60 @@
+use axum_postgres_rust::config::{ChainedSecretProvider, EnvSecretProvider, FileSecretProvider, SecretProvider};
+use std::path::PathBuf;
+
+fn temp_secrets_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("axum_postgres_rust_{}_{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_secret_provider_reads_and_trims_secret() {
+        let dir = temp_secrets_dir("file_provider");
+        std::fs::write(dir.join("DATABASE_URL"), "postgres://user:pass@db/app\n").unwrap();
+
+        let provider = FileSecretProvider::new(&dir);
+
+        assert_eq!(
+            provider.get_secret("DATABASE_URL").unwrap(),
+            Some("postgres://user:pass@db/app".to_string())
+        );
+        assert_eq!(provider.get_secret("MISSING_SECRET").unwrap(), None);
+    }
+
+    #[test]
+    fn test_env_secret_provider_reads_file_reference() {
+        let dir = temp_secrets_dir("env_file_provider");
+        let path = dir.join("token");
+        std::fs::write(&path, "s3cr3t\n").unwrap();
+        std::env::set_var("SECRETS_TEST_TOKEN_FILE", &path);
+
+        let value = EnvSecretProvider.get_secret("SECRETS_TEST_TOKEN").unwrap();
+
+        assert_eq!(value, Some("s3cr3t".to_string()));
+    }
+
+    #[test]
+    fn test_env_secret_provider_missing_file_is_an_error() {
+        std::env::set_var("SECRETS_TEST_BROKEN_FILE", "/definitely/not/here");
+
+        assert!(EnvSecretProvider.get_secret("SECRETS_TEST_BROKEN").is_err());
+    }
+
+    #[test]
+    fn test_chained_provider_returns_first_match() {
+        let first = temp_secrets_dir("chain_first");
+        let second = temp_secrets_dir("chain_second");
+        std::fs::write(second.join("API_SECRET"), "from-second").unwrap();
+
+        let provider = ChainedSecretProvider::new(vec![
+            Box::new(FileSecretProvider::new(&first)),
+            Box::new(FileSecretProvider::new(&second)),
+        ]);
+
+        assert_eq!(provider.get_secret("API_SECRET").unwrap(), Some("from-second".to_string()));
+    }
+}
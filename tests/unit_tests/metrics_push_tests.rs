@@ -0,0 +1,51 @@
+use axum_postgres_rust::application::{AnalyticsGaugesDto, BudgetStats};
+use axum_postgres_rust::infrastructure::adapters::{render_budget_stats, render_cache_stats, render_gauges, CacheStats};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_render_gauges_uses_prometheus_text_format() {
+    let gauges = AnalyticsGaugesDto {
+        open_tasks: 5,
+        open_tasks_by_status: BTreeMap::from([
+            ("InProgress".to_string(), 1),
+            ("Pending".to_string(), 3),
+            ("PendingReview".to_string(), 1),
+        ]),
+        stale_tasks: 2,
+        approval_backlog: 1,
+    };
+
+    let body = render_gauges(&gauges);
+
+    assert!(body.contains("# TYPE tasks_open gauge\ntasks_open 5\n"));
+    assert!(body.contains("tasks_open_by_status{status=\"Pending\"} 3\n"));
+    assert!(body.contains("tasks_open_by_status{status=\"PendingReview\"} 1\n"));
+    assert!(body.contains("# HELP tasks_stale "));
+    assert!(body.contains("tasks_stale 2\n"));
+    assert!(body.contains("tasks_approval_backlog 1\n"));
+    assert!(body.ends_with('\n'));
+}
+
+#[test]
+fn test_render_cache_stats_reports_hits_and_misses() {
+    let body = render_cache_stats(CacheStats { hits: 7, misses: 3 });
+
+    assert!(body.contains("# TYPE query_cache_requests_total counter\n"));
+    assert!(body.contains("query_cache_requests_total{result=\"hit\"} 7\n"));
+    assert!(body.contains("query_cache_requests_total{result=\"miss\"} 3\n"));
+}
+
+#[test]
+fn test_render_budget_stats_reports_calls_and_overruns_per_operation() {
+    let stats = BTreeMap::from([
+        ("list_tasks", BudgetStats { calls: 12, exceeded: 0 }),
+        ("search_tasks", BudgetStats { calls: 4, exceeded: 3 }),
+    ]);
+
+    let body = render_budget_stats(&stats);
+
+    assert!(body.contains("# TYPE use_case_budget_exceeded_total counter\n"));
+    assert!(body.contains("use_case_calls_total{operation=\"list_tasks\"} 12\n"));
+    assert!(body.contains("use_case_budget_exceeded_total{operation=\"list_tasks\"} 0\n"));
+    assert!(body.contains("use_case_budget_exceeded_total{operation=\"search_tasks\"} 3\n"));
+}
@@ -2,4 +2,9 @@
 pub mod task_dto_tests;
 pub mod use_case_error_tests;
 pub mod api_response_tests;
-pub mod web_controller_tests;
\ No newline at end of file
+pub mod web_controller_tests;
+pub mod config_secrets_tests;
+pub mod config_builder_tests;
+pub mod metrics_push_tests;
+pub mod report_format_tests;
+pub mod payload_snapshot_tests;
@@ -0,0 +1,224 @@
+use axum::body::to_bytes;
+use axum::response::IntoResponse;
+use axum_postgres_rust::application::{
+    AnalyticsGaugesDto, ApiKeyCreatedDto, CohortAnalysisDto, CohortDto, CompletionAnalyticsDto, FieldError, HistoryListDto,
+    PriorityCompletionDto, ReviewProgressDto, StatusHistoryDto, TaskAnalyticsDto, TaskChangeDto, TaskChangeKind, TaskDependenciesDto,
+    TaskDto, TaskHistoryDto, TaskWithTransitionsDto, TransitionMatrixDto,
+};
+use axum_postgres_rust::domain::{
+    ActorKind, ChecklistItem, Cohort, DomainError, DomainEvent, PriorityLabel, TaskEvent, TaskField, TaskStatus, TaskStatusChanged,
+    TransitionEdge, UserRole,
+};
+use axum_postgres_rust::infrastructure::adapters::web::{ServerMessage, WebError};
+use axum_postgres_rust::responses::{ApiResponse, TaskCreatedResponse, TaskListResponse, TaskUpdatedResponse};
+use chrono::{DateTime, TimeZone, Utc};
+use insta::assert_json_snapshot;
+
+fn at(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 1, day, 9, 30, 0).unwrap()
+}
+
+fn task_dto() -> TaskDto {
+    TaskDto {
+        id: 1,
+        name: "Write release notes".to_string(),
+        priority: Some(2),
+        priority_label: Some(PriorityLabel::High),
+        description: Some("Cover the API changes".to_string()),
+        status: TaskStatus::InProgress,
+        created_at: at(1),
+        updated_at: at(2),
+        deleted_at: None,
+        archived_at: None,
+        created_by: None,
+    }
+}
+
+fn history_entry() -> StatusHistoryDto {
+    StatusHistoryDto {
+        id: "5f0c6f8e-3d1a-4c8e-9a57-6b1de2c1f001".to_string(),
+        task_id: 1,
+        from_status: Some(TaskStatus::PendingReview),
+        to_status: TaskStatus::Completed,
+        changed_at: at(3),
+        changed_by: "reviewer".to_string(),
+        comment: Some("Looks good".to_string()),
+        user_role: "Manager".to_string(),
+        actor: ActorKind::Human,
+        checklist: Some(vec![ChecklistItem { item: "Tests pass".to_string(), checked: true }]),
+        review_stage: Some("approval".to_string()),
+    }
+}
+
+async fn error_body(error: WebError) -> serde_json::Value {
+    let response = error.into_response();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_payloads() {
+        assert_json_snapshot!("task_list", ApiResponse::success(TaskListResponse { tasks: vec![task_dto()] }));
+        assert_json_snapshot!("deleted_task", TaskDto { deleted_at: Some(at(4)), ..task_dto() });
+        assert_json_snapshot!("task_created", ApiResponse::success(TaskCreatedResponse {
+            task_id: 1,
+            message: "Task created successfully".to_string(),
+        }));
+        assert_json_snapshot!("task_updated", ApiResponse::success(TaskUpdatedResponse {
+            message: "Task updated successfully".to_string(),
+            changed_fields: vec![TaskField::Name, TaskField::Priority],
+        }));
+        assert_json_snapshot!("task_with_transitions", TaskWithTransitionsDto {
+            task: task_dto(),
+            valid_transitions: vec![TaskStatus::PendingReview, TaskStatus::Completed],
+            blocked_by: vec![3],
+            history_unavailable: false,
+            review: None,
+        });
+        assert_json_snapshot!("task_in_review", TaskWithTransitionsDto {
+            task: TaskDto { status: TaskStatus::PendingReview, ..task_dto() },
+            valid_transitions: vec![TaskStatus::InProgress, TaskStatus::Completed, TaskStatus::Cancelled],
+            blocked_by: vec![],
+            history_unavailable: false,
+            review: Some(ReviewProgressDto {
+                stage: "approval".to_string(),
+                required_role: "Manager".to_string(),
+                stage_number: 2,
+                stage_count: 2,
+                signed_off_by: vec!["peer-reviewer".to_string()],
+            }),
+        });
+        assert_json_snapshot!("task_dependencies", TaskDependenciesDto { task_id: 1, depends_on: vec![2, 3], blocked_by: vec![3] });
+    }
+
+    #[test]
+    fn test_history_payloads() {
+        assert_json_snapshot!("task_history", TaskHistoryDto {
+            task_id: 1,
+            history: vec![history_entry()],
+            total_entries: 1,
+            limit: 50,
+            offset: 0,
+            next_cursor: None,
+            total_time_in_progress: Some("1d 0h 0m 0s".to_string()),
+            number_of_transitions: 3,
+        });
+        assert_json_snapshot!("history_list", HistoryListDto {
+            history: vec![StatusHistoryDto { checklist: None, ..history_entry() }],
+            total_entries: 1,
+            limit: 50,
+            offset: 0,
+            next_cursor: Some("eyJrIjp7fX0.c2lnbmF0dXJl".to_string()),
+        });
+    }
+
+    #[test]
+    fn test_analytics_payloads() {
+        assert_json_snapshot!("task_analytics", TaskAnalyticsDto {
+            task_id: 1,
+            total_time_in_progress: Some("1d 0h 0m 0s".to_string()),
+            time_to_completion: Some("2d 0h 0m 0s".to_string()),
+            number_of_transitions: 3,
+            was_approved: true,
+            approval_time: Some("2h 0m 0s".to_string()),
+            created_at: at(1),
+            completed_at: Some(at(3)),
+            times_reopened: 0,
+            rework_time: None,
+        });
+        assert_json_snapshot!("completion_analytics", CompletionAnalyticsDto {
+            period_start: at(1),
+            period_end: at(31),
+            total_completed_tasks: 4,
+            average_completion_time: Some("2d 0h 0m 0s".to_string()),
+            completion_times_by_priority: vec![PriorityCompletionDto {
+                priority: 2,
+                average_time: "2d 0h 0m 0s".to_string(),
+                p50_time: Some("1d 0h 0m 0s".to_string()),
+                p85_time: Some("3d 0h 0m 0s".to_string()),
+                p95_time: Some("4d 0h 0m 0s".to_string()),
+                task_count: 4,
+            }],
+            approval_rate: 75.0,
+        });
+        let edges = vec![TransitionEdge {
+            from_status: TaskStatus::Pending,
+            to_status: TaskStatus::InProgress,
+            count: 5,
+            median_dwell_time: Some(chrono::Duration::hours(2)),
+        }];
+        assert_json_snapshot!("transition_matrix", TransitionMatrixDto::new(at(1), at(31), edges));
+        let cohort = Cohort { start: at(6), tasks_created: 4, completions_by_period: [(1, 2)].into_iter().collect() };
+        assert_json_snapshot!("cohort_analysis", CohortAnalysisDto {
+            bucket: "week".to_string(),
+            period_start: at(1),
+            period_end: at(31),
+            cohorts: vec![CohortDto::new(&cohort, 2)],
+        });
+        assert_json_snapshot!("analytics_gauges", AnalyticsGaugesDto {
+            open_tasks: 3,
+            open_tasks_by_status: [("Pending".to_string(), 2), ("InProgress".to_string(), 1)].into_iter().collect(),
+            stale_tasks: 1,
+            approval_backlog: 0,
+        });
+    }
+
+    #[test]
+    fn test_change_and_event_payloads() {
+        let change = TaskChangeDto {
+            kind: TaskChangeKind::StatusChanged,
+            task_id: 1,
+            task: Some(task_dto()),
+            changed_fields: vec!["status".to_string()],
+            changed_by: "alice".to_string(),
+            workspace_id: "default".to_string(),
+            occurred_at: at(2),
+            coalesced: None,
+        };
+        assert_json_snapshot!("task_change", change.clone());
+        assert_json_snapshot!("server_message_task_changed", ServerMessage::TaskChanged { change });
+        assert_json_snapshot!("server_message_identity", ServerMessage::Identity { user_id: "alice".to_string(), role: UserRole::Manager });
+        assert_json_snapshot!("domain_event_status_changed", DomainEvent {
+            changed_by: "alice".to_string(),
+            workspace_id: "default".to_string(),
+            event: TaskEvent::TaskStatusChanged(TaskStatusChanged {
+                task_id: 1,
+                from_status: TaskStatus::Pending,
+                to_status: TaskStatus::InProgress,
+                comment: None,
+                occurred_at: at(2),
+            }),
+        });
+        assert_json_snapshot!("api_key_created", ApiKeyCreatedDto {
+            id: "0b9f3a52-8f7e-4a3c-b1c2-6d5e4f3a2b10".to_string(),
+            name: "ci-bot".to_string(),
+            role: "Manager".to_string(),
+            workspace_id: "default".to_string(),
+            key: "tk_live_secret".to_string(),
+            key_prefix: "tk_live_".to_string(),
+            created_at: at(1),
+        });
+    }
+
+    #[tokio::test]
+    async fn test_error_envelopes() {
+        assert_json_snapshot!("error_validation", error_body(WebError::ValidationError("Task name cannot be empty".to_string())).await);
+        assert_json_snapshot!("error_not_found", error_body(WebError::NotFound("Task with id 9 not found".to_string())).await);
+        assert_json_snapshot!(
+            "error_domain_rule",
+            error_body(WebError::Domain(DomainError::InvalidTransition { from: TaskStatus::Completed, to: TaskStatus::Pending })).await
+        );
+        assert_json_snapshot!(
+            "error_invalid_fields",
+            error_body(WebError::InvalidFields(vec![FieldError::new("name", DomainError::NameTooLong { max: 255 })])).await
+        );
+        assert_json_snapshot!(
+            "error_with_request_id",
+            ApiResponse::<()>::error("Internal error".to_string()).with_request_id(Some("support-123".to_string()))
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use axum_postgres_rust::application::{CohortAnalysisDto, CohortDto, CumulativeFlowDto, FlowBucketDto, StatusFacetDto, TransitionMatrixDto};
+use axum_postgres_rust::domain::{Cohort, TaskStatus, TransitionEdge};
+use axum_postgres_rust::infrastructure::adapters::web::{ReportFormat, TextReport};
+use chrono::{TimeZone, Utc};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_format_defaults_to_json() {
+        assert_eq!(ReportFormat::from_param(None).unwrap(), ReportFormat::Json);
+        assert_eq!(ReportFormat::from_param(Some("txt")).unwrap(), ReportFormat::Txt);
+        assert!(ReportFormat::from_param(Some("html")).is_err());
+    }
+
+    #[test]
+    fn test_transition_matrix_renders_one_sentence_per_edge() {
+        let edges = vec![TransitionEdge {
+            from_status: TaskStatus::Pending,
+            to_status: TaskStatus::InProgress,
+            count: 5,
+            median_dwell_time: Some(chrono::Duration::hours(2)),
+        }];
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2025, 1, 31, 0, 0, 0).unwrap();
+
+        let text = TransitionMatrixDto::new(start, end, edges).render_text();
+
+        assert_eq!(text, "Status transitions from 2025-01-01 00:00 UTC to 2025-01-31 00:00 UTC\n\
+                          Total transitions: 5\n\
+                          Pending to InProgress: 5 transitions, median time in Pending 2h 0m 0s\n");
+    }
+
+    #[test]
+    fn test_cohort_analysis_renders_completion_per_period() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 6, 0, 0, 0).unwrap();
+        let cohort = Cohort {
+            start,
+            tasks_created: 4,
+            completions_by_period: [(1, 2)].into_iter().collect(),
+        };
+        let report = CohortAnalysisDto {
+            bucket: "week".to_string(),
+            period_start: start,
+            period_end: start,
+            cohorts: vec![CohortDto::new(&cohort, 2)],
+        };
+
+        let text = report.render_text();
+
+        assert!(text.contains("Tasks created in the week starting 2025-01-06 00:00 UTC: 4\n"));
+        assert!(text.contains("  0 weeks after creation: 0 completed (0%)\n"));
+        assert!(text.contains("  1 week after creation: 2 completed (50%)\n"));
+    }
+
+    #[test]
+    fn test_cumulative_flow_renders_one_line_per_bucket() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let report = CumulativeFlowDto {
+            bucket: "day".to_string(),
+            period_start: start,
+            period_end: start + chrono::Duration::days(1),
+            statuses: vec![TaskStatus::Pending, TaskStatus::Completed],
+            buckets: vec![FlowBucketDto {
+                bucket_start: start,
+                total: 3,
+                statuses: vec![
+                    StatusFacetDto { status: TaskStatus::Pending, count: 2 },
+                    StatusFacetDto { status: TaskStatus::Completed, count: 1 },
+                ],
+            }],
+        };
+
+        let text = report.render_text();
+
+        assert_eq!(text, "Tasks in each status by day from 2025-01-01 00:00 UTC to 2025-01-02 00:00 UTC\n\
+                          At the close of the day starting 2025-01-01 00:00 UTC: 3 tasks, 2 Pending, 1 Completed\n");
+    }
+}
@@ -1,5 +1,5 @@
-use axum_postgres_rust::application::dto::{TaskDto, CreateTaskRequest, UpdateTaskRequest};
-use axum_postgres_rust::domain::{Task, TaskId, TaskStatus};
+use axum_postgres_rust::application::dto::{TaskDto, CreateTaskRequest, UpdateTaskRequest, PriorityInput, StatusHistoryDto, TransitionMatrixDto, CohortDto};
+use axum_postgres_rust::domain::{DomainError, Task, TaskId, TaskStatus, PriorityLabel, PriorityLabels, StatusHistory, ChecklistItem, TransitionEdge, Cohort, UserRole};
 use chrono::Utc;
 use serde_json;
 
@@ -37,9 +37,14 @@ mod tests {
             id: 3,
             name: "Valid Task".to_string(),
             priority: Some(7),
+            priority_label: None,
+            description: None,
             status: TaskStatus::Pending,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
+            archived_at: None,
+            created_by: None,
         };
 
         let task = Task::try_from(dto).unwrap();
@@ -54,14 +59,19 @@ mod tests {
             id: 4,
             name: "".to_string(), // Invalid empty name
             priority: Some(3),
+            priority_label: None,
+            description: None,
             status: TaskStatus::Pending,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
+            archived_at: None,
+            created_by: None,
         };
 
         let result = Task::try_from(dto);
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Task name cannot be empty");
+        assert_eq!(result.unwrap_err(), DomainError::EmptyName);
     }
 
     #[test]
@@ -70,14 +80,19 @@ mod tests {
             id: 5,
             name: "Valid Name".to_string(),
             priority: Some(11), // Invalid priority
+            priority_label: None,
+            description: None,
             status: TaskStatus::Pending,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
+            archived_at: None,
+            created_by: None,
         };
 
         let result = Task::try_from(dto);
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Priority must be between 1 and 10");
+        assert_eq!(result.unwrap_err(), DomainError::PriorityOutOfRange { priority: 11, min: 1, max: 10 });
     }
 
     #[test]
@@ -86,9 +101,14 @@ mod tests {
             id: 1,
             name: "Serialization Test".to_string(),
             priority: Some(8),
+            priority_label: None,
+            description: None,
             status: TaskStatus::Pending,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
+            archived_at: None,
+            created_by: None,
         };
 
         let serialized = serde_json::to_string(&dto).unwrap();
@@ -114,9 +134,14 @@ mod tests {
             id: 3,
             name: "No Priority".to_string(),
             priority: None,
+            priority_label: None,
+            description: None,
             status: TaskStatus::Pending,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
+            archived_at: None,
+            created_by: None,
         };
 
         let serialized = serde_json::to_string(&dto).unwrap();
@@ -129,7 +154,7 @@ mod tests {
         let request: CreateTaskRequest = serde_json::from_str(json).unwrap();
 
         assert_eq!(request.name, "New Task");
-        assert_eq!(request.priority, Some(4));
+        assert_eq!(request.priority, Some(4.into()));
     }
 
     #[test]
@@ -145,7 +170,8 @@ mod tests {
     fn test_create_task_request_debug() {
         let request = CreateTaskRequest {
             name: "Debug Test".to_string(),
-            priority: Some(9),
+            priority: Some(9.into()),
+            description: None,
         };
 
         let debug_output = format!("{:?}", request);
@@ -160,7 +186,7 @@ mod tests {
         let request: UpdateTaskRequest = serde_json::from_str(json).unwrap();
 
         assert_eq!(request.name, Some("Updated Task".to_string()));
-        assert_eq!(request.priority, Some(7));
+        assert_eq!(request.priority, Some(7.into()));
     }
 
     #[test]
@@ -178,7 +204,7 @@ mod tests {
         let request: UpdateTaskRequest = serde_json::from_str(json).unwrap();
 
         assert_eq!(request.name, None);
-        assert_eq!(request.priority, Some(10));
+        assert_eq!(request.priority, Some(10.into()));
     }
 
     #[test]
@@ -195,6 +221,7 @@ mod tests {
         let request = UpdateTaskRequest {
             name: Some("Debug Update".to_string()),
             priority: None,
+            description: None,
         };
 
         let debug_output = format!("{:?}", request);
@@ -249,9 +276,14 @@ mod tests {
             id: 100,
             name: "Roundtrip Equality Test".to_string(),
             priority: Some(5),
+            priority_label: None,
+            description: None,
             status: TaskStatus::Pending,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
+            archived_at: None,
+            created_by: None,
         };
 
         let serialized = serde_json::to_string(&dto).unwrap();
@@ -261,4 +293,108 @@ mod tests {
         assert_eq!(dto.name, deserialized.name);
         assert_eq!(dto.priority, deserialized.priority);
     }
+
+
+    #[test]
+    fn test_priority_accepts_number_or_label() {
+        let numeric: CreateTaskRequest = serde_json::from_str(r#"{"name": "A", "priority": 4}"#).unwrap();
+        assert_eq!(numeric.priority, Some(PriorityInput::Value(4)));
+
+        let labeled: CreateTaskRequest = serde_json::from_str(r#"{"name": "B", "priority": "High"}"#).unwrap();
+        assert_eq!(labeled.priority, Some(PriorityInput::Label(PriorityLabel::High)));
+        assert_eq!(labeled.priority.unwrap().resolve(&PriorityLabels::default()), 3);
+
+        let invalid: Result<UpdateTaskRequest, _> = serde_json::from_str(r#"{"priority": "Urgent"}"#);
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_task_dto_includes_priority_label() {
+        let task = Task::new(TaskId::new(1), "Labelled".to_string(), Some(7)).unwrap();
+
+        let json = serde_json::to_value(TaskDto::from(task.clone())).unwrap();
+        assert_eq!(json["priority"], 7);
+        assert_eq!(json["priority_label"], "Medium");
+
+        let labels = PriorityLabels::parse("Critical=1,High=2,Medium=3,Low=7").unwrap();
+        assert_eq!(TaskDto::from_task(task, &labels).priority_label, Some(PriorityLabel::Low));
+    }
+
+
+    #[test]
+    fn test_status_history_dto_exposes_checklist() {
+        let history = StatusHistory::new(
+            "5f0c1f0e-4d7a-4a7c-9a59-2b1c7e9d8a11".to_string(),
+            1,
+            Some(TaskStatus::PendingReview),
+            TaskStatus::Completed,
+            Utc::now(),
+            "reviewer".to_string(),
+            None,
+            UserRole::Manager,
+        ).with_checklist(Some(vec![ChecklistItem { item: "Tests pass".to_string(), checked: true }]));
+
+        let json = serde_json::to_value(StatusHistoryDto::from(history)).unwrap();
+        assert_eq!(json["checklist"][0]["item"], "Tests pass");
+        assert_eq!(json["checklist"][0]["checked"], true);
+    }
+
+
+    #[test]
+    fn test_transition_matrix_dto_groups_edges_by_status() {
+        let edge = |from_status, to_status, count, hours| TransitionEdge {
+            from_status,
+            to_status,
+            count,
+            median_dwell_time: Some(chrono::Duration::hours(hours)),
+        };
+        let edges = vec![
+            edge(TaskStatus::InProgress, TaskStatus::Completed, 3, 26),
+            edge(TaskStatus::Pending, TaskStatus::InProgress, 5, 2),
+            edge(TaskStatus::Pending, TaskStatus::Cancelled, 1, 48),
+        ];
+
+        let dto = TransitionMatrixDto::new(Utc::now(), Utc::now(), edges);
+        let json = serde_json::to_value(&dto).unwrap();
+
+        assert_eq!(dto.total_transitions, 9);
+        assert_eq!(json["matrix"]["Pending"]["InProgress"], 5);
+        assert_eq!(json["matrix"]["Pending"]["Cancelled"], 1);
+        assert_eq!(json["matrix"]["InProgress"]["Completed"], 3);
+        assert_eq!(json["edges"][0]["median_dwell_time"], "1d 2h 0m 0s");
+        assert_eq!(json["edges"][0]["median_dwell_seconds"], 26 * 3600);
+    }
+
+
+    #[test]
+    fn test_cohort_dto_reports_cumulative_completion_percentage() {
+        let cohort = Cohort {
+            start: Utc::now(),
+            tasks_created: 3,
+            completions_by_period: [(0, 1), (2, 1)].into_iter().collect(),
+        };
+
+        let dto = CohortDto::new(&cohort, 4);
+
+        let completed: Vec<usize> = dto.completion.iter().map(|period| period.completed).collect();
+        let percentages: Vec<f64> = dto.completion.iter().map(|period| period.completion_percentage).collect();
+        assert_eq!(completed, vec![1, 1, 2, 2]);
+        assert_eq!(percentages, vec![33.3, 33.3, 66.7, 66.7]);
+        assert_eq!(dto.completion[3].periods_since_creation, 3);
+    }
+
+
+    #[test]
+    fn test_task_dto_from_task_moves_strings_instead_of_cloning() {
+        let task = create_test_task(1, "A name long enough to need its own allocation", Some(5))
+            .with_description(Some("A description that list responses should not copy".to_string()))
+            .unwrap();
+        let name_ptr = task.name.as_ptr();
+        let description_ptr = task.description.as_ref().unwrap().as_ptr();
+
+        let dto = TaskDto::from_task(task, &PriorityLabels::default());
+
+        assert_eq!(dto.name.as_ptr(), name_ptr);
+        assert_eq!(dto.description.as_ref().unwrap().as_ptr(), description_ptr);
+    }
 }
@@ -41,6 +41,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_constraint_violations_keep_the_field() {
+        let conflict = UseCaseError::from(RepositoryError::Conflict {
+            field: "key".to_string(),
+            message: "A record with this key already exists".to_string(),
+        });
+        match conflict {
+            UseCaseError::Conflict(error) => {
+                assert_eq!((error.field.as_str(), error.code.as_str()), ("key", "conflict"));
+            }
+            other => panic!("Expected Conflict, got {:?}", other),
+        }
+
+        let missing = UseCaseError::from(RepositoryError::ForeignKeyViolation {
+            field: "depends_on".to_string(),
+            message: "The referenced depends_on does not exist".to_string(),
+        });
+        match missing {
+            UseCaseError::InvalidFields(errors) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!((errors[0].field.as_str(), errors[0].code.as_str()), ("depends_on", "foreign_key_violation"));
+            }
+            other => panic!("Expected InvalidFields, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_usecase_error_clone() {
         let original = UseCaseError::ValidationError("Test".to_string());
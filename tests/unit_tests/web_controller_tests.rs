@@ -11,9 +11,14 @@ fn create_test_dto(id: i32, name: &str, priority: Option<i32>) -> TaskDto {
         id,
         name: name.to_string(),
         priority,
+        priority_label: None,
+        description: None,
         status: TaskStatus::Pending,
         created_at: Utc::now(),
         updated_at: Utc::now(),
+        deleted_at: None,
+        archived_at: None,
+        created_by: None,
     }
 }
 
@@ -46,6 +51,22 @@ mod tests {
             WebError::InternalError(msg) => assert_eq!(msg, "Database error"),
             _ => panic!("Expected InternalError"),
         }
+
+        let cancelled_error = UseCaseError::Cancelled("Client went away".to_string());
+        let web_error = WebError::from(cancelled_error);
+
+        match web_error {
+            WebError::Unavailable(msg) => assert_eq!(msg, "Client went away"),
+            _ => panic!("Expected Unavailable error"),
+        }
+
+        let forbidden_error = UseCaseError::Forbidden("Not your task".to_string());
+        let web_error = WebError::from(forbidden_error);
+
+        match web_error {
+            WebError::Forbidden(msg) => assert_eq!(msg, "Not your task"),
+            _ => panic!("Expected Forbidden error"),
+        }
     }
 
     #[test]
@@ -81,34 +102,55 @@ mod tests {
         assert!(response.status().is_client_error());
     }
 
+    #[tokio::test]
+    async fn test_conflict_is_answered_with_409_naming_the_field() {
+        use axum::response::IntoResponse;
+        use axum_postgres_rust::domain::RepositoryError;
+
+        let error = UseCaseError::from(RepositoryError::Conflict {
+            field: "key".to_string(),
+            message: "A record with this key already exists".to_string(),
+        });
+        let response = WebError::from(error).into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::CONFLICT);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "conflict");
+        assert_eq!(body["errors"][0]["field"], "key");
+    }
+
     #[test]
     fn test_create_task_request_structure() {
         let request = CreateTaskRequest {
             name: "Test Task".to_string(),
-            priority: Some(5),
+            priority: Some(5.into()),
+            description: None,
         };
         
         assert_eq!(request.name, "Test Task");
-        assert_eq!(request.priority, Some(5));
+        assert_eq!(request.priority, Some(5.into()));
     }
 
     #[test]
     fn test_update_task_request_structure() {
         let request = UpdateTaskRequest {
             name: Some("Updated Task".to_string()),
-            priority: Some(8),
+            priority: Some(8.into()),
+            description: None,
         };
         
         assert_eq!(request.name, Some("Updated Task".to_string()));
-        assert_eq!(request.priority, Some(8));
+        assert_eq!(request.priority, Some(8.into()));
 
         let partial_request = UpdateTaskRequest {
             name: None,
-            priority: Some(3),
+            priority: Some(3.into()),
+            description: None,
         };
         
         assert_eq!(partial_request.name, None);
-        assert_eq!(partial_request.priority, Some(3));
+        assert_eq!(partial_request.priority, Some(3.into()));
     }
 
     #[test]
@@ -212,18 +254,20 @@ mod tests {
         // Test that our DTOs can be serialized/deserialized
         let create_request = CreateTaskRequest {
             name: "New Task".to_string(),
-            priority: Some(7),
+            priority: Some(7.into()),
+            description: None,
         };
 
         let json = serde_json::to_string(&create_request).unwrap();
         let deserialized: CreateTaskRequest = serde_json::from_str(&json).unwrap();
         
         assert_eq!(deserialized.name, "New Task");
-        assert_eq!(deserialized.priority, Some(7));
+        assert_eq!(deserialized.priority, Some(7.into()));
 
         let update_request = UpdateTaskRequest {
             name: Some("Updated".to_string()),
             priority: None,
+            description: None,
         };
 
         let json = serde_json::to_string(&update_request).unwrap();
@@ -263,4 +307,19 @@ mod tests {
         assert_eq!(deserialized.task_id, 999);
         assert_eq!(deserialized.message, "Successfully created task");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_route_roles_cover_admin_routes_deletes_and_v2_copies() {
+        use axum::http::Method;
+        use axum_postgres_rust::infrastructure::adapters::web::{required_role, RouteRole};
+
+        assert_eq!(required_role(&Method::DELETE, "/tasks/{task_id}"), Some(RouteRole::Elevated));
+        assert_eq!(required_role(&Method::DELETE, "/v2/tasks/{task_id}"), Some(RouteRole::Elevated));
+        assert_eq!(required_role(&Method::GET, "/tasks/{task_id}"), None);
+        assert_eq!(required_role(&Method::POST, "/admin/jobs/{name}/pause"), Some(RouteRole::Admin));
+        assert_eq!(required_role(&Method::GET, "/administrators"), None);
+        assert_eq!(required_role(&Method::PUT, "/automation/rules/{rule_id}"), Some(RouteRole::Elevated));
+        assert!(RouteRole::Elevated.allows(&axum_postgres_rust::domain::UserRole::Manager));
+        assert!(!RouteRole::Admin.allows(&axum_postgres_rust::domain::UserRole::Manager));
+    }
+}